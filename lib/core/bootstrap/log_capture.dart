@@ -1,13 +1,19 @@
 ﻿import 'dart:async';
-import 'dart:io';
-import 'dart:convert';
 import 'package:flutter/foundation.dart';
 import 'package:astral/core/services/service_manager.dart';
+import 'package:astral/src/rust/api/p2p.dart'
+    show InstanceEventRecord, subscribeInstanceEvents;
+import 'package:astral/src/rust/api/simple.dart' show defaultInstanceId;
 
-/// UDP 日志捕获（单例）
+/// 实例事件日志捕获（单例）
+///
+/// 订阅默认实例的事件流并写入日志列表。默认实例变化时自动重新订阅，
+/// Rust 端会先回放实例最近的事件，因此启动阶段的事件不会丢失。
 class LogCapture {
   static LogCapture? _instance;
-  RawDatagramSocket? _udpSocket;
+  Timer? _watchTimer;
+  StreamSubscription<InstanceEventRecord>? _subscription;
+  String? _instanceId;
   bool _isCapturing = false;
 
   factory LogCapture() {
@@ -17,52 +23,65 @@ class LogCapture {
 
   LogCapture._internal();
 
-  /// 开始捕获UDP日志
+  /// 开始捕获实例事件日志
   Future<void> startCapture({
-    String host = '127.0.0.1',
-    int port = 9999,
+    Duration pollInterval = const Duration(seconds: 1),
   }) async {
     if (_isCapturing) return;
+    _isCapturing = true;
+    await _syncInstance();
+    _watchTimer = Timer.periodic(pollInterval, (_) => _syncInstance());
+  }
+
+  /// 停止捕获
+  Future<void> stopCapture() async {
+    _watchTimer?.cancel();
+    _watchTimer = null;
+    await _subscription?.cancel();
+    _subscription = null;
+    _instanceId = null;
+    _isCapturing = false;
+  }
+
+  Future<void> _syncInstance() async {
+    final String? id;
     try {
-      _udpSocket = await RawDatagramSocket.bind(InternetAddress(host), port);
-      _isCapturing = true;
-      _udpSocket!.listen(
-        (RawSocketEvent event) {
-          if (event == RawSocketEvent.read) {
-            final datagram = _udpSocket!.receive();
-            if (datagram != null) {
-              try {
-                final logData = utf8.decode(datagram.data);
-                if (logData.isNotEmpty) {
-                  _addLogToSignal(
-                    '[${DateTime.now().toString().substring(11, 19)}] $logData',
-                  );
-                }
-              } catch (e) {
-                if (kDebugMode) {
-                  debugPrint('UDP log decode error: $e');
-                }
-              }
-            }
-          }
-        },
-        onError: (error) {
-          if (kDebugMode) {
-            debugPrint('UDP socket error: $error');
-          }
-          _isCapturing = false;
-        },
-        onDone: () {
-          _isCapturing = false;
-        },
-      );
+      id = await defaultInstanceId();
     } catch (e) {
       if (kDebugMode) {
-        debugPrint('Failed to start UDP log capture: $e');
+        debugPrint('Failed to query default instance: $e');
       }
-      _isCapturing = false;
-      rethrow;
+      return;
     }
+    if (id == _instanceId) return;
+
+    await _subscription?.cancel();
+    _subscription = null;
+    _instanceId = id;
+    if (id == null) return;
+
+    _subscription = subscribeInstanceEvents(instanceId: id).listen(
+      (record) {
+        final time = DateTime.fromMillisecondsSinceEpoch(
+          record.timestamp.toInt(),
+        );
+        _addLogToSignal(
+          '[${time.toString().substring(11, 19)}] ${record.event}',
+        );
+      },
+      onError: (error) {
+        if (kDebugMode) {
+          debugPrint('Instance event stream error: $error');
+        }
+      },
+      onDone: () {
+        // 实例已关闭，下次轮询时重新订阅
+        if (_instanceId == id) {
+          _instanceId = null;
+          _subscription = null;
+        }
+      },
+    );
   }
 
   void _addLogToSignal(String logEntry) {
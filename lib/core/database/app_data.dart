@@ -27,6 +27,9 @@ class AppDatabase {
   late AllSettingsDao allSettings;
   late ServerDao servers;
   late MagicWallDao magicWall;
+
+  /// 应用数据目录，数据库目录的上一级
+  late String dataDir;
   bool _initialized = false;
   bool get isInitialized => _initialized;
 
@@ -60,6 +63,7 @@ class AppDatabase {
 
     // 确保数据库目录存在
     await Directory(dbDir).create(recursive: true);
+    dataDir = Directory(dbDir).parent.path;
     isar = await Isar.open([
       ThemeSettingsSchema,
       NetConfigSchema,
@@ -1,3 +1,5 @@
+import 'dart:io';
+
 import 'package:flutter/material.dart';
 import 'package:uuid/uuid.dart';
 import 'package:isar_community/isar.dart';
@@ -87,11 +89,19 @@ class _MagicWallGroupDialogState extends State<MagicWallGroupDialog> {
               const SizedBox(height: 16),
               TextField(
                 controller: _processController,
-                decoration: const InputDecoration(
+                decoration: InputDecoration(
                   labelText: '绑定进程名称',
-                  hintText: '如: game.exe',
-                  border: OutlineInputBorder(),
-                  prefixIcon: Icon(Icons.memory),
+                  hintText:
+                      Platform.isLinux ? '如: game 或 uid:1000' : '如: game.exe',
+                  // nftables 无法按可执行文件匹配，进程规则按其所在的 cgroup 生效
+                  helperText:
+                      Platform.isLinux
+                          ? '按运行中进程所在的 cgroup 匹配，仅对出站连接生效；'
+                              '也可填写 uid:<UID>、gid:<GID> 或 cgroup:<路径>'
+                          : null,
+                  helperMaxLines: 2,
+                  border: const OutlineInputBorder(),
+                  prefixIcon: const Icon(Icons.memory),
                 ),
               ),
               const SizedBox(height: 16),
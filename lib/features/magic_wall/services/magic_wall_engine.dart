@@ -1,3 +1,5 @@
+import 'dart:convert';
+
 import 'package:astral/core/database/dao/magic_wall_dao.dart';
 import 'package:astral/core/models/magic_wall_model.dart';
 import 'package:astral/features/magic_wall/models/magic_wall_group_bundle.dart';
@@ -6,6 +8,10 @@ import 'package:astral/src/rust/api/magic_wall.dart' as rust_api;
 import 'package:flutter/foundation.dart';
 
 /// Rust Magic Wall engine sync: convert models, start/stop, add/remove/update.
+///
+/// The DAO is the source of truth for groups and rules. The Rust store only
+/// holds the rules to apply and persists them across stop and restart, so
+/// rules of disabled groups must be removed from it explicitly.
 class MagicWallEngine {
   MagicWallEngine({required this.store});
 
@@ -35,6 +41,7 @@ class MagicWallEngine {
       localIp: model.localIp,
       remotePort: model.remotePort,
       localPort: model.localPort,
+      priority: model.priority,
       description: model.description,
       createdAt: model.createdAt,
     );
@@ -69,12 +76,41 @@ class MagicWallEngine {
   Future<void> removeRuleFromEngine(String ruleId) =>
       rust_api.removeMagicWallRule(ruleId: ruleId);
 
+  /// Remove rules from the Rust store that aren't active in [bundles]:
+  /// rules of disabled groups, disabled rules and rules deleted meanwhile.
+  Future<void> pruneInactiveRules(List<MagicWallGroupBundle> bundles) async {
+    final activeRuleIds = <String>{
+      for (final bundle in bundles.where((b) => b.group.enabled))
+        for (final rule in bundle.rules.where((r) => r.enabled)) rule.ruleId,
+    };
+
+    final List<dynamic> storedRules;
+    try {
+      final json = await rust_api.exportMagicWallRules();
+      final exported = jsonDecode(json) as Map<String, dynamic>;
+      storedRules = exported['rules'] as List<dynamic>;
+    } catch (e) {
+      debugPrint('⚠️  读取已保存的规则失败: $e');
+      return;
+    }
+
+    for (final stored in storedRules.cast<Map<String, dynamic>>()) {
+      final ruleId = stored['id'] as String;
+      if (!activeRuleIds.contains(ruleId)) {
+        await removeRuleQuietly(ruleId, ruleName: stored['name'] as String);
+      }
+    }
+  }
+
   /// Sync enabled rules for all enabled groups, then start the engine.
   Future<void> startAndSyncRules({
     required List<MagicWallGroupBundle> bundles,
     required Future<String?> Function(MagicWallGroupModel group)
     resolveGroupAppPath,
   }) async {
+    // Rust 层保存的规则在启动时会全部应用，先移除已停用分组的规则
+    await pruneInactiveRules(bundles);
+
     // 先同步所有启用的规则到 Rust 层
     for (final bundle in bundles.where((b) => b.group.enabled)) {
       final groupExecutable = await resolveGroupAppPath(bundle.group);
@@ -177,6 +177,7 @@ class MagicWallStore {
               ..localIp = rule.localIp
               ..remotePort = rule.remotePort
               ..localPort = rule.localPort
+              ..priority = rule.priority
               ..description = rule.description
               ..createdAt = rule.createdAt
               ..updatedAt = DateTime.now().millisecondsSinceEpoch;
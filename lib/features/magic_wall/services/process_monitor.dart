@@ -189,6 +189,7 @@ class MagicWallProcessMonitor {
                 ..localIp = rule.localIp
                 ..remotePort = rule.remotePort
                 ..localPort = rule.localPort
+                ..priority = rule.priority
                 ..description = rule.description
                 ..createdAt = rule.createdAt
                 ..updatedAt = DateTime.now().millisecondsSinceEpoch;
@@ -84,6 +84,7 @@ class ProcessPathResolver {
                   ..localIp = rule.localIp
                   ..remotePort = rule.remotePort
                   ..localPort = rule.localPort
+                  ..priority = rule.priority
                   ..description = rule.description
                   ..createdAt = rule.createdAt
                   ..updatedAt = DateTime.now().millisecondsSinceEpoch;
@@ -147,6 +148,7 @@ class ProcessPathResolver {
           ..localIp = rule.localIp
           ..remotePort = rule.remotePort
           ..localPort = rule.localPort
+          ..priority = rule.priority
           ..description = rule.description
           ..createdAt = rule.createdAt
           ..updatedAt = DateTime.now().millisecondsSinceEpoch;
@@ -202,6 +204,11 @@ class ProcessPathResolver {
       }
     }
 
+    // Linux 下由 nftables 后端按进程名、uid:/gid:/cgroup: 自行解析
+    if (Platform.isLinux) {
+      return trimmed;
+    }
+
     final sanitized = trimmed.replaceAll(
       RegExp(r'\.exe$', caseSensitive: false),
       '',
@@ -1,6 +1,7 @@
 import 'dart:async';
 import 'dart:io';
 import 'package:astral/src/rust/api/utils.dart';
+import 'package:astral/src/rust/api/magic_wall.dart' show initMagicWallStore;
 import 'package:easy_localization/easy_localization.dart';
 import 'package:astral/core/platform/app_info.dart';
 import 'package:astral/core/platform/startup_url_scheme.dart';
@@ -101,6 +102,8 @@ Future<void> _initializeApp() async {
     await AppDatabase().init();
     FileLogger().info('Database initialized');
 
+    await _initMagicWallStore();
+
     // 初始化新的服务管理器
     final services = ServiceManager();
     await services.init();
@@ -160,6 +163,18 @@ void _runApp() {
   );
 }
 
+/// 启用魔法墙规则持久化，规则文件与数据库放在同一数据目录。
+/// 上次退出时引擎仍在运行的话，遗留的过滤器也在这里清理
+Future<void> _initMagicWallStore() async {
+  try {
+    await initMagicWallStore(dir: AppDatabase().dataDir);
+    FileLogger().info('Magic Wall store initialized');
+  } catch (e) {
+    // 规则文件损坏时不启用持久化，应用照常启动
+    FileLogger().warning('魔法墙规则载入失败: $e');
+  }
+}
+
 Future<void> _initAppLinks() async {
   try {
     final registry = AppLinkRegistry();
@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'p2p.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `append`, `instance_id_of`, `log_file`, `logged_instances`, `prune_instances`, `rotate`, `run_writer`, `write_record`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FILES`, `LOG_DIR`, `WRITER`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `deref`, `deref`, `initialize`, `initialize`, `initialize`
+
+/// Enables event persistence under `dir`, usually the app support directory.
+Future<void> initEventLog({required String dir}) =>
+    RustLib.instance.api.crateApiEventLogInitEventLog(dir: dir);
+
+/// Lists instance ids that have recorded history, most recently active first.
+Future<List<String>> listLoggedInstances() =>
+    RustLib.instance.api.crateApiEventLogListLoggedInstances();
+
+/// Queries the recorded events of an instance, oldest first.
+///
+/// `since` is a Unix timestamp in milliseconds (inclusive), `kinds` filters by
+/// event kind such as `"PeerRemoved"` or `"ConnectError"`, and `limit` keeps only
+/// the most recent matches.
+Future<List<InstanceEventRecord>> queryInstanceEvents({
+  required String instanceId,
+  PlatformInt64? since,
+  List<String>? kinds,
+  BigInt? limit,
+}) => RustLib.instance.api.crateApiEventLogQueryInstanceEvents(
+  instanceId: instanceId,
+  since: since,
+  kinds: kinds,
+  limit: limit,
+);
@@ -6,14 +6,14 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `handle_connection`
+// These functions are ignored because they are not marked as `pub`: `handle_connection`, `open_connection`, `start_forward_server`, `start_tcp`, `start_udp`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FORWARD_SERVERS`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `deref`, `deref`, `initialize`, `initialize`
-// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `new`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `default`, `deref`, `deref`, `drop`, `eq`, `fmt`, `fmt`, `initialize`, `initialize`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `as_str`, `info`, `kill_connection`, `list_connections`, `local_addr`, `new`, `parse`, `stop`, `with_protocol`, `with_udp_idle_timeout`
 
 /// 创建并启动一个端口转发服务器
-/// 返回服务器索引，用于后续操作
-Future<BigInt> createForwardServer({
+/// 返回服务器句柄，用于后续操作
+Future<String> createForwardServer({
   required String listenAddr,
   required String forwardAddr,
 }) => RustLib.instance.api.crateApiForwardCreateForwardServer(
@@ -21,26 +21,71 @@ Future<BigInt> createForwardServer({
   forwardAddr: forwardAddr,
 );
 
-/// 停止指定索引的端口转发服务器
-Future<void> stopForwardServer({required BigInt index}) =>
-    RustLib.instance.api.crateApiForwardStopForwardServer(index: index);
+/// 创建并启动一个指定协议的端口转发服务器
+/// protocol 取值为 "tcp" 或 "udp"，返回服务器句柄
+Future<String> createForwardServerWithProtocol({
+  required String listenAddr,
+  required String forwardAddr,
+  required String protocol,
+}) => RustLib.instance.api.crateApiForwardCreateForwardServerWithProtocol(
+  listenAddr: listenAddr,
+  forwardAddr: forwardAddr,
+  protocol: protocol,
+);
+
+/// 创建并启动一个 UDP 端口转发服务器，超过 `idle_timeout_secs` 秒没有收发数据的会话会被关闭，
+/// 返回服务器句柄
+Future<String> createUdpForwardServer({
+  required String listenAddr,
+  required String forwardAddr,
+  required BigInt idleTimeoutSecs,
+}) => RustLib.instance.api.crateApiForwardCreateUdpForwardServer(
+  listenAddr: listenAddr,
+  forwardAddr: forwardAddr,
+  idleTimeoutSecs: idleTimeoutSecs,
+);
+
+/// 停止指定的端口转发服务器，记录保留以便查询
+Future<void> stopForwardServer({required String id}) =>
+    RustLib.instance.api.crateApiForwardStopForwardServer(id: id);
 
-/// 停止所有端口转发服务器
+/// 停止并移除指定的端口转发服务器
+Future<void> removeForwardServer({required String id}) =>
+    RustLib.instance.api.crateApiForwardRemoveForwardServer(id: id);
+
+/// 停止并移除所有端口转发服务器
 Future<void> stopAllForwardServers() =>
     RustLib.instance.api.crateApiForwardStopAllForwardServers();
 
 /// 获取指定服务器的统计信息
-Future<(BigInt, BigInt, BigInt)> getForwardServerStats({
-  required BigInt index,
-}) => RustLib.instance.api.crateApiForwardGetForwardServerStats(index: index);
+Future<(BigInt, BigInt, BigInt)> getForwardServerStats({required String id}) =>
+    RustLib.instance.api.crateApiForwardGetForwardServerStats(id: id);
+
+/// 列出所有端口转发服务器及其状态
+Future<List<ForwardServerInfo>> listForwardServers() =>
+    RustLib.instance.api.crateApiForwardListForwardServers();
+
+/// 列出指定服务器的活跃连接和最近关闭的连接
+Future<List<ForwardConnectionInfo>> listForwardConnections({
+  required String id,
+}) => RustLib.instance.api.crateApiForwardListForwardConnections(id: id);
+
+/// 强制断开指定服务器上的一条连接
+Future<void> killForwardConnection({
+  required String id,
+  required BigInt connectionId,
+}) => RustLib.instance.api.crateApiForwardKillForwardConnection(
+  id: id,
+  connectionId: connectionId,
+);
 
-/// 获取所有正在运行的服务器数量
+/// 获取已注册的服务器数量
 Future<BigInt> getForwardServerCount() =>
     RustLib.instance.api.crateApiForwardGetForwardServerCount();
 
 /// 检查指定服务器是否正在运行
-Future<bool> isForwardServerRunning({required BigInt index}) =>
-    RustLib.instance.api.crateApiForwardIsForwardServerRunning(index: index);
+Future<bool> isForwardServerRunning({required String id}) =>
+    RustLib.instance.api.crateApiForwardIsForwardServerRunning(id: id);
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>>
 abstract class ForwardServer implements RustOpaqueInterface {
@@ -67,3 +112,99 @@ abstract class ServerStats implements RustOpaqueInterface {
   static Future<ServerStats> newInstance() =>
       RustLib.instance.api.crateApiForwardServerStatsNew();
 }
+
+/// 单条代理连接的记录
+class ForwardConnectionInfo {
+  final BigInt id;
+  final String peerAddr;
+
+  /// 建立时间（Unix 毫秒时间戳）
+  final PlatformInt64 startedAt;
+
+  /// 关闭时间，连接仍活跃时为 None
+  final PlatformInt64? closedAt;
+  final BigInt bytesSent;
+  final BigInt bytesReceived;
+  final String? closeReason;
+
+  const ForwardConnectionInfo({
+    required this.id,
+    required this.peerAddr,
+    required this.startedAt,
+    this.closedAt,
+    required this.bytesSent,
+    required this.bytesReceived,
+    this.closeReason,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      peerAddr.hashCode ^
+      startedAt.hashCode ^
+      closedAt.hashCode ^
+      bytesSent.hashCode ^
+      bytesReceived.hashCode ^
+      closeReason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ForwardConnectionInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          peerAddr == other.peerAddr &&
+          startedAt == other.startedAt &&
+          closedAt == other.closedAt &&
+          bytesSent == other.bytesSent &&
+          bytesReceived == other.bytesReceived &&
+          closeReason == other.closeReason;
+}
+
+/// 端口转发服务器的状态快照，供 Flutter 在热重启后重建界面
+class ForwardServerInfo {
+  final String id;
+  final String listenAddr;
+  final String forwardAddr;
+  final String protocol;
+  final bool isRunning;
+  final BigInt connections;
+  final BigInt bytesSent;
+  final BigInt bytesReceived;
+
+  const ForwardServerInfo({
+    required this.id,
+    required this.listenAddr,
+    required this.forwardAddr,
+    required this.protocol,
+    required this.isRunning,
+    required this.connections,
+    required this.bytesSent,
+    required this.bytesReceived,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      listenAddr.hashCode ^
+      forwardAddr.hashCode ^
+      protocol.hashCode ^
+      isRunning.hashCode ^
+      connections.hashCode ^
+      bytesSent.hashCode ^
+      bytesReceived.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ForwardServerInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          listenAddr == other.listenAddr &&
+          forwardAddr == other.forwardAddr &&
+          protocol == other.protocol &&
+          isRunning == other.isRunning &&
+          connections == other.connections &&
+          bytesSent == other.bytesSent &&
+          bytesReceived == other.bytesReceived;
+}
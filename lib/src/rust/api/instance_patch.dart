@@ -0,0 +1,113 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+// These functions are ignored because they are not marked as `pub`: `config_changes`, `from_proto`, `parse_patch`, `patch_list`, `patch_live`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `eq`, `fmt`, `from`
+
+/// Applies `patch` to a running instance.
+///
+/// Nothing is applied if any entry is invalid. Peers, port forwards and proxy
+/// CIDRs are patched live first; success means EasyTier accepted them, and the
+/// matching `ConfigPatched` event confirms they took effect. Whitelist entries
+/// can't be patched live: when they change a whitelist, the instance is then
+/// restarted under the same id from its updated config, which drops its
+/// connections for a moment, and a `ConfigPatched` listing the whitelist
+/// entries follows the restart.
+Future<void> patchInstance({
+  required String instanceId,
+  required InstancePatch patch,
+}) => RustLib.instance.api.crateApiInstancePatchPatchInstance(
+  instanceId: instanceId,
+  patch: patch,
+);
+
+class ForwardPatch {
+  final PatchAction action;
+  final Forward forward;
+
+  const ForwardPatch({required this.action, required this.forward});
+
+  @override
+  int get hashCode => action.hashCode ^ forward.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ForwardPatch &&
+          runtimeType == other.runtimeType &&
+          action == other.action &&
+          forward == other.forward;
+}
+
+/// Changes to apply to a running instance. Entries are applied in order,
+/// and removing an entry that isn't configured is not an error.
+class InstancePatch {
+  /// Server/peer URLs, e.g. `tcp://public.easytier.top:11010`.
+  final List<ValuePatch> peers;
+  final List<ForwardPatch> portForwards;
+
+  /// Proxy CIDRs, e.g. `192.168.1.0/24`.
+  final List<ValuePatch> proxyCidrs;
+
+  /// Ports or port ranges as in `FlagsC::tcp_whitelist`. EasyTier can't
+  /// change them live, so a change restarts the instance, see `patch_instance`.
+  final List<ValuePatch> tcpWhitelist;
+  final List<ValuePatch> udpWhitelist;
+
+  const InstancePatch({
+    required this.peers,
+    required this.portForwards,
+    required this.proxyCidrs,
+    required this.tcpWhitelist,
+    required this.udpWhitelist,
+  });
+
+  @override
+  int get hashCode =>
+      peers.hashCode ^
+      portForwards.hashCode ^
+      proxyCidrs.hashCode ^
+      tcpWhitelist.hashCode ^
+      udpWhitelist.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InstancePatch &&
+          runtimeType == other.runtimeType &&
+          peers == other.peers &&
+          portForwards == other.portForwards &&
+          proxyCidrs == other.proxyCidrs &&
+          tcpWhitelist == other.tcpWhitelist &&
+          udpWhitelist == other.udpWhitelist;
+}
+
+/// What to do with a patch entry.
+enum PatchAction {
+  add,
+  remove,
+}
+
+class ValuePatch {
+  final PatchAction action;
+  final String value;
+
+  const ValuePatch({required this.action, required this.value});
+
+  @override
+  int get hashCode => action.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ValuePatch &&
+          runtimeType == other.runtimeType &&
+          action == other.action &&
+          value == other.value;
+}
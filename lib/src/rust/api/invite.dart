@@ -0,0 +1,150 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+// These functions are ignored because they are not marked as `pub`: `sign`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `default`, `fmt`, `fmt`
+
+/// Encodes an invite into an `astral://` link, signed when `secret` is given.
+Future<String> encodeRoomInvite({required RoomInvite invite, String? secret}) =>
+    RustLib.instance.api.crateApiInviteEncodeRoomInvite(
+      invite: invite,
+      secret: secret,
+    );
+
+/// Decodes and checks an invite link.
+///
+/// When `secret` is given the link must carry a valid signature; unsigned or
+/// tampered links are rejected. Without a secret the link decodes with
+/// `verified` set to false, signed or not. Expired invites are always rejected.
+Future<RoomInvite> decodeRoomInvite({required String link, String? secret}) =>
+    RustLib.instance.api.crateApiInviteDecodeRoomInvite(
+      link: link,
+      secret: secret,
+    );
+
+/// Picks the options an invite carries from a full set of flags.
+Future<InviteFlags> inviteFlagsFrom({required FlagsC flag}) =>
+    RustLib.instance.api.crateApiInviteInviteFlagsFrom(flag: flag);
+
+/// Overlays the options set in an invite on the joiner's own flags.
+Future<FlagsC> applyInviteFlags({
+  required InviteFlags inviteFlags,
+  required FlagsC flag,
+}) => RustLib.instance.api.crateApiInviteApplyInviteFlags(
+  inviteFlags: inviteFlags,
+  flag: flag,
+);
+
+/// The subset of `FlagsC` carried by an invite. Unset options keep the
+/// joiner's own setting.
+class InviteFlags {
+  final String? defaultProtocol;
+  final bool? enableEncryption;
+  final bool? enableIpv6;
+  final int? mtu;
+  final bool? latencyFirst;
+  final bool? disableP2P;
+  final bool? enableUdpBroadcastRelay;
+  final int? dataCompressAlgo;
+  final bool? enableKcpProxy;
+  final bool? enableQuicProxy;
+
+  const InviteFlags({
+    this.defaultProtocol,
+    this.enableEncryption,
+    this.enableIpv6,
+    this.mtu,
+    this.latencyFirst,
+    this.disableP2P,
+    this.enableUdpBroadcastRelay,
+    this.dataCompressAlgo,
+    this.enableKcpProxy,
+    this.enableQuicProxy,
+  });
+
+  @override
+  int get hashCode =>
+      defaultProtocol.hashCode ^
+      enableEncryption.hashCode ^
+      enableIpv6.hashCode ^
+      mtu.hashCode ^
+      latencyFirst.hashCode ^
+      disableP2P.hashCode ^
+      enableUdpBroadcastRelay.hashCode ^
+      dataCompressAlgo.hashCode ^
+      enableKcpProxy.hashCode ^
+      enableQuicProxy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InviteFlags &&
+          runtimeType == other.runtimeType &&
+          defaultProtocol == other.defaultProtocol &&
+          enableEncryption == other.enableEncryption &&
+          enableIpv6 == other.enableIpv6 &&
+          mtu == other.mtu &&
+          latencyFirst == other.latencyFirst &&
+          disableP2P == other.disableP2P &&
+          enableUdpBroadcastRelay == other.enableUdpBroadcastRelay &&
+          dataCompressAlgo == other.dataCompressAlgo &&
+          enableKcpProxy == other.enableKcpProxy &&
+          enableQuicProxy == other.enableQuicProxy;
+}
+
+/// A room definition that can be shared as a link.
+class RoomInvite {
+  final String roomName;
+  final String roomPassword;
+  final List<String> servers;
+
+  /// Fixed virtual IPv4 range in CIDR notation; `None` means DHCP.
+  final String? ipRange;
+  final InviteFlags flags;
+
+  /// Unix timestamp in seconds after which the invite is rejected.
+  final PlatformInt64? expiresAt;
+
+  /// Whether the link carried a signature that matched the secret. Only set by
+  /// `decode_room_invite`; never part of the encoded link.
+  final bool verified;
+
+  const RoomInvite({
+    required this.roomName,
+    required this.roomPassword,
+    required this.servers,
+    this.ipRange,
+    required this.flags,
+    this.expiresAt,
+    required this.verified,
+  });
+
+  @override
+  int get hashCode =>
+      roomName.hashCode ^
+      roomPassword.hashCode ^
+      servers.hashCode ^
+      ipRange.hashCode ^
+      flags.hashCode ^
+      expiresAt.hashCode ^
+      verified.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomInvite &&
+          runtimeType == other.runtimeType &&
+          roomName == other.roomName &&
+          roomPassword == other.roomPassword &&
+          servers == other.servers &&
+          ipRange == other.ipRange &&
+          flags == other.flags &&
+          expiresAt == other.expiresAt &&
+          verified == other.verified;
+}
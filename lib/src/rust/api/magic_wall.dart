@@ -6,9 +6,29 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `apply_rule`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FILTER_TRACKER`, `FIREWALL`, `RULE_STORE`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `deref`, `deref`, `deref`, `fmt`, `fmt`, `initialize`, `initialize`, `initialize`
+// These functions are ignored because they are not marked as `pub`: `apply_rule`, `decode_rules`, `encode_rules`, `mark_running`, `persist_rules`, `save_rules`, `sorted_rules`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FIREWALL`, `RULE_FILE`, `RULE_STORE`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`, `initialize`
+
+/// 启用规则持久化并载入已保存的规则，`dir` 通常是应用数据目录。
+/// 魔法墙运行中时，载入的已启用规则会立即应用。
+/// 上次退出时引擎仍在运行的话，先清理它遗留的过滤器。
+Future<void> initMagicWallStore({required String dir}) =>
+    RustLib.instance.api.crateApiMagicWallInitMagicWallStore(dir: dir);
+
+/// 导出全部规则为带版本号的 JSON，可在其他设备上用 `import_magic_wall_rules` 导入
+Future<String> exportMagicWallRules() =>
+    RustLib.instance.api.crateApiMagicWallExportMagicWallRules();
+
+/// 导入 `export_magic_wall_rules` 导出的规则，返回导入的规则数。
+/// `replace` 为 true 时替换全部现有规则，否则合并，ID 相同的规则会被更新。
+Future<BigInt> importMagicWallRules({
+  required String json,
+  required bool replace,
+}) => RustLib.instance.api.crateApiMagicWallImportMagicWallRules(
+  json: json,
+  replace: replace,
+);
 
 /// 启动魔法墙引擎
 Future<void> startMagicWall() =>
@@ -30,14 +50,82 @@ Future<void> removeMagicWallRule({required String ruleId}) =>
 Future<void> updateMagicWallRule({required MagicWallRule rule}) =>
     RustLib.instance.api.crateApiMagicWallUpdateMagicWallRule(rule: rule);
 
+/// 清理魔法墙遗留的全部过滤器，用于异常退出后的恢复，返回清理的过滤器数。
+/// 魔法墙运行中时会先停止引擎，规则保留。
+Future<BigInt> purgeMagicWallFilters() =>
+    RustLib.instance.api.crateApiMagicWallPurgeMagicWallFilters();
+
 /// 获取魔法墙状态
 Future<MagicWallStatus> getMagicWallStatus() =>
     RustLib.instance.api.crateApiMagicWallGetMagicWallStatus();
 
+/// 模拟规则集对一个新连接的判定，不会修改系统防火墙，所有平台可用。
+/// 只有启用的规则参与匹配，优先级高的先匹配，同优先级时阻止规则优先，再按列表顺序，
+/// 第一条命中的规则生效，没有命中时放行。
+/// 只模拟魔法墙自身的规则，Windows 上其他子层（如 Windows 防火墙）的阻止规则仍会生效，
+/// 见 `SimFirewall`。
+Future<MagicWallVerdict> simulateMagicWall({
+  required List<MagicWallRule> rules,
+  required MagicWallPacket packet,
+}) => RustLib.instance.api.crateApiMagicWallSimulateMagicWall(
+  rules: rules,
+  packet: packet,
+);
+
+/// 在应用前分析规则集，报告被遮蔽、冗余和冲突的规则，不会修改系统防火墙。
+/// 只分析启用的规则，匹配顺序与 `simulate_magic_wall` 相同。
+Future<List<MagicWallRuleIssue>> analyzeMagicWallRules({
+  required List<MagicWallRule> rules,
+}) => RustLib.instance.api.crateApiMagicWallAnalyzeMagicWallRules(rules: rules);
+
 /// 创建默认规则示例
 Future<List<MagicWallRule>> createDefaultMagicWallRules() =>
     RustLib.instance.api.crateApiMagicWallCreateDefaultMagicWallRules();
 
+/// 用于模拟判定的连接
+class MagicWallPacket {
+  final String protocol;
+  final String direction;
+  final String localIp;
+  final String remoteIp;
+  final int? localPort;
+  final int? remotePort;
+  final String? appPath;
+
+  const MagicWallPacket({
+    required this.protocol,
+    required this.direction,
+    required this.localIp,
+    required this.remoteIp,
+    this.localPort,
+    this.remotePort,
+    this.appPath,
+  });
+
+  @override
+  int get hashCode =>
+      protocol.hashCode ^
+      direction.hashCode ^
+      localIp.hashCode ^
+      remoteIp.hashCode ^
+      localPort.hashCode ^
+      remotePort.hashCode ^
+      appPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MagicWallPacket &&
+          runtimeType == other.runtimeType &&
+          protocol == other.protocol &&
+          direction == other.direction &&
+          localIp == other.localIp &&
+          remoteIp == other.remoteIp &&
+          localPort == other.localPort &&
+          remotePort == other.remotePort &&
+          appPath == other.appPath;
+}
+
 /// 魔法墙规则配置
 class MagicWallRule {
   final String id;
@@ -51,6 +139,7 @@ class MagicWallRule {
   final String? localIp;
   final String? remotePort;
   final String? localPort;
+  final int priority;
   final String? description;
   final PlatformInt64? createdAt;
 
@@ -66,6 +155,7 @@ class MagicWallRule {
     this.localIp,
     this.remotePort,
     this.localPort,
+    required this.priority,
     this.description,
     this.createdAt,
   });
@@ -83,6 +173,7 @@ class MagicWallRule {
       localIp.hashCode ^
       remotePort.hashCode ^
       localPort.hashCode ^
+      priority.hashCode ^
       description.hashCode ^
       createdAt.hashCode;
 
@@ -102,10 +193,50 @@ class MagicWallRule {
           localIp == other.localIp &&
           remotePort == other.remotePort &&
           localPort == other.localPort &&
+          priority == other.priority &&
           description == other.description &&
           createdAt == other.createdAt;
 }
 
+/// 规则分析结果
+class MagicWallRuleIssue {
+  final MagicWallRuleIssueKind kind;
+  final String ruleId;
+  final String otherRuleId;
+  final String message;
+
+  const MagicWallRuleIssue({
+    required this.kind,
+    required this.ruleId,
+    required this.otherRuleId,
+    required this.message,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ ruleId.hashCode ^ otherRuleId.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MagicWallRuleIssue &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          ruleId == other.ruleId &&
+          otherRuleId == other.otherRuleId &&
+          message == other.message;
+}
+
+/// 规则分析发现的问题类型
+enum MagicWallRuleIssueKind {
+  /// 被匹配顺序更靠前、动作相反的规则完全覆盖，永远不会生效
+  shadowed,
+  /// 被动作相同的规则完全覆盖，删除后结果不变
+  redundant,
+  /// 与同优先级、动作相反的规则部分重叠，重叠部分按阻止处理
+  conflicting,
+}
+
 /// 魔法墙状态
 class MagicWallStatus {
   final bool isRunning;
@@ -131,3 +262,22 @@ class MagicWallStatus {
           activeRules == other.activeRules &&
           totalRules == other.totalRules;
 }
+
+/// 模拟判定结果
+class MagicWallVerdict {
+  final String action;
+  final String? matchedRuleId;
+
+  const MagicWallVerdict({required this.action, this.matchedRuleId});
+
+  @override
+  int get hashCode => action.hashCode ^ matchedRuleId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MagicWallVerdict &&
+          runtimeType == other.runtimeType &&
+          action == other.action &&
+          matchedRuleId == other.matchedRuleId;
+}
@@ -0,0 +1,117 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `between`, `bind_lan_listener`, `parse_virtual_net`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LAN_BRIDGES`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `initialize`, `initialize`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `new`, `stop`, `with_lan_bind_addr`, `with_motd_prefix`, `worlds`
+
+/// 解析 Minecraft 局域网广播内容
+Future<MinecraftLanAnnouncement?> parseMinecraftLanAnnouncement({
+  required List<int> data,
+}) => RustLib.instance.api.crateApiMinecraftLanParseMinecraftLanAnnouncement(
+  data: data,
+);
+
+/// 按 Minecraft 局域网广播格式组装数据
+Future<Uint8List> encodeMinecraftLanAnnouncement({
+  required String motd,
+  required int port,
+}) => RustLib.instance.api.crateApiMinecraftLanEncodeMinecraftLanAnnouncement(
+  motd: motd,
+  port: port,
+);
+
+/// 启动 Minecraft 局域网桥接
+/// virtual_ip 为本机虚拟网 IP，可带前缀长度（如 10.126.126.1/24），缺省时按 /24 处理；
+/// lan_bind_addr 为物理局域网网卡地址（为空时使用默认路由）
+/// 返回桥接句柄
+Future<String> startMinecraftLanBridge({
+  required String virtualIp,
+  String? lanBindAddr,
+}) => RustLib.instance.api.crateApiMinecraftLanStartMinecraftLanBridge(
+  virtualIp: virtualIp,
+  lanBindAddr: lanBindAddr,
+);
+
+/// 停止并移除指定的局域网桥接，同时关闭其创建的所有转发和广播
+Future<void> stopMinecraftLanBridge({required String id}) =>
+    RustLib.instance.api.crateApiMinecraftLanStopMinecraftLanBridge(id: id);
+
+/// 列出指定桥接当前转发的局域网世界
+Future<List<MinecraftLanWorld>> listMinecraftLanWorlds({required String id}) =>
+    RustLib.instance.api.crateApiMinecraftLanListMinecraftLanWorlds(id: id);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>>
+abstract class MinecraftLanBridge implements RustOpaqueInterface {
+  Future<bool> isRunning();
+
+  Future<void> start();
+
+  Future<void> stop();
+}
+
+/// 一条局域网世界广播
+class MinecraftLanAnnouncement {
+  final String motd;
+  final int port;
+
+  const MinecraftLanAnnouncement({required this.motd, required this.port});
+
+  @override
+  int get hashCode => motd.hashCode ^ port.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MinecraftLanAnnouncement &&
+          runtimeType == other.runtimeType &&
+          motd == other.motd &&
+          port == other.port;
+}
+
+/// 桥接中的局域网世界
+class MinecraftLanWorld {
+  /// 远端主机的虚拟网 IP
+  final String host;
+  final int remotePort;
+
+  /// 本机转发端口，即在局域网上重新广播的端口
+  final int localPort;
+  final String motd;
+
+  /// 最近一次收到广播的时间（Unix 毫秒时间戳）
+  final PlatformInt64 lastSeen;
+
+  const MinecraftLanWorld({
+    required this.host,
+    required this.remotePort,
+    required this.localPort,
+    required this.motd,
+    required this.lastSeen,
+  });
+
+  @override
+  int get hashCode =>
+      host.hashCode ^
+      remotePort.hashCode ^
+      localPort.hashCode ^
+      motd.hashCode ^
+      lastSeen.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MinecraftLanWorld &&
+          runtimeType == other.runtimeType &&
+          host == other.host &&
+          remotePort == other.remotePort &&
+          localPort == other.localPort &&
+          motd == other.motd &&
+          lastSeen == other.lastSeen;
+}
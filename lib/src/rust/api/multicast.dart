@@ -6,13 +6,14 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MULTICAST_SENDERS`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `deref`, `initialize`, `initialize`
-// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `new`, `with_bind_addr`
+// These functions are ignored because they are not marked as `pub`: `bind`, `ipv6_interface_index`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MULTICAST_LISTENERS`, `MULTICAST_SENDERS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `deref`, `deref`, `deref`, `fmt`, `initialize`, `initialize`, `initialize`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `info`, `new`, `recent_packets`, `set_sink`, `stop`, `with_bind_addr`, `with_capacity`, `with_interface_addr`
 
 /// 创建并启动一个组播发送器
-/// 返回发送器索引，用于后续操作
-Future<BigInt> createMulticastSender({
+/// 返回发送器句柄，用于后续操作
+Future<String> createMulticastSender({
   required String multicastAddr,
   required int port,
   required List<int> data,
@@ -25,7 +26,7 @@ Future<BigInt> createMulticastSender({
 );
 
 /// 创建并启动一个组播发送器（带自定义绑定地址）
-Future<BigInt> createMulticastSenderWithBind({
+Future<String> createMulticastSenderWithBind({
   required String multicastAddr,
   required int port,
   required String bindAddr,
@@ -39,23 +40,80 @@ Future<BigInt> createMulticastSenderWithBind({
   intervalMs: intervalMs,
 );
 
-/// 停止指定索引的组播发送器
-Future<void> stopMulticastSender({required BigInt index}) =>
-    RustLib.instance.api.crateApiMulticastStopMulticastSender(index: index);
+/// 停止指定的组播发送器，记录保留以便查询
+Future<void> stopMulticastSender({required String id}) =>
+    RustLib.instance.api.crateApiMulticastStopMulticastSender(id: id);
 
-/// 停止所有组播发送器
+/// 停止并移除指定的组播发送器
+Future<void> removeMulticastSender({required String id}) =>
+    RustLib.instance.api.crateApiMulticastRemoveMulticastSender(id: id);
+
+/// 停止并移除所有组播发送器
 Future<void> stopAllMulticastSenders() =>
     RustLib.instance.api.crateApiMulticastStopAllMulticastSenders();
 
-/// 获取所有正在运行的组播发送器数量
+/// 列出所有组播发送器及其状态
+Future<List<MulticastSenderInfo>> listMulticastSenders() =>
+    RustLib.instance.api.crateApiMulticastListMulticastSenders();
+
+/// 获取已注册的组播发送器数量
 Future<BigInt> getMulticastSenderCount() =>
     RustLib.instance.api.crateApiMulticastGetMulticastSenderCount();
 
 /// 检查指定发送器是否正在运行
-Future<bool> isMulticastSenderRunning({required BigInt index}) => RustLib
-    .instance
-    .api
-    .crateApiMulticastIsMulticastSenderRunning(index: index);
+Future<bool> isMulticastSenderRunning({required String id}) =>
+    RustLib.instance.api.crateApiMulticastIsMulticastSenderRunning(id: id);
+
+/// 创建并启动一个组播/广播监听器
+/// group_addr 可以是组播地址，也可以是 255.255.255.255 或 0.0.0.0 以监听广播；
+/// interface_addr 为加入组播组所用的网卡地址，为空时由系统选择
+/// 返回监听器句柄，收到的数据包可通过 subscribe_multicast_listener 订阅
+Future<String> createMulticastListener({
+  required String groupAddr,
+  required int port,
+  String? interfaceAddr,
+  BigInt? capacity,
+}) => RustLib.instance.api.crateApiMulticastCreateMulticastListener(
+  groupAddr: groupAddr,
+  port: port,
+  interfaceAddr: interfaceAddr,
+  capacity: capacity,
+);
+
+/// 订阅监听器收到的数据包，新的订阅会替换旧的订阅
+Stream<MulticastPacket> subscribeMulticastListener({required String id}) =>
+    RustLib.instance.api.crateApiMulticastSubscribeMulticastListener(id: id);
+
+/// 获取监听器缓冲区中最近的数据包
+Future<List<MulticastPacket>> getMulticastListenerPackets({
+  required String id,
+  BigInt? limit,
+}) => RustLib.instance.api.crateApiMulticastGetMulticastListenerPackets(
+  id: id,
+  limit: limit,
+);
+
+/// 停止指定的组播监听器，缓冲区保留以便查询
+Future<void> stopMulticastListener({required String id}) =>
+    RustLib.instance.api.crateApiMulticastStopMulticastListener(id: id);
+
+/// 停止并移除指定的组播监听器
+Future<void> removeMulticastListener({required String id}) =>
+    RustLib.instance.api.crateApiMulticastRemoveMulticastListener(id: id);
+
+/// 列出所有组播监听器及其状态
+Future<List<MulticastListenerInfo>> listMulticastListeners() =>
+    RustLib.instance.api.crateApiMulticastListMulticastListeners();
+
+/// 组播/广播监听器，用于排查局域网发现报文是否穿过了虚拟网络
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>>
+abstract class MulticastListener implements RustOpaqueInterface {
+  Future<bool> isRunning();
+
+  Future<void> start();
+
+  Future<void> stop();
+}
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>>
 abstract class MulticastSender implements RustOpaqueInterface {
@@ -65,3 +123,115 @@ abstract class MulticastSender implements RustOpaqueInterface {
 
   Future<void> stop();
 }
+
+/// 组播监听器的状态快照
+class MulticastListenerInfo {
+  final String id;
+  final String groupAddr;
+  final String interfaceAddr;
+  final BigInt capacity;
+  final BigInt packetsReceived;
+  final bool isRunning;
+
+  const MulticastListenerInfo({
+    required this.id,
+    required this.groupAddr,
+    required this.interfaceAddr,
+    required this.capacity,
+    required this.packetsReceived,
+    required this.isRunning,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      groupAddr.hashCode ^
+      interfaceAddr.hashCode ^
+      capacity.hashCode ^
+      packetsReceived.hashCode ^
+      isRunning.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MulticastListenerInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          groupAddr == other.groupAddr &&
+          interfaceAddr == other.interfaceAddr &&
+          capacity == other.capacity &&
+          packetsReceived == other.packetsReceived &&
+          isRunning == other.isRunning;
+}
+
+/// 监听器收到的一个数据包
+class MulticastPacket {
+  /// 发送方地址
+  final String source;
+  final BigInt size;
+
+  /// 接收时间（Unix 毫秒时间戳）
+  final PlatformInt64 timestamp;
+  final Uint8List data;
+
+  const MulticastPacket({
+    required this.source,
+    required this.size,
+    required this.timestamp,
+    required this.data,
+  });
+
+  @override
+  int get hashCode =>
+      source.hashCode ^ size.hashCode ^ timestamp.hashCode ^ data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MulticastPacket &&
+          runtimeType == other.runtimeType &&
+          source == other.source &&
+          size == other.size &&
+          timestamp == other.timestamp &&
+          data == other.data;
+}
+
+/// 组播发送器的状态快照
+class MulticastSenderInfo {
+  final String id;
+  final String multicastAddr;
+  final String bindAddr;
+  final BigInt intervalMs;
+  final BigInt dataLen;
+  final bool isRunning;
+
+  const MulticastSenderInfo({
+    required this.id,
+    required this.multicastAddr,
+    required this.bindAddr,
+    required this.intervalMs,
+    required this.dataLen,
+    required this.isRunning,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      multicastAddr.hashCode ^
+      bindAddr.hashCode ^
+      intervalMs.hashCode ^
+      dataLen.hashCode ^
+      isRunning.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MulticastSenderInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          multicastAddr == other.multicastAddr &&
+          bindAddr == other.bindAddr &&
+          intervalMs == other.intervalMs &&
+          dataLen == other.dataLen &&
+          isRunning == other.isRunning;
+}
@@ -6,8 +6,8 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `build_udp_stun_server_list`, `create_binding_request`, `detect_nat_type_ipv4`, `is_success_stun_response`, `map_easy_nat_type`, `normalize_stun_server`, `test_udp_ipv4`, `test_udp_ipv6`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `build_udp_stun_server_list`, `classic_nat_type`, `detect_nat_type_ipv4`, `fail`, `first_endpoint`, `hole_punch_matrix`, `local_ip_towards`, `map_easy_nat_type`, `nat_behavior`, `new`, `normalize_stun_server`, `predict_hole_punch`, `punch_class`, `resolve_stun_server`, `tcp_exchange`, `tcp_hairpinning`, `tcp_nat_behavior`, `tcp_socket`, `test_stun_server`, `test_stun_servers`, `test_udp_ipv4`, `test_udp_ipv6`, `udp_exchange`, `udp_filtering`, `udp_hairpinning`, `udp_nat_behavior`, `unspecified_addr`, `xor_address`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// NAT test entry for Flutter.
 Future<NetworkTestResult> testNetworkConnectivity({
@@ -20,6 +20,123 @@ Future<NetworkTestResult> testNetworkConnectivity({
 Future<String> detectNatType({required String stunServer}) =>
     RustLib.instance.api.crateApiNatTestDetectNatType(stunServer: stunServer);
 
+/// RFC 5780 NAT behavior discovery entry for Flutter. Returns results for
+/// UDP/IPv4, UDP/IPv6, TCP/IPv4 and TCP/IPv6, in that order.
+Future<List<NatBehaviorResult>> testNatBehavior({required String stunServer}) =>
+    RustLib.instance.api.crateApiNatTestTestNatBehavior(stunServer: stunServer);
+
+/// Which inbound packets the NAT lets through to a mapping (RFC 5780 §4.4).
+enum FilteringBehavior {
+  endpointIndependent,
+  addressDependent,
+  addressAndPortDependent,
+  unknown,
+}
+
+enum HolePunchChance {
+  likely,
+  /// Needs port prediction and may take several attempts.
+  possible,
+  /// Traffic will most likely go through a relay.
+  unlikely,
+}
+
+class HolePunchPrediction {
+  /// EasyTier NAT type of the peer, e.g. `PortRestricted`.
+  final String peerNatType;
+  final HolePunchChance chance;
+
+  const HolePunchPrediction({required this.peerNatType, required this.chance});
+
+  @override
+  int get hashCode => peerNatType.hashCode ^ chance.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HolePunchPrediction &&
+          runtimeType == other.runtimeType &&
+          peerNatType == other.peerNatType &&
+          chance == other.chance;
+}
+
+/// How the NAT picks the public endpoint of an outgoing flow (RFC 5780 §4.3).
+enum MappingBehavior {
+  /// The mapped address is the local address.
+  noNat,
+  endpointIndependent,
+  addressDependent,
+  addressAndPortDependent,
+  unknown,
+}
+
+/// RFC 5780 behavior of one protocol and address family.
+///
+/// Mapping and filtering need a server that reports `OTHER-ADDRESS`; against
+/// other servers they stay `Unknown` but the mapped address is still known.
+/// Filtering is never tested over TCP, where `CHANGE-REQUEST` isn't defined.
+class NatBehaviorResult {
+  /// `udp` or `tcp`.
+  final String protocol;
+
+  /// `ipv4` or `ipv6`.
+  final String ipVersion;
+  final String? server;
+  final String? localAddr;
+  final String? mappedAddr;
+  final PlatformInt64? rttMs;
+  final MappingBehavior mapping;
+  final FilteringBehavior filtering;
+  final bool? hairpinning;
+  final bool? portPreserved;
+  final String? error;
+
+  const NatBehaviorResult({
+    required this.protocol,
+    required this.ipVersion,
+    this.server,
+    this.localAddr,
+    this.mappedAddr,
+    this.rttMs,
+    required this.mapping,
+    required this.filtering,
+    this.hairpinning,
+    this.portPreserved,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      protocol.hashCode ^
+      ipVersion.hashCode ^
+      server.hashCode ^
+      localAddr.hashCode ^
+      mappedAddr.hashCode ^
+      rttMs.hashCode ^
+      mapping.hashCode ^
+      filtering.hashCode ^
+      hairpinning.hashCode ^
+      portPreserved.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NatBehaviorResult &&
+          runtimeType == other.runtimeType &&
+          protocol == other.protocol &&
+          ipVersion == other.ipVersion &&
+          server == other.server &&
+          localAddr == other.localAddr &&
+          mappedAddr == other.mappedAddr &&
+          rttMs == other.rttMs &&
+          mapping == other.mapping &&
+          filtering == other.filtering &&
+          hairpinning == other.hairpinning &&
+          portPreserved == other.portPreserved &&
+          error == other.error;
+}
+
 enum NatType {
   openInternet,
   fullCone,
@@ -40,11 +157,32 @@ class NetworkTestResult {
   final PlatformInt64 ipv4Latency;
   final PlatformInt64 ipv6Latency;
 
+  /// EasyTier's NAT type, e.g. `SymmetricEasyInc`; `None` if detection failed.
+  final String? natTypeV4Raw;
+  final String? errorV4;
+  final String? errorV6;
+
+  /// Public `ip:port` seen by the first STUN server that answered.
+  final String? publicEndpointV4;
+  final String? publicEndpointV6;
+  final List<StunServerResult> stunServers;
+
+  /// Expected outcome of UDP hole punching against each NAT type, based
+  /// on the local IPv4 NAT type.
+  final List<HolePunchPrediction> holePunch;
+
   const NetworkTestResult({
     required this.natTypeV4,
     required this.natTypeV6,
     required this.ipv4Latency,
     required this.ipv6Latency,
+    this.natTypeV4Raw,
+    this.errorV4,
+    this.errorV6,
+    this.publicEndpointV4,
+    this.publicEndpointV6,
+    required this.stunServers,
+    required this.holePunch,
   });
 
   @override
@@ -52,7 +190,14 @@ class NetworkTestResult {
       natTypeV4.hashCode ^
       natTypeV6.hashCode ^
       ipv4Latency.hashCode ^
-      ipv6Latency.hashCode;
+      ipv6Latency.hashCode ^
+      natTypeV4Raw.hashCode ^
+      errorV4.hashCode ^
+      errorV6.hashCode ^
+      publicEndpointV4.hashCode ^
+      publicEndpointV6.hashCode ^
+      stunServers.hashCode ^
+      holePunch.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -62,5 +207,54 @@ class NetworkTestResult {
           natTypeV4 == other.natTypeV4 &&
           natTypeV6 == other.natTypeV6 &&
           ipv4Latency == other.ipv4Latency &&
-          ipv6Latency == other.ipv6Latency;
+          ipv6Latency == other.ipv6Latency &&
+          natTypeV4Raw == other.natTypeV4Raw &&
+          errorV4 == other.errorV4 &&
+          errorV6 == other.errorV6 &&
+          publicEndpointV4 == other.publicEndpointV4 &&
+          publicEndpointV6 == other.publicEndpointV6 &&
+          stunServers == other.stunServers &&
+          holePunch == other.holePunch;
+}
+
+/// Outcome of a single binding request to one STUN server.
+class StunServerResult {
+  final String server;
+
+  /// `ipv4` or `ipv6`.
+  final String ipVersion;
+  final bool success;
+  final PlatformInt64 latency;
+  final String? mappedAddr;
+  final String? error;
+
+  const StunServerResult({
+    required this.server,
+    required this.ipVersion,
+    required this.success,
+    required this.latency,
+    this.mappedAddr,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      server.hashCode ^
+      ipVersion.hashCode ^
+      success.hashCode ^
+      latency.hashCode ^
+      mappedAddr.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StunServerResult &&
+          runtimeType == other.runtimeType &&
+          server == other.server &&
+          ipVersion == other.ipVersion &&
+          success == other.success &&
+          latency == other.latency &&
+          mappedAddr == other.mappedAddr &&
+          error == other.error;
 }
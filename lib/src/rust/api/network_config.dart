@@ -0,0 +1,172 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'relay_select.dart';
+import 'simple.dart';
+
+// These functions are ignored because they are not marked as `pub`: `build_config`, `issues_to_string`, `parse_forward`, `parse_port_list`, `parse_specified_ip`, `strip_instance_keys`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `default`, `default`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Checks a network config without starting anything. An empty list means the
+/// config can be passed to `create_server_with_config` as is.
+Future<List<ConfigIssue>> validateNetworkConfig({
+  required NetworkConfigC config,
+}) => RustLib.instance.api.crateApiNetworkConfigValidateNetworkConfig(
+  config: config,
+);
+
+/// Renders a flags-style config as EasyTier TOML, e.g. to share a room file.
+///
+/// `instance_id` and `instance_name` are left out, so every import of the file
+/// starts a new instance instead of clashing with the exporter's one.
+Future<String> flagsToToml({required NetworkConfigC config}) =>
+    RustLib.instance.api.crateApiNetworkConfigFlagsToToml(config: config);
+
+/// Reads an EasyTier TOML config back into the flags form.
+///
+/// Options that `NetworkConfigC` has no field for (mapped proxy CIDRs, extra
+/// flags, ...) are dropped; start from the TOML with `create_server` to keep them.
+/// The instance identity is dropped as well, a config built from the result
+/// gets a fresh `instance_id`.
+Future<NetworkConfigC> tomlToFlags({required String toml}) =>
+    RustLib.instance.api.crateApiNetworkConfigTomlToFlags(toml: toml);
+
+/// Knobs for behaviour that used to be hard-coded in the builder.
+class ConfigBuildOptions {
+  /// Prefix length applied to `specified_ip` when it has no `/len` suffix.
+  final int ipv4PrefixLen;
+  final InvalidCidrPolicy invalidCidrPolicy;
+
+  /// Name the TUN device after `flag.dev_name` instead of `astral`. Off by
+  /// default: the device used to be `astral` whatever the flag said, and saved
+  /// settings may still carry names that were never applied.
+  final bool customDevName;
+
+  const ConfigBuildOptions({
+    required this.ipv4PrefixLen,
+    required this.invalidCidrPolicy,
+    required this.customDevName,
+  });
+
+  @override
+  int get hashCode =>
+      ipv4PrefixLen.hashCode ^
+      invalidCidrPolicy.hashCode ^
+      customDevName.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConfigBuildOptions &&
+          runtimeType == other.runtimeType &&
+          ipv4PrefixLen == other.ipv4PrefixLen &&
+          invalidCidrPolicy == other.invalidCidrPolicy &&
+          customDevName == other.customDevName;
+}
+
+/// A single invalid input.
+class ConfigIssue {
+  /// Path of the offending field, e.g. `forwards[1].dst_addr`.
+  final String field;
+  final String value;
+  final String message;
+
+  const ConfigIssue({
+    required this.field,
+    required this.value,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ value.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConfigIssue &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          value == other.value &&
+          message == other.message;
+}
+
+/// How proxy CIDRs that fail to parse are handled.
+enum InvalidCidrPolicy {
+  /// Report the CIDR as a config issue and refuse to start.
+  reject,
+  /// Drop the CIDR with a log line and start with the remaining ones.
+  skip,
+}
+
+/// Everything `create_server_with_flags` takes plus the options it leaves at
+/// their defaults, bundled so it can be validated without starting an instance.
+class NetworkConfigC {
+  final String username;
+  final bool enableDhcp;
+
+  /// Static virtual IPv4, either `a.b.c.d` or `a.b.c.d/len`.
+  final String specifiedIp;
+  final String roomName;
+  final String roomPassword;
+  final List<String> severurl;
+  final List<String> onurl;
+  final List<String> cidrs;
+  final List<Forward> forwards;
+  final FlagsC flag;
+  final ConfigBuildOptions options;
+
+  /// Relays picked by latency in addition to `severurl`, see `relay_select`.
+  final RelayPool? relayPool;
+
+  const NetworkConfigC({
+    required this.username,
+    required this.enableDhcp,
+    required this.specifiedIp,
+    required this.roomName,
+    required this.roomPassword,
+    required this.severurl,
+    required this.onurl,
+    required this.cidrs,
+    required this.forwards,
+    required this.flag,
+    required this.options,
+    this.relayPool,
+  });
+
+  @override
+  int get hashCode =>
+      username.hashCode ^
+      enableDhcp.hashCode ^
+      specifiedIp.hashCode ^
+      roomName.hashCode ^
+      roomPassword.hashCode ^
+      severurl.hashCode ^
+      onurl.hashCode ^
+      cidrs.hashCode ^
+      forwards.hashCode ^
+      flag.hashCode ^
+      options.hashCode ^
+      relayPool.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NetworkConfigC &&
+          runtimeType == other.runtimeType &&
+          username == other.username &&
+          enableDhcp == other.enableDhcp &&
+          specifiedIp == other.specifiedIp &&
+          roomName == other.roomName &&
+          roomPassword == other.roomPassword &&
+          severurl == other.severurl &&
+          onurl == other.onurl &&
+          cidrs == other.cidrs &&
+          forwards == other.forwards &&
+          flag == other.flag &&
+          options == other.options &&
+          relayPool == other.relayPool;
+}
@@ -4,18 +4,30 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'instance_patch.dart';
+import 'network_config.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 import 'simple.dart';
+part 'p2p.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_instance_info`, `parse_instance_id`, `peer_conn_info_to_string`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MANAGER`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `deref`, `initialize`, `initialize`
+// These functions are ignored because they are not marked as `pub`: `discard_instance`, `drop_event_sinks`, `format_ipv4`, `format_ipv4_inet`, `format_ipv6`, `format_ipv6_inet`, `from_global_ctx_event`, `get_instance_info`, `has_instance_config`, `ipv6_to_proto`, `is_known_instance`, `local_ipv6`, `manager`, `nat_type_name`, `parse_instance_id`, `publish_event`, `restart_instance`, `run_instance`, `start_instance`, `watch_instance_events`, `with_instance_config`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CONFIGS`, `EVENT_CHANNELS`, `MANAGER`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `default`, `deref`, `deref`, `deref`, `deref`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `initialize`, `initialize`, `initialize`, `initialize`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `handle_event`
 
-Future<void> sendUdpToLocalhost({required String message}) =>
-    RustLib.instance.api.crateApiP2PSendUdpToLocalhost(message: message);
-
-Future<JoinHandle> handleEvent({required EventBusSubscriber events}) =>
-    RustLib.instance.api.crateApiP2PHandleEvent(events: events);
+/// Subscribes to the typed event stream of an instance.
+///
+/// The most recent events of the instance are replayed first, so start-up
+/// events are not lost to a subscriber that attaches late. Several subscribers
+/// may listen to the same instance; a subscriber is dropped once its Dart
+/// stream is cancelled. Fails for instances that are neither running nor
+/// waiting for a restart.
+Stream<InstanceEventRecord> subscribeInstanceEvents({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiP2PSubscribeInstanceEvents(
+  instanceId: instanceId,
+);
 
 Future<String> easytierVersion() =>
     RustLib.instance.api.crateApiP2PEasytierVersion();
@@ -29,7 +41,7 @@ Future<List<String>> getIps({required String instanceId}) =>
 Future<void> setTunFd({required String instanceId, required int fd}) =>
     RustLib.instance.api.crateApiP2PSetTunFd(instanceId: instanceId, fd: fd);
 
-Future<String> getRunningInfo({required String instanceId}) =>
+Future<RunningInfo> getRunningInfo({required String instanceId}) =>
     RustLib.instance.api.crateApiP2PGetRunningInfo(instanceId: instanceId);
 
 Future<JoinHandleResultStringString> createServer({
@@ -40,6 +52,8 @@ Future<JoinHandleResultStringString> createServer({
   watchEvent: watchEvent,
 );
 
+/// Starts an instance from the flags-style arguments. To pick relays from a
+/// `RelayPool` or to change the build options, use `create_server_with_config`.
 Future<JoinHandleResultStringString> createServerWithFlags({
   required String username,
   required bool enableDhcp,
@@ -64,6 +78,15 @@ Future<JoinHandleResultStringString> createServerWithFlags({
   flag: flag,
 );
 
+/// Validates `config` and starts an instance from it. All validation issues
+/// are reported in the error, see `validate_network_config`.
+///
+/// With a `relay_pool` the candidates are probed first and the best ones are
+/// connected in addition to `severurl`.
+Future<JoinHandleResultStringString> createServerWithConfig({
+  required NetworkConfigC config,
+}) => RustLib.instance.api.crateApiP2PCreateServerWithConfig(config: config);
+
 Future<void> closeServer({required String instanceId}) =>
     RustLib.instance.api.crateApiP2PCloseServer(instanceId: instanceId);
 
@@ -75,14 +98,480 @@ Future<KVNetworkStatus> getNetworkStatus({required String instanceId}) =>
 
 Future<void> initApp() => RustLib.instance.api.crateApiP2PInitApp();
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>
-abstract class EventBusSubscriber implements RustOpaqueInterface {}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner< JoinHandle < () >>>
-abstract class JoinHandle implements RustOpaqueInterface {}
-
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle < Result < String , String > >>>
 abstract class JoinHandleResultStringString implements RustOpaqueInterface {}
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>
 abstract class PeerRoutePair implements RustOpaqueInterface {}
+
+/// One entry of a config patch applied to a running instance.
+class ConfigChange {
+  /// Patched field: `peers`, `port_forwards`, `proxy_cidrs`, `tcp_whitelist`,
+  /// `udp_whitelist`, `hostname` or `ipv4`.
+  final String field;
+
+  /// `None` when the value replaces the field instead of being added to or
+  /// removed from a list.
+  final PatchAction? action;
+  final String value;
+
+  const ConfigChange({required this.field, this.action, required this.value});
+
+  @override
+  int get hashCode => field.hashCode ^ action.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConfigChange &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          action == other.action &&
+          value == other.value;
+}
+
+/// Typed mirror of `GlobalCtxEvent`, streamed to Flutter per instance.
+@freezed
+sealed class InstanceEvent with _$InstanceEvent {
+  const InstanceEvent._();
+
+  const factory InstanceEvent.peerAdded({required int peerId}) =
+      InstanceEvent_PeerAdded;
+  const factory InstanceEvent.peerRemoved({required int peerId}) =
+      InstanceEvent_PeerRemoved;
+  const factory InstanceEvent.peerConnAdded({required PeerConnEvent conn}) =
+      InstanceEvent_PeerConnAdded;
+  const factory InstanceEvent.peerConnRemoved({required PeerConnEvent conn}) =
+      InstanceEvent_PeerConnRemoved;
+  const factory InstanceEvent.listenerAdded({required String listener}) =
+      InstanceEvent_ListenerAdded;
+  const factory InstanceEvent.listenerAddFailed({
+    required String listener,
+    required String message,
+  }) = InstanceEvent_ListenerAddFailed;
+  const factory InstanceEvent.listenerAcceptFailed({
+    required String listener,
+    required String message,
+  }) = InstanceEvent_ListenerAcceptFailed;
+  const factory InstanceEvent.connectionAccepted({
+    required String local,
+    required String remote,
+  }) = InstanceEvent_ConnectionAccepted;
+  const factory InstanceEvent.connectionError({
+    required String local,
+    required String remote,
+    required String error,
+  }) = InstanceEvent_ConnectionError;
+  const factory InstanceEvent.tunDeviceReady({required String dev}) =
+      InstanceEvent_TunDeviceReady;
+  const factory InstanceEvent.tunDeviceError({required String error}) =
+      InstanceEvent_TunDeviceError;
+  const factory InstanceEvent.connecting({required String dst}) =
+      InstanceEvent_Connecting;
+  const factory InstanceEvent.connectError({
+    required String dst,
+    required String ipVersion,
+    required String error,
+  }) = InstanceEvent_ConnectError;
+  const factory InstanceEvent.vpnPortalStarted({required String portal}) =
+      InstanceEvent_VpnPortalStarted;
+  const factory InstanceEvent.vpnPortalClientConnected({
+    required String portal,
+    required String clientAddr,
+  }) = InstanceEvent_VpnPortalClientConnected;
+  const factory InstanceEvent.vpnPortalClientDisconnected({
+    required String portal,
+    required String clientAddr,
+  }) = InstanceEvent_VpnPortalClientDisconnected;
+  const factory InstanceEvent.dhcpIpv4Changed({String? oldIp, String? newIp}) =
+      InstanceEvent_DhcpIpv4Changed;
+  const factory InstanceEvent.dhcpIpv4Conflicted({String? ip}) =
+      InstanceEvent_DhcpIpv4Conflicted;
+  const factory InstanceEvent.portForwardAdded({
+    required String bindAddr,
+    required String dstAddr,
+    required String proto,
+  }) = InstanceEvent_PortForwardAdded;
+  /// A config patch was applied to the running instance.
+  const factory InstanceEvent.configPatched({
+    required List<ConfigChange> changes,
+  }) = InstanceEvent_ConfigPatched;
+  const factory InstanceEvent.proxyCidrsUpdated({
+    required List<String> added,
+    required List<String> removed,
+  }) = InstanceEvent_ProxyCidrsUpdated;
+  /// The supervisor will restart the instance after `delay_ms`.
+  const factory InstanceEvent.instanceRestarting({
+    required int attempt,
+    required BigInt delayMs,
+    required String reason,
+  }) = InstanceEvent_InstanceRestarting;
+  const factory InstanceEvent.instanceRestarted({required int attempt}) =
+      InstanceEvent_InstanceRestarted;
+  /// The supervisor ran out of retries; the instance stays down.
+  const factory InstanceEvent.instanceRestartGaveUp({
+    required int attempts,
+    required String reason,
+  }) = InstanceEvent_InstanceRestartGaveUp;
+  /// The subscriber fell behind and `dropped` events were lost.
+  const factory InstanceEvent.eventsLagged({required BigInt dropped}) =
+      InstanceEvent_EventsLagged;
+  /// The instance's event bus closed; no further events will follow.
+  const factory InstanceEvent.eventsClosed() = InstanceEvent_EventsClosed;
+}
+
+/// An event together with the instance that produced it.
+class InstanceEventRecord {
+  final String instanceId;
+
+  /// Unix timestamp in milliseconds.
+  final PlatformInt64 timestamp;
+  final InstanceEvent event;
+
+  const InstanceEventRecord({
+    required this.instanceId,
+    required this.timestamp,
+    required this.event,
+  });
+
+  @override
+  int get hashCode => instanceId.hashCode ^ timestamp.hashCode ^ event.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InstanceEventRecord &&
+          runtimeType == other.runtimeType &&
+          instanceId == other.instanceId &&
+          timestamp == other.timestamp &&
+          event == other.event;
+}
+
+/// Summary of a peer connection carried by connection events.
+class PeerConnEvent {
+  final String connId;
+  final int myPeerId;
+  final int peerId;
+  final String tunnelType;
+  final String localAddr;
+  final String remoteAddr;
+
+  const PeerConnEvent({
+    required this.connId,
+    required this.myPeerId,
+    required this.peerId,
+    required this.tunnelType,
+    required this.localAddr,
+    required this.remoteAddr,
+  });
+
+  @override
+  int get hashCode =>
+      connId.hashCode ^
+      myPeerId.hashCode ^
+      peerId.hashCode ^
+      tunnelType.hashCode ^
+      localAddr.hashCode ^
+      remoteAddr.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerConnEvent &&
+          runtimeType == other.runtimeType &&
+          connId == other.connId &&
+          myPeerId == other.myPeerId &&
+          peerId == other.peerId &&
+          tunnelType == other.tunnelType &&
+          localAddr == other.localAddr &&
+          remoteAddr == other.remoteAddr;
+}
+
+/// A single tunnel to a directly connected peer.
+class RunningConnInfo {
+  final String connId;
+  final String tunnelType;
+  final String localAddr;
+  final String remoteAddr;
+  final bool isClient;
+  final String networkName;
+  final double latencyMs;
+  final double lossRate;
+  final BigInt rxBytes;
+  final BigInt txBytes;
+  final BigInt rxPackets;
+  final BigInt txPackets;
+
+  const RunningConnInfo({
+    required this.connId,
+    required this.tunnelType,
+    required this.localAddr,
+    required this.remoteAddr,
+    required this.isClient,
+    required this.networkName,
+    required this.latencyMs,
+    required this.lossRate,
+    required this.rxBytes,
+    required this.txBytes,
+    required this.rxPackets,
+    required this.txPackets,
+  });
+
+  @override
+  int get hashCode =>
+      connId.hashCode ^
+      tunnelType.hashCode ^
+      localAddr.hashCode ^
+      remoteAddr.hashCode ^
+      isClient.hashCode ^
+      networkName.hashCode ^
+      latencyMs.hashCode ^
+      lossRate.hashCode ^
+      rxBytes.hashCode ^
+      txBytes.hashCode ^
+      rxPackets.hashCode ^
+      txPackets.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RunningConnInfo &&
+          runtimeType == other.runtimeType &&
+          connId == other.connId &&
+          tunnelType == other.tunnelType &&
+          localAddr == other.localAddr &&
+          remoteAddr == other.remoteAddr &&
+          isClient == other.isClient &&
+          networkName == other.networkName &&
+          latencyMs == other.latencyMs &&
+          lossRate == other.lossRate &&
+          rxBytes == other.rxBytes &&
+          txBytes == other.txBytes &&
+          rxPackets == other.rxPackets &&
+          txPackets == other.txPackets;
+}
+
+/// Snapshot of a running instance.
+class RunningInfo {
+  final String devName;
+  final bool running;
+  final String? errorMsg;
+  final RunningNodeInfo? myNode;
+  final List<RunningPeerInfo> peers;
+  final List<RunningRouteInfo> routes;
+
+  const RunningInfo({
+    required this.devName,
+    required this.running,
+    this.errorMsg,
+    this.myNode,
+    required this.peers,
+    required this.routes,
+  });
+
+  @override
+  int get hashCode =>
+      devName.hashCode ^
+      running.hashCode ^
+      errorMsg.hashCode ^
+      myNode.hashCode ^
+      peers.hashCode ^
+      routes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RunningInfo &&
+          runtimeType == other.runtimeType &&
+          devName == other.devName &&
+          running == other.running &&
+          errorMsg == other.errorMsg &&
+          myNode == other.myNode &&
+          peers == other.peers &&
+          routes == other.routes;
+}
+
+/// The local node as seen by the running instance.
+class RunningNodeInfo {
+  final int peerId;
+  final String hostname;
+  final String version;
+
+  /// Virtual IPv4 in CIDR notation, `None` until assigned (e.g. waiting for DHCP).
+  final String? virtualIpv4;
+
+  /// Virtual IPv6 in CIDR notation, if one is configured.
+  final String? virtualIpv6;
+  final List<String> listeners;
+  final String? publicIpv4;
+  final String? publicIpv6;
+  final List<String> interfaceIpv4S;
+  final List<String> interfaceIpv6S;
+  final RunningStunInfo? stun;
+
+  const RunningNodeInfo({
+    required this.peerId,
+    required this.hostname,
+    required this.version,
+    this.virtualIpv4,
+    this.virtualIpv6,
+    required this.listeners,
+    this.publicIpv4,
+    this.publicIpv6,
+    required this.interfaceIpv4S,
+    required this.interfaceIpv6S,
+    this.stun,
+  });
+
+  @override
+  int get hashCode =>
+      peerId.hashCode ^
+      hostname.hashCode ^
+      version.hashCode ^
+      virtualIpv4.hashCode ^
+      virtualIpv6.hashCode ^
+      listeners.hashCode ^
+      publicIpv4.hashCode ^
+      publicIpv6.hashCode ^
+      interfaceIpv4S.hashCode ^
+      interfaceIpv6S.hashCode ^
+      stun.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RunningNodeInfo &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          hostname == other.hostname &&
+          version == other.version &&
+          virtualIpv4 == other.virtualIpv4 &&
+          virtualIpv6 == other.virtualIpv6 &&
+          listeners == other.listeners &&
+          publicIpv4 == other.publicIpv4 &&
+          publicIpv6 == other.publicIpv6 &&
+          interfaceIpv4S == other.interfaceIpv4S &&
+          interfaceIpv6S == other.interfaceIpv6S &&
+          stun == other.stun;
+}
+
+/// A directly connected peer and its tunnels.
+class RunningPeerInfo {
+  final int peerId;
+  final List<RunningConnInfo> connections;
+
+  const RunningPeerInfo({required this.peerId, required this.connections});
+
+  @override
+  int get hashCode => peerId.hashCode ^ connections.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RunningPeerInfo &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          connections == other.connections;
+}
+
+/// A route to a remote node, direct or relayed.
+class RunningRouteInfo {
+  final int peerId;
+  final String instId;
+  final String hostname;
+  final String version;
+  final String? ipv4;
+  final String? ipv6;
+  final int nextHopPeerId;
+  final int cost;
+  final int pathLatency;
+  final List<String> proxyCidrs;
+  final String udpNatType;
+
+  const RunningRouteInfo({
+    required this.peerId,
+    required this.instId,
+    required this.hostname,
+    required this.version,
+    this.ipv4,
+    this.ipv6,
+    required this.nextHopPeerId,
+    required this.cost,
+    required this.pathLatency,
+    required this.proxyCidrs,
+    required this.udpNatType,
+  });
+
+  @override
+  int get hashCode =>
+      peerId.hashCode ^
+      instId.hashCode ^
+      hostname.hashCode ^
+      version.hashCode ^
+      ipv4.hashCode ^
+      ipv6.hashCode ^
+      nextHopPeerId.hashCode ^
+      cost.hashCode ^
+      pathLatency.hashCode ^
+      proxyCidrs.hashCode ^
+      udpNatType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RunningRouteInfo &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          instId == other.instId &&
+          hostname == other.hostname &&
+          version == other.version &&
+          ipv4 == other.ipv4 &&
+          ipv6 == other.ipv6 &&
+          nextHopPeerId == other.nextHopPeerId &&
+          cost == other.cost &&
+          pathLatency == other.pathLatency &&
+          proxyCidrs == other.proxyCidrs &&
+          udpNatType == other.udpNatType;
+}
+
+/// STUN probing result of the local node.
+class RunningStunInfo {
+  final String udpNatType;
+  final String tcpNatType;
+  final List<String> publicIps;
+  final int minPort;
+  final int maxPort;
+
+  /// Unix timestamp of the last STUN probe, in seconds.
+  final PlatformInt64 lastUpdateTime;
+
+  const RunningStunInfo({
+    required this.udpNatType,
+    required this.tcpNatType,
+    required this.publicIps,
+    required this.minPort,
+    required this.maxPort,
+    required this.lastUpdateTime,
+  });
+
+  @override
+  int get hashCode =>
+      udpNatType.hashCode ^
+      tcpNatType.hashCode ^
+      publicIps.hashCode ^
+      minPort.hashCode ^
+      maxPort.hashCode ^
+      lastUpdateTime.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RunningStunInfo &&
+          runtimeType == other.runtimeType &&
+          udpNatType == other.udpNatType &&
+          tcpNatType == other.tcpNatType &&
+          publicIps == other.publicIps &&
+          minPort == other.minPort &&
+          maxPort == other.maxPort &&
+          lastUpdateTime == other.lastUpdateTime;
+}
@@ -0,0 +1,1801 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'p2p.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$InstanceEvent {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'InstanceEvent()';
+}
+
+
+}
+
+/// @nodoc
+class $InstanceEventCopyWith<$Res>  {
+$InstanceEventCopyWith(InstanceEvent _, $Res Function(InstanceEvent) __);
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_PeerAdded extends InstanceEvent {
+  const InstanceEvent_PeerAdded({required this.peerId}): super._();
+  
+
+ final  int peerId;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_PeerAddedCopyWith<InstanceEvent_PeerAdded> get copyWith => _$InstanceEvent_PeerAddedCopyWithImpl<InstanceEvent_PeerAdded>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_PeerAdded&&(identical(other.peerId, peerId) || other.peerId == peerId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,peerId);
+
+@override
+String toString() {
+  return 'InstanceEvent.peerAdded(peerId: $peerId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_PeerAddedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_PeerAddedCopyWith(InstanceEvent_PeerAdded value, $Res Function(InstanceEvent_PeerAdded) _then) = _$InstanceEvent_PeerAddedCopyWithImpl;
+@useResult
+$Res call({
+ int peerId
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_PeerAddedCopyWithImpl<$Res>
+    implements $InstanceEvent_PeerAddedCopyWith<$Res> {
+  _$InstanceEvent_PeerAddedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_PeerAdded _self;
+  final $Res Function(InstanceEvent_PeerAdded) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? peerId = null,}) {
+  return _then(InstanceEvent_PeerAdded(
+peerId: null == peerId ? _self.peerId : peerId // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_PeerRemoved extends InstanceEvent {
+  const InstanceEvent_PeerRemoved({required this.peerId}): super._();
+  
+
+ final  int peerId;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_PeerRemovedCopyWith<InstanceEvent_PeerRemoved> get copyWith => _$InstanceEvent_PeerRemovedCopyWithImpl<InstanceEvent_PeerRemoved>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_PeerRemoved&&(identical(other.peerId, peerId) || other.peerId == peerId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,peerId);
+
+@override
+String toString() {
+  return 'InstanceEvent.peerRemoved(peerId: $peerId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_PeerRemovedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_PeerRemovedCopyWith(InstanceEvent_PeerRemoved value, $Res Function(InstanceEvent_PeerRemoved) _then) = _$InstanceEvent_PeerRemovedCopyWithImpl;
+@useResult
+$Res call({
+ int peerId
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_PeerRemovedCopyWithImpl<$Res>
+    implements $InstanceEvent_PeerRemovedCopyWith<$Res> {
+  _$InstanceEvent_PeerRemovedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_PeerRemoved _self;
+  final $Res Function(InstanceEvent_PeerRemoved) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? peerId = null,}) {
+  return _then(InstanceEvent_PeerRemoved(
+peerId: null == peerId ? _self.peerId : peerId // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_PeerConnAdded extends InstanceEvent {
+  const InstanceEvent_PeerConnAdded({required this.conn}): super._();
+  
+
+ final  PeerConnEvent conn;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_PeerConnAddedCopyWith<InstanceEvent_PeerConnAdded> get copyWith => _$InstanceEvent_PeerConnAddedCopyWithImpl<InstanceEvent_PeerConnAdded>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_PeerConnAdded&&(identical(other.conn, conn) || other.conn == conn));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,conn);
+
+@override
+String toString() {
+  return 'InstanceEvent.peerConnAdded(conn: $conn)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_PeerConnAddedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_PeerConnAddedCopyWith(InstanceEvent_PeerConnAdded value, $Res Function(InstanceEvent_PeerConnAdded) _then) = _$InstanceEvent_PeerConnAddedCopyWithImpl;
+@useResult
+$Res call({
+ PeerConnEvent conn
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_PeerConnAddedCopyWithImpl<$Res>
+    implements $InstanceEvent_PeerConnAddedCopyWith<$Res> {
+  _$InstanceEvent_PeerConnAddedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_PeerConnAdded _self;
+  final $Res Function(InstanceEvent_PeerConnAdded) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? conn = null,}) {
+  return _then(InstanceEvent_PeerConnAdded(
+conn: null == conn ? _self.conn : conn // ignore: cast_nullable_to_non_nullable
+as PeerConnEvent,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_PeerConnRemoved extends InstanceEvent {
+  const InstanceEvent_PeerConnRemoved({required this.conn}): super._();
+  
+
+ final  PeerConnEvent conn;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_PeerConnRemovedCopyWith<InstanceEvent_PeerConnRemoved> get copyWith => _$InstanceEvent_PeerConnRemovedCopyWithImpl<InstanceEvent_PeerConnRemoved>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_PeerConnRemoved&&(identical(other.conn, conn) || other.conn == conn));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,conn);
+
+@override
+String toString() {
+  return 'InstanceEvent.peerConnRemoved(conn: $conn)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_PeerConnRemovedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_PeerConnRemovedCopyWith(InstanceEvent_PeerConnRemoved value, $Res Function(InstanceEvent_PeerConnRemoved) _then) = _$InstanceEvent_PeerConnRemovedCopyWithImpl;
+@useResult
+$Res call({
+ PeerConnEvent conn
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_PeerConnRemovedCopyWithImpl<$Res>
+    implements $InstanceEvent_PeerConnRemovedCopyWith<$Res> {
+  _$InstanceEvent_PeerConnRemovedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_PeerConnRemoved _self;
+  final $Res Function(InstanceEvent_PeerConnRemoved) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? conn = null,}) {
+  return _then(InstanceEvent_PeerConnRemoved(
+conn: null == conn ? _self.conn : conn // ignore: cast_nullable_to_non_nullable
+as PeerConnEvent,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_ListenerAdded extends InstanceEvent {
+  const InstanceEvent_ListenerAdded({required this.listener}): super._();
+  
+
+ final  String listener;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_ListenerAddedCopyWith<InstanceEvent_ListenerAdded> get copyWith => _$InstanceEvent_ListenerAddedCopyWithImpl<InstanceEvent_ListenerAdded>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_ListenerAdded&&(identical(other.listener, listener) || other.listener == listener));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,listener);
+
+@override
+String toString() {
+  return 'InstanceEvent.listenerAdded(listener: $listener)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_ListenerAddedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_ListenerAddedCopyWith(InstanceEvent_ListenerAdded value, $Res Function(InstanceEvent_ListenerAdded) _then) = _$InstanceEvent_ListenerAddedCopyWithImpl;
+@useResult
+$Res call({
+ String listener
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_ListenerAddedCopyWithImpl<$Res>
+    implements $InstanceEvent_ListenerAddedCopyWith<$Res> {
+  _$InstanceEvent_ListenerAddedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_ListenerAdded _self;
+  final $Res Function(InstanceEvent_ListenerAdded) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? listener = null,}) {
+  return _then(InstanceEvent_ListenerAdded(
+listener: null == listener ? _self.listener : listener // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_ListenerAddFailed extends InstanceEvent {
+  const InstanceEvent_ListenerAddFailed({required this.listener, required this.message}): super._();
+  
+
+ final  String listener;
+ final  String message;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_ListenerAddFailedCopyWith<InstanceEvent_ListenerAddFailed> get copyWith => _$InstanceEvent_ListenerAddFailedCopyWithImpl<InstanceEvent_ListenerAddFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_ListenerAddFailed&&(identical(other.listener, listener) || other.listener == listener)&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,listener,message);
+
+@override
+String toString() {
+  return 'InstanceEvent.listenerAddFailed(listener: $listener, message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_ListenerAddFailedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_ListenerAddFailedCopyWith(InstanceEvent_ListenerAddFailed value, $Res Function(InstanceEvent_ListenerAddFailed) _then) = _$InstanceEvent_ListenerAddFailedCopyWithImpl;
+@useResult
+$Res call({
+ String listener, String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_ListenerAddFailedCopyWithImpl<$Res>
+    implements $InstanceEvent_ListenerAddFailedCopyWith<$Res> {
+  _$InstanceEvent_ListenerAddFailedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_ListenerAddFailed _self;
+  final $Res Function(InstanceEvent_ListenerAddFailed) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? listener = null,Object? message = null,}) {
+  return _then(InstanceEvent_ListenerAddFailed(
+listener: null == listener ? _self.listener : listener // ignore: cast_nullable_to_non_nullable
+as String,message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_ListenerAcceptFailed extends InstanceEvent {
+  const InstanceEvent_ListenerAcceptFailed({required this.listener, required this.message}): super._();
+  
+
+ final  String listener;
+ final  String message;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_ListenerAcceptFailedCopyWith<InstanceEvent_ListenerAcceptFailed> get copyWith => _$InstanceEvent_ListenerAcceptFailedCopyWithImpl<InstanceEvent_ListenerAcceptFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_ListenerAcceptFailed&&(identical(other.listener, listener) || other.listener == listener)&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,listener,message);
+
+@override
+String toString() {
+  return 'InstanceEvent.listenerAcceptFailed(listener: $listener, message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_ListenerAcceptFailedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_ListenerAcceptFailedCopyWith(InstanceEvent_ListenerAcceptFailed value, $Res Function(InstanceEvent_ListenerAcceptFailed) _then) = _$InstanceEvent_ListenerAcceptFailedCopyWithImpl;
+@useResult
+$Res call({
+ String listener, String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_ListenerAcceptFailedCopyWithImpl<$Res>
+    implements $InstanceEvent_ListenerAcceptFailedCopyWith<$Res> {
+  _$InstanceEvent_ListenerAcceptFailedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_ListenerAcceptFailed _self;
+  final $Res Function(InstanceEvent_ListenerAcceptFailed) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? listener = null,Object? message = null,}) {
+  return _then(InstanceEvent_ListenerAcceptFailed(
+listener: null == listener ? _self.listener : listener // ignore: cast_nullable_to_non_nullable
+as String,message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_ConnectionAccepted extends InstanceEvent {
+  const InstanceEvent_ConnectionAccepted({required this.local, required this.remote}): super._();
+  
+
+ final  String local;
+ final  String remote;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_ConnectionAcceptedCopyWith<InstanceEvent_ConnectionAccepted> get copyWith => _$InstanceEvent_ConnectionAcceptedCopyWithImpl<InstanceEvent_ConnectionAccepted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_ConnectionAccepted&&(identical(other.local, local) || other.local == local)&&(identical(other.remote, remote) || other.remote == remote));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,local,remote);
+
+@override
+String toString() {
+  return 'InstanceEvent.connectionAccepted(local: $local, remote: $remote)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_ConnectionAcceptedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_ConnectionAcceptedCopyWith(InstanceEvent_ConnectionAccepted value, $Res Function(InstanceEvent_ConnectionAccepted) _then) = _$InstanceEvent_ConnectionAcceptedCopyWithImpl;
+@useResult
+$Res call({
+ String local, String remote
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_ConnectionAcceptedCopyWithImpl<$Res>
+    implements $InstanceEvent_ConnectionAcceptedCopyWith<$Res> {
+  _$InstanceEvent_ConnectionAcceptedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_ConnectionAccepted _self;
+  final $Res Function(InstanceEvent_ConnectionAccepted) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? local = null,Object? remote = null,}) {
+  return _then(InstanceEvent_ConnectionAccepted(
+local: null == local ? _self.local : local // ignore: cast_nullable_to_non_nullable
+as String,remote: null == remote ? _self.remote : remote // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_ConnectionError extends InstanceEvent {
+  const InstanceEvent_ConnectionError({required this.local, required this.remote, required this.error}): super._();
+  
+
+ final  String local;
+ final  String remote;
+ final  String error;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_ConnectionErrorCopyWith<InstanceEvent_ConnectionError> get copyWith => _$InstanceEvent_ConnectionErrorCopyWithImpl<InstanceEvent_ConnectionError>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_ConnectionError&&(identical(other.local, local) || other.local == local)&&(identical(other.remote, remote) || other.remote == remote)&&(identical(other.error, error) || other.error == error));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,local,remote,error);
+
+@override
+String toString() {
+  return 'InstanceEvent.connectionError(local: $local, remote: $remote, error: $error)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_ConnectionErrorCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_ConnectionErrorCopyWith(InstanceEvent_ConnectionError value, $Res Function(InstanceEvent_ConnectionError) _then) = _$InstanceEvent_ConnectionErrorCopyWithImpl;
+@useResult
+$Res call({
+ String local, String remote, String error
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_ConnectionErrorCopyWithImpl<$Res>
+    implements $InstanceEvent_ConnectionErrorCopyWith<$Res> {
+  _$InstanceEvent_ConnectionErrorCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_ConnectionError _self;
+  final $Res Function(InstanceEvent_ConnectionError) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? local = null,Object? remote = null,Object? error = null,}) {
+  return _then(InstanceEvent_ConnectionError(
+local: null == local ? _self.local : local // ignore: cast_nullable_to_non_nullable
+as String,remote: null == remote ? _self.remote : remote // ignore: cast_nullable_to_non_nullable
+as String,error: null == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_TunDeviceReady extends InstanceEvent {
+  const InstanceEvent_TunDeviceReady({required this.dev}): super._();
+  
+
+ final  String dev;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_TunDeviceReadyCopyWith<InstanceEvent_TunDeviceReady> get copyWith => _$InstanceEvent_TunDeviceReadyCopyWithImpl<InstanceEvent_TunDeviceReady>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_TunDeviceReady&&(identical(other.dev, dev) || other.dev == dev));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,dev);
+
+@override
+String toString() {
+  return 'InstanceEvent.tunDeviceReady(dev: $dev)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_TunDeviceReadyCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_TunDeviceReadyCopyWith(InstanceEvent_TunDeviceReady value, $Res Function(InstanceEvent_TunDeviceReady) _then) = _$InstanceEvent_TunDeviceReadyCopyWithImpl;
+@useResult
+$Res call({
+ String dev
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_TunDeviceReadyCopyWithImpl<$Res>
+    implements $InstanceEvent_TunDeviceReadyCopyWith<$Res> {
+  _$InstanceEvent_TunDeviceReadyCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_TunDeviceReady _self;
+  final $Res Function(InstanceEvent_TunDeviceReady) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? dev = null,}) {
+  return _then(InstanceEvent_TunDeviceReady(
+dev: null == dev ? _self.dev : dev // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_TunDeviceError extends InstanceEvent {
+  const InstanceEvent_TunDeviceError({required this.error}): super._();
+  
+
+ final  String error;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_TunDeviceErrorCopyWith<InstanceEvent_TunDeviceError> get copyWith => _$InstanceEvent_TunDeviceErrorCopyWithImpl<InstanceEvent_TunDeviceError>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_TunDeviceError&&(identical(other.error, error) || other.error == error));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,error);
+
+@override
+String toString() {
+  return 'InstanceEvent.tunDeviceError(error: $error)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_TunDeviceErrorCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_TunDeviceErrorCopyWith(InstanceEvent_TunDeviceError value, $Res Function(InstanceEvent_TunDeviceError) _then) = _$InstanceEvent_TunDeviceErrorCopyWithImpl;
+@useResult
+$Res call({
+ String error
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_TunDeviceErrorCopyWithImpl<$Res>
+    implements $InstanceEvent_TunDeviceErrorCopyWith<$Res> {
+  _$InstanceEvent_TunDeviceErrorCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_TunDeviceError _self;
+  final $Res Function(InstanceEvent_TunDeviceError) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? error = null,}) {
+  return _then(InstanceEvent_TunDeviceError(
+error: null == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_Connecting extends InstanceEvent {
+  const InstanceEvent_Connecting({required this.dst}): super._();
+  
+
+ final  String dst;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_ConnectingCopyWith<InstanceEvent_Connecting> get copyWith => _$InstanceEvent_ConnectingCopyWithImpl<InstanceEvent_Connecting>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_Connecting&&(identical(other.dst, dst) || other.dst == dst));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,dst);
+
+@override
+String toString() {
+  return 'InstanceEvent.connecting(dst: $dst)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_ConnectingCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_ConnectingCopyWith(InstanceEvent_Connecting value, $Res Function(InstanceEvent_Connecting) _then) = _$InstanceEvent_ConnectingCopyWithImpl;
+@useResult
+$Res call({
+ String dst
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_ConnectingCopyWithImpl<$Res>
+    implements $InstanceEvent_ConnectingCopyWith<$Res> {
+  _$InstanceEvent_ConnectingCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_Connecting _self;
+  final $Res Function(InstanceEvent_Connecting) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? dst = null,}) {
+  return _then(InstanceEvent_Connecting(
+dst: null == dst ? _self.dst : dst // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_ConnectError extends InstanceEvent {
+  const InstanceEvent_ConnectError({required this.dst, required this.ipVersion, required this.error}): super._();
+  
+
+ final  String dst;
+ final  String ipVersion;
+ final  String error;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_ConnectErrorCopyWith<InstanceEvent_ConnectError> get copyWith => _$InstanceEvent_ConnectErrorCopyWithImpl<InstanceEvent_ConnectError>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_ConnectError&&(identical(other.dst, dst) || other.dst == dst)&&(identical(other.ipVersion, ipVersion) || other.ipVersion == ipVersion)&&(identical(other.error, error) || other.error == error));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,dst,ipVersion,error);
+
+@override
+String toString() {
+  return 'InstanceEvent.connectError(dst: $dst, ipVersion: $ipVersion, error: $error)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_ConnectErrorCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_ConnectErrorCopyWith(InstanceEvent_ConnectError value, $Res Function(InstanceEvent_ConnectError) _then) = _$InstanceEvent_ConnectErrorCopyWithImpl;
+@useResult
+$Res call({
+ String dst, String ipVersion, String error
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_ConnectErrorCopyWithImpl<$Res>
+    implements $InstanceEvent_ConnectErrorCopyWith<$Res> {
+  _$InstanceEvent_ConnectErrorCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_ConnectError _self;
+  final $Res Function(InstanceEvent_ConnectError) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? dst = null,Object? ipVersion = null,Object? error = null,}) {
+  return _then(InstanceEvent_ConnectError(
+dst: null == dst ? _self.dst : dst // ignore: cast_nullable_to_non_nullable
+as String,ipVersion: null == ipVersion ? _self.ipVersion : ipVersion // ignore: cast_nullable_to_non_nullable
+as String,error: null == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_VpnPortalStarted extends InstanceEvent {
+  const InstanceEvent_VpnPortalStarted({required this.portal}): super._();
+  
+
+ final  String portal;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_VpnPortalStartedCopyWith<InstanceEvent_VpnPortalStarted> get copyWith => _$InstanceEvent_VpnPortalStartedCopyWithImpl<InstanceEvent_VpnPortalStarted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_VpnPortalStarted&&(identical(other.portal, portal) || other.portal == portal));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,portal);
+
+@override
+String toString() {
+  return 'InstanceEvent.vpnPortalStarted(portal: $portal)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_VpnPortalStartedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_VpnPortalStartedCopyWith(InstanceEvent_VpnPortalStarted value, $Res Function(InstanceEvent_VpnPortalStarted) _then) = _$InstanceEvent_VpnPortalStartedCopyWithImpl;
+@useResult
+$Res call({
+ String portal
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_VpnPortalStartedCopyWithImpl<$Res>
+    implements $InstanceEvent_VpnPortalStartedCopyWith<$Res> {
+  _$InstanceEvent_VpnPortalStartedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_VpnPortalStarted _self;
+  final $Res Function(InstanceEvent_VpnPortalStarted) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? portal = null,}) {
+  return _then(InstanceEvent_VpnPortalStarted(
+portal: null == portal ? _self.portal : portal // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_VpnPortalClientConnected extends InstanceEvent {
+  const InstanceEvent_VpnPortalClientConnected({required this.portal, required this.clientAddr}): super._();
+  
+
+ final  String portal;
+ final  String clientAddr;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_VpnPortalClientConnectedCopyWith<InstanceEvent_VpnPortalClientConnected> get copyWith => _$InstanceEvent_VpnPortalClientConnectedCopyWithImpl<InstanceEvent_VpnPortalClientConnected>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_VpnPortalClientConnected&&(identical(other.portal, portal) || other.portal == portal)&&(identical(other.clientAddr, clientAddr) || other.clientAddr == clientAddr));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,portal,clientAddr);
+
+@override
+String toString() {
+  return 'InstanceEvent.vpnPortalClientConnected(portal: $portal, clientAddr: $clientAddr)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_VpnPortalClientConnectedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_VpnPortalClientConnectedCopyWith(InstanceEvent_VpnPortalClientConnected value, $Res Function(InstanceEvent_VpnPortalClientConnected) _then) = _$InstanceEvent_VpnPortalClientConnectedCopyWithImpl;
+@useResult
+$Res call({
+ String portal, String clientAddr
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_VpnPortalClientConnectedCopyWithImpl<$Res>
+    implements $InstanceEvent_VpnPortalClientConnectedCopyWith<$Res> {
+  _$InstanceEvent_VpnPortalClientConnectedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_VpnPortalClientConnected _self;
+  final $Res Function(InstanceEvent_VpnPortalClientConnected) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? portal = null,Object? clientAddr = null,}) {
+  return _then(InstanceEvent_VpnPortalClientConnected(
+portal: null == portal ? _self.portal : portal // ignore: cast_nullable_to_non_nullable
+as String,clientAddr: null == clientAddr ? _self.clientAddr : clientAddr // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_VpnPortalClientDisconnected extends InstanceEvent {
+  const InstanceEvent_VpnPortalClientDisconnected({required this.portal, required this.clientAddr}): super._();
+  
+
+ final  String portal;
+ final  String clientAddr;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_VpnPortalClientDisconnectedCopyWith<InstanceEvent_VpnPortalClientDisconnected> get copyWith => _$InstanceEvent_VpnPortalClientDisconnectedCopyWithImpl<InstanceEvent_VpnPortalClientDisconnected>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_VpnPortalClientDisconnected&&(identical(other.portal, portal) || other.portal == portal)&&(identical(other.clientAddr, clientAddr) || other.clientAddr == clientAddr));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,portal,clientAddr);
+
+@override
+String toString() {
+  return 'InstanceEvent.vpnPortalClientDisconnected(portal: $portal, clientAddr: $clientAddr)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_VpnPortalClientDisconnectedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_VpnPortalClientDisconnectedCopyWith(InstanceEvent_VpnPortalClientDisconnected value, $Res Function(InstanceEvent_VpnPortalClientDisconnected) _then) = _$InstanceEvent_VpnPortalClientDisconnectedCopyWithImpl;
+@useResult
+$Res call({
+ String portal, String clientAddr
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_VpnPortalClientDisconnectedCopyWithImpl<$Res>
+    implements $InstanceEvent_VpnPortalClientDisconnectedCopyWith<$Res> {
+  _$InstanceEvent_VpnPortalClientDisconnectedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_VpnPortalClientDisconnected _self;
+  final $Res Function(InstanceEvent_VpnPortalClientDisconnected) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? portal = null,Object? clientAddr = null,}) {
+  return _then(InstanceEvent_VpnPortalClientDisconnected(
+portal: null == portal ? _self.portal : portal // ignore: cast_nullable_to_non_nullable
+as String,clientAddr: null == clientAddr ? _self.clientAddr : clientAddr // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_DhcpIpv4Changed extends InstanceEvent {
+  const InstanceEvent_DhcpIpv4Changed({this.oldIp, this.newIp}): super._();
+  
+
+ final  String? oldIp;
+ final  String? newIp;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_DhcpIpv4ChangedCopyWith<InstanceEvent_DhcpIpv4Changed> get copyWith => _$InstanceEvent_DhcpIpv4ChangedCopyWithImpl<InstanceEvent_DhcpIpv4Changed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_DhcpIpv4Changed&&(identical(other.oldIp, oldIp) || other.oldIp == oldIp)&&(identical(other.newIp, newIp) || other.newIp == newIp));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,oldIp,newIp);
+
+@override
+String toString() {
+  return 'InstanceEvent.dhcpIpv4Changed(oldIp: $oldIp, newIp: $newIp)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_DhcpIpv4ChangedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_DhcpIpv4ChangedCopyWith(InstanceEvent_DhcpIpv4Changed value, $Res Function(InstanceEvent_DhcpIpv4Changed) _then) = _$InstanceEvent_DhcpIpv4ChangedCopyWithImpl;
+@useResult
+$Res call({
+ String? oldIp, String? newIp
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_DhcpIpv4ChangedCopyWithImpl<$Res>
+    implements $InstanceEvent_DhcpIpv4ChangedCopyWith<$Res> {
+  _$InstanceEvent_DhcpIpv4ChangedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_DhcpIpv4Changed _self;
+  final $Res Function(InstanceEvent_DhcpIpv4Changed) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? oldIp = freezed,Object? newIp = freezed,}) {
+  return _then(InstanceEvent_DhcpIpv4Changed(
+oldIp: freezed == oldIp ? _self.oldIp : oldIp // ignore: cast_nullable_to_non_nullable
+as String?,newIp: freezed == newIp ? _self.newIp : newIp // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_DhcpIpv4Conflicted extends InstanceEvent {
+  const InstanceEvent_DhcpIpv4Conflicted({this.ip}): super._();
+  
+
+ final  String? ip;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_DhcpIpv4ConflictedCopyWith<InstanceEvent_DhcpIpv4Conflicted> get copyWith => _$InstanceEvent_DhcpIpv4ConflictedCopyWithImpl<InstanceEvent_DhcpIpv4Conflicted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_DhcpIpv4Conflicted&&(identical(other.ip, ip) || other.ip == ip));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,ip);
+
+@override
+String toString() {
+  return 'InstanceEvent.dhcpIpv4Conflicted(ip: $ip)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_DhcpIpv4ConflictedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_DhcpIpv4ConflictedCopyWith(InstanceEvent_DhcpIpv4Conflicted value, $Res Function(InstanceEvent_DhcpIpv4Conflicted) _then) = _$InstanceEvent_DhcpIpv4ConflictedCopyWithImpl;
+@useResult
+$Res call({
+ String? ip
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_DhcpIpv4ConflictedCopyWithImpl<$Res>
+    implements $InstanceEvent_DhcpIpv4ConflictedCopyWith<$Res> {
+  _$InstanceEvent_DhcpIpv4ConflictedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_DhcpIpv4Conflicted _self;
+  final $Res Function(InstanceEvent_DhcpIpv4Conflicted) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? ip = freezed,}) {
+  return _then(InstanceEvent_DhcpIpv4Conflicted(
+ip: freezed == ip ? _self.ip : ip // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_PortForwardAdded extends InstanceEvent {
+  const InstanceEvent_PortForwardAdded({required this.bindAddr, required this.dstAddr, required this.proto}): super._();
+  
+
+ final  String bindAddr;
+ final  String dstAddr;
+ final  String proto;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_PortForwardAddedCopyWith<InstanceEvent_PortForwardAdded> get copyWith => _$InstanceEvent_PortForwardAddedCopyWithImpl<InstanceEvent_PortForwardAdded>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_PortForwardAdded&&(identical(other.bindAddr, bindAddr) || other.bindAddr == bindAddr)&&(identical(other.dstAddr, dstAddr) || other.dstAddr == dstAddr)&&(identical(other.proto, proto) || other.proto == proto));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,bindAddr,dstAddr,proto);
+
+@override
+String toString() {
+  return 'InstanceEvent.portForwardAdded(bindAddr: $bindAddr, dstAddr: $dstAddr, proto: $proto)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_PortForwardAddedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_PortForwardAddedCopyWith(InstanceEvent_PortForwardAdded value, $Res Function(InstanceEvent_PortForwardAdded) _then) = _$InstanceEvent_PortForwardAddedCopyWithImpl;
+@useResult
+$Res call({
+ String bindAddr, String dstAddr, String proto
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_PortForwardAddedCopyWithImpl<$Res>
+    implements $InstanceEvent_PortForwardAddedCopyWith<$Res> {
+  _$InstanceEvent_PortForwardAddedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_PortForwardAdded _self;
+  final $Res Function(InstanceEvent_PortForwardAdded) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? bindAddr = null,Object? dstAddr = null,Object? proto = null,}) {
+  return _then(InstanceEvent_PortForwardAdded(
+bindAddr: null == bindAddr ? _self.bindAddr : bindAddr // ignore: cast_nullable_to_non_nullable
+as String,dstAddr: null == dstAddr ? _self.dstAddr : dstAddr // ignore: cast_nullable_to_non_nullable
+as String,proto: null == proto ? _self.proto : proto // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// A config patch was applied to the running instance.
+
+
+class InstanceEvent_ConfigPatched extends InstanceEvent {
+  const InstanceEvent_ConfigPatched({required final  List<ConfigChange> changes}): _changes = changes,super._();
+  
+
+ final  List<ConfigChange> _changes;
+ List<ConfigChange> get changes {
+  if (_changes is EqualUnmodifiableListView) return _changes;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_changes);
+}
+
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_ConfigPatchedCopyWith<InstanceEvent_ConfigPatched> get copyWith => _$InstanceEvent_ConfigPatchedCopyWithImpl<InstanceEvent_ConfigPatched>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_ConfigPatched&&const DeepCollectionEquality().equals(other._changes, _changes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_changes));
+
+@override
+String toString() {
+  return 'InstanceEvent.configPatched(changes: $changes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_ConfigPatchedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_ConfigPatchedCopyWith(InstanceEvent_ConfigPatched value, $Res Function(InstanceEvent_ConfigPatched) _then) = _$InstanceEvent_ConfigPatchedCopyWithImpl;
+@useResult
+$Res call({
+ List<ConfigChange> changes
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_ConfigPatchedCopyWithImpl<$Res>
+    implements $InstanceEvent_ConfigPatchedCopyWith<$Res> {
+  _$InstanceEvent_ConfigPatchedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_ConfigPatched _self;
+  final $Res Function(InstanceEvent_ConfigPatched) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? changes = null,}) {
+  return _then(InstanceEvent_ConfigPatched(
+changes: null == changes ? _self._changes : changes // ignore: cast_nullable_to_non_nullable
+as List<ConfigChange>,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_ProxyCidrsUpdated extends InstanceEvent {
+  const InstanceEvent_ProxyCidrsUpdated({required final  List<String> added, required final  List<String> removed}): _added = added,_removed = removed,super._();
+  
+
+ final  List<String> _added;
+ List<String> get added {
+  if (_added is EqualUnmodifiableListView) return _added;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_added);
+}
+
+ final  List<String> _removed;
+ List<String> get removed {
+  if (_removed is EqualUnmodifiableListView) return _removed;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_removed);
+}
+
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_ProxyCidrsUpdatedCopyWith<InstanceEvent_ProxyCidrsUpdated> get copyWith => _$InstanceEvent_ProxyCidrsUpdatedCopyWithImpl<InstanceEvent_ProxyCidrsUpdated>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_ProxyCidrsUpdated&&const DeepCollectionEquality().equals(other._added, _added)&&const DeepCollectionEquality().equals(other._removed, _removed));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_added),const DeepCollectionEquality().hash(_removed));
+
+@override
+String toString() {
+  return 'InstanceEvent.proxyCidrsUpdated(added: $added, removed: $removed)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_ProxyCidrsUpdatedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_ProxyCidrsUpdatedCopyWith(InstanceEvent_ProxyCidrsUpdated value, $Res Function(InstanceEvent_ProxyCidrsUpdated) _then) = _$InstanceEvent_ProxyCidrsUpdatedCopyWithImpl;
+@useResult
+$Res call({
+ List<String> added, List<String> removed
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_ProxyCidrsUpdatedCopyWithImpl<$Res>
+    implements $InstanceEvent_ProxyCidrsUpdatedCopyWith<$Res> {
+  _$InstanceEvent_ProxyCidrsUpdatedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_ProxyCidrsUpdated _self;
+  final $Res Function(InstanceEvent_ProxyCidrsUpdated) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? added = null,Object? removed = null,}) {
+  return _then(InstanceEvent_ProxyCidrsUpdated(
+added: null == added ? _self._added : added // ignore: cast_nullable_to_non_nullable
+as List<String>,removed: null == removed ? _self._removed : removed // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+
+/// The supervisor will restart the instance after `delay_ms`.
+
+
+class InstanceEvent_InstanceRestarting extends InstanceEvent {
+  const InstanceEvent_InstanceRestarting({required this.attempt, required this.delayMs, required this.reason}): super._();
+  
+
+ final  int attempt;
+ final  BigInt delayMs;
+ final  String reason;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_InstanceRestartingCopyWith<InstanceEvent_InstanceRestarting> get copyWith => _$InstanceEvent_InstanceRestartingCopyWithImpl<InstanceEvent_InstanceRestarting>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_InstanceRestarting&&(identical(other.attempt, attempt) || other.attempt == attempt)&&(identical(other.delayMs, delayMs) || other.delayMs == delayMs)&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,attempt,delayMs,reason);
+
+@override
+String toString() {
+  return 'InstanceEvent.instanceRestarting(attempt: $attempt, delayMs: $delayMs, reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_InstanceRestartingCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_InstanceRestartingCopyWith(InstanceEvent_InstanceRestarting value, $Res Function(InstanceEvent_InstanceRestarting) _then) = _$InstanceEvent_InstanceRestartingCopyWithImpl;
+@useResult
+$Res call({
+ int attempt, BigInt delayMs, String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_InstanceRestartingCopyWithImpl<$Res>
+    implements $InstanceEvent_InstanceRestartingCopyWith<$Res> {
+  _$InstanceEvent_InstanceRestartingCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_InstanceRestarting _self;
+  final $Res Function(InstanceEvent_InstanceRestarting) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? attempt = null,Object? delayMs = null,Object? reason = null,}) {
+  return _then(InstanceEvent_InstanceRestarting(
+attempt: null == attempt ? _self.attempt : attempt // ignore: cast_nullable_to_non_nullable
+as int,delayMs: null == delayMs ? _self.delayMs : delayMs // ignore: cast_nullable_to_non_nullable
+as BigInt,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+
+class InstanceEvent_InstanceRestarted extends InstanceEvent {
+  const InstanceEvent_InstanceRestarted({required this.attempt}): super._();
+  
+
+ final  int attempt;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_InstanceRestartedCopyWith<InstanceEvent_InstanceRestarted> get copyWith => _$InstanceEvent_InstanceRestartedCopyWithImpl<InstanceEvent_InstanceRestarted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_InstanceRestarted&&(identical(other.attempt, attempt) || other.attempt == attempt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,attempt);
+
+@override
+String toString() {
+  return 'InstanceEvent.instanceRestarted(attempt: $attempt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_InstanceRestartedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_InstanceRestartedCopyWith(InstanceEvent_InstanceRestarted value, $Res Function(InstanceEvent_InstanceRestarted) _then) = _$InstanceEvent_InstanceRestartedCopyWithImpl;
+@useResult
+$Res call({
+ int attempt
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_InstanceRestartedCopyWithImpl<$Res>
+    implements $InstanceEvent_InstanceRestartedCopyWith<$Res> {
+  _$InstanceEvent_InstanceRestartedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_InstanceRestarted _self;
+  final $Res Function(InstanceEvent_InstanceRestarted) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? attempt = null,}) {
+  return _then(InstanceEvent_InstanceRestarted(
+attempt: null == attempt ? _self.attempt : attempt // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+
+/// The supervisor ran out of retries; the instance stays down.
+
+
+class InstanceEvent_InstanceRestartGaveUp extends InstanceEvent {
+  const InstanceEvent_InstanceRestartGaveUp({required this.attempts, required this.reason}): super._();
+  
+
+ final  int attempts;
+ final  String reason;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_InstanceRestartGaveUpCopyWith<InstanceEvent_InstanceRestartGaveUp> get copyWith => _$InstanceEvent_InstanceRestartGaveUpCopyWithImpl<InstanceEvent_InstanceRestartGaveUp>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_InstanceRestartGaveUp&&(identical(other.attempts, attempts) || other.attempts == attempts)&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,attempts,reason);
+
+@override
+String toString() {
+  return 'InstanceEvent.instanceRestartGaveUp(attempts: $attempts, reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_InstanceRestartGaveUpCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_InstanceRestartGaveUpCopyWith(InstanceEvent_InstanceRestartGaveUp value, $Res Function(InstanceEvent_InstanceRestartGaveUp) _then) = _$InstanceEvent_InstanceRestartGaveUpCopyWithImpl;
+@useResult
+$Res call({
+ int attempts, String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_InstanceRestartGaveUpCopyWithImpl<$Res>
+    implements $InstanceEvent_InstanceRestartGaveUpCopyWith<$Res> {
+  _$InstanceEvent_InstanceRestartGaveUpCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_InstanceRestartGaveUp _self;
+  final $Res Function(InstanceEvent_InstanceRestartGaveUp) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? attempts = null,Object? reason = null,}) {
+  return _then(InstanceEvent_InstanceRestartGaveUp(
+attempts: null == attempts ? _self.attempts : attempts // ignore: cast_nullable_to_non_nullable
+as int,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// The subscriber fell behind and `dropped` events were lost.
+
+
+class InstanceEvent_EventsLagged extends InstanceEvent {
+  const InstanceEvent_EventsLagged({required this.dropped}): super._();
+  
+
+ final  BigInt dropped;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$InstanceEvent_EventsLaggedCopyWith<InstanceEvent_EventsLagged> get copyWith => _$InstanceEvent_EventsLaggedCopyWithImpl<InstanceEvent_EventsLagged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_EventsLagged&&(identical(other.dropped, dropped) || other.dropped == dropped));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,dropped);
+
+@override
+String toString() {
+  return 'InstanceEvent.eventsLagged(dropped: $dropped)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $InstanceEvent_EventsLaggedCopyWith<$Res> implements $InstanceEventCopyWith<$Res> {
+  factory $InstanceEvent_EventsLaggedCopyWith(InstanceEvent_EventsLagged value, $Res Function(InstanceEvent_EventsLagged) _then) = _$InstanceEvent_EventsLaggedCopyWithImpl;
+@useResult
+$Res call({
+ BigInt dropped
+});
+
+
+
+
+}
+/// @nodoc
+class _$InstanceEvent_EventsLaggedCopyWithImpl<$Res>
+    implements $InstanceEvent_EventsLaggedCopyWith<$Res> {
+  _$InstanceEvent_EventsLaggedCopyWithImpl(this._self, this._then);
+
+  final InstanceEvent_EventsLagged _self;
+  final $Res Function(InstanceEvent_EventsLagged) _then;
+
+/// Create a copy of InstanceEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? dropped = null,}) {
+  return _then(InstanceEvent_EventsLagged(
+dropped: null == dropped ? _self.dropped : dropped // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+
+/// The instance's event bus closed; no further events will follow.
+
+
+class InstanceEvent_EventsClosed extends InstanceEvent {
+  const InstanceEvent_EventsClosed(): super._();
+  
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is InstanceEvent_EventsClosed);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'InstanceEvent.eventsClosed()';
+}
+
+
+}
+
+
+
+
+// dart format on
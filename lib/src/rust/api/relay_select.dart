@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `ewma`, `stop`, `watch`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SELECTORS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `default`, `deref`, `deref`, `fmt`, `fmt`, `initialize`, `initialize`
+
+/// A pool of relay servers to choose from when starting an instance.
+class RelayPool {
+  /// Candidate server URLs. Entries already in `severurl` are ignored.
+  final List<String> candidates;
+
+  /// Number of relays to connect to.
+  final BigInt bestN;
+
+  /// Seconds between re-evaluations, at least `MIN_REEVALUATE_INTERVAL_SECS`;
+  /// 0 keeps the initial choice.
+  final BigInt reevaluateIntervalSecs;
+
+  /// How much lower a candidate's score must be before it replaces a
+  /// connected relay, so close scores don't cause flapping. Must be positive.
+  final PlatformInt64 minImprovementMs;
+
+  const RelayPool({
+    required this.candidates,
+    required this.bestN,
+    required this.reevaluateIntervalSecs,
+    required this.minImprovementMs,
+  });
+
+  @override
+  int get hashCode =>
+      candidates.hashCode ^
+      bestN.hashCode ^
+      reevaluateIntervalSecs.hashCode ^
+      minImprovementMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RelayPool &&
+          runtimeType == other.runtimeType &&
+          candidates == other.candidates &&
+          bestN == other.bestN &&
+          reevaluateIntervalSecs == other.reevaluateIntervalSecs &&
+          minImprovementMs == other.minImprovementMs;
+}
@@ -0,0 +1,91 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `classify_connect_error`, `classify_handshake_error`, `classify_message`, `default_port`, `fail`, `io_error_kind`, `new`, `probe`, `probe_all`, `probe_global_ctx`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `deref`, `eq`, `fmt`, `fmt`, `initialize`
+
+/// Server probe entry for Flutter. `timeout_ms` applies to each stage and
+/// defaults to 5 seconds.
+Future<List<ServerProbeResult>> probeServers({
+  required List<String> urls,
+  BigInt? timeoutMs,
+}) => RustLib.instance.api.crateApiServerProbeProbeServers(
+  urls: urls,
+  timeoutMs: timeoutMs,
+);
+
+enum ProbeErrorKind {
+  invalidUrl,
+  dns,
+  refused,
+  timeout,
+  tls,
+  /// The transport connected but the EasyTier handshake failed.
+  handshake,
+  /// The server accepted the connection and closed it during the EasyTier
+  /// handshake. Servers that don't relay foreign networks do this for the
+  /// probe's network name, so the address itself works, for the networks
+  /// the server accepts.
+  networkRejected,
+  other,
+}
+
+class ServerProbeResult {
+  final String url;
+  final bool reachable;
+  final List<String> resolvedAddrs;
+  final PlatformInt64? dnsMs;
+
+  /// Time to establish the transport connection.
+  final PlatformInt64? connectMs;
+
+  /// Round trip of the EasyTier handshake over the established connection.
+  final PlatformInt64? rttMs;
+  final int? remotePeerId;
+  final ProbeErrorKind? errorKind;
+  final String? error;
+
+  const ServerProbeResult({
+    required this.url,
+    required this.reachable,
+    required this.resolvedAddrs,
+    this.dnsMs,
+    this.connectMs,
+    this.rttMs,
+    this.remotePeerId,
+    this.errorKind,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      reachable.hashCode ^
+      resolvedAddrs.hashCode ^
+      dnsMs.hashCode ^
+      connectMs.hashCode ^
+      rttMs.hashCode ^
+      remotePeerId.hashCode ^
+      errorKind.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ServerProbeResult &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          reachable == other.reachable &&
+          resolvedAddrs == other.resolvedAddrs &&
+          dnsMs == other.dnsMs &&
+          connectMs == other.connectMs &&
+          rttMs == other.rttMs &&
+          remotePeerId == other.remotePeerId &&
+          errorKind == other.errorKind &&
+          error == other.error;
+}
@@ -7,15 +7,12 @@ import '../frb_generated.dart';
 import 'p2p.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `create_and_store_network_instance`, `peer_conn_info_to_string`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `INSTANCE`, `RT`
+// These functions are ignored because they are not marked as `pub`: `close_default_instance`, `default_instance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DEFAULT_INSTANCE`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `deref`, `initialize`, `initialize`
 
-Future<void> sendUdpToLocalhost({required String message}) =>
-    RustLib.instance.api.crateApiSimpleSendUdpToLocalhost(message: message);
-
-Future<JoinHandle> handleEvent({required EventBusSubscriber events}) =>
-    RustLib.instance.api.crateApiSimpleHandleEvent(events: events);
+Future<String?> defaultInstanceId() =>
+    RustLib.instance.api.crateApiSimpleDefaultInstanceId();
 
 Future<String> easytierVersion() =>
     RustLib.instance.api.crateApiSimpleEasytierVersion();
@@ -286,6 +283,9 @@ class KVNodeInfo {
   final int peerId;
   final String hostname;
   final String ipv4;
+  final String? ipv6;
+  final String? publicIpv6;
+  final String? ipv6Prefix;
   final double latencyMs;
   final String nat;
   final List<NodeHopStats> hops;
@@ -303,6 +303,9 @@ class KVNodeInfo {
     required this.peerId,
     required this.hostname,
     required this.ipv4,
+    this.ipv6,
+    this.publicIpv6,
+    this.ipv6Prefix,
     required this.latencyMs,
     required this.nat,
     required this.hops,
@@ -322,6 +325,9 @@ class KVNodeInfo {
       peerId.hashCode ^
       hostname.hashCode ^
       ipv4.hashCode ^
+      ipv6.hashCode ^
+      publicIpv6.hashCode ^
+      ipv6Prefix.hashCode ^
       latencyMs.hashCode ^
       nat.hashCode ^
       hops.hashCode ^
@@ -343,6 +349,9 @@ class KVNodeInfo {
           peerId == other.peerId &&
           hostname == other.hostname &&
           ipv4 == other.ipv4 &&
+          ipv6 == other.ipv6 &&
+          publicIpv6 == other.publicIpv6 &&
+          ipv6Prefix == other.ipv6Prefix &&
           latencyMs == other.latencyMs &&
           nat == other.nat &&
           hops == other.hops &&
@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `binding_response`, `serve_tcp`, `serve_tcp_connection`, `serve_udp`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `STUN_SERVERS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `deref`, `deref`, `fmt`, `initialize`, `initialize`
+
+/// Starts a STUN server on two IPs and two ports, returns its handle.
+///
+/// Both IPs must be local addresses of the same family, e.g. two addresses
+/// of one interface or `127.0.0.1` and `127.0.0.2` for local testing.
+Future<String> startStunServer({
+  required String primaryIp,
+  required String alternateIp,
+  required int primaryPort,
+  required int alternatePort,
+}) => RustLib.instance.api.crateApiStunServerStartStunServer(
+  primaryIp: primaryIp,
+  alternateIp: alternateIp,
+  primaryPort: primaryPort,
+  alternatePort: alternatePort,
+);
+
+/// Stops a STUN server; it stays listed until removed.
+Future<void> stopStunServer({required String id}) =>
+    RustLib.instance.api.crateApiStunServerStopStunServer(id: id);
+
+Future<void> removeStunServer({required String id}) =>
+    RustLib.instance.api.crateApiStunServerRemoveStunServer(id: id);
+
+Future<List<StunServerInfo>> listStunServers() =>
+    RustLib.instance.api.crateApiStunServerListStunServers();
+
+class StunServerInfo {
+  final String id;
+  final String primaryAddr;
+  final String alternateAddr;
+  final bool running;
+
+  /// Binding requests answered so far, over UDP and TCP.
+  final BigInt requests;
+
+  const StunServerInfo({
+    required this.id,
+    required this.primaryAddr,
+    required this.alternateAddr,
+    required this.running,
+    required this.requests,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      primaryAddr.hashCode ^
+      alternateAddr.hashCode ^
+      running.hashCode ^
+      requests.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StunServerInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          primaryAddr == other.primaryAddr &&
+          alternateAddr == other.alternateAddr &&
+          running == other.running &&
+          requests == other.requests;
+}
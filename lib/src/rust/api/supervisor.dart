@@ -0,0 +1,135 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `autostart_requested`, `backoff`, `check_instance`, `is_supervised`, `next_restart`, `on_event`, `run`, `stop`, `supervise`, `update`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `RT`, `SUPERVISORS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `default`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+
+/// Supervises a running instance, restarting it according to `policy`
+/// (the default policy when `None`) if it stops unexpectedly.
+Future<void> superviseInstance({
+  required String instanceId,
+  RestartPolicy? policy,
+}) => RustLib.instance.api.crateApiSupervisorSuperviseInstance(
+  instanceId: instanceId,
+  policy: policy,
+);
+
+/// Stops supervising an instance; the instance itself keeps running.
+Future<void> unsuperviseInstance({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiSupervisorUnsuperviseInstance(instanceId: instanceId);
+
+/// Returns the supervisor status of an instance, `None` if it isn't supervised.
+Future<SupervisorStatus?> getSupervisorStatus({required String instanceId}) =>
+    RustLib.instance.api.crateApiSupervisorGetSupervisorStatus(
+      instanceId: instanceId,
+    );
+
+Future<List<SupervisorStatus>> listSupervisorStatus() =>
+    RustLib.instance.api.crateApiSupervisorListSupervisorStatus();
+
+/// When and how often a failed instance is restarted.
+class RestartPolicy {
+  /// Consecutive failed restarts before giving up; 0 retries forever.
+  final int maxRetries;
+
+  /// Delay before the first restart, doubled on each further attempt.
+  final BigInt initialBackoffMs;
+  final BigInt maxBackoffMs;
+
+  /// Also restart when the TUN device reports an error, even if the
+  /// instance itself keeps running.
+  final bool restartOnTunError;
+
+  const RestartPolicy({
+    required this.maxRetries,
+    required this.initialBackoffMs,
+    required this.maxBackoffMs,
+    required this.restartOnTunError,
+  });
+
+  @override
+  int get hashCode =>
+      maxRetries.hashCode ^
+      initialBackoffMs.hashCode ^
+      maxBackoffMs.hashCode ^
+      restartOnTunError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RestartPolicy &&
+          runtimeType == other.runtimeType &&
+          maxRetries == other.maxRetries &&
+          initialBackoffMs == other.initialBackoffMs &&
+          maxBackoffMs == other.maxBackoffMs &&
+          restartOnTunError == other.restartOnTunError;
+}
+
+enum SupervisorState {
+  running,
+  /// Waiting for the backoff delay before the next restart.
+  restarting,
+  /// `max_retries` was exceeded; the instance was removed.
+  gaveUp,
+}
+
+class SupervisorStatus {
+  final String instanceId;
+  final RestartPolicy policy;
+  final SupervisorState state;
+
+  /// Successful restarts since supervision started.
+  final int restartCount;
+  final int consecutiveFailures;
+  final String? lastFailure;
+
+  /// Unix timestamp in milliseconds.
+  final PlatformInt64? lastFailureAt;
+
+  /// Unix timestamp in milliseconds of the next restart while `Restarting`.
+  final PlatformInt64? nextRestartAt;
+
+  const SupervisorStatus({
+    required this.instanceId,
+    required this.policy,
+    required this.state,
+    required this.restartCount,
+    required this.consecutiveFailures,
+    this.lastFailure,
+    this.lastFailureAt,
+    this.nextRestartAt,
+  });
+
+  @override
+  int get hashCode =>
+      instanceId.hashCode ^
+      policy.hashCode ^
+      state.hashCode ^
+      restartCount.hashCode ^
+      consecutiveFailures.hashCode ^
+      lastFailure.hashCode ^
+      lastFailureAt.hashCode ^
+      nextRestartAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SupervisorStatus &&
+          runtimeType == other.runtimeType &&
+          instanceId == other.instanceId &&
+          policy == other.policy &&
+          state == other.state &&
+          restartCount == other.restartCount &&
+          consecutiveFailures == other.consecutiveFailures &&
+          lastFailure == other.lastFailure &&
+          lastFailureAt == other.lastFailureAt &&
+          nextRestartAt == other.nextRestartAt;
+}
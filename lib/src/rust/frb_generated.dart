@@ -3,15 +3,24 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/event_log.dart';
 import 'api/firewall.dart';
 import 'api/forward.dart';
 import 'api/hops.dart';
+import 'api/instance_patch.dart';
+import 'api/invite.dart';
 import 'api/magic_wall.dart';
+import 'api/minecraft_lan.dart';
 import 'api/multicast.dart';
 import 'api/nat_test.dart';
+import 'api/network_config.dart';
 import 'api/nt.dart';
 import 'api/p2p.dart';
+import 'api/relay_select.dart';
+import 'api/server_probe.dart';
 import 'api/simple.dart';
+import 'api/stun_server.dart';
+import 'api/supervisor.dart';
 import 'api/utils.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -73,7 +82,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => -2101453571;
+  int get rustContentHash => -458141824;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -102,6 +111,30 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiForwardForwardServerStop({required ForwardServer that});
 
+  Future<bool> crateApiMinecraftLanMinecraftLanBridgeIsRunning({
+    required MinecraftLanBridge that,
+  });
+
+  Future<void> crateApiMinecraftLanMinecraftLanBridgeStart({
+    required MinecraftLanBridge that,
+  });
+
+  Future<void> crateApiMinecraftLanMinecraftLanBridgeStop({
+    required MinecraftLanBridge that,
+  });
+
+  Future<bool> crateApiMulticastMulticastListenerIsRunning({
+    required MulticastListener that,
+  });
+
+  Future<void> crateApiMulticastMulticastListenerStart({
+    required MulticastListener that,
+  });
+
+  Future<void> crateApiMulticastMulticastListenerStop({
+    required MulticastListener that,
+  });
+
   Future<bool> crateApiMulticastMulticastSenderIsRunning({
     required MulticastSender that,
   });
@@ -130,6 +163,15 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiMagicWallAddMagicWallRule({required MagicWallRule rule});
 
+  Future<List<MagicWallRuleIssue>> crateApiMagicWallAnalyzeMagicWallRules({
+    required List<MagicWallRule> rules,
+  });
+
+  Future<FlagsC> crateApiInviteApplyInviteFlags({
+    required InviteFlags inviteFlags,
+    required FlagsC flag,
+  });
+
   Future<bool> crateApiUtilsCheckSudo();
 
   Future<void> crateApiP2PCloseServer({required String instanceId});
@@ -138,19 +180,32 @@ abstract class RustLibApi extends BaseApi {
 
   Future<List<MagicWallRule>> crateApiMagicWallCreateDefaultMagicWallRules();
 
-  Future<BigInt> crateApiForwardCreateForwardServer({
+  Future<String> crateApiForwardCreateForwardServer({
+    required String listenAddr,
+    required String forwardAddr,
+  });
+
+  Future<String> crateApiForwardCreateForwardServerWithProtocol({
     required String listenAddr,
     required String forwardAddr,
+    required String protocol,
+  });
+
+  Future<String> crateApiMulticastCreateMulticastListener({
+    required String groupAddr,
+    required int port,
+    String? interfaceAddr,
+    BigInt? capacity,
   });
 
-  Future<BigInt> crateApiMulticastCreateMulticastSender({
+  Future<String> crateApiMulticastCreateMulticastSender({
     required String multicastAddr,
     required int port,
     required List<int> data,
     required BigInt intervalMs,
   });
 
-  Future<BigInt> crateApiMulticastCreateMulticastSenderWithBind({
+  Future<String> crateApiMulticastCreateMulticastSenderWithBind({
     required String multicastAddr,
     required int port,
     required String bindAddr,
@@ -176,6 +231,10 @@ abstract class RustLibApi extends BaseApi {
     required FlagsC flag,
   });
 
+  Future<JoinHandleResultStringString> crateApiP2PCreateServerWithConfig({
+    required NetworkConfigC config,
+  });
+
   Future<JoinHandleResultStringString> crateApiP2PCreateServerWithFlags({
     required String username,
     required bool enableDhcp,
@@ -189,12 +248,41 @@ abstract class RustLibApi extends BaseApi {
     required FlagsC flag,
   });
 
+  Future<String> crateApiForwardCreateUdpForwardServer({
+    required String listenAddr,
+    required String forwardAddr,
+    required BigInt idleTimeoutSecs,
+  });
+
+  Future<RoomInvite> crateApiInviteDecodeRoomInvite({
+    required String link,
+    String? secret,
+  });
+
+  Future<String?> crateApiSimpleDefaultInstanceId();
+
   Future<String> crateApiNatTestDetectNatType({required String stunServer});
 
   Future<String> crateApiP2PEasytierVersion();
 
   Future<String> crateApiSimpleEasytierVersion();
 
+  Future<Uint8List> crateApiMinecraftLanEncodeMinecraftLanAnnouncement({
+    required String motd,
+    required int port,
+  });
+
+  Future<String> crateApiInviteEncodeRoomInvite({
+    required RoomInvite invite,
+    String? secret,
+  });
+
+  Future<String> crateApiMagicWallExportMagicWallRules();
+
+  Future<String> crateApiNetworkConfigFlagsToToml({
+    required NetworkConfigC config,
+  });
+
   Future<List<(String, int)>> crateApiHopsGetAllInterfacesMetrics();
 
   Future<bool> crateApiFirewallGetFirewallStatus({required int profileIndex});
@@ -202,7 +290,7 @@ abstract class RustLibApi extends BaseApi {
   Future<BigInt> crateApiForwardGetForwardServerCount();
 
   Future<(BigInt, BigInt, BigInt)> crateApiForwardGetForwardServerStats({
-    required BigInt index,
+    required String id,
   });
 
   Future<List<String>> crateApiP2PGetIps({required String instanceId});
@@ -211,6 +299,11 @@ abstract class RustLibApi extends BaseApi {
 
   Future<MagicWallStatus> crateApiMagicWallGetMagicWallStatus();
 
+  Future<List<MulticastPacket>> crateApiMulticastGetMulticastListenerPackets({
+    required String id,
+    BigInt? limit,
+  });
+
   Future<BigInt> crateApiMulticastGetMulticastSenderCount();
 
   Future<KVNetworkStatus> crateApiP2PGetNetworkStatus({
@@ -227,39 +320,97 @@ abstract class RustLibApi extends BaseApi {
 
   Future<List<PeerRoutePair>> crateApiSimpleGetPeerRoutePairs();
 
-  Future<String> crateApiP2PGetRunningInfo({required String instanceId});
+  Future<RunningInfo> crateApiP2PGetRunningInfo({required String instanceId});
 
   Future<String> crateApiSimpleGetRunningInfo();
 
-  Future<JoinHandle> crateApiP2PHandleEvent({
-    required EventBusSubscriber events,
+  Future<SupervisorStatus?> crateApiSupervisorGetSupervisorStatus({
+    required String instanceId,
   });
 
-  Future<JoinHandle> crateApiSimpleHandleEvent({
-    required EventBusSubscriber events,
+  Future<BigInt> crateApiMagicWallImportMagicWallRules({
+    required String json,
+    required bool replace,
   });
 
   Future<void> crateApiP2PInitApp();
 
   Future<void> crateApiSimpleInitApp();
 
+  Future<void> crateApiEventLogInitEventLog({required String dir});
+
+  Future<void> crateApiMagicWallInitMagicWallStore({required String dir});
+
+  Future<InviteFlags> crateApiInviteInviteFlagsFrom({required FlagsC flag});
+
   Future<bool> crateApiP2PIsEasytierRunning({required String instanceId});
 
   Future<bool> crateApiSimpleIsEasytierRunning();
 
-  Future<bool> crateApiForwardIsForwardServerRunning({required BigInt index});
+  Future<bool> crateApiForwardIsForwardServerRunning({required String id});
+
+  Future<bool> crateApiMulticastIsMulticastSenderRunning({required String id});
 
-  Future<bool> crateApiMulticastIsMulticastSenderRunning({
-    required BigInt index,
+  Future<void> crateApiForwardKillForwardConnection({
+    required String id,
+    required BigInt connectionId,
   });
 
+  Future<List<ForwardConnectionInfo>> crateApiForwardListForwardConnections({
+    required String id,
+  });
+
+  Future<List<ForwardServerInfo>> crateApiForwardListForwardServers();
+
+  Future<List<String>> crateApiEventLogListLoggedInstances();
+
+  Future<List<MinecraftLanWorld>> crateApiMinecraftLanListMinecraftLanWorlds({
+    required String id,
+  });
+
+  Future<List<MulticastListenerInfo>> crateApiMulticastListMulticastListeners();
+
+  Future<List<MulticastSenderInfo>> crateApiMulticastListMulticastSenders();
+
+  Future<List<StunServerInfo>> crateApiStunServerListStunServers();
+
+  Future<List<SupervisorStatus>> crateApiSupervisorListSupervisorStatus();
+
   Future<String> crateApiNatTestNatTypeGetDescription({required NatType that});
 
+  Future<MinecraftLanAnnouncement?>
+  crateApiMinecraftLanParseMinecraftLanAnnouncement({
+    required List<int> data,
+  });
+
+  Future<void> crateApiInstancePatchPatchInstance({
+    required String instanceId,
+    required InstancePatch patch,
+  });
+
+  Future<List<ServerProbeResult>> crateApiServerProbeProbeServers({
+    required List<String> urls,
+    BigInt? timeoutMs,
+  });
+
+  Future<BigInt> crateApiMagicWallPurgeMagicWallFilters();
+
+  Future<List<InstanceEventRecord>> crateApiEventLogQueryInstanceEvents({
+    required String instanceId,
+    PlatformInt64? since,
+    List<String>? kinds,
+    BigInt? limit,
+  });
+
+  Future<void> crateApiForwardRemoveForwardServer({required String id});
+
   Future<void> crateApiMagicWallRemoveMagicWallRule({required String ruleId});
 
-  Future<void> crateApiP2PSendUdpToLocalhost({required String message});
+  Future<void> crateApiMulticastRemoveMulticastListener({required String id});
+
+  Future<void> crateApiMulticastRemoveMulticastSender({required String id});
 
-  Future<void> crateApiSimpleSendUdpToLocalhost({required String message});
+  Future<void> crateApiStunServerRemoveStunServer({required String id});
 
   Future<void> crateApiFirewallSetFirewallStatus({
     required int profileIndex,
@@ -278,69 +429,122 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiSimpleSetTunFd({required int fd});
 
+  Future<MagicWallVerdict> crateApiMagicWallSimulateMagicWall({
+    required List<MagicWallRule> rules,
+    required MagicWallPacket packet,
+  });
+
   Future<void> crateApiMagicWallStartMagicWall();
 
+  Future<String> crateApiMinecraftLanStartMinecraftLanBridge({
+    required String virtualIp,
+    String? lanBindAddr,
+  });
+
+  Future<String> crateApiStunServerStartStunServer({
+    required String primaryIp,
+    required String alternateIp,
+    required int primaryPort,
+    required int alternatePort,
+  });
+
   Future<void> crateApiForwardStopAllForwardServers();
 
   Future<void> crateApiMulticastStopAllMulticastSenders();
 
-  Future<void> crateApiForwardStopForwardServer({required BigInt index});
+  Future<void> crateApiForwardStopForwardServer({required String id});
 
   Future<void> crateApiMagicWallStopMagicWall();
 
-  Future<void> crateApiMulticastStopMulticastSender({required BigInt index});
+  Future<void> crateApiMinecraftLanStopMinecraftLanBridge({required String id});
+
+  Future<void> crateApiMulticastStopMulticastListener({required String id});
+
+  Future<void> crateApiMulticastStopMulticastSender({required String id});
+
+  Future<void> crateApiStunServerStopStunServer({required String id});
+
+  Stream<InstanceEventRecord> crateApiP2PSubscribeInstanceEvents({
+    required String instanceId,
+  });
+
+  Stream<MulticastPacket> crateApiMulticastSubscribeMulticastListener({
+    required String id,
+  });
+
+  Future<void> crateApiSupervisorSuperviseInstance({
+    required String instanceId,
+    RestartPolicy? policy,
+  });
+
+  Future<List<NatBehaviorResult>> crateApiNatTestTestNatBehavior({
+    required String stunServer,
+  });
 
   Future<NetworkTestResult> crateApiNatTestTestNetworkConnectivity({
     required String stunServer,
   });
 
+  Future<NetworkConfigC> crateApiNetworkConfigTomlToFlags({
+    required String toml,
+  });
+
+  Future<void> crateApiSupervisorUnsuperviseInstance({
+    required String instanceId,
+  });
+
   Future<void> crateApiMagicWallUpdateMagicWallRule({
     required MagicWallRule rule,
   });
 
+  Future<List<ConfigIssue>> crateApiNetworkConfigValidateNetworkConfig({
+    required NetworkConfigC config,
+  });
+
   RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_EventBusSubscriber;
+  get rust_arc_increment_strong_count_ForwardServer;
 
   RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_EventBusSubscriber;
+  get rust_arc_decrement_strong_count_ForwardServer;
 
   CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_EventBusSubscriberPtr;
+  get rust_arc_decrement_strong_count_ForwardServerPtr;
 
   RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ForwardServer;
+  get rust_arc_increment_strong_count_JoinHandleResultString;
 
   RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ForwardServer;
+  get rust_arc_decrement_strong_count_JoinHandleResultString;
 
   CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ForwardServerPtr;
+  get rust_arc_decrement_strong_count_JoinHandleResultStringPtr;
 
   RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandle;
+  get rust_arc_increment_strong_count_JoinHandleResultStringString;
 
   RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandle;
+  get rust_arc_decrement_strong_count_JoinHandleResultStringString;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_JoinHandlePtr;
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_JoinHandleResultStringStringPtr;
 
   RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandleResultString;
+  get rust_arc_increment_strong_count_MinecraftLanBridge;
 
   RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandleResultString;
+  get rust_arc_decrement_strong_count_MinecraftLanBridge;
 
   CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_JoinHandleResultStringPtr;
+  get rust_arc_decrement_strong_count_MinecraftLanBridgePtr;
 
   RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandleResultStringString;
+  get rust_arc_increment_strong_count_MulticastListener;
 
   RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandleResultStringString;
+  get rust_arc_decrement_strong_count_MulticastListener;
 
   CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_JoinHandleResultStringStringPtr;
+  get rust_arc_decrement_strong_count_MulticastListenerPtr;
 
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_MulticastSender;
@@ -548,14 +752,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "ForwardServer_stop", argNames: ["that"]);
 
   @override
-  Future<bool> crateApiMulticastMulticastSenderIsRunning({
-    required MulticastSender that,
+  Future<bool> crateApiMinecraftLanMinecraftLanBridgeIsRunning({
+    required MinecraftLanBridge that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
             that,
             serializer,
           );
@@ -570,28 +774,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMulticastMulticastSenderIsRunningConstMeta,
+        constMeta: kCrateApiMinecraftLanMinecraftLanBridgeIsRunningConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMulticastMulticastSenderIsRunningConstMeta =>
+  TaskConstMeta get kCrateApiMinecraftLanMinecraftLanBridgeIsRunningConstMeta =>
       const TaskConstMeta(
-        debugName: "MulticastSender_is_running",
+        debugName: "MinecraftLanBridge_is_running",
         argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiMulticastMulticastSenderStart({
-    required MulticastSender that,
+  Future<void> crateApiMinecraftLanMinecraftLanBridgeStart({
+    required MinecraftLanBridge that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
             that,
             serializer,
           );
@@ -606,28 +810,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMulticastMulticastSenderStartConstMeta,
+        constMeta: kCrateApiMinecraftLanMinecraftLanBridgeStartConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMulticastMulticastSenderStartConstMeta =>
+  TaskConstMeta get kCrateApiMinecraftLanMinecraftLanBridgeStartConstMeta =>
       const TaskConstMeta(
-        debugName: "MulticastSender_start",
+        debugName: "MinecraftLanBridge_start",
         argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiMulticastMulticastSenderStop({
-    required MulticastSender that,
+  Future<void> crateApiMinecraftLanMinecraftLanBridgeStop({
+    required MinecraftLanBridge that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
             that,
             serializer,
           );
@@ -642,28 +846,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMulticastMulticastSenderStopConstMeta,
+        constMeta: kCrateApiMinecraftLanMinecraftLanBridgeStopConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMulticastMulticastSenderStopConstMeta =>
+  TaskConstMeta get kCrateApiMinecraftLanMinecraftLanBridgeStopConstMeta =>
       const TaskConstMeta(
-        debugName: "MulticastSender_stop",
+        debugName: "MinecraftLanBridge_stop",
         argNames: ["that"],
       );
 
   @override
-  Future<BigInt> crateApiForwardServerStatsGetBytesReceived({
-    required ServerStats that,
+  Future<bool> crateApiMulticastMulticastListenerIsRunning({
+    required MulticastListener that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
             that,
             serializer,
           );
@@ -675,31 +879,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiForwardServerStatsGetBytesReceivedConstMeta,
+        constMeta: kCrateApiMulticastMulticastListenerIsRunningConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardServerStatsGetBytesReceivedConstMeta =>
+  TaskConstMeta get kCrateApiMulticastMulticastListenerIsRunningConstMeta =>
       const TaskConstMeta(
-        debugName: "ServerStats_get_bytes_received",
+        debugName: "MulticastListener_is_running",
         argNames: ["that"],
       );
 
   @override
-  Future<BigInt> crateApiForwardServerStatsGetBytesSent({
-    required ServerStats that,
+  Future<void> crateApiMulticastMulticastListenerStart({
+    required MulticastListener that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
             that,
             serializer,
           );
@@ -711,31 +915,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiForwardServerStatsGetBytesSentConstMeta,
+        constMeta: kCrateApiMulticastMulticastListenerStartConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardServerStatsGetBytesSentConstMeta =>
+  TaskConstMeta get kCrateApiMulticastMulticastListenerStartConstMeta =>
       const TaskConstMeta(
-        debugName: "ServerStats_get_bytes_sent",
+        debugName: "MulticastListener_start",
         argNames: ["that"],
       );
 
   @override
-  Future<BigInt> crateApiForwardServerStatsGetConnections({
-    required ServerStats that,
+  Future<void> crateApiMulticastMulticastListenerStop({
+    required MulticastListener that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
             that,
             serializer,
           );
@@ -747,28 +951,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiForwardServerStatsGetConnectionsConstMeta,
+        constMeta: kCrateApiMulticastMulticastListenerStopConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardServerStatsGetConnectionsConstMeta =>
+  TaskConstMeta get kCrateApiMulticastMulticastListenerStopConstMeta =>
       const TaskConstMeta(
-        debugName: "ServerStats_get_connections",
+        debugName: "MulticastListener_stop",
         argNames: ["that"],
       );
 
   @override
-  Future<ServerStats> crateApiForwardServerStatsNew() {
+  Future<bool> crateApiMulticastMulticastSenderIsRunning({
+    required MulticastSender that,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -777,29 +987,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiForwardServerStatsNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMulticastMulticastSenderIsRunningConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardServerStatsNewConstMeta =>
-      const TaskConstMeta(debugName: "ServerStats_new", argNames: []);
+  TaskConstMeta get kCrateApiMulticastMulticastSenderIsRunningConstMeta =>
+      const TaskConstMeta(
+        debugName: "MulticastSender_is_running",
+        argNames: ["that"],
+      );
 
   @override
-  Future<void> crateApiMagicWallAddMagicWallRule({
-    required MagicWallRule rule,
+  Future<void> crateApiMulticastMulticastSenderStart({
+    required MulticastSender that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_magic_wall_rule(rule, serializer);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -809,24 +1024,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMagicWallAddMagicWallRuleConstMeta,
-        argValues: [rule],
+        constMeta: kCrateApiMulticastMulticastSenderStartConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMagicWallAddMagicWallRuleConstMeta =>
-      const TaskConstMeta(debugName: "add_magic_wall_rule", argNames: ["rule"]);
+  TaskConstMeta get kCrateApiMulticastMulticastSenderStartConstMeta =>
+      const TaskConstMeta(
+        debugName: "MulticastSender_start",
+        argNames: ["that"],
+      );
 
   @override
-  Future<bool> crateApiUtilsCheckSudo() {
+  Future<void> crateApiMulticastMulticastSenderStop({
+    required MulticastSender that,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -835,26 +1059,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiUtilsCheckSudoConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMulticastMulticastSenderStopConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsCheckSudoConstMeta =>
-      const TaskConstMeta(debugName: "check_sudo", argNames: []);
+  TaskConstMeta get kCrateApiMulticastMulticastSenderStopConstMeta =>
+      const TaskConstMeta(
+        debugName: "MulticastSender_stop",
+        argNames: ["that"],
+      );
 
   @override
-  Future<void> crateApiP2PCloseServer({required String instanceId}) {
+  Future<BigInt> crateApiForwardServerStatsGetBytesReceived({
+    required ServerStats that,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(instanceId, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -863,26 +1095,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiP2PCloseServerConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiForwardServerStatsGetBytesReceivedConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PCloseServerConstMeta =>
-      const TaskConstMeta(debugName: "close_server", argNames: ["instanceId"]);
+  TaskConstMeta get kCrateApiForwardServerStatsGetBytesReceivedConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerStats_get_bytes_received",
+        argNames: ["that"],
+      );
 
   @override
-  Future<void> crateApiSimpleCloseServer() {
+  Future<BigInt> crateApiForwardServerStatsGetBytesSent({
+    required ServerStats that,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
             funcId: 16,
@@ -890,25 +1131,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleCloseServerConstMeta,
-        argValues: [],
+        constMeta: kCrateApiForwardServerStatsGetBytesSentConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleCloseServerConstMeta =>
-      const TaskConstMeta(debugName: "close_server", argNames: []);
+  TaskConstMeta get kCrateApiForwardServerStatsGetBytesSentConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerStats_get_bytes_sent",
+        argNames: ["that"],
+      );
 
   @override
-  Future<List<MagicWallRule>> crateApiMagicWallCreateDefaultMagicWallRules() {
+  Future<BigInt> crateApiForwardServerStatsGetConnections({
+    required ServerStats that,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -917,33 +1167,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_magic_wall_rule,
+          decodeSuccessData: sse_decode_usize,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMagicWallCreateDefaultMagicWallRulesConstMeta,
-        argValues: [],
+        constMeta: kCrateApiForwardServerStatsGetConnectionsConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMagicWallCreateDefaultMagicWallRulesConstMeta =>
+  TaskConstMeta get kCrateApiForwardServerStatsGetConnectionsConstMeta =>
       const TaskConstMeta(
-        debugName: "create_default_magic_wall_rules",
-        argNames: [],
+        debugName: "ServerStats_get_connections",
+        argNames: ["that"],
       );
 
   @override
-  Future<BigInt> crateApiForwardCreateForwardServer({
-    required String listenAddr,
-    required String forwardAddr,
-  }) {
+  Future<ServerStats> crateApiForwardServerStatsNew() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(listenAddr, serializer);
-          sse_encode_String(forwardAddr, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -952,37 +1197,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiForwardCreateForwardServerConstMeta,
-        argValues: [listenAddr, forwardAddr],
+        constMeta: kCrateApiForwardServerStatsNewConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardCreateForwardServerConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_forward_server",
-        argNames: ["listenAddr", "forwardAddr"],
-      );
+  TaskConstMeta get kCrateApiForwardServerStatsNewConstMeta =>
+      const TaskConstMeta(debugName: "ServerStats_new", argNames: []);
 
   @override
-  Future<BigInt> crateApiMulticastCreateMulticastSender({
-    required String multicastAddr,
-    required int port,
-    required List<int> data,
-    required BigInt intervalMs,
+  Future<void> crateApiMagicWallAddMagicWallRule({
+    required MagicWallRule rule,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(multicastAddr, serializer);
-          sse_encode_u_16(port, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          sse_encode_u_64(intervalMs, serializer);
+          sse_encode_box_autoadd_magic_wall_rule(rule, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -991,39 +1228,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiMulticastCreateMulticastSenderConstMeta,
-        argValues: [multicastAddr, port, data, intervalMs],
+        constMeta: kCrateApiMagicWallAddMagicWallRuleConstMeta,
+        argValues: [rule],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMulticastCreateMulticastSenderConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_multicast_sender",
-        argNames: ["multicastAddr", "port", "data", "intervalMs"],
-      );
+  TaskConstMeta get kCrateApiMagicWallAddMagicWallRuleConstMeta =>
+      const TaskConstMeta(debugName: "add_magic_wall_rule", argNames: ["rule"]);
 
   @override
-  Future<BigInt> crateApiMulticastCreateMulticastSenderWithBind({
-    required String multicastAddr,
-    required int port,
-    required String bindAddr,
-    required List<int> data,
-    required BigInt intervalMs,
+  Future<List<MagicWallRuleIssue>> crateApiMagicWallAnalyzeMagicWallRules({
+    required List<MagicWallRule> rules,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(multicastAddr, serializer);
-          sse_encode_u_16(port, serializer);
-          sse_encode_String(bindAddr, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          sse_encode_u_64(intervalMs, serializer);
+          sse_encode_list_magic_wall_rule(rules, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1032,33 +1258,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
+          decodeSuccessData: sse_decode_list_magic_wall_rule_issue,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiMulticastCreateMulticastSenderWithBindConstMeta,
-        argValues: [multicastAddr, port, bindAddr, data, intervalMs],
+        constMeta: kCrateApiMagicWallAnalyzeMagicWallRulesConstMeta,
+        argValues: [rules],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMulticastCreateMulticastSenderWithBindConstMeta =>
+  TaskConstMeta get kCrateApiMagicWallAnalyzeMagicWallRulesConstMeta =>
       const TaskConstMeta(
-        debugName: "create_multicast_sender_with_bind",
-        argNames: ["multicastAddr", "port", "bindAddr", "data", "intervalMs"],
+        debugName: "analyze_magic_wall_rules",
+        argNames: ["rules"],
       );
 
   @override
-  Future<JoinHandleResultStringString> crateApiP2PCreateServer({
-    required String configToml,
-    required bool watchEvent,
+  Future<FlagsC> crateApiInviteApplyInviteFlags({
+    required InviteFlags inviteFlags,
+    required FlagsC flag,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(configToml, serializer);
-          sse_encode_bool(watchEvent, serializer);
+          sse_encode_box_autoadd_invite_flags(inviteFlags, serializer);
+          sse_encode_box_autoadd_flags_c(flag, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1067,49 +1293,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString,
+          decodeSuccessData: sse_decode_flags_c,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiP2PCreateServerConstMeta,
-        argValues: [configToml, watchEvent],
+        constMeta: kCrateApiInviteApplyInviteFlagsConstMeta,
+        argValues: [inviteFlags, flag],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PCreateServerConstMeta => const TaskConstMeta(
-    debugName: "create_server",
-    argNames: ["configToml", "watchEvent"],
-  );
+  TaskConstMeta get kCrateApiInviteApplyInviteFlagsConstMeta =>
+      const TaskConstMeta(
+        debugName: "apply_invite_flags",
+        argNames: ["inviteFlags", "flag"],
+      );
 
   @override
-  Future<JoinHandleResultString> crateApiSimpleCreateServer({
-    required String username,
-    required bool enableDhcp,
-    required String specifiedIp,
-    required String roomName,
-    required String roomPassword,
-    required List<String> severurl,
-    required List<String> onurl,
-    required List<String> cidrs,
-    required List<Forward> forwards,
-    required FlagsC flag,
-  }) {
+  Future<bool> crateApiUtilsCheckSudo() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(username, serializer);
-          sse_encode_bool(enableDhcp, serializer);
-          sse_encode_String(specifiedIp, serializer);
-          sse_encode_String(roomName, serializer);
-          sse_encode_String(roomPassword, serializer);
-          sse_encode_list_String(severurl, serializer);
-          sse_encode_list_String(onurl, serializer);
-          sse_encode_list_String(cidrs, serializer);
-          sse_encode_list_forward(forwards, serializer);
-          sse_encode_box_autoadd_flags_c(flag, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1118,71 +1323,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleCreateServerConstMeta,
-        argValues: [
-          username,
-          enableDhcp,
-          specifiedIp,
-          roomName,
-          roomPassword,
-          severurl,
-          onurl,
-          cidrs,
-          forwards,
-          flag,
-        ],
+        constMeta: kCrateApiUtilsCheckSudoConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleCreateServerConstMeta => const TaskConstMeta(
-    debugName: "create_server",
-    argNames: [
-      "username",
-      "enableDhcp",
-      "specifiedIp",
-      "roomName",
-      "roomPassword",
-      "severurl",
-      "onurl",
-      "cidrs",
-      "forwards",
-      "flag",
-    ],
-  );
+  TaskConstMeta get kCrateApiUtilsCheckSudoConstMeta =>
+      const TaskConstMeta(debugName: "check_sudo", argNames: []);
 
   @override
-  Future<JoinHandleResultStringString> crateApiP2PCreateServerWithFlags({
-    required String username,
-    required bool enableDhcp,
-    required String specifiedIp,
-    required String roomName,
-    required String roomPassword,
-    required List<String> severurl,
-    required List<String> onurl,
-    required List<String> cidrs,
-    required List<Forward> forwards,
-    required FlagsC flag,
-  }) {
+  Future<void> crateApiP2PCloseServer({required String instanceId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(username, serializer);
-          sse_encode_bool(enableDhcp, serializer);
-          sse_encode_String(specifiedIp, serializer);
-          sse_encode_String(roomName, serializer);
-          sse_encode_String(roomPassword, serializer);
-          sse_encode_list_String(severurl, serializer);
-          sse_encode_list_String(onurl, serializer);
-          sse_encode_list_String(cidrs, serializer);
-          sse_encode_list_forward(forwards, serializer);
-          sse_encode_box_autoadd_flags_c(flag, serializer);
+          sse_encode_String(instanceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1191,52 +1351,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PCreateServerWithFlagsConstMeta,
-        argValues: [
-          username,
-          enableDhcp,
-          specifiedIp,
-          roomName,
-          roomPassword,
-          severurl,
-          onurl,
-          cidrs,
-          forwards,
-          flag,
-        ],
+        constMeta: kCrateApiP2PCloseServerConstMeta,
+        argValues: [instanceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PCreateServerWithFlagsConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_server_with_flags",
-        argNames: [
-          "username",
-          "enableDhcp",
-          "specifiedIp",
-          "roomName",
-          "roomPassword",
-          "severurl",
-          "onurl",
-          "cidrs",
-          "forwards",
-          "flag",
-        ],
-      );
+  TaskConstMeta get kCrateApiP2PCloseServerConstMeta =>
+      const TaskConstMeta(debugName: "close_server", argNames: ["instanceId"]);
 
   @override
-  Future<String> crateApiNatTestDetectNatType({required String stunServer}) {
+  Future<void> crateApiSimpleCloseServer() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(stunServer, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1245,24 +1378,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiNatTestDetectNatTypeConstMeta,
-        argValues: [stunServer],
+        constMeta: kCrateApiSimpleCloseServerConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNatTestDetectNatTypeConstMeta =>
-      const TaskConstMeta(
-        debugName: "detect_nat_type",
-        argNames: ["stunServer"],
-      );
+  TaskConstMeta get kCrateApiSimpleCloseServerConstMeta =>
+      const TaskConstMeta(debugName: "close_server", argNames: []);
 
   @override
-  Future<String> crateApiP2PEasytierVersion() {
+  Future<List<MagicWallRule>> crateApiMagicWallCreateDefaultMagicWallRules() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1275,25 +1405,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_magic_wall_rule,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiP2PEasytierVersionConstMeta,
+        constMeta: kCrateApiMagicWallCreateDefaultMagicWallRulesConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PEasytierVersionConstMeta =>
-      const TaskConstMeta(debugName: "easytier_version", argNames: []);
+  TaskConstMeta get kCrateApiMagicWallCreateDefaultMagicWallRulesConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_default_magic_wall_rules",
+        argNames: [],
+      );
 
   @override
-  Future<String> crateApiSimpleEasytierVersion() {
+  Future<String> crateApiForwardCreateForwardServer({
+    required String listenAddr,
+    required String forwardAddr,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(listenAddr, serializer);
+          sse_encode_String(forwardAddr, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1305,22 +1443,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleEasytierVersionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiForwardCreateForwardServerConstMeta,
+        argValues: [listenAddr, forwardAddr],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleEasytierVersionConstMeta =>
-      const TaskConstMeta(debugName: "easytier_version", argNames: []);
+  TaskConstMeta get kCrateApiForwardCreateForwardServerConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_forward_server",
+        argNames: ["listenAddr", "forwardAddr"],
+      );
 
   @override
-  Future<List<(String, int)>> crateApiHopsGetAllInterfacesMetrics() {
+  Future<String> crateApiForwardCreateForwardServerWithProtocol({
+    required String listenAddr,
+    required String forwardAddr,
+    required String protocol,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(listenAddr, serializer);
+          sse_encode_String(forwardAddr, serializer);
+          sse_encode_String(protocol, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1329,29 +1477,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_record_string_u_32,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiHopsGetAllInterfacesMetricsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiForwardCreateForwardServerWithProtocolConstMeta,
+        argValues: [listenAddr, forwardAddr, protocol],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHopsGetAllInterfacesMetricsConstMeta =>
+  TaskConstMeta get kCrateApiForwardCreateForwardServerWithProtocolConstMeta =>
       const TaskConstMeta(
-        debugName: "get_all_interfaces_metrics",
-        argNames: [],
+        debugName: "create_forward_server_with_protocol",
+        argNames: ["listenAddr", "forwardAddr", "protocol"],
       );
 
   @override
-  Future<bool> crateApiFirewallGetFirewallStatus({required int profileIndex}) {
+  Future<String> crateApiMulticastCreateMulticastListener({
+    required String groupAddr,
+    required int port,
+    String? interfaceAddr,
+    BigInt? capacity,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(profileIndex, serializer);
+          sse_encode_String(groupAddr, serializer);
+          sse_encode_u_16(port, serializer);
+          sse_encode_opt_String(interfaceAddr, serializer);
+          sse_encode_opt_box_autoadd_usize(capacity, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1360,28 +1516,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiFirewallGetFirewallStatusConstMeta,
-        argValues: [profileIndex],
+        constMeta: kCrateApiMulticastCreateMulticastListenerConstMeta,
+        argValues: [groupAddr, port, interfaceAddr, capacity],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiFirewallGetFirewallStatusConstMeta =>
+  TaskConstMeta get kCrateApiMulticastCreateMulticastListenerConstMeta =>
       const TaskConstMeta(
-        debugName: "get_firewall_status",
-        argNames: ["profileIndex"],
+        debugName: "create_multicast_listener",
+        argNames: ["groupAddr", "port", "interfaceAddr", "capacity"],
       );
 
   @override
-  Future<BigInt> crateApiForwardGetForwardServerCount() {
+  Future<String> crateApiMulticastCreateMulticastSender({
+    required String multicastAddr,
+    required int port,
+    required List<int> data,
+    required BigInt intervalMs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(multicastAddr, serializer);
+          sse_encode_u_16(port, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_u_64(intervalMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1390,28 +1555,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiForwardGetForwardServerCountConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMulticastCreateMulticastSenderConstMeta,
+        argValues: [multicastAddr, port, data, intervalMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardGetForwardServerCountConstMeta =>
-      const TaskConstMeta(debugName: "get_forward_server_count", argNames: []);
+  TaskConstMeta get kCrateApiMulticastCreateMulticastSenderConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_multicast_sender",
+        argNames: ["multicastAddr", "port", "data", "intervalMs"],
+      );
 
   @override
-  Future<(BigInt, BigInt, BigInt)> crateApiForwardGetForwardServerStats({
-    required BigInt index,
+  Future<String> crateApiMulticastCreateMulticastSenderWithBind({
+    required String multicastAddr,
+    required int port,
+    required String bindAddr,
+    required List<int> data,
+    required BigInt intervalMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_usize(index, serializer);
+          sse_encode_String(multicastAddr, serializer);
+          sse_encode_u_16(port, serializer);
+          sse_encode_String(bindAddr, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_u_64(intervalMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1420,29 +1596,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_record_usize_u_64_u_64,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiForwardGetForwardServerStatsConstMeta,
-        argValues: [index],
+        constMeta: kCrateApiMulticastCreateMulticastSenderWithBindConstMeta,
+        argValues: [multicastAddr, port, bindAddr, data, intervalMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardGetForwardServerStatsConstMeta =>
+  TaskConstMeta get kCrateApiMulticastCreateMulticastSenderWithBindConstMeta =>
       const TaskConstMeta(
-        debugName: "get_forward_server_stats",
-        argNames: ["index"],
+        debugName: "create_multicast_sender_with_bind",
+        argNames: ["multicastAddr", "port", "bindAddr", "data", "intervalMs"],
       );
 
   @override
-  Future<List<String>> crateApiP2PGetIps({required String instanceId}) {
+  Future<JoinHandleResultStringString> crateApiP2PCreateServer({
+    required String configToml,
+    required bool watchEvent,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(instanceId, serializer);
+          sse_encode_String(configToml, serializer);
+          sse_encode_bool(watchEvent, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1451,25 +1631,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiP2PGetIpsConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiP2PCreateServerConstMeta,
+        argValues: [configToml, watchEvent],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PGetIpsConstMeta =>
-      const TaskConstMeta(debugName: "get_ips", argNames: ["instanceId"]);
+  TaskConstMeta get kCrateApiP2PCreateServerConstMeta => const TaskConstMeta(
+    debugName: "create_server",
+    argNames: ["configToml", "watchEvent"],
+  );
 
   @override
-  Future<List<String>> crateApiSimpleGetIps() {
+  Future<JoinHandleResultString> crateApiSimpleCreateServer({
+    required String username,
+    required bool enableDhcp,
+    required String specifiedIp,
+    required String roomName,
+    required String roomPassword,
+    required List<String> severurl,
+    required List<String> onurl,
+    required List<String> cidrs,
+    required List<Forward> forwards,
+    required FlagsC flag,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(username, serializer);
+          sse_encode_bool(enableDhcp, serializer);
+          sse_encode_String(specifiedIp, serializer);
+          sse_encode_String(roomName, serializer);
+          sse_encode_String(roomPassword, serializer);
+          sse_encode_list_String(severurl, serializer);
+          sse_encode_list_String(onurl, serializer);
+          sse_encode_list_String(cidrs, serializer);
+          sse_encode_list_forward(forwards, serializer);
+          sse_encode_box_autoadd_flags_c(flag, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1478,25 +1682,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGetIpsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSimpleCreateServerConstMeta,
+        argValues: [
+          username,
+          enableDhcp,
+          specifiedIp,
+          roomName,
+          roomPassword,
+          severurl,
+          onurl,
+          cidrs,
+          forwards,
+          flag,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetIpsConstMeta =>
-      const TaskConstMeta(debugName: "get_ips", argNames: []);
+  TaskConstMeta get kCrateApiSimpleCreateServerConstMeta => const TaskConstMeta(
+    debugName: "create_server",
+    argNames: [
+      "username",
+      "enableDhcp",
+      "specifiedIp",
+      "roomName",
+      "roomPassword",
+      "severurl",
+      "onurl",
+      "cidrs",
+      "forwards",
+      "flag",
+    ],
+  );
 
   @override
-  Future<MagicWallStatus> crateApiMagicWallGetMagicWallStatus() {
+  Future<JoinHandleResultStringString> crateApiP2PCreateServerWithConfig({
+    required NetworkConfigC config,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_network_config_c(config, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1505,25 +1737,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_magic_wall_status,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMagicWallGetMagicWallStatusConstMeta,
-        argValues: [],
+        constMeta: kCrateApiP2PCreateServerWithConfigConstMeta,
+        argValues: [config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMagicWallGetMagicWallStatusConstMeta =>
-      const TaskConstMeta(debugName: "get_magic_wall_status", argNames: []);
+  TaskConstMeta get kCrateApiP2PCreateServerWithConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_server_with_config",
+        argNames: ["config"],
+      );
 
   @override
-  Future<BigInt> crateApiMulticastGetMulticastSenderCount() {
+  Future<JoinHandleResultStringString> crateApiP2PCreateServerWithFlags({
+    required String username,
+    required bool enableDhcp,
+    required String specifiedIp,
+    required String roomName,
+    required String roomPassword,
+    required List<String> severurl,
+    required List<String> onurl,
+    required List<String> cidrs,
+    required List<Forward> forwards,
+    required FlagsC flag,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(username, serializer);
+          sse_encode_bool(enableDhcp, serializer);
+          sse_encode_String(specifiedIp, serializer);
+          sse_encode_String(roomName, serializer);
+          sse_encode_String(roomPassword, serializer);
+          sse_encode_list_String(severurl, serializer);
+          sse_encode_list_String(onurl, serializer);
+          sse_encode_list_String(cidrs, serializer);
+          sse_encode_list_forward(forwards, serializer);
+          sse_encode_box_autoadd_flags_c(flag, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1532,31 +1789,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMulticastGetMulticastSenderCountConstMeta,
-        argValues: [],
+        constMeta: kCrateApiP2PCreateServerWithFlagsConstMeta,
+        argValues: [
+          username,
+          enableDhcp,
+          specifiedIp,
+          roomName,
+          roomPassword,
+          severurl,
+          onurl,
+          cidrs,
+          forwards,
+          flag,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMulticastGetMulticastSenderCountConstMeta =>
+  TaskConstMeta get kCrateApiP2PCreateServerWithFlagsConstMeta =>
       const TaskConstMeta(
-        debugName: "get_multicast_sender_count",
-        argNames: [],
+        debugName: "create_server_with_flags",
+        argNames: [
+          "username",
+          "enableDhcp",
+          "specifiedIp",
+          "roomName",
+          "roomPassword",
+          "severurl",
+          "onurl",
+          "cidrs",
+          "forwards",
+          "flag",
+        ],
       );
 
   @override
-  Future<KVNetworkStatus> crateApiP2PGetNetworkStatus({
-    required String instanceId,
+  Future<String> crateApiForwardCreateUdpForwardServer({
+    required String listenAddr,
+    required String forwardAddr,
+    required BigInt idleTimeoutSecs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(instanceId, serializer);
+          sse_encode_String(listenAddr, serializer);
+          sse_encode_String(forwardAddr, serializer);
+          sse_encode_u_64(idleTimeoutSecs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1565,28 +1849,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_kv_network_status,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PGetNetworkStatusConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiForwardCreateUdpForwardServerConstMeta,
+        argValues: [listenAddr, forwardAddr, idleTimeoutSecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PGetNetworkStatusConstMeta =>
+  TaskConstMeta get kCrateApiForwardCreateUdpForwardServerConstMeta =>
       const TaskConstMeta(
-        debugName: "get_network_status",
-        argNames: ["instanceId"],
+        debugName: "create_udp_forward_server",
+        argNames: ["listenAddr", "forwardAddr", "idleTimeoutSecs"],
       );
 
   @override
-  Future<KVNetworkStatus> crateApiSimpleGetNetworkStatus() {
+  Future<RoomInvite> crateApiInviteDecodeRoomInvite({
+    required String link,
+    String? secret,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(link, serializer);
+          sse_encode_opt_String(secret, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1595,26 +1884,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_kv_network_status,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_room_invite,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleGetNetworkStatusConstMeta,
-        argValues: [],
+        constMeta: kCrateApiInviteDecodeRoomInviteConstMeta,
+        argValues: [link, secret],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetNetworkStatusConstMeta =>
-      const TaskConstMeta(debugName: "get_network_status", argNames: []);
+  TaskConstMeta get kCrateApiInviteDecodeRoomInviteConstMeta =>
+      const TaskConstMeta(
+        debugName: "decode_room_invite",
+        argNames: ["link", "secret"],
+      );
 
   @override
-  Future<String?> crateApiNtGetNtPath({required String dosPath}) {
+  Future<String?> crateApiSimpleDefaultInstanceId() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dosPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1626,25 +1917,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNtGetNtPathConstMeta,
-        argValues: [dosPath],
+        constMeta: kCrateApiSimpleDefaultInstanceIdConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNtGetNtPathConstMeta =>
-      const TaskConstMeta(debugName: "get_nt_path", argNames: ["dosPath"]);
+  TaskConstMeta get kCrateApiSimpleDefaultInstanceIdConstMeta =>
+      const TaskConstMeta(debugName: "default_instance_id", argNames: []);
 
   @override
-  Future<List<PeerRoutePair>> crateApiP2PGetPeerRoutePairs({
-    required String instanceId,
-  }) {
+  Future<String> crateApiNatTestDetectNatType({required String stunServer}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(instanceId, serializer);
+          sse_encode_String(stunServer, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1653,25 +1942,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PGetPeerRoutePairsConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiNatTestDetectNatTypeConstMeta,
+        argValues: [stunServer],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PGetPeerRoutePairsConstMeta =>
+  TaskConstMeta get kCrateApiNatTestDetectNatTypeConstMeta =>
       const TaskConstMeta(
-        debugName: "get_peer_route_pairs",
-        argNames: ["instanceId"],
+        debugName: "detect_nat_type",
+        argNames: ["stunServer"],
       );
 
   @override
-  Future<List<PeerRoutePair>> crateApiSimpleGetPeerRoutePairs() {
+  Future<String> crateApiP2PEasytierVersion() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1684,27 +1972,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleGetPeerRoutePairsConstMeta,
+        constMeta: kCrateApiP2PEasytierVersionConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetPeerRoutePairsConstMeta =>
-      const TaskConstMeta(debugName: "get_peer_route_pairs", argNames: []);
+  TaskConstMeta get kCrateApiP2PEasytierVersionConstMeta =>
+      const TaskConstMeta(debugName: "easytier_version", argNames: []);
 
   @override
-  Future<String> crateApiP2PGetRunningInfo({required String instanceId}) {
+  Future<String> crateApiSimpleEasytierVersion() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(instanceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1714,26 +2000,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PGetRunningInfoConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiSimpleEasytierVersionConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PGetRunningInfoConstMeta => const TaskConstMeta(
-    debugName: "get_running_info",
-    argNames: ["instanceId"],
-  );
+  TaskConstMeta get kCrateApiSimpleEasytierVersionConstMeta =>
+      const TaskConstMeta(debugName: "easytier_version", argNames: []);
 
   @override
-  Future<String> crateApiSimpleGetRunningInfo() {
+  Future<Uint8List> crateApiMinecraftLanEncodeMinecraftLanAnnouncement({
+    required String motd,
+    required int port,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(motd, serializer);
+          sse_encode_u_16(port, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1742,31 +2031,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGetRunningInfoConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMinecraftLanEncodeMinecraftLanAnnouncementConstMeta,
+        argValues: [motd, port],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetRunningInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_running_info", argNames: []);
+  TaskConstMeta
+  get kCrateApiMinecraftLanEncodeMinecraftLanAnnouncementConstMeta =>
+      const TaskConstMeta(
+        debugName: "encode_minecraft_lan_announcement",
+        argNames: ["motd", "port"],
+      );
 
   @override
-  Future<JoinHandle> crateApiP2PHandleEvent({
-    required EventBusSubscriber events,
+  Future<String> crateApiInviteEncodeRoomInvite({
+    required RoomInvite invite,
+    String? secret,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-            events,
-            serializer,
-          );
+          sse_encode_box_autoadd_room_invite(invite, serializer);
+          sse_encode_opt_String(secret, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1775,32 +2067,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PHandleEventConstMeta,
-        argValues: [events],
+        constMeta: kCrateApiInviteEncodeRoomInviteConstMeta,
+        argValues: [invite, secret],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PHandleEventConstMeta =>
-      const TaskConstMeta(debugName: "handle_event", argNames: ["events"]);
+  TaskConstMeta get kCrateApiInviteEncodeRoomInviteConstMeta =>
+      const TaskConstMeta(
+        debugName: "encode_room_invite",
+        argNames: ["invite", "secret"],
+      );
 
   @override
-  Future<JoinHandle> crateApiSimpleHandleEvent({
-    required EventBusSubscriber events,
-  }) {
+  Future<String> crateApiMagicWallExportMagicWallRules() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-            events,
-            serializer,
-          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1809,26 +2097,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleHandleEventConstMeta,
-        argValues: [events],
+        constMeta: kCrateApiMagicWallExportMagicWallRulesConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleHandleEventConstMeta =>
-      const TaskConstMeta(debugName: "handle_event", argNames: ["events"]);
+  TaskConstMeta get kCrateApiMagicWallExportMagicWallRulesConstMeta =>
+      const TaskConstMeta(debugName: "export_magic_wall_rules", argNames: []);
 
   @override
-  Future<void> crateApiP2PInitApp() {
+  Future<String> crateApiNetworkConfigFlagsToToml({
+    required NetworkConfigC config,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_network_config_c(config, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1837,21 +2127,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiNetworkConfigFlagsToTomlConstMeta,
+        argValues: [config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiNetworkConfigFlagsToTomlConstMeta =>
+      const TaskConstMeta(debugName: "flags_to_toml", argNames: ["config"]);
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<List<(String, int)>> crateApiHopsGetAllInterfacesMetrics() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1864,26 +2154,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_record_string_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
+        constMeta: kCrateApiHopsGetAllInterfacesMetricsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiHopsGetAllInterfacesMetricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_all_interfaces_metrics",
+        argNames: [],
+      );
 
   @override
-  Future<bool> crateApiP2PIsEasytierRunning({required String instanceId}) {
+  Future<bool> crateApiFirewallGetFirewallStatus({required int profileIndex}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(profileIndex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1893,23 +2186,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiP2PIsEasytierRunningConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiFirewallGetFirewallStatusConstMeta,
+        argValues: [profileIndex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PIsEasytierRunningConstMeta =>
+  TaskConstMeta get kCrateApiFirewallGetFirewallStatusConstMeta =>
       const TaskConstMeta(
-        debugName: "is_easytier_running",
-        argNames: ["instanceId"],
+        debugName: "get_firewall_status",
+        argNames: ["profileIndex"],
       );
 
   @override
-  Future<bool> crateApiSimpleIsEasytierRunning() {
+  Future<BigInt> crateApiForwardGetForwardServerCount() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1922,26 +2215,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_usize,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleIsEasytierRunningConstMeta,
+        constMeta: kCrateApiForwardGetForwardServerCountConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleIsEasytierRunningConstMeta =>
-      const TaskConstMeta(debugName: "is_easytier_running", argNames: []);
+  TaskConstMeta get kCrateApiForwardGetForwardServerCountConstMeta =>
+      const TaskConstMeta(debugName: "get_forward_server_count", argNames: []);
 
   @override
-  Future<bool> crateApiForwardIsForwardServerRunning({required BigInt index}) {
+  Future<(BigInt, BigInt, BigInt)> crateApiForwardGetForwardServerStats({
+    required String id,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_usize(index, serializer);
+          sse_encode_String(id, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1950,31 +2245,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_record_usize_u_64_u_64,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiForwardIsForwardServerRunningConstMeta,
-        argValues: [index],
+        constMeta: kCrateApiForwardGetForwardServerStatsConstMeta,
+        argValues: [id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardIsForwardServerRunningConstMeta =>
+  TaskConstMeta get kCrateApiForwardGetForwardServerStatsConstMeta =>
       const TaskConstMeta(
-        debugName: "is_forward_server_running",
-        argNames: ["index"],
+        debugName: "get_forward_server_stats",
+        argNames: ["id"],
       );
 
   @override
-  Future<bool> crateApiMulticastIsMulticastSenderRunning({
-    required BigInt index,
-  }) {
+  Future<List<String>> crateApiP2PGetIps({required String instanceId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_usize(index, serializer);
+          sse_encode_String(instanceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1983,29 +2276,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMulticastIsMulticastSenderRunningConstMeta,
-        argValues: [index],
+        constMeta: kCrateApiP2PGetIpsConstMeta,
+        argValues: [instanceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMulticastIsMulticastSenderRunningConstMeta =>
-      const TaskConstMeta(
-        debugName: "is_multicast_sender_running",
-        argNames: ["index"],
-      );
+  TaskConstMeta get kCrateApiP2PGetIpsConstMeta =>
+      const TaskConstMeta(debugName: "get_ips", argNames: ["instanceId"]);
 
   @override
-  Future<String> crateApiNatTestNatTypeGetDescription({required NatType that}) {
+  Future<List<String>> crateApiSimpleGetIps() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_nat_type(that, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2014,29 +2303,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNatTestNatTypeGetDescriptionConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiSimpleGetIpsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNatTestNatTypeGetDescriptionConstMeta =>
-      const TaskConstMeta(
-        debugName: "nat_type_get_description",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiSimpleGetIpsConstMeta =>
+      const TaskConstMeta(debugName: "get_ips", argNames: []);
 
   @override
-  Future<void> crateApiMagicWallRemoveMagicWallRule({required String ruleId}) {
+  Future<MagicWallStatus> crateApiMagicWallGetMagicWallStatus() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ruleId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2045,29 +2330,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_magic_wall_status,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiMagicWallRemoveMagicWallRuleConstMeta,
-        argValues: [ruleId],
+        constMeta: kCrateApiMagicWallGetMagicWallStatusConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMagicWallRemoveMagicWallRuleConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_magic_wall_rule",
-        argNames: ["ruleId"],
-      );
+  TaskConstMeta get kCrateApiMagicWallGetMagicWallStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_magic_wall_status", argNames: []);
 
   @override
-  Future<void> crateApiP2PSendUdpToLocalhost({required String message}) {
+  Future<List<MulticastPacket>> crateApiMulticastGetMulticastListenerPackets({
+    required String id,
+    BigInt? limit,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
+          sse_encode_String(id, serializer);
+          sse_encode_opt_box_autoadd_usize(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2076,29 +2362,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_multicast_packet,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PSendUdpToLocalhostConstMeta,
-        argValues: [message],
+        constMeta: kCrateApiMulticastGetMulticastListenerPacketsConstMeta,
+        argValues: [id, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PSendUdpToLocalhostConstMeta =>
+  TaskConstMeta get kCrateApiMulticastGetMulticastListenerPacketsConstMeta =>
       const TaskConstMeta(
-        debugName: "send_udp_to_localhost",
-        argNames: ["message"],
+        debugName: "get_multicast_listener_packets",
+        argNames: ["id", "limit"],
       );
 
   @override
-  Future<void> crateApiSimpleSendUdpToLocalhost({required String message}) {
+  Future<BigInt> crateApiMulticastGetMulticastSenderCount() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2107,33 +2392,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleSendUdpToLocalhostConstMeta,
-        argValues: [message],
+        constMeta: kCrateApiMulticastGetMulticastSenderCountConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleSendUdpToLocalhostConstMeta =>
+  TaskConstMeta get kCrateApiMulticastGetMulticastSenderCountConstMeta =>
       const TaskConstMeta(
-        debugName: "send_udp_to_localhost",
-        argNames: ["message"],
+        debugName: "get_multicast_sender_count",
+        argNames: [],
       );
 
   @override
-  Future<void> crateApiFirewallSetFirewallStatus({
-    required int profileIndex,
-    required bool enable,
+  Future<KVNetworkStatus> crateApiP2PGetNetworkStatus({
+    required String instanceId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(profileIndex, serializer);
-          sse_encode_bool(enable, serializer);
+          sse_encode_String(instanceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2142,33 +2425,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_kv_network_status,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiFirewallSetFirewallStatusConstMeta,
-        argValues: [profileIndex, enable],
+        constMeta: kCrateApiP2PGetNetworkStatusConstMeta,
+        argValues: [instanceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiFirewallSetFirewallStatusConstMeta =>
+  TaskConstMeta get kCrateApiP2PGetNetworkStatusConstMeta =>
       const TaskConstMeta(
-        debugName: "set_firewall_status",
-        argNames: ["profileIndex", "enable"],
+        debugName: "get_network_status",
+        argNames: ["instanceId"],
       );
 
   @override
-  Future<void> crateApiHopsSetInterfaceMetric({
-    required String interfaceName,
-    required int metric,
-  }) {
+  Future<KVNetworkStatus> crateApiSimpleGetNetworkStatus() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(interfaceName, serializer);
-          sse_encode_u_32(metric, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2177,33 +2455,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_kv_network_status,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiHopsSetInterfaceMetricConstMeta,
-        argValues: [interfaceName, metric],
+        constMeta: kCrateApiSimpleGetNetworkStatusConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHopsSetInterfaceMetricConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_interface_metric",
-        argNames: ["interfaceName", "metric"],
-      );
+  TaskConstMeta get kCrateApiSimpleGetNetworkStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_network_status", argNames: []);
 
   @override
-  Future<void> crateApiP2PSetTunFd({
-    required String instanceId,
-    required int fd,
-  }) {
+  Future<String?> crateApiNtGetNtPath({required String dosPath}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(instanceId, serializer);
-          sse_encode_i_32(fd, serializer);
+          sse_encode_String(dosPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2212,28 +2483,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiP2PSetTunFdConstMeta,
-        argValues: [instanceId, fd],
+        constMeta: kCrateApiNtGetNtPathConstMeta,
+        argValues: [dosPath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PSetTunFdConstMeta => const TaskConstMeta(
-    debugName: "set_tun_fd",
-    argNames: ["instanceId", "fd"],
-  );
+  TaskConstMeta get kCrateApiNtGetNtPathConstMeta =>
+      const TaskConstMeta(debugName: "get_nt_path", argNames: ["dosPath"]);
 
   @override
-  Future<void> crateApiSimpleSetTunFd({required int fd}) {
+  Future<List<PeerRoutePair>> crateApiP2PGetPeerRoutePairs({
+    required String instanceId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(fd, serializer);
+          sse_encode_String(instanceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2242,21 +2513,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleSetTunFdConstMeta,
-        argValues: [fd],
+        constMeta: kCrateApiP2PGetPeerRoutePairsConstMeta,
+        argValues: [instanceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleSetTunFdConstMeta =>
-      const TaskConstMeta(debugName: "set_tun_fd", argNames: ["fd"]);
+  TaskConstMeta get kCrateApiP2PGetPeerRoutePairsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_peer_route_pairs",
+        argNames: ["instanceId"],
+      );
 
   @override
-  Future<void> crateApiMagicWallStartMagicWall() {
+  Future<List<PeerRoutePair>> crateApiSimpleGetPeerRoutePairs() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -2269,25 +2544,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiMagicWallStartMagicWallConstMeta,
+        constMeta: kCrateApiSimpleGetPeerRoutePairsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMagicWallStartMagicWallConstMeta =>
-      const TaskConstMeta(debugName: "start_magic_wall", argNames: []);
+  TaskConstMeta get kCrateApiSimpleGetPeerRoutePairsConstMeta =>
+      const TaskConstMeta(debugName: "get_peer_route_pairs", argNames: []);
 
   @override
-  Future<void> crateApiForwardStopAllForwardServers() {
+  Future<RunningInfo> crateApiP2PGetRunningInfo({required String instanceId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2296,21 +2573,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_running_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiForwardStopAllForwardServersConstMeta,
-        argValues: [],
+        constMeta: kCrateApiP2PGetRunningInfoConstMeta,
+        argValues: [instanceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardStopAllForwardServersConstMeta =>
-      const TaskConstMeta(debugName: "stop_all_forward_servers", argNames: []);
+  TaskConstMeta get kCrateApiP2PGetRunningInfoConstMeta => const TaskConstMeta(
+    debugName: "get_running_info",
+    argNames: ["instanceId"],
+  );
 
   @override
-  Future<void> crateApiMulticastStopAllMulticastSenders() {
+  Future<String> crateApiSimpleGetRunningInfo() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -2323,29 +2602,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMulticastStopAllMulticastSendersConstMeta,
+        constMeta: kCrateApiSimpleGetRunningInfoConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMulticastStopAllMulticastSendersConstMeta =>
-      const TaskConstMeta(
-        debugName: "stop_all_multicast_senders",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiSimpleGetRunningInfoConstMeta =>
+      const TaskConstMeta(debugName: "get_running_info", argNames: []);
 
   @override
-  Future<void> crateApiForwardStopForwardServer({required BigInt index}) {
+  Future<SupervisorStatus?> crateApiSupervisorGetSupervisorStatus({
+    required String instanceId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_usize(index, serializer);
+          sse_encode_String(instanceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2354,28 +2632,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_supervisor_status,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiForwardStopForwardServerConstMeta,
-        argValues: [index],
+        constMeta: kCrateApiSupervisorGetSupervisorStatusConstMeta,
+        argValues: [instanceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiForwardStopForwardServerConstMeta =>
+  TaskConstMeta get kCrateApiSupervisorGetSupervisorStatusConstMeta =>
       const TaskConstMeta(
-        debugName: "stop_forward_server",
-        argNames: ["index"],
+        debugName: "get_supervisor_status",
+        argNames: ["instanceId"],
       );
 
   @override
-  Future<void> crateApiMagicWallStopMagicWall() {
+  Future<BigInt> crateApiMagicWallImportMagicWallRules({
+    required String json,
+    required bool replace,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
+          sse_encode_bool(replace, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2384,26 +2667,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_usize,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiMagicWallStopMagicWallConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMagicWallImportMagicWallRulesConstMeta,
+        argValues: [json, replace],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMagicWallStopMagicWallConstMeta =>
-      const TaskConstMeta(debugName: "stop_magic_wall", argNames: []);
+  TaskConstMeta get kCrateApiMagicWallImportMagicWallRulesConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_magic_wall_rules",
+        argNames: ["json", "replace"],
+      );
 
   @override
-  Future<void> crateApiMulticastStopMulticastSender({required BigInt index}) {
+  Future<void> crateApiP2PInitApp() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_usize(index, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2413,30 +2698,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMulticastStopMulticastSenderConstMeta,
-        argValues: [index],
+        constMeta: kCrateApiP2PInitAppConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMulticastStopMulticastSenderConstMeta =>
-      const TaskConstMeta(
-        debugName: "stop_multicast_sender",
-        argNames: ["index"],
-      );
+  TaskConstMeta get kCrateApiP2PInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
 
   @override
-  Future<NetworkTestResult> crateApiNatTestTestNetworkConnectivity({
-    required String stunServer,
-  }) {
+  Future<void> crateApiSimpleInitApp() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(stunServer, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2445,31 +2724,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_network_test_result,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiNatTestTestNetworkConnectivityConstMeta,
-        argValues: [stunServer],
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNatTestTestNetworkConnectivityConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_network_connectivity",
-        argNames: ["stunServer"],
-      );
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
 
   @override
-  Future<void> crateApiMagicWallUpdateMagicWallRule({
-    required MagicWallRule rule,
-  }) {
+  Future<void> crateApiEventLogInitEventLog({required String dir}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_magic_wall_rule(rule, serializer);
+          sse_encode_String(dir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2481,2059 +2755,7690 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiMagicWallUpdateMagicWallRuleConstMeta,
-        argValues: [rule],
+        constMeta: kCrateApiEventLogInitEventLogConstMeta,
+        argValues: [dir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMagicWallUpdateMagicWallRuleConstMeta =>
+  TaskConstMeta get kCrateApiEventLogInitEventLogConstMeta =>
+      const TaskConstMeta(debugName: "init_event_log", argNames: ["dir"]);
+
+  @override
+  Future<void> crateApiMagicWallInitMagicWallStore({required String dir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMagicWallInitMagicWallStoreConstMeta,
+        argValues: [dir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMagicWallInitMagicWallStoreConstMeta =>
       const TaskConstMeta(
-        debugName: "update_magic_wall_rule",
-        argNames: ["rule"],
+        debugName: "init_magic_wall_store",
+        argNames: ["dir"],
       );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_EventBusSubscriber =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber;
+  @override
+  Future<InviteFlags> crateApiInviteInviteFlagsFrom({required FlagsC flag}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_flags_c(flag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_invite_flags,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInviteInviteFlagsFromConstMeta,
+        argValues: [flag],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_EventBusSubscriber =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber;
+  TaskConstMeta get kCrateApiInviteInviteFlagsFromConstMeta =>
+      const TaskConstMeta(debugName: "invite_flags_from", argNames: ["flag"]);
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ForwardServer =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer;
+  @override
+  Future<bool> crateApiP2PIsEasytierRunning({required String instanceId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiP2PIsEasytierRunningConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ForwardServer =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer;
+  TaskConstMeta get kCrateApiP2PIsEasytierRunningConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_easytier_running",
+        argNames: ["instanceId"],
+      );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandle =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle;
+  @override
+  Future<bool> crateApiSimpleIsEasytierRunning() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleIsEasytierRunningConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandle =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle;
+  TaskConstMeta get kCrateApiSimpleIsEasytierRunningConstMeta =>
+      const TaskConstMeta(debugName: "is_easytier_running", argNames: []);
+
+  @override
+  Future<bool> crateApiForwardIsForwardServerRunning({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiForwardIsForwardServerRunningConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiForwardIsForwardServerRunningConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_forward_server_running",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<bool> crateApiMulticastIsMulticastSenderRunning({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMulticastIsMulticastSenderRunningConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMulticastIsMulticastSenderRunningConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_multicast_sender_running",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<void> crateApiForwardKillForwardConnection({
+    required String id,
+    required BigInt connectionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_u_64(connectionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiForwardKillForwardConnectionConstMeta,
+        argValues: [id, connectionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiForwardKillForwardConnectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "kill_forward_connection",
+        argNames: ["id", "connectionId"],
+      );
+
+  @override
+  Future<List<ForwardConnectionInfo>> crateApiForwardListForwardConnections({
+    required String id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_forward_connection_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiForwardListForwardConnectionsConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiForwardListForwardConnectionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_forward_connections",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<List<ForwardServerInfo>> crateApiForwardListForwardServers() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_forward_server_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiForwardListForwardServersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiForwardListForwardServersConstMeta =>
+      const TaskConstMeta(debugName: "list_forward_servers", argNames: []);
+
+  @override
+  Future<List<String>> crateApiEventLogListLoggedInstances() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiEventLogListLoggedInstancesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEventLogListLoggedInstancesConstMeta =>
+      const TaskConstMeta(debugName: "list_logged_instances", argNames: []);
+
+  @override
+  Future<List<MinecraftLanWorld>> crateApiMinecraftLanListMinecraftLanWorlds({
+    required String id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_minecraft_lan_world,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMinecraftLanListMinecraftLanWorldsConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMinecraftLanListMinecraftLanWorldsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_minecraft_lan_worlds",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<List<MulticastListenerInfo>>
+  crateApiMulticastListMulticastListeners() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_multicast_listener_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMulticastListMulticastListenersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMulticastListMulticastListenersConstMeta =>
+      const TaskConstMeta(debugName: "list_multicast_listeners", argNames: []);
+
+  @override
+  Future<List<MulticastSenderInfo>> crateApiMulticastListMulticastSenders() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_multicast_sender_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMulticastListMulticastSendersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMulticastListMulticastSendersConstMeta =>
+      const TaskConstMeta(debugName: "list_multicast_senders", argNames: []);
+
+  @override
+  Future<List<StunServerInfo>> crateApiStunServerListStunServers() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_stun_server_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiStunServerListStunServersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStunServerListStunServersConstMeta =>
+      const TaskConstMeta(debugName: "list_stun_servers", argNames: []);
+
+  @override
+  Future<List<SupervisorStatus>> crateApiSupervisorListSupervisorStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_supervisor_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSupervisorListSupervisorStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSupervisorListSupervisorStatusConstMeta =>
+      const TaskConstMeta(debugName: "list_supervisor_status", argNames: []);
+
+  @override
+  Future<String> crateApiNatTestNatTypeGetDescription({required NatType that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_nat_type(that, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNatTestNatTypeGetDescriptionConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNatTestNatTypeGetDescriptionConstMeta =>
+      const TaskConstMeta(
+        debugName: "nat_type_get_description",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<MinecraftLanAnnouncement?>
+  crateApiMinecraftLanParseMinecraftLanAnnouncement({
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_opt_box_autoadd_minecraft_lan_announcement,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMinecraftLanParseMinecraftLanAnnouncementConstMeta,
+        argValues: [data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiMinecraftLanParseMinecraftLanAnnouncementConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_minecraft_lan_announcement",
+        argNames: ["data"],
+      );
+
+  @override
+  Future<void> crateApiInstancePatchPatchInstance({
+    required String instanceId,
+    required InstancePatch patch,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_box_autoadd_instance_patch(patch, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstancePatchPatchInstanceConstMeta,
+        argValues: [instanceId, patch],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInstancePatchPatchInstanceConstMeta =>
+      const TaskConstMeta(
+        debugName: "patch_instance",
+        argNames: ["instanceId", "patch"],
+      );
+
+  @override
+  Future<List<ServerProbeResult>> crateApiServerProbeProbeServers({
+    required List<String> urls,
+    BigInt? timeoutMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(urls, serializer);
+          sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_server_probe_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerProbeProbeServersConstMeta,
+        argValues: [urls, timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerProbeProbeServersConstMeta =>
+      const TaskConstMeta(
+        debugName: "probe_servers",
+        argNames: ["urls", "timeoutMs"],
+      );
+
+  @override
+  Future<BigInt> crateApiMagicWallPurgeMagicWallFilters() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMagicWallPurgeMagicWallFiltersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMagicWallPurgeMagicWallFiltersConstMeta =>
+      const TaskConstMeta(debugName: "purge_magic_wall_filters", argNames: []);
+
+  @override
+  Future<List<InstanceEventRecord>> crateApiEventLogQueryInstanceEvents({
+    required String instanceId,
+    PlatformInt64? since,
+    List<String>? kinds,
+    BigInt? limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_opt_box_autoadd_i_64(since, serializer);
+          sse_encode_opt_list_String(kinds, serializer);
+          sse_encode_opt_box_autoadd_usize(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_instance_event_record,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiEventLogQueryInstanceEventsConstMeta,
+        argValues: [instanceId, since, kinds, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEventLogQueryInstanceEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "query_instance_events",
+        argNames: ["instanceId", "since", "kinds", "limit"],
+      );
+
+  @override
+  Future<void> crateApiForwardRemoveForwardServer({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiForwardRemoveForwardServerConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiForwardRemoveForwardServerConstMeta =>
+      const TaskConstMeta(debugName: "remove_forward_server", argNames: ["id"]);
+
+  @override
+  Future<void> crateApiMagicWallRemoveMagicWallRule({required String ruleId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ruleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMagicWallRemoveMagicWallRuleConstMeta,
+        argValues: [ruleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMagicWallRemoveMagicWallRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_magic_wall_rule",
+        argNames: ["ruleId"],
+      );
+
+  @override
+  Future<void> crateApiMulticastRemoveMulticastListener({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMulticastRemoveMulticastListenerConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMulticastRemoveMulticastListenerConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_multicast_listener",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<void> crateApiMulticastRemoveMulticastSender({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMulticastRemoveMulticastSenderConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMulticastRemoveMulticastSenderConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_multicast_sender",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<void> crateApiStunServerRemoveStunServer({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiStunServerRemoveStunServerConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStunServerRemoveStunServerConstMeta =>
+      const TaskConstMeta(debugName: "remove_stun_server", argNames: ["id"]);
+
+  @override
+  Future<void> crateApiFirewallSetFirewallStatus({
+    required int profileIndex,
+    required bool enable,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(profileIndex, serializer);
+          sse_encode_bool(enable, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFirewallSetFirewallStatusConstMeta,
+        argValues: [profileIndex, enable],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallSetFirewallStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_firewall_status",
+        argNames: ["profileIndex", "enable"],
+      );
+
+  @override
+  Future<void> crateApiHopsSetInterfaceMetric({
+    required String interfaceName,
+    required int metric,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(interfaceName, serializer);
+          sse_encode_u_32(metric, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHopsSetInterfaceMetricConstMeta,
+        argValues: [interfaceName, metric],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHopsSetInterfaceMetricConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_interface_metric",
+        argNames: ["interfaceName", "metric"],
+      );
+
+  @override
+  Future<void> crateApiP2PSetTunFd({
+    required String instanceId,
+    required int fd,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_i_32(fd, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PSetTunFdConstMeta,
+        argValues: [instanceId, fd],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PSetTunFdConstMeta => const TaskConstMeta(
+    debugName: "set_tun_fd",
+    argNames: ["instanceId", "fd"],
+  );
+
+  @override
+  Future<void> crateApiSimpleSetTunFd({required int fd}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_32(fd, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSimpleSetTunFdConstMeta,
+        argValues: [fd],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetTunFdConstMeta =>
+      const TaskConstMeta(debugName: "set_tun_fd", argNames: ["fd"]);
+
+  @override
+  Future<MagicWallVerdict> crateApiMagicWallSimulateMagicWall({
+    required List<MagicWallRule> rules,
+    required MagicWallPacket packet,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_magic_wall_rule(rules, serializer);
+          sse_encode_box_autoadd_magic_wall_packet(packet, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_magic_wall_verdict,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMagicWallSimulateMagicWallConstMeta,
+        argValues: [rules, packet],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMagicWallSimulateMagicWallConstMeta =>
+      const TaskConstMeta(
+        debugName: "simulate_magic_wall",
+        argNames: ["rules", "packet"],
+      );
+
+  @override
+  Future<void> crateApiMagicWallStartMagicWall() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMagicWallStartMagicWallConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMagicWallStartMagicWallConstMeta =>
+      const TaskConstMeta(debugName: "start_magic_wall", argNames: []);
+
+  @override
+  Future<String> crateApiMinecraftLanStartMinecraftLanBridge({
+    required String virtualIp,
+    String? lanBindAddr,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(virtualIp, serializer);
+          sse_encode_opt_String(lanBindAddr, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMinecraftLanStartMinecraftLanBridgeConstMeta,
+        argValues: [virtualIp, lanBindAddr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMinecraftLanStartMinecraftLanBridgeConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_minecraft_lan_bridge",
+        argNames: ["virtualIp", "lanBindAddr"],
+      );
+
+  @override
+  Future<String> crateApiStunServerStartStunServer({
+    required String primaryIp,
+    required String alternateIp,
+    required int primaryPort,
+    required int alternatePort,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(primaryIp, serializer);
+          sse_encode_String(alternateIp, serializer);
+          sse_encode_u_16(primaryPort, serializer);
+          sse_encode_u_16(alternatePort, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiStunServerStartStunServerConstMeta,
+        argValues: [primaryIp, alternateIp, primaryPort, alternatePort],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStunServerStartStunServerConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_stun_server",
+        argNames: ["primaryIp", "alternateIp", "primaryPort", "alternatePort"],
+      );
+
+  @override
+  Future<void> crateApiForwardStopAllForwardServers() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiForwardStopAllForwardServersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiForwardStopAllForwardServersConstMeta =>
+      const TaskConstMeta(debugName: "stop_all_forward_servers", argNames: []);
+
+  @override
+  Future<void> crateApiMulticastStopAllMulticastSenders() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMulticastStopAllMulticastSendersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMulticastStopAllMulticastSendersConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_all_multicast_senders",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiForwardStopForwardServer({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiForwardStopForwardServerConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiForwardStopForwardServerConstMeta =>
+      const TaskConstMeta(debugName: "stop_forward_server", argNames: ["id"]);
+
+  @override
+  Future<void> crateApiMagicWallStopMagicWall() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMagicWallStopMagicWallConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMagicWallStopMagicWallConstMeta =>
+      const TaskConstMeta(debugName: "stop_magic_wall", argNames: []);
+
+  @override
+  Future<void> crateApiMinecraftLanStopMinecraftLanBridge({
+    required String id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMinecraftLanStopMinecraftLanBridgeConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMinecraftLanStopMinecraftLanBridgeConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_minecraft_lan_bridge",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<void> crateApiMulticastStopMulticastListener({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMulticastStopMulticastListenerConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMulticastStopMulticastListenerConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_multicast_listener",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<void> crateApiMulticastStopMulticastSender({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMulticastStopMulticastSenderConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMulticastStopMulticastSenderConstMeta =>
+      const TaskConstMeta(debugName: "stop_multicast_sender", argNames: ["id"]);
+
+  @override
+  Future<void> crateApiStunServerStopStunServer({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiStunServerStopStunServerConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStunServerStopStunServerConstMeta =>
+      const TaskConstMeta(debugName: "stop_stun_server", argNames: ["id"]);
+
+  @override
+  Stream<InstanceEventRecord> crateApiP2PSubscribeInstanceEvents({
+    required String instanceId,
+  }) {
+    final sink = RustStreamSink<InstanceEventRecord>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_StreamSink_instance_event_record_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 108,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiP2PSubscribeInstanceEventsConstMeta,
+          argValues: [instanceId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiP2PSubscribeInstanceEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_instance_events",
+        argNames: ["instanceId", "sink"],
+      );
+
+  @override
+  Stream<MulticastPacket> crateApiMulticastSubscribeMulticastListener({
+    required String id,
+  }) {
+    final sink = RustStreamSink<MulticastPacket>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(id, serializer);
+            sse_encode_StreamSink_multicast_packet_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 109,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiMulticastSubscribeMulticastListenerConstMeta,
+          argValues: [id, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMulticastSubscribeMulticastListenerConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_multicast_listener",
+        argNames: ["id", "sink"],
+      );
+
+  @override
+  Future<void> crateApiSupervisorSuperviseInstance({
+    required String instanceId,
+    RestartPolicy? policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_opt_box_autoadd_restart_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSupervisorSuperviseInstanceConstMeta,
+        argValues: [instanceId, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSupervisorSuperviseInstanceConstMeta =>
+      const TaskConstMeta(
+        debugName: "supervise_instance",
+        argNames: ["instanceId", "policy"],
+      );
+
+  @override
+  Future<List<NatBehaviorResult>> crateApiNatTestTestNatBehavior({
+    required String stunServer,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(stunServer, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_nat_behavior_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNatTestTestNatBehaviorConstMeta,
+        argValues: [stunServer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNatTestTestNatBehaviorConstMeta =>
+      const TaskConstMeta(
+        debugName: "test_nat_behavior",
+        argNames: ["stunServer"],
+      );
+
+  @override
+  Future<NetworkTestResult> crateApiNatTestTestNetworkConnectivity({
+    required String stunServer,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(stunServer, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_network_test_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNatTestTestNetworkConnectivityConstMeta,
+        argValues: [stunServer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNatTestTestNetworkConnectivityConstMeta =>
+      const TaskConstMeta(
+        debugName: "test_network_connectivity",
+        argNames: ["stunServer"],
+      );
+
+  @override
+  Future<NetworkConfigC> crateApiNetworkConfigTomlToFlags({
+    required String toml,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(toml, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_network_config_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetworkConfigTomlToFlagsConstMeta,
+        argValues: [toml],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetworkConfigTomlToFlagsConstMeta =>
+      const TaskConstMeta(debugName: "toml_to_flags", argNames: ["toml"]);
+
+  @override
+  Future<void> crateApiSupervisorUnsuperviseInstance({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSupervisorUnsuperviseInstanceConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSupervisorUnsuperviseInstanceConstMeta =>
+      const TaskConstMeta(
+        debugName: "unsupervise_instance",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<void> crateApiMagicWallUpdateMagicWallRule({
+    required MagicWallRule rule,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_magic_wall_rule(rule, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMagicWallUpdateMagicWallRuleConstMeta,
+        argValues: [rule],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMagicWallUpdateMagicWallRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_magic_wall_rule",
+        argNames: ["rule"],
+      );
+
+  @override
+  Future<List<ConfigIssue>> crateApiNetworkConfigValidateNetworkConfig({
+    required NetworkConfigC config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_network_config_c(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_config_issue,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNetworkConfigValidateNetworkConfigConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetworkConfigValidateNetworkConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "validate_network_config",
+        argNames: ["config"],
+      );
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ForwardServer =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ForwardServer =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_JoinHandleResultString =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_JoinHandleResultString =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_JoinHandleResultStringString =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_JoinHandleResultStringString =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_MinecraftLanBridge =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_MinecraftLanBridge =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_MulticastListener =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_MulticastListener =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_MulticastSender =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_MulticastSender =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_PeerRoutePair =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_PeerRoutePair =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair;
 
   RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandleResultString =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString;
+  get rust_arc_increment_strong_count_ServerStats =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ServerStats =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  ForwardServer
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ForwardServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  JoinHandleResultString
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return JoinHandleResultStringImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  JoinHandleResultStringString
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return JoinHandleResultStringStringImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  MinecraftLanBridge
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MinecraftLanBridgeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MulticastListener
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MulticastListenerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MulticastSender
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MulticastSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  PeerRoutePair
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PeerRoutePairImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ServerStats
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ServerStatsImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ForwardServer
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ForwardServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MinecraftLanBridge
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MinecraftLanBridgeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MulticastListener
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MulticastListenerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MulticastSender
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MulticastSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ForwardServer
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ForwardServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MinecraftLanBridge
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MinecraftLanBridgeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MulticastListener
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MulticastListenerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MulticastSender
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MulticastSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ServerStats
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ServerStatsImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ForwardServer
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ForwardServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  JoinHandleResultString
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return JoinHandleResultStringImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  JoinHandleResultStringString
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return JoinHandleResultStringStringImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  MinecraftLanBridge
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MinecraftLanBridgeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MulticastListener
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MulticastListenerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MulticastSender
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MulticastSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  PeerRoutePair
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PeerRoutePairImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ServerStats
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ServerStatsImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<InstanceEventRecord>
+  dco_decode_StreamSink_instance_event_record_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<MulticastPacket> dco_decode_StreamSink_multicast_packet_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bool(raw);
+  }
+
+  @protected
+  FlagsC dco_decode_box_autoadd_flags_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_flags_c(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_32(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  InstancePatch dco_decode_box_autoadd_instance_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_instance_patch(raw);
+  }
+
+  @protected
+  InviteFlags dco_decode_box_autoadd_invite_flags(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_invite_flags(raw);
+  }
+
+  @protected
+  MagicWallPacket dco_decode_box_autoadd_magic_wall_packet(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_magic_wall_packet(raw);
+  }
+
+  @protected
+  MagicWallRule dco_decode_box_autoadd_magic_wall_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_magic_wall_rule(raw);
+  }
+
+  @protected
+  MinecraftLanAnnouncement dco_decode_box_autoadd_minecraft_lan_announcement(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_minecraft_lan_announcement(raw);
+  }
+
+  @protected
+  NetworkConfigC dco_decode_box_autoadd_network_config_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_network_config_c(raw);
+  }
+
+  @protected
+  PatchAction dco_decode_box_autoadd_patch_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_patch_action(raw);
+  }
+
+  @protected
+  ProbeErrorKind dco_decode_box_autoadd_probe_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_probe_error_kind(raw);
+  }
+
+  @protected
+  RelayPool dco_decode_box_autoadd_relay_pool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_relay_pool(raw);
+  }
+
+  @protected
+  RestartPolicy dco_decode_box_autoadd_restart_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_restart_policy(raw);
+  }
+
+  @protected
+  RoomInvite dco_decode_box_autoadd_room_invite(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_room_invite(raw);
+  }
+
+  @protected
+  RunningNodeInfo dco_decode_box_autoadd_running_node_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_running_node_info(raw);
+  }
+
+  @protected
+  RunningStunInfo dco_decode_box_autoadd_running_stun_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_running_stun_info(raw);
+  }
+
+  @protected
+  SupervisorStatus dco_decode_box_autoadd_supervisor_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_supervisor_status(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_16(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_32(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_usize(raw);
+  }
+
+  @protected
+  ConfigBuildOptions dco_decode_config_build_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ConfigBuildOptions(
+      ipv4PrefixLen: dco_decode_u_8(arr[0]),
+      invalidCidrPolicy: dco_decode_invalid_cidr_policy(arr[1]),
+      customDevName: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  ConfigChange dco_decode_config_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ConfigChange(
+      field: dco_decode_String(arr[0]),
+      action: dco_decode_opt_box_autoadd_patch_action(arr[1]),
+      value: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ConfigIssue dco_decode_config_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ConfigIssue(
+      field: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FilteringBehavior dco_decode_filtering_behavior(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FilteringBehavior.values[raw as int];
+  }
+
+  @protected
+  FlagsC dco_decode_flags_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 30)
+      throw Exception('unexpected arr length: expect 30 but see ${arr.length}');
+    return FlagsC(
+      defaultProtocol: dco_decode_String(arr[0]),
+      devName: dco_decode_String(arr[1]),
+      enableEncryption: dco_decode_bool(arr[2]),
+      enableIpv6: dco_decode_bool(arr[3]),
+      mtu: dco_decode_u_32(arr[4]),
+      latencyFirst: dco_decode_bool(arr[5]),
+      enableExitNode: dco_decode_bool(arr[6]),
+      noTun: dco_decode_bool(arr[7]),
+      useSmoltcp: dco_decode_bool(arr[8]),
+      relayNetworkWhitelist: dco_decode_String(arr[9]),
+      disableP2P: dco_decode_bool(arr[10]),
+      enableUdpBroadcastRelay: dco_decode_bool(arr[11]),
+      relayAllPeerRpc: dco_decode_bool(arr[12]),
+      disableUdpHolePunching: dco_decode_bool(arr[13]),
+      disableTcpHolePunching: dco_decode_bool(arr[14]),
+      multiThread: dco_decode_bool(arr[15]),
+      dataCompressAlgo: dco_decode_i_32(arr[16]),
+      bindDevice: dco_decode_bool(arr[17]),
+      enableKcpProxy: dco_decode_bool(arr[18]),
+      disableKcpInput: dco_decode_bool(arr[19]),
+      disableRelayKcp: dco_decode_bool(arr[20]),
+      proxyForwardBySystem: dco_decode_bool(arr[21]),
+      acceptDns: dco_decode_bool(arr[22]),
+      privateMode: dco_decode_bool(arr[23]),
+      enableQuicProxy: dco_decode_bool(arr[24]),
+      disableQuicInput: dco_decode_bool(arr[25]),
+      disableSymHolePunching: dco_decode_bool(arr[26]),
+      tcpWhitelist: dco_decode_String(arr[27]),
+      udpWhitelist: dco_decode_String(arr[28]),
+      socks5Port: dco_decode_u_16(arr[29]),
+    );
+  }
+
+  @protected
+  Forward dco_decode_forward(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Forward(
+      bindAddr: dco_decode_String(arr[0]),
+      dstAddr: dco_decode_String(arr[1]),
+      proto: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ForwardConnectionInfo dco_decode_forward_connection_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ForwardConnectionInfo(
+      id: dco_decode_u_64(arr[0]),
+      peerAddr: dco_decode_String(arr[1]),
+      startedAt: dco_decode_i_64(arr[2]),
+      closedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      bytesSent: dco_decode_u_64(arr[4]),
+      bytesReceived: dco_decode_u_64(arr[5]),
+      closeReason: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  ForwardPatch dco_decode_forward_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ForwardPatch(
+      action: dco_decode_patch_action(arr[0]),
+      forward: dco_decode_forward(arr[1]),
+    );
+  }
+
+  @protected
+  ForwardServerInfo dco_decode_forward_server_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ForwardServerInfo(
+      id: dco_decode_String(arr[0]),
+      listenAddr: dco_decode_String(arr[1]),
+      forwardAddr: dco_decode_String(arr[2]),
+      protocol: dco_decode_String(arr[3]),
+      isRunning: dco_decode_bool(arr[4]),
+      connections: dco_decode_usize(arr[5]),
+      bytesSent: dco_decode_u_64(arr[6]),
+      bytesReceived: dco_decode_u_64(arr[7]),
+    );
+  }
+
+  @protected
+  HolePunchChance dco_decode_hole_punch_chance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HolePunchChance.values[raw as int];
+  }
+
+  @protected
+  HolePunchPrediction dco_decode_hole_punch_prediction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return HolePunchPrediction(
+      peerNatType: dco_decode_String(arr[0]),
+      chance: dco_decode_hole_punch_chance(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  InstanceEvent dco_decode_instance_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return InstanceEvent_PeerAdded(peerId: dco_decode_u_32(raw[1]));
+      case 1:
+        return InstanceEvent_PeerRemoved(peerId: dco_decode_u_32(raw[1]));
+      case 2:
+        return InstanceEvent_PeerConnAdded(
+          conn: dco_decode_peer_conn_event(raw[1]),
+        );
+      case 3:
+        return InstanceEvent_PeerConnRemoved(
+          conn: dco_decode_peer_conn_event(raw[1]),
+        );
+      case 4:
+        return InstanceEvent_ListenerAdded(listener: dco_decode_String(raw[1]));
+      case 5:
+        return InstanceEvent_ListenerAddFailed(
+          listener: dco_decode_String(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      case 6:
+        return InstanceEvent_ListenerAcceptFailed(
+          listener: dco_decode_String(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      case 7:
+        return InstanceEvent_ConnectionAccepted(
+          local: dco_decode_String(raw[1]),
+          remote: dco_decode_String(raw[2]),
+        );
+      case 8:
+        return InstanceEvent_ConnectionError(
+          local: dco_decode_String(raw[1]),
+          remote: dco_decode_String(raw[2]),
+          error: dco_decode_String(raw[3]),
+        );
+      case 9:
+        return InstanceEvent_TunDeviceReady(dev: dco_decode_String(raw[1]));
+      case 10:
+        return InstanceEvent_TunDeviceError(error: dco_decode_String(raw[1]));
+      case 11:
+        return InstanceEvent_Connecting(dst: dco_decode_String(raw[1]));
+      case 12:
+        return InstanceEvent_ConnectError(
+          dst: dco_decode_String(raw[1]),
+          ipVersion: dco_decode_String(raw[2]),
+          error: dco_decode_String(raw[3]),
+        );
+      case 13:
+        return InstanceEvent_VpnPortalStarted(
+          portal: dco_decode_String(raw[1]),
+        );
+      case 14:
+        return InstanceEvent_VpnPortalClientConnected(
+          portal: dco_decode_String(raw[1]),
+          clientAddr: dco_decode_String(raw[2]),
+        );
+      case 15:
+        return InstanceEvent_VpnPortalClientDisconnected(
+          portal: dco_decode_String(raw[1]),
+          clientAddr: dco_decode_String(raw[2]),
+        );
+      case 16:
+        return InstanceEvent_DhcpIpv4Changed(
+          oldIp: dco_decode_opt_String(raw[1]),
+          newIp: dco_decode_opt_String(raw[2]),
+        );
+      case 17:
+        return InstanceEvent_DhcpIpv4Conflicted(
+          ip: dco_decode_opt_String(raw[1]),
+        );
+      case 18:
+        return InstanceEvent_PortForwardAdded(
+          bindAddr: dco_decode_String(raw[1]),
+          dstAddr: dco_decode_String(raw[2]),
+          proto: dco_decode_String(raw[3]),
+        );
+      case 19:
+        return InstanceEvent_ConfigPatched(
+          changes: dco_decode_list_config_change(raw[1]),
+        );
+      case 20:
+        return InstanceEvent_ProxyCidrsUpdated(
+          added: dco_decode_list_String(raw[1]),
+          removed: dco_decode_list_String(raw[2]),
+        );
+      case 21:
+        return InstanceEvent_InstanceRestarting(
+          attempt: dco_decode_u_32(raw[1]),
+          delayMs: dco_decode_u_64(raw[2]),
+          reason: dco_decode_String(raw[3]),
+        );
+      case 22:
+        return InstanceEvent_InstanceRestarted(
+          attempt: dco_decode_u_32(raw[1]),
+        );
+      case 23:
+        return InstanceEvent_InstanceRestartGaveUp(
+          attempts: dco_decode_u_32(raw[1]),
+          reason: dco_decode_String(raw[2]),
+        );
+      case 24:
+        return InstanceEvent_EventsLagged(dropped: dco_decode_u_64(raw[1]));
+      case 25:
+        return InstanceEvent_EventsClosed();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  InstanceEventRecord dco_decode_instance_event_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return InstanceEventRecord(
+      instanceId: dco_decode_String(arr[0]),
+      timestamp: dco_decode_i_64(arr[1]),
+      event: dco_decode_instance_event(arr[2]),
+    );
+  }
+
+  @protected
+  InstancePatch dco_decode_instance_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return InstancePatch(
+      peers: dco_decode_list_value_patch(arr[0]),
+      portForwards: dco_decode_list_forward_patch(arr[1]),
+      proxyCidrs: dco_decode_list_value_patch(arr[2]),
+      tcpWhitelist: dco_decode_list_value_patch(arr[3]),
+      udpWhitelist: dco_decode_list_value_patch(arr[4]),
+    );
+  }
+
+  @protected
+  InvalidCidrPolicy dco_decode_invalid_cidr_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return InvalidCidrPolicy.values[raw as int];
+  }
+
+  @protected
+  InviteFlags dco_decode_invite_flags(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return InviteFlags(
+      defaultProtocol: dco_decode_opt_String(arr[0]),
+      enableEncryption: dco_decode_opt_box_autoadd_bool(arr[1]),
+      enableIpv6: dco_decode_opt_box_autoadd_bool(arr[2]),
+      mtu: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      latencyFirst: dco_decode_opt_box_autoadd_bool(arr[4]),
+      disableP2P: dco_decode_opt_box_autoadd_bool(arr[5]),
+      enableUdpBroadcastRelay: dco_decode_opt_box_autoadd_bool(arr[6]),
+      dataCompressAlgo: dco_decode_opt_box_autoadd_i_32(arr[7]),
+      enableKcpProxy: dco_decode_opt_box_autoadd_bool(arr[8]),
+      enableQuicProxy: dco_decode_opt_box_autoadd_bool(arr[9]),
+    );
+  }
+
+  @protected
+  KVNetworkStatus dco_decode_kv_network_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KVNetworkStatus(
+      totalNodes: dco_decode_usize(arr[0]),
+      nodes: dco_decode_list_kv_node_info(arr[1]),
+    );
+  }
+
+  @protected
+  KVNodeConnectionStats dco_decode_kv_node_connection_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return KVNodeConnectionStats(
+      connType: dco_decode_String(arr[0]),
+      rxBytes: dco_decode_u_64(arr[1]),
+      txBytes: dco_decode_u_64(arr[2]),
+      rxPackets: dco_decode_u_64(arr[3]),
+      txPackets: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  KVNodeInfo dco_decode_kv_node_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 18)
+      throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+    return KVNodeInfo(
+      peerId: dco_decode_u_32(arr[0]),
+      hostname: dco_decode_String(arr[1]),
+      ipv4: dco_decode_String(arr[2]),
+      ipv6: dco_decode_opt_String(arr[3]),
+      publicIpv6: dco_decode_opt_String(arr[4]),
+      ipv6Prefix: dco_decode_opt_String(arr[5]),
+      latencyMs: dco_decode_f_64(arr[6]),
+      nat: dco_decode_String(arr[7]),
+      hops: dco_decode_list_node_hop_stats(arr[8]),
+      lossRate: dco_decode_f_32(arr[9]),
+      connections: dco_decode_list_kv_node_connection_stats(arr[10]),
+      tunnelProto: dco_decode_String(arr[11]),
+      connType: dco_decode_String(arr[12]),
+      rxBytes: dco_decode_u_64(arr[13]),
+      txBytes: dco_decode_u_64(arr[14]),
+      version: dco_decode_String(arr[15]),
+      cost: dco_decode_i_32(arr[16]),
+      proxyCidrs: dco_decode_list_String(arr[17]),
+    );
+  }
+
+  @protected
+  List<PeerRoutePair>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
+        )
+        .toList();
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<ConfigChange> dco_decode_list_config_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_config_change).toList();
+  }
+
+  @protected
+  List<ConfigIssue> dco_decode_list_config_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_config_issue).toList();
+  }
+
+  @protected
+  List<Forward> dco_decode_list_forward(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_forward).toList();
+  }
+
+  @protected
+  List<ForwardConnectionInfo> dco_decode_list_forward_connection_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_forward_connection_info)
+        .toList();
+  }
+
+  @protected
+  List<ForwardPatch> dco_decode_list_forward_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_forward_patch).toList();
+  }
+
+  @protected
+  List<ForwardServerInfo> dco_decode_list_forward_server_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_forward_server_info).toList();
+  }
+
+  @protected
+  List<HolePunchPrediction> dco_decode_list_hole_punch_prediction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_hole_punch_prediction)
+        .toList();
+  }
+
+  @protected
+  List<InstanceEventRecord> dco_decode_list_instance_event_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_instance_event_record)
+        .toList();
+  }
+
+  @protected
+  List<KVNodeConnectionStats> dco_decode_list_kv_node_connection_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_kv_node_connection_stats)
+        .toList();
+  }
+
+  @protected
+  List<KVNodeInfo> dco_decode_list_kv_node_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_kv_node_info).toList();
+  }
+
+  @protected
+  List<MagicWallRule> dco_decode_list_magic_wall_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_magic_wall_rule).toList();
+  }
+
+  @protected
+  List<MagicWallRuleIssue> dco_decode_list_magic_wall_rule_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_magic_wall_rule_issue)
+        .toList();
+  }
+
+  @protected
+  List<MinecraftLanWorld> dco_decode_list_minecraft_lan_world(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_minecraft_lan_world).toList();
+  }
+
+  @protected
+  List<MulticastListenerInfo> dco_decode_list_multicast_listener_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_multicast_listener_info)
+        .toList();
+  }
+
+  @protected
+  List<MulticastPacket> dco_decode_list_multicast_packet(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_multicast_packet).toList();
+  }
+
+  @protected
+  List<MulticastSenderInfo> dco_decode_list_multicast_sender_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_multicast_sender_info)
+        .toList();
+  }
+
+  @protected
+  List<NatBehaviorResult> dco_decode_list_nat_behavior_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_nat_behavior_result).toList();
+  }
+
+  @protected
+  List<NodeHopStats> dco_decode_list_node_hop_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_node_hop_stats).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, int)> dco_decode_list_record_string_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_u_32).toList();
+  }
+
+  @protected
+  List<RunningConnInfo> dco_decode_list_running_conn_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_running_conn_info).toList();
+  }
+
+  @protected
+  List<RunningPeerInfo> dco_decode_list_running_peer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_running_peer_info).toList();
+  }
+
+  @protected
+  List<RunningRouteInfo> dco_decode_list_running_route_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_running_route_info).toList();
+  }
+
+  @protected
+  List<ServerProbeResult> dco_decode_list_server_probe_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_server_probe_result).toList();
+  }
+
+  @protected
+  List<StunServerInfo> dco_decode_list_stun_server_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_stun_server_info).toList();
+  }
+
+  @protected
+  List<StunServerResult> dco_decode_list_stun_server_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_stun_server_result).toList();
+  }
+
+  @protected
+  List<SupervisorStatus> dco_decode_list_supervisor_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_supervisor_status).toList();
+  }
+
+  @protected
+  List<ValuePatch> dco_decode_list_value_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_value_patch).toList();
+  }
+
+  @protected
+  MagicWallPacket dco_decode_magic_wall_packet(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MagicWallPacket(
+      protocol: dco_decode_String(arr[0]),
+      direction: dco_decode_String(arr[1]),
+      localIp: dco_decode_String(arr[2]),
+      remoteIp: dco_decode_String(arr[3]),
+      localPort: dco_decode_opt_box_autoadd_u_16(arr[4]),
+      remotePort: dco_decode_opt_box_autoadd_u_16(arr[5]),
+      appPath: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  MagicWallRule dco_decode_magic_wall_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return MagicWallRule(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      enabled: dco_decode_bool(arr[2]),
+      action: dco_decode_String(arr[3]),
+      protocol: dco_decode_String(arr[4]),
+      direction: dco_decode_String(arr[5]),
+      appPath: dco_decode_opt_String(arr[6]),
+      remoteIp: dco_decode_opt_String(arr[7]),
+      localIp: dco_decode_opt_String(arr[8]),
+      remotePort: dco_decode_opt_String(arr[9]),
+      localPort: dco_decode_opt_String(arr[10]),
+      priority: dco_decode_u_32(arr[11]),
+      description: dco_decode_opt_String(arr[12]),
+      createdAt: dco_decode_opt_box_autoadd_i_64(arr[13]),
+    );
+  }
+
+  @protected
+  MagicWallRuleIssue dco_decode_magic_wall_rule_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MagicWallRuleIssue(
+      kind: dco_decode_magic_wall_rule_issue_kind(arr[0]),
+      ruleId: dco_decode_String(arr[1]),
+      otherRuleId: dco_decode_String(arr[2]),
+      message: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  MagicWallRuleIssueKind dco_decode_magic_wall_rule_issue_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MagicWallRuleIssueKind.values[raw as int];
+  }
+
+  @protected
+  MagicWallStatus dco_decode_magic_wall_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MagicWallStatus(
+      isRunning: dco_decode_bool(arr[0]),
+      activeRules: dco_decode_usize(arr[1]),
+      totalRules: dco_decode_usize(arr[2]),
+    );
+  }
+
+  @protected
+  MagicWallVerdict dco_decode_magic_wall_verdict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MagicWallVerdict(
+      action: dco_decode_String(arr[0]),
+      matchedRuleId: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  MappingBehavior dco_decode_mapping_behavior(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MappingBehavior.values[raw as int];
+  }
+
+  @protected
+  MinecraftLanAnnouncement dco_decode_minecraft_lan_announcement(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MinecraftLanAnnouncement(
+      motd: dco_decode_String(arr[0]),
+      port: dco_decode_u_16(arr[1]),
+    );
+  }
+
+  @protected
+  MinecraftLanWorld dco_decode_minecraft_lan_world(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MinecraftLanWorld(
+      host: dco_decode_String(arr[0]),
+      remotePort: dco_decode_u_16(arr[1]),
+      localPort: dco_decode_u_16(arr[2]),
+      motd: dco_decode_String(arr[3]),
+      lastSeen: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  MulticastListenerInfo dco_decode_multicast_listener_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MulticastListenerInfo(
+      id: dco_decode_String(arr[0]),
+      groupAddr: dco_decode_String(arr[1]),
+      interfaceAddr: dco_decode_String(arr[2]),
+      capacity: dco_decode_usize(arr[3]),
+      packetsReceived: dco_decode_u_64(arr[4]),
+      isRunning: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  MulticastPacket dco_decode_multicast_packet(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MulticastPacket(
+      source: dco_decode_String(arr[0]),
+      size: dco_decode_usize(arr[1]),
+      timestamp: dco_decode_i_64(arr[2]),
+      data: dco_decode_list_prim_u_8_strict(arr[3]),
+    );
+  }
+
+  @protected
+  MulticastSenderInfo dco_decode_multicast_sender_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MulticastSenderInfo(
+      id: dco_decode_String(arr[0]),
+      multicastAddr: dco_decode_String(arr[1]),
+      bindAddr: dco_decode_String(arr[2]),
+      intervalMs: dco_decode_u_64(arr[3]),
+      dataLen: dco_decode_usize(arr[4]),
+      isRunning: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  NatBehaviorResult dco_decode_nat_behavior_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return NatBehaviorResult(
+      protocol: dco_decode_String(arr[0]),
+      ipVersion: dco_decode_String(arr[1]),
+      server: dco_decode_opt_String(arr[2]),
+      localAddr: dco_decode_opt_String(arr[3]),
+      mappedAddr: dco_decode_opt_String(arr[4]),
+      rttMs: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      mapping: dco_decode_mapping_behavior(arr[6]),
+      filtering: dco_decode_filtering_behavior(arr[7]),
+      hairpinning: dco_decode_opt_box_autoadd_bool(arr[8]),
+      portPreserved: dco_decode_opt_box_autoadd_bool(arr[9]),
+      error: dco_decode_opt_String(arr[10]),
+    );
+  }
+
+  @protected
+  NatType dco_decode_nat_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NatType.values[raw as int];
+  }
+
+  @protected
+  NetworkConfigC dco_decode_network_config_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return NetworkConfigC(
+      username: dco_decode_String(arr[0]),
+      enableDhcp: dco_decode_bool(arr[1]),
+      specifiedIp: dco_decode_String(arr[2]),
+      roomName: dco_decode_String(arr[3]),
+      roomPassword: dco_decode_String(arr[4]),
+      severurl: dco_decode_list_String(arr[5]),
+      onurl: dco_decode_list_String(arr[6]),
+      cidrs: dco_decode_list_String(arr[7]),
+      forwards: dco_decode_list_forward(arr[8]),
+      flag: dco_decode_flags_c(arr[9]),
+      options: dco_decode_config_build_options(arr[10]),
+      relayPool: dco_decode_opt_box_autoadd_relay_pool(arr[11]),
+    );
+  }
+
+  @protected
+  NetworkTestResult dco_decode_network_test_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return NetworkTestResult(
+      natTypeV4: dco_decode_String(arr[0]),
+      natTypeV6: dco_decode_String(arr[1]),
+      ipv4Latency: dco_decode_i_64(arr[2]),
+      ipv6Latency: dco_decode_i_64(arr[3]),
+      natTypeV4Raw: dco_decode_opt_String(arr[4]),
+      errorV4: dco_decode_opt_String(arr[5]),
+      errorV6: dco_decode_opt_String(arr[6]),
+      publicEndpointV4: dco_decode_opt_String(arr[7]),
+      publicEndpointV6: dco_decode_opt_String(arr[8]),
+      stunServers: dco_decode_list_stun_server_result(arr[9]),
+      holePunch: dco_decode_list_hole_punch_prediction(arr[10]),
+    );
+  }
+
+  @protected
+  NodeHopStats dco_decode_node_hop_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NodeHopStats(
+      peerId: dco_decode_u_32(arr[0]),
+      targetIp: dco_decode_String(arr[1]),
+      latencyMs: dco_decode_f_64(arr[2]),
+      packetLoss: dco_decode_f_32(arr[3]),
+      nodeName: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  MinecraftLanAnnouncement?
+  dco_decode_opt_box_autoadd_minecraft_lan_announcement(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_minecraft_lan_announcement(raw);
+  }
+
+  @protected
+  PatchAction? dco_decode_opt_box_autoadd_patch_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_patch_action(raw);
+  }
+
+  @protected
+  ProbeErrorKind? dco_decode_opt_box_autoadd_probe_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_probe_error_kind(raw);
+  }
+
+  @protected
+  RelayPool? dco_decode_opt_box_autoadd_relay_pool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_relay_pool(raw);
+  }
+
+  @protected
+  RestartPolicy? dco_decode_opt_box_autoadd_restart_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_restart_policy(raw);
+  }
+
+  @protected
+  RunningNodeInfo? dco_decode_opt_box_autoadd_running_node_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_running_node_info(raw);
+  }
+
+  @protected
+  RunningStunInfo? dco_decode_opt_box_autoadd_running_stun_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_running_stun_info(raw);
+  }
+
+  @protected
+  SupervisorStatus? dco_decode_opt_box_autoadd_supervisor_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_supervisor_status(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  PatchAction dco_decode_patch_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PatchAction.values[raw as int];
+  }
+
+  @protected
+  PeerConnEvent dco_decode_peer_conn_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PeerConnEvent(
+      connId: dco_decode_String(arr[0]),
+      myPeerId: dco_decode_u_32(arr[1]),
+      peerId: dco_decode_u_32(arr[2]),
+      tunnelType: dco_decode_String(arr[3]),
+      localAddr: dco_decode_String(arr[4]),
+      remoteAddr: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  ProbeErrorKind dco_decode_probe_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProbeErrorKind.values[raw as int];
+  }
+
+  @protected
+  (String, int) dco_decode_record_string_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_u_32(arr[1]));
+  }
+
+  @protected
+  (BigInt, BigInt, BigInt) dco_decode_record_usize_u_64_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_usize(arr[0]),
+      dco_decode_u_64(arr[1]),
+      dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  RelayPool dco_decode_relay_pool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RelayPool(
+      candidates: dco_decode_list_String(arr[0]),
+      bestN: dco_decode_usize(arr[1]),
+      reevaluateIntervalSecs: dco_decode_u_64(arr[2]),
+      minImprovementMs: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  RestartPolicy dco_decode_restart_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RestartPolicy(
+      maxRetries: dco_decode_u_32(arr[0]),
+      initialBackoffMs: dco_decode_u_64(arr[1]),
+      maxBackoffMs: dco_decode_u_64(arr[2]),
+      restartOnTunError: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  RoomInvite dco_decode_room_invite(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return RoomInvite(
+      roomName: dco_decode_String(arr[0]),
+      roomPassword: dco_decode_String(arr[1]),
+      servers: dco_decode_list_String(arr[2]),
+      ipRange: dco_decode_opt_String(arr[3]),
+      flags: dco_decode_invite_flags(arr[4]),
+      expiresAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      verified: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  RunningConnInfo dco_decode_running_conn_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return RunningConnInfo(
+      connId: dco_decode_String(arr[0]),
+      tunnelType: dco_decode_String(arr[1]),
+      localAddr: dco_decode_String(arr[2]),
+      remoteAddr: dco_decode_String(arr[3]),
+      isClient: dco_decode_bool(arr[4]),
+      networkName: dco_decode_String(arr[5]),
+      latencyMs: dco_decode_f_64(arr[6]),
+      lossRate: dco_decode_f_32(arr[7]),
+      rxBytes: dco_decode_u_64(arr[8]),
+      txBytes: dco_decode_u_64(arr[9]),
+      rxPackets: dco_decode_u_64(arr[10]),
+      txPackets: dco_decode_u_64(arr[11]),
+    );
+  }
+
+  @protected
+  RunningInfo dco_decode_running_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RunningInfo(
+      devName: dco_decode_String(arr[0]),
+      running: dco_decode_bool(arr[1]),
+      errorMsg: dco_decode_opt_String(arr[2]),
+      myNode: dco_decode_opt_box_autoadd_running_node_info(arr[3]),
+      peers: dco_decode_list_running_peer_info(arr[4]),
+      routes: dco_decode_list_running_route_info(arr[5]),
+    );
+  }
+
+  @protected
+  RunningNodeInfo dco_decode_running_node_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return RunningNodeInfo(
+      peerId: dco_decode_u_32(arr[0]),
+      hostname: dco_decode_String(arr[1]),
+      version: dco_decode_String(arr[2]),
+      virtualIpv4: dco_decode_opt_String(arr[3]),
+      virtualIpv6: dco_decode_opt_String(arr[4]),
+      listeners: dco_decode_list_String(arr[5]),
+      publicIpv4: dco_decode_opt_String(arr[6]),
+      publicIpv6: dco_decode_opt_String(arr[7]),
+      interfaceIpv4S: dco_decode_list_String(arr[8]),
+      interfaceIpv6S: dco_decode_list_String(arr[9]),
+      stun: dco_decode_opt_box_autoadd_running_stun_info(arr[10]),
+    );
+  }
+
+  @protected
+  RunningPeerInfo dco_decode_running_peer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RunningPeerInfo(
+      peerId: dco_decode_u_32(arr[0]),
+      connections: dco_decode_list_running_conn_info(arr[1]),
+    );
+  }
+
+  @protected
+  RunningRouteInfo dco_decode_running_route_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return RunningRouteInfo(
+      peerId: dco_decode_u_32(arr[0]),
+      instId: dco_decode_String(arr[1]),
+      hostname: dco_decode_String(arr[2]),
+      version: dco_decode_String(arr[3]),
+      ipv4: dco_decode_opt_String(arr[4]),
+      ipv6: dco_decode_opt_String(arr[5]),
+      nextHopPeerId: dco_decode_u_32(arr[6]),
+      cost: dco_decode_i_32(arr[7]),
+      pathLatency: dco_decode_i_32(arr[8]),
+      proxyCidrs: dco_decode_list_String(arr[9]),
+      udpNatType: dco_decode_String(arr[10]),
+    );
+  }
+
+  @protected
+  RunningStunInfo dco_decode_running_stun_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RunningStunInfo(
+      udpNatType: dco_decode_String(arr[0]),
+      tcpNatType: dco_decode_String(arr[1]),
+      publicIps: dco_decode_list_String(arr[2]),
+      minPort: dco_decode_u_32(arr[3]),
+      maxPort: dco_decode_u_32(arr[4]),
+      lastUpdateTime: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  ServerProbeResult dco_decode_server_probe_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ServerProbeResult(
+      url: dco_decode_String(arr[0]),
+      reachable: dco_decode_bool(arr[1]),
+      resolvedAddrs: dco_decode_list_String(arr[2]),
+      dnsMs: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      connectMs: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      rttMs: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      remotePeerId: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      errorKind: dco_decode_opt_box_autoadd_probe_error_kind(arr[7]),
+      error: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  StunServerInfo dco_decode_stun_server_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return StunServerInfo(
+      id: dco_decode_String(arr[0]),
+      primaryAddr: dco_decode_String(arr[1]),
+      alternateAddr: dco_decode_String(arr[2]),
+      running: dco_decode_bool(arr[3]),
+      requests: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  StunServerResult dco_decode_stun_server_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return StunServerResult(
+      server: dco_decode_String(arr[0]),
+      ipVersion: dco_decode_String(arr[1]),
+      success: dco_decode_bool(arr[2]),
+      latency: dco_decode_i_64(arr[3]),
+      mappedAddr: dco_decode_opt_String(arr[4]),
+      error: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  SupervisorState dco_decode_supervisor_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SupervisorState.values[raw as int];
+  }
+
+  @protected
+  SupervisorStatus dco_decode_supervisor_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return SupervisorStatus(
+      instanceId: dco_decode_String(arr[0]),
+      policy: dco_decode_restart_policy(arr[1]),
+      state: dco_decode_supervisor_state(arr[2]),
+      restartCount: dco_decode_u_32(arr[3]),
+      consecutiveFailures: dco_decode_u_32(arr[4]),
+      lastFailure: dco_decode_opt_String(arr[5]),
+      lastFailureAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      nextRestartAt: dco_decode_opt_box_autoadd_i_64(arr[7]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  ValuePatch dco_decode_value_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ValuePatch(
+      action: dco_decode_patch_action(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  ForwardServer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ForwardServerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  JoinHandleResultString
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return JoinHandleResultStringImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  JoinHandleResultStringString
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return JoinHandleResultStringStringImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MinecraftLanBridge
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MinecraftLanBridgeImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MulticastListener
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MulticastListenerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MulticastSender
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MulticastSenderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  PeerRoutePair
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return PeerRoutePairImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ServerStats
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ServerStatsImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ForwardServer
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ForwardServerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MinecraftLanBridge
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MinecraftLanBridgeImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MulticastListener
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MulticastListenerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MulticastSender
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MulticastSenderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ForwardServer
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ForwardServerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MinecraftLanBridge
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MinecraftLanBridgeImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MulticastListener
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MulticastListenerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MulticastSender
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MulticastSenderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ServerStats
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ServerStatsImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ForwardServer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ForwardServerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  JoinHandleResultString
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return JoinHandleResultStringImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  JoinHandleResultStringString
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return JoinHandleResultStringStringImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MinecraftLanBridge
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MinecraftLanBridgeImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MulticastListener
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MulticastListenerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  MulticastSender
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MulticastSenderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  PeerRoutePair
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return PeerRoutePairImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ServerStats
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ServerStatsImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<InstanceEventRecord>
+  sse_decode_StreamSink_instance_event_record_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<MulticastPacket> sse_decode_StreamSink_multicast_packet_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  FlagsC sse_decode_box_autoadd_flags_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_flags_c(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  InstancePatch sse_decode_box_autoadd_instance_patch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_instance_patch(deserializer));
+  }
+
+  @protected
+  InviteFlags sse_decode_box_autoadd_invite_flags(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_invite_flags(deserializer));
+  }
+
+  @protected
+  MagicWallPacket sse_decode_box_autoadd_magic_wall_packet(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_magic_wall_packet(deserializer));
+  }
+
+  @protected
+  MagicWallRule sse_decode_box_autoadd_magic_wall_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_magic_wall_rule(deserializer));
+  }
+
+  @protected
+  MinecraftLanAnnouncement sse_decode_box_autoadd_minecraft_lan_announcement(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_minecraft_lan_announcement(deserializer));
+  }
+
+  @protected
+  NetworkConfigC sse_decode_box_autoadd_network_config_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_network_config_c(deserializer));
+  }
+
+  @protected
+  PatchAction sse_decode_box_autoadd_patch_action(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_patch_action(deserializer));
+  }
+
+  @protected
+  ProbeErrorKind sse_decode_box_autoadd_probe_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_probe_error_kind(deserializer));
+  }
+
+  @protected
+  RelayPool sse_decode_box_autoadd_relay_pool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_relay_pool(deserializer));
+  }
+
+  @protected
+  RestartPolicy sse_decode_box_autoadd_restart_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_restart_policy(deserializer));
+  }
+
+  @protected
+  RoomInvite sse_decode_box_autoadd_room_invite(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_room_invite(deserializer));
+  }
+
+  @protected
+  RunningNodeInfo sse_decode_box_autoadd_running_node_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_running_node_info(deserializer));
+  }
+
+  @protected
+  RunningStunInfo sse_decode_box_autoadd_running_stun_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_running_stun_info(deserializer));
+  }
+
+  @protected
+  SupervisorStatus sse_decode_box_autoadd_supervisor_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_supervisor_status(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_usize(deserializer));
+  }
+
+  @protected
+  ConfigBuildOptions sse_decode_config_build_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ipv4PrefixLen = sse_decode_u_8(deserializer);
+    var var_invalidCidrPolicy = sse_decode_invalid_cidr_policy(deserializer);
+    var var_customDevName = sse_decode_bool(deserializer);
+    return ConfigBuildOptions(
+      ipv4PrefixLen: var_ipv4PrefixLen,
+      invalidCidrPolicy: var_invalidCidrPolicy,
+      customDevName: var_customDevName,
+    );
+  }
+
+  @protected
+  ConfigChange sse_decode_config_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_String(deserializer);
+    var var_action = sse_decode_opt_box_autoadd_patch_action(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return ConfigChange(field: var_field, action: var_action, value: var_value);
+  }
+
+  @protected
+  ConfigIssue sse_decode_config_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return ConfigIssue(
+      field: var_field,
+      value: var_value,
+      message: var_message,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FilteringBehavior sse_decode_filtering_behavior(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FilteringBehavior.values[inner];
+  }
+
+  @protected
+  FlagsC sse_decode_flags_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_defaultProtocol = sse_decode_String(deserializer);
+    var var_devName = sse_decode_String(deserializer);
+    var var_enableEncryption = sse_decode_bool(deserializer);
+    var var_enableIpv6 = sse_decode_bool(deserializer);
+    var var_mtu = sse_decode_u_32(deserializer);
+    var var_latencyFirst = sse_decode_bool(deserializer);
+    var var_enableExitNode = sse_decode_bool(deserializer);
+    var var_noTun = sse_decode_bool(deserializer);
+    var var_useSmoltcp = sse_decode_bool(deserializer);
+    var var_relayNetworkWhitelist = sse_decode_String(deserializer);
+    var var_disableP2P = sse_decode_bool(deserializer);
+    var var_enableUdpBroadcastRelay = sse_decode_bool(deserializer);
+    var var_relayAllPeerRpc = sse_decode_bool(deserializer);
+    var var_disableUdpHolePunching = sse_decode_bool(deserializer);
+    var var_disableTcpHolePunching = sse_decode_bool(deserializer);
+    var var_multiThread = sse_decode_bool(deserializer);
+    var var_dataCompressAlgo = sse_decode_i_32(deserializer);
+    var var_bindDevice = sse_decode_bool(deserializer);
+    var var_enableKcpProxy = sse_decode_bool(deserializer);
+    var var_disableKcpInput = sse_decode_bool(deserializer);
+    var var_disableRelayKcp = sse_decode_bool(deserializer);
+    var var_proxyForwardBySystem = sse_decode_bool(deserializer);
+    var var_acceptDns = sse_decode_bool(deserializer);
+    var var_privateMode = sse_decode_bool(deserializer);
+    var var_enableQuicProxy = sse_decode_bool(deserializer);
+    var var_disableQuicInput = sse_decode_bool(deserializer);
+    var var_disableSymHolePunching = sse_decode_bool(deserializer);
+    var var_tcpWhitelist = sse_decode_String(deserializer);
+    var var_udpWhitelist = sse_decode_String(deserializer);
+    var var_socks5Port = sse_decode_u_16(deserializer);
+    return FlagsC(
+      defaultProtocol: var_defaultProtocol,
+      devName: var_devName,
+      enableEncryption: var_enableEncryption,
+      enableIpv6: var_enableIpv6,
+      mtu: var_mtu,
+      latencyFirst: var_latencyFirst,
+      enableExitNode: var_enableExitNode,
+      noTun: var_noTun,
+      useSmoltcp: var_useSmoltcp,
+      relayNetworkWhitelist: var_relayNetworkWhitelist,
+      disableP2P: var_disableP2P,
+      enableUdpBroadcastRelay: var_enableUdpBroadcastRelay,
+      relayAllPeerRpc: var_relayAllPeerRpc,
+      disableUdpHolePunching: var_disableUdpHolePunching,
+      disableTcpHolePunching: var_disableTcpHolePunching,
+      multiThread: var_multiThread,
+      dataCompressAlgo: var_dataCompressAlgo,
+      bindDevice: var_bindDevice,
+      enableKcpProxy: var_enableKcpProxy,
+      disableKcpInput: var_disableKcpInput,
+      disableRelayKcp: var_disableRelayKcp,
+      proxyForwardBySystem: var_proxyForwardBySystem,
+      acceptDns: var_acceptDns,
+      privateMode: var_privateMode,
+      enableQuicProxy: var_enableQuicProxy,
+      disableQuicInput: var_disableQuicInput,
+      disableSymHolePunching: var_disableSymHolePunching,
+      tcpWhitelist: var_tcpWhitelist,
+      udpWhitelist: var_udpWhitelist,
+      socks5Port: var_socks5Port,
+    );
+  }
+
+  @protected
+  Forward sse_decode_forward(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bindAddr = sse_decode_String(deserializer);
+    var var_dstAddr = sse_decode_String(deserializer);
+    var var_proto = sse_decode_String(deserializer);
+    return Forward(
+      bindAddr: var_bindAddr,
+      dstAddr: var_dstAddr,
+      proto: var_proto,
+    );
+  }
+
+  @protected
+  ForwardConnectionInfo sse_decode_forward_connection_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_64(deserializer);
+    var var_peerAddr = sse_decode_String(deserializer);
+    var var_startedAt = sse_decode_i_64(deserializer);
+    var var_closedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_bytesSent = sse_decode_u_64(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    var var_closeReason = sse_decode_opt_String(deserializer);
+    return ForwardConnectionInfo(
+      id: var_id,
+      peerAddr: var_peerAddr,
+      startedAt: var_startedAt,
+      closedAt: var_closedAt,
+      bytesSent: var_bytesSent,
+      bytesReceived: var_bytesReceived,
+      closeReason: var_closeReason,
+    );
+  }
+
+  @protected
+  ForwardPatch sse_decode_forward_patch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_patch_action(deserializer);
+    var var_forward = sse_decode_forward(deserializer);
+    return ForwardPatch(action: var_action, forward: var_forward);
+  }
+
+  @protected
+  ForwardServerInfo sse_decode_forward_server_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_listenAddr = sse_decode_String(deserializer);
+    var var_forwardAddr = sse_decode_String(deserializer);
+    var var_protocol = sse_decode_String(deserializer);
+    var var_isRunning = sse_decode_bool(deserializer);
+    var var_connections = sse_decode_usize(deserializer);
+    var var_bytesSent = sse_decode_u_64(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    return ForwardServerInfo(
+      id: var_id,
+      listenAddr: var_listenAddr,
+      forwardAddr: var_forwardAddr,
+      protocol: var_protocol,
+      isRunning: var_isRunning,
+      connections: var_connections,
+      bytesSent: var_bytesSent,
+      bytesReceived: var_bytesReceived,
+    );
+  }
+
+  @protected
+  HolePunchChance sse_decode_hole_punch_chance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return HolePunchChance.values[inner];
+  }
+
+  @protected
+  HolePunchPrediction sse_decode_hole_punch_prediction(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerNatType = sse_decode_String(deserializer);
+    var var_chance = sse_decode_hole_punch_chance(deserializer);
+    return HolePunchPrediction(
+      peerNatType: var_peerNatType,
+      chance: var_chance,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  InstanceEvent sse_decode_instance_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_peerId = sse_decode_u_32(deserializer);
+        return InstanceEvent_PeerAdded(peerId: var_peerId);
+      case 1:
+        var var_peerId = sse_decode_u_32(deserializer);
+        return InstanceEvent_PeerRemoved(peerId: var_peerId);
+      case 2:
+        var var_conn = sse_decode_peer_conn_event(deserializer);
+        return InstanceEvent_PeerConnAdded(conn: var_conn);
+      case 3:
+        var var_conn = sse_decode_peer_conn_event(deserializer);
+        return InstanceEvent_PeerConnRemoved(conn: var_conn);
+      case 4:
+        var var_listener = sse_decode_String(deserializer);
+        return InstanceEvent_ListenerAdded(listener: var_listener);
+      case 5:
+        var var_listener = sse_decode_String(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return InstanceEvent_ListenerAddFailed(
+          listener: var_listener,
+          message: var_message,
+        );
+      case 6:
+        var var_listener = sse_decode_String(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return InstanceEvent_ListenerAcceptFailed(
+          listener: var_listener,
+          message: var_message,
+        );
+      case 7:
+        var var_local = sse_decode_String(deserializer);
+        var var_remote = sse_decode_String(deserializer);
+        return InstanceEvent_ConnectionAccepted(
+          local: var_local,
+          remote: var_remote,
+        );
+      case 8:
+        var var_local = sse_decode_String(deserializer);
+        var var_remote = sse_decode_String(deserializer);
+        var var_error = sse_decode_String(deserializer);
+        return InstanceEvent_ConnectionError(
+          local: var_local,
+          remote: var_remote,
+          error: var_error,
+        );
+      case 9:
+        var var_dev = sse_decode_String(deserializer);
+        return InstanceEvent_TunDeviceReady(dev: var_dev);
+      case 10:
+        var var_error = sse_decode_String(deserializer);
+        return InstanceEvent_TunDeviceError(error: var_error);
+      case 11:
+        var var_dst = sse_decode_String(deserializer);
+        return InstanceEvent_Connecting(dst: var_dst);
+      case 12:
+        var var_dst = sse_decode_String(deserializer);
+        var var_ipVersion = sse_decode_String(deserializer);
+        var var_error = sse_decode_String(deserializer);
+        return InstanceEvent_ConnectError(
+          dst: var_dst,
+          ipVersion: var_ipVersion,
+          error: var_error,
+        );
+      case 13:
+        var var_portal = sse_decode_String(deserializer);
+        return InstanceEvent_VpnPortalStarted(portal: var_portal);
+      case 14:
+        var var_portal = sse_decode_String(deserializer);
+        var var_clientAddr = sse_decode_String(deserializer);
+        return InstanceEvent_VpnPortalClientConnected(
+          portal: var_portal,
+          clientAddr: var_clientAddr,
+        );
+      case 15:
+        var var_portal = sse_decode_String(deserializer);
+        var var_clientAddr = sse_decode_String(deserializer);
+        return InstanceEvent_VpnPortalClientDisconnected(
+          portal: var_portal,
+          clientAddr: var_clientAddr,
+        );
+      case 16:
+        var var_oldIp = sse_decode_opt_String(deserializer);
+        var var_newIp = sse_decode_opt_String(deserializer);
+        return InstanceEvent_DhcpIpv4Changed(
+          oldIp: var_oldIp,
+          newIp: var_newIp,
+        );
+      case 17:
+        var var_ip = sse_decode_opt_String(deserializer);
+        return InstanceEvent_DhcpIpv4Conflicted(ip: var_ip);
+      case 18:
+        var var_bindAddr = sse_decode_String(deserializer);
+        var var_dstAddr = sse_decode_String(deserializer);
+        var var_proto = sse_decode_String(deserializer);
+        return InstanceEvent_PortForwardAdded(
+          bindAddr: var_bindAddr,
+          dstAddr: var_dstAddr,
+          proto: var_proto,
+        );
+      case 19:
+        var var_changes = sse_decode_list_config_change(deserializer);
+        return InstanceEvent_ConfigPatched(changes: var_changes);
+      case 20:
+        var var_added = sse_decode_list_String(deserializer);
+        var var_removed = sse_decode_list_String(deserializer);
+        return InstanceEvent_ProxyCidrsUpdated(
+          added: var_added,
+          removed: var_removed,
+        );
+      case 21:
+        var var_attempt = sse_decode_u_32(deserializer);
+        var var_delayMs = sse_decode_u_64(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return InstanceEvent_InstanceRestarting(
+          attempt: var_attempt,
+          delayMs: var_delayMs,
+          reason: var_reason,
+        );
+      case 22:
+        var var_attempt = sse_decode_u_32(deserializer);
+        return InstanceEvent_InstanceRestarted(attempt: var_attempt);
+      case 23:
+        var var_attempts = sse_decode_u_32(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return InstanceEvent_InstanceRestartGaveUp(
+          attempts: var_attempts,
+          reason: var_reason,
+        );
+      case 24:
+        var var_dropped = sse_decode_u_64(deserializer);
+        return InstanceEvent_EventsLagged(dropped: var_dropped);
+      case 25:
+        return InstanceEvent_EventsClosed();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  InstanceEventRecord sse_decode_instance_event_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_instanceId = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_event = sse_decode_instance_event(deserializer);
+    return InstanceEventRecord(
+      instanceId: var_instanceId,
+      timestamp: var_timestamp,
+      event: var_event,
+    );
+  }
+
+  @protected
+  InstancePatch sse_decode_instance_patch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peers = sse_decode_list_value_patch(deserializer);
+    var var_portForwards = sse_decode_list_forward_patch(deserializer);
+    var var_proxyCidrs = sse_decode_list_value_patch(deserializer);
+    var var_tcpWhitelist = sse_decode_list_value_patch(deserializer);
+    var var_udpWhitelist = sse_decode_list_value_patch(deserializer);
+    return InstancePatch(
+      peers: var_peers,
+      portForwards: var_portForwards,
+      proxyCidrs: var_proxyCidrs,
+      tcpWhitelist: var_tcpWhitelist,
+      udpWhitelist: var_udpWhitelist,
+    );
+  }
+
+  @protected
+  InvalidCidrPolicy sse_decode_invalid_cidr_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return InvalidCidrPolicy.values[inner];
+  }
+
+  @protected
+  InviteFlags sse_decode_invite_flags(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_defaultProtocol = sse_decode_opt_String(deserializer);
+    var var_enableEncryption = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_enableIpv6 = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_mtu = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_latencyFirst = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_disableP2P = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_enableUdpBroadcastRelay = sse_decode_opt_box_autoadd_bool(
+      deserializer,
+    );
+    var var_dataCompressAlgo = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_enableKcpProxy = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_enableQuicProxy = sse_decode_opt_box_autoadd_bool(deserializer);
+    return InviteFlags(
+      defaultProtocol: var_defaultProtocol,
+      enableEncryption: var_enableEncryption,
+      enableIpv6: var_enableIpv6,
+      mtu: var_mtu,
+      latencyFirst: var_latencyFirst,
+      disableP2P: var_disableP2P,
+      enableUdpBroadcastRelay: var_enableUdpBroadcastRelay,
+      dataCompressAlgo: var_dataCompressAlgo,
+      enableKcpProxy: var_enableKcpProxy,
+      enableQuicProxy: var_enableQuicProxy,
+    );
+  }
+
+  @protected
+  KVNetworkStatus sse_decode_kv_network_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalNodes = sse_decode_usize(deserializer);
+    var var_nodes = sse_decode_list_kv_node_info(deserializer);
+    return KVNetworkStatus(totalNodes: var_totalNodes, nodes: var_nodes);
+  }
+
+  @protected
+  KVNodeConnectionStats sse_decode_kv_node_connection_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connType = sse_decode_String(deserializer);
+    var var_rxBytes = sse_decode_u_64(deserializer);
+    var var_txBytes = sse_decode_u_64(deserializer);
+    var var_rxPackets = sse_decode_u_64(deserializer);
+    var var_txPackets = sse_decode_u_64(deserializer);
+    return KVNodeConnectionStats(
+      connType: var_connType,
+      rxBytes: var_rxBytes,
+      txBytes: var_txBytes,
+      rxPackets: var_rxPackets,
+      txPackets: var_txPackets,
+    );
+  }
+
+  @protected
+  KVNodeInfo sse_decode_kv_node_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_hostname = sse_decode_String(deserializer);
+    var var_ipv4 = sse_decode_String(deserializer);
+    var var_ipv6 = sse_decode_opt_String(deserializer);
+    var var_publicIpv6 = sse_decode_opt_String(deserializer);
+    var var_ipv6Prefix = sse_decode_opt_String(deserializer);
+    var var_latencyMs = sse_decode_f_64(deserializer);
+    var var_nat = sse_decode_String(deserializer);
+    var var_hops = sse_decode_list_node_hop_stats(deserializer);
+    var var_lossRate = sse_decode_f_32(deserializer);
+    var var_connections = sse_decode_list_kv_node_connection_stats(
+      deserializer,
+    );
+    var var_tunnelProto = sse_decode_String(deserializer);
+    var var_connType = sse_decode_String(deserializer);
+    var var_rxBytes = sse_decode_u_64(deserializer);
+    var var_txBytes = sse_decode_u_64(deserializer);
+    var var_version = sse_decode_String(deserializer);
+    var var_cost = sse_decode_i_32(deserializer);
+    var var_proxyCidrs = sse_decode_list_String(deserializer);
+    return KVNodeInfo(
+      peerId: var_peerId,
+      hostname: var_hostname,
+      ipv4: var_ipv4,
+      ipv6: var_ipv6,
+      publicIpv6: var_publicIpv6,
+      ipv6Prefix: var_ipv6Prefix,
+      latencyMs: var_latencyMs,
+      nat: var_nat,
+      hops: var_hops,
+      lossRate: var_lossRate,
+      connections: var_connections,
+      tunnelProto: var_tunnelProto,
+      connType: var_connType,
+      rxBytes: var_rxBytes,
+      txBytes: var_txBytes,
+      version: var_version,
+      cost: var_cost,
+      proxyCidrs: var_proxyCidrs,
+    );
+  }
+
+  @protected
+  List<PeerRoutePair>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerRoutePair>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConfigChange> sse_decode_list_config_change(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConfigChange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_config_change(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConfigIssue> sse_decode_list_config_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConfigIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_config_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Forward> sse_decode_list_forward(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Forward>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_forward(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ForwardConnectionInfo> sse_decode_list_forward_connection_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ForwardConnectionInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_forward_connection_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ForwardPatch> sse_decode_list_forward_patch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ForwardPatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_forward_patch(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ForwardServerInfo> sse_decode_list_forward_server_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ForwardServerInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_forward_server_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HolePunchPrediction> sse_decode_list_hole_punch_prediction(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HolePunchPrediction>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_hole_punch_prediction(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InstanceEventRecord> sse_decode_list_instance_event_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InstanceEventRecord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_instance_event_record(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KVNodeConnectionStats> sse_decode_list_kv_node_connection_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KVNodeConnectionStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_kv_node_connection_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KVNodeInfo> sse_decode_list_kv_node_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KVNodeInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_kv_node_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MagicWallRule> sse_decode_list_magic_wall_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MagicWallRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_magic_wall_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MagicWallRuleIssue> sse_decode_list_magic_wall_rule_issue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MagicWallRuleIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_magic_wall_rule_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MinecraftLanWorld> sse_decode_list_minecraft_lan_world(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MinecraftLanWorld>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_minecraft_lan_world(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MulticastListenerInfo> sse_decode_list_multicast_listener_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MulticastListenerInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_multicast_listener_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MulticastPacket> sse_decode_list_multicast_packet(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MulticastPacket>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_multicast_packet(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MulticastSenderInfo> sse_decode_list_multicast_sender_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MulticastSenderInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_multicast_sender_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NatBehaviorResult> sse_decode_list_nat_behavior_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NatBehaviorResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_nat_behavior_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NodeHopStats> sse_decode_list_node_hop_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NodeHopStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_node_hop_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, int)> sse_decode_list_record_string_u_32(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, int)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_u_32(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RunningConnInfo> sse_decode_list_running_conn_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RunningConnInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_running_conn_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RunningPeerInfo> sse_decode_list_running_peer_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RunningPeerInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_running_peer_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RunningRouteInfo> sse_decode_list_running_route_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RunningRouteInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_running_route_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ServerProbeResult> sse_decode_list_server_probe_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ServerProbeResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_server_probe_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<StunServerInfo> sse_decode_list_stun_server_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StunServerInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_stun_server_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<StunServerResult> sse_decode_list_stun_server_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StunServerResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_stun_server_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SupervisorStatus> sse_decode_list_supervisor_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SupervisorStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_supervisor_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ValuePatch> sse_decode_list_value_patch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ValuePatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_value_patch(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MagicWallPacket sse_decode_magic_wall_packet(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_protocol = sse_decode_String(deserializer);
+    var var_direction = sse_decode_String(deserializer);
+    var var_localIp = sse_decode_String(deserializer);
+    var var_remoteIp = sse_decode_String(deserializer);
+    var var_localPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+    var var_remotePort = sse_decode_opt_box_autoadd_u_16(deserializer);
+    var var_appPath = sse_decode_opt_String(deserializer);
+    return MagicWallPacket(
+      protocol: var_protocol,
+      direction: var_direction,
+      localIp: var_localIp,
+      remoteIp: var_remoteIp,
+      localPort: var_localPort,
+      remotePort: var_remotePort,
+      appPath: var_appPath,
+    );
+  }
+
+  @protected
+  MagicWallRule sse_decode_magic_wall_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_action = sse_decode_String(deserializer);
+    var var_protocol = sse_decode_String(deserializer);
+    var var_direction = sse_decode_String(deserializer);
+    var var_appPath = sse_decode_opt_String(deserializer);
+    var var_remoteIp = sse_decode_opt_String(deserializer);
+    var var_localIp = sse_decode_opt_String(deserializer);
+    var var_remotePort = sse_decode_opt_String(deserializer);
+    var var_localPort = sse_decode_opt_String(deserializer);
+    var var_priority = sse_decode_u_32(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return MagicWallRule(
+      id: var_id,
+      name: var_name,
+      enabled: var_enabled,
+      action: var_action,
+      protocol: var_protocol,
+      direction: var_direction,
+      appPath: var_appPath,
+      remoteIp: var_remoteIp,
+      localIp: var_localIp,
+      remotePort: var_remotePort,
+      localPort: var_localPort,
+      priority: var_priority,
+      description: var_description,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  MagicWallRuleIssue sse_decode_magic_wall_rule_issue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_magic_wall_rule_issue_kind(deserializer);
+    var var_ruleId = sse_decode_String(deserializer);
+    var var_otherRuleId = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return MagicWallRuleIssue(
+      kind: var_kind,
+      ruleId: var_ruleId,
+      otherRuleId: var_otherRuleId,
+      message: var_message,
+    );
+  }
+
+  @protected
+  MagicWallRuleIssueKind sse_decode_magic_wall_rule_issue_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MagicWallRuleIssueKind.values[inner];
+  }
+
+  @protected
+  MagicWallStatus sse_decode_magic_wall_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_isRunning = sse_decode_bool(deserializer);
+    var var_activeRules = sse_decode_usize(deserializer);
+    var var_totalRules = sse_decode_usize(deserializer);
+    return MagicWallStatus(
+      isRunning: var_isRunning,
+      activeRules: var_activeRules,
+      totalRules: var_totalRules,
+    );
+  }
+
+  @protected
+  MagicWallVerdict sse_decode_magic_wall_verdict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_String(deserializer);
+    var var_matchedRuleId = sse_decode_opt_String(deserializer);
+    return MagicWallVerdict(
+      action: var_action,
+      matchedRuleId: var_matchedRuleId,
+    );
+  }
+
+  @protected
+  MappingBehavior sse_decode_mapping_behavior(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MappingBehavior.values[inner];
+  }
+
+  @protected
+  MinecraftLanAnnouncement sse_decode_minecraft_lan_announcement(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_motd = sse_decode_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    return MinecraftLanAnnouncement(motd: var_motd, port: var_port);
+  }
+
+  @protected
+  MinecraftLanWorld sse_decode_minecraft_lan_world(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_host = sse_decode_String(deserializer);
+    var var_remotePort = sse_decode_u_16(deserializer);
+    var var_localPort = sse_decode_u_16(deserializer);
+    var var_motd = sse_decode_String(deserializer);
+    var var_lastSeen = sse_decode_i_64(deserializer);
+    return MinecraftLanWorld(
+      host: var_host,
+      remotePort: var_remotePort,
+      localPort: var_localPort,
+      motd: var_motd,
+      lastSeen: var_lastSeen,
+    );
+  }
+
+  @protected
+  MulticastListenerInfo sse_decode_multicast_listener_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_groupAddr = sse_decode_String(deserializer);
+    var var_interfaceAddr = sse_decode_String(deserializer);
+    var var_capacity = sse_decode_usize(deserializer);
+    var var_packetsReceived = sse_decode_u_64(deserializer);
+    var var_isRunning = sse_decode_bool(deserializer);
+    return MulticastListenerInfo(
+      id: var_id,
+      groupAddr: var_groupAddr,
+      interfaceAddr: var_interfaceAddr,
+      capacity: var_capacity,
+      packetsReceived: var_packetsReceived,
+      isRunning: var_isRunning,
+    );
+  }
+
+  @protected
+  MulticastPacket sse_decode_multicast_packet(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_source = sse_decode_String(deserializer);
+    var var_size = sse_decode_usize(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    return MulticastPacket(
+      source: var_source,
+      size: var_size,
+      timestamp: var_timestamp,
+      data: var_data,
+    );
+  }
+
+  @protected
+  MulticastSenderInfo sse_decode_multicast_sender_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_multicastAddr = sse_decode_String(deserializer);
+    var var_bindAddr = sse_decode_String(deserializer);
+    var var_intervalMs = sse_decode_u_64(deserializer);
+    var var_dataLen = sse_decode_usize(deserializer);
+    var var_isRunning = sse_decode_bool(deserializer);
+    return MulticastSenderInfo(
+      id: var_id,
+      multicastAddr: var_multicastAddr,
+      bindAddr: var_bindAddr,
+      intervalMs: var_intervalMs,
+      dataLen: var_dataLen,
+      isRunning: var_isRunning,
+    );
+  }
+
+  @protected
+  NatBehaviorResult sse_decode_nat_behavior_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_protocol = sse_decode_String(deserializer);
+    var var_ipVersion = sse_decode_String(deserializer);
+    var var_server = sse_decode_opt_String(deserializer);
+    var var_localAddr = sse_decode_opt_String(deserializer);
+    var var_mappedAddr = sse_decode_opt_String(deserializer);
+    var var_rttMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_mapping = sse_decode_mapping_behavior(deserializer);
+    var var_filtering = sse_decode_filtering_behavior(deserializer);
+    var var_hairpinning = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_portPreserved = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return NatBehaviorResult(
+      protocol: var_protocol,
+      ipVersion: var_ipVersion,
+      server: var_server,
+      localAddr: var_localAddr,
+      mappedAddr: var_mappedAddr,
+      rttMs: var_rttMs,
+      mapping: var_mapping,
+      filtering: var_filtering,
+      hairpinning: var_hairpinning,
+      portPreserved: var_portPreserved,
+      error: var_error,
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandleResultString =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString;
+  @protected
+  NatType sse_decode_nat_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NatType.values[inner];
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandleResultStringString =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString;
+  @protected
+  NetworkConfigC sse_decode_network_config_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_username = sse_decode_String(deserializer);
+    var var_enableDhcp = sse_decode_bool(deserializer);
+    var var_specifiedIp = sse_decode_String(deserializer);
+    var var_roomName = sse_decode_String(deserializer);
+    var var_roomPassword = sse_decode_String(deserializer);
+    var var_severurl = sse_decode_list_String(deserializer);
+    var var_onurl = sse_decode_list_String(deserializer);
+    var var_cidrs = sse_decode_list_String(deserializer);
+    var var_forwards = sse_decode_list_forward(deserializer);
+    var var_flag = sse_decode_flags_c(deserializer);
+    var var_options = sse_decode_config_build_options(deserializer);
+    var var_relayPool = sse_decode_opt_box_autoadd_relay_pool(deserializer);
+    return NetworkConfigC(
+      username: var_username,
+      enableDhcp: var_enableDhcp,
+      specifiedIp: var_specifiedIp,
+      roomName: var_roomName,
+      roomPassword: var_roomPassword,
+      severurl: var_severurl,
+      onurl: var_onurl,
+      cidrs: var_cidrs,
+      forwards: var_forwards,
+      flag: var_flag,
+      options: var_options,
+      relayPool: var_relayPool,
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandleResultStringString =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString;
+  @protected
+  NetworkTestResult sse_decode_network_test_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_natTypeV4 = sse_decode_String(deserializer);
+    var var_natTypeV6 = sse_decode_String(deserializer);
+    var var_ipv4Latency = sse_decode_i_64(deserializer);
+    var var_ipv6Latency = sse_decode_i_64(deserializer);
+    var var_natTypeV4Raw = sse_decode_opt_String(deserializer);
+    var var_errorV4 = sse_decode_opt_String(deserializer);
+    var var_errorV6 = sse_decode_opt_String(deserializer);
+    var var_publicEndpointV4 = sse_decode_opt_String(deserializer);
+    var var_publicEndpointV6 = sse_decode_opt_String(deserializer);
+    var var_stunServers = sse_decode_list_stun_server_result(deserializer);
+    var var_holePunch = sse_decode_list_hole_punch_prediction(deserializer);
+    return NetworkTestResult(
+      natTypeV4: var_natTypeV4,
+      natTypeV6: var_natTypeV6,
+      ipv4Latency: var_ipv4Latency,
+      ipv6Latency: var_ipv6Latency,
+      natTypeV4Raw: var_natTypeV4Raw,
+      errorV4: var_errorV4,
+      errorV6: var_errorV6,
+      publicEndpointV4: var_publicEndpointV4,
+      publicEndpointV6: var_publicEndpointV6,
+      stunServers: var_stunServers,
+      holePunch: var_holePunch,
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_MulticastSender =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender;
+  @protected
+  NodeHopStats sse_decode_node_hop_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_targetIp = sse_decode_String(deserializer);
+    var var_latencyMs = sse_decode_f_64(deserializer);
+    var var_packetLoss = sse_decode_f_32(deserializer);
+    var var_nodeName = sse_decode_String(deserializer);
+    return NodeHopStats(
+      peerId: var_peerId,
+      targetIp: var_targetIp,
+      latencyMs: var_latencyMs,
+      packetLoss: var_packetLoss,
+      nodeName: var_nodeName,
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_MulticastSender =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender;
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_PeerRoutePair =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair;
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_PeerRoutePair =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair;
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ServerStats =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats;
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ServerStats =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats;
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  EventBusSubscriber
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    dynamic raw,
+  MinecraftLanAnnouncement?
+  sse_decode_opt_box_autoadd_minecraft_lan_announcement(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EventBusSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_minecraft_lan_announcement(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PatchAction? sse_decode_opt_box_autoadd_patch_action(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_patch_action(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProbeErrorKind? sse_decode_opt_box_autoadd_probe_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_probe_error_kind(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RelayPool? sse_decode_opt_box_autoadd_relay_pool(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_relay_pool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RestartPolicy? sse_decode_opt_box_autoadd_restart_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_restart_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RunningNodeInfo? sse_decode_opt_box_autoadd_running_node_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_running_node_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RunningStunInfo? sse_decode_opt_box_autoadd_running_stun_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_running_stun_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SupervisorStatus? sse_decode_opt_box_autoadd_supervisor_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_supervisor_status(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_usize(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PatchAction sse_decode_patch_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PatchAction.values[inner];
+  }
+
+  @protected
+  PeerConnEvent sse_decode_peer_conn_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connId = sse_decode_String(deserializer);
+    var var_myPeerId = sse_decode_u_32(deserializer);
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_tunnelType = sse_decode_String(deserializer);
+    var var_localAddr = sse_decode_String(deserializer);
+    var var_remoteAddr = sse_decode_String(deserializer);
+    return PeerConnEvent(
+      connId: var_connId,
+      myPeerId: var_myPeerId,
+      peerId: var_peerId,
+      tunnelType: var_tunnelType,
+      localAddr: var_localAddr,
+      remoteAddr: var_remoteAddr,
+    );
+  }
+
+  @protected
+  ProbeErrorKind sse_decode_probe_error_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ProbeErrorKind.values[inner];
   }
 
   @protected
-  ForwardServer
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ForwardServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  (String, int) sse_decode_record_string_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_u_32(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  JoinHandle
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    dynamic raw,
+  (BigInt, BigInt, BigInt) sse_decode_record_usize_u_64_u_64(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_usize(deserializer);
+    var var_field1 = sse_decode_u_64(deserializer);
+    var var_field2 = sse_decode_u_64(deserializer);
+    return (var_field0, var_field1, var_field2);
   }
 
   @protected
-  JoinHandleResultString
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleResultStringImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
+  RelayPool sse_decode_relay_pool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_candidates = sse_decode_list_String(deserializer);
+    var var_bestN = sse_decode_usize(deserializer);
+    var var_reevaluateIntervalSecs = sse_decode_u_64(deserializer);
+    var var_minImprovementMs = sse_decode_i_64(deserializer);
+    return RelayPool(
+      candidates: var_candidates,
+      bestN: var_bestN,
+      reevaluateIntervalSecs: var_reevaluateIntervalSecs,
+      minImprovementMs: var_minImprovementMs,
     );
   }
 
   @protected
-  JoinHandleResultStringString
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleResultStringStringImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
+  RestartPolicy sse_decode_restart_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxRetries = sse_decode_u_32(deserializer);
+    var var_initialBackoffMs = sse_decode_u_64(deserializer);
+    var var_maxBackoffMs = sse_decode_u_64(deserializer);
+    var var_restartOnTunError = sse_decode_bool(deserializer);
+    return RestartPolicy(
+      maxRetries: var_maxRetries,
+      initialBackoffMs: var_initialBackoffMs,
+      maxBackoffMs: var_maxBackoffMs,
+      restartOnTunError: var_restartOnTunError,
     );
   }
 
   @protected
-  MulticastSender
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MulticastSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  RoomInvite sse_decode_room_invite(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_roomName = sse_decode_String(deserializer);
+    var var_roomPassword = sse_decode_String(deserializer);
+    var var_servers = sse_decode_list_String(deserializer);
+    var var_ipRange = sse_decode_opt_String(deserializer);
+    var var_flags = sse_decode_invite_flags(deserializer);
+    var var_expiresAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_verified = sse_decode_bool(deserializer);
+    return RoomInvite(
+      roomName: var_roomName,
+      roomPassword: var_roomPassword,
+      servers: var_servers,
+      ipRange: var_ipRange,
+      flags: var_flags,
+      expiresAt: var_expiresAt,
+      verified: var_verified,
+    );
+  }
+
+  @protected
+  RunningConnInfo sse_decode_running_conn_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connId = sse_decode_String(deserializer);
+    var var_tunnelType = sse_decode_String(deserializer);
+    var var_localAddr = sse_decode_String(deserializer);
+    var var_remoteAddr = sse_decode_String(deserializer);
+    var var_isClient = sse_decode_bool(deserializer);
+    var var_networkName = sse_decode_String(deserializer);
+    var var_latencyMs = sse_decode_f_64(deserializer);
+    var var_lossRate = sse_decode_f_32(deserializer);
+    var var_rxBytes = sse_decode_u_64(deserializer);
+    var var_txBytes = sse_decode_u_64(deserializer);
+    var var_rxPackets = sse_decode_u_64(deserializer);
+    var var_txPackets = sse_decode_u_64(deserializer);
+    return RunningConnInfo(
+      connId: var_connId,
+      tunnelType: var_tunnelType,
+      localAddr: var_localAddr,
+      remoteAddr: var_remoteAddr,
+      isClient: var_isClient,
+      networkName: var_networkName,
+      latencyMs: var_latencyMs,
+      lossRate: var_lossRate,
+      rxBytes: var_rxBytes,
+      txBytes: var_txBytes,
+      rxPackets: var_rxPackets,
+      txPackets: var_txPackets,
+    );
   }
 
   @protected
-  PeerRoutePair
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PeerRoutePairImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  RunningInfo sse_decode_running_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_devName = sse_decode_String(deserializer);
+    var var_running = sse_decode_bool(deserializer);
+    var var_errorMsg = sse_decode_opt_String(deserializer);
+    var var_myNode = sse_decode_opt_box_autoadd_running_node_info(deserializer);
+    var var_peers = sse_decode_list_running_peer_info(deserializer);
+    var var_routes = sse_decode_list_running_route_info(deserializer);
+    return RunningInfo(
+      devName: var_devName,
+      running: var_running,
+      errorMsg: var_errorMsg,
+      myNode: var_myNode,
+      peers: var_peers,
+      routes: var_routes,
+    );
   }
 
   @protected
-  ServerStats
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ServerStatsImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  RunningNodeInfo sse_decode_running_node_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_hostname = sse_decode_String(deserializer);
+    var var_version = sse_decode_String(deserializer);
+    var var_virtualIpv4 = sse_decode_opt_String(deserializer);
+    var var_virtualIpv6 = sse_decode_opt_String(deserializer);
+    var var_listeners = sse_decode_list_String(deserializer);
+    var var_publicIpv4 = sse_decode_opt_String(deserializer);
+    var var_publicIpv6 = sse_decode_opt_String(deserializer);
+    var var_interfaceIpv4S = sse_decode_list_String(deserializer);
+    var var_interfaceIpv6S = sse_decode_list_String(deserializer);
+    var var_stun = sse_decode_opt_box_autoadd_running_stun_info(deserializer);
+    return RunningNodeInfo(
+      peerId: var_peerId,
+      hostname: var_hostname,
+      version: var_version,
+      virtualIpv4: var_virtualIpv4,
+      virtualIpv6: var_virtualIpv6,
+      listeners: var_listeners,
+      publicIpv4: var_publicIpv4,
+      publicIpv6: var_publicIpv6,
+      interfaceIpv4S: var_interfaceIpv4S,
+      interfaceIpv6S: var_interfaceIpv6S,
+      stun: var_stun,
+    );
   }
 
   @protected
-  ForwardServer
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ForwardServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  RunningPeerInfo sse_decode_running_peer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_connections = sse_decode_list_running_conn_info(deserializer);
+    return RunningPeerInfo(peerId: var_peerId, connections: var_connections);
   }
 
   @protected
-  MulticastSender
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MulticastSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  RunningRouteInfo sse_decode_running_route_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_instId = sse_decode_String(deserializer);
+    var var_hostname = sse_decode_String(deserializer);
+    var var_version = sse_decode_String(deserializer);
+    var var_ipv4 = sse_decode_opt_String(deserializer);
+    var var_ipv6 = sse_decode_opt_String(deserializer);
+    var var_nextHopPeerId = sse_decode_u_32(deserializer);
+    var var_cost = sse_decode_i_32(deserializer);
+    var var_pathLatency = sse_decode_i_32(deserializer);
+    var var_proxyCidrs = sse_decode_list_String(deserializer);
+    var var_udpNatType = sse_decode_String(deserializer);
+    return RunningRouteInfo(
+      peerId: var_peerId,
+      instId: var_instId,
+      hostname: var_hostname,
+      version: var_version,
+      ipv4: var_ipv4,
+      ipv6: var_ipv6,
+      nextHopPeerId: var_nextHopPeerId,
+      cost: var_cost,
+      pathLatency: var_pathLatency,
+      proxyCidrs: var_proxyCidrs,
+      udpNatType: var_udpNatType,
+    );
   }
 
   @protected
-  ForwardServer
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ForwardServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  RunningStunInfo sse_decode_running_stun_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_udpNatType = sse_decode_String(deserializer);
+    var var_tcpNatType = sse_decode_String(deserializer);
+    var var_publicIps = sse_decode_list_String(deserializer);
+    var var_minPort = sse_decode_u_32(deserializer);
+    var var_maxPort = sse_decode_u_32(deserializer);
+    var var_lastUpdateTime = sse_decode_i_64(deserializer);
+    return RunningStunInfo(
+      udpNatType: var_udpNatType,
+      tcpNatType: var_tcpNatType,
+      publicIps: var_publicIps,
+      minPort: var_minPort,
+      maxPort: var_maxPort,
+      lastUpdateTime: var_lastUpdateTime,
+    );
   }
 
   @protected
-  MulticastSender
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    dynamic raw,
+  ServerProbeResult sse_decode_server_probe_result(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MulticastSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_reachable = sse_decode_bool(deserializer);
+    var var_resolvedAddrs = sse_decode_list_String(deserializer);
+    var var_dnsMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_connectMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_rttMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_remotePeerId = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_errorKind = sse_decode_opt_box_autoadd_probe_error_kind(
+      deserializer,
+    );
+    var var_error = sse_decode_opt_String(deserializer);
+    return ServerProbeResult(
+      url: var_url,
+      reachable: var_reachable,
+      resolvedAddrs: var_resolvedAddrs,
+      dnsMs: var_dnsMs,
+      connectMs: var_connectMs,
+      rttMs: var_rttMs,
+      remotePeerId: var_remotePeerId,
+      errorKind: var_errorKind,
+      error: var_error,
+    );
   }
 
   @protected
-  ServerStats
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ServerStatsImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  StunServerInfo sse_decode_stun_server_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_primaryAddr = sse_decode_String(deserializer);
+    var var_alternateAddr = sse_decode_String(deserializer);
+    var var_running = sse_decode_bool(deserializer);
+    var var_requests = sse_decode_u_64(deserializer);
+    return StunServerInfo(
+      id: var_id,
+      primaryAddr: var_primaryAddr,
+      alternateAddr: var_alternateAddr,
+      running: var_running,
+      requests: var_requests,
+    );
   }
 
   @protected
-  EventBusSubscriber
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EventBusSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  StunServerResult sse_decode_stun_server_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_server = sse_decode_String(deserializer);
+    var var_ipVersion = sse_decode_String(deserializer);
+    var var_success = sse_decode_bool(deserializer);
+    var var_latency = sse_decode_i_64(deserializer);
+    var var_mappedAddr = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return StunServerResult(
+      server: var_server,
+      ipVersion: var_ipVersion,
+      success: var_success,
+      latency: var_latency,
+      mappedAddr: var_mappedAddr,
+      error: var_error,
+    );
   }
 
   @protected
-  ForwardServer
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ForwardServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  SupervisorState sse_decode_supervisor_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SupervisorState.values[inner];
   }
 
   @protected
-  JoinHandle
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  SupervisorStatus sse_decode_supervisor_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_instanceId = sse_decode_String(deserializer);
+    var var_policy = sse_decode_restart_policy(deserializer);
+    var var_state = sse_decode_supervisor_state(deserializer);
+    var var_restartCount = sse_decode_u_32(deserializer);
+    var var_consecutiveFailures = sse_decode_u_32(deserializer);
+    var var_lastFailure = sse_decode_opt_String(deserializer);
+    var var_lastFailureAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_nextRestartAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return SupervisorStatus(
+      instanceId: var_instanceId,
+      policy: var_policy,
+      state: var_state,
+      restartCount: var_restartCount,
+      consecutiveFailures: var_consecutiveFailures,
+      lastFailure: var_lastFailure,
+      lastFailureAt: var_lastFailureAt,
+      nextRestartAt: var_nextRestartAt,
+    );
   }
 
   @protected
-  JoinHandleResultString
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleResultStringImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
-    );
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
   }
 
   @protected
-  JoinHandleResultStringString
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleResultStringStringImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
-    );
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  MulticastSender
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MulticastSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  PeerRoutePair
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PeerRoutePairImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  ServerStats
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ServerStatsImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  ValuePatch sse_decode_value_patch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_patch_action(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return ValuePatch(action: var_action, value: var_value);
   }
 
   @protected
-  FlagsC dco_decode_box_autoadd_flags_c(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flags_c(raw);
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_i_64(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    ForwardServer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ForwardServerImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  MagicWallRule dco_decode_box_autoadd_magic_wall_rule(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_magic_wall_rule(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    JoinHandleResultString self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as JoinHandleResultStringImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    JoinHandleResultStringString self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as JoinHandleResultStringStringImpl).frbInternalSseEncode(
+        move: true,
+      ),
+      serializer,
+    );
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    MinecraftLanBridge self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MinecraftLanBridgeImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  FlagsC dco_decode_flags_c(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 30)
-      throw Exception('unexpected arr length: expect 30 but see ${arr.length}');
-    return FlagsC(
-      defaultProtocol: dco_decode_String(arr[0]),
-      devName: dco_decode_String(arr[1]),
-      enableEncryption: dco_decode_bool(arr[2]),
-      enableIpv6: dco_decode_bool(arr[3]),
-      mtu: dco_decode_u_32(arr[4]),
-      latencyFirst: dco_decode_bool(arr[5]),
-      enableExitNode: dco_decode_bool(arr[6]),
-      noTun: dco_decode_bool(arr[7]),
-      useSmoltcp: dco_decode_bool(arr[8]),
-      relayNetworkWhitelist: dco_decode_String(arr[9]),
-      disableP2P: dco_decode_bool(arr[10]),
-      enableUdpBroadcastRelay: dco_decode_bool(arr[11]),
-      relayAllPeerRpc: dco_decode_bool(arr[12]),
-      disableUdpHolePunching: dco_decode_bool(arr[13]),
-      disableTcpHolePunching: dco_decode_bool(arr[14]),
-      multiThread: dco_decode_bool(arr[15]),
-      dataCompressAlgo: dco_decode_i_32(arr[16]),
-      bindDevice: dco_decode_bool(arr[17]),
-      enableKcpProxy: dco_decode_bool(arr[18]),
-      disableKcpInput: dco_decode_bool(arr[19]),
-      disableRelayKcp: dco_decode_bool(arr[20]),
-      proxyForwardBySystem: dco_decode_bool(arr[21]),
-      acceptDns: dco_decode_bool(arr[22]),
-      privateMode: dco_decode_bool(arr[23]),
-      enableQuicProxy: dco_decode_bool(arr[24]),
-      disableQuicInput: dco_decode_bool(arr[25]),
-      disableSymHolePunching: dco_decode_bool(arr[26]),
-      tcpWhitelist: dco_decode_String(arr[27]),
-      udpWhitelist: dco_decode_String(arr[28]),
-      socks5Port: dco_decode_u_16(arr[29]),
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    MulticastListener self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MulticastListenerImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  Forward dco_decode_forward(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return Forward(
-      bindAddr: dco_decode_String(arr[0]),
-      dstAddr: dco_decode_String(arr[1]),
-      proto: dco_decode_String(arr[2]),
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    MulticastSender self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MulticastSenderImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    PeerRoutePair self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as PeerRoutePairImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    ServerStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ServerStatsImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  KVNetworkStatus dco_decode_kv_network_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return KVNetworkStatus(
-      totalNodes: dco_decode_usize(arr[0]),
-      nodes: dco_decode_list_kv_node_info(arr[1]),
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    ForwardServer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ForwardServerImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  KVNodeConnectionStats dco_decode_kv_node_connection_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return KVNodeConnectionStats(
-      connType: dco_decode_String(arr[0]),
-      rxBytes: dco_decode_u_64(arr[1]),
-      txBytes: dco_decode_u_64(arr[2]),
-      rxPackets: dco_decode_u_64(arr[3]),
-      txPackets: dco_decode_u_64(arr[4]),
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    MinecraftLanBridge self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MinecraftLanBridgeImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  KVNodeInfo dco_decode_kv_node_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 15)
-      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
-    return KVNodeInfo(
-      peerId: dco_decode_u_32(arr[0]),
-      hostname: dco_decode_String(arr[1]),
-      ipv4: dco_decode_String(arr[2]),
-      latencyMs: dco_decode_f_64(arr[3]),
-      nat: dco_decode_String(arr[4]),
-      hops: dco_decode_list_node_hop_stats(arr[5]),
-      lossRate: dco_decode_f_32(arr[6]),
-      connections: dco_decode_list_kv_node_connection_stats(arr[7]),
-      tunnelProto: dco_decode_String(arr[8]),
-      connType: dco_decode_String(arr[9]),
-      rxBytes: dco_decode_u_64(arr[10]),
-      txBytes: dco_decode_u_64(arr[11]),
-      version: dco_decode_String(arr[12]),
-      cost: dco_decode_i_32(arr[13]),
-      proxyCidrs: dco_decode_list_String(arr[14]),
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    MulticastListener self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MulticastListenerImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  List<PeerRoutePair>
-  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    dynamic raw,
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    MulticastSender self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(
-          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
-        )
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MulticastSenderImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    ForwardServer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ForwardServerImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  List<Forward> dco_decode_list_forward(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_forward).toList();
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    MinecraftLanBridge self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MinecraftLanBridgeImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  List<KVNodeConnectionStats> dco_decode_list_kv_node_connection_stats(
-    dynamic raw,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    MulticastListener self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_kv_node_connection_stats)
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MulticastListenerImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  List<KVNodeInfo> dco_decode_list_kv_node_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_kv_node_info).toList();
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    MulticastSender self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MulticastSenderImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  List<MagicWallRule> dco_decode_list_magic_wall_rule(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_magic_wall_rule).toList();
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    ServerStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ServerStatsImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  List<NodeHopStats> dco_decode_list_node_hop_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_node_hop_stats).toList();
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    ForwardServer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ForwardServerImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    JoinHandleResultString self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as JoinHandleResultStringImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    JoinHandleResultStringString self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as JoinHandleResultStringStringImpl).frbInternalSseEncode(
+        move: null,
+      ),
+      serializer,
+    );
   }
 
   @protected
-  List<(String, int)> dco_decode_list_record_string_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_u_32).toList();
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    MinecraftLanBridge self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MinecraftLanBridgeImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  MagicWallRule dco_decode_magic_wall_rule(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 13)
-      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
-    return MagicWallRule(
-      id: dco_decode_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-      enabled: dco_decode_bool(arr[2]),
-      action: dco_decode_String(arr[3]),
-      protocol: dco_decode_String(arr[4]),
-      direction: dco_decode_String(arr[5]),
-      appPath: dco_decode_opt_String(arr[6]),
-      remoteIp: dco_decode_opt_String(arr[7]),
-      localIp: dco_decode_opt_String(arr[8]),
-      remotePort: dco_decode_opt_String(arr[9]),
-      localPort: dco_decode_opt_String(arr[10]),
-      description: dco_decode_opt_String(arr[11]),
-      createdAt: dco_decode_opt_box_autoadd_i_64(arr[12]),
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    MulticastListener self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MulticastListenerImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  MagicWallStatus dco_decode_magic_wall_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return MagicWallStatus(
-      isRunning: dco_decode_bool(arr[0]),
-      activeRules: dco_decode_usize(arr[1]),
-      totalRules: dco_decode_usize(arr[2]),
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    MulticastSender self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MulticastSenderImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  NatType dco_decode_nat_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return NatType.values[raw as int];
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    PeerRoutePair self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as PeerRoutePairImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  NetworkTestResult dco_decode_network_test_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return NetworkTestResult(
-      natTypeV4: dco_decode_String(arr[0]),
-      natTypeV6: dco_decode_String(arr[1]),
-      ipv4Latency: dco_decode_i_64(arr[2]),
-      ipv6Latency: dco_decode_i_64(arr[3]),
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    ServerStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ServerStatsImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  NodeHopStats dco_decode_node_hop_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return NodeHopStats(
-      peerId: dco_decode_u_32(arr[0]),
-      targetIp: dco_decode_String(arr[1]),
-      latencyMs: dco_decode_f_64(arr[2]),
-      packetLoss: dco_decode_f_32(arr[3]),
-      nodeName: dco_decode_String(arr[4]),
+  void sse_encode_StreamSink_instance_event_record_Sse(
+    RustStreamSink<InstanceEventRecord> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_instance_event_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_StreamSink_multicast_packet_Sse(
+    RustStreamSink<MulticastPacket> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_multicast_packet,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  (String, int) dco_decode_record_string_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_u_32(arr[1]));
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  (BigInt, BigInt, BigInt) dco_decode_record_usize_u_64_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3) {
-      throw Exception('Expected 3 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_usize(arr[0]),
-      dco_decode_u_64(arr[1]),
-      dco_decode_u_64(arr[2]),
-    );
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
   }
 
   @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_flags_c(FlagsC self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_flags_c(self, serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_instance_patch(
+    InstancePatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_instance_patch(self, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_box_autoadd_invite_flags(
+    InviteFlags self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_invite_flags(self, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_box_autoadd_magic_wall_packet(
+    MagicWallPacket self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_magic_wall_packet(self, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_magic_wall_rule(
+    MagicWallRule self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_magic_wall_rule(self, serializer);
   }
 
   @protected
-  EventBusSubscriber
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_minecraft_lan_announcement(
+    MinecraftLanAnnouncement self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return EventBusSubscriberImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_minecraft_lan_announcement(self, serializer);
   }
 
   @protected
-  ForwardServer
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_network_config_c(
+    NetworkConfigC self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ForwardServerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_network_config_c(self, serializer);
   }
 
   @protected
-  JoinHandle
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_patch_action(
+    PatchAction self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_patch_action(self, serializer);
   }
 
   @protected
-  JoinHandleResultString
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_probe_error_kind(
+    ProbeErrorKind self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleResultStringImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_probe_error_kind(self, serializer);
   }
 
   @protected
-  JoinHandleResultStringString
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_relay_pool(
+    RelayPool self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleResultStringStringImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_relay_pool(self, serializer);
   }
 
   @protected
-  MulticastSender
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_restart_policy(
+    RestartPolicy self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return MulticastSenderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_restart_policy(self, serializer);
   }
 
   @protected
-  PeerRoutePair
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_room_invite(
+    RoomInvite self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return PeerRoutePairImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_room_invite(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_running_node_info(
+    RunningNodeInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_running_node_info(self, serializer);
   }
 
   @protected
-  ServerStats
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_running_stun_info(
+    RunningStunInfo self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ServerStatsImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_running_stun_info(self, serializer);
   }
 
   @protected
-  ForwardServer
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_supervisor_status(
+    SupervisorStatus self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ForwardServerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_supervisor_status(self, serializer);
   }
 
   @protected
-  MulticastSender
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return MulticastSenderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_u_16(self, serializer);
   }
 
   @protected
-  ForwardServer
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ForwardServerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  MulticastSender
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return MulticastSenderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  ServerStats
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ServerStatsImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_usize(self, serializer);
   }
 
   @protected
-  EventBusSubscriber
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    SseDeserializer deserializer,
+  void sse_encode_config_build_options(
+    ConfigBuildOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return EventBusSubscriberImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_u_8(self.ipv4PrefixLen, serializer);
+    sse_encode_invalid_cidr_policy(self.invalidCidrPolicy, serializer);
+    sse_encode_bool(self.customDevName, serializer);
   }
 
   @protected
-  ForwardServer
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_config_change(ConfigChange self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ForwardServerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_String(self.field, serializer);
+    sse_encode_opt_box_autoadd_patch_action(self.action, serializer);
+    sse_encode_String(self.value, serializer);
   }
 
   @protected
-  JoinHandle
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_config_issue(ConfigIssue self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_String(self.field, serializer);
+    sse_encode_String(self.value, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  JoinHandleResultString
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleResultStringImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  JoinHandleResultStringString
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleResultStringStringImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  MulticastSender
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    SseDeserializer deserializer,
+  void sse_encode_filtering_behavior(
+    FilteringBehavior self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return MulticastSenderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  PeerRoutePair
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_flags_c(FlagsC self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return PeerRoutePairImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_String(self.defaultProtocol, serializer);
+    sse_encode_String(self.devName, serializer);
+    sse_encode_bool(self.enableEncryption, serializer);
+    sse_encode_bool(self.enableIpv6, serializer);
+    sse_encode_u_32(self.mtu, serializer);
+    sse_encode_bool(self.latencyFirst, serializer);
+    sse_encode_bool(self.enableExitNode, serializer);
+    sse_encode_bool(self.noTun, serializer);
+    sse_encode_bool(self.useSmoltcp, serializer);
+    sse_encode_String(self.relayNetworkWhitelist, serializer);
+    sse_encode_bool(self.disableP2P, serializer);
+    sse_encode_bool(self.enableUdpBroadcastRelay, serializer);
+    sse_encode_bool(self.relayAllPeerRpc, serializer);
+    sse_encode_bool(self.disableUdpHolePunching, serializer);
+    sse_encode_bool(self.disableTcpHolePunching, serializer);
+    sse_encode_bool(self.multiThread, serializer);
+    sse_encode_i_32(self.dataCompressAlgo, serializer);
+    sse_encode_bool(self.bindDevice, serializer);
+    sse_encode_bool(self.enableKcpProxy, serializer);
+    sse_encode_bool(self.disableKcpInput, serializer);
+    sse_encode_bool(self.disableRelayKcp, serializer);
+    sse_encode_bool(self.proxyForwardBySystem, serializer);
+    sse_encode_bool(self.acceptDns, serializer);
+    sse_encode_bool(self.privateMode, serializer);
+    sse_encode_bool(self.enableQuicProxy, serializer);
+    sse_encode_bool(self.disableQuicInput, serializer);
+    sse_encode_bool(self.disableSymHolePunching, serializer);
+    sse_encode_String(self.tcpWhitelist, serializer);
+    sse_encode_String(self.udpWhitelist, serializer);
+    sse_encode_u_16(self.socks5Port, serializer);
   }
 
   @protected
-  ServerStats
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_forward(Forward self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ServerStatsImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_String(self.bindAddr, serializer);
+    sse_encode_String(self.dstAddr, serializer);
+    sse_encode_String(self.proto, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_forward_connection_info(
+    ForwardConnectionInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_u_64(self.id, serializer);
+    sse_encode_String(self.peerAddr, serializer);
+    sse_encode_i_64(self.startedAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.closedAt, serializer);
+    sse_encode_u_64(self.bytesSent, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_opt_String(self.closeReason, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_forward_patch(ForwardPatch self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_patch_action(self.action, serializer);
+    sse_encode_forward(self.forward, serializer);
   }
 
   @protected
-  FlagsC sse_decode_box_autoadd_flags_c(SseDeserializer deserializer) {
+  void sse_encode_forward_server_info(
+    ForwardServerInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flags_c(deserializer));
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.listenAddr, serializer);
+    sse_encode_String(self.forwardAddr, serializer);
+    sse_encode_String(self.protocol, serializer);
+    sse_encode_bool(self.isRunning, serializer);
+    sse_encode_usize(self.connections, serializer);
+    sse_encode_u_64(self.bytesSent, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
   }
 
   @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+  void sse_encode_hole_punch_chance(
+    HolePunchChance self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_64(deserializer));
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  MagicWallRule sse_decode_box_autoadd_magic_wall_rule(
-    SseDeserializer deserializer,
+  void sse_encode_hole_punch_prediction(
+    HolePunchPrediction self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_magic_wall_rule(deserializer));
+    sse_encode_String(self.peerNatType, serializer);
+    sse_encode_hole_punch_chance(self.chance, serializer);
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  FlagsC sse_decode_flags_c(SseDeserializer deserializer) {
+  void sse_encode_instance_event(InstanceEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_defaultProtocol = sse_decode_String(deserializer);
-    var var_devName = sse_decode_String(deserializer);
-    var var_enableEncryption = sse_decode_bool(deserializer);
-    var var_enableIpv6 = sse_decode_bool(deserializer);
-    var var_mtu = sse_decode_u_32(deserializer);
-    var var_latencyFirst = sse_decode_bool(deserializer);
-    var var_enableExitNode = sse_decode_bool(deserializer);
-    var var_noTun = sse_decode_bool(deserializer);
-    var var_useSmoltcp = sse_decode_bool(deserializer);
-    var var_relayNetworkWhitelist = sse_decode_String(deserializer);
-    var var_disableP2P = sse_decode_bool(deserializer);
-    var var_enableUdpBroadcastRelay = sse_decode_bool(deserializer);
-    var var_relayAllPeerRpc = sse_decode_bool(deserializer);
-    var var_disableUdpHolePunching = sse_decode_bool(deserializer);
-    var var_disableTcpHolePunching = sse_decode_bool(deserializer);
-    var var_multiThread = sse_decode_bool(deserializer);
-    var var_dataCompressAlgo = sse_decode_i_32(deserializer);
-    var var_bindDevice = sse_decode_bool(deserializer);
-    var var_enableKcpProxy = sse_decode_bool(deserializer);
-    var var_disableKcpInput = sse_decode_bool(deserializer);
-    var var_disableRelayKcp = sse_decode_bool(deserializer);
-    var var_proxyForwardBySystem = sse_decode_bool(deserializer);
-    var var_acceptDns = sse_decode_bool(deserializer);
-    var var_privateMode = sse_decode_bool(deserializer);
-    var var_enableQuicProxy = sse_decode_bool(deserializer);
-    var var_disableQuicInput = sse_decode_bool(deserializer);
-    var var_disableSymHolePunching = sse_decode_bool(deserializer);
-    var var_tcpWhitelist = sse_decode_String(deserializer);
-    var var_udpWhitelist = sse_decode_String(deserializer);
-    var var_socks5Port = sse_decode_u_16(deserializer);
-    return FlagsC(
-      defaultProtocol: var_defaultProtocol,
-      devName: var_devName,
-      enableEncryption: var_enableEncryption,
-      enableIpv6: var_enableIpv6,
-      mtu: var_mtu,
-      latencyFirst: var_latencyFirst,
-      enableExitNode: var_enableExitNode,
-      noTun: var_noTun,
-      useSmoltcp: var_useSmoltcp,
-      relayNetworkWhitelist: var_relayNetworkWhitelist,
-      disableP2P: var_disableP2P,
-      enableUdpBroadcastRelay: var_enableUdpBroadcastRelay,
-      relayAllPeerRpc: var_relayAllPeerRpc,
-      disableUdpHolePunching: var_disableUdpHolePunching,
-      disableTcpHolePunching: var_disableTcpHolePunching,
-      multiThread: var_multiThread,
-      dataCompressAlgo: var_dataCompressAlgo,
-      bindDevice: var_bindDevice,
-      enableKcpProxy: var_enableKcpProxy,
-      disableKcpInput: var_disableKcpInput,
-      disableRelayKcp: var_disableRelayKcp,
-      proxyForwardBySystem: var_proxyForwardBySystem,
-      acceptDns: var_acceptDns,
-      privateMode: var_privateMode,
-      enableQuicProxy: var_enableQuicProxy,
-      disableQuicInput: var_disableQuicInput,
-      disableSymHolePunching: var_disableSymHolePunching,
-      tcpWhitelist: var_tcpWhitelist,
-      udpWhitelist: var_udpWhitelist,
-      socks5Port: var_socks5Port,
-    );
+    switch (self) {
+      case InstanceEvent_PeerAdded(peerId: final peerId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(peerId, serializer);
+      case InstanceEvent_PeerRemoved(peerId: final peerId):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(peerId, serializer);
+      case InstanceEvent_PeerConnAdded(conn: final conn):
+        sse_encode_i_32(2, serializer);
+        sse_encode_peer_conn_event(conn, serializer);
+      case InstanceEvent_PeerConnRemoved(conn: final conn):
+        sse_encode_i_32(3, serializer);
+        sse_encode_peer_conn_event(conn, serializer);
+      case InstanceEvent_ListenerAdded(listener: final listener):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(listener, serializer);
+      case InstanceEvent_ListenerAddFailed(
+        listener: final listener,
+        message: final message,
+      ):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(listener, serializer);
+        sse_encode_String(message, serializer);
+      case InstanceEvent_ListenerAcceptFailed(
+        listener: final listener,
+        message: final message,
+      ):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(listener, serializer);
+        sse_encode_String(message, serializer);
+      case InstanceEvent_ConnectionAccepted(
+        local: final local,
+        remote: final remote,
+      ):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(local, serializer);
+        sse_encode_String(remote, serializer);
+      case InstanceEvent_ConnectionError(
+        local: final local,
+        remote: final remote,
+        error: final error,
+      ):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(local, serializer);
+        sse_encode_String(remote, serializer);
+        sse_encode_String(error, serializer);
+      case InstanceEvent_TunDeviceReady(dev: final dev):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(dev, serializer);
+      case InstanceEvent_TunDeviceError(error: final error):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(error, serializer);
+      case InstanceEvent_Connecting(dst: final dst):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(dst, serializer);
+      case InstanceEvent_ConnectError(
+        dst: final dst,
+        ipVersion: final ipVersion,
+        error: final error,
+      ):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(dst, serializer);
+        sse_encode_String(ipVersion, serializer);
+        sse_encode_String(error, serializer);
+      case InstanceEvent_VpnPortalStarted(portal: final portal):
+        sse_encode_i_32(13, serializer);
+        sse_encode_String(portal, serializer);
+      case InstanceEvent_VpnPortalClientConnected(
+        portal: final portal,
+        clientAddr: final clientAddr,
+      ):
+        sse_encode_i_32(14, serializer);
+        sse_encode_String(portal, serializer);
+        sse_encode_String(clientAddr, serializer);
+      case InstanceEvent_VpnPortalClientDisconnected(
+        portal: final portal,
+        clientAddr: final clientAddr,
+      ):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(portal, serializer);
+        sse_encode_String(clientAddr, serializer);
+      case InstanceEvent_DhcpIpv4Changed(
+        oldIp: final oldIp,
+        newIp: final newIp,
+      ):
+        sse_encode_i_32(16, serializer);
+        sse_encode_opt_String(oldIp, serializer);
+        sse_encode_opt_String(newIp, serializer);
+      case InstanceEvent_DhcpIpv4Conflicted(ip: final ip):
+        sse_encode_i_32(17, serializer);
+        sse_encode_opt_String(ip, serializer);
+      case InstanceEvent_PortForwardAdded(
+        bindAddr: final bindAddr,
+        dstAddr: final dstAddr,
+        proto: final proto,
+      ):
+        sse_encode_i_32(18, serializer);
+        sse_encode_String(bindAddr, serializer);
+        sse_encode_String(dstAddr, serializer);
+        sse_encode_String(proto, serializer);
+      case InstanceEvent_ConfigPatched(changes: final changes):
+        sse_encode_i_32(19, serializer);
+        sse_encode_list_config_change(changes, serializer);
+      case InstanceEvent_ProxyCidrsUpdated(
+        added: final added,
+        removed: final removed,
+      ):
+        sse_encode_i_32(20, serializer);
+        sse_encode_list_String(added, serializer);
+        sse_encode_list_String(removed, serializer);
+      case InstanceEvent_InstanceRestarting(
+        attempt: final attempt,
+        delayMs: final delayMs,
+        reason: final reason,
+      ):
+        sse_encode_i_32(21, serializer);
+        sse_encode_u_32(attempt, serializer);
+        sse_encode_u_64(delayMs, serializer);
+        sse_encode_String(reason, serializer);
+      case InstanceEvent_InstanceRestarted(attempt: final attempt):
+        sse_encode_i_32(22, serializer);
+        sse_encode_u_32(attempt, serializer);
+      case InstanceEvent_InstanceRestartGaveUp(
+        attempts: final attempts,
+        reason: final reason,
+      ):
+        sse_encode_i_32(23, serializer);
+        sse_encode_u_32(attempts, serializer);
+        sse_encode_String(reason, serializer);
+      case InstanceEvent_EventsLagged(dropped: final dropped):
+        sse_encode_i_32(24, serializer);
+        sse_encode_u_64(dropped, serializer);
+      case InstanceEvent_EventsClosed():
+        sse_encode_i_32(25, serializer);
+    }
   }
 
   @protected
-  Forward sse_decode_forward(SseDeserializer deserializer) {
+  void sse_encode_instance_event_record(
+    InstanceEventRecord self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_bindAddr = sse_decode_String(deserializer);
-    var var_dstAddr = sse_decode_String(deserializer);
-    var var_proto = sse_decode_String(deserializer);
-    return Forward(
-      bindAddr: var_bindAddr,
-      dstAddr: var_dstAddr,
-      proto: var_proto,
-    );
+    sse_encode_String(self.instanceId, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_instance_event(self.event, serializer);
+  }
+
+  @protected
+  void sse_encode_instance_patch(InstancePatch self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_value_patch(self.peers, serializer);
+    sse_encode_list_forward_patch(self.portForwards, serializer);
+    sse_encode_list_value_patch(self.proxyCidrs, serializer);
+    sse_encode_list_value_patch(self.tcpWhitelist, serializer);
+    sse_encode_list_value_patch(self.udpWhitelist, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_invalid_cidr_policy(
+    InvalidCidrPolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_invite_flags(InviteFlags self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_opt_String(self.defaultProtocol, serializer);
+    sse_encode_opt_box_autoadd_bool(self.enableEncryption, serializer);
+    sse_encode_opt_box_autoadd_bool(self.enableIpv6, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.mtu, serializer);
+    sse_encode_opt_box_autoadd_bool(self.latencyFirst, serializer);
+    sse_encode_opt_box_autoadd_bool(self.disableP2P, serializer);
+    sse_encode_opt_box_autoadd_bool(self.enableUdpBroadcastRelay, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.dataCompressAlgo, serializer);
+    sse_encode_opt_box_autoadd_bool(self.enableKcpProxy, serializer);
+    sse_encode_opt_box_autoadd_bool(self.enableQuicProxy, serializer);
   }
 
   @protected
-  KVNetworkStatus sse_decode_kv_network_status(SseDeserializer deserializer) {
+  void sse_encode_kv_network_status(
+    KVNetworkStatus self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalNodes = sse_decode_usize(deserializer);
-    var var_nodes = sse_decode_list_kv_node_info(deserializer);
-    return KVNetworkStatus(totalNodes: var_totalNodes, nodes: var_nodes);
+    sse_encode_usize(self.totalNodes, serializer);
+    sse_encode_list_kv_node_info(self.nodes, serializer);
   }
 
   @protected
-  KVNodeConnectionStats sse_decode_kv_node_connection_stats(
-    SseDeserializer deserializer,
+  void sse_encode_kv_node_connection_stats(
+    KVNodeConnectionStats self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_connType = sse_decode_String(deserializer);
-    var var_rxBytes = sse_decode_u_64(deserializer);
-    var var_txBytes = sse_decode_u_64(deserializer);
-    var var_rxPackets = sse_decode_u_64(deserializer);
-    var var_txPackets = sse_decode_u_64(deserializer);
-    return KVNodeConnectionStats(
-      connType: var_connType,
-      rxBytes: var_rxBytes,
-      txBytes: var_txBytes,
-      rxPackets: var_rxPackets,
-      txPackets: var_txPackets,
-    );
+    sse_encode_String(self.connType, serializer);
+    sse_encode_u_64(self.rxBytes, serializer);
+    sse_encode_u_64(self.txBytes, serializer);
+    sse_encode_u_64(self.rxPackets, serializer);
+    sse_encode_u_64(self.txPackets, serializer);
   }
 
   @protected
-  KVNodeInfo sse_decode_kv_node_info(SseDeserializer deserializer) {
+  void sse_encode_kv_node_info(KVNodeInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_peerId = sse_decode_u_32(deserializer);
-    var var_hostname = sse_decode_String(deserializer);
-    var var_ipv4 = sse_decode_String(deserializer);
-    var var_latencyMs = sse_decode_f_64(deserializer);
-    var var_nat = sse_decode_String(deserializer);
-    var var_hops = sse_decode_list_node_hop_stats(deserializer);
-    var var_lossRate = sse_decode_f_32(deserializer);
-    var var_connections = sse_decode_list_kv_node_connection_stats(
-      deserializer,
-    );
-    var var_tunnelProto = sse_decode_String(deserializer);
-    var var_connType = sse_decode_String(deserializer);
-    var var_rxBytes = sse_decode_u_64(deserializer);
-    var var_txBytes = sse_decode_u_64(deserializer);
-    var var_version = sse_decode_String(deserializer);
-    var var_cost = sse_decode_i_32(deserializer);
-    var var_proxyCidrs = sse_decode_list_String(deserializer);
-    return KVNodeInfo(
-      peerId: var_peerId,
-      hostname: var_hostname,
-      ipv4: var_ipv4,
-      latencyMs: var_latencyMs,
-      nat: var_nat,
-      hops: var_hops,
-      lossRate: var_lossRate,
-      connections: var_connections,
-      tunnelProto: var_tunnelProto,
-      connType: var_connType,
-      rxBytes: var_rxBytes,
-      txBytes: var_txBytes,
-      version: var_version,
-      cost: var_cost,
-      proxyCidrs: var_proxyCidrs,
-    );
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_String(self.hostname, serializer);
+    sse_encode_String(self.ipv4, serializer);
+    sse_encode_opt_String(self.ipv6, serializer);
+    sse_encode_opt_String(self.publicIpv6, serializer);
+    sse_encode_opt_String(self.ipv6Prefix, serializer);
+    sse_encode_f_64(self.latencyMs, serializer);
+    sse_encode_String(self.nat, serializer);
+    sse_encode_list_node_hop_stats(self.hops, serializer);
+    sse_encode_f_32(self.lossRate, serializer);
+    sse_encode_list_kv_node_connection_stats(self.connections, serializer);
+    sse_encode_String(self.tunnelProto, serializer);
+    sse_encode_String(self.connType, serializer);
+    sse_encode_u_64(self.rxBytes, serializer);
+    sse_encode_u_64(self.txBytes, serializer);
+    sse_encode_String(self.version, serializer);
+    sse_encode_i_32(self.cost, serializer);
+    sse_encode_list_String(self.proxyCidrs, serializer);
   }
 
   @protected
-  List<PeerRoutePair>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    List<PeerRoutePair> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PeerRoutePair>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-          deserializer,
-        ),
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+        item,
+        serializer,
       );
     }
-    return ans_;
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<Forward> sse_decode_list_forward(SseDeserializer deserializer) {
+  void sse_encode_list_config_change(
+    List<ConfigChange> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Forward>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_forward(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_config_change(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<KVNodeConnectionStats> sse_decode_list_kv_node_connection_stats(
-    SseDeserializer deserializer,
+  void sse_encode_list_config_issue(
+    List<ConfigIssue> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <KVNodeConnectionStats>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_kv_node_connection_stats(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_config_issue(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<KVNodeInfo> sse_decode_list_kv_node_info(SseDeserializer deserializer) {
+  void sse_encode_list_forward(List<Forward> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <KVNodeInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_kv_node_info(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_forward(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<MagicWallRule> sse_decode_list_magic_wall_rule(
-    SseDeserializer deserializer,
+  void sse_encode_list_forward_connection_info(
+    List<ForwardConnectionInfo> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MagicWallRule>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_magic_wall_rule(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_forward_connection_info(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<NodeHopStats> sse_decode_list_node_hop_stats(
-    SseDeserializer deserializer,
+  void sse_encode_list_forward_patch(
+    List<ForwardPatch> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NodeHopStats>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_node_hop_stats(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_forward_patch(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_list_forward_server_info(
+    List<ForwardServerInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_forward_server_info(item, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_hole_punch_prediction(
+    List<HolePunchPrediction> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_hole_punch_prediction(item, serializer);
+    }
   }
 
   @protected
-  List<(String, int)> sse_decode_list_record_string_u_32(
-    SseDeserializer deserializer,
+  void sse_encode_list_instance_event_record(
+    List<InstanceEventRecord> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_u_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_instance_event_record(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  MagicWallRule sse_decode_magic_wall_rule(SseDeserializer deserializer) {
+  void sse_encode_list_kv_node_connection_stats(
+    List<KVNodeConnectionStats> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_enabled = sse_decode_bool(deserializer);
-    var var_action = sse_decode_String(deserializer);
-    var var_protocol = sse_decode_String(deserializer);
-    var var_direction = sse_decode_String(deserializer);
-    var var_appPath = sse_decode_opt_String(deserializer);
-    var var_remoteIp = sse_decode_opt_String(deserializer);
-    var var_localIp = sse_decode_opt_String(deserializer);
-    var var_remotePort = sse_decode_opt_String(deserializer);
-    var var_localPort = sse_decode_opt_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
-    return MagicWallRule(
-      id: var_id,
-      name: var_name,
-      enabled: var_enabled,
-      action: var_action,
-      protocol: var_protocol,
-      direction: var_direction,
-      appPath: var_appPath,
-      remoteIp: var_remoteIp,
-      localIp: var_localIp,
-      remotePort: var_remotePort,
-      localPort: var_localPort,
-      description: var_description,
-      createdAt: var_createdAt,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_kv_node_connection_stats(item, serializer);
+    }
   }
 
   @protected
-  MagicWallStatus sse_decode_magic_wall_status(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_isRunning = sse_decode_bool(deserializer);
-    var var_activeRules = sse_decode_usize(deserializer);
-    var var_totalRules = sse_decode_usize(deserializer);
-    return MagicWallStatus(
-      isRunning: var_isRunning,
-      activeRules: var_activeRules,
-      totalRules: var_totalRules,
-    );
+  void sse_encode_list_kv_node_info(
+    List<KVNodeInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_kv_node_info(item, serializer);
+    }
   }
 
   @protected
-  NatType sse_decode_nat_type(SseDeserializer deserializer) {
+  void sse_encode_list_magic_wall_rule(
+    List<MagicWallRule> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return NatType.values[inner];
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_magic_wall_rule(item, serializer);
+    }
   }
 
   @protected
-  NetworkTestResult sse_decode_network_test_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_magic_wall_rule_issue(
+    List<MagicWallRuleIssue> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_natTypeV4 = sse_decode_String(deserializer);
-    var var_natTypeV6 = sse_decode_String(deserializer);
-    var var_ipv4Latency = sse_decode_i_64(deserializer);
-    var var_ipv6Latency = sse_decode_i_64(deserializer);
-    return NetworkTestResult(
-      natTypeV4: var_natTypeV4,
-      natTypeV6: var_natTypeV6,
-      ipv4Latency: var_ipv4Latency,
-      ipv6Latency: var_ipv6Latency,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_magic_wall_rule_issue(item, serializer);
+    }
   }
 
   @protected
-  NodeHopStats sse_decode_node_hop_stats(SseDeserializer deserializer) {
+  void sse_encode_list_minecraft_lan_world(
+    List<MinecraftLanWorld> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_peerId = sse_decode_u_32(deserializer);
-    var var_targetIp = sse_decode_String(deserializer);
-    var var_latencyMs = sse_decode_f_64(deserializer);
-    var var_packetLoss = sse_decode_f_32(deserializer);
-    var var_nodeName = sse_decode_String(deserializer);
-    return NodeHopStats(
-      peerId: var_peerId,
-      targetIp: var_targetIp,
-      latencyMs: var_latencyMs,
-      packetLoss: var_packetLoss,
-      nodeName: var_nodeName,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_minecraft_lan_world(item, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_list_multicast_listener_info(
+    List<MulticastListenerInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_multicast_listener_info(item, serializer);
     }
   }
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_multicast_packet(
+    List<MulticastPacket> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_multicast_packet(item, serializer);
     }
   }
 
   @protected
-  (String, int) sse_decode_record_string_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_multicast_sender_info(
+    List<MulticastSenderInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_u_32(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_multicast_sender_info(item, serializer);
+    }
   }
 
   @protected
-  (BigInt, BigInt, BigInt) sse_decode_record_usize_u_64_u_64(
-    SseDeserializer deserializer,
+  void sse_encode_list_nat_behavior_result(
+    List<NatBehaviorResult> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_usize(deserializer);
-    var var_field1 = sse_decode_u_64(deserializer);
-    var var_field2 = sse_decode_u_64(deserializer);
-    return (var_field0, var_field1, var_field2);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_nat_behavior_result(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
+  void sse_encode_list_node_hop_stats(
+    List<NodeHopStats> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_node_hop_stats(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_record_string_u_32(
+    List<(String, int)> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_u_32(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_running_conn_info(
+    List<RunningConnInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_running_conn_info(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_list_running_peer_info(
+    List<RunningPeerInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_running_peer_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_running_route_info(
+    List<RunningRouteInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_running_route_info(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    EventBusSubscriber self,
+  void sse_encode_list_server_probe_result(
+    List<ServerProbeResult> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as EventBusSubscriberImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_server_probe_result(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    ForwardServer self,
+  void sse_encode_list_stun_server_info(
+    List<StunServerInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ForwardServerImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_stun_server_info(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    JoinHandle self,
+  void sse_encode_list_stun_server_result(
+    List<StunServerResult> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_stun_server_result(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    JoinHandleResultString self,
+  void sse_encode_list_supervisor_status(
+    List<SupervisorStatus> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleResultStringImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_supervisor_status(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    JoinHandleResultStringString self,
+  void sse_encode_list_value_patch(
+    List<ValuePatch> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleResultStringStringImpl).frbInternalSseEncode(
-        move: true,
-      ),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_value_patch(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    MulticastSender self,
+  void sse_encode_magic_wall_packet(
+    MagicWallPacket self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MulticastSenderImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_String(self.protocol, serializer);
+    sse_encode_String(self.direction, serializer);
+    sse_encode_String(self.localIp, serializer);
+    sse_encode_String(self.remoteIp, serializer);
+    sse_encode_opt_box_autoadd_u_16(self.localPort, serializer);
+    sse_encode_opt_box_autoadd_u_16(self.remotePort, serializer);
+    sse_encode_opt_String(self.appPath, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    PeerRoutePair self,
+  void sse_encode_magic_wall_rule(
+    MagicWallRule self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as PeerRoutePairImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_String(self.action, serializer);
+    sse_encode_String(self.protocol, serializer);
+    sse_encode_String(self.direction, serializer);
+    sse_encode_opt_String(self.appPath, serializer);
+    sse_encode_opt_String(self.remoteIp, serializer);
+    sse_encode_opt_String(self.localIp, serializer);
+    sse_encode_opt_String(self.remotePort, serializer);
+    sse_encode_opt_String(self.localPort, serializer);
+    sse_encode_u_32(self.priority, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.createdAt, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    ServerStats self,
+  void sse_encode_magic_wall_rule_issue(
+    MagicWallRuleIssue self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ServerStatsImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_magic_wall_rule_issue_kind(self.kind, serializer);
+    sse_encode_String(self.ruleId, serializer);
+    sse_encode_String(self.otherRuleId, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    ForwardServer self,
+  void sse_encode_magic_wall_rule_issue_kind(
+    MagicWallRuleIssueKind self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ForwardServerImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    MulticastSender self,
+  void sse_encode_magic_wall_status(
+    MagicWallStatus self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MulticastSenderImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_bool(self.isRunning, serializer);
+    sse_encode_usize(self.activeRules, serializer);
+    sse_encode_usize(self.totalRules, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    ForwardServer self,
+  void sse_encode_magic_wall_verdict(
+    MagicWallVerdict self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ForwardServerImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_String(self.action, serializer);
+    sse_encode_opt_String(self.matchedRuleId, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    MulticastSender self,
+  void sse_encode_mapping_behavior(
+    MappingBehavior self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MulticastSenderImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    ServerStats self,
+  void sse_encode_minecraft_lan_announcement(
+    MinecraftLanAnnouncement self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ServerStatsImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_String(self.motd, serializer);
+    sse_encode_u_16(self.port, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    EventBusSubscriber self,
+  void sse_encode_minecraft_lan_world(
+    MinecraftLanWorld self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as EventBusSubscriberImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_String(self.host, serializer);
+    sse_encode_u_16(self.remotePort, serializer);
+    sse_encode_u_16(self.localPort, serializer);
+    sse_encode_String(self.motd, serializer);
+    sse_encode_i_64(self.lastSeen, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    ForwardServer self,
+  void sse_encode_multicast_listener_info(
+    MulticastListenerInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ForwardServerImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.groupAddr, serializer);
+    sse_encode_String(self.interfaceAddr, serializer);
+    sse_encode_usize(self.capacity, serializer);
+    sse_encode_u_64(self.packetsReceived, serializer);
+    sse_encode_bool(self.isRunning, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    JoinHandle self,
+  void sse_encode_multicast_packet(
+    MulticastPacket self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_String(self.source, serializer);
+    sse_encode_usize(self.size, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    JoinHandleResultString self,
+  void sse_encode_multicast_sender_info(
+    MulticastSenderInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleResultStringImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.multicastAddr, serializer);
+    sse_encode_String(self.bindAddr, serializer);
+    sse_encode_u_64(self.intervalMs, serializer);
+    sse_encode_usize(self.dataLen, serializer);
+    sse_encode_bool(self.isRunning, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    JoinHandleResultStringString self,
+  void sse_encode_nat_behavior_result(
+    NatBehaviorResult self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleResultStringStringImpl).frbInternalSseEncode(
-        move: null,
-      ),
-      serializer,
-    );
+    sse_encode_String(self.protocol, serializer);
+    sse_encode_String(self.ipVersion, serializer);
+    sse_encode_opt_String(self.server, serializer);
+    sse_encode_opt_String(self.localAddr, serializer);
+    sse_encode_opt_String(self.mappedAddr, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.rttMs, serializer);
+    sse_encode_mapping_behavior(self.mapping, serializer);
+    sse_encode_filtering_behavior(self.filtering, serializer);
+    sse_encode_opt_box_autoadd_bool(self.hairpinning, serializer);
+    sse_encode_opt_box_autoadd_bool(self.portPreserved, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    MulticastSender self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_nat_type(NatType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MulticastSenderImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    PeerRoutePair self,
+  void sse_encode_network_config_c(
+    NetworkConfigC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as PeerRoutePairImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_String(self.username, serializer);
+    sse_encode_bool(self.enableDhcp, serializer);
+    sse_encode_String(self.specifiedIp, serializer);
+    sse_encode_String(self.roomName, serializer);
+    sse_encode_String(self.roomPassword, serializer);
+    sse_encode_list_String(self.severurl, serializer);
+    sse_encode_list_String(self.onurl, serializer);
+    sse_encode_list_String(self.cidrs, serializer);
+    sse_encode_list_forward(self.forwards, serializer);
+    sse_encode_flags_c(self.flag, serializer);
+    sse_encode_config_build_options(self.options, serializer);
+    sse_encode_opt_box_autoadd_relay_pool(self.relayPool, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    ServerStats self,
+  void sse_encode_network_test_result(
+    NetworkTestResult self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ServerStatsImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_String(self.natTypeV4, serializer);
+    sse_encode_String(self.natTypeV6, serializer);
+    sse_encode_i_64(self.ipv4Latency, serializer);
+    sse_encode_i_64(self.ipv6Latency, serializer);
+    sse_encode_opt_String(self.natTypeV4Raw, serializer);
+    sse_encode_opt_String(self.errorV4, serializer);
+    sse_encode_opt_String(self.errorV6, serializer);
+    sse_encode_opt_String(self.publicEndpointV4, serializer);
+    sse_encode_opt_String(self.publicEndpointV6, serializer);
+    sse_encode_list_stun_server_result(self.stunServers, serializer);
+    sse_encode_list_hole_punch_prediction(self.holePunch, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_node_hop_stats(NodeHopStats self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_String(self.targetIp, serializer);
+    sse_encode_f_64(self.latencyMs, serializer);
+    sse_encode_f_32(self.packetLoss, serializer);
+    sse_encode_String(self.nodeName, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_flags_c(FlagsC self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flags_c(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_magic_wall_rule(
-    MagicWallRule self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_magic_wall_rule(self, serializer);
-  }
 
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_minecraft_lan_announcement(
+    MinecraftLanAnnouncement? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_minecraft_lan_announcement(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_flags_c(FlagsC self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_patch_action(
+    PatchAction? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.defaultProtocol, serializer);
-    sse_encode_String(self.devName, serializer);
-    sse_encode_bool(self.enableEncryption, serializer);
-    sse_encode_bool(self.enableIpv6, serializer);
-    sse_encode_u_32(self.mtu, serializer);
-    sse_encode_bool(self.latencyFirst, serializer);
-    sse_encode_bool(self.enableExitNode, serializer);
-    sse_encode_bool(self.noTun, serializer);
-    sse_encode_bool(self.useSmoltcp, serializer);
-    sse_encode_String(self.relayNetworkWhitelist, serializer);
-    sse_encode_bool(self.disableP2P, serializer);
-    sse_encode_bool(self.enableUdpBroadcastRelay, serializer);
-    sse_encode_bool(self.relayAllPeerRpc, serializer);
-    sse_encode_bool(self.disableUdpHolePunching, serializer);
-    sse_encode_bool(self.disableTcpHolePunching, serializer);
-    sse_encode_bool(self.multiThread, serializer);
-    sse_encode_i_32(self.dataCompressAlgo, serializer);
-    sse_encode_bool(self.bindDevice, serializer);
-    sse_encode_bool(self.enableKcpProxy, serializer);
-    sse_encode_bool(self.disableKcpInput, serializer);
-    sse_encode_bool(self.disableRelayKcp, serializer);
-    sse_encode_bool(self.proxyForwardBySystem, serializer);
-    sse_encode_bool(self.acceptDns, serializer);
-    sse_encode_bool(self.privateMode, serializer);
-    sse_encode_bool(self.enableQuicProxy, serializer);
-    sse_encode_bool(self.disableQuicInput, serializer);
-    sse_encode_bool(self.disableSymHolePunching, serializer);
-    sse_encode_String(self.tcpWhitelist, serializer);
-    sse_encode_String(self.udpWhitelist, serializer);
-    sse_encode_u_16(self.socks5Port, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_patch_action(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_forward(Forward self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_probe_error_kind(
+    ProbeErrorKind? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.bindAddr, serializer);
-    sse_encode_String(self.dstAddr, serializer);
-    sse_encode_String(self.proto, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_probe_error_kind(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_relay_pool(
+    RelayPool? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_relay_pool(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_restart_policy(
+    RestartPolicy? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_restart_policy(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_kv_network_status(
-    KVNetworkStatus self,
+  void sse_encode_opt_box_autoadd_running_node_info(
+    RunningNodeInfo? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.totalNodes, serializer);
-    sse_encode_list_kv_node_info(self.nodes, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_running_node_info(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_kv_node_connection_stats(
-    KVNodeConnectionStats self,
+  void sse_encode_opt_box_autoadd_running_stun_info(
+    RunningStunInfo? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.connType, serializer);
-    sse_encode_u_64(self.rxBytes, serializer);
-    sse_encode_u_64(self.txBytes, serializer);
-    sse_encode_u_64(self.rxPackets, serializer);
-    sse_encode_u_64(self.txPackets, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_running_stun_info(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_kv_node_info(KVNodeInfo self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_supervisor_status(
+    SupervisorStatus? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.peerId, serializer);
-    sse_encode_String(self.hostname, serializer);
-    sse_encode_String(self.ipv4, serializer);
-    sse_encode_f_64(self.latencyMs, serializer);
-    sse_encode_String(self.nat, serializer);
-    sse_encode_list_node_hop_stats(self.hops, serializer);
-    sse_encode_f_32(self.lossRate, serializer);
-    sse_encode_list_kv_node_connection_stats(self.connections, serializer);
-    sse_encode_String(self.tunnelProto, serializer);
-    sse_encode_String(self.connType, serializer);
-    sse_encode_u_64(self.rxBytes, serializer);
-    sse_encode_u_64(self.txBytes, serializer);
-    sse_encode_String(self.version, serializer);
-    sse_encode_i_32(self.cost, serializer);
-    sse_encode_list_String(self.proxyCidrs, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_supervisor_status(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    List<PeerRoutePair> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-        item,
-        serializer,
-      );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_forward(List<Forward> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_forward(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_kv_node_connection_stats(
-    List<KVNodeConnectionStats> self,
+  void sse_encode_opt_box_autoadd_usize(
+    BigInt? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_kv_node_connection_stats(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_usize(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_kv_node_info(
-    List<KVNodeInfo> self,
+  void sse_encode_opt_list_String(
+    List<String>? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_kv_node_info(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_magic_wall_rule(
-    List<MagicWallRule> self,
+  void sse_encode_patch_action(PatchAction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_peer_conn_event(
+    PeerConnEvent self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_magic_wall_rule(item, serializer);
-    }
+    sse_encode_String(self.connId, serializer);
+    sse_encode_u_32(self.myPeerId, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_String(self.tunnelType, serializer);
+    sse_encode_String(self.localAddr, serializer);
+    sse_encode_String(self.remoteAddr, serializer);
   }
 
   @protected
-  void sse_encode_list_node_hop_stats(
-    List<NodeHopStats> self,
+  void sse_encode_probe_error_kind(
+    ProbeErrorKind self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_node_hop_stats(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_record_string_u_32(
+    (String, int) self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
+    sse_encode_String(self.$1, serializer);
+    sse_encode_u_32(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_record_usize_u_64_u_64(
+    (BigInt, BigInt, BigInt) self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_usize(self.$1, serializer);
+    sse_encode_u_64(self.$2, serializer);
+    sse_encode_u_64(self.$3, serializer);
   }
 
   @protected
-  void sse_encode_list_record_string_u_32(
-    List<(String, int)> self,
+  void sse_encode_relay_pool(RelayPool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.candidates, serializer);
+    sse_encode_usize(self.bestN, serializer);
+    sse_encode_u_64(self.reevaluateIntervalSecs, serializer);
+    sse_encode_i_64(self.minImprovementMs, serializer);
+  }
+
+  @protected
+  void sse_encode_restart_policy(RestartPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxRetries, serializer);
+    sse_encode_u_64(self.initialBackoffMs, serializer);
+    sse_encode_u_64(self.maxBackoffMs, serializer);
+    sse_encode_bool(self.restartOnTunError, serializer);
+  }
+
+  @protected
+  void sse_encode_room_invite(RoomInvite self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.roomName, serializer);
+    sse_encode_String(self.roomPassword, serializer);
+    sse_encode_list_String(self.servers, serializer);
+    sse_encode_opt_String(self.ipRange, serializer);
+    sse_encode_invite_flags(self.flags, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.expiresAt, serializer);
+    sse_encode_bool(self.verified, serializer);
+  }
+
+  @protected
+  void sse_encode_running_conn_info(
+    RunningConnInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_u_32(item, serializer);
-    }
+    sse_encode_String(self.connId, serializer);
+    sse_encode_String(self.tunnelType, serializer);
+    sse_encode_String(self.localAddr, serializer);
+    sse_encode_String(self.remoteAddr, serializer);
+    sse_encode_bool(self.isClient, serializer);
+    sse_encode_String(self.networkName, serializer);
+    sse_encode_f_64(self.latencyMs, serializer);
+    sse_encode_f_32(self.lossRate, serializer);
+    sse_encode_u_64(self.rxBytes, serializer);
+    sse_encode_u_64(self.txBytes, serializer);
+    sse_encode_u_64(self.rxPackets, serializer);
+    sse_encode_u_64(self.txPackets, serializer);
   }
 
   @protected
-  void sse_encode_magic_wall_rule(
-    MagicWallRule self,
+  void sse_encode_running_info(RunningInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.devName, serializer);
+    sse_encode_bool(self.running, serializer);
+    sse_encode_opt_String(self.errorMsg, serializer);
+    sse_encode_opt_box_autoadd_running_node_info(self.myNode, serializer);
+    sse_encode_list_running_peer_info(self.peers, serializer);
+    sse_encode_list_running_route_info(self.routes, serializer);
+  }
+
+  @protected
+  void sse_encode_running_node_info(
+    RunningNodeInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_bool(self.enabled, serializer);
-    sse_encode_String(self.action, serializer);
-    sse_encode_String(self.protocol, serializer);
-    sse_encode_String(self.direction, serializer);
-    sse_encode_opt_String(self.appPath, serializer);
-    sse_encode_opt_String(self.remoteIp, serializer);
-    sse_encode_opt_String(self.localIp, serializer);
-    sse_encode_opt_String(self.remotePort, serializer);
-    sse_encode_opt_String(self.localPort, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.createdAt, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_String(self.hostname, serializer);
+    sse_encode_String(self.version, serializer);
+    sse_encode_opt_String(self.virtualIpv4, serializer);
+    sse_encode_opt_String(self.virtualIpv6, serializer);
+    sse_encode_list_String(self.listeners, serializer);
+    sse_encode_opt_String(self.publicIpv4, serializer);
+    sse_encode_opt_String(self.publicIpv6, serializer);
+    sse_encode_list_String(self.interfaceIpv4S, serializer);
+    sse_encode_list_String(self.interfaceIpv6S, serializer);
+    sse_encode_opt_box_autoadd_running_stun_info(self.stun, serializer);
   }
 
   @protected
-  void sse_encode_magic_wall_status(
-    MagicWallStatus self,
+  void sse_encode_running_peer_info(
+    RunningPeerInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.isRunning, serializer);
-    sse_encode_usize(self.activeRules, serializer);
-    sse_encode_usize(self.totalRules, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_list_running_conn_info(self.connections, serializer);
   }
 
   @protected
-  void sse_encode_nat_type(NatType self, SseSerializer serializer) {
+  void sse_encode_running_route_info(
+    RunningRouteInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_String(self.instId, serializer);
+    sse_encode_String(self.hostname, serializer);
+    sse_encode_String(self.version, serializer);
+    sse_encode_opt_String(self.ipv4, serializer);
+    sse_encode_opt_String(self.ipv6, serializer);
+    sse_encode_u_32(self.nextHopPeerId, serializer);
+    sse_encode_i_32(self.cost, serializer);
+    sse_encode_i_32(self.pathLatency, serializer);
+    sse_encode_list_String(self.proxyCidrs, serializer);
+    sse_encode_String(self.udpNatType, serializer);
   }
 
   @protected
-  void sse_encode_network_test_result(
-    NetworkTestResult self,
+  void sse_encode_running_stun_info(
+    RunningStunInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.natTypeV4, serializer);
-    sse_encode_String(self.natTypeV6, serializer);
-    sse_encode_i_64(self.ipv4Latency, serializer);
-    sse_encode_i_64(self.ipv6Latency, serializer);
+    sse_encode_String(self.udpNatType, serializer);
+    sse_encode_String(self.tcpNatType, serializer);
+    sse_encode_list_String(self.publicIps, serializer);
+    sse_encode_u_32(self.minPort, serializer);
+    sse_encode_u_32(self.maxPort, serializer);
+    sse_encode_i_64(self.lastUpdateTime, serializer);
   }
 
   @protected
-  void sse_encode_node_hop_stats(NodeHopStats self, SseSerializer serializer) {
+  void sse_encode_server_probe_result(
+    ServerProbeResult self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.peerId, serializer);
-    sse_encode_String(self.targetIp, serializer);
-    sse_encode_f_64(self.latencyMs, serializer);
-    sse_encode_f_32(self.packetLoss, serializer);
-    sse_encode_String(self.nodeName, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_bool(self.reachable, serializer);
+    sse_encode_list_String(self.resolvedAddrs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.dnsMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.connectMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.rttMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.remotePeerId, serializer);
+    sse_encode_opt_box_autoadd_probe_error_kind(self.errorKind, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_stun_server_info(
+    StunServerInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.primaryAddr, serializer);
+    sse_encode_String(self.alternateAddr, serializer);
+    sse_encode_bool(self.running, serializer);
+    sse_encode_u_64(self.requests, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
+  void sse_encode_stun_server_result(
+    StunServerResult self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
-    }
+    sse_encode_String(self.server, serializer);
+    sse_encode_String(self.ipVersion, serializer);
+    sse_encode_bool(self.success, serializer);
+    sse_encode_i_64(self.latency, serializer);
+    sse_encode_opt_String(self.mappedAddr, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  void sse_encode_record_string_u_32(
-    (String, int) self,
+  void sse_encode_supervisor_state(
+    SupervisorState self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_u_32(self.$2, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_record_usize_u_64_u_64(
-    (BigInt, BigInt, BigInt) self,
+  void sse_encode_supervisor_status(
+    SupervisorStatus self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.$1, serializer);
-    sse_encode_u_64(self.$2, serializer);
-    sse_encode_u_64(self.$3, serializer);
+    sse_encode_String(self.instanceId, serializer);
+    sse_encode_restart_policy(self.policy, serializer);
+    sse_encode_supervisor_state(self.state, serializer);
+    sse_encode_u_32(self.restartCount, serializer);
+    sse_encode_u_32(self.consecutiveFailures, serializer);
+    sse_encode_opt_String(self.lastFailure, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastFailureAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextRestartAt, serializer);
   }
 
   @protected
@@ -4570,31 +10475,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
-}
-
-@sealed
-class EventBusSubscriberImpl extends RustOpaque implements EventBusSubscriber {
-  // Not to be used by end users
-  EventBusSubscriberImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  EventBusSubscriberImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
 
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_EventBusSubscriber,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_EventBusSubscriber,
-    rustArcDecrementStrongCountPtr:
-        RustLib
-            .instance
-            .api
-            .rust_arc_decrement_strong_count_EventBusSubscriberPtr,
-  );
+  @protected
+  void sse_encode_value_patch(ValuePatch self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_patch_action(self.action, serializer);
+    sse_encode_String(self.value, serializer);
+  }
 }
 
 @sealed
@@ -4635,26 +10522,6 @@ class ForwardServerImpl extends RustOpaque implements ForwardServer {
       RustLib.instance.api.crateApiForwardForwardServerStop(that: this);
 }
 
-@sealed
-class JoinHandleImpl extends RustOpaque implements JoinHandle {
-  // Not to be used by end users
-  JoinHandleImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  JoinHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_JoinHandle,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_JoinHandle,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_JoinHandlePtr,
-  );
-}
-
 @sealed
 class JoinHandleResultStringImpl extends RustOpaque
     implements JoinHandleResultString {
@@ -4719,6 +10586,74 @@ class JoinHandleResultStringStringImpl extends RustOpaque
   );
 }
 
+@sealed
+class MinecraftLanBridgeImpl extends RustOpaque implements MinecraftLanBridge {
+  // Not to be used by end users
+  MinecraftLanBridgeImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  MinecraftLanBridgeImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_MinecraftLanBridge,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_MinecraftLanBridge,
+    rustArcDecrementStrongCountPtr:
+        RustLib
+            .instance
+            .api
+            .rust_arc_decrement_strong_count_MinecraftLanBridgePtr,
+  );
+
+  Future<bool> isRunning() => RustLib.instance.api
+      .crateApiMinecraftLanMinecraftLanBridgeIsRunning(that: this);
+
+  Future<void> start() => RustLib.instance.api
+      .crateApiMinecraftLanMinecraftLanBridgeStart(that: this);
+
+  Future<void> stop() => RustLib.instance.api
+      .crateApiMinecraftLanMinecraftLanBridgeStop(that: this);
+}
+
+@sealed
+class MulticastListenerImpl extends RustOpaque implements MulticastListener {
+  // Not to be used by end users
+  MulticastListenerImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  MulticastListenerImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_MulticastListener,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_MulticastListener,
+    rustArcDecrementStrongCountPtr:
+        RustLib
+            .instance
+            .api
+            .rust_arc_decrement_strong_count_MulticastListenerPtr,
+  );
+
+  Future<bool> isRunning() => RustLib.instance.api
+      .crateApiMulticastMulticastListenerIsRunning(that: this);
+
+  Future<void> start() =>
+      RustLib.instance.api.crateApiMulticastMulticastListenerStart(that: this);
+
+  Future<void> stop() =>
+      RustLib.instance.api.crateApiMulticastMulticastListenerStop(that: this);
+}
+
 @sealed
 class MulticastSenderImpl extends RustOpaque implements MulticastSender {
   // Not to be used by end users
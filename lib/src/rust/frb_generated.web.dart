@@ -6,15 +6,24 @@
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/event_log.dart';
 import 'api/firewall.dart';
 import 'api/forward.dart';
 import 'api/hops.dart';
+import 'api/instance_patch.dart';
+import 'api/invite.dart';
 import 'api/magic_wall.dart';
+import 'api/minecraft_lan.dart';
 import 'api/multicast.dart';
 import 'api/nat_test.dart';
+import 'api/network_config.dart';
 import 'api/nt.dart';
 import 'api/p2p.dart';
+import 'api/relay_select.dart';
+import 'api/server_probe.dart';
 import 'api/simple.dart';
+import 'api/stun_server.dart';
+import 'api/supervisor.dart';
 import 'api/utils.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -29,17 +38,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_EventBusSubscriberPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber;
-
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_ForwardServerPtr =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_JoinHandlePtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle;
-
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_JoinHandleResultStringPtr =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString;
@@ -48,6 +50,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_JoinHandleResultStringStringPtr =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_MinecraftLanBridgePtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_MulticastListenerPtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_MulticastSenderPtr =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender;
@@ -64,32 +74,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
   @protected
-  EventBusSubscriber
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+  ForwardServer
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
     dynamic raw,
   );
 
   @protected
-  ForwardServer
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+  JoinHandleResultString
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
     dynamic raw,
   );
 
   @protected
-  JoinHandle
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+  JoinHandleResultStringString
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
     dynamic raw,
   );
 
   @protected
-  JoinHandleResultString
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+  MinecraftLanBridge
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
     dynamic raw,
   );
 
   @protected
-  JoinHandleResultStringString
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+  MulticastListener
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
     dynamic raw,
   );
 
@@ -117,6 +127,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  MinecraftLanBridge
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    dynamic raw,
+  );
+
+  @protected
+  MulticastListener
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    dynamic raw,
+  );
+
   @protected
   MulticastSender
   dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
@@ -130,32 +152,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  MulticastSender
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+  MinecraftLanBridge
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
     dynamic raw,
   );
 
   @protected
-  ServerStats
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+  MulticastListener
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
     dynamic raw,
   );
 
   @protected
-  EventBusSubscriber
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+  MulticastSender
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
     dynamic raw,
   );
 
   @protected
-  ForwardServer
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+  ServerStats
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
     dynamic raw,
   );
 
   @protected
-  JoinHandle
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+  ForwardServer
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
     dynamic raw,
   );
 
@@ -171,6 +193,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  MinecraftLanBridge
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    dynamic raw,
+  );
+
+  @protected
+  MulticastListener
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    dynamic raw,
+  );
+
   @protected
   MulticastSender
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
@@ -189,691 +223,1994 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<InstanceEventRecord>
+  dco_decode_StreamSink_instance_event_record_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<MulticastPacket> dco_decode_StreamSink_multicast_packet_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
   @protected
   FlagsC dco_decode_box_autoadd_flags_c(dynamic raw);
 
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  InstancePatch dco_decode_box_autoadd_instance_patch(dynamic raw);
+
+  @protected
+  InviteFlags dco_decode_box_autoadd_invite_flags(dynamic raw);
+
+  @protected
+  MagicWallPacket dco_decode_box_autoadd_magic_wall_packet(dynamic raw);
+
   @protected
   MagicWallRule dco_decode_box_autoadd_magic_wall_rule(dynamic raw);
 
   @protected
-  double dco_decode_f_32(dynamic raw);
+  MinecraftLanAnnouncement dco_decode_box_autoadd_minecraft_lan_announcement(
+    dynamic raw,
+  );
 
   @protected
-  double dco_decode_f_64(dynamic raw);
+  NetworkConfigC dco_decode_box_autoadd_network_config_c(dynamic raw);
 
   @protected
-  FlagsC dco_decode_flags_c(dynamic raw);
+  PatchAction dco_decode_box_autoadd_patch_action(dynamic raw);
 
   @protected
-  Forward dco_decode_forward(dynamic raw);
+  ProbeErrorKind dco_decode_box_autoadd_probe_error_kind(dynamic raw);
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  RelayPool dco_decode_box_autoadd_relay_pool(dynamic raw);
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  RestartPolicy dco_decode_box_autoadd_restart_policy(dynamic raw);
 
   @protected
-  KVNetworkStatus dco_decode_kv_network_status(dynamic raw);
+  RoomInvite dco_decode_box_autoadd_room_invite(dynamic raw);
 
   @protected
-  KVNodeConnectionStats dco_decode_kv_node_connection_stats(dynamic raw);
+  RunningNodeInfo dco_decode_box_autoadd_running_node_info(dynamic raw);
 
   @protected
-  KVNodeInfo dco_decode_kv_node_info(dynamic raw);
+  RunningStunInfo dco_decode_box_autoadd_running_stun_info(dynamic raw);
 
   @protected
-  List<PeerRoutePair>
-  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    dynamic raw,
-  );
+  SupervisorStatus dco_decode_box_autoadd_supervisor_status(dynamic raw);
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw);
+  int dco_decode_box_autoadd_u_16(dynamic raw);
 
   @protected
-  List<Forward> dco_decode_list_forward(dynamic raw);
+  int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
-  List<KVNodeConnectionStats> dco_decode_list_kv_node_connection_stats(
-    dynamic raw,
-  );
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
   @protected
-  List<KVNodeInfo> dco_decode_list_kv_node_info(dynamic raw);
+  BigInt dco_decode_box_autoadd_usize(dynamic raw);
 
   @protected
-  List<MagicWallRule> dco_decode_list_magic_wall_rule(dynamic raw);
+  ConfigBuildOptions dco_decode_config_build_options(dynamic raw);
 
   @protected
-  List<NodeHopStats> dco_decode_list_node_hop_stats(dynamic raw);
+  ConfigChange dco_decode_config_change(dynamic raw);
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  ConfigIssue dco_decode_config_issue(dynamic raw);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  double dco_decode_f_32(dynamic raw);
 
   @protected
-  List<(String, int)> dco_decode_list_record_string_u_32(dynamic raw);
+  double dco_decode_f_64(dynamic raw);
 
   @protected
-  MagicWallRule dco_decode_magic_wall_rule(dynamic raw);
+  FilteringBehavior dco_decode_filtering_behavior(dynamic raw);
 
   @protected
-  MagicWallStatus dco_decode_magic_wall_status(dynamic raw);
+  FlagsC dco_decode_flags_c(dynamic raw);
 
   @protected
-  NatType dco_decode_nat_type(dynamic raw);
+  Forward dco_decode_forward(dynamic raw);
 
   @protected
-  NetworkTestResult dco_decode_network_test_result(dynamic raw);
+  ForwardConnectionInfo dco_decode_forward_connection_info(dynamic raw);
 
   @protected
-  NodeHopStats dco_decode_node_hop_stats(dynamic raw);
+  ForwardPatch dco_decode_forward_patch(dynamic raw);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  ForwardServerInfo dco_decode_forward_server_info(dynamic raw);
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+  HolePunchChance dco_decode_hole_punch_chance(dynamic raw);
 
   @protected
-  (String, int) dco_decode_record_string_u_32(dynamic raw);
+  HolePunchPrediction dco_decode_hole_punch_prediction(dynamic raw);
 
   @protected
-  (BigInt, BigInt, BigInt) dco_decode_record_usize_u_64_u_64(dynamic raw);
+  int dco_decode_i_32(dynamic raw);
 
   @protected
-  int dco_decode_u_16(dynamic raw);
+  PlatformInt64 dco_decode_i_64(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  InstanceEvent dco_decode_instance_event(dynamic raw);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  InstanceEventRecord dco_decode_instance_event_record(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  InstancePatch dco_decode_instance_patch(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  InvalidCidrPolicy dco_decode_invalid_cidr_policy(dynamic raw);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  InviteFlags dco_decode_invite_flags(dynamic raw);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  KVNetworkStatus dco_decode_kv_network_status(dynamic raw);
 
   @protected
-  EventBusSubscriber
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    SseDeserializer deserializer,
-  );
+  KVNodeConnectionStats dco_decode_kv_node_connection_stats(dynamic raw);
 
   @protected
-  ForwardServer
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    SseDeserializer deserializer,
-  );
+  KVNodeInfo dco_decode_kv_node_info(dynamic raw);
 
   @protected
-  JoinHandle
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    SseDeserializer deserializer,
+  List<PeerRoutePair>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    dynamic raw,
   );
 
   @protected
-  JoinHandleResultString
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    SseDeserializer deserializer,
-  );
+  List<String> dco_decode_list_String(dynamic raw);
 
   @protected
-  JoinHandleResultStringString
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    SseDeserializer deserializer,
-  );
+  List<ConfigChange> dco_decode_list_config_change(dynamic raw);
 
   @protected
-  MulticastSender
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    SseDeserializer deserializer,
-  );
+  List<ConfigIssue> dco_decode_list_config_issue(dynamic raw);
 
   @protected
-  PeerRoutePair
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
-  );
+  List<Forward> dco_decode_list_forward(dynamic raw);
 
   @protected
-  ServerStats
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    SseDeserializer deserializer,
+  List<ForwardConnectionInfo> dco_decode_list_forward_connection_info(
+    dynamic raw,
   );
 
   @protected
-  ForwardServer
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    SseDeserializer deserializer,
-  );
+  List<ForwardPatch> dco_decode_list_forward_patch(dynamic raw);
 
   @protected
-  MulticastSender
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    SseDeserializer deserializer,
-  );
+  List<ForwardServerInfo> dco_decode_list_forward_server_info(dynamic raw);
 
   @protected
-  ForwardServer
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    SseDeserializer deserializer,
-  );
+  List<HolePunchPrediction> dco_decode_list_hole_punch_prediction(dynamic raw);
 
   @protected
-  MulticastSender
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    SseDeserializer deserializer,
-  );
+  List<InstanceEventRecord> dco_decode_list_instance_event_record(dynamic raw);
 
   @protected
-  ServerStats
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    SseDeserializer deserializer,
+  List<KVNodeConnectionStats> dco_decode_list_kv_node_connection_stats(
+    dynamic raw,
   );
 
   @protected
-  EventBusSubscriber
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    SseDeserializer deserializer,
-  );
+  List<KVNodeInfo> dco_decode_list_kv_node_info(dynamic raw);
 
   @protected
-  ForwardServer
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    SseDeserializer deserializer,
-  );
+  List<MagicWallRule> dco_decode_list_magic_wall_rule(dynamic raw);
 
   @protected
-  JoinHandle
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    SseDeserializer deserializer,
-  );
+  List<MagicWallRuleIssue> dco_decode_list_magic_wall_rule_issue(dynamic raw);
 
   @protected
-  JoinHandleResultString
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    SseDeserializer deserializer,
-  );
+  List<MinecraftLanWorld> dco_decode_list_minecraft_lan_world(dynamic raw);
 
   @protected
-  JoinHandleResultStringString
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    SseDeserializer deserializer,
+  List<MulticastListenerInfo> dco_decode_list_multicast_listener_info(
+    dynamic raw,
   );
 
   @protected
-  MulticastSender
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    SseDeserializer deserializer,
-  );
+  List<MulticastPacket> dco_decode_list_multicast_packet(dynamic raw);
 
   @protected
-  PeerRoutePair
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
-  );
+  List<MulticastSenderInfo> dco_decode_list_multicast_sender_info(dynamic raw);
 
   @protected
-  ServerStats
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    SseDeserializer deserializer,
-  );
+  List<NatBehaviorResult> dco_decode_list_nat_behavior_result(dynamic raw);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  List<NodeHopStats> dco_decode_list_node_hop_stats(dynamic raw);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
   @protected
-  FlagsC sse_decode_box_autoadd_flags_c(SseDeserializer deserializer);
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+  List<(String, int)> dco_decode_list_record_string_u_32(dynamic raw);
 
   @protected
-  MagicWallRule sse_decode_box_autoadd_magic_wall_rule(
-    SseDeserializer deserializer,
-  );
+  List<RunningConnInfo> dco_decode_list_running_conn_info(dynamic raw);
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  List<RunningPeerInfo> dco_decode_list_running_peer_info(dynamic raw);
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  List<RunningRouteInfo> dco_decode_list_running_route_info(dynamic raw);
 
   @protected
-  FlagsC sse_decode_flags_c(SseDeserializer deserializer);
+  List<ServerProbeResult> dco_decode_list_server_probe_result(dynamic raw);
 
   @protected
-  Forward sse_decode_forward(SseDeserializer deserializer);
+  List<StunServerInfo> dco_decode_list_stun_server_info(dynamic raw);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  List<StunServerResult> dco_decode_list_stun_server_result(dynamic raw);
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  List<SupervisorStatus> dco_decode_list_supervisor_status(dynamic raw);
 
   @protected
-  KVNetworkStatus sse_decode_kv_network_status(SseDeserializer deserializer);
+  List<ValuePatch> dco_decode_list_value_patch(dynamic raw);
 
   @protected
-  KVNodeConnectionStats sse_decode_kv_node_connection_stats(
-    SseDeserializer deserializer,
-  );
+  MagicWallPacket dco_decode_magic_wall_packet(dynamic raw);
 
   @protected
-  KVNodeInfo sse_decode_kv_node_info(SseDeserializer deserializer);
+  MagicWallRule dco_decode_magic_wall_rule(dynamic raw);
 
   @protected
-  List<PeerRoutePair>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
-  );
+  MagicWallRuleIssue dco_decode_magic_wall_rule_issue(dynamic raw);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  MagicWallRuleIssueKind dco_decode_magic_wall_rule_issue_kind(dynamic raw);
 
   @protected
-  List<Forward> sse_decode_list_forward(SseDeserializer deserializer);
+  MagicWallStatus dco_decode_magic_wall_status(dynamic raw);
 
   @protected
-  List<KVNodeConnectionStats> sse_decode_list_kv_node_connection_stats(
-    SseDeserializer deserializer,
-  );
+  MagicWallVerdict dco_decode_magic_wall_verdict(dynamic raw);
 
   @protected
-  List<KVNodeInfo> sse_decode_list_kv_node_info(SseDeserializer deserializer);
+  MappingBehavior dco_decode_mapping_behavior(dynamic raw);
 
   @protected
-  List<MagicWallRule> sse_decode_list_magic_wall_rule(
-    SseDeserializer deserializer,
-  );
+  MinecraftLanAnnouncement dco_decode_minecraft_lan_announcement(dynamic raw);
 
   @protected
-  List<NodeHopStats> sse_decode_list_node_hop_stats(
-    SseDeserializer deserializer,
-  );
+  MinecraftLanWorld dco_decode_minecraft_lan_world(dynamic raw);
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  MulticastListenerInfo dco_decode_multicast_listener_info(dynamic raw);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  MulticastPacket dco_decode_multicast_packet(dynamic raw);
 
   @protected
-  List<(String, int)> sse_decode_list_record_string_u_32(
-    SseDeserializer deserializer,
+  MulticastSenderInfo dco_decode_multicast_sender_info(dynamic raw);
+
+  @protected
+  NatBehaviorResult dco_decode_nat_behavior_result(dynamic raw);
+
+  @protected
+  NatType dco_decode_nat_type(dynamic raw);
+
+  @protected
+  NetworkConfigC dco_decode_network_config_c(dynamic raw);
+
+  @protected
+  NetworkTestResult dco_decode_network_test_result(dynamic raw);
+
+  @protected
+  NodeHopStats dco_decode_node_hop_stats(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  MinecraftLanAnnouncement?
+  dco_decode_opt_box_autoadd_minecraft_lan_announcement(
+    dynamic raw,
+  );
+
+  @protected
+  PatchAction? dco_decode_opt_box_autoadd_patch_action(dynamic raw);
+
+  @protected
+  ProbeErrorKind? dco_decode_opt_box_autoadd_probe_error_kind(dynamic raw);
+
+  @protected
+  RelayPool? dco_decode_opt_box_autoadd_relay_pool(dynamic raw);
+
+  @protected
+  RestartPolicy? dco_decode_opt_box_autoadd_restart_policy(dynamic raw);
+
+  @protected
+  RunningNodeInfo? dco_decode_opt_box_autoadd_running_node_info(dynamic raw);
+
+  @protected
+  RunningStunInfo? dco_decode_opt_box_autoadd_running_stun_info(dynamic raw);
+
+  @protected
+  SupervisorStatus? dco_decode_opt_box_autoadd_supervisor_status(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  PatchAction dco_decode_patch_action(dynamic raw);
+
+  @protected
+  PeerConnEvent dco_decode_peer_conn_event(dynamic raw);
+
+  @protected
+  ProbeErrorKind dco_decode_probe_error_kind(dynamic raw);
+
+  @protected
+  (String, int) dco_decode_record_string_u_32(dynamic raw);
+
+  @protected
+  (BigInt, BigInt, BigInt) dco_decode_record_usize_u_64_u_64(dynamic raw);
+
+  @protected
+  RelayPool dco_decode_relay_pool(dynamic raw);
+
+  @protected
+  RestartPolicy dco_decode_restart_policy(dynamic raw);
+
+  @protected
+  RoomInvite dco_decode_room_invite(dynamic raw);
+
+  @protected
+  RunningConnInfo dco_decode_running_conn_info(dynamic raw);
+
+  @protected
+  RunningInfo dco_decode_running_info(dynamic raw);
+
+  @protected
+  RunningNodeInfo dco_decode_running_node_info(dynamic raw);
+
+  @protected
+  RunningPeerInfo dco_decode_running_peer_info(dynamic raw);
+
+  @protected
+  RunningRouteInfo dco_decode_running_route_info(dynamic raw);
+
+  @protected
+  RunningStunInfo dco_decode_running_stun_info(dynamic raw);
+
+  @protected
+  ServerProbeResult dco_decode_server_probe_result(dynamic raw);
+
+  @protected
+  StunServerInfo dco_decode_stun_server_info(dynamic raw);
+
+  @protected
+  StunServerResult dco_decode_stun_server_result(dynamic raw);
+
+  @protected
+  SupervisorState dco_decode_supervisor_state(dynamic raw);
+
+  @protected
+  SupervisorStatus dco_decode_supervisor_status(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  ValuePatch dco_decode_value_patch(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  ForwardServer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  JoinHandleResultString
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  JoinHandleResultStringString
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MinecraftLanBridge
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastListener
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastSender
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PeerRoutePair
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ServerStats
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ForwardServer
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MinecraftLanBridge
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastListener
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastSender
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ForwardServer
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MinecraftLanBridge
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastListener
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastSender
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ServerStats
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ForwardServer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  JoinHandleResultString
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  JoinHandleResultStringString
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MinecraftLanBridge
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastListener
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastSender
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PeerRoutePair
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ServerStats
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<InstanceEventRecord>
+  sse_decode_StreamSink_instance_event_record_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<MulticastPacket> sse_decode_StreamSink_multicast_packet_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  FlagsC sse_decode_box_autoadd_flags_c(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  InstancePatch sse_decode_box_autoadd_instance_patch(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InviteFlags sse_decode_box_autoadd_invite_flags(SseDeserializer deserializer);
+
+  @protected
+  MagicWallPacket sse_decode_box_autoadd_magic_wall_packet(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MagicWallRule sse_decode_box_autoadd_magic_wall_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MinecraftLanAnnouncement sse_decode_box_autoadd_minecraft_lan_announcement(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NetworkConfigC sse_decode_box_autoadd_network_config_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PatchAction sse_decode_box_autoadd_patch_action(SseDeserializer deserializer);
+
+  @protected
+  ProbeErrorKind sse_decode_box_autoadd_probe_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RelayPool sse_decode_box_autoadd_relay_pool(SseDeserializer deserializer);
+
+  @protected
+  RestartPolicy sse_decode_box_autoadd_restart_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomInvite sse_decode_box_autoadd_room_invite(SseDeserializer deserializer);
+
+  @protected
+  RunningNodeInfo sse_decode_box_autoadd_running_node_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RunningStunInfo sse_decode_box_autoadd_running_stun_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SupervisorStatus sse_decode_box_autoadd_supervisor_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  ConfigBuildOptions sse_decode_config_build_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConfigChange sse_decode_config_change(SseDeserializer deserializer);
+
+  @protected
+  ConfigIssue sse_decode_config_issue(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FilteringBehavior sse_decode_filtering_behavior(SseDeserializer deserializer);
+
+  @protected
+  FlagsC sse_decode_flags_c(SseDeserializer deserializer);
+
+  @protected
+  Forward sse_decode_forward(SseDeserializer deserializer);
+
+  @protected
+  ForwardConnectionInfo sse_decode_forward_connection_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ForwardPatch sse_decode_forward_patch(SseDeserializer deserializer);
+
+  @protected
+  ForwardServerInfo sse_decode_forward_server_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HolePunchChance sse_decode_hole_punch_chance(SseDeserializer deserializer);
+
+  @protected
+  HolePunchPrediction sse_decode_hole_punch_prediction(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  InstanceEvent sse_decode_instance_event(SseDeserializer deserializer);
+
+  @protected
+  InstanceEventRecord sse_decode_instance_event_record(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InstancePatch sse_decode_instance_patch(SseDeserializer deserializer);
+
+  @protected
+  InvalidCidrPolicy sse_decode_invalid_cidr_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InviteFlags sse_decode_invite_flags(SseDeserializer deserializer);
+
+  @protected
+  KVNetworkStatus sse_decode_kv_network_status(SseDeserializer deserializer);
+
+  @protected
+  KVNodeConnectionStats sse_decode_kv_node_connection_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KVNodeInfo sse_decode_kv_node_info(SseDeserializer deserializer);
+
+  @protected
+  List<PeerRoutePair>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<ConfigChange> sse_decode_list_config_change(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ConfigIssue> sse_decode_list_config_issue(SseDeserializer deserializer);
+
+  @protected
+  List<Forward> sse_decode_list_forward(SseDeserializer deserializer);
+
+  @protected
+  List<ForwardConnectionInfo> sse_decode_list_forward_connection_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ForwardPatch> sse_decode_list_forward_patch(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ForwardServerInfo> sse_decode_list_forward_server_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<HolePunchPrediction> sse_decode_list_hole_punch_prediction(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<InstanceEventRecord> sse_decode_list_instance_event_record(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<KVNodeConnectionStats> sse_decode_list_kv_node_connection_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<KVNodeInfo> sse_decode_list_kv_node_info(SseDeserializer deserializer);
+
+  @protected
+  List<MagicWallRule> sse_decode_list_magic_wall_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MagicWallRuleIssue> sse_decode_list_magic_wall_rule_issue(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MinecraftLanWorld> sse_decode_list_minecraft_lan_world(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MulticastListenerInfo> sse_decode_list_multicast_listener_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MulticastPacket> sse_decode_list_multicast_packet(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MulticastSenderInfo> sse_decode_list_multicast_sender_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NatBehaviorResult> sse_decode_list_nat_behavior_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NodeHopStats> sse_decode_list_node_hop_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<(String, int)> sse_decode_list_record_string_u_32(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RunningConnInfo> sse_decode_list_running_conn_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RunningPeerInfo> sse_decode_list_running_peer_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RunningRouteInfo> sse_decode_list_running_route_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ServerProbeResult> sse_decode_list_server_probe_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<StunServerInfo> sse_decode_list_stun_server_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<StunServerResult> sse_decode_list_stun_server_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SupervisorStatus> sse_decode_list_supervisor_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ValuePatch> sse_decode_list_value_patch(SseDeserializer deserializer);
+
+  @protected
+  MagicWallPacket sse_decode_magic_wall_packet(SseDeserializer deserializer);
+
+  @protected
+  MagicWallRule sse_decode_magic_wall_rule(SseDeserializer deserializer);
+
+  @protected
+  MagicWallRuleIssue sse_decode_magic_wall_rule_issue(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MagicWallRuleIssueKind sse_decode_magic_wall_rule_issue_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MagicWallStatus sse_decode_magic_wall_status(SseDeserializer deserializer);
+
+  @protected
+  MagicWallVerdict sse_decode_magic_wall_verdict(SseDeserializer deserializer);
+
+  @protected
+  MappingBehavior sse_decode_mapping_behavior(SseDeserializer deserializer);
+
+  @protected
+  MinecraftLanAnnouncement sse_decode_minecraft_lan_announcement(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MinecraftLanWorld sse_decode_minecraft_lan_world(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastListenerInfo sse_decode_multicast_listener_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MulticastPacket sse_decode_multicast_packet(SseDeserializer deserializer);
+
+  @protected
+  MulticastSenderInfo sse_decode_multicast_sender_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NatBehaviorResult sse_decode_nat_behavior_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NatType sse_decode_nat_type(SseDeserializer deserializer);
+
+  @protected
+  NetworkConfigC sse_decode_network_config_c(SseDeserializer deserializer);
+
+  @protected
+  NetworkTestResult sse_decode_network_test_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NodeHopStats sse_decode_node_hop_stats(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  MinecraftLanAnnouncement?
+  sse_decode_opt_box_autoadd_minecraft_lan_announcement(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PatchAction? sse_decode_opt_box_autoadd_patch_action(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProbeErrorKind? sse_decode_opt_box_autoadd_probe_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RelayPool? sse_decode_opt_box_autoadd_relay_pool(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RestartPolicy? sse_decode_opt_box_autoadd_restart_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RunningNodeInfo? sse_decode_opt_box_autoadd_running_node_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RunningStunInfo? sse_decode_opt_box_autoadd_running_stun_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SupervisorStatus? sse_decode_opt_box_autoadd_supervisor_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  PatchAction sse_decode_patch_action(SseDeserializer deserializer);
+
+  @protected
+  PeerConnEvent sse_decode_peer_conn_event(SseDeserializer deserializer);
+
+  @protected
+  ProbeErrorKind sse_decode_probe_error_kind(SseDeserializer deserializer);
+
+  @protected
+  (String, int) sse_decode_record_string_u_32(SseDeserializer deserializer);
+
+  @protected
+  (BigInt, BigInt, BigInt) sse_decode_record_usize_u_64_u_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RelayPool sse_decode_relay_pool(SseDeserializer deserializer);
+
+  @protected
+  RestartPolicy sse_decode_restart_policy(SseDeserializer deserializer);
+
+  @protected
+  RoomInvite sse_decode_room_invite(SseDeserializer deserializer);
+
+  @protected
+  RunningConnInfo sse_decode_running_conn_info(SseDeserializer deserializer);
+
+  @protected
+  RunningInfo sse_decode_running_info(SseDeserializer deserializer);
+
+  @protected
+  RunningNodeInfo sse_decode_running_node_info(SseDeserializer deserializer);
+
+  @protected
+  RunningPeerInfo sse_decode_running_peer_info(SseDeserializer deserializer);
+
+  @protected
+  RunningRouteInfo sse_decode_running_route_info(SseDeserializer deserializer);
+
+  @protected
+  RunningStunInfo sse_decode_running_stun_info(SseDeserializer deserializer);
+
+  @protected
+  ServerProbeResult sse_decode_server_probe_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StunServerInfo sse_decode_stun_server_info(SseDeserializer deserializer);
+
+  @protected
+  StunServerResult sse_decode_stun_server_result(SseDeserializer deserializer);
+
+  @protected
+  SupervisorState sse_decode_supervisor_state(SseDeserializer deserializer);
+
+  @protected
+  SupervisorStatus sse_decode_supervisor_status(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  ValuePatch sse_decode_value_patch(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    ForwardServer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    JoinHandleResultString self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    JoinHandleResultStringString self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    MinecraftLanBridge self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    MulticastListener self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    MulticastSender self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    PeerRoutePair self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    ServerStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    ForwardServer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    MinecraftLanBridge self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    MulticastListener self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    MulticastSender self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    ForwardServer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    MinecraftLanBridge self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    MulticastListener self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    MulticastSender self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    ServerStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+    ForwardServer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+    JoinHandleResultString self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    JoinHandleResultStringString self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+    MinecraftLanBridge self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+    MulticastListener self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
+    MulticastSender self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    PeerRoutePair self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
+    ServerStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_instance_event_record_Sse(
+    RustStreamSink<InstanceEventRecord> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_multicast_packet_Sse(
+    RustStreamSink<MulticastPacket> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_flags_c(FlagsC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_instance_patch(
+    InstancePatch self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_invite_flags(
+    InviteFlags self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_magic_wall_packet(
+    MagicWallPacket self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_magic_wall_rule(
+    MagicWallRule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_minecraft_lan_announcement(
+    MinecraftLanAnnouncement self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_network_config_c(
+    NetworkConfigC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_patch_action(
+    PatchAction self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_probe_error_kind(
+    ProbeErrorKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_relay_pool(
+    RelayPool self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_restart_policy(
+    RestartPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_room_invite(
+    RoomInvite self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_running_node_info(
+    RunningNodeInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_running_stun_info(
+    RunningStunInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_supervisor_status(
+    SupervisorStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_config_build_options(
+    ConfigBuildOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_config_change(ConfigChange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_config_issue(ConfigIssue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_filtering_behavior(
+    FilteringBehavior self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_flags_c(FlagsC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_forward(Forward self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_forward_connection_info(
+    ForwardConnectionInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_forward_patch(ForwardPatch self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_forward_server_info(
+    ForwardServerInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_hole_punch_chance(
+    HolePunchChance self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_hole_punch_prediction(
+    HolePunchPrediction self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_instance_event(InstanceEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_instance_event_record(
+    InstanceEventRecord self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_instance_patch(InstancePatch self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_invalid_cidr_policy(
+    InvalidCidrPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_invite_flags(InviteFlags self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_kv_network_status(
+    KVNetworkStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_kv_node_connection_stats(
+    KVNodeConnectionStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_kv_node_info(KVNodeInfo self, SseSerializer serializer);
+
+  @protected
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    List<PeerRoutePair> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_config_change(
+    List<ConfigChange> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_config_issue(
+    List<ConfigIssue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_forward(List<Forward> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_forward_connection_info(
+    List<ForwardConnectionInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_forward_patch(
+    List<ForwardPatch> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_forward_server_info(
+    List<ForwardServerInfo> self,
+    SseSerializer serializer,
   );
 
   @protected
-  MagicWallRule sse_decode_magic_wall_rule(SseDeserializer deserializer);
+  void sse_encode_list_hole_punch_prediction(
+    List<HolePunchPrediction> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MagicWallStatus sse_decode_magic_wall_status(SseDeserializer deserializer);
+  void sse_encode_list_instance_event_record(
+    List<InstanceEventRecord> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NatType sse_decode_nat_type(SseDeserializer deserializer);
+  void sse_encode_list_kv_node_connection_stats(
+    List<KVNodeConnectionStats> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NetworkTestResult sse_decode_network_test_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_kv_node_info(
+    List<KVNodeInfo> self,
+    SseSerializer serializer,
   );
 
   @protected
-  NodeHopStats sse_decode_node_hop_stats(SseDeserializer deserializer);
+  void sse_encode_list_magic_wall_rule(
+    List<MagicWallRule> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_list_magic_wall_rule_issue(
+    List<MagicWallRuleIssue> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+  void sse_encode_list_minecraft_lan_world(
+    List<MinecraftLanWorld> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  (String, int) sse_decode_record_string_u_32(SseDeserializer deserializer);
+  void sse_encode_list_multicast_listener_info(
+    List<MulticastListenerInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  (BigInt, BigInt, BigInt) sse_decode_record_usize_u_64_u_64(
-    SseDeserializer deserializer,
+  void sse_encode_list_multicast_packet(
+    List<MulticastPacket> self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer);
+  void sse_encode_list_multicast_sender_info(
+    List<MulticastSenderInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_list_nat_behavior_result(
+    List<NatBehaviorResult> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_list_node_hop_stats(
+    List<NodeHopStats> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_list_record_string_u_32(
+    List<(String, int)> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_running_conn_info(
+    List<RunningConnInfo> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    EventBusSubscriber self,
+  void sse_encode_list_running_peer_info(
+    List<RunningPeerInfo> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    ForwardServer self,
+  void sse_encode_list_running_route_info(
+    List<RunningRouteInfo> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    JoinHandle self,
+  void sse_encode_list_server_probe_result(
+    List<ServerProbeResult> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    JoinHandleResultString self,
+  void sse_encode_list_stun_server_info(
+    List<StunServerInfo> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    JoinHandleResultStringString self,
+  void sse_encode_list_stun_server_result(
+    List<StunServerResult> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    MulticastSender self,
+  void sse_encode_list_supervisor_status(
+    List<SupervisorStatus> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    PeerRoutePair self,
+  void sse_encode_list_value_patch(
+    List<ValuePatch> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    ServerStats self,
+  void sse_encode_magic_wall_packet(
+    MagicWallPacket self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    ForwardServer self,
+  void sse_encode_magic_wall_rule(MagicWallRule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_magic_wall_rule_issue(
+    MagicWallRuleIssue self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    MulticastSender self,
+  void sse_encode_magic_wall_rule_issue_kind(
+    MagicWallRuleIssueKind self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    ForwardServer self,
+  void sse_encode_magic_wall_status(
+    MagicWallStatus self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    MulticastSender self,
+  void sse_encode_magic_wall_verdict(
+    MagicWallVerdict self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    ServerStats self,
+  void sse_encode_mapping_behavior(
+    MappingBehavior self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    EventBusSubscriber self,
+  void sse_encode_minecraft_lan_announcement(
+    MinecraftLanAnnouncement self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
-    ForwardServer self,
+  void sse_encode_minecraft_lan_world(
+    MinecraftLanWorld self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    JoinHandle self,
+  void sse_encode_multicast_listener_info(
+    MulticastListenerInfo self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
-    JoinHandleResultString self,
+  void sse_encode_multicast_packet(
+    MulticastPacket self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    JoinHandleResultStringString self,
+  void sse_encode_multicast_sender_info(
+    MulticastSenderInfo self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
-    MulticastSender self,
+  void sse_encode_nat_behavior_result(
+    NatBehaviorResult self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    PeerRoutePair self,
+  void sse_encode_nat_type(NatType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_network_config_c(
+    NetworkConfigC self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerStats(
-    ServerStats self,
+  void sse_encode_network_test_result(
+    NetworkTestResult self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_node_hop_stats(NodeHopStats self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_flags_c(FlagsC self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  );
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_magic_wall_rule(
-    MagicWallRule self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_minecraft_lan_announcement(
+    MinecraftLanAnnouncement? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_patch_action(
+    PatchAction? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_flags_c(FlagsC self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_probe_error_kind(
+    ProbeErrorKind? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_forward(Forward self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_relay_pool(
+    RelayPool? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_restart_policy(
+    RestartPolicy? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_running_node_info(
+    RunningNodeInfo? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_kv_network_status(
-    KVNetworkStatus self,
+  void sse_encode_opt_box_autoadd_running_stun_info(
+    RunningStunInfo? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_kv_node_connection_stats(
-    KVNodeConnectionStats self,
+  void sse_encode_opt_box_autoadd_supervisor_status(
+    SupervisorStatus? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_kv_node_info(KVNodeInfo self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    List<PeerRoutePair> self,
-    SseSerializer serializer,
-  );
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_forward(List<Forward> self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_kv_node_connection_stats(
-    List<KVNodeConnectionStats> self,
-    SseSerializer serializer,
-  );
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_kv_node_info(
-    List<KVNodeInfo> self,
+  void sse_encode_patch_action(PatchAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_peer_conn_event(PeerConnEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_probe_error_kind(
+    ProbeErrorKind self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_magic_wall_rule(
-    List<MagicWallRule> self,
+  void sse_encode_record_string_u_32(
+    (String, int) self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_node_hop_stats(
-    List<NodeHopStats> self,
+  void sse_encode_record_usize_u_64_u_64(
+    (BigInt, BigInt, BigInt) self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  void sse_encode_relay_pool(RelayPool self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+  void sse_encode_restart_policy(RestartPolicy self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_record_string_u_32(
-    List<(String, int)> self,
+  void sse_encode_room_invite(RoomInvite self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_running_conn_info(
+    RunningConnInfo self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_magic_wall_rule(MagicWallRule self, SseSerializer serializer);
+  void sse_encode_running_info(RunningInfo self, SseSerializer serializer);
 
   @protected
-  void sse_encode_magic_wall_status(
-    MagicWallStatus self,
+  void sse_encode_running_node_info(
+    RunningNodeInfo self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_nat_type(NatType self, SseSerializer serializer);
+  void sse_encode_running_peer_info(
+    RunningPeerInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_network_test_result(
-    NetworkTestResult self,
+  void sse_encode_running_route_info(
+    RunningRouteInfo self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_node_hop_stats(NodeHopStats self, SseSerializer serializer);
+  void sse_encode_running_stun_info(
+    RunningStunInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_server_probe_result(
+    ServerProbeResult self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
+  void sse_encode_stun_server_info(
+    StunServerInfo self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_record_string_u_32(
-    (String, int) self,
+  void sse_encode_stun_server_result(
+    StunServerResult self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_record_usize_u_64_u_64(
-    (BigInt, BigInt, BigInt) self,
+  void sse_encode_supervisor_state(
+    SupervisorState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_supervisor_status(
+    SupervisorStatus self,
     SseSerializer serializer,
   );
 
@@ -894,6 +2231,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_value_patch(ValuePatch self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -902,82 +2242,82 @@ class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
         ptr,
       );
 
@@ -1037,52 +2377,52 @@ external RustLibWasmModule get wasmModule;
 @anonymous
 extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
     int ptr,
   );
 
   external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
     int ptr,
   );
 
   external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
     int ptr,
   );
 
   external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
     int ptr,
   );
 
   external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
     int ptr,
   );
 
   external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
     int ptr,
   );
 
   external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
     int ptr,
   );
 
   external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
     int ptr,
   );
 
   external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
     int ptr,
   );
 
   external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
     int ptr,
   );
 
@@ -0,0 +1,51 @@
+//! 后台收包、accept 循环出错时的退避
+//!
+//! 持续出错的套接字不会让循环空转占满 CPU，不可恢复的错误由调用方结束循环。
+
+use std::time::Duration;
+use tokio::io;
+use tokio_util::sync::CancellationToken;
+
+/// 首次出错后的等待时间，之后每次翻倍直到 `MAX_DELAY`
+const INITIAL_DELAY: Duration = Duration::from_millis(10);
+const MAX_DELAY: Duration = Duration::from_secs(1);
+
+/// 只影响单个数据报或对端的错误，套接字本身仍可继续收包。
+/// Windows 会把之前发送触发的 ICMP 端口不可达报告为下一次接收的 `ConnectionReset`
+pub(crate) fn is_transient(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::Interrupted
+            | io::ErrorKind::WouldBlock
+            | io::ErrorKind::TimedOut
+    )
+}
+
+pub(crate) struct Backoff {
+    delay: Duration,
+}
+
+impl Backoff {
+    pub(crate) fn new() -> Self {
+        Self {
+            delay: INITIAL_DELAY,
+        }
+    }
+
+    /// 成功后恢复初始等待时间
+    pub(crate) fn reset(&mut self) {
+        self.delay = INITIAL_DELAY;
+    }
+
+    /// 等待当前时长并翻倍，被取消时返回 false
+    pub(crate) async fn wait(&mut self, cancel: &CancellationToken) -> bool {
+        let delay = self.delay;
+        self.delay = (self.delay * 2).min(MAX_DELAY);
+        tokio::select! {
+            _ = cancel.cancelled() => false,
+            _ = tokio::time::sleep(delay) => true,
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! Persistent per-instance event history.
+//!
+//! Every event published by `p2p::handle_event` is appended as one JSON line to
+//! `<dir>/<instance_id>.jsonl`. When a file grows past `MAX_LOG_FILE_BYTES` it is
+//! rotated to `<instance_id>.1.jsonl`, `<instance_id>.2.jsonl`, ... and the oldest
+//! rotation is dropped, so each instance keeps a bounded amount of history.
+//!
+//! Writes happen on a dedicated thread, so publishing an event never waits for
+//! the disk.
+
+use lazy_static::lazy_static;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Mutex, RwLock};
+use std::thread;
+use std::time::SystemTime;
+use uuid::Uuid;
+
+use super::p2p::InstanceEventRecord;
+
+/// Size at which the active log file of an instance is rotated.
+const MAX_LOG_FILE_BYTES: u64 = 512 * 1024;
+/// Number of rotated files kept per instance in addition to the active one.
+const MAX_ROTATED_FILES: usize = 4;
+/// Number of instances whose history survives `init_event_log`.
+const MAX_LOGGED_INSTANCES: usize = 32;
+const LOG_EXTENSION: &str = "jsonl";
+
+lazy_static! {
+    /// Log directory; `None` until Flutter calls `init_event_log`.
+    static ref LOG_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+    /// Queue of the writer thread; `None` until Flutter calls `init_event_log`.
+    static ref WRITER: Mutex<Option<Sender<InstanceEventRecord>>> = Mutex::new(None);
+    /// Held exclusively while log files are written, rotated or pruned and
+    /// shared while they are read, so readers never see a half-done rotation.
+    static ref FILES: RwLock<()> = RwLock::new(());
+}
+
+fn log_file(dir: &Path, instance_id: &str, rotation: usize) -> PathBuf {
+    if rotation == 0 {
+        dir.join(format!("{}.{}", instance_id, LOG_EXTENSION))
+    } else {
+        dir.join(format!("{}.{}.{}", instance_id, rotation, LOG_EXTENSION))
+    }
+}
+
+fn rotate(dir: &Path, instance_id: &str) -> std::io::Result<()> {
+    let oldest = log_file(dir, instance_id, MAX_ROTATED_FILES);
+    if oldest.exists() {
+        fs::remove_file(&oldest)?;
+    }
+    for rotation in (0..MAX_ROTATED_FILES).rev() {
+        let from = log_file(dir, instance_id, rotation);
+        if from.exists() {
+            fs::rename(&from, log_file(dir, instance_id, rotation + 1))?;
+        }
+    }
+    Ok(())
+}
+
+/// Lists instances that have log files, most recently active first.
+fn logged_instances(dir: &Path) -> std::io::Result<Vec<(String, SystemTime)>> {
+    let mut instances: Vec<(String, SystemTime)> = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let Some(id) = instance_id_of(&entry.path()) else {
+            continue;
+        };
+        let modified = entry.metadata()?.modified()?;
+        match instances.iter_mut().find(|(known, _)| *known == id) {
+            Some((_, latest)) => *latest = (*latest).max(modified),
+            None => instances.push((id, modified)),
+        }
+    }
+    instances.sort_by_key(|(_, modified)| std::cmp::Reverse(*modified));
+    Ok(instances)
+}
+
+/// Removes the history of all but the most recently active instances.
+fn prune_instances(dir: &Path) -> std::io::Result<()> {
+    for (id, _) in logged_instances(dir)?.iter().skip(MAX_LOGGED_INSTANCES) {
+        for rotation in 0..=MAX_ROTATED_FILES {
+            let path = log_file(dir, id, rotation);
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the instance id a log file belongs to, if it is one of ours.
+fn instance_id_of(path: &Path) -> Option<String> {
+    if path.extension()? != LOG_EXTENSION {
+        return None;
+    }
+    let stem = path.file_stem()?.to_str()?;
+    let id = stem.split('.').next()?;
+    Uuid::parse_str(id).ok().map(|id| id.to_string())
+}
+
+/// Queues a record for its instance's log. Does nothing before `init_event_log`.
+pub(crate) fn append(record: &InstanceEventRecord) -> Result<(), String> {
+    let writer = WRITER.lock().map_err(|e| e.to_string())?;
+    let Some(writer) = writer.as_ref() else {
+        return Ok(());
+    };
+    writer
+        .send(record.clone())
+        .map_err(|_| "event log writer stopped".to_string())
+}
+
+/// Body of the writer thread; ends once `init_event_log` replaces its queue.
+fn run_writer(dir: PathBuf, records: Receiver<InstanceEventRecord>) {
+    for record in records {
+        if let Err(e) = write_record(&dir, &record) {
+            eprintln!("write event log failed: {}", e);
+        }
+    }
+}
+
+fn write_record(dir: &Path, record: &InstanceEventRecord) -> Result<(), String> {
+    let _files = FILES.write().map_err(|e| e.to_string())?;
+
+    let path = log_file(dir, &record.instance_id, 0);
+    if fs::metadata(&path).is_ok_and(|m| m.len() >= MAX_LOG_FILE_BYTES) {
+        rotate(dir, &record.instance_id).map_err(|e| format!("rotate event log failed: {}", e))?;
+    }
+
+    let line = serde_json::to_string(record).map_err(|e| e.to_string())?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("open event log failed: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("write event log failed: {}", e))
+}
+
+/// Enables event persistence under `dir`, usually the app support directory.
+pub fn init_event_log(dir: String) -> Result<(), String> {
+    let dir = PathBuf::from(dir);
+    fs::create_dir_all(&dir).map_err(|e| format!("create event log dir failed: {}", e))?;
+    {
+        let _files = FILES.write().map_err(|e| e.to_string())?;
+        if let Err(e) = prune_instances(&dir) {
+            eprintln!("prune event logs failed: {}", e);
+        }
+    }
+
+    let (sender, records) = mpsc::channel();
+    let writer_dir = dir.clone();
+    thread::Builder::new()
+        .name("event-log-writer".to_string())
+        .spawn(move || run_writer(writer_dir, records))
+        .map_err(|e| format!("start event log writer failed: {}", e))?;
+    *LOG_DIR.lock().map_err(|e| e.to_string())? = Some(dir);
+    *WRITER.lock().map_err(|e| e.to_string())? = Some(sender);
+    Ok(())
+}
+
+/// Lists instance ids that have recorded history, most recently active first.
+pub fn list_logged_instances() -> Result<Vec<String>, String> {
+    let dir = LOG_DIR.lock().map_err(|e| e.to_string())?;
+    let dir = dir
+        .as_ref()
+        .ok_or_else(|| "event log not initialized".to_string())?;
+
+    let _files = FILES.read().map_err(|e| e.to_string())?;
+    let instances =
+        logged_instances(dir).map_err(|e| format!("read event log dir failed: {}", e))?;
+    Ok(instances.into_iter().map(|(id, _)| id).collect())
+}
+
+/// Queries the recorded events of an instance, oldest first.
+///
+/// `since` is a Unix timestamp in milliseconds (inclusive), `kinds` filters by
+/// event kind such as `"PeerRemoved"` or `"ConnectError"`, and `limit` keeps only
+/// the most recent matches.
+pub fn query_instance_events(
+    instance_id: String,
+    since: Option<i64>,
+    kinds: Option<Vec<String>>,
+    limit: Option<usize>,
+) -> Result<Vec<InstanceEventRecord>, String> {
+    let instance_id = Uuid::parse_str(&instance_id)
+        .map_err(|e| format!("invalid instance_id: {}", e))?
+        .to_string();
+    let dir = LOG_DIR
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clone()
+        .ok_or_else(|| "event log not initialized".to_string())?;
+
+    let _files = FILES.read().map_err(|e| e.to_string())?;
+    let mut records = Vec::new();
+    for rotation in (0..=MAX_ROTATED_FILES).rev() {
+        let Ok(file) = fs::File::open(log_file(&dir, &instance_id, rotation)) else {
+            continue;
+        };
+        for line in BufReader::new(file).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            // skip lines truncated by a crash or written by an incompatible version
+            let Ok(record) = serde_json::from_str::<serde_json::Value>(&line) else {
+                continue;
+            };
+            if since.is_some_and(|since| record["timestamp"].as_i64().unwrap_or(0) < since) {
+                continue;
+            }
+            if let Some(kinds) = &kinds {
+                let kind = record["event"]["kind"].as_str().unwrap_or_default();
+                if !kinds.iter().any(|k| k == kind) {
+                    continue;
+                }
+            }
+            if let Ok(record) = serde_json::from_value::<InstanceEventRecord>(record) {
+                records.push(record);
+            }
+        }
+    }
+
+    if let Some(limit) = limit {
+        let skip = records.len().saturating_sub(limit);
+        records.drain(..skip);
+    }
+    Ok(records)
+}
@@ -1,17 +1,108 @@
 use flutter_rust_bridge::frb;
 use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
-use tokio::net::{TcpListener, TcpStream};
+use tokio::net::{TcpListener, TcpStream, UdpSocket};
+use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
+use tokio::time::{interval, Duration, Instant};
 use tokio_util::sync::CancellationToken;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
-use tokio::sync::Mutex;
 use lazy_static::lazy_static;
 use tokio::runtime::Runtime;
 
+use super::backoff::{is_transient, Backoff};
+use super::registry::{HandleRegistry, RegistryEntry};
+
 lazy_static! {
     static ref RT: Runtime = Runtime::new().expect("创建 Tokio 运行时失败");
-    static ref FORWARD_SERVERS: Mutex<Vec<ForwardServer>> = Mutex::new(Vec::new());
+    static ref FORWARD_SERVERS: HandleRegistry<ForwardServer> = HandleRegistry::new();
+}
+
+/// UDP 会话默认空闲超时时间
+const DEFAULT_UDP_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+/// UDP 会话空闲超时的下限
+const MIN_UDP_IDLE_TIMEOUT: Duration = Duration::from_millis(1);
+/// 每个 UDP 会话在上游建立前后可排队的数据报数量
+const UDP_SESSION_QUEUE: usize = 256;
+/// UDP 单个数据报的最大长度
+const UDP_BUFFER_SIZE: usize = 65535;
+/// 每个服务器保留的已关闭连接记录条数
+const CLOSED_CONNECTION_HISTORY: usize = 100;
+
+/// 端口转发协议
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForwardProtocol {
+    Tcp,
+    Udp,
+}
+
+impl ForwardProtocol {
+    #[frb(ignore)]
+    pub fn parse(protocol: &str) -> io::Result<Self> {
+        match protocol.trim().to_ascii_lowercase().as_str() {
+            "tcp" => Ok(ForwardProtocol::Tcp),
+            "udp" => Ok(ForwardProtocol::Udp),
+            other => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("不支持的转发协议: {}", other),
+            )),
+        }
+    }
+
+    #[frb(ignore)]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ForwardProtocol::Tcp => "tcp",
+            ForwardProtocol::Udp => "udp",
+        }
+    }
+}
+
+/// 单条代理连接的记录
+#[derive(Debug, Clone)]
+pub struct ForwardConnectionInfo {
+    pub id: u64,
+    pub peer_addr: String,
+    /// 建立时间（Unix 毫秒时间戳）
+    pub started_at: i64,
+    /// 关闭时间，连接仍活跃时为 None
+    pub closed_at: Option<i64>,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub close_reason: Option<String>,
+}
+
+struct TrackedConnection {
+    peer_addr: SocketAddr,
+    started_at: i64,
+    bytes_sent: Arc<AtomicU64>,
+    bytes_received: Arc<AtomicU64>,
+    close_reason: Option<String>,
+    cancel_token: CancellationToken,
+}
+
+impl TrackedConnection {
+    fn info(&self, id: u64, closed_at: Option<i64>) -> ForwardConnectionInfo {
+        ForwardConnectionInfo {
+            id,
+            peer_addr: self.peer_addr.to_string(),
+            started_at: self.started_at,
+            closed_at,
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            close_reason: self.close_reason.clone(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct ConnectionTracker {
+    next_id: AtomicU64,
+    active: std::sync::Mutex<HashMap<u64, TrackedConnection>>,
+    closed: std::sync::Mutex<VecDeque<ForwardConnectionInfo>>,
 }
 
 #[frb(opaque)]
@@ -20,6 +111,7 @@ pub struct ServerStats {
     connections: Arc<AtomicUsize>,
     bytes_sent: Arc<AtomicU64>,
     bytes_received: Arc<AtomicU64>,
+    tracker: Arc<ConnectionTracker>,
 }
 
 impl ServerStats {
@@ -28,6 +120,76 @@ impl ServerStats {
             connections: Arc::new(AtomicUsize::new(0)),
             bytes_sent: Arc::new(AtomicU64::new(0)),
             bytes_received: Arc::new(AtomicU64::new(0)),
+            tracker: Arc::new(ConnectionTracker::default()),
+        }
+    }
+
+    /// 登记一条新连接，返回的守卫析构时自动减少计数并归档记录
+    fn open_connection(&self, peer_addr: SocketAddr, parent: &CancellationToken) -> ConnectionGuard {
+        let id = self.tracker.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let bytes_sent = Arc::new(AtomicU64::new(0));
+        let bytes_received = Arc::new(AtomicU64::new(0));
+        let cancel_token = parent.child_token();
+
+        if let Ok(mut active) = self.tracker.active.lock() {
+            active.insert(
+                id,
+                TrackedConnection {
+                    peer_addr,
+                    started_at: chrono::Utc::now().timestamp_millis(),
+                    bytes_sent: bytes_sent.clone(),
+                    bytes_received: bytes_received.clone(),
+                    close_reason: None,
+                    cancel_token: cancel_token.clone(),
+                },
+            );
+        }
+        self.connections.fetch_add(1, Ordering::Relaxed);
+
+        ConnectionGuard {
+            id,
+            stats: self.clone(),
+            bytes_sent,
+            bytes_received,
+            cancel_token,
+        }
+    }
+
+    /// 列出活跃连接和最近关闭的连接
+    #[frb(ignore)]
+    pub fn list_connections(&self) -> Vec<ForwardConnectionInfo> {
+        let mut result: Vec<ForwardConnectionInfo> = self
+            .tracker
+            .active
+            .lock()
+            .map(|active| {
+                active
+                    .iter()
+                    .map(|(id, conn)| conn.info(*id, None))
+                    .collect()
+            })
+            .unwrap_or_default();
+        result.sort_by_key(|conn| conn.id);
+
+        if let Ok(closed) = self.tracker.closed.lock() {
+            result.extend(closed.iter().cloned());
+        }
+        result
+    }
+
+    /// 强制断开指定连接，连接不存在时返回 false
+    #[frb(ignore)]
+    pub fn kill_connection(&self, id: u64) -> bool {
+        let Ok(mut active) = self.tracker.active.lock() else {
+            return false;
+        };
+        match active.get_mut(&id) {
+            Some(conn) => {
+                conn.close_reason.get_or_insert_with(|| "已被手动断开".to_string());
+                conn.cancel_token.cancel();
+                true
+            }
+            None => false,
         }
     }
 
@@ -43,9 +205,60 @@ impl ServerStats {
         self.bytes_received.load(Ordering::Relaxed)
     }
 }
+
+/// 单条代理连接的生命周期守卫
+struct ConnectionGuard {
+    id: u64,
+    stats: ServerStats,
+    bytes_sent: Arc<AtomicU64>,
+    bytes_received: Arc<AtomicU64>,
+    cancel_token: CancellationToken,
+}
+
+impl ConnectionGuard {
+    fn add_sent(&self, n: usize) {
+        self.bytes_sent.fetch_add(n as u64, Ordering::Relaxed);
+        self.stats.bytes_sent.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    fn add_received(&self, n: usize) {
+        self.bytes_received.fetch_add(n as u64, Ordering::Relaxed);
+        self.stats.bytes_received.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    /// 记录关闭原因，只保留第一次设置的原因
+    fn set_close_reason(&self, reason: impl Into<String>) {
+        if let Ok(mut active) = self.stats.tracker.active.lock() {
+            if let Some(conn) = active.get_mut(&self.id) {
+                conn.close_reason.get_or_insert_with(|| reason.into());
+            }
+        }
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.stats.connections.fetch_sub(1, Ordering::Relaxed);
+
+        let conn = self
+            .stats
+            .tracker
+            .active
+            .lock()
+            .ok()
+            .and_then(|mut active| active.remove(&self.id));
+        if let (Some(conn), Ok(mut closed)) = (conn, self.stats.tracker.closed.lock()) {
+            closed.push_front(conn.info(self.id, Some(chrono::Utc::now().timestamp_millis())));
+            closed.truncate(CLOSED_CONNECTION_HISTORY);
+        }
+    }
+}
 #[frb(opaque)]pub struct ForwardServer {
     listen_addr: String,
     forward_addr: String,
+    protocol: ForwardProtocol,
+    udp_idle_timeout: Duration,
+    local_addr: Option<SocketAddr>,
     handle: Option<JoinHandle<()>>,
     cancel_token: Option<CancellationToken>,
     pub stats: ServerStats,
@@ -57,37 +270,64 @@ impl ForwardServer {
         Self {
             listen_addr: listen_addr.into(),
             forward_addr: forward_addr.into(),
+            protocol: ForwardProtocol::Tcp,
+            udp_idle_timeout: DEFAULT_UDP_IDLE_TIMEOUT,
+            local_addr: None,
             handle: None,
             cancel_token: None,
             stats: ServerStats::new(),
         }
     }
 
+    #[frb(ignore)]
+    pub fn with_protocol(mut self, protocol: ForwardProtocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// 设置 UDP 会话的空闲超时
+    #[frb(ignore)]
+    pub fn with_udp_idle_timeout(mut self, timeout: Duration) -> Self {
+        // 超时为 0 会让清理定时器的周期为 0 而 panic
+        self.udp_idle_timeout = timeout.max(MIN_UDP_IDLE_TIMEOUT);
+        self
+    }
+
     pub async fn start(&mut self) -> io::Result<()> {
         if self.handle.is_some() {
             return Err(io::Error::new(io::ErrorKind::AlreadyExists, "服务已启动"));
         }
 
+        let cancel_token = CancellationToken::new();
+        let handle = match self.protocol {
+            ForwardProtocol::Tcp => self.start_tcp(cancel_token.clone()).await?,
+            ForwardProtocol::Udp => self.start_udp(cancel_token.clone()).await?,
+        };
+
+        self.handle = Some(handle);
+        self.cancel_token = Some(cancel_token);
+        Ok(())
+    }
+
+    async fn start_tcp(&mut self, cancel_token: CancellationToken) -> io::Result<JoinHandle<()>> {
         let listener = TcpListener::bind(&self.listen_addr).await?;
+        self.local_addr = listener.local_addr().ok();
         let forward_addr = self.forward_addr.clone();
-        let cancel_token = CancellationToken::new();
-        let cancel_token_clone = cancel_token.clone();
         let stats = self.stats.clone();
 
-        let handle = tokio::spawn(async move {
+        Ok(tokio::spawn(async move {
             loop {
                 tokio::select! {
-                    _ = cancel_token_clone.cancelled() => {
+                    _ = cancel_token.cancelled() => {
                         break;
                     }
                     result = listener.accept() => {
                         match result {
-                            Ok((client_stream, _)) => {
+                            Ok((client_stream, peer_addr)) => {
                                 let forward_addr = forward_addr.clone();
-                                let stats = stats.clone();
-                                stats.connections.fetch_add(1, Ordering::Relaxed);
+                                let guard = stats.open_connection(peer_addr, &cancel_token);
                                 tokio::spawn(async move {
-                                    let _ = handle_connection(client_stream, &forward_addr, stats).await;
+                                    let _ = handle_connection(client_stream, &forward_addr, guard).await;
                                 });
                             }
                             Err(_) => break,
@@ -95,11 +335,94 @@ impl ForwardServer {
                     }
                 }
             }
-        });
+        }))
+    }
 
-        self.handle = Some(handle);
-        self.cancel_token = Some(cancel_token);
-        Ok(())
+    async fn start_udp(&mut self, cancel_token: CancellationToken) -> io::Result<JoinHandle<()>> {
+        let socket = Arc::new(UdpSocket::bind(&self.listen_addr).await?);
+        self.local_addr = socket.local_addr().ok();
+        let forward_addr = self.forward_addr.clone();
+        let idle_timeout = self.udp_idle_timeout;
+        let stats = self.stats.clone();
+
+        Ok(tokio::spawn(async move {
+            // 以客户端源地址为键的 NAT 会话表
+            let mut sessions: HashMap<SocketAddr, UdpSession> = HashMap::new();
+            let mut buf = vec![0u8; UDP_BUFFER_SIZE];
+            let mut sweeper = interval(idle_timeout.min(Duration::from_secs(5)));
+            let mut backoff = Backoff::new();
+
+            loop {
+                tokio::select! {
+                    _ = cancel_token.cancelled() => {
+                        break;
+                    }
+                    _ = sweeper.tick() => {
+                        sessions.retain(|_, session| {
+                            if session.guard.cancel_token.is_cancelled() {
+                                false
+                            } else if session.is_idle(idle_timeout) {
+                                session.guard.set_close_reason("空闲超时");
+                                session.guard.cancel_token.cancel();
+                                false
+                            } else {
+                                true
+                            }
+                        });
+                    }
+                    result = socket.recv_from(&mut buf) => {
+                        let (n, client_addr) = match result {
+                            Ok(recv) => {
+                                backoff.reset();
+                                recv
+                            }
+                            // Windows 上 ICMP 端口不可达会表现为 recv_from 错误，稍后重试
+                            Err(e) if is_transient(&e) => {
+                                if !backoff.wait(&cancel_token).await {
+                                    break;
+                                }
+                                continue;
+                            }
+                            Err(e) => {
+                                println!("UDP 转发监听套接字出错，停止转发: {}", e);
+                                break;
+                            }
+                        };
+
+                        // 被手动断开的会话在收到新数据时重新建立
+                        if sessions
+                            .get(&client_addr)
+                            .is_some_and(|session| session.guard.cancel_token.is_cancelled())
+                        {
+                            sessions.remove(&client_addr);
+                        }
+
+                        // 会话的上游连接在后台建立，不阻塞其它客户端的收包
+                        let session = match sessions.entry(client_addr) {
+                            Entry::Occupied(entry) => entry.into_mut(),
+                            Entry::Vacant(entry) => {
+                                let guard = stats.open_connection(client_addr, &cancel_token);
+                                entry.insert(UdpSession::spawn(
+                                    socket.clone(),
+                                    client_addr,
+                                    forward_addr.clone(),
+                                    guard,
+                                ))
+                            }
+                        };
+
+                        session.touch();
+                        // 队列已满时按 UDP 语义直接丢弃该数据报
+                        let _ = session.queue.try_send(buf[..n].to_vec());
+                    }
+                }
+            }
+
+            for (_, session) in sessions.drain() {
+                session.guard.set_close_reason("服务已停止");
+                session.guard.cancel_token.cancel();
+            }
+        }))
     }
 
     pub async fn stop(&mut self) {
@@ -114,15 +437,159 @@ impl ForwardServer {
     pub fn is_running(&self) -> bool {
         self.handle.is_some()
     }
+
+    /// 实际绑定的监听地址，监听端口为 0 时可用于获取系统分配的端口
+    #[frb(ignore)]
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    #[frb(ignore)]
+    pub fn info(&self, id: &str) -> ForwardServerInfo {
+        ForwardServerInfo {
+            id: id.to_string(),
+            listen_addr: self.listen_addr.clone(),
+            forward_addr: self.forward_addr.clone(),
+            protocol: self.protocol.as_str().to_string(),
+            is_running: self.is_running(),
+            connections: self.stats.get_connections(),
+            bytes_sent: self.stats.get_bytes_sent(),
+            bytes_received: self.stats.get_bytes_received(),
+        }
+    }
+}
+
+impl RegistryEntry for ForwardServer {
+    #[frb(ignore)]
+    async fn stop(&mut self) {
+        ForwardServer::stop(self).await
+    }
+}
+
+/// 单个客户端源地址对应的 UDP 转发会话
+struct UdpSession {
+    queue: mpsc::Sender<Vec<u8>>,
+    last_active: Arc<std::sync::Mutex<Instant>>,
+    guard: Arc<ConnectionGuard>,
+}
+
+impl UdpSession {
+    /// 创建会话并在后台解析、连接上游，建立前收到的数据报先在队列中等待
+    fn spawn(
+        socket: Arc<UdpSocket>,
+        client_addr: SocketAddr,
+        forward_addr: String,
+        guard: ConnectionGuard,
+    ) -> Self {
+        let (queue, mut pending) = mpsc::channel::<Vec<u8>>(UDP_SESSION_QUEUE);
+        let last_active = Arc::new(std::sync::Mutex::new(Instant::now()));
+        let guard = Arc::new(guard);
+
+        let last_active_clone = last_active.clone();
+        let guard_clone = guard.clone();
+        tokio::spawn(async move {
+            let connect_result = tokio::select! {
+                _ = guard_clone.cancel_token.cancelled() => return,
+                result = Self::connect_upstream(&forward_addr) => result,
+            };
+            let upstream = match connect_result {
+                Ok(upstream) => upstream,
+                Err(e) => {
+                    // 取消后由清理定时器或下一个数据报移除该会话
+                    guard_clone.set_close_reason(format!("连接目标失败: {}", e));
+                    guard_clone.cancel_token.cancel();
+                    return;
+                }
+            };
+
+            let mut buf = vec![0u8; UDP_BUFFER_SIZE];
+            loop {
+                tokio::select! {
+                    _ = guard_clone.cancel_token.cancelled() => {
+                        break;
+                    }
+                    datagram = pending.recv() => {
+                        let Some(datagram) = datagram else {
+                            break;
+                        };
+                        if upstream.send(&datagram).await.is_ok() {
+                            guard_clone.add_sent(datagram.len());
+                        }
+                    }
+                    result = upstream.recv(&mut buf) => {
+                        let n = match result {
+                            Ok(n) => n,
+                            Err(e) => {
+                                // 目标端口不可达等错误会一直出现，结束会话，
+                                // 客户端再发数据时重新建立
+                                guard_clone.set_close_reason(format!("接收目标数据失败: {}", e));
+                                guard_clone.cancel_token.cancel();
+                                break;
+                            }
+                        };
+                        if socket.send_to(&buf[..n], client_addr).await.is_ok() {
+                            guard_clone.add_received(n);
+                            if let Ok(mut last_active) = last_active_clone.lock() {
+                                *last_active = Instant::now();
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        Self {
+            queue,
+            last_active,
+            guard,
+        }
+    }
+
+    async fn connect_upstream(forward_addr: &str) -> io::Result<UdpSocket> {
+        let target = tokio::net::lookup_host(forward_addr)
+            .await?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "无法解析转发目标地址"))?;
+        let bind_addr = if target.is_ipv6() { "[::]:0" } else { "0.0.0.0:0" };
+        let upstream = UdpSocket::bind(bind_addr).await?;
+        upstream.connect(target).await?;
+        Ok(upstream)
+    }
+
+    fn touch(&self) {
+        if let Ok(mut last_active) = self.last_active.lock() {
+            *last_active = Instant::now();
+        }
+    }
+
+    fn is_idle(&self, idle_timeout: Duration) -> bool {
+        self.last_active
+            .lock()
+            .map(|last_active| last_active.elapsed() >= idle_timeout)
+            .unwrap_or(true)
+    }
 }
 
-async fn handle_connection(mut client_stream: TcpStream, forward_addr: &str, stats: ServerStats) -> io::Result<()> {
-    let mut remote_stream = TcpStream::connect(forward_addr).await?;
+async fn handle_connection(mut client_stream: TcpStream, forward_addr: &str, guard: ConnectionGuard) -> io::Result<()> {
+    let connect_result = tokio::select! {
+        _ = guard.cancel_token.cancelled() => {
+            guard.set_close_reason("服务已停止");
+            return Ok(());
+        }
+        result = TcpStream::connect(forward_addr) => result,
+    };
+    let mut remote_stream = match connect_result {
+        Ok(stream) => stream,
+        Err(e) => {
+            guard.set_close_reason(format!("连接目标失败: {}", e));
+            return Err(e);
+        }
+    };
 
     let (mut client_read, mut client_write) = client_stream.split();
     let (mut remote_read, mut remote_write) = remote_stream.split();
+    let guard = &guard;
 
-    let stats_send = stats.clone();
     let client_to_remote = async move {
         let mut buf = vec![0u8; 8192];
         loop {
@@ -131,11 +598,10 @@ async fn handle_connection(mut client_stream: TcpStream, forward_addr: &str, sta
                 return io::Result::Ok(());
             }
             remote_write.write_all(&buf[..n]).await?;
-            stats_send.bytes_sent.fetch_add(n as u64, Ordering::Relaxed);
+            guard.add_sent(n);
         }
     };
 
-    let stats_recv = stats.clone();
     let remote_to_client = async move {
         let mut buf = vec![0u8; 8192];
         loop {
@@ -144,106 +610,362 @@ async fn handle_connection(mut client_stream: TcpStream, forward_addr: &str, sta
                 return io::Result::Ok(());
             }
             client_write.write_all(&buf[..n]).await?;
-            stats_recv.bytes_received.fetch_add(n as u64, Ordering::Relaxed);
+            guard.add_received(n);
         }
     };
 
-    let result = tokio::select! {
-        result = client_to_remote => result,
-        result = remote_to_client => result,
+    let (result, closed_by) = tokio::select! {
+        _ = guard.cancel_token.cancelled() => {
+            // 手动断开时原因已提前写入
+            guard.set_close_reason("服务已停止");
+            return Ok(());
+        }
+        result = client_to_remote => (result, "客户端关闭连接"),
+        result = remote_to_client => (result, "目标关闭连接"),
     };
-    
-    stats.connections.fetch_sub(1, Ordering::Relaxed);
+
+    match &result {
+        Ok(()) => guard.set_close_reason(closed_by),
+        Err(e) => guard.set_close_reason(format!("连接错误: {}", e)),
+    }
     result
 }
 
 // Flutter 友好的 API
 
+/// 端口转发服务器的状态快照，供 Flutter 在热重启后重建界面
+pub struct ForwardServerInfo {
+    pub id: String,
+    pub listen_addr: String,
+    pub forward_addr: String,
+    pub protocol: String,
+    pub is_running: bool,
+    pub connections: usize,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+}
+
 /// 创建并启动一个端口转发服务器
-/// 返回服务器索引，用于后续操作
-pub fn create_forward_server(listen_addr: String, forward_addr: String) -> Result<usize, String> {
+/// 返回服务器句柄，用于后续操作
+pub fn create_forward_server(listen_addr: String, forward_addr: String) -> Result<String, String> {
+    create_forward_server_with_protocol(listen_addr, forward_addr, "tcp".to_string())
+}
+
+/// 创建并启动一个指定协议的端口转发服务器
+/// protocol 取值为 "tcp" 或 "udp"，返回服务器句柄
+pub fn create_forward_server_with_protocol(
+    listen_addr: String,
+    forward_addr: String,
+    protocol: String,
+) -> Result<String, String> {
+    let protocol = ForwardProtocol::parse(&protocol).map_err(|e| e.to_string())?;
+    start_forward_server(ForwardServer::new(listen_addr, forward_addr).with_protocol(protocol))
+}
+
+/// 创建并启动一个 UDP 端口转发服务器，超过 `idle_timeout_secs` 秒没有收发数据的会话会被关闭，
+/// 返回服务器句柄
+pub fn create_udp_forward_server(
+    listen_addr: String,
+    forward_addr: String,
+    idle_timeout_secs: u64,
+) -> Result<String, String> {
+    if idle_timeout_secs == 0 {
+        return Err("UDP 会话空闲超时必须大于 0 秒".to_string());
+    }
+    start_forward_server(
+        ForwardServer::new(listen_addr, forward_addr)
+            .with_protocol(ForwardProtocol::Udp)
+            .with_udp_idle_timeout(Duration::from_secs(idle_timeout_secs)),
+    )
+}
+
+fn start_forward_server(mut server: ForwardServer) -> Result<String, String> {
     RT.block_on(async move {
-        let mut server = ForwardServer::new(listen_addr.clone(), forward_addr.clone());
-        
         match server.start().await {
             Ok(_) => {
-                let mut servers = FORWARD_SERVERS.lock().await;
-                servers.push(server);
-                let index = servers.len() - 1;
-                println!("端口转发服务器已启动: {} -> {}, 索引: {}", listen_addr, forward_addr, index);
-                Ok(index)
+                let info = format!(
+                    "{} -> {} ({:?})",
+                    server.listen_addr, server.forward_addr, server.protocol
+                );
+                let id = FORWARD_SERVERS.insert(server).await;
+                println!("端口转发服务器已启动: {}, 句柄: {}", info, id);
+                Ok(id)
             }
             Err(e) => Err(format!("启动端口转发服务器失败: {}", e))
         }
     })
 }
 
-/// 停止指定索引的端口转发服务器
-pub fn stop_forward_server(index: usize) -> Result<(), String> {
+/// 停止指定的端口转发服务器，记录保留以便查询
+pub fn stop_forward_server(id: String) -> Result<(), String> {
     RT.block_on(async move {
-        let mut servers = FORWARD_SERVERS.lock().await;
-        
-        if index >= servers.len() {
-            return Err(format!("无效的服务器索引: {}", index));
-        }
-        
-        servers[index].stop().await;
-        println!("端口转发服务器已停止，索引: {}", index);
+        FORWARD_SERVERS.stop(&id).await?;
+        println!("端口转发服务器已停止，句柄: {}", id);
         Ok(())
     })
 }
 
-/// 停止所有端口转发服务器
+/// 停止并移除指定的端口转发服务器
+pub fn remove_forward_server(id: String) -> Result<(), String> {
+    RT.block_on(async move {
+        FORWARD_SERVERS.remove(&id).await?;
+        println!("端口转发服务器已移除，句柄: {}", id);
+        Ok(())
+    })
+}
+
+/// 停止并移除所有端口转发服务器
 pub fn stop_all_forward_servers() -> Result<(), String> {
     RT.block_on(async move {
-        let mut servers = FORWARD_SERVERS.lock().await;
-        
-        for (index, server) in servers.iter_mut().enumerate() {
-            server.stop().await;
-            println!("端口转发服务器已停止，索引: {}", index);
-        }
-        
-        servers.clear();
+        for id in FORWARD_SERVERS.remove_all().await {
+            println!("端口转发服务器已停止，句柄: {}", id);
+        }
         println!("所有端口转发服务器已停止");
         Ok(())
     })
 }
 
 /// 获取指定服务器的统计信息
-pub fn get_forward_server_stats(index: usize) -> Result<(usize, u64, u64), String> {
+pub fn get_forward_server_stats(id: String) -> Result<(usize, u64, u64), String> {
     RT.block_on(async move {
-        let servers = FORWARD_SERVERS.lock().await;
-        
-        if index >= servers.len() {
-            return Err(format!("无效的服务器索引: {}", index));
-        }
-        
-        let stats = &servers[index].stats;
-        Ok((
-            stats.get_connections(),
-            stats.get_bytes_sent(),
-            stats.get_bytes_received(),
-        ))
+        FORWARD_SERVERS
+            .with(&id, |server| {
+                (
+                    server.stats.get_connections(),
+                    server.stats.get_bytes_sent(),
+                    server.stats.get_bytes_received(),
+                )
+            })
+            .await
     })
 }
 
-/// 获取所有正在运行的服务器数量
-pub fn get_forward_server_count() -> usize {
+/// 列出所有端口转发服务器及其状态
+pub fn list_forward_servers() -> Vec<ForwardServerInfo> {
     RT.block_on(async move {
-        let servers = FORWARD_SERVERS.lock().await;
-        servers.len()
+        FORWARD_SERVERS
+            .lock()
+            .await
+            .iter()
+            .map(|entry| entry.value.info(&entry.id))
+            .collect()
     })
 }
 
-/// 检查指定服务器是否正在运行
-pub fn is_forward_server_running(index: usize) -> bool {
+/// 列出指定服务器的活跃连接和最近关闭的连接
+pub fn list_forward_connections(id: String) -> Result<Vec<ForwardConnectionInfo>, String> {
     RT.block_on(async move {
-        let servers = FORWARD_SERVERS.lock().await;
-        
-        if index >= servers.len() {
-            return false;
+        FORWARD_SERVERS
+            .with(&id, |server| server.stats.list_connections())
+            .await
+    })
+}
+
+/// 强制断开指定服务器上的一条连接
+pub fn kill_forward_connection(id: String, connection_id: u64) -> Result<(), String> {
+    RT.block_on(async move {
+        let killed = FORWARD_SERVERS
+            .with(&id, |server| server.stats.kill_connection(connection_id))
+            .await?;
+        if !killed {
+            return Err(format!("连接不存在: {}", connection_id));
         }
-        
-        servers[index].is_running()
+        println!("已断开连接 {}，服务器句柄: {}", connection_id, id);
+        Ok(())
+    })
+}
+
+/// 获取已注册的服务器数量
+pub fn get_forward_server_count() -> usize {
+    RT.block_on(async move { FORWARD_SERVERS.len().await })
+}
+
+/// 检查指定服务器是否正在运行
+pub fn is_forward_server_running(id: String) -> bool {
+    RT.block_on(async move {
+        FORWARD_SERVERS
+            .with(&id, |server| server.is_running())
+            .await
+            .unwrap_or(false)
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 把收到的数据原样发回的 UDP 服务，返回其地址
+    async fn udp_echo() -> SocketAddr {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = socket.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0u8; UDP_BUFFER_SIZE];
+            while let Ok((n, peer)) = socket.recv_from(&mut buf).await {
+                let _ = socket.send_to(&buf[..n], peer).await;
+            }
+        });
+        addr
+    }
+
+    /// 一个当前没有监听者的 UDP 端口
+    fn closed_udp_port() -> SocketAddr {
+        std::net::UdpSocket::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+    }
+
+    async fn start_udp(forward_addr: SocketAddr, idle_timeout: Duration) -> ForwardServer {
+        let mut server = ForwardServer::new("127.0.0.1:0", forward_addr.to_string())
+            .with_protocol(ForwardProtocol::Udp)
+            .with_udp_idle_timeout(idle_timeout);
+        server.start().await.unwrap();
+        server
+    }
+
+    async fn exchange(client: &UdpSocket, server: &ForwardServer, data: &[u8]) -> Vec<u8> {
+        client
+            .send_to(data, server.local_addr().unwrap())
+            .await
+            .unwrap();
+        let mut buf = vec![0u8; UDP_BUFFER_SIZE];
+        let n = tokio::time::timeout(Duration::from_secs(2), client.recv(&mut buf))
+            .await
+            .expect("no reply")
+            .unwrap();
+        buf.truncate(n);
+        buf
+    }
+
+    async fn wait_until(mut condition: impl FnMut() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while !condition() {
+            assert!(Instant::now() < deadline, "condition not met in time");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn udp_round_trip_is_tracked_per_client() {
+        let mut server = start_udp(udp_echo().await, DEFAULT_UDP_IDLE_TIMEOUT).await;
+        let first = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let second = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        assert_eq!(exchange(&first, &server, b"ping").await, b"ping");
+        assert_eq!(exchange(&first, &server, b"again").await, b"again");
+        assert_eq!(exchange(&second, &server, b"hello").await, b"hello");
+
+        assert_eq!(server.stats.get_connections(), 2);
+        assert_eq!(server.stats.get_bytes_sent(), 14);
+        assert_eq!(server.stats.get_bytes_received(), 14);
+
+        let connections = server.stats.list_connections();
+        assert_eq!(connections.len(), 2);
+        let first_addr = first.local_addr().unwrap().to_string();
+        let first_conn = connections
+            .iter()
+            .find(|conn| conn.peer_addr == first_addr)
+            .unwrap();
+        assert_eq!((first_conn.bytes_sent, first_conn.bytes_received), (9, 9));
+        assert_eq!(first_conn.closed_at, None);
+
+        server.stop().await;
+        wait_until(|| server.stats.get_connections() == 0).await;
+        let connections = server.stats.list_connections();
+        assert!(connections
+            .iter()
+            .all(|conn| conn.close_reason.as_deref() == Some("服务已停止")));
+    }
+
+    #[tokio::test]
+    async fn idle_udp_sessions_are_closed() {
+        let mut server = start_udp(udp_echo().await, Duration::from_millis(50)).await;
+        let client = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        exchange(&client, &server, b"ping").await;
+
+        wait_until(|| server.stats.get_connections() == 0).await;
+        let connections = server.stats.list_connections();
+        assert_eq!(connections.len(), 1);
+        assert_eq!(connections[0].close_reason.as_deref(), Some("空闲超时"));
+        assert!(connections[0].closed_at.is_some());
+        server.stop().await;
+    }
+
+    #[tokio::test]
+    async fn killed_udp_session_reopens_on_next_datagram() {
+        let mut server = start_udp(udp_echo().await, DEFAULT_UDP_IDLE_TIMEOUT).await;
+        let client = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        exchange(&client, &server, b"ping").await;
+
+        let id = server.stats.list_connections()[0].id;
+        assert!(server.stats.kill_connection(id));
+        assert!(!server.stats.kill_connection(id + 100));
+        wait_until(|| server.stats.get_connections() == 0).await;
+
+        assert_eq!(exchange(&client, &server, b"back").await, b"back");
+        let connections = server.stats.list_connections();
+        assert_eq!(connections.len(), 2);
+        assert_ne!(connections[0].id, id);
+        assert_eq!(connections[0].closed_at, None);
+        assert_eq!(connections[1].close_reason.as_deref(), Some("已被手动断开"));
+        server.stop().await;
+    }
+
+    // 发往已关闭端口的数据报会让已连接的 UDP 套接字收到 ConnectionRefused
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn unreachable_upstream_closes_session() {
+        let mut server = start_udp(closed_udp_port(), DEFAULT_UDP_IDLE_TIMEOUT).await;
+        let client = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        client
+            .send_to(b"ping", server.local_addr().unwrap())
+            .await
+            .unwrap();
+
+        wait_until(|| {
+            server
+                .stats
+                .list_connections()
+                .first()
+                .is_some_and(|conn| conn.closed_at.is_some())
+        })
+        .await;
+        let reason = server.stats.list_connections()[0].close_reason.clone();
+        assert!(reason.unwrap().starts_with("接收目标数据失败"));
+        assert!(server.is_running());
+        server.stop().await;
+    }
+
+    #[tokio::test]
+    async fn tcp_connection_close_is_recorded() {
+        let upstream = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let upstream_addr = upstream.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = upstream.accept().await.unwrap();
+            let mut buf = [0u8; 4];
+            stream.read_exact(&mut buf).await.unwrap();
+            stream.write_all(&buf).await.unwrap();
+        });
+
+        let mut server = ForwardServer::new("127.0.0.1:0", upstream_addr.to_string());
+        server.start().await.unwrap();
+        let mut client = TcpStream::connect(server.local_addr().unwrap())
+            .await
+            .unwrap();
+        client.write_all(b"ping").await.unwrap();
+        let mut buf = [0u8; 4];
+        client.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"ping");
+        drop(client);
+
+        wait_until(|| server.stats.get_connections() == 0).await;
+        let connections = server.stats.list_connections();
+        assert_eq!(connections.len(), 1);
+        assert_eq!(
+            (connections[0].bytes_sent, connections[0].bytes_received),
+            (4, 4)
+        );
+        assert!(connections[0].close_reason.is_some());
+        server.stop().await;
+    }
+}
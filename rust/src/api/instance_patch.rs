@@ -0,0 +1,585 @@
+//! Live reconfiguration of running instances.
+//!
+//! Changes go through EasyTier's config RPC, the same path its web console
+//! uses, so peers stay connected. Once EasyTier has applied a patch it emits
+//! `ConfigPatched` (and `ProxyCidrsUpdated` for proxy CIDR changes), which are
+//! forwarded to `subscribe_instance_events` subscribers as confirmation.
+//! Whitelist changes are the exception: they need a restart of the instance,
+//! after which this module emits the `ConfigPatched` confirmation itself.
+
+use easytier::common::config::{ConfigLoader, PeerConfig, PortForwardConfig, TomlConfigLoader};
+use easytier::proto::api::config::{
+    ConfigPatchAction, ConfigRpc, InstanceConfigPatch, PatchConfigRequest, PortForwardPatch,
+    ProxyNetworkPatch, UrlPatch,
+};
+use easytier::proto::rpc_types::controller::BaseController;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::network_config::{
+    issues_to_string, parse_forward, parse_port_list, ConfigIssue, Forward, Issues,
+};
+use super::p2p::{self, ConfigChange, InstanceEvent};
+
+/// What to do with a patch entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PatchAction {
+    Add,
+    Remove,
+}
+
+impl From<PatchAction> for ConfigPatchAction {
+    fn from(action: PatchAction) -> Self {
+        match action {
+            PatchAction::Add => ConfigPatchAction::Add,
+            PatchAction::Remove => ConfigPatchAction::Remove,
+        }
+    }
+}
+
+impl PatchAction {
+    fn from_proto(action: i32) -> Option<Self> {
+        if action == ConfigPatchAction::Add as i32 {
+            Some(Self::Add)
+        } else if action == ConfigPatchAction::Remove as i32 {
+            Some(Self::Remove)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct ValuePatch {
+    pub action: PatchAction,
+    pub value: String,
+}
+
+pub struct ForwardPatch {
+    pub action: PatchAction,
+    pub forward: Forward,
+}
+
+/// Changes to apply to a running instance. Entries are applied in order,
+/// and removing an entry that isn't configured is not an error.
+#[derive(Default)]
+pub struct InstancePatch {
+    /// Server/peer URLs, e.g. `tcp://public.easytier.top:11010`.
+    pub peers: Vec<ValuePatch>,
+    pub port_forwards: Vec<ForwardPatch>,
+    /// Proxy CIDRs, e.g. `192.168.1.0/24`.
+    pub proxy_cidrs: Vec<ValuePatch>,
+    /// Ports or port ranges as in `FlagsC::tcp_whitelist`. EasyTier can't
+    /// change them live, so a change restarts the instance, see `patch_instance`.
+    pub tcp_whitelist: Vec<ValuePatch>,
+    pub udp_whitelist: Vec<ValuePatch>,
+}
+
+/// A validated `InstancePatch`.
+struct ParsedPatch {
+    peers: Vec<(PatchAction, url::Url)>,
+    port_forwards: Vec<(PatchAction, PortForwardConfig)>,
+    proxy_cidrs: Vec<(PatchAction, cidr::Ipv4Cidr)>,
+    tcp_whitelist: Vec<(PatchAction, String)>,
+    udp_whitelist: Vec<(PatchAction, String)>,
+}
+
+/// Parses every entry of the patch, collecting every invalid one.
+fn parse_patch(patch: &InstancePatch) -> Result<ParsedPatch, Vec<ConfigIssue>> {
+    let mut issues = Issues::default();
+    let mut parsed = ParsedPatch {
+        peers: Vec::new(),
+        port_forwards: Vec::new(),
+        proxy_cidrs: Vec::new(),
+        tcp_whitelist: Vec::new(),
+        udp_whitelist: Vec::new(),
+    };
+
+    for (i, p) in patch.peers.iter().enumerate() {
+        match p.value.trim().parse::<url::Url>() {
+            Ok(url) => parsed.peers.push((p.action, url)),
+            Err(e) => issues.push(format!("peers[{}]", i), &p.value, e),
+        }
+    }
+
+    for (i, p) in patch.port_forwards.iter().enumerate() {
+        let field = format!("port_forwards[{}]", i);
+        if let Some(cfg) = parse_forward(&field, &p.forward, &mut issues) {
+            parsed.port_forwards.push((p.action, cfg));
+        }
+    }
+
+    for (i, p) in patch.proxy_cidrs.iter().enumerate() {
+        match p.value.trim().parse::<cidr::Ipv4Cidr>() {
+            Ok(cidr) => parsed.proxy_cidrs.push((p.action, cidr)),
+            Err(e) => issues.push(format!("proxy_cidrs[{}]", i), &p.value, e),
+        }
+    }
+
+    for (field, entries, out) in [
+        (
+            "tcp_whitelist",
+            &patch.tcp_whitelist,
+            &mut parsed.tcp_whitelist,
+        ),
+        (
+            "udp_whitelist",
+            &patch.udp_whitelist,
+            &mut parsed.udp_whitelist,
+        ),
+    ] {
+        for (i, p) in entries.iter().enumerate() {
+            let field = format!("{}[{}]", field, i);
+            for port in parse_port_list(&field, &p.value, &mut issues) {
+                out.push((p.action, port));
+            }
+        }
+    }
+
+    issues.into_result(parsed)
+}
+
+impl ParsedPatch {
+    /// Whether the patch has entries EasyTier can apply without a restart.
+    fn has_live_changes(&self) -> bool {
+        !self.peers.is_empty() || !self.port_forwards.is_empty() || !self.proxy_cidrs.is_empty()
+    }
+
+    /// Converts the live-patchable part into EasyTier's form.
+    fn to_config_patch(&self) -> InstanceConfigPatch {
+        let mut config_patch = InstanceConfigPatch::default();
+        for (action, url) in &self.peers {
+            config_patch.connectors.push(UrlPatch {
+                action: ConfigPatchAction::from(*action) as i32,
+                url: Some(url.clone().into()),
+            });
+        }
+        for (action, cfg) in &self.port_forwards {
+            config_patch.port_forwards.push(PortForwardPatch {
+                action: ConfigPatchAction::from(*action) as i32,
+                cfg: Some(cfg.clone().into()),
+            });
+        }
+        for (action, cidr) in &self.proxy_cidrs {
+            config_patch.proxy_networks.push(ProxyNetworkPatch {
+                action: ConfigPatchAction::from(*action) as i32,
+                cidr: Some((*cidr).into()),
+                mapped_cidr: None,
+            });
+        }
+        config_patch
+    }
+
+    /// Applies the live-patchable part to an instance config, so restarts
+    /// from it keep the change.
+    fn apply_live_to(&self, cfg: &TomlConfigLoader) {
+        let mut peers = cfg.get_peers();
+        for (action, url) in &self.peers {
+            peers.retain(|peer| peer.uri != *url);
+            if *action == PatchAction::Add {
+                peers.push(PeerConfig {
+                    uri: url.clone(),
+                    peer_public_key: None,
+                });
+            }
+        }
+        cfg.set_peers(peers);
+
+        let mut port_forwards = cfg.get_port_forwards();
+        for (action, forward) in &self.port_forwards {
+            port_forwards.retain(|f| {
+                (f.bind_addr, f.dst_addr, &f.proto)
+                    != (forward.bind_addr, forward.dst_addr, &forward.proto)
+            });
+            if *action == PatchAction::Add {
+                port_forwards.push(forward.clone());
+            }
+        }
+        cfg.set_port_forwards(port_forwards);
+
+        for (action, cidr) in &self.proxy_cidrs {
+            match action {
+                PatchAction::Add => {
+                    if let Err(e) = cfg.add_proxy_cidr(*cidr, None) {
+                        println!("add proxy cidr {} to config failed: {}", cidr, e);
+                    }
+                }
+                PatchAction::Remove => cfg.remove_proxy_cidr(*cidr),
+            }
+        }
+    }
+
+    /// EasyTier compiles the whitelists into ACL rules when the instance
+    /// starts and has no patch for them, so a `true` result means the instance
+    /// must be restarted for the change to take effect.
+    fn apply_whitelists_to(&self, cfg: &TomlConfigLoader) -> bool {
+        let mut changed = false;
+        let tcp_whitelist = cfg.get_tcp_whitelist();
+        let patched = patch_list(tcp_whitelist.clone(), &self.tcp_whitelist);
+        if patched != tcp_whitelist {
+            cfg.set_tcp_whitelist(patched);
+            changed = true;
+        }
+        let udp_whitelist = cfg.get_udp_whitelist();
+        let patched = patch_list(udp_whitelist.clone(), &self.udp_whitelist);
+        if patched != udp_whitelist {
+            cfg.set_udp_whitelist(patched);
+            changed = true;
+        }
+        changed
+    }
+
+    /// Lists the whitelist entries, for the `ConfigPatched` sent after a restart.
+    fn whitelist_changes(&self) -> Vec<ConfigChange> {
+        let mut changes = Vec::new();
+        for (field, entries) in [
+            ("tcp_whitelist", &self.tcp_whitelist),
+            ("udp_whitelist", &self.udp_whitelist),
+        ] {
+            for (action, value) in entries {
+                changes.push(ConfigChange {
+                    field: field.to_string(),
+                    action: Some(*action),
+                    value: value.clone(),
+                });
+            }
+        }
+        changes
+    }
+}
+
+fn patch_list(mut list: Vec<String>, entries: &[(PatchAction, String)]) -> Vec<String> {
+    for (action, value) in entries {
+        list.retain(|v| v != value);
+        if *action == PatchAction::Add {
+            list.push(value.clone());
+        }
+    }
+    list
+}
+
+/// Lists the entries of a patch EasyTier applied, for `ConfigPatched`.
+pub(crate) fn config_changes(patch: &InstanceConfigPatch) -> Vec<ConfigChange> {
+    let mut changes = Vec::new();
+    if let Some(hostname) = &patch.hostname {
+        changes.push(ConfigChange {
+            field: "hostname".to_string(),
+            action: None,
+            value: hostname.clone(),
+        });
+    }
+    if let Some(ipv4) = patch.ipv4.as_ref().and_then(p2p::format_ipv4_inet) {
+        changes.push(ConfigChange {
+            field: "ipv4".to_string(),
+            action: None,
+            value: ipv4,
+        });
+    }
+    for p in &patch.connectors {
+        changes.push(ConfigChange {
+            field: "peers".to_string(),
+            action: PatchAction::from_proto(p.action),
+            value: p.url.as_ref().map(|u| u.url.clone()).unwrap_or_default(),
+        });
+    }
+    for p in &patch.port_forwards {
+        let value = p
+            .cfg
+            .clone()
+            .map(|cfg| {
+                let cfg: PortForwardConfig = cfg.into();
+                format!("{} {} -> {}", cfg.proto, cfg.bind_addr, cfg.dst_addr)
+            })
+            .unwrap_or_default();
+        changes.push(ConfigChange {
+            field: "port_forwards".to_string(),
+            action: PatchAction::from_proto(p.action),
+            value,
+        });
+    }
+    for p in &patch.proxy_networks {
+        let value = p
+            .cidr
+            .as_ref()
+            .and_then(|cidr| {
+                let addr = cidr.address.as_ref()?;
+                Some(format!(
+                    "{}/{}",
+                    p2p::format_ipv4(addr),
+                    cidr.network_length
+                ))
+            })
+            .unwrap_or_default();
+        changes.push(ConfigChange {
+            field: "proxy_cidrs".to_string(),
+            action: PatchAction::from_proto(p.action),
+            value,
+        });
+    }
+    changes
+}
+
+/// Applies `patch` to a running instance.
+///
+/// Nothing is applied if any entry is invalid. Peers, port forwards and proxy
+/// CIDRs are patched live first; success means EasyTier accepted them, and the
+/// matching `ConfigPatched` event confirms they took effect. Whitelist entries
+/// can't be patched live: when they change a whitelist, the instance is then
+/// restarted under the same id from its updated config, which drops its
+/// connections for a moment, and a `ConfigPatched` listing the whitelist
+/// entries follows the restart.
+pub async fn patch_instance(instance_id: String, patch: InstancePatch) -> Result<(), String> {
+    let id = p2p::parse_instance_id(&instance_id)?;
+    let parsed = parse_patch(&patch).map_err(|issues| issues_to_string(&issues))?;
+
+    if parsed.has_live_changes() {
+        patch_live(&id, &parsed).await?;
+    }
+
+    if p2p::with_instance_config(&id, |cfg| parsed.apply_whitelists_to(cfg))? {
+        p2p::restart_instance(&id)?;
+        p2p::publish_event(
+            &id.to_string(),
+            InstanceEvent::ConfigPatched {
+                changes: parsed.whitelist_changes(),
+            },
+        );
+    }
+    Ok(())
+}
+
+/// Sends the live-patchable part of `parsed` through EasyTier's config RPC.
+async fn patch_live(id: &Uuid, parsed: &ParsedPatch) -> Result<(), String> {
+    let service = p2p::manager()
+        .get_instance_service(id)
+        .ok_or_else(|| "instance not found".to_string())?;
+    service
+        .get_config_service()
+        .patch_config(
+            BaseController::default(),
+            PatchConfigRequest {
+                instance: None,
+                patch: Some(parsed.to_config_patch()),
+            },
+        )
+        .await
+        .map_err(|e| format!("patch instance failed: {}", e))?;
+    // keep the cached config in step, later restarts start from it
+    p2p::with_instance_config(id, |cfg| parsed.apply_live_to(cfg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(action: PatchAction, value: &str) -> ValuePatch {
+        ValuePatch {
+            action,
+            value: value.to_string(),
+        }
+    }
+
+    fn forward(action: PatchAction, bind_addr: &str, dst_addr: &str, proto: &str) -> ForwardPatch {
+        ForwardPatch {
+            action,
+            forward: Forward {
+                bind_addr: bind_addr.to_string(),
+                dst_addr: dst_addr.to_string(),
+                proto: proto.to_string(),
+            },
+        }
+    }
+
+    fn sample_patch() -> InstancePatch {
+        InstancePatch {
+            peers: vec![
+                value(PatchAction::Add, "tcp://1.2.3.4:11010"),
+                value(PatchAction::Remove, "udp://5.6.7.8:11010"),
+            ],
+            port_forwards: vec![forward(
+                PatchAction::Add,
+                "0.0.0.0:25565",
+                "10.126.126.1:25565",
+                "TCP",
+            )],
+            proxy_cidrs: vec![value(PatchAction::Add, "192.168.1.0/24")],
+            tcp_whitelist: vec![value(PatchAction::Add, "80, 8000-9000")],
+            udp_whitelist: vec![value(PatchAction::Remove, "25565")],
+        }
+    }
+
+    #[test]
+    fn parse_patch_accepts_valid_entries() {
+        let parsed = parse_patch(&sample_patch()).unwrap();
+        assert!(parsed.has_live_changes());
+        assert_eq!(parsed.peers.len(), 2);
+        assert_eq!(parsed.peers[0].1.as_str(), "tcp://1.2.3.4:11010");
+        assert_eq!(parsed.port_forwards[0].1.proto, "tcp");
+        assert_eq!(parsed.proxy_cidrs[0].1.to_string(), "192.168.1.0/24");
+        // a list entry is split into one entry per port or range
+        assert_eq!(
+            parsed.tcp_whitelist,
+            [
+                (PatchAction::Add, "80".to_string()),
+                (PatchAction::Add, "8000-9000".to_string()),
+            ]
+        );
+        assert_eq!(
+            parsed.udp_whitelist,
+            [(PatchAction::Remove, "25565".to_string())]
+        );
+
+        let whitelist_only = InstancePatch {
+            tcp_whitelist: vec![value(PatchAction::Add, "80")],
+            ..Default::default()
+        };
+        assert!(!parse_patch(&whitelist_only).unwrap().has_live_changes());
+    }
+
+    #[test]
+    fn parse_patch_reports_every_invalid_entry() {
+        let patch = InstancePatch {
+            peers: vec![value(PatchAction::Add, "not a url")],
+            port_forwards: vec![forward(PatchAction::Add, "0.0.0.0:1", "host", "icmp")],
+            proxy_cidrs: vec![
+                value(PatchAction::Add, "192.168.1.0/24"),
+                value(PatchAction::Remove, "300.0.0.0/8"),
+            ],
+            tcp_whitelist: vec![value(PatchAction::Add, "80,x")],
+            udp_whitelist: vec![value(PatchAction::Add, "9000-8000")],
+        };
+        let issues = parse_patch(&patch).err().unwrap();
+        let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "peers[0]",
+                "port_forwards[0].dst_addr",
+                "port_forwards[0].proto",
+                "proxy_cidrs[1]",
+                "tcp_whitelist[0]",
+                "udp_whitelist[0]",
+            ]
+        );
+        assert_eq!(issues[4].value, "x");
+    }
+
+    #[test]
+    fn patch_list_applies_entries_in_order() {
+        let list = vec!["80".to_string(), "443".to_string()];
+        let entries = [
+            (PatchAction::Add, "8080".to_string()),
+            (PatchAction::Add, "80".to_string()),
+            (PatchAction::Remove, "443".to_string()),
+            (PatchAction::Remove, "22".to_string()),
+            (PatchAction::Add, "22".to_string()),
+        ];
+        // adding an existing entry moves it to the end instead of duplicating it
+        assert_eq!(patch_list(list.clone(), &entries), ["8080", "80", "22"]);
+        assert_eq!(patch_list(list.clone(), &[]), list);
+    }
+
+    #[test]
+    fn apply_live_to_updates_config() {
+        let cfg = TomlConfigLoader::default();
+        cfg.set_peers(vec![PeerConfig {
+            uri: "udp://5.6.7.8:11010".parse().unwrap(),
+            peer_public_key: None,
+        }]);
+
+        let parsed = parse_patch(&sample_patch()).unwrap();
+        parsed.apply_live_to(&cfg);
+        // applying twice leaves no duplicates
+        parsed.apply_live_to(&cfg);
+
+        let peers: Vec<String> = cfg.get_peers().iter().map(|p| p.uri.to_string()).collect();
+        assert_eq!(peers, ["tcp://1.2.3.4:11010"]);
+        let forwards = cfg.get_port_forwards();
+        assert_eq!(forwards.len(), 1);
+        assert_eq!(forwards[0].dst_addr.to_string(), "10.126.126.1:25565");
+        let cidrs: Vec<String> = cfg
+            .get_proxy_cidrs()
+            .iter()
+            .map(|proxy| proxy.cidr.to_string())
+            .collect();
+        assert_eq!(cidrs, ["192.168.1.0/24"]);
+        assert!(cfg.get_tcp_whitelist().is_empty());
+
+        let removal = InstancePatch {
+            port_forwards: vec![forward(
+                PatchAction::Remove,
+                "0.0.0.0:25565",
+                "10.126.126.1:25565",
+                "tcp",
+            )],
+            proxy_cidrs: vec![value(PatchAction::Remove, "192.168.1.0/24")],
+            ..Default::default()
+        };
+        parse_patch(&removal).unwrap().apply_live_to(&cfg);
+        assert!(cfg.get_port_forwards().is_empty());
+        assert!(cfg.get_proxy_cidrs().is_empty());
+    }
+
+    #[test]
+    fn apply_whitelists_to_reports_changes() {
+        let cfg = TomlConfigLoader::default();
+        cfg.set_udp_whitelist(vec!["25565".to_string()]);
+
+        let parsed = parse_patch(&sample_patch()).unwrap();
+        assert!(parsed.apply_whitelists_to(&cfg));
+        assert_eq!(cfg.get_tcp_whitelist(), ["80", "8000-9000"]);
+        assert!(cfg.get_udp_whitelist().is_empty());
+        assert!(cfg.get_peers().is_empty());
+
+        // nothing left to change, so no restart is needed
+        assert!(!parsed.apply_whitelists_to(&cfg));
+        let unrelated = InstancePatch {
+            peers: vec![value(PatchAction::Add, "tcp://1.2.3.4:11010")],
+            ..Default::default()
+        };
+        assert!(!parse_patch(&unrelated).unwrap().apply_whitelists_to(&cfg));
+    }
+
+    #[test]
+    fn whitelist_changes_list_each_entry() {
+        let changes = parse_patch(&sample_patch()).unwrap().whitelist_changes();
+        let changes: Vec<(&str, Option<PatchAction>, &str)> = changes
+            .iter()
+            .map(|c| (c.field.as_str(), c.action, c.value.as_str()))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("tcp_whitelist", Some(PatchAction::Add), "80"),
+                ("tcp_whitelist", Some(PatchAction::Add), "8000-9000"),
+                ("udp_whitelist", Some(PatchAction::Remove), "25565"),
+            ]
+        );
+    }
+
+    #[test]
+    fn config_changes_describe_patch() {
+        let mut patch = parse_patch(&sample_patch()).unwrap().to_config_patch();
+        patch.hostname = Some("alice".to_string());
+
+        let changes = config_changes(&patch);
+        let changes: Vec<(&str, Option<PatchAction>, &str)> = changes
+            .iter()
+            .map(|c| (c.field.as_str(), c.action, c.value.as_str()))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("hostname", None, "alice"),
+                ("peers", Some(PatchAction::Add), "tcp://1.2.3.4:11010"),
+                ("peers", Some(PatchAction::Remove), "udp://5.6.7.8:11010"),
+                (
+                    "port_forwards",
+                    Some(PatchAction::Add),
+                    "tcp 0.0.0.0:25565 -> 10.126.126.1:25565"
+                ),
+                ("proxy_cidrs", Some(PatchAction::Add), "192.168.1.0/24"),
+            ]
+        );
+        assert!(config_changes(&InstanceConfigPatch::default()).is_empty());
+    }
+}
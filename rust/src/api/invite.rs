@@ -0,0 +1,423 @@
+//! Shareable room invite links.
+//!
+//! An invite is encoded as `astral://join/<payload>[.<signature>]`. The payload is
+//! the invite as compact JSON, deflate-compressed and base64url encoded. The
+//! optional signature is an HMAC-SHA256 over the payload, keyed by a secret
+//! shared out of band (e.g. by a community or server operator), so a modified
+//! invite is rejected by clients that know the secret. Anyone can strip the
+//! signature off a link, so clients must look at `RoomInvite::verified` rather
+//! than at whether decoding succeeded.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::io::{Read, Write};
+use std::net::Ipv4Addr;
+
+use super::simple::FlagsC;
+
+const INVITE_PREFIX: &str = "astral://join/";
+const INVITE_VERSION: u8 = 1;
+/// Upper bound for the decompressed payload, so a crafted link can't balloon in memory.
+const MAX_PAYLOAD_BYTES: u64 = 64 * 1024;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The subset of `FlagsC` carried by an invite. Unset options keep the
+/// joiner's own setting.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InviteFlags {
+    #[serde(rename = "dp", default, skip_serializing_if = "Option::is_none")]
+    pub default_protocol: Option<String>,
+    #[serde(rename = "enc", default, skip_serializing_if = "Option::is_none")]
+    pub enable_encryption: Option<bool>,
+    #[serde(rename = "v6", default, skip_serializing_if = "Option::is_none")]
+    pub enable_ipv6: Option<bool>,
+    #[serde(rename = "mtu", default, skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u32>,
+    #[serde(rename = "lf", default, skip_serializing_if = "Option::is_none")]
+    pub latency_first: Option<bool>,
+    #[serde(rename = "np2p", default, skip_serializing_if = "Option::is_none")]
+    pub disable_p2p: Option<bool>,
+    #[serde(rename = "bcast", default, skip_serializing_if = "Option::is_none")]
+    pub enable_udp_broadcast_relay: Option<bool>,
+    #[serde(rename = "zip", default, skip_serializing_if = "Option::is_none")]
+    pub data_compress_algo: Option<i32>,
+    #[serde(rename = "kcp", default, skip_serializing_if = "Option::is_none")]
+    pub enable_kcp_proxy: Option<bool>,
+    #[serde(rename = "quic", default, skip_serializing_if = "Option::is_none")]
+    pub enable_quic_proxy: Option<bool>,
+}
+
+/// A room definition that can be shared as a link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomInvite {
+    #[serde(rename = "n")]
+    pub room_name: String,
+    #[serde(rename = "p", default)]
+    pub room_password: String,
+    #[serde(rename = "s", default)]
+    pub servers: Vec<String>,
+    /// Fixed virtual IPv4 range in CIDR notation; `None` means DHCP.
+    #[serde(rename = "r", default, skip_serializing_if = "Option::is_none")]
+    pub ip_range: Option<String>,
+    #[serde(rename = "f", default)]
+    pub flags: InviteFlags,
+    /// Unix timestamp in seconds after which the invite is rejected.
+    #[serde(rename = "e", default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+    /// Whether the link carried a signature that matched the secret. Only set by
+    /// `decode_room_invite`; never part of the encoded link.
+    #[serde(skip)]
+    pub verified: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct InvitePayload {
+    v: u8,
+    #[serde(flatten)]
+    invite: RoomInvite,
+}
+
+fn sign(secret: &str, payload: &str) -> HmacSha256 {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("hmac accepts keys of any length");
+    mac.update(payload.as_bytes());
+    mac
+}
+
+/// Encodes an invite into an `astral://` link, signed when `secret` is given.
+pub fn encode_room_invite(invite: RoomInvite, secret: Option<String>) -> Result<String, String> {
+    if invite.room_name.is_empty() {
+        return Err("room name must not be empty".to_string());
+    }
+    if invite
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= chrono::Utc::now().timestamp())
+    {
+        return Err("invite expiry must be in the future".to_string());
+    }
+    if let Some(range) = &invite.ip_range {
+        let valid = range.split_once('/').is_some_and(|(addr, prefix)| {
+            addr.parse::<Ipv4Addr>().is_ok() && prefix.parse::<u8>().is_ok_and(|p| p <= 32)
+        });
+        if !valid {
+            return Err(format!(
+                "invalid ip range {:?}, expected a.b.c.d/len",
+                range
+            ));
+        }
+    }
+
+    let json = serde_json::to_vec(&InvitePayload {
+        v: INVITE_VERSION,
+        invite,
+    })
+    .map_err(|e| format!("serialize invite failed: {}", e))?;
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(&json)
+        .map_err(|e| format!("compress invite failed: {}", e))?;
+    let compressed = encoder
+        .finish()
+        .map_err(|e| format!("compress invite failed: {}", e))?;
+    let payload = URL_SAFE_NO_PAD.encode(compressed);
+
+    match secret.filter(|s| !s.is_empty()) {
+        Some(secret) => {
+            let signature = sign(&secret, &payload).finalize().into_bytes();
+            Ok(format!(
+                "{}{}.{}",
+                INVITE_PREFIX,
+                payload,
+                URL_SAFE_NO_PAD.encode(signature)
+            ))
+        }
+        None => Ok(format!("{}{}", INVITE_PREFIX, payload)),
+    }
+}
+
+/// Decodes and checks an invite link.
+///
+/// When `secret` is given the link must carry a valid signature; unsigned or
+/// tampered links are rejected. Without a secret the link decodes with
+/// `verified` set to false, signed or not. Expired invites are always rejected.
+pub fn decode_room_invite(link: String, secret: Option<String>) -> Result<RoomInvite, String> {
+    let body = link
+        .trim()
+        .strip_prefix(INVITE_PREFIX)
+        .ok_or_else(|| format!("not an astral invite link, expected {}...", INVITE_PREFIX))?;
+    let (payload, signature) = match body.split_once('.') {
+        Some((payload, signature)) => (payload, Some(signature)),
+        None => (body, None),
+    };
+
+    let verified = match (secret.filter(|s| !s.is_empty()), signature) {
+        (Some(secret), Some(signature)) => {
+            let signature = URL_SAFE_NO_PAD
+                .decode(signature)
+                .map_err(|_| "invalid invite signature".to_string())?;
+            sign(&secret, payload)
+                .verify_slice(&signature)
+                .map_err(|_| {
+                    "invite signature mismatch, the link may have been modified".to_string()
+                })?;
+            true
+        }
+        (Some(_), None) => return Err("invite is not signed".to_string()),
+        // nothing to check against, the caller decides whether to trust it
+        (None, _) => false,
+    };
+
+    let compressed = URL_SAFE_NO_PAD
+        .decode(payload)
+        .map_err(|e| format!("invalid invite encoding: {}", e))?;
+    let mut json = Vec::new();
+    DeflateDecoder::new(compressed.as_slice())
+        .take(MAX_PAYLOAD_BYTES + 1)
+        .read_to_end(&mut json)
+        .map_err(|e| format!("invalid invite data: {}", e))?;
+    if json.len() as u64 > MAX_PAYLOAD_BYTES {
+        return Err("invite payload too large".to_string());
+    }
+
+    let payload: InvitePayload =
+        serde_json::from_slice(&json).map_err(|e| format!("invalid invite data: {}", e))?;
+    if payload.v != INVITE_VERSION {
+        return Err(format!("unsupported invite version {}", payload.v));
+    }
+    if let Some(expires_at) = payload.invite.expires_at {
+        if chrono::Utc::now().timestamp() > expires_at {
+            return Err("invite has expired".to_string());
+        }
+    }
+    Ok(RoomInvite {
+        verified,
+        ..payload.invite
+    })
+}
+
+/// Picks the options an invite carries from a full set of flags.
+pub fn invite_flags_from(flag: FlagsC) -> InviteFlags {
+    InviteFlags {
+        default_protocol: Some(flag.default_protocol),
+        enable_encryption: Some(flag.enable_encryption),
+        enable_ipv6: Some(flag.enable_ipv6),
+        mtu: Some(flag.mtu),
+        latency_first: Some(flag.latency_first),
+        disable_p2p: Some(flag.disable_p2p),
+        enable_udp_broadcast_relay: Some(flag.enable_udp_broadcast_relay),
+        data_compress_algo: Some(flag.data_compress_algo),
+        enable_kcp_proxy: Some(flag.enable_kcp_proxy),
+        enable_quic_proxy: Some(flag.enable_quic_proxy),
+    }
+}
+
+/// Overlays the options set in an invite on the joiner's own flags.
+pub fn apply_invite_flags(invite_flags: InviteFlags, mut flag: FlagsC) -> FlagsC {
+    if let Some(v) = invite_flags.default_protocol {
+        flag.default_protocol = v;
+    }
+    if let Some(v) = invite_flags.enable_encryption {
+        flag.enable_encryption = v;
+    }
+    if let Some(v) = invite_flags.enable_ipv6 {
+        flag.enable_ipv6 = v;
+    }
+    if let Some(v) = invite_flags.mtu {
+        flag.mtu = v;
+    }
+    if let Some(v) = invite_flags.latency_first {
+        flag.latency_first = v;
+    }
+    if let Some(v) = invite_flags.disable_p2p {
+        flag.disable_p2p = v;
+    }
+    if let Some(v) = invite_flags.enable_udp_broadcast_relay {
+        flag.enable_udp_broadcast_relay = v;
+    }
+    if let Some(v) = invite_flags.data_compress_algo {
+        flag.data_compress_algo = v;
+    }
+    if let Some(v) = invite_flags.enable_kcp_proxy {
+        flag.enable_kcp_proxy = v;
+    }
+    if let Some(v) = invite_flags.enable_quic_proxy {
+        flag.enable_quic_proxy = v;
+    }
+    flag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "community-secret";
+
+    fn sample_invite() -> RoomInvite {
+        RoomInvite {
+            room_name: "room".to_string(),
+            room_password: "password".to_string(),
+            servers: vec!["tcp://public.easytier.top:11010".to_string()],
+            ip_range: Some("10.126.126.0/24".to_string()),
+            flags: InviteFlags {
+                default_protocol: Some("udp".to_string()),
+                mtu: Some(1360),
+                ..Default::default()
+            },
+            expires_at: Some(chrono::Utc::now().timestamp() + 3600),
+            verified: false,
+        }
+    }
+
+    /// Builds a link around arbitrary JSON, skipping the checks of `encode_room_invite`.
+    fn raw_link(json: &[u8]) -> String {
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(json).unwrap();
+        let payload = URL_SAFE_NO_PAD.encode(encoder.finish().unwrap());
+        format!("{}{}", INVITE_PREFIX, payload)
+    }
+
+    fn payload_of(link: &str) -> &str {
+        let body = link.strip_prefix(INVITE_PREFIX).unwrap();
+        body.split('.').next().unwrap()
+    }
+
+    #[test]
+    fn signed_invite_round_trip() {
+        let invite = sample_invite();
+        let link = encode_room_invite(invite.clone(), Some(SECRET.to_string())).unwrap();
+        assert!(link.starts_with(INVITE_PREFIX));
+
+        let back = decode_room_invite(link.clone(), Some(SECRET.to_string())).unwrap();
+        assert!(back.verified);
+        assert_eq!(back.room_name, invite.room_name);
+        assert_eq!(back.room_password, invite.room_password);
+        assert_eq!(back.servers, invite.servers);
+        assert_eq!(back.ip_range, invite.ip_range);
+        assert_eq!(back.expires_at, invite.expires_at);
+        assert_eq!(back.flags.default_protocol.as_deref(), Some("udp"));
+        assert_eq!(back.flags.mtu, Some(1360));
+        assert_eq!(back.flags.enable_encryption, None);
+
+        // without the secret the link still decodes, but unverified
+        let back = decode_room_invite(link, None).unwrap();
+        assert!(!back.verified);
+        assert_eq!(back.room_name, invite.room_name);
+    }
+
+    #[test]
+    fn unsigned_invite_round_trip() {
+        let link = encode_room_invite(sample_invite(), None).unwrap();
+        assert!(!payload_of(&link).is_empty());
+        assert!(!link.contains('.'));
+
+        let back = decode_room_invite(link.clone(), None).unwrap();
+        assert!(!back.verified);
+        assert_eq!(back.room_name, "room");
+        assert_eq!(
+            decode_room_invite(link, Some(SECRET.to_string())).unwrap_err(),
+            "invite is not signed"
+        );
+    }
+
+    #[test]
+    fn tampered_invite_is_not_verified() {
+        let link = encode_room_invite(sample_invite(), Some(SECRET.to_string())).unwrap();
+        let signature = link.rsplit('.').next().unwrap();
+
+        let mut other = sample_invite();
+        other.room_password = "changed".to_string();
+        let other = encode_room_invite(other, None).unwrap();
+        let tampered = format!("{}{}.{}", INVITE_PREFIX, payload_of(&other), signature);
+
+        let err = decode_room_invite(tampered.clone(), Some(SECRET.to_string())).unwrap_err();
+        assert!(err.contains("signature mismatch"));
+        let err = decode_room_invite(link.clone(), Some("other-secret".to_string())).unwrap_err();
+        assert!(err.contains("signature mismatch"));
+
+        let back = decode_room_invite(tampered, None).unwrap();
+        assert!(!back.verified);
+        assert_eq!(back.room_password, "changed");
+
+        // a stripped signature decodes, unverified
+        let stripped = format!("{}{}", INVITE_PREFIX, payload_of(&link));
+        assert!(!decode_room_invite(stripped, None).unwrap().verified);
+    }
+
+    #[test]
+    fn expired_invite_is_rejected() {
+        let mut invite = sample_invite();
+        invite.expires_at = Some(chrono::Utc::now().timestamp() - 1);
+        assert!(encode_room_invite(invite, None).is_err());
+
+        let json = format!(
+            r#"{{"v":{},"n":"room","e":{}}}"#,
+            INVITE_VERSION,
+            chrono::Utc::now().timestamp() - 60
+        );
+        assert_eq!(
+            decode_room_invite(raw_link(json.as_bytes()), None).unwrap_err(),
+            "invite has expired"
+        );
+
+        let mut invite = sample_invite();
+        invite.expires_at = None;
+        let link = encode_room_invite(invite, None).unwrap();
+        assert_eq!(decode_room_invite(link, None).unwrap().expires_at, None);
+    }
+
+    #[test]
+    fn malformed_links_are_rejected() {
+        let link = encode_room_invite(sample_invite(), None).unwrap();
+        let truncated = link[..link.len() / 2].to_string();
+        let version = format!(r#"{{"v":{},"n":"room"}}"#, INVITE_VERSION + 1);
+
+        for link in [
+            String::new(),
+            "https://example.com/join/abc".to_string(),
+            INVITE_PREFIX.to_string(),
+            format!("{}!!!", INVITE_PREFIX),
+            truncated,
+            format!(
+                "{}{}",
+                INVITE_PREFIX,
+                URL_SAFE_NO_PAD.encode(b"not deflate")
+            ),
+            raw_link(b"not json"),
+            raw_link(br#"{"v":1}"#),
+            raw_link(version.as_bytes()),
+        ] {
+            assert!(
+                decode_room_invite(link.clone(), None).is_err(),
+                "{:?} was accepted",
+                link
+            );
+        }
+
+        let signed = encode_room_invite(sample_invite(), Some(SECRET.to_string())).unwrap();
+        let bad_signature = format!("{}.%%%", signed.rsplit_once('.').unwrap().0);
+        assert_eq!(
+            decode_room_invite(bad_signature, Some(SECRET.to_string())).unwrap_err(),
+            "invalid invite signature"
+        );
+    }
+
+    #[test]
+    fn invalid_invites_are_not_encoded() {
+        let mut invite = sample_invite();
+        invite.room_name = String::new();
+        assert!(encode_room_invite(invite, None).is_err());
+
+        for range in ["10.0.0.0", "10.0.0.0/33", "room/24"] {
+            let mut invite = sample_invite();
+            invite.ip_range = Some(range.to_string());
+            assert!(encode_room_invite(invite, None).is_err(), "{}", range);
+        }
+    }
+}
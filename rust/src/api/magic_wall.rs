@@ -1,12 +1,20 @@
-//! 魔法墙 (Magic Wall) - WFP 防火墙管理模块
+//! 魔法墙 (Magic Wall) - 防火墙管理模块
 //! 
-//! 简化版实现，提供基本的防火墙规则管理接口
+//! 简化版实现，提供基本的防火墙规则管理接口。
+//! Windows 使用 WFP（独立的提供程序和子层），Linux 使用 nftables（独立的 `inet astral_magic_wall` 表），
+//! 启动时会先清理上次异常退出遗留的过滤器，也可以调用 `purge_magic_wall_filters` 手动清理。
+//! 两者都实现 `FirewallBackend`，另有纯内存的模拟后端，供 `simulate_magic_wall` 在任意平台上检验规则。
+//! 调用 `init_magic_wall_store` 后规则保存在应用数据目录的 JSON 文件中，停止引擎只移除过滤器。
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 use std::collections::HashMap;
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+use std::fs;
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+use std::path::PathBuf;
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 use std::sync::Mutex;
 
 // ============= 公共数据结构 =============
@@ -25,6 +33,8 @@ pub struct MagicWallRule {
     pub local_ip: Option<String>,
     pub remote_port: Option<String>,
     pub local_port: Option<String>,
+    #[serde(default)]
+    pub priority: u32,             // 数值越大越先匹配，同优先级时阻止规则优先
     pub description: Option<String>,
     pub created_at: Option<i64>,
 }
@@ -37,20 +47,68 @@ pub struct MagicWallStatus {
     pub total_rules: usize,
 }
 
-// ============= Windows 实现 =============
+/// 用于模拟判定的连接
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MagicWallPacket {
+    pub protocol: String,          // "tcp", "udp", "icmp", "icmpv6" 或协议号
+    pub direction: String,         // "inbound" or "outbound"
+    pub local_ip: String,
+    pub remote_ip: String,
+    pub local_port: Option<u16>,
+    pub remote_port: Option<u16>,
+    pub app_path: Option<String>,
+}
+
+/// 模拟判定结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MagicWallVerdict {
+    pub action: String,            // "allow" or "block"
+    pub matched_rule_id: Option<String>,
+}
+
+/// 规则分析发现的问题类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MagicWallRuleIssueKind {
+    /// 被匹配顺序更靠前、动作相反的规则完全覆盖，永远不会生效
+    Shadowed,
+    /// 被动作相同的规则完全覆盖，删除后结果不变
+    Redundant,
+    /// 与同优先级、动作相反的规则部分重叠，重叠部分按阻止处理
+    Conflicting,
+}
+
+/// 规则分析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MagicWallRuleIssue {
+    pub kind: MagicWallRuleIssueKind,
+    pub rule_id: String,
+    pub other_rule_id: String,     // 引起问题的另一条规则
+    pub message: String,
+}
+
+// ============= 规则解析 =============
 
-#[cfg(target_os = "windows")]
 #[cfg_attr(not(target_family = "wasm"), flutter_rust_bridge::frb(ignore))]
-mod wfp_impl {
+mod rule_impl {
     use super::*;
     use anyhow::{bail, Result};
     use ipnetwork::IpNetwork;
     use std::net::IpAddr;
     use std::str::FromStr;
-    use windows::core::{PCWSTR, PWSTR};
-    use windows::Win32::Foundation::HANDLE;
-    use windows::Win32::NetworkManagement::WindowsFilteringPlatform::*;
-    use windows::Win32::System::Rpc::RPC_C_AUTHN_DEFAULT;
+
+    /// 防火墙后端，规则以魔法墙规则 ID 为键，一条规则可能对应多个底层过滤器
+    pub trait FirewallBackend: Send {
+        fn add_rule(&mut self, id: &str, rule: &FilterRule) -> Result<()>;
+        fn remove_rule(&mut self, id: &str) -> Result<()>;
+        fn list(&self) -> Vec<AppliedRule>;
+    }
+
+    /// 已应用到后端的规则
+    #[derive(Debug, Clone)]
+    pub struct AppliedRule {
+        pub id: String,
+        pub filter_ids: Vec<u64>,
+    }
 
     #[derive(Debug, Clone)]
     pub struct FilterRule {
@@ -63,6 +121,7 @@ mod wfp_impl {
         pub local_ip: Option<IpFilter>,
         pub remote_port: Option<PortFilter>,
         pub local_port: Option<PortFilter>,
+        pub priority: u32,
     }
 
     impl FilterRule {
@@ -77,6 +136,7 @@ mod wfp_impl {
                 local_ip: None,
                 remote_port: None,
                 local_port: None,
+                priority: 0,
             }
         }
 
@@ -114,6 +174,17 @@ mod wfp_impl {
             self.local_port = Some(port);
             self
         }
+
+        pub fn priority(mut self, priority: u32) -> Self {
+            self.priority = priority;
+            self
+        }
+
+        /// 匹配顺序，越大越先匹配，同优先级时阻止规则优先。
+        /// 各后端据此决定过滤器权重或规则在链中的位置
+        pub fn precedence(&self) -> u64 {
+            ((self.priority as u64) << 1) | (self.action == RuleAction::Block) as u64
+        }
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -151,6 +222,13 @@ mod wfp_impl {
         pub fn network(network: IpNetwork) -> Self {
             IpFilter::Network(network)
         }
+
+        pub fn is_ipv6(&self) -> bool {
+            match self {
+                IpFilter::Single(ip) => ip.is_ipv6(),
+                IpFilter::Network(network) => network.is_ipv6(),
+            }
+        }
     }
 
     #[derive(Debug, Clone)]
@@ -169,12 +247,152 @@ mod wfp_impl {
         }
     }
 
+    pub fn convert_rule(rule: &MagicWallRule) -> Result<FilterRule> {
+        let action = match rule.action.as_str() {
+            "allow" => RuleAction::Allow,
+            "block" => RuleAction::Block,
+            other => bail!("不支持的动作: {other}"),
+        };
+
+        let protocol = match rule.protocol.as_str() {
+            "tcp" => Protocol::TCP,
+            "udp" => Protocol::UDP,
+            "both" => Protocol::Both,
+            "any" => Protocol::Any,
+            other => bail!("不支持的协议: {other}"),
+        };
+
+        let direction = match rule.direction.as_str() {
+            "inbound" => Direction::Inbound,
+            "outbound" => Direction::Outbound,
+            "both" => Direction::Both,
+            other => bail!("不支持的方向: {other}"),
+        };
+
+        let mut f_rule = FilterRule::new(&rule.name, action)
+            .protocol(protocol)
+            .direction(direction)
+            .priority(rule.priority);
+
+        if let Some(app) = rule.app_path.as_ref().filter(|s| !s.trim().is_empty()) {
+            f_rule = f_rule.application(app.clone());
+        }
+
+        if let Some(ref remote_ip) = rule.remote_ip {
+            if !remote_ip.trim().is_empty() {
+                f_rule = f_rule.remote_ip(parse_ip_filter(remote_ip)?);
+            }
+        }
+
+        if let Some(ref local_ip) = rule.local_ip {
+            if !local_ip.trim().is_empty() {
+                f_rule = f_rule.local_ip(parse_ip_filter(local_ip)?);
+            }
+        }
+
+        if let Some(ref remote_port) = rule.remote_port {
+            if !remote_port.trim().is_empty() {
+                f_rule = f_rule.remote_port(parse_port_filter(remote_port)?);
+            }
+        }
+
+        if let Some(ref local_port) = rule.local_port {
+            if !local_port.trim().is_empty() {
+                f_rule = f_rule.local_port(parse_port_filter(local_port)?);
+            }
+        }
+
+        Ok(f_rule)
+    }
+
+    fn parse_ip_filter(value: &str) -> Result<IpFilter> {
+        if value.contains('/') {
+            let network = IpNetwork::from_str(value.trim())?;
+            Ok(IpFilter::network(network))
+        } else {
+            let ip = IpAddr::from_str(value.trim())?;
+            Ok(IpFilter::single(ip))
+        }
+    }
+
+    fn parse_port_filter(value: &str) -> Result<PortFilter> {
+        if let Some((start, end)) = value.split_once('-') {
+            let start: u16 = start.trim().parse()?;
+            let end: u16 = end.trim().parse()?;
+            Ok(PortFilter::range(start, end))
+        } else {
+            Ok(PortFilter::single(value.trim().parse()?))
+        }
+    }
+}
+
+// ============= Windows 实现 =============
+
+#[cfg(target_os = "windows")]
+#[cfg_attr(not(target_family = "wasm"), flutter_rust_bridge::frb(ignore))]
+mod wfp_impl {
+    use super::rule_impl::*;
+    use anyhow::{bail, Result};
+    use ipnetwork::IpNetwork;
+    use std::collections::HashMap;
+    use std::net::IpAddr;
+    use windows::core::{GUID, PCWSTR, PWSTR};
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::NetworkManagement::WindowsFilteringPlatform::*;
+    use windows::Win32::System::Rpc::RPC_C_AUTHN_DEFAULT;
+
+    pub const PLATFORM_NAME: &str = "Windows Filtering Platform (WFP)";
+
+    /// 魔法墙专用的提供程序和子层，所有过滤器都挂在它们下面，
+    /// 异常退出后遗留的过滤器可以据此找出并删除
+    static PROVIDER_KEY: GUID = GUID::from_u128(0x5f3a9c2e_7b41_4d8a_9e6f_1c2d3b4a5e60);
+    static SUBLAYER_KEY: GUID = GUID::from_u128(0x8c1e4b7d_2a95_4f3c_b6d0_9e8f7a6b5c41);
+
+    const FWP_E_FILTER_NOT_FOUND: u32 = 0x8032_0003;
+    const FWP_E_PROVIDER_NOT_FOUND: u32 = 0x8032_0005;
+    const FWP_E_SUBLAYER_NOT_FOUND: u32 = 0x8032_0007;
+    const FWP_E_ALREADY_EXISTS: u32 = 0x8032_0009;
+
+    /// 每次枚举请求的过滤器数量
+    const ENUM_BATCH: u32 = 256;
+
+    /// 魔法墙添加过滤器的 ALE 层
+    const FILTER_LAYERS: [GUID; 4] = [
+        FWPM_LAYER_ALE_AUTH_RECV_ACCEPT_V4,
+        FWPM_LAYER_ALE_AUTH_CONNECT_V4,
+        FWPM_LAYER_ALE_AUTH_RECV_ACCEPT_V6,
+        FWPM_LAYER_ALE_AUTH_CONNECT_V6,
+    ];
+
+    /// 删除所有遗留的魔法墙过滤器，返回删除的过滤器数
+    pub fn purge_filters() -> Result<usize> {
+        WfpFirewall::open()?.purge()
+    }
+
     pub struct WfpFirewall {
         engine_handle: HANDLE,
+        /// 规则 ID -> 过滤器 ID
+        filters: HashMap<String, Vec<u64>>,
     }
 
     impl WfpFirewall {
         pub fn new() -> Result<Self> {
+            let firewall = Self::open()?;
+
+            // 对账：清掉上次异常退出遗留的过滤器，再重新注册提供程序和子层。
+            // 有遗留过滤器删不掉时照常启动，子层已存在不影响注册
+            match firewall.purge() {
+                Ok(0) => {}
+                Ok(removed) => println!("✓ 已清理 {} 个遗留的魔法墙过滤器", removed),
+                Err(e) => println!("⚠️  {}", e),
+            }
+            firewall.register()?;
+
+            Ok(firewall)
+        }
+
+        /// 打开动态会话，会话中添加的对象随引擎关闭自动清理
+        fn open() -> Result<Self> {
             unsafe {
                 let mut engine_handle = HANDLE::default();
 
@@ -204,11 +422,140 @@ mod wfp_impl {
 
                 println!("✓ WFP 动态会话已创建（规则随引擎自动清理）");
 
-                Ok(Self { engine_handle })
+                Ok(Self {
+                    engine_handle,
+                    filters: HashMap::new(),
+                })
+            }
+        }
+
+        /// 注册魔法墙的提供程序和子层
+        fn register(&self) -> Result<()> {
+            let mut name = to_wstring("Astral Magic Wall");
+            let mut desc = to_wstring("Astral Magic Wall filters");
+            let display_data = FWPM_DISPLAY_DATA0 {
+                name: PWSTR(name.as_mut_ptr()),
+                description: PWSTR(desc.as_mut_ptr()),
+            };
+
+            let provider = FWPM_PROVIDER0 {
+                providerKey: PROVIDER_KEY,
+                displayData: display_data,
+                ..Default::default()
+            };
+            let status = unsafe { FwpmProviderAdd0(self.engine_handle, &provider, None) };
+            if status != 0 && status != FWP_E_ALREADY_EXISTS {
+                bail!("注册 WFP 提供程序失败，错误代码: {:#x}", status);
+            }
+
+            let sublayer = FWPM_SUBLAYER0 {
+                subLayerKey: SUBLAYER_KEY,
+                displayData: display_data,
+                providerKey: &PROVIDER_KEY as *const GUID as *mut GUID,
+                // 子层按权重从高到低评估
+                weight: u16::MAX,
+                ..Default::default()
+            };
+            let status = unsafe { FwpmSubLayerAdd0(self.engine_handle, &sublayer, None) };
+            if status != 0 && status != FWP_E_ALREADY_EXISTS {
+                bail!("注册 WFP 子层失败，错误代码: {:#x}", status);
+            }
+
+            println!("✓ WFP 提供程序和子层已注册");
+            Ok(())
+        }
+
+        /// 删除魔法墙提供程序下的所有过滤器，以及子层和提供程序本身，
+        /// 返回删除的过滤器数。删除失败的项不影响其余的项，最后一并报告
+        fn purge(&self) -> Result<usize> {
+            let mut removed = 0;
+            let mut errors = Vec::new();
+            for id in self.provider_filters()? {
+                match unsafe { FwpmFilterDeleteById0(self.engine_handle, id) } {
+                    0 => removed += 1,
+                    // 枚举之后已被删除，例如所属的动态会话刚好关闭
+                    FWP_E_FILTER_NOT_FOUND => {}
+                    status => errors.push(format!("过滤器 {}: {:#x}", id, status)),
+                }
+            }
+
+            let status = unsafe { FwpmSubLayerDeleteByKey0(self.engine_handle, &SUBLAYER_KEY) };
+            if status != 0 && status != FWP_E_SUBLAYER_NOT_FOUND {
+                errors.push(format!("子层: {:#x}", status));
+            }
+            let status = unsafe { FwpmProviderDeleteByKey0(self.engine_handle, &PROVIDER_KEY) };
+            if status != 0 && status != FWP_E_PROVIDER_NOT_FOUND {
+                errors.push(format!("提供程序: {:#x}", status));
+            }
+
+            if !errors.is_empty() {
+                bail!(
+                    "已清理 {} 个遗留过滤器，{} 项删除失败: {}",
+                    removed,
+                    errors.len(),
+                    errors.join("; ")
+                );
+            }
+            Ok(removed)
+        }
+
+        /// 枚举魔法墙提供程序在各 ALE 层的过滤器 ID
+        fn provider_filters(&self) -> Result<Vec<u64>> {
+            let mut ids = Vec::new();
+            for layer in FILTER_LAYERS {
+                // 不带过滤条件的 OVERLAPPING 枚举返回该层的全部过滤器，再按提供程序筛选
+                let template = FWPM_FILTER_ENUM_TEMPLATE0 {
+                    providerKey: &PROVIDER_KEY as *const GUID as *mut GUID,
+                    layerKey: layer,
+                    enumType: FWP_FILTER_ENUM_OVERLAPPING,
+                    actionMask: u32::MAX,
+                    ..Default::default()
+                };
+                unsafe {
+                    let mut enum_handle = HANDLE::default();
+                    let status = FwpmFilterCreateEnumHandle0(
+                        self.engine_handle,
+                        Some(&template),
+                        &mut enum_handle,
+                    );
+                    if status != 0 {
+                        bail!("枚举过滤器失败，错误代码: {:#x}", status);
+                    }
+
+                    loop {
+                        let mut entries: *mut *mut FWPM_FILTER0 = std::ptr::null_mut();
+                        let mut count: u32 = 0;
+                        let status = FwpmFilterEnum0(
+                            self.engine_handle,
+                            enum_handle,
+                            ENUM_BATCH,
+                            &mut entries,
+                            &mut count,
+                        );
+                        if status != 0 {
+                            FwpmFilterDestroyEnumHandle0(self.engine_handle, enum_handle);
+                            bail!("枚举过滤器失败，错误代码: {:#x}", status);
+                        }
+
+                        for i in 0..count as usize {
+                            ids.push((**entries.add(i)).filterId);
+                        }
+
+                        if !entries.is_null() {
+                            FwpmFreeMemory0(&mut entries as *mut _ as *mut *mut _);
+                        }
+                        if count < ENUM_BATCH {
+                            break;
+                        }
+                    }
+
+                    FwpmFilterDestroyEnumHandle0(self.engine_handle, enum_handle);
+                }
             }
+            Ok(ids)
         }
 
-        pub fn add_rule(&mut self, rule: &FilterRule) -> Result<Vec<u64>> {
+        fn add_filters(&mut self, rule: &FilterRule) -> Result<Vec<u64>> {
             let mut ids = Vec::new();
 
             let directions = match rule.direction {
@@ -257,7 +604,7 @@ mod wfp_impl {
             Ok(ids)
         }
 
-        pub fn remove_filter(&mut self, filter_id: u64) -> Result<()> {
+        fn remove_filter(&mut self, filter_id: u64) -> Result<()> {
             unsafe {
                 println!("🔍 尝试删除过滤器 ID: {}", filter_id);
                 let status = FwpmFilterDeleteById0(self.engine_handle, filter_id);
@@ -343,6 +690,41 @@ mod wfp_impl {
         }
     }
 
+    impl FirewallBackend for WfpFirewall {
+        fn add_rule(&mut self, id: &str, rule: &FilterRule) -> Result<()> {
+            if self.filters.contains_key(id) {
+                bail!("规则 {} 已应用", id);
+            }
+            let ids = self.add_filters(rule)?;
+            self.filters.insert(id.to_string(), ids);
+            Ok(())
+        }
+
+        fn remove_rule(&mut self, id: &str) -> Result<()> {
+            let Some(ids) = self.filters.remove(id) else {
+                bail!("未找到规则 {} 的过滤器记录", id);
+            };
+
+            println!("📝 找到 {} 个过滤器需要删除", ids.len());
+            for filter_id in ids {
+                if let Err(err) = self.remove_filter(filter_id) {
+                    println!("⚠️  删除过滤器失败: {}", err);
+                }
+            }
+            Ok(())
+        }
+
+        fn list(&self) -> Vec<AppliedRule> {
+            self.filters
+                .iter()
+                .map(|(id, filter_ids)| AppliedRule {
+                    id: id.clone(),
+                    filter_ids: filter_ids.clone(),
+                })
+                .collect()
+        }
+    }
+
     impl Drop for WfpFirewall {
         fn drop(&mut self) {
             unsafe {
@@ -365,6 +747,7 @@ mod wfp_impl {
         v6_masks: Vec<Box<FWP_V6_ADDR_AND_MASK>>,
         ranges: Vec<Box<FWP_RANGE0>>,
         array16: Vec<Box<[u8; 16]>>,
+        weight: Box<u64>,
     }
 
     fn create_filter(
@@ -377,13 +760,16 @@ mod wfp_impl {
         let mut name = to_wstring(&rule.name).into_boxed_slice();
         filter.displayData.name = PWSTR(name.as_mut_ptr());
         filter.layerKey = unsafe { *layer_key };
-        filter.subLayerKey = FWPM_SUBLAYER_UNIVERSAL;
+        filter.providerKey = &PROVIDER_KEY as *const GUID as *mut GUID;
+        filter.subLayerKey = SUBLAYER_KEY;
         filter.action.r#type = match rule.action {
             RuleAction::Block => FWP_ACTION_BLOCK,
             RuleAction::Allow => FWP_ACTION_PERMIT,
         };
-        filter.weight.r#type = FWP_UINT8;
-        filter.weight.Anonymous.uint8 = 15;
+        // 保持原来权重 15 所在的最高权重段，段内按规则优先级排序
+        let mut weight = Box::new((15u64 << 60) | rule.precedence());
+        filter.weight.r#type = FWP_UINT64;
+        filter.weight.Anonymous.uint64 = weight.as_mut() as *mut u64;
 
         let mut conditions: Vec<FWPM_FILTER_CONDITION0> = Vec::new();
         let mut byte_arrays: Vec<Box<[u8]>> = Vec::new();
@@ -478,6 +864,7 @@ mod wfp_impl {
             v6_masks,
             ranges,
             array16,
+            weight,
         })
     }
 
@@ -640,111 +1027,1338 @@ mod wfp_impl {
 
         Ok(bytes)
     }
+}
 
-    pub fn convert_rule(rule: &MagicWallRule) -> Result<FilterRule> {
-        let action = match rule.action.as_str() {
-            "allow" => RuleAction::Allow,
-            "block" => RuleAction::Block,
-            other => bail!("不支持的动作: {other}"),
-        };
+// ============= Linux 实现 =============
 
-        let protocol = match rule.protocol.as_str() {
-            "tcp" => Protocol::TCP,
-            "udp" => Protocol::UDP,
-            "both" => Protocol::Both,
-            "any" => Protocol::Any,
-            other => bail!("不支持的协议: {other}"),
-        };
+#[cfg(target_os = "linux")]
+#[cfg_attr(not(target_family = "wasm"), flutter_rust_bridge::frb(ignore))]
+mod nft_impl {
+    use super::rule_impl::*;
+    use anyhow::{bail, Context, Result};
+    use ipnetwork::IpNetwork;
+    use std::collections::{BTreeSet, HashMap};
+    use std::fs;
+    use std::io::Write;
+    use std::net::IpAddr;
+    use std::path::PathBuf;
+    use std::process::{Command, Stdio};
+
+    pub const PLATFORM_NAME: &str = "nftables";
+
+    /// 魔法墙独占的 nftables 表，停止时整表删除
+    const TABLE: &str = "inet astral_magic_wall";
+    const INPUT_CHAIN: &str = "input";
+    const OUTPUT_CHAIN: &str = "output";
+
+    /// 删除魔法墙的 nftables 表，返回表中魔法墙规则的数量
+    pub fn purge_filters() -> Result<usize> {
+        // 表不存在时列出失败，视为没有遗留规则；魔法墙规则都带注释
+        let removed = run_nft(&[], &format!("list table {TABLE}\n"))
+            .map(|listing| {
+                listing
+                    .lines()
+                    .filter(|l| l.contains(" comment \""))
+                    .count()
+            })
+            .unwrap_or(0);
+
+        // 先声明再删除，表不存在时也不会报错
+        run_nft(&[], &format!("table {TABLE}\ndelete table {TABLE}\n"))?;
+        Ok(removed)
+    }
 
-        let direction = match rule.direction.as_str() {
-            "inbound" => Direction::Inbound,
-            "outbound" => Direction::Outbound,
-            "both" => Direction::Both,
-            other => bail!("不支持的方向: {other}"),
-        };
+    /// nftables 防火墙，过滤器 ID 即 nft 规则句柄
+    pub struct NftFirewall {
+        /// 规则 ID -> (所在链, 规则句柄)，删除规则时需要指定链
+        rules: HashMap<String, Vec<(&'static str, u64)>>,
+        /// 每条链中规则的 (匹配顺序, 句柄)，与链中的实际顺序一致
+        order: HashMap<&'static str, Vec<(u64, u64)>>,
+    }
 
-        let mut f_rule = FilterRule::new(&rule.name, action)
-            .protocol(protocol)
-            .direction(direction);
+    impl NftFirewall {
+        pub fn new() -> Result<Self> {
+            // 对账：清掉上次异常退出遗留的表
+            let removed = purge_filters()?;
+            if removed > 0 {
+                println!("✓ 已清理 {} 条遗留的魔法墙规则", removed);
+            }
 
-        if let Some(app) = rule.app_path.as_ref().filter(|s| !s.trim().is_empty()) {
-            f_rule = f_rule.application(app.clone());
+            // 与 WFP 的 ALE 层一致，只在建立连接时匹配规则，已建立的连接直接放行。
+            let script = format!(
+                "table {TABLE} {{\n\
+                 \tchain {INPUT_CHAIN} {{\n\
+                 \t\ttype filter hook input priority 0; policy accept;\n\
+                 \t\tct state established,related accept\n\
+                 \t}}\n\
+                 \tchain {OUTPUT_CHAIN} {{\n\
+                 \t\ttype filter hook output priority 0; policy accept;\n\
+                 \t\tct state established,related accept\n\
+                 \t}}\n\
+                 }}\n"
+            );
+            run_nft(&[], &script)?;
+
+            println!("✓ nftables 表 {} 已创建（停止时整表删除）", TABLE);
+
+            Ok(Self {
+                rules: HashMap::new(),
+                order: HashMap::new(),
+            })
         }
 
-        if let Some(ref remote_ip) = rule.remote_ip {
-            if !remote_ip.trim().is_empty() {
-                f_rule = f_rule.remote_ip(parse_ip_filter(remote_ip)?);
-            }
+        /// 链中第一条匹配顺序低于 `precedence` 的规则句柄
+        fn position(&self, chain: &str, precedence: u64) -> Option<u64> {
+            self.order
+                .get(chain)?
+                .iter()
+                .find(|(p, _)| *p < precedence)
+                .map(|(_, handle)| *handle)
         }
+    }
 
-        if let Some(ref local_ip) = rule.local_ip {
-            if !local_ip.trim().is_empty() {
-                f_rule = f_rule.local_ip(parse_ip_filter(local_ip)?);
+    impl FirewallBackend for NftFirewall {
+        fn add_rule(&mut self, id: &str, rule: &FilterRule) -> Result<()> {
+            if self.rules.contains_key(id) {
+                bail!("规则 {} 已应用", id);
             }
-        }
 
-        if let Some(ref remote_port) = rule.remote_port {
-            if !remote_port.trim().is_empty() {
-                f_rule = f_rule.remote_port(parse_port_filter(remote_port)?);
+            let directions = match rule.direction {
+                Direction::Inbound => vec![true],
+                Direction::Outbound => vec![false],
+                Direction::Both => vec![true, false],
+            };
+
+            let precedence = rule.precedence();
+            let mut script = String::new();
+            let mut chains = Vec::new();
+            for is_inbound in directions {
+                let chain = if is_inbound {
+                    INPUT_CHAIN
+                } else {
+                    OUTPUT_CHAIN
+                };
+                for expr in rule_expr(rule, is_inbound)? {
+                    // nft 按链中顺序匹配，插入到第一条匹配顺序更低的规则之前
+                    match self.position(chain, precedence) {
+                        Some(handle) => script.push_str(&format!(
+                            "insert rule {TABLE} {chain} position {handle} {expr}\n"
+                        )),
+                        None => script.push_str(&format!("add rule {TABLE} {chain} {expr}\n")),
+                    }
+                    chains.push(chain);
+                }
             }
-        }
 
-        if let Some(ref local_port) = rule.local_port {
-            if !local_port.trim().is_empty() {
-                f_rule = f_rule.local_port(parse_port_filter(local_port)?);
+            // 没有可匹配的部分时仍记为已应用，不影响其他规则，删除时也无需操作
+            if chains.is_empty() {
+                println!(
+                    "⚠️  规则 {} 在 nftables 下没有可匹配的部分，暂不生效",
+                    rule.name
+                );
+                self.rules.insert(id.to_string(), Vec::new());
+                return Ok(());
             }
-        }
 
-        Ok(f_rule)
-    }
+            // 同一次 nft -f 是一个事务，失败时不会留下部分规则
+            let output = run_nft(&["-e", "-a"], &script)?;
+            let handles: Vec<u64> = output.lines().filter_map(parse_handle).collect();
+            if handles.len() != chains.len() {
+                bail!("无法从 nft 输出中获取规则句柄: {}", output.trim());
+            }
 
-    fn parse_ip_filter(value: &str) -> Result<IpFilter> {
-        if value.contains('/') {
-            let network = IpNetwork::from_str(value.trim())?;
-            Ok(IpFilter::network(network))
-        } else {
-            let ip = IpAddr::from_str(value.trim())?;
-            Ok(IpFilter::single(ip))
+            for (chain, handle) in chains.iter().zip(&handles) {
+                let order = self.order.entry(*chain).or_default();
+                let index = order
+                    .iter()
+                    .position(|(p, _)| *p < precedence)
+                    .unwrap_or(order.len());
+                order.insert(index, (precedence, *handle));
+            }
+
+            self.rules
+                .insert(id.to_string(), chains.into_iter().zip(handles).collect());
+            Ok(())
+        }
+
+        fn remove_rule(&mut self, id: &str) -> Result<()> {
+            let Some(handles) = self.rules.remove(id) else {
+                bail!("未找到规则 {} 的 nft 规则记录", id);
+            };
+
+            if handles.is_empty() {
+                return Ok(());
+            }
+
+            println!("📝 找到 {} 条 nft 规则需要删除", handles.len());
+            let script: String = handles
+                .iter()
+                .map(|(chain, handle)| format!("delete rule {TABLE} {chain} handle {handle}\n"))
+                .collect();
+            run_nft(&[], &script)?;
+
+            for (chain, handle) in handles {
+                if let Some(order) = self.order.get_mut(chain) {
+                    order.retain(|(_, h)| *h != handle);
+                }
+            }
+            Ok(())
+        }
+
+        fn list(&self) -> Vec<AppliedRule> {
+            self.rules
+                .iter()
+                .map(|(id, handles)| AppliedRule {
+                    id: id.clone(),
+                    filter_ids: handles.iter().map(|(_, handle)| *handle).collect(),
+                })
+                .collect()
         }
     }
 
-    fn parse_port_filter(value: &str) -> Result<PortFilter> {
-        if let Some((start, end)) = value.split_once('-') {
-            let start: u16 = start.trim().parse()?;
-            let end: u16 = end.trim().parse()?;
-            Ok(PortFilter::range(start, end))
+    impl Drop for NftFirewall {
+        fn drop(&mut self) {
+            println!("\n正在删除 nftables 表...");
+            match run_nft(&[], &format!("delete table {TABLE}\n")) {
+                Ok(_) => println!("✓ nftables 表已删除，所有规则已清理"),
+                Err(e) => println!("⚠️  删除 nftables 表失败: {}", e),
+            }
+        }
+    }
+
+    /// 生成单个方向的 nft 规则表达式，按应用匹配到多个 cgroup 时每个一条，
+    /// 该方向无法匹配时返回空列表
+    fn rule_expr(rule: &FilterRule, is_inbound: bool) -> Result<Vec<String>> {
+        let apps = match rule.application {
+            // 入站数据包在 input 钩子上还没有关联到本地 socket，无法按应用匹配
+            Some(_) if is_inbound => {
+                println!(
+                    "⚠️  nftables 无法按应用匹配入站连接，跳过规则 {} 的入站部分",
+                    rule.name
+                );
+                return Ok(Vec::new());
+            }
+            Some(ref app) => {
+                let apps = app_match(app)?;
+                if apps.is_empty() {
+                    println!(
+                        "⚠️  未找到运行中的 {} 进程所在的 cgroup，规则 {} 的出站部分暂不生效",
+                        app, rule.name
+                    );
+                    return Ok(Vec::new());
+                }
+                apps.into_iter().map(Some).collect()
+            }
+            None => vec![None],
+        };
+
+        let mut parts = Vec::new();
+
+        // 入站时远程地址是源地址，出站时是目的地址
+        let (remote, local) = if is_inbound {
+            ("saddr", "daddr")
         } else {
-            Ok(PortFilter::single(value.trim().parse()?))
+            ("daddr", "saddr")
+        };
+        let (remote_port, local_port) = if is_inbound {
+            ("sport", "dport")
+        } else {
+            ("dport", "sport")
+        };
+
+        let has_ports = rule.remote_port.is_some() || rule.local_port.is_some();
+        match rule.protocol {
+            Protocol::TCP => parts.push("meta l4proto tcp".to_string()),
+            Protocol::UDP => parts.push("meta l4proto udp".to_string()),
+            Protocol::Both => parts.push("meta l4proto { tcp, udp }".to_string()),
+            // 端口条件只对 TCP/UDP 有意义，与 WFP 的行为一致
+            Protocol::Any if has_ports => parts.push("meta l4proto { tcp, udp }".to_string()),
+            Protocol::Any => {}
+        }
+
+        if let (Some(remote_ip), Some(local_ip)) = (&rule.remote_ip, &rule.local_ip) {
+            if remote_ip.is_ipv6() != local_ip.is_ipv6() {
+                bail!("远程 IP 与本地 IP 的地址族不一致");
+            }
+        }
+        if let Some(ref remote_ip) = rule.remote_ip {
+            parts.push(ip_match(remote_ip, remote));
+        }
+        if let Some(ref local_ip) = rule.local_ip {
+            parts.push(ip_match(local_ip, local));
+        }
+
+        if let Some(ref port) = rule.remote_port {
+            parts.push(format!("th {} {}", remote_port, port_value(port)));
+        }
+        if let Some(ref port) = rule.local_port {
+            parts.push(format!("th {} {}", local_port, port_value(port)));
+        }
+
+        parts.push(
+            match rule.action {
+                RuleAction::Allow => "accept",
+                RuleAction::Block => "drop",
+            }
+            .to_string(),
+        );
+        parts.push(format!("comment \"{}\"", comment(&rule.name)));
+
+        let expr = parts.join(" ");
+        Ok(apps
+            .into_iter()
+            .map(|app| match app {
+                Some(app) => format!("{} {}", app, expr),
+                None => expr.clone(),
+            })
+            .collect())
+    }
+
+    /// 应用匹配，命中其中任意一项即可。nftables 只能按 socket 所属用户/组或
+    /// cgroup 匹配：`uid:<UID>`、`gid:<GID>` 和 `cgroup:<cgroup v2 路径>` 直接转换；
+    /// 可执行文件路径或进程名按当前运行的对应进程所在的 cgroup 匹配，
+    /// 进程未运行时返回空列表
+    fn app_match(app: &str) -> Result<Vec<String>> {
+        let app = app.trim();
+        if let Some(uid) = app.strip_prefix("uid:") {
+            let uid: u32 = uid.trim().parse().context("无效的 UID")?;
+            Ok(vec![format!("meta skuid {}", uid)])
+        } else if let Some(gid) = app.strip_prefix("gid:") {
+            let gid: u32 = gid.trim().parse().context("无效的 GID")?;
+            Ok(vec![format!("meta skgid {}", gid)])
+        } else if let Some(path) = app.strip_prefix("cgroup:") {
+            Ok(vec![cgroup_match(path)?])
+        } else {
+            let cgroups = process_cgroups(app);
+            if !cgroups.is_empty() {
+                println!(
+                    "⚠️  nftables 无法按可执行文件匹配，{} 改为按其所在的 cgroup 匹配: {}",
+                    app,
+                    cgroups.iter().cloned().collect::<Vec<_>>().join(", ")
+                );
+            }
+            cgroups.iter().map(|path| cgroup_match(path)).collect()
+        }
+    }
+
+    fn cgroup_match(path: &str) -> Result<String> {
+        let path = path.trim().trim_matches('/');
+        if path.is_empty() || path.contains('"') {
+            bail!("无效的 cgroup 路径: {}", path);
+        }
+        let level = path.split('/').count();
+        Ok(format!("socket cgroupv2 level {} \"{}\"", level, path))
+    }
+
+    /// 运行中的进程里可执行文件为 `app` 的进程所在的 cgroup v2 路径。
+    /// `app` 不含 `/` 时按可执行文件名匹配；根 cgroup 会匹配所有进程，不计入
+    fn process_cgroups(app: &str) -> BTreeSet<String> {
+        let target = if app.contains('/') {
+            Some(fs::canonicalize(app).unwrap_or_else(|_| PathBuf::from(app)))
+        } else {
+            None
+        };
+        let Ok(entries) = fs::read_dir("/proc") else {
+            return BTreeSet::new();
+        };
+
+        entries
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().parse::<u32>().is_ok())
+            .filter(|entry| {
+                fs::read_link(entry.path().join("exe")).is_ok_and(|exe| match &target {
+                    Some(target) => exe == *target,
+                    None => exe.file_name().is_some_and(|name| name == app),
+                })
+            })
+            .filter_map(|entry| fs::read_to_string(entry.path().join("cgroup")).ok())
+            .filter_map(|cgroup| {
+                cgroup
+                    .lines()
+                    .find_map(|line| line.strip_prefix("0::"))
+                    .map(|path| path.trim().to_string())
+            })
+            .filter(|path| !path.trim_matches('/').is_empty())
+            .collect()
+    }
+
+    fn ip_match(filter: &IpFilter, field: &str) -> String {
+        let family = if filter.is_ipv6() { "ip6" } else { "ip" };
+        let value = match filter {
+            IpFilter::Single(IpAddr::V4(ip)) => ip.to_string(),
+            IpFilter::Single(IpAddr::V6(ip)) => ip.to_string(),
+            // 按网络地址输出，nft 不接受带主机位的前缀
+            IpFilter::Network(IpNetwork::V4(net)) => format!("{}/{}", net.network(), net.prefix()),
+            IpFilter::Network(IpNetwork::V6(net)) => format!("{}/{}", net.network(), net.prefix()),
+        };
+        format!("{} {} {}", family, field, value)
+    }
+
+    fn port_value(port: &PortFilter) -> String {
+        match port {
+            PortFilter::Single(port) => port.to_string(),
+            PortFilter::Range(start, end) => format!("{}-{}", start, end),
+        }
+    }
+
+    fn comment(name: &str) -> String {
+        // nft 注释最长 128 字节，且不能包含双引号
+        let mut comment = String::new();
+        for c in name.chars().map(|c| if c == '"' { '\'' } else { c }) {
+            if comment.len() + c.len_utf8() > 128 {
+                break;
+            }
+            comment.push(c);
+        }
+        comment
+    }
+
+    /// 从 `nft -e -a` 的回显中解析规则句柄，如 `add rule ... # handle 12`
+    fn parse_handle(line: &str) -> Option<u64> {
+        let (_, handle) = line.rsplit_once("# handle ")?;
+        handle.trim().parse().ok()
+    }
+
+    fn run_nft(args: &[&str], script: &str) -> Result<String> {
+        let mut child = Command::new("nft")
+            .args(args)
+            .args(["-f", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("无法执行 nft，请确认已安装 nftables")?;
+
+        child
+            .stdin
+            .take()
+            .context("无法写入 nft 标准输入")?
+            .write_all(script.as_bytes())?;
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            bail!(
+                "nft 执行失败（需要 root 或 CAP_NET_ADMIN 权限）: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn ip(value: &str) -> IpFilter {
+            match value.parse::<IpNetwork>() {
+                Ok(network) if value.contains('/') => IpFilter::network(network),
+                _ => IpFilter::single(value.parse().unwrap()),
+            }
+        }
+
+        fn expr(rule: &FilterRule, is_inbound: bool) -> String {
+            let exprs = rule_expr(rule, is_inbound).unwrap();
+            assert_eq!(exprs.len(), 1);
+            exprs[0].clone()
+        }
+
+        #[test]
+        fn direction_swaps_addresses() {
+            let rule = FilterRule::new("r", RuleAction::Block)
+                .protocol(Protocol::TCP)
+                .remote_ip(ip("10.0.0.0/8"))
+                .local_ip(ip("192.168.1.2"))
+                .remote_port(PortFilter::single(80))
+                .local_port(PortFilter::range(1000, 2000));
+            assert_eq!(
+                expr(&rule, true),
+                "meta l4proto tcp ip saddr 10.0.0.0/8 ip daddr 192.168.1.2 \
+                 th sport 80 th dport 1000-2000 drop comment \"r\""
+            );
+            assert_eq!(
+                expr(&rule, false),
+                "meta l4proto tcp ip daddr 10.0.0.0/8 ip saddr 192.168.1.2 \
+                 th dport 80 th sport 1000-2000 drop comment \"r\""
+            );
+        }
+
+        #[test]
+        fn protocol() {
+            for (protocol, port, expected) in [
+                (Protocol::TCP, None, "meta l4proto tcp accept"),
+                (Protocol::UDP, None, "meta l4proto udp accept"),
+                (Protocol::Both, None, "meta l4proto { tcp, udp } accept"),
+                (Protocol::Any, None, "accept"),
+                // 端口条件让 Any 收窄到 TCP/UDP
+                (
+                    Protocol::Any,
+                    Some(PortFilter::single(53)),
+                    "meta l4proto { tcp, udp } th dport 53 accept",
+                ),
+            ] {
+                let mut rule = FilterRule::new("p", RuleAction::Allow).protocol(protocol);
+                if let Some(port) = port {
+                    rule = rule.remote_port(port);
+                }
+                assert_eq!(
+                    expr(&rule, false),
+                    format!("{} comment \"p\"", expected),
+                    "{:?}",
+                    protocol
+                );
+            }
+        }
+
+        #[test]
+        fn ip_and_port_values() {
+            for (filter, expected) in [
+                ("10.1.2.3", "ip daddr 10.1.2.3"),
+                // 主机位被清零
+                ("192.168.1.77/24", "ip daddr 192.168.1.0/24"),
+                ("10.0.0.1/32", "ip daddr 10.0.0.1/32"),
+                ("2001:db8::1", "ip6 daddr 2001:db8::1"),
+                ("2001:db8:1:2::1/32", "ip6 daddr 2001:db8::/32"),
+            ] {
+                assert_eq!(ip_match(&ip(filter), "daddr"), expected);
+            }
+
+            assert_eq!(port_value(&PortFilter::single(80)), "80");
+            assert_eq!(port_value(&PortFilter::range(8000, 9000)), "8000-9000");
+        }
+
+        #[test]
+        fn mixed_address_families() {
+            let rule = FilterRule::new("m", RuleAction::Block)
+                .remote_ip(ip("2001:db8::/32"))
+                .local_ip(ip("192.168.1.2"));
+            assert!(rule_expr(&rule, true).is_err());
+
+            let rule = FilterRule::new("m", RuleAction::Block)
+                .remote_ip(ip("2001:db8::/32"))
+                .local_ip(ip("2001:db8::2"));
+            assert!(rule_expr(&rule, true).is_ok());
+        }
+
+        #[test]
+        fn comment_escaping() {
+            assert_eq!(comment("say \"hi\""), "say 'hi'");
+            assert_eq!(comment(&"a".repeat(200)).len(), 128);
+            // 按字符截断，不会切开多字节字符
+            let truncated = comment(&"墙".repeat(50));
+            assert_eq!(truncated, "墙".repeat(42));
+        }
+
+        #[test]
+        fn handle_parsing() {
+            for (line, expected) in [
+                (
+                    "add rule inet astral_magic_wall output meta l4proto tcp drop # handle 12",
+                    Some(12),
+                ),
+                (
+                    "insert rule inet t input position 3 accept # handle 7 ",
+                    Some(7),
+                ),
+                ("add rule inet t output accept comment \"# handle\"", None),
+                ("add rule inet t output accept # handle x", None),
+                ("table inet astral_magic_wall", None),
+            ] {
+                assert_eq!(parse_handle(line), expected, "{}", line);
+            }
+        }
+
+        #[test]
+        fn application() {
+            for (app, expected) in [
+                ("uid:1000", "meta skuid 1000"),
+                ("gid: 100", "meta skgid 100"),
+                (
+                    "cgroup:/user.slice/app.scope",
+                    "socket cgroupv2 level 2 \"user.slice/app.scope\"",
+                ),
+            ] {
+                assert_eq!(app_match(app).unwrap(), [expected], "{}", app);
+            }
+            for app in ["uid:root", "gid:", "cgroup:/", "cgroup:a\"b"] {
+                assert!(app_match(app).is_err(), "{}", app);
+            }
+
+            // 可执行文件按运行中进程的 cgroup 匹配，没有运行时不报错
+            let missing = app_match("/nonexistent/astral-test-app").unwrap();
+            assert!(missing.is_empty());
+            let exe = std::env::current_exe().unwrap();
+            for m in app_match(exe.to_str().unwrap()).unwrap() {
+                assert!(m.starts_with("socket cgroupv2 level "), "{}", m);
+            }
+
+            // 入站部分和找不到进程时该方向不生效
+            let rule = FilterRule::new("a", RuleAction::Block).application("uid:1000");
+            assert!(rule_expr(&rule, true).unwrap().is_empty());
+            assert_eq!(
+                expr(&rule, false),
+                "meta skuid 1000 meta l4proto { tcp, udp } drop comment \"a\""
+            );
+            let rule =
+                FilterRule::new("a", RuleAction::Block).application("/nonexistent/astral-test-app");
+            assert!(rule_expr(&rule, false).unwrap().is_empty());
         }
     }
 }
 
-#[cfg(target_os = "windows")]
-use wfp_impl::{convert_rule, WfpFirewall};
+// ============= 规则模拟 =============
+
+#[cfg_attr(not(target_family = "wasm"), flutter_rust_bridge::frb(ignore))]
+mod sim_impl {
+    use super::rule_impl::*;
+    use super::MagicWallPacket;
+    use anyhow::{bail, Result};
+    use std::net::IpAddr;
+
+    const ICMP: u8 = 1;
+    const TCP: u8 = 6;
+    const UDP: u8 = 17;
+    const ICMPV6: u8 = 58;
+
+    /// 一个新连接的首个数据包，与 WFP ALE 层和 nft 的 `ct state new` 对应
+    #[derive(Debug, Clone)]
+    pub struct Packet {
+        pub protocol: u8,
+        pub direction: Direction,
+        pub local_ip: IpAddr,
+        pub remote_ip: IpAddr,
+        pub local_port: Option<u16>,
+        pub remote_port: Option<u16>,
+        pub application: Option<String>,
+    }
+
+    impl Packet {
+        pub fn parse(packet: &MagicWallPacket) -> Result<Self> {
+            let protocol = match packet.protocol.trim().to_lowercase().as_str() {
+                "tcp" => TCP,
+                "udp" => UDP,
+                "icmp" => ICMP,
+                "icmpv6" => ICMPV6,
+                other => match other.parse() {
+                    Ok(protocol) => protocol,
+                    Err(_) => bail!("不支持的协议: {other}"),
+                },
+            };
+
+            let direction = match packet.direction.as_str() {
+                "inbound" => Direction::Inbound,
+                "outbound" => Direction::Outbound,
+                other => bail!("不支持的方向: {other}"),
+            };
+
+            Ok(Self {
+                protocol,
+                direction,
+                local_ip: packet.local_ip.trim().parse()?,
+                remote_ip: packet.remote_ip.trim().parse()?,
+                local_port: packet.local_port,
+                remote_port: packet.remote_port,
+                application: packet.app_path.clone().filter(|s| !s.trim().is_empty()),
+            })
+        }
+    }
+
+    /// 纯内存的防火墙，按 `FilterRule::precedence` 匹配规则，相同时按添加顺序，
+    /// 第一条命中的规则决定结果，没有命中时放行，与 nftables 后端一致。
+    ///
+    /// WFP 后端只在魔法墙自己的子层内与此一致：子层内权重最高的过滤器生效，
+    /// 权重相同时顺序不确定。各子层的结果再由 WFP 仲裁，任一子层阻止即阻止，
+    /// 所以这里判定放行的连接仍可能被 Windows 防火墙等其他子层的规则阻止。
+    #[derive(Default)]
+    pub struct SimFirewall {
+        rules: Vec<(AppliedRule, FilterRule)>,
+        next_filter_id: u64,
+    }
+
+    impl SimFirewall {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// 返回判定结果和命中的规则 ID
+        pub fn evaluate(&self, packet: &Packet) -> (RuleAction, Option<&str>) {
+            self.rules
+                .iter()
+                .find(|(_, rule)| matches(rule, packet))
+                .map(|(applied, rule)| (rule.action, Some(applied.id.as_str())))
+                .unwrap_or((RuleAction::Allow, None))
+        }
+    }
+
+    impl FirewallBackend for SimFirewall {
+        fn add_rule(&mut self, id: &str, rule: &FilterRule) -> Result<()> {
+            if self.rules.iter().any(|(applied, _)| applied.id == id) {
+                bail!("规则 {} 已应用", id);
+            }
+            self.next_filter_id += 1;
+            let applied = AppliedRule {
+                id: id.to_string(),
+                filter_ids: vec![self.next_filter_id],
+            };
+            let precedence = rule.precedence();
+            let index = self
+                .rules
+                .iter()
+                .position(|(_, r)| r.precedence() < precedence)
+                .unwrap_or(self.rules.len());
+            self.rules.insert(index, (applied, rule.clone()));
+            Ok(())
+        }
+
+        fn remove_rule(&mut self, id: &str) -> Result<()> {
+            let Some(pos) = self.rules.iter().position(|(applied, _)| applied.id == id) else {
+                bail!("未找到规则 {}", id);
+            };
+            self.rules.remove(pos);
+            Ok(())
+        }
+
+        fn list(&self) -> Vec<AppliedRule> {
+            self.rules
+                .iter()
+                .map(|(applied, _)| applied.clone())
+                .collect()
+        }
+    }
+
+    fn matches(rule: &FilterRule, packet: &Packet) -> bool {
+        let direction = match rule.direction {
+            Direction::Both => true,
+            direction => direction == packet.direction,
+        };
+        // 端口条件只对 TCP/UDP 有意义
+        let has_ports = rule.remote_port.is_some() || rule.local_port.is_some();
+        let protocol = match rule.protocol {
+            Protocol::TCP => packet.protocol == TCP,
+            Protocol::UDP => packet.protocol == UDP,
+            Protocol::Both => packet.protocol == TCP || packet.protocol == UDP,
+            Protocol::Any => !has_ports || packet.protocol == TCP || packet.protocol == UDP,
+        };
+        let application = match (&rule.application, &packet.application) {
+            (None, _) => true,
+            (Some(rule_app), Some(app)) => rule_app.trim().eq_ignore_ascii_case(app.trim()),
+            (Some(_), None) => false,
+        };
+
+        direction
+            && protocol
+            && application
+            && ip_matches(&rule.remote_ip, packet.remote_ip)
+            && ip_matches(&rule.local_ip, packet.local_ip)
+            && port_matches(&rule.remote_port, packet.remote_port)
+            && port_matches(&rule.local_port, packet.local_port)
+    }
+
+    fn ip_matches(filter: &Option<IpFilter>, ip: IpAddr) -> bool {
+        match filter {
+            None => true,
+            Some(IpFilter::Single(addr)) => *addr == ip,
+            Some(IpFilter::Network(network)) => network.contains(ip),
+        }
+    }
+
+    fn port_matches(filter: &Option<PortFilter>, port: Option<u16>) -> bool {
+        match (filter, port) {
+            (None, _) => true,
+            (Some(PortFilter::Single(expected)), Some(port)) => *expected == port,
+            (Some(PortFilter::Range(start, end)), Some(port)) => (*start..=*end).contains(&port),
+            (Some(_), None) => false,
+        }
+    }
+
+    /// 规则能匹配的连接集合，各维度相互独立
+    struct MatchSet<'a> {
+        /// 入站、出站
+        directions: [bool; 2],
+        /// TCP、UDP、其他协议
+        protocols: [bool; 3],
+        /// IPv4、IPv6
+        families: [bool; 2],
+        application: Option<&'a str>,
+        remote_ip: Option<(u128, u128)>,
+        local_ip: Option<(u128, u128)>,
+        remote_port: Option<(u16, u16)>,
+        local_port: Option<(u16, u16)>,
+    }
+
+    impl<'a> MatchSet<'a> {
+        fn of(rule: &'a FilterRule) -> Self {
+            let directions = match rule.direction {
+                Direction::Inbound => [true, false],
+                Direction::Outbound => [false, true],
+                Direction::Both => [true, true],
+            };
+            let has_ports = rule.remote_port.is_some() || rule.local_port.is_some();
+            let protocols = match rule.protocol {
+                Protocol::TCP => [true, false, false],
+                Protocol::UDP => [false, true, false],
+                Protocol::Both => [true, true, false],
+                Protocol::Any => [true, true, !has_ports],
+            };
+
+            // 一个连接只有一个地址族，远程和本地 IP 共同限定地址族
+            let mut families = [true, true];
+            for filter in [&rule.remote_ip, &rule.local_ip].into_iter().flatten() {
+                families[!filter.is_ipv6() as usize] = false;
+            }
+
+            Self {
+                directions,
+                protocols,
+                families,
+                application: rule.application.as_deref().map(str::trim),
+                remote_ip: rule.remote_ip.as_ref().map(ip_range),
+                local_ip: rule.local_ip.as_ref().map(ip_range),
+                remote_port: rule.remote_port.as_ref().map(port_range),
+                local_port: rule.local_port.as_ref().map(port_range),
+            }
+        }
+
+        fn covers(&self, other: &Self) -> bool {
+            fn flags<const N: usize>(a: &[bool; N], b: &[bool; N]) -> bool {
+                a.iter().zip(b).all(|(a, b)| *a || !*b)
+            }
+            fn range<T: PartialOrd>(a: &Option<(T, T)>, b: &Option<(T, T)>) -> bool {
+                match (a, b) {
+                    (None, _) => true,
+                    (Some(a), Some(b)) => a.0 <= b.0 && b.1 <= a.1,
+                    (Some(_), None) => false,
+                }
+            }
+            let application = match (self.application, other.application) {
+                (None, _) => true,
+                (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                (Some(_), None) => false,
+            };
+
+            flags(&self.directions, &other.directions)
+                && flags(&self.protocols, &other.protocols)
+                && flags(&self.families, &other.families)
+                && application
+                && range(&self.remote_ip, &other.remote_ip)
+                && range(&self.local_ip, &other.local_ip)
+                && range(&self.remote_port, &other.remote_port)
+                && range(&self.local_port, &other.local_port)
+        }
+
+        fn overlaps(&self, other: &Self) -> bool {
+            fn flags<const N: usize>(a: &[bool; N], b: &[bool; N]) -> bool {
+                a.iter().zip(b).any(|(a, b)| *a && *b)
+            }
+            fn range<T: PartialOrd>(a: &Option<(T, T)>, b: &Option<(T, T)>) -> bool {
+                match (a, b) {
+                    (Some(a), Some(b)) => a.0 <= b.1 && b.0 <= a.1,
+                    _ => true,
+                }
+            }
+            let application = match (self.application, other.application) {
+                (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                _ => true,
+            };
 
+            flags(&self.directions, &other.directions)
+                && flags(&self.protocols, &other.protocols)
+                && flags(&self.families, &other.families)
+                && application
+                && range(&self.remote_ip, &other.remote_ip)
+                && range(&self.local_ip, &other.local_ip)
+                && range(&self.remote_port, &other.remote_port)
+                && range(&self.local_port, &other.local_port)
+        }
+    }
+
+    /// `a` 匹配的连接是否包含 `b` 匹配的所有连接
+    pub fn covers(a: &FilterRule, b: &FilterRule) -> bool {
+        MatchSet::of(a).covers(&MatchSet::of(b))
+    }
+
+    /// 是否存在同时被 `a` 和 `b` 匹配的连接
+    pub fn overlaps(a: &FilterRule, b: &FilterRule) -> bool {
+        MatchSet::of(a).overlaps(&MatchSet::of(b))
+    }
+
+    /// 地址族内的 (起始地址, 结束地址)
+    fn ip_range(filter: &IpFilter) -> (u128, u128) {
+        fn bits(ip: IpAddr) -> u128 {
+            match ip {
+                IpAddr::V4(ip) => u32::from(ip) as u128,
+                IpAddr::V6(ip) => u128::from(ip),
+            }
+        }
+        match filter {
+            IpFilter::Single(ip) => (bits(*ip), bits(*ip)),
+            IpFilter::Network(network) => (bits(network.network()), bits(network.broadcast())),
+        }
+    }
+
+    fn port_range(filter: &PortFilter) -> (u16, u16) {
+        match filter {
+            PortFilter::Single(port) => (*port, *port),
+            PortFilter::Range(start, end) => (*start, *end),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::api::magic_wall::{
+            analyze_magic_wall_rules, MagicWallRule, MagicWallRuleIssueKind,
+        };
+
+        fn packet(protocol: u8, direction: Direction, remote: &str, port: Option<u16>) -> Packet {
+            Packet {
+                protocol,
+                direction,
+                local_ip: "192.168.1.2".parse().unwrap(),
+                remote_ip: remote.parse().unwrap(),
+                local_port: port.map(|_| 50000),
+                remote_port: port,
+                application: None,
+            }
+        }
+
+        fn tcp_in(remote: &str, port: u16) -> Packet {
+            packet(TCP, Direction::Inbound, remote, Some(port))
+        }
+
+        fn wall_rule(id: &str, action: &str, priority: u32) -> MagicWallRule {
+            MagicWallRule {
+                id: id.to_string(),
+                name: id.to_string(),
+                enabled: true,
+                action: action.to_string(),
+                protocol: "both".to_string(),
+                direction: "both".to_string(),
+                app_path: None,
+                remote_ip: None,
+                local_ip: None,
+                remote_port: None,
+                local_port: None,
+                priority,
+                description: None,
+                created_at: None,
+            }
+        }
+
+        #[test]
+        fn direction() {
+            let inbound = FilterRule::new("in", RuleAction::Block).direction(Direction::Inbound);
+            assert!(matches(&inbound, &tcp_in("10.0.0.1", 80)));
+            assert!(!matches(
+                &inbound,
+                &packet(TCP, Direction::Outbound, "10.0.0.1", Some(80))
+            ));
+
+            let both = FilterRule::new("both", RuleAction::Block);
+            assert!(matches(
+                &both,
+                &packet(TCP, Direction::Outbound, "10.0.0.1", Some(80))
+            ));
+        }
+
+        #[test]
+        fn protocol() {
+            let icmp = packet(ICMP, Direction::Inbound, "10.0.0.1", None);
+            let udp = packet(UDP, Direction::Inbound, "10.0.0.1", Some(53));
+
+            let tcp = FilterRule::new("tcp", RuleAction::Block).protocol(Protocol::TCP);
+            assert!(matches(&tcp, &tcp_in("10.0.0.1", 80)));
+            assert!(!matches(&tcp, &udp));
+
+            let both = FilterRule::new("both", RuleAction::Block);
+            assert!(matches(&both, &udp));
+            assert!(!matches(&both, &icmp));
+
+            let any = FilterRule::new("any", RuleAction::Block).protocol(Protocol::Any);
+            assert!(matches(&any, &icmp));
+
+            // 带端口条件时只能匹配 TCP/UDP
+            let any_port = any.remote_port(PortFilter::single(80));
+            assert!(matches(&any_port, &tcp_in("10.0.0.1", 80)));
+            assert!(!matches(&any_port, &icmp));
+        }
+
+        #[test]
+        fn cidr_and_port_range() {
+            let rule = FilterRule::new("lan", RuleAction::Block)
+                .remote_ip(IpFilter::network("10.0.0.0/8".parse().unwrap()))
+                .remote_port(PortFilter::range(1000, 2000));
+            assert!(matches(&rule, &tcp_in("10.1.2.3", 1000)));
+            assert!(matches(&rule, &tcp_in("10.255.255.255", 2000)));
+            assert!(!matches(&rule, &tcp_in("11.0.0.1", 1500)));
+            assert!(!matches(&rule, &tcp_in("10.1.2.3", 999)));
+            assert!(!matches(&rule, &tcp_in("10.1.2.3", 2001)));
+
+            let single = FilterRule::new("host", RuleAction::Block)
+                .remote_ip(IpFilter::single("10.0.0.1".parse().unwrap()));
+            assert!(matches(&single, &tcp_in("10.0.0.1", 80)));
+            assert!(!matches(&single, &tcp_in("10.0.0.2", 80)));
+        }
+
+        #[test]
+        fn application() {
+            let rule = FilterRule::new("app", RuleAction::Block).application("C:\\Games\\game.exe");
+            let mut packet = tcp_in("10.0.0.1", 80);
+            assert!(!matches(&rule, &packet));
+
+            packet.application = Some(" c:\\games\\GAME.EXE ".to_string());
+            assert!(matches(&rule, &packet));
+
+            packet.application = Some("C:\\Games\\other.exe".to_string());
+            assert!(!matches(&rule, &packet));
+        }
+
+        #[test]
+        fn evaluate_precedence() {
+            let mut firewall = SimFirewall::new();
+            let packet = tcp_in("10.0.0.1", 80);
+            assert_eq!(firewall.evaluate(&packet), (RuleAction::Allow, None));
+
+            // 同优先级时阻止优先，与添加顺序无关
+            firewall
+                .add_rule("allow", &FilterRule::new("allow", RuleAction::Allow))
+                .unwrap();
+            firewall
+                .add_rule("block", &FilterRule::new("block", RuleAction::Block))
+                .unwrap();
+            assert_eq!(
+                firewall.evaluate(&packet),
+                (RuleAction::Block, Some("block"))
+            );
+
+            // 优先级高的先匹配
+            let high = FilterRule::new("high", RuleAction::Allow).priority(10);
+            firewall.add_rule("high", &high).unwrap();
+            assert_eq!(
+                firewall.evaluate(&packet),
+                (RuleAction::Allow, Some("high"))
+            );
+
+            // 匹配顺序相同时按添加顺序
+            firewall.add_rule("high2", &high).unwrap();
+            assert_eq!(
+                firewall.evaluate(&packet),
+                (RuleAction::Allow, Some("high"))
+            );
+
+            firewall.remove_rule("high").unwrap();
+            assert_eq!(
+                firewall.evaluate(&packet),
+                (RuleAction::Allow, Some("high2"))
+            );
+            assert!(firewall.add_rule("high2", &high).is_err());
+        }
+
+        #[test]
+        fn analyze_shadowed() {
+            let block_all = wall_rule("block_all", "block", 10);
+            let mut allow_web = wall_rule("allow_web", "allow", 0);
+            allow_web.protocol = "tcp".to_string();
+            allow_web.remote_port = Some("80".to_string());
+
+            let issues = analyze_magic_wall_rules(vec![allow_web, block_all]).unwrap();
+            assert_eq!(issues.len(), 1);
+            assert_eq!(issues[0].kind, MagicWallRuleIssueKind::Shadowed);
+            assert_eq!(issues[0].rule_id, "allow_web");
+            assert_eq!(issues[0].other_rule_id, "block_all");
+        }
+
+        #[test]
+        fn analyze_redundant() {
+            let mut wide = wall_rule("wide", "allow", 0);
+            wide.remote_ip = Some("10.0.0.0/8".to_string());
+            let mut narrow = wall_rule("narrow", "allow", 0);
+            narrow.remote_ip = Some("10.1.0.0/16".to_string());
+
+            let issues = analyze_magic_wall_rules(vec![wide.clone(), narrow.clone()]).unwrap();
+            assert_eq!(issues.len(), 1);
+            assert_eq!(issues[0].kind, MagicWallRuleIssueKind::Redundant);
+            assert_eq!(issues[0].rule_id, "narrow");
+
+            // 覆盖它的规则匹配顺序靠后也算冗余，除非中间有动作相反的规则
+            narrow.priority = 5;
+            let issues = analyze_magic_wall_rules(vec![wide.clone(), narrow.clone()]).unwrap();
+            assert_eq!(issues.len(), 1);
+            assert_eq!(issues[0].kind, MagicWallRuleIssueKind::Redundant);
+            assert_eq!(issues[0].rule_id, "narrow");
+
+            let mut block = wall_rule("block", "block", 1);
+            block.protocol = "tcp".to_string();
+            block.remote_ip = Some("10.0.0.0/8".to_string());
+            let issues = analyze_magic_wall_rules(vec![wide, narrow, block]).unwrap();
+            assert!(issues.is_empty());
+        }
+
+        #[test]
+        fn analyze_conflicting() {
+            let mut allow = wall_rule("allow", "allow", 0);
+            allow.protocol = "tcp".to_string();
+            allow.remote_port = Some("80-90".to_string());
+            let mut block = wall_rule("block", "block", 0);
+            block.protocol = "tcp".to_string();
+            block.remote_port = Some("85-100".to_string());
+
+            let issues = analyze_magic_wall_rules(vec![allow.clone(), block.clone()]).unwrap();
+            assert_eq!(issues.len(), 1);
+            assert_eq!(issues[0].kind, MagicWallRuleIssueKind::Conflicting);
+            assert_eq!(issues[0].rule_id, "allow");
+            assert_eq!(issues[0].other_rule_id, "block");
+
+            // 优先级不同时顺序明确，不算冲突
+            allow.priority = 1;
+            assert!(analyze_magic_wall_rules(vec![allow, block])
+                .unwrap()
+                .is_empty());
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+use nft_impl::{purge_filters, NftFirewall as PlatformFirewall, PLATFORM_NAME};
+use rule_impl::{convert_rule, FirewallBackend, RuleAction};
+use sim_impl::{covers, overlaps, Packet, SimFirewall};
 #[cfg(target_os = "windows")]
+use wfp_impl::{purge_filters, WfpFirewall as PlatformFirewall, PLATFORM_NAME};
+
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 lazy_static::lazy_static! {
-    static ref FIREWALL: Mutex<Option<WfpFirewall>> = Mutex::new(None);
-    static ref FILTER_TRACKER: Mutex<HashMap<String, Vec<u64>>> = Mutex::new(HashMap::new());
+    static ref FIREWALL: Mutex<Option<Box<dyn FirewallBackend>>> = Mutex::new(None);
     static ref RULE_STORE: Mutex<HashMap<String, MagicWallRule>> = Mutex::new(HashMap::new());
+    /// 规则文件路径，调用 `init_magic_wall_store` 之前规则只保存在内存中
+    static ref RULE_FILE: Mutex<Option<PathBuf>> = Mutex::new(None);
+}
+
+// ============= 规则持久化 =============
+
+/// 规则文件当前的格式版本
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+const RULE_FILE_VERSION: u32 = 1;
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+const RULE_FILE_NAME: &str = "magic_wall_rules.json";
+/// 引擎运行期间存在的标记文件，启动时仍存在说明上次退出时没有停止引擎
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+const RUNNING_MARKER_NAME: &str = "magic_wall.running";
+
+/// 规则文件和导出文件的格式
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+#[derive(Serialize, Deserialize)]
+struct RuleFile {
+    version: u32,
+    rules: Vec<MagicWallRule>,
+}
+
+/// 按创建时间排序，保证文件内容和同优先级规则的顺序稳定
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn sorted_rules(rules: &HashMap<String, MagicWallRule>) -> Vec<MagicWallRule> {
+    let mut rules: Vec<MagicWallRule> = rules.values().cloned().collect();
+    rules.sort_by(|a, b| {
+        a.created_at
+            .cmp(&b.created_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    rules
+}
+
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn encode_rules(rules: Vec<MagicWallRule>) -> std::result::Result<String, String> {
+    let file = RuleFile {
+        version: RULE_FILE_VERSION,
+        rules,
+    };
+    serde_json::to_string_pretty(&file).map_err(|e| e.to_string())
+}
+
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn decode_rules(json: &str) -> std::result::Result<Vec<MagicWallRule>, String> {
+    let file: RuleFile =
+        serde_json::from_str(json).map_err(|e| format!("规则文件格式错误: {}", e))?;
+    if file.version > RULE_FILE_VERSION {
+        return Err(format!(
+            "规则文件版本 {} 高于当前支持的版本 {}，请升级应用",
+            file.version, RULE_FILE_VERSION
+        ));
+    }
+    Ok(file.rules)
+}
+
+/// 把规则写入规则文件，持久化未启用时什么也不做
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn save_rules(rules: &HashMap<String, MagicWallRule>) -> std::result::Result<(), String> {
+    let Some(path) = RULE_FILE.lock().map_err(|e| e.to_string())?.clone() else {
+        return Ok(());
+    };
+
+    let json = encode_rules(sorted_rules(rules))?;
+    // 先写临时文件再替换，写到一半时崩溃也不会损坏原文件
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json).map_err(|e| format!("写入规则文件失败: {}", e))?;
+    fs::rename(&tmp, &path).map_err(|e| format!("写入规则文件失败: {}", e))
+}
+
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn persist_rules(rules: &HashMap<String, MagicWallRule>) {
+    if let Err(err) = save_rules(rules) {
+        println!("⚠️  保存规则失败: {}", err);
+    }
+}
+
+/// 创建或删除运行标记，持久化未启用时什么也不做
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn mark_running(running: bool) {
+    let Ok(path) = RULE_FILE.lock() else {
+        return;
+    };
+    let Some(marker) = path.as_ref().map(|p| p.with_file_name(RUNNING_MARKER_NAME)) else {
+        return;
+    };
+    let result = if running {
+        fs::write(&marker, b"")
+    } else {
+        match fs::remove_file(&marker) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    };
+    if let Err(err) = result {
+        println!("⚠️  更新运行标记失败: {}", err);
+    }
+}
+
+/// 启用规则持久化并载入已保存的规则，`dir` 通常是应用数据目录。
+/// 魔法墙运行中时，载入的已启用规则会立即应用。
+/// 上次退出时引擎仍在运行的话，先清理它遗留的过滤器。
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+pub fn init_magic_wall_store(dir: String) -> std::result::Result<(), String> {
+    let dir = PathBuf::from(dir);
+    fs::create_dir_all(&dir).map_err(|e| format!("创建规则目录失败: {}", e))?;
+    let path = dir.join(RULE_FILE_NAME);
+
+    // 只在有遗留时清理，清理需要管理员权限，失败时留给下次启动引擎时对账
+    let marker = dir.join(RUNNING_MARKER_NAME);
+    if marker.exists() && FIREWALL.lock().map_err(|e| e.to_string())?.is_none() {
+        match purge_filters() {
+            Ok(removed) => {
+                println!("🧹 已清理 {} 个上次运行遗留的魔法墙过滤器", removed);
+                let _ = fs::remove_file(&marker);
+            }
+            Err(err) => println!("⚠️  清理遗留的魔法墙过滤器失败: {}", err),
+        }
+    }
+
+    // 文件损坏时不启用持久化，避免覆盖用户的规则
+    let saved = match fs::read_to_string(&path) {
+        Ok(json) => decode_rules(&json)?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(format!("读取规则文件失败: {}", e)),
+    };
+    *RULE_FILE.lock().map_err(|e| e.to_string())? = Some(path);
+
+    let running = FIREWALL.lock().map_err(|e| e.to_string())?.is_some();
+    let mut rules = RULE_STORE.lock().map_err(|e| e.to_string())?;
+    let mut loaded = 0;
+    for rule in saved {
+        if rules.contains_key(&rule.id) {
+            continue;
+        }
+        if running && rule.enabled {
+            if let Err(err) = apply_rule(&rule) {
+                println!("⚠️  规则 {} 应用失败: {}", rule.name, err);
+            }
+        }
+        rules.insert(rule.id.clone(), rule);
+        loaded += 1;
+    }
+    println!("📂 已载入 {} 条魔法墙规则", loaded);
+
+    // 初始化之前添加的规则也写入文件
+    save_rules(&rules)
+}
+
+/// 导出全部规则为带版本号的 JSON，可在其他设备上用 `import_magic_wall_rules` 导入
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+pub fn export_magic_wall_rules() -> std::result::Result<String, String> {
+    let rules = RULE_STORE.lock().map_err(|e| e.to_string())?;
+    encode_rules(sorted_rules(&rules))
+}
+
+/// 导入 `export_magic_wall_rules` 导出的规则，返回导入的规则数。
+/// `replace` 为 true 时替换全部现有规则，否则合并，ID 相同的规则会被更新。
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+pub fn import_magic_wall_rules(json: String, replace: bool) -> std::result::Result<usize, String> {
+    let imported = decode_rules(&json)?;
+
+    // 先全部校验，避免只导入一部分
+    for rule in &imported {
+        convert_rule(rule).map_err(|e| format!("规则 {} 无效: {}", rule.name, e))?;
+    }
+
+    // 先生成并保存新的规则集再应用到防火墙，应用失败的规则与启动时一样保留，
+    // 不会因为导入失败丢失规则
+    let mut rules = RULE_STORE.lock().map_err(|e| e.to_string())?;
+    let mut updated = if replace {
+        HashMap::new()
+    } else {
+        rules.clone()
+    };
+    for rule in &imported {
+        updated.insert(rule.id.clone(), rule.clone());
+    }
+    save_rules(&updated)?;
+    let previous = std::mem::replace(&mut *rules, updated);
+
+    // 被替换掉或被更新的旧规则先从防火墙移除
+    if let Some(firewall) = FIREWALL.lock().map_err(|e| e.to_string())?.as_mut() {
+        let replaced = |id: &String| replace || imported.iter().any(|r| &r.id == id);
+        for (id, old) in previous.iter().filter(|(id, r)| r.enabled && replaced(id)) {
+            if let Err(err) = firewall.remove_rule(id) {
+                println!("⚠️  规则 {}: {}", old.name, err);
+            }
+        }
+    }
+
+    let total = imported.len();
+    let mut failed = Vec::new();
+    for rule in imported.iter().filter(|r| r.enabled) {
+        if let Err(err) = apply_rule(rule) {
+            failed.push(format!("{}: {}", rule.name, err));
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(format!(
+            "已导入 {} 条规则，其中 {} 条应用失败（规则已保存）: {}",
+            total,
+            failed.len(),
+            failed.join("; ")
+        ));
+    }
+    println!("📥 已导入 {} 条魔法墙规则", total);
+    Ok(total)
 }
 
 /// 启动魔法墙引擎
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 pub fn start_magic_wall() -> std::result::Result<(), String> {
     let mut firewall_guard = FIREWALL.lock().map_err(|e| e.to_string())?;
     if firewall_guard.is_some() {
         return Err("魔法墙已经在运行".to_string());
     }
 
-    let firewall = WfpFirewall::new().map_err(|e| e.to_string())?;
+    let firewall: Box<dyn FirewallBackend> =
+        Box::new(PlatformFirewall::new().map_err(|e| e.to_string())?);
     println!("\n🔥 ============ 魔法墙引擎启动 ============");
     println!("✓ 引擎状态: 运行中");
-    println!("✓ 平台: Windows Filtering Platform (WFP)");
+    println!("✓ 平台: {}", PLATFORM_NAME);
     println!("============================================\n");
 
     *firewall_guard = Some(firewall);
     drop(firewall_guard);
+    mark_running(true);
 
     // 重新应用所有已启用的规则
     let rules = RULE_STORE.lock().map_err(|e| e.to_string())?;
@@ -758,7 +2372,7 @@ pub fn start_magic_wall() -> std::result::Result<(), String> {
 }
 
 /// 停止魔法墙引擎
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 pub fn stop_magic_wall() -> std::result::Result<(), String> {
     let mut firewall_guard = FIREWALL.lock().map_err(|e| e.to_string())?;
     if firewall_guard.is_none() {
@@ -772,19 +2386,19 @@ pub fn stop_magic_wall() -> std::result::Result<(), String> {
         .filter(|r| r.enabled)
         .count();
 
+    // 只移除过滤器，规则保留在存储中，下次启动时重新应用
     *firewall_guard = None;
-    FILTER_TRACKER.lock().map_err(|e| e.to_string())?.clear();
-    RULE_STORE.lock().map_err(|e| e.to_string())?.clear();
+    mark_running(false);
 
     println!("\n🛑 ============ 魔法墙引擎停止 ============");
     println!("✓ 引擎状态: 已停止");
-    println!("✓ 清理规则: {} 条活跃规则", active_count);
+    println!("✓ 移除过滤器: {} 条活跃规则（规则已保留）", active_count);
     println!("============================================\n");
     Ok(())
 }
 
 /// 添加规则
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 pub fn add_magic_wall_rule(rule: MagicWallRule) -> std::result::Result<(), String> {
     // 检查规则是否已存在
     {
@@ -804,15 +2418,14 @@ pub fn add_magic_wall_rule(rule: MagicWallRule) -> std::result::Result<(), Strin
     }
 
     // 只有成功后才添加到存储
-    RULE_STORE
-        .lock()
-        .map_err(|e| e.to_string())?
-        .insert(rule.id.clone(), rule.clone());
+    let mut rules = RULE_STORE.lock().map_err(|e| e.to_string())?;
+    rules.insert(rule.id.clone(), rule);
+    persist_rules(&rules);
 
     Ok(())
 }
 
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 fn apply_rule(rule: &MagicWallRule) -> std::result::Result<(), String> {
     println!("\n➕ ============ 添加防火墙规则 ============");
     println!("📌 规则名称: {}", rule.name);
@@ -826,6 +2439,7 @@ fn apply_rule(rule: &MagicWallRule) -> std::result::Result<(), String> {
         _ => &rule.direction,
     });
 
+    #[cfg(target_os = "windows")]
     if let Some(app_path) = &rule.app_path {
         println!("💻 应用路径 (DOS): {}", app_path);
         if let Some(nt_path) = crate::api::nt::get_nt_path(app_path) {
@@ -835,6 +2449,11 @@ fn apply_rule(rule: &MagicWallRule) -> std::result::Result<(), String> {
         }
     }
 
+    #[cfg(target_os = "linux")]
+    if let Some(app_path) = &rule.app_path {
+        println!("💻 应用: {}", app_path);
+    }
+
     if let Some(remote_ip) = &rule.remote_ip {
         println!("🌐 远程 IP: {}", remote_ip);
     }
@@ -856,19 +2475,14 @@ fn apply_rule(rule: &MagicWallRule) -> std::result::Result<(), String> {
     }
 
     let mut firewall_guard = FIREWALL.lock().map_err(|e| e.to_string())?;
-    if let Some(ref mut firewall) = *firewall_guard {
-        let ids = convert_rule(rule)
-            .and_then(|f_rule| firewall.add_rule(&f_rule))
+    if let Some(firewall) = firewall_guard.as_mut() {
+        convert_rule(rule)
+            .and_then(|f_rule| firewall.add_rule(&rule.id, &f_rule))
             .map_err(|e| {
                 println!("!  添加规则失败: {}", e);
                 e.to_string()
             })?;
 
-        FILTER_TRACKER
-            .lock()
-            .map_err(|e| e.to_string())?
-            .insert(rule.id.clone(), ids);
-
         println!("✅ 状态: 已启用");
         println!("============================================\n");
     } else {
@@ -880,13 +2494,14 @@ fn apply_rule(rule: &MagicWallRule) -> std::result::Result<(), String> {
 }
 
 /// 删除规则
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 pub fn remove_magic_wall_rule(rule_id: String) -> std::result::Result<(), String> {
     let mut rules = RULE_STORE.lock().map_err(|e| e.to_string())?;
     if let Some(rule) = rules.remove(&rule_id) {
         println!("\n➖ ============ 删除防火墙规则 ============");
         println!("📌 规则名称: {}", rule.name);
         println!("🔑 规则 ID: {}", rule.id);
+        #[cfg(target_os = "windows")]
         if let Some(app_path) = &rule.app_path {
             println!("💻 应用路径 (DOS): {}", app_path);
             if let Some(nt_path) = crate::api::nt::get_nt_path(app_path) {
@@ -894,25 +2509,20 @@ pub fn remove_magic_wall_rule(rule_id: String) -> std::result::Result<(), String
             }
         }
 
+        #[cfg(target_os = "linux")]
+        if let Some(app_path) = &rule.app_path {
+            println!("💻 应用: {}", app_path);
+        }
+
         let mut firewall_guard = FIREWALL.lock().map_err(|e| e.to_string())?;
         if let Some(firewall) = firewall_guard.as_mut() {
-            let ids = {
-                let mut tracker = FILTER_TRACKER.lock().map_err(|e| e.to_string())?;
-                tracker.remove(&rule_id)
-            };
-
-            if let Some(ids) = ids {
-                println!("📝 找到 {} 个过滤器需要删除", ids.len());
-                for id in ids {
-                    if let Err(err) = firewall.remove_filter(id) {
-                        println!("⚠️  删除过滤器失败: {}", err);
-                    }
-                }
-            } else {
-                println!("⚠️  FILTER_TRACKER 中未找到规则 {} 的过滤器记录", rule_id);
+            if let Err(err) = firewall.remove_rule(&rule_id) {
+                println!("⚠️  {}", err);
             }
         }
 
+        persist_rules(&rules);
+
         println!("✅ 规则已从防火墙中移除");
         println!("============================================\n");
         Ok(())
@@ -923,32 +2533,73 @@ pub fn remove_magic_wall_rule(rule_id: String) -> std::result::Result<(), String
 }
 
 /// 更新规则
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 pub fn update_magic_wall_rule(rule: MagicWallRule) -> std::result::Result<(), String> {
     println!("\n🔄 ============ 更新防火墙规则 ============");
     println!("📌 规则名称: {}", rule.name);
     println!("🔑 规则 ID: {}", rule.id);
     println!("============================================\n");
 
+    let old = RULE_STORE
+        .lock()
+        .map_err(|e| e.to_string())?
+        .get(&rule.id)
+        .cloned();
+
     let _ = remove_magic_wall_rule(rule.id.clone());
-    add_magic_wall_rule(rule)?;
+    if let Err(err) = add_magic_wall_rule(rule) {
+        // 规则已持久化，更新失败时恢复旧规则，避免规则丢失
+        if let Some(old) = old {
+            let _ = add_magic_wall_rule(old);
+        }
+        return Err(err);
+    }
 
     println!("✅ 规则更新完成\n");
     Ok(())
 }
 
+/// 清理魔法墙遗留的全部过滤器，用于异常退出后的恢复，返回清理的过滤器数。
+/// 魔法墙运行中时会先停止引擎，规则保留。
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+pub fn purge_magic_wall_filters() -> std::result::Result<usize, String> {
+    // 持有锁直到清理完成，避免期间重新启动
+    let mut firewall_guard = FIREWALL.lock().map_err(|e| e.to_string())?;
+    if firewall_guard.take().is_some() {
+        println!("⚠️  魔法墙运行中，已先停止引擎");
+    }
+
+    let removed = purge_filters().map_err(|e| e.to_string())?;
+    mark_running(false);
+    println!("🧹 已清理 {} 个遗留的魔法墙过滤器", removed);
+    Ok(removed)
+}
+
 /// 获取魔法墙状态
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 pub fn get_magic_wall_status() -> std::result::Result<MagicWallStatus, String> {
-    let running = FIREWALL.lock().map_err(|e| e.to_string())?.is_some();
+    // 运行中以后端实际应用的规则为准，应用失败的规则不计入
+    let applied = FIREWALL
+        .lock()
+        .map_err(|e| e.to_string())?
+        .as_ref()
+        .map(|firewall| firewall.list());
+    let running = applied.is_some();
     let rules = RULE_STORE.lock().map_err(|e| e.to_string())?;
 
-    let active_rules = rules.values().filter(|r| r.enabled).count();
+    let active_rules = match &applied {
+        Some(applied) => applied.len(),
+        None => rules.values().filter(|r| r.enabled).count(),
+    };
     let total_rules = rules.len();
 
     println!("📊 魔法墙状态查询:");
     println!("   引擎: {}", if running { "🟢 运行中" } else { "🔴 已停止" });
     println!("   活跃规则: {} / {} 条", active_rules, total_rules);
+    if let Some(applied) = &applied {
+        let filters: usize = applied.iter().map(|r| r.filter_ids.len()).sum();
+        println!("   底层过滤器: {} 个", filters);
+    }
 
     Ok(MagicWallStatus {
         is_running: running,
@@ -957,34 +2608,57 @@ pub fn get_magic_wall_status() -> std::result::Result<MagicWallStatus, String> {
     })
 }
 
-// ============= 非 Windows 平台实现 =============
+// ============= 其他平台实现 =============
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn start_magic_wall() -> std::result::Result<(), String> {
-    Err("魔法墙仅支持 Windows 平台".to_string())
+    Err("魔法墙仅支持 Windows 和 Linux 平台".to_string())
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn stop_magic_wall() -> std::result::Result<(), String> {
-    Err("魔法墙仅支持 Windows 平台".to_string())
+    Err("魔法墙仅支持 Windows 和 Linux 平台".to_string())
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn add_magic_wall_rule(_rule: MagicWallRule) -> std::result::Result<(), String> {
-    Err("魔法墙仅支持 Windows 平台".to_string())
+    Err("魔法墙仅支持 Windows 和 Linux 平台".to_string())
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn remove_magic_wall_rule(_rule_id: String) -> std::result::Result<(), String> {
-    Err("魔法墙仅支持 Windows 平台".to_string())
+    Err("魔法墙仅支持 Windows 和 Linux 平台".to_string())
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn update_magic_wall_rule(_rule: MagicWallRule) -> std::result::Result<(), String> {
-    Err("魔法墙仅支持 Windows 平台".to_string())
+    Err("魔法墙仅支持 Windows 和 Linux 平台".to_string())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn init_magic_wall_store(_dir: String) -> std::result::Result<(), String> {
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn export_magic_wall_rules() -> std::result::Result<String, String> {
+    Err("魔法墙仅支持 Windows 和 Linux 平台".to_string())
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn import_magic_wall_rules(
+    _json: String,
+    _replace: bool,
+) -> std::result::Result<usize, String> {
+    Err("魔法墙仅支持 Windows 和 Linux 平台".to_string())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn purge_magic_wall_filters() -> std::result::Result<usize, String> {
+    Ok(0)
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn get_magic_wall_status() -> std::result::Result<MagicWallStatus, String> {
     Ok(MagicWallStatus {
         is_running: false,
@@ -995,6 +2669,123 @@ pub fn get_magic_wall_status() -> std::result::Result<MagicWallStatus, String> {
 
 // ============= 通用函数 =============
 
+/// 模拟规则集对一个新连接的判定，不会修改系统防火墙，所有平台可用。
+/// 只有启用的规则参与匹配，优先级高的先匹配，同优先级时阻止规则优先，再按列表顺序，
+/// 第一条命中的规则生效，没有命中时放行。
+/// 只模拟魔法墙自身的规则，Windows 上其他子层（如 Windows 防火墙）的阻止规则仍会生效，
+/// 见 `SimFirewall`。
+pub fn simulate_magic_wall(
+    rules: Vec<MagicWallRule>,
+    packet: MagicWallPacket,
+) -> std::result::Result<MagicWallVerdict, String> {
+    let mut firewall = SimFirewall::new();
+    for rule in rules.iter().filter(|r| r.enabled) {
+        convert_rule(rule)
+            .and_then(|f_rule| firewall.add_rule(&rule.id, &f_rule))
+            .map_err(|e| format!("规则 {} 无效: {}", rule.name, e))?;
+    }
+
+    let packet = Packet::parse(&packet).map_err(|e| format!("无效的连接: {}", e))?;
+    let (action, matched_rule_id) = firewall.evaluate(&packet);
+
+    Ok(MagicWallVerdict {
+        action: match action {
+            RuleAction::Allow => "allow",
+            RuleAction::Block => "block",
+        }
+        .to_string(),
+        matched_rule_id: matched_rule_id.map(str::to_string),
+    })
+}
+
+/// 在应用前分析规则集，报告被遮蔽、冗余和冲突的规则，不会修改系统防火墙。
+/// 只分析启用的规则，匹配顺序与 `simulate_magic_wall` 相同。
+pub fn analyze_magic_wall_rules(
+    rules: Vec<MagicWallRule>,
+) -> std::result::Result<Vec<MagicWallRuleIssue>, String> {
+    let mut ordered = Vec::new();
+    for rule in rules.iter().filter(|r| r.enabled) {
+        let f_rule = convert_rule(rule).map_err(|e| format!("规则 {} 无效: {}", rule.name, e))?;
+        ordered.push((rule, f_rule));
+    }
+    // 稳定排序，同匹配顺序时保持列表顺序
+    ordered.sort_by_key(|(_, f_rule)| std::cmp::Reverse(f_rule.precedence()));
+
+    let issue = |kind, rule: &MagicWallRule, other: &MagicWallRule, message| MagicWallRuleIssue {
+        kind,
+        rule_id: rule.id.clone(),
+        other_rule_id: other.id.clone(),
+        message,
+    };
+
+    let mut issues = Vec::new();
+    for (i, (rule, f_rule)) in ordered.iter().enumerate() {
+        // 被前面的规则完全覆盖，永远轮不到它
+        if let Some((other, other_f)) = ordered[..i].iter().find(|(_, o)| covers(o, f_rule)) {
+            issues.push(if other_f.action == f_rule.action {
+                issue(
+                    MagicWallRuleIssueKind::Redundant,
+                    rule,
+                    other,
+                    format!(
+                        "规则 {} 被规则 {} 完全覆盖且动作相同，可以删除",
+                        rule.name, other.name
+                    ),
+                )
+            } else {
+                issue(
+                    MagicWallRuleIssueKind::Shadowed,
+                    rule,
+                    other,
+                    format!(
+                        "规则 {} 被匹配顺序更靠前的规则 {} 完全覆盖且动作相反，永远不会生效",
+                        rule.name, other.name
+                    ),
+                )
+            });
+            continue;
+        }
+
+        // 被后面动作相同的规则覆盖，且中间没有动作相反的规则截走重叠部分
+        let later = &ordered[i + 1..];
+        let redundant_with = later.iter().enumerate().find(|(j, (_, o))| {
+            o.action == f_rule.action
+                && covers(o, f_rule)
+                && !later[..*j]
+                    .iter()
+                    .any(|(_, m)| m.action != f_rule.action && overlaps(m, f_rule))
+        });
+        if let Some((_, (other, _))) = redundant_with {
+            issues.push(issue(
+                MagicWallRuleIssueKind::Redundant,
+                rule,
+                other,
+                format!(
+                    "规则 {} 被规则 {} 完全覆盖且动作相同，可以删除",
+                    rule.name, other.name
+                ),
+            ));
+        }
+
+        // 同优先级、动作相反的部分重叠，结果取决于阻止优先的约定
+        for (other, _) in ordered[..i].iter().filter(|(_, o)| {
+            o.priority == f_rule.priority && o.action != f_rule.action && overlaps(o, f_rule)
+        }) {
+            issues.push(issue(
+                MagicWallRuleIssueKind::Conflicting,
+                rule,
+                other,
+                format!(
+                    "规则 {} 与规则 {} 优先级相同、部分重叠且动作相反，重叠部分的连接将被阻止，请调整优先级",
+                    rule.name, other.name
+                ),
+            ));
+        }
+    }
+
+    Ok(issues)
+}
+
 /// 创建默认规则示例
 pub fn create_default_magic_wall_rules() -> Vec<MagicWallRule> {
     let now = chrono::Utc::now().timestamp();
@@ -1012,6 +2803,7 @@ pub fn create_default_magic_wall_rules() -> Vec<MagicWallRule> {
             local_ip: None,
             remote_port: None,
             local_port: None,
+            priority: 0,
             description: Some("阻止所有入站的 TCP 和 UDP 连接".to_string()),
             created_at: Some(now),
         },
@@ -1027,8 +2819,278 @@ pub fn create_default_magic_wall_rules() -> Vec<MagicWallRule> {
             local_ip: None,
             remote_port: None,
             local_port: None,
+            // 高于上面的阻止规则，本地网络的入站连接仍然放行
+            priority: 100,
             description: Some("允许访问本地网络段".to_string()),
             created_at: Some(now),
         },
     ]
 }
+
+#[cfg(all(test, any(target_os = "windows", target_os = "linux")))]
+mod tests {
+    use super::*;
+    use rule_impl::{AppliedRule, FilterRule};
+    use std::path::Path;
+
+    /// 引擎和规则存储是全局的，用到它们的测试依次执行
+    static GLOBALS: Mutex<()> = Mutex::new(());
+
+    /// 名称以 bad 开头的规则应用失败的后端
+    struct FlakyFirewall(SimFirewall);
+
+    impl FirewallBackend for FlakyFirewall {
+        fn add_rule(&mut self, id: &str, rule: &FilterRule) -> anyhow::Result<()> {
+            if rule.name.starts_with("bad") {
+                anyhow::bail!("模拟应用失败");
+            }
+            self.0.add_rule(id, rule)
+        }
+
+        fn remove_rule(&mut self, id: &str) -> anyhow::Result<()> {
+            self.0.remove_rule(id)
+        }
+
+        fn list(&self) -> Vec<AppliedRule> {
+            self.0.list()
+        }
+    }
+
+    fn rule(id: &str, enabled: bool) -> MagicWallRule {
+        MagicWallRule {
+            id: id.to_string(),
+            name: id.to_string(),
+            enabled,
+            action: "block".to_string(),
+            protocol: "tcp".to_string(),
+            direction: "inbound".to_string(),
+            app_path: None,
+            remote_ip: Some("10.0.0.0/8".to_string()),
+            local_ip: None,
+            remote_port: None,
+            local_port: Some("8000-9000".to_string()),
+            priority: 0,
+            description: None,
+            created_at: Some(1),
+        }
+    }
+
+    /// 重置引擎和规则存储，返回一个不存在的临时目录
+    fn reset(name: &str, firewall: Option<Box<dyn FirewallBackend>>) -> PathBuf {
+        *FIREWALL.lock().unwrap() = firewall;
+        RULE_STORE.lock().unwrap().clear();
+        *RULE_FILE.lock().unwrap() = None;
+        let dir =
+            std::env::temp_dir().join(format!("astral-magic-wall-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn saved_ids(dir: &Path) -> Vec<String> {
+        let json = fs::read_to_string(dir.join(RULE_FILE_NAME)).unwrap();
+        decode_rules(&json)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.id)
+            .collect()
+    }
+
+    fn stored_ids() -> Vec<String> {
+        let mut ids: Vec<String> = RULE_STORE.lock().unwrap().keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    fn applied_ids() -> Vec<String> {
+        let mut ids: Vec<String> = FIREWALL
+            .lock()
+            .unwrap()
+            .as_ref()
+            .unwrap()
+            .list()
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn rule_file_round_trip() {
+        let mut second = rule("b", false);
+        second.priority = 5;
+        second.app_path = Some("uid:1000".to_string());
+        second.description = Some("说明".to_string());
+
+        let json = encode_rules(vec![rule("a", true), second.clone()]).unwrap();
+        let decoded = decode_rules(&json).unwrap();
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(vec![rule("a", true), second]).unwrap()
+        );
+    }
+
+    #[test]
+    fn rule_file_version() {
+        let rules = decode_rules(r#"{"version":1,"rules":[]}"#).unwrap();
+        assert!(rules.is_empty());
+        let err = decode_rules(r#"{"version":2,"rules":[]}"#).unwrap_err();
+        assert!(err.contains("版本 2"), "{}", err);
+        assert!(decode_rules("{").is_err());
+        assert!(decode_rules(r#"{"rules":[]}"#).is_err());
+    }
+
+    #[test]
+    fn missing_priority_defaults_to_zero() {
+        let json = r#"{"version":1,"rules":[{"id":"a","name":"a","enabled":true,
+            "action":"block","protocol":"tcp","direction":"inbound","app_path":null,
+            "remote_ip":null,"local_ip":null,"remote_port":null,"local_port":null,
+            "description":null,"created_at":null}]}"#;
+        assert_eq!(decode_rules(json).unwrap()[0].priority, 0);
+    }
+
+    #[test]
+    fn rules_are_saved_in_creation_order() {
+        let _globals = GLOBALS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = reset("order", None);
+        fs::create_dir_all(&dir).unwrap();
+        *RULE_FILE.lock().unwrap() = Some(dir.join(RULE_FILE_NAME));
+
+        let mut early = rule("z", true);
+        early.created_at = Some(0);
+        let rules: HashMap<String, MagicWallRule> = [rule("b", true), early, rule("a", true)]
+            .into_iter()
+            .map(|r| (r.id.clone(), r))
+            .collect();
+        save_rules(&rules).unwrap();
+        assert_eq!(saved_ids(&dir), ["z", "a", "b"]);
+        // 替换时不留下临时文件
+        assert!(!dir.join("magic_wall_rules.json.tmp").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn store_loads_saved_rules() {
+        let _globals = GLOBALS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = reset("load", None);
+        fs::create_dir_all(&dir).unwrap();
+        let json = encode_rules(vec![rule("a", true), rule("b", false)]).unwrap();
+        fs::write(dir.join(RULE_FILE_NAME), json).unwrap();
+
+        // 初始化之前添加的规则也会写入文件
+        add_magic_wall_rule(rule("early", true)).unwrap();
+        init_magic_wall_store(dir.to_string_lossy().into_owned()).unwrap();
+        assert_eq!(stored_ids(), ["a", "b", "early"]);
+        assert_eq!(saved_ids(&dir).len(), 3);
+
+        add_magic_wall_rule(rule("c", true)).unwrap();
+        remove_magic_wall_rule("a".to_string()).unwrap();
+        let mut saved = saved_ids(&dir);
+        saved.sort();
+        assert_eq!(saved, ["b", "c", "early"]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn corrupt_file_disables_persistence() {
+        let _globals = GLOBALS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = reset("corrupt", None);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(RULE_FILE_NAME);
+        fs::write(&path, "{ not json").unwrap();
+
+        assert!(init_magic_wall_store(dir.to_string_lossy().into_owned()).is_err());
+        assert!(RULE_FILE.lock().unwrap().is_none());
+
+        // 规则只保存在内存中，不会覆盖损坏的文件
+        add_magic_wall_rule(rule("a", true)).unwrap();
+        assert_eq!(stored_ids(), ["a"]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{ not json");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn stop_keeps_rules() {
+        let _globals = GLOBALS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = reset("stop", Some(Box::new(SimFirewall::new())));
+        init_magic_wall_store(dir.to_string_lossy().into_owned()).unwrap();
+        add_magic_wall_rule(rule("a", true)).unwrap();
+        add_magic_wall_rule(rule("b", false)).unwrap();
+        assert_eq!(applied_ids(), ["a"]);
+
+        stop_magic_wall().unwrap();
+        let status = get_magic_wall_status().unwrap();
+        assert!(!status.is_running);
+        assert_eq!((status.active_rules, status.total_rules), (1, 2));
+        assert_eq!(stored_ids(), ["a", "b"]);
+        assert_eq!(saved_ids(&dir).len(), 2);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn stop_clears_running_marker() {
+        let _globals = GLOBALS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = reset("marker", None);
+        init_magic_wall_store(dir.to_string_lossy().into_owned()).unwrap();
+        let marker = dir.join(RUNNING_MARKER_NAME);
+
+        mark_running(true);
+        assert!(marker.exists());
+        *FIREWALL.lock().unwrap() = Some(Box::new(SimFirewall::new()));
+        stop_magic_wall().unwrap();
+        assert!(!marker.exists());
+        // 没有标记时删除也不报错
+        mark_running(false);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn import_keeps_rules_that_fail_to_apply() {
+        let _globals = GLOBALS.lock().unwrap_or_else(|e| e.into_inner());
+        let flaky = FlakyFirewall(SimFirewall::new());
+        let dir = reset("import", Some(Box::new(flaky)));
+        init_magic_wall_store(dir.to_string_lossy().into_owned()).unwrap();
+        add_magic_wall_rule(rule("old", true)).unwrap();
+
+        let mut bad = rule("x", true);
+        bad.name = "bad".to_string();
+        let json = encode_rules(vec![rule("good", true), bad]).unwrap();
+        let err = import_magic_wall_rules(json, true).unwrap_err();
+        assert!(err.contains("1 条应用失败"), "{}", err);
+
+        // 应用失败的规则仍然保存，旧规则已从防火墙移除
+        assert_eq!(stored_ids(), ["good", "x"]);
+        let mut saved = saved_ids(&dir);
+        saved.sort();
+        assert_eq!(saved, ["good", "x"]);
+        assert_eq!(applied_ids(), ["good"]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn import_merges_and_reapplies() {
+        let _globals = GLOBALS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = reset("merge", Some(Box::new(SimFirewall::new())));
+        init_magic_wall_store(dir.to_string_lossy().into_owned()).unwrap();
+        add_magic_wall_rule(rule("a", true)).unwrap();
+        add_magic_wall_rule(rule("b", true)).unwrap();
+
+        let mut updated = rule("a", true);
+        updated.name = "renamed".to_string();
+        let json = encode_rules(vec![updated, rule("c", false)]).unwrap();
+        assert_eq!(import_magic_wall_rules(json, false).unwrap(), 2);
+
+        assert_eq!(stored_ids(), ["a", "b", "c"]);
+        assert_eq!(RULE_STORE.lock().unwrap()["a"].name, "renamed");
+        assert_eq!(applied_ids(), ["a", "b"]);
+
+        // 导入无效规则时什么都不改
+        let mut invalid = rule("d", true);
+        invalid.protocol = "icmp".to_string();
+        let json = encode_rules(vec![invalid]).unwrap();
+        assert!(import_magic_wall_rules(json, true).is_err());
+        assert_eq!(stored_ids(), ["a", "b", "c"]);
+        assert_eq!(saved_ids(&dir).len(), 3);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
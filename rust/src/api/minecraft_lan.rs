@@ -0,0 +1,488 @@
+//! Minecraft 局域网世界桥接
+//!
+//! 在虚拟网卡上监听远端玩家发出的 `[MOTD]..[/MOTD][AD]port[/AD]` 广播，
+//! 为每个世界启动本地端口转发，并在物理局域网上用改写后的 MOTD 和端口重新广播，
+//! 让远端玩家开放的局域网世界出现在本机每个玩家的多人游戏列表中。
+
+use flutter_rust_bridge::frb;
+use ipnetwork::Ipv4Network;
+use lazy_static::lazy_static;
+use socket2::{Domain, Protocol, Socket, Type};
+use std::collections::{HashMap, HashSet};
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::sync::Arc;
+use tokio::io;
+use tokio::net::UdpSocket;
+use tokio::runtime::Runtime;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio::time::{interval, Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+use super::forward::ForwardServer;
+use super::multicast::MulticastSender;
+use super::registry::{HandleRegistry, RegistryEntry};
+
+lazy_static! {
+    static ref RT: Runtime = Runtime::new().expect("创建 Tokio 运行时失败");
+    static ref LAN_BRIDGES: HandleRegistry<MinecraftLanBridge> = HandleRegistry::new();
+}
+
+/// Minecraft 局域网广播使用的组播地址
+const MINECRAFT_LAN_GROUP: Ipv4Addr = Ipv4Addr::new(224, 0, 2, 60);
+/// Minecraft 局域网广播使用的端口
+const MINECRAFT_LAN_PORT: u16 = 4445;
+/// 重新广播的间隔，与游戏客户端保持一致
+const ANNOUNCE_INTERVAL_MS: u64 = 1500;
+/// 超过该时间未收到广播则认为世界已关闭
+const WORLD_EXPIRE_TIMEOUT: Duration = Duration::from_secs(10);
+/// 默认添加在 MOTD 前的标记，同时用于识别自己发出的广播
+const DEFAULT_MOTD_PREFIX: &str = "[Astral] ";
+/// 虚拟网 IP 未带前缀长度时使用的前缀
+const DEFAULT_VIRTUAL_PREFIX_LEN: u8 = 24;
+/// 桥接某个世界失败后，首次重试前的等待时间，之后每次翻倍直到上限
+const OPEN_RETRY_INITIAL: Duration = Duration::from_secs(2);
+const OPEN_RETRY_MAX: Duration = Duration::from_secs(60);
+
+/// 一条局域网世界广播
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinecraftLanAnnouncement {
+    pub motd: String,
+    pub port: u16,
+}
+
+/// 桥接中的局域网世界
+#[derive(Debug, Clone)]
+pub struct MinecraftLanWorld {
+    /// 远端主机的虚拟网 IP
+    pub host: String,
+    pub remote_port: u16,
+    /// 本机转发端口，即在局域网上重新广播的端口
+    pub local_port: u16,
+    pub motd: String,
+    /// 最近一次收到广播的时间（Unix 毫秒时间戳）
+    pub last_seen: i64,
+}
+
+/// 解析 Minecraft 局域网广播内容
+pub fn parse_minecraft_lan_announcement(data: Vec<u8>) -> Option<MinecraftLanAnnouncement> {
+    let text = String::from_utf8_lossy(&data);
+    let motd = between(&text, "[MOTD]", "[/MOTD]")?;
+    let port = between(&text, "[AD]", "[/AD]")?.trim().parse().ok()?;
+    Some(MinecraftLanAnnouncement {
+        motd: motd.to_string(),
+        port,
+    })
+}
+
+/// 按 Minecraft 局域网广播格式组装数据
+pub fn encode_minecraft_lan_announcement(motd: String, port: u16) -> Vec<u8> {
+    format!("[MOTD]{}[/MOTD][AD]{}[/AD]", motd, port).into_bytes()
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = text.find(start)? + start.len();
+    let to = from + text[from..].find(end)?;
+    Some(&text[from..to])
+}
+
+/// 单个被桥接的世界：一个本地端口转发加一个局域网广播
+struct BridgedWorld {
+    motd: String,
+    forward: ForwardServer,
+    sender: MulticastSender,
+    last_seen: Instant,
+    last_seen_at: i64,
+}
+
+impl BridgedWorld {
+    async fn open(
+        host: Ipv4Addr,
+        announcement: &MinecraftLanAnnouncement,
+        lan_bind_addr: &str,
+        motd_prefix: &str,
+    ) -> io::Result<Self> {
+        let target = SocketAddrV4::new(host, announcement.port).to_string();
+
+        // 优先使用与远端相同的端口，被占用时由系统分配
+        let mut forward =
+            ForwardServer::new(format!("0.0.0.0:{}", announcement.port), target.clone());
+        if forward.start().await.is_err() {
+            forward = ForwardServer::new("0.0.0.0:0", target);
+            forward.start().await?;
+        }
+        let local_port = forward
+            .local_addr()
+            .map(|addr| addr.port())
+            .unwrap_or(announcement.port);
+
+        let payload = encode_minecraft_lan_announcement(
+            format!("{}{}", motd_prefix, announcement.motd),
+            local_port,
+        );
+        let mut sender = MulticastSender::new(
+            MINECRAFT_LAN_GROUP.to_string(),
+            MINECRAFT_LAN_PORT,
+            payload,
+            ANNOUNCE_INTERVAL_MS,
+        )?
+        .with_bind_addr(lan_bind_addr);
+        if let Err(e) = sender.start().await {
+            forward.stop().await;
+            return Err(e);
+        }
+
+        Ok(Self {
+            motd: announcement.motd.clone(),
+            forward,
+            sender,
+            last_seen: Instant::now(),
+            last_seen_at: chrono::Utc::now().timestamp_millis(),
+        })
+    }
+
+    async fn close(mut self) {
+        self.sender.stop().await;
+        self.forward.stop().await;
+    }
+
+    fn info(&self, host: &SocketAddrV4) -> MinecraftLanWorld {
+        MinecraftLanWorld {
+            host: host.ip().to_string(),
+            remote_port: host.port(),
+            local_port: self
+                .forward
+                .local_addr()
+                .map(|addr| addr.port())
+                .unwrap_or(0),
+            motd: self.motd.clone(),
+            last_seen: self.last_seen_at,
+        }
+    }
+}
+
+/// 某个世界最近一次桥接失败的记录，在重试时间之前不再尝试
+struct OpenFailure {
+    delay: Duration,
+    retry_at: Instant,
+}
+
+impl OpenFailure {
+    fn next(previous: Option<&OpenFailure>) -> Self {
+        let delay = previous
+            .map(|failure| (failure.delay * 2).min(OPEN_RETRY_MAX))
+            .unwrap_or(OPEN_RETRY_INITIAL);
+        Self {
+            delay,
+            retry_at: Instant::now() + delay,
+        }
+    }
+}
+
+#[frb(opaque)]
+pub struct MinecraftLanBridge {
+    /// 本机虚拟网地址及其所在网段，只接受来自该网段的广播
+    virtual_net: Ipv4Network,
+    lan_bind_addr: String,
+    motd_prefix: String,
+    worlds: Arc<std::sync::Mutex<Vec<MinecraftLanWorld>>>,
+    handle: Option<JoinHandle<()>>,
+    cancel_token: Option<CancellationToken>,
+}
+
+impl MinecraftLanBridge {
+    #[frb(ignore)]
+    pub fn new(virtual_net: Ipv4Network) -> Self {
+        Self {
+            virtual_net,
+            lan_bind_addr: "0.0.0.0:0".to_string(),
+            motd_prefix: DEFAULT_MOTD_PREFIX.to_string(),
+            worlds: Arc::new(std::sync::Mutex::new(Vec::new())),
+            handle: None,
+            cancel_token: None,
+        }
+    }
+
+    /// 指定物理局域网网卡地址，重新广播将从该网卡发出
+    #[frb(ignore)]
+    pub fn with_lan_bind_addr(mut self, lan_bind_addr: impl Into<String>) -> Self {
+        self.lan_bind_addr = lan_bind_addr.into();
+        self
+    }
+
+    #[frb(ignore)]
+    pub fn with_motd_prefix(mut self, motd_prefix: impl Into<String>) -> Self {
+        self.motd_prefix = motd_prefix.into();
+        self
+    }
+
+    pub async fn start(&mut self) -> io::Result<()> {
+        if self.handle.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "局域网桥接已启动",
+            ));
+        }
+
+        let socket = bind_lan_listener(self.virtual_net.ip())?;
+        let virtual_net = self.virtual_net;
+        let lan_bind_addr = self.lan_bind_addr.clone();
+        let motd_prefix = self.motd_prefix.clone();
+        let snapshot = self.worlds.clone();
+        let cancel_token = CancellationToken::new();
+        let cancel_token_clone = cancel_token.clone();
+
+        let handle = tokio::spawn(async move {
+            let mut worlds: HashMap<SocketAddrV4, BridgedWorld> = HashMap::new();
+            // 正在后台建立转发和广播的世界，建立过程不阻塞收包
+            let mut opening: JoinSet<(SocketAddrV4, String, io::Result<BridgedWorld>)> =
+                JoinSet::new();
+            let mut pending: HashSet<SocketAddrV4> = HashSet::new();
+            let mut failures: HashMap<SocketAddrV4, OpenFailure> = HashMap::new();
+            let mut buf = vec![0u8; 1500];
+            let mut sweeper = interval(Duration::from_secs(1));
+
+            loop {
+                tokio::select! {
+                    _ = cancel_token_clone.cancelled() => {
+                        break;
+                    }
+                    _ = sweeper.tick() => {
+                        let expired: Vec<SocketAddrV4> = worlds
+                            .iter()
+                            .filter(|(_, world)| world.last_seen.elapsed() >= WORLD_EXPIRE_TIMEOUT)
+                            .map(|(host, _)| *host)
+                            .collect();
+                        for host in expired {
+                            if let Some(world) = worlds.remove(&host) {
+                                println!("局域网世界已过期: {} ({})", host, world.motd);
+                                world.close().await;
+                            }
+                        }
+                        // 远端已不再广播的世界无需保留失败记录
+                        failures.retain(|_, failure| {
+                            failure.retry_at.elapsed() < WORLD_EXPIRE_TIMEOUT
+                        });
+                    }
+                    Some(joined) = opening.join_next(), if !opening.is_empty() => {
+                        let Ok((host, motd, result)) = joined else {
+                            continue;
+                        };
+                        pending.remove(&host);
+                        match result {
+                            Ok(world) => {
+                                println!(
+                                    "已桥接局域网世界: {} ({}) -> 本地端口 {}",
+                                    host,
+                                    motd,
+                                    world.info(&host).local_port
+                                );
+                                failures.remove(&host);
+                                worlds.insert(host, world);
+                            }
+                            Err(e) => {
+                                let failure = OpenFailure::next(failures.get(&host));
+                                println!(
+                                    "桥接局域网世界失败: {}, 错误: {}, {} 秒后重试",
+                                    host,
+                                    e,
+                                    failure.delay.as_secs()
+                                );
+                                failures.insert(host, failure);
+                            }
+                        }
+                    }
+                    result = socket.recv_from(&mut buf) => {
+                        let Ok((n, SocketAddr::V4(source))) = result else {
+                            continue;
+                        };
+                        // 监听套接字绑定在 0.0.0.0 上，物理局域网（包括本机重新广播）的
+                        // 报文同样会送达，只接受来自虚拟网段的广播
+                        if !virtual_net.contains(*source.ip()) {
+                            continue;
+                        }
+                        // 忽略本机开放的世界，它会由其他节点负责桥接
+                        if *source.ip() == virtual_net.ip() {
+                            continue;
+                        }
+                        let Some(announcement) = parse_minecraft_lan_announcement(buf[..n].to_vec()) else {
+                            continue;
+                        };
+                        // 忽略已经被桥接过的广播，避免环路
+                        if announcement.motd.starts_with(&motd_prefix) {
+                            continue;
+                        }
+
+                        let host = SocketAddrV4::new(*source.ip(), announcement.port);
+                        if let Some(world) = worlds.get_mut(&host) {
+                            world.last_seen = Instant::now();
+                            world.last_seen_at = chrono::Utc::now().timestamp_millis();
+                            if world.motd == announcement.motd {
+                                continue;
+                            }
+                            // MOTD 变化时重建广播内容
+                            if let Some(world) = worlds.remove(&host) {
+                                world.close().await;
+                            }
+                        }
+                        if pending.contains(&host) {
+                            continue;
+                        }
+                        if failures
+                            .get(&host)
+                            .is_some_and(|failure| Instant::now() < failure.retry_at)
+                        {
+                            continue;
+                        }
+
+                        pending.insert(host);
+                        let lan_bind_addr = lan_bind_addr.clone();
+                        let motd_prefix = motd_prefix.clone();
+                        opening.spawn(async move {
+                            let result = BridgedWorld::open(
+                                *host.ip(),
+                                &announcement,
+                                &lan_bind_addr,
+                                &motd_prefix,
+                            )
+                            .await;
+                            (host, announcement.motd, result)
+                        });
+                    }
+                }
+
+                if let Ok(mut snapshot) = snapshot.lock() {
+                    *snapshot = worlds
+                        .iter()
+                        .map(|(host, world)| world.info(host))
+                        .collect();
+                }
+            }
+
+            // 等待仍在建立的世界完成后一并关闭，避免遗留转发端口
+            while let Some(joined) = opening.join_next().await {
+                if let Ok((_, _, Ok(world))) = joined {
+                    world.close().await;
+                }
+            }
+            for (_, world) in worlds.drain() {
+                world.close().await;
+            }
+            if let Ok(mut snapshot) = snapshot.lock() {
+                snapshot.clear();
+            }
+        });
+
+        self.handle = Some(handle);
+        self.cancel_token = Some(cancel_token);
+        Ok(())
+    }
+
+    pub async fn stop(&mut self) {
+        if let Some(cancel_token) = self.cancel_token.take() {
+            cancel_token.cancel();
+        }
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.await;
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.handle.is_some()
+    }
+
+    #[frb(ignore)]
+    pub fn worlds(&self) -> Vec<MinecraftLanWorld> {
+        self.worlds
+            .lock()
+            .map(|worlds| worlds.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl RegistryEntry for MinecraftLanBridge {
+    #[frb(ignore)]
+    async fn stop(&mut self) {
+        MinecraftLanBridge::stop(self).await
+    }
+}
+
+/// 在虚拟网卡上加入 Minecraft 局域网组播组
+///
+/// 游戏客户端本身也会监听 4445 端口，因此需要开启地址复用。
+fn bind_lan_listener(virtual_ip: Ipv4Addr) -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    socket.set_reuse_port(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, MINECRAFT_LAN_PORT).into())?;
+    socket.join_multicast_v4(&MINECRAFT_LAN_GROUP, &virtual_ip)?;
+    UdpSocket::from_std(socket.into())
+}
+
+/// 解析虚拟网地址，未带前缀长度时使用默认前缀
+fn parse_virtual_net(virtual_ip: &str) -> Result<Ipv4Network, String> {
+    let text = virtual_ip.trim();
+    let (addr, prefix) = match text.split_once('/') {
+        Some((addr, prefix)) => (
+            addr,
+            prefix
+                .parse::<u8>()
+                .map_err(|e| format!("无效的前缀长度: {}, 错误: {}", virtual_ip, e))?,
+        ),
+        None => (text, DEFAULT_VIRTUAL_PREFIX_LEN),
+    };
+    let addr: Ipv4Addr = addr
+        .parse()
+        .map_err(|e| format!("无效的虚拟网 IP: {}, 错误: {}", virtual_ip, e))?;
+    Ipv4Network::new(addr, prefix)
+        .map_err(|e| format!("无效的虚拟网网段: {}, 错误: {}", virtual_ip, e))
+}
+
+// Flutter 友好的 API
+
+/// 启动 Minecraft 局域网桥接
+/// virtual_ip 为本机虚拟网 IP，可带前缀长度（如 10.126.126.1/24），缺省时按 /24 处理；
+/// lan_bind_addr 为物理局域网网卡地址（为空时使用默认路由）
+/// 返回桥接句柄
+pub fn start_minecraft_lan_bridge(
+    virtual_ip: String,
+    lan_bind_addr: Option<String>,
+) -> Result<String, String> {
+    let virtual_net = parse_virtual_net(&virtual_ip)?;
+
+    RT.block_on(async move {
+        let mut bridge = MinecraftLanBridge::new(virtual_net);
+        if let Some(addr) = lan_bind_addr.filter(|addr| !addr.trim().is_empty()) {
+            let addr = addr.trim();
+            bridge = if addr.contains(':') {
+                bridge.with_lan_bind_addr(addr)
+            } else {
+                bridge.with_lan_bind_addr(format!("{}:0", addr))
+            };
+        }
+
+        match bridge.start().await {
+            Ok(_) => {
+                let id = LAN_BRIDGES.insert(bridge).await;
+                println!("Minecraft 局域网桥接已启动: {}, 句柄: {}", virtual_net, id);
+                Ok(id)
+            }
+            Err(e) => Err(format!("启动局域网桥接失败: {}", e)),
+        }
+    })
+}
+
+/// 停止并移除指定的局域网桥接，同时关闭其创建的所有转发和广播
+pub fn stop_minecraft_lan_bridge(id: String) -> Result<(), String> {
+    RT.block_on(async move {
+        LAN_BRIDGES.remove(&id).await?;
+        println!("Minecraft 局域网桥接已停止，句柄: {}", id);
+        Ok(())
+    })
+}
+
+/// 列出指定桥接当前转发的局域网世界
+pub fn list_minecraft_lan_worlds(id: String) -> Result<Vec<MinecraftLanWorld>, String> {
+    RT.block_on(async move { LAN_BRIDGES.with(&id, |bridge| bridge.worlds()).await })
+}
@@ -8,3 +8,14 @@ pub mod forward;
 pub mod multicast;
 pub mod nat_test;
 pub mod magic_wall;
+pub mod registry;
+pub mod minecraft_lan;
+pub mod event_log;
+pub mod network_config;
+pub mod invite;
+pub mod instance_patch;
+pub mod supervisor;
+pub mod server_probe;
+pub mod relay_select;
+pub mod stun_server;
+pub mod backoff;
@@ -1,19 +1,30 @@
 use flutter_rust_bridge::frb;
+use socket2::{Domain, Protocol, Socket, Type};
 use tokio::io;
 use tokio::net::UdpSocket;
 use tokio::task::JoinHandle;
 use tokio::time::{interval, Duration};
 use tokio_util::sync::CancellationToken;
-use std::net::SocketAddr;
-use tokio::sync::Mutex;
+use std::collections::VecDeque;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use lazy_static::lazy_static;
 use tokio::runtime::Runtime;
 
+use super::backoff::{is_transient, Backoff};
+use super::registry::{HandleRegistry, RegistryEntry};
+use crate::frb_generated::StreamSink;
+
 lazy_static! {
     static ref RT: Runtime = Runtime::new().expect("创建 Tokio 运行时失败");
-    static ref MULTICAST_SENDERS: Mutex<Vec<MulticastSender>> = Mutex::new(Vec::new());
+    static ref MULTICAST_SENDERS: HandleRegistry<MulticastSender> = HandleRegistry::new();
+    static ref MULTICAST_LISTENERS: HandleRegistry<MulticastListener> = HandleRegistry::new();
 }
 
+/// 监听器默认保留的最近数据包数量
+const DEFAULT_PACKET_HISTORY: usize = 256;
+
 #[frb(opaque)]
 pub struct MulticastSender {
     multicast_addr: SocketAddr,
@@ -59,6 +70,14 @@ impl MulticastSender {
         }
 
         let socket = UdpSocket::bind(&self.bind_addr).await?;
+        // 绑定到具体网卡地址时，让组播从该网卡发出而不是走默认路由
+        if let (SocketAddr::V4(target), Ok(SocketAddr::V4(local))) =
+            (self.multicast_addr, socket.local_addr())
+        {
+            if target.ip().is_multicast() && !local.ip().is_unspecified() {
+                socket2::SockRef::from(&socket).set_multicast_if_v4(local.ip())?;
+            }
+        }
         let multicast_addr = self.multicast_addr;
         let data = self.data.clone();
         let interval_ms = self.interval_ms;
@@ -96,18 +115,380 @@ impl MulticastSender {
     pub fn is_running(&self) -> bool {
         self.handle.is_some()
     }
+
+    #[frb(ignore)]
+    pub fn info(&self, id: &str) -> MulticastSenderInfo {
+        MulticastSenderInfo {
+            id: id.to_string(),
+            multicast_addr: self.multicast_addr.to_string(),
+            bind_addr: self.bind_addr.clone(),
+            interval_ms: self.interval_ms,
+            data_len: self.data.len(),
+            is_running: self.is_running(),
+        }
+    }
+}
+
+impl RegistryEntry for MulticastSender {
+    #[frb(ignore)]
+    async fn stop(&mut self) {
+        MulticastSender::stop(self).await
+    }
+}
+
+/// 监听器收到的一个数据包
+#[derive(Debug, Clone)]
+pub struct MulticastPacket {
+    /// 发送方地址
+    pub source: String,
+    pub size: usize,
+    /// 接收时间（Unix 毫秒时间戳）
+    pub timestamp: i64,
+    pub data: Vec<u8>,
+}
+
+/// 组播/广播监听器，用于排查局域网发现报文是否穿过了虚拟网络
+#[frb(opaque)]
+pub struct MulticastListener {
+    group_addr: SocketAddr,
+    interface_addr: IpAddr,
+    capacity: usize,
+    recent: Arc<Mutex<VecDeque<MulticastPacket>>>,
+    sink: Arc<Mutex<Option<StreamSink<MulticastPacket>>>>,
+    received: Arc<AtomicU64>,
+    handle: Option<JoinHandle<()>>,
+    cancel_token: Option<CancellationToken>,
+}
+
+impl MulticastListener {
+    /// group_addr 为组播地址时加入该组；为广播地址或未指定地址时只监听端口
+    #[frb(ignore)]
+    pub fn new(group_addr: impl Into<String>, port: u16) -> io::Result<Self> {
+        let group_addr: String = group_addr.into();
+        let group_ip = group_addr
+            .trim()
+            .parse::<IpAddr>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let interface_addr = match group_ip {
+            IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        };
+
+        Ok(Self {
+            group_addr: SocketAddr::new(group_ip, port),
+            interface_addr,
+            capacity: DEFAULT_PACKET_HISTORY,
+            recent: Arc::new(Mutex::new(VecDeque::new())),
+            sink: Arc::new(Mutex::new(None)),
+            received: Arc::new(AtomicU64::new(0)),
+            handle: None,
+            cancel_token: None,
+        })
+    }
+
+    /// 指定加入组播组所使用的网卡地址，例如虚拟网 IP
+    #[frb(ignore)]
+    pub fn with_interface_addr(mut self, interface_addr: IpAddr) -> Self {
+        self.interface_addr = interface_addr;
+        self
+    }
+
+    /// 设置环形缓冲区保留的数据包数量
+    #[frb(ignore)]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// 设置接收数据包的推送目标，替换之前的订阅
+    #[frb(ignore)]
+    pub fn set_sink(&self, sink: StreamSink<MulticastPacket>) {
+        if let Ok(mut current) = self.sink.lock() {
+            *current = Some(sink);
+        }
+    }
+
+    pub async fn start(&mut self) -> io::Result<()> {
+        if self.handle.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "组播监听已启动",
+            ));
+        }
+
+        let socket = self.bind()?;
+        let capacity = self.capacity;
+        let recent = self.recent.clone();
+        let sink = self.sink.clone();
+        let received = self.received.clone();
+        let cancel_token = CancellationToken::new();
+        let cancel_token_clone = cancel_token.clone();
+
+        let handle = tokio::spawn(async move {
+            let mut buf = vec![0u8; 65535];
+            let mut backoff = Backoff::new();
+            loop {
+                tokio::select! {
+                    _ = cancel_token_clone.cancelled() => {
+                        break;
+                    }
+                    result = socket.recv_from(&mut buf) => {
+                        let (n, source) = match result {
+                            Ok(r) => {
+                                backoff.reset();
+                                r
+                            }
+                            Err(e) if is_transient(&e) => {
+                                println!("组播监听接收失败: {}", e);
+                                if !backoff.wait(&cancel_token_clone).await {
+                                    break;
+                                }
+                                continue;
+                            }
+                            Err(e) => {
+                                println!("组播监听套接字出错，停止监听: {}", e);
+                                break;
+                            }
+                        };
+                        let packet = MulticastPacket {
+                            source: source.to_string(),
+                            size: n,
+                            timestamp: chrono::Utc::now().timestamp_millis(),
+                            data: buf[..n].to_vec(),
+                        };
+
+                        received.fetch_add(1, Ordering::Relaxed);
+                        if let Ok(mut recent) = recent.lock() {
+                            if recent.len() >= capacity {
+                                recent.pop_front();
+                            }
+                            recent.push_back(packet.clone());
+                        }
+                        if let Ok(mut sink) = sink.lock() {
+                            // Dart 端关闭了 Stream 时放弃订阅，数据包仍会进入缓冲区
+                            if sink.as_ref().is_some_and(|s| s.add(packet).is_err()) {
+                                *sink = None;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        self.handle = Some(handle);
+        self.cancel_token = Some(cancel_token);
+        Ok(())
+    }
+
+    /// 创建监听套接字，开启地址复用以便与游戏本身共享端口
+    fn bind(&self) -> io::Result<UdpSocket> {
+        let domain = match self.group_addr {
+            SocketAddr::V4(_) => Domain::IPV4,
+            SocketAddr::V6(_) => Domain::IPV6,
+        };
+        let socket = Socket::new(domain, Type::DGRAM, Some(Protocol::UDP))?;
+        socket.set_reuse_address(true)?;
+        #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+        socket.set_reuse_port(true)?;
+        socket.set_nonblocking(true)?;
+
+        let port = self.group_addr.port();
+        match (self.group_addr.ip(), self.interface_addr) {
+            (IpAddr::V4(group), IpAddr::V4(interface)) => {
+                socket.set_broadcast(true)?;
+                let bind_addr = SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), port);
+                socket.bind(&bind_addr.into())?;
+                if group.is_multicast() {
+                    socket.join_multicast_v4(&group, &interface)?;
+                }
+            }
+            (IpAddr::V6(group), IpAddr::V6(interface)) => {
+                socket.set_only_v6(true)?;
+                let bind_addr = SocketAddr::new(Ipv6Addr::UNSPECIFIED.into(), port);
+                socket.bind(&bind_addr.into())?;
+                if group.is_multicast() {
+                    // IPv6 按网卡索引加入组播组，0 表示由系统选择
+                    let index = if interface.is_unspecified() {
+                        0
+                    } else {
+                        ipv6_interface_index(interface)?
+                    };
+                    socket.join_multicast_v6(&group, index)?;
+                }
+            }
+            (group, interface) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("网卡地址 {} 与组地址 {} 的地址族不一致", interface, group),
+                ));
+            }
+        }
+
+        UdpSocket::from_std(socket.into())
+    }
+
+    pub async fn stop(&mut self) {
+        if let Some(cancel_token) = self.cancel_token.take() {
+            cancel_token.cancel();
+        }
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.await;
+        }
+        if let Ok(mut sink) = self.sink.lock() {
+            *sink = None;
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.handle.is_some()
+    }
+
+    /// 获取最近收到的数据包，按接收顺序排列，limit 为空时返回全部
+    #[frb(ignore)]
+    pub fn recent_packets(&self, limit: Option<usize>) -> Vec<MulticastPacket> {
+        let Ok(recent) = self.recent.lock() else {
+            return Vec::new();
+        };
+        let skip = limit.map_or(0, |limit| recent.len().saturating_sub(limit));
+        recent.iter().skip(skip).cloned().collect()
+    }
+
+    #[frb(ignore)]
+    pub fn info(&self, id: &str) -> MulticastListenerInfo {
+        MulticastListenerInfo {
+            id: id.to_string(),
+            group_addr: self.group_addr.to_string(),
+            interface_addr: self.interface_addr.to_string(),
+            capacity: self.capacity,
+            packets_received: self.received.load(Ordering::Relaxed),
+            is_running: self.is_running(),
+        }
+    }
+}
+
+/// 查找绑定了指定 IPv6 地址的网卡索引
+#[cfg(unix)]
+fn ipv6_interface_index(addr: Ipv6Addr) -> io::Result<u32> {
+    let mut addrs: *mut libc::ifaddrs = std::ptr::null_mut();
+    if unsafe { libc::getifaddrs(&mut addrs) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mut index = 0;
+    let mut current = addrs;
+    while !current.is_null() {
+        let ifa = unsafe { &*current };
+        current = ifa.ifa_next;
+        if ifa.ifa_addr.is_null()
+            || i32::from(unsafe { (*ifa.ifa_addr).sa_family }) != libc::AF_INET6
+        {
+            continue;
+        }
+        let sin6 = unsafe { &*(ifa.ifa_addr as *const libc::sockaddr_in6) };
+        if Ipv6Addr::from(sin6.sin6_addr.s6_addr) == addr {
+            index = unsafe { libc::if_nametoindex(ifa.ifa_name) };
+            break;
+        }
+    }
+    unsafe { libc::freeifaddrs(addrs) };
+
+    if index == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("找不到地址为 {} 的网卡", addr),
+        ));
+    }
+    Ok(index)
+}
+
+/// 查找绑定了指定 IPv6 地址的网卡索引
+#[cfg(windows)]
+fn ipv6_interface_index(addr: Ipv6Addr) -> io::Result<u32> {
+    use windows::Win32::Foundation::{ERROR_BUFFER_OVERFLOW, NO_ERROR};
+    use windows::Win32::NetworkManagement::IpHelper::{
+        GetAdaptersAddresses, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER,
+        GAA_FLAG_SKIP_MULTICAST, IP_ADAPTER_ADDRESSES_LH,
+    };
+    use windows::Win32::Networking::WinSock::{AF_INET6, SOCKADDR_IN6};
+
+    let flags = GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_DNS_SERVER;
+    let mut size: u32 = 16 * 1024;
+    let mut buf: Vec<u64> = Vec::new();
+    // 缓冲区不足时按系统返回的大小重试
+    for _ in 0..3 {
+        buf = vec![0u64; (size as usize).div_ceil(8)];
+        let ret = unsafe {
+            GetAdaptersAddresses(
+                AF_INET6.0 as u32,
+                flags,
+                None,
+                Some(buf.as_mut_ptr() as *mut IP_ADAPTER_ADDRESSES_LH),
+                &mut size,
+            )
+        };
+        if ret == NO_ERROR.0 {
+            break;
+        }
+        if ret != ERROR_BUFFER_OVERFLOW.0 {
+            return Err(io::Error::from_raw_os_error(ret as i32));
+        }
+        buf.clear();
+    }
+    if buf.is_empty() {
+        return Err(io::Error::other("获取网卡列表失败"));
+    }
+
+    let mut adapter = buf.as_ptr() as *const IP_ADAPTER_ADDRESSES_LH;
+    while !adapter.is_null() {
+        let info = unsafe { &*adapter };
+        let mut unicast = info.FirstUnicastAddress;
+        while !unicast.is_null() {
+            let entry = unsafe { &*unicast };
+            let sockaddr = entry.Address.lpSockaddr;
+            if !sockaddr.is_null() && unsafe { (*sockaddr).sa_family } == AF_INET6 {
+                let sin6 = unsafe { &*(sockaddr as *const SOCKADDR_IN6) };
+                if Ipv6Addr::from(unsafe { sin6.sin6_addr.u.Byte }) == addr {
+                    return Ok(info.Ipv6IfIndex);
+                }
+            }
+            unicast = entry.Next;
+        }
+        adapter = info.Next;
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("找不到地址为 {} 的网卡", addr),
+    ))
+}
+
+impl RegistryEntry for MulticastListener {
+    #[frb(ignore)]
+    async fn stop(&mut self) {
+        MulticastListener::stop(self).await
+    }
 }
 
 // Flutter 友好的 API
 
+/// 组播发送器的状态快照
+pub struct MulticastSenderInfo {
+    pub id: String,
+    pub multicast_addr: String,
+    pub bind_addr: String,
+    pub interval_ms: u64,
+    pub data_len: usize,
+    pub is_running: bool,
+}
+
 /// 创建并启动一个组播发送器
-/// 返回发送器索引，用于后续操作
+/// 返回发送器句柄，用于后续操作
 pub fn create_multicast_sender(
     multicast_addr: String,
     port: u16,
     data: Vec<u8>,
     interval_ms: u64,
-) -> Result<usize, String> {
+) -> Result<String, String> {
     RT.block_on(async move {
         let mut sender = match MulticastSender::new(multicast_addr.clone(), port, data, interval_ms) {
             Ok(s) => s,
@@ -116,11 +497,9 @@ pub fn create_multicast_sender(
         
         match sender.start().await {
             Ok(_) => {
-                let mut senders = MULTICAST_SENDERS.lock().await;
-                senders.push(sender);
-                let index = senders.len() - 1;
-                println!("组播发送器已启动: {}:{}, 间隔: {}ms, 索引: {}", multicast_addr, port, interval_ms, index);
-                Ok(index)
+                let id = MULTICAST_SENDERS.insert(sender).await;
+                println!("组播发送器已启动: {}:{}, 间隔: {}ms, 句柄: {}", multicast_addr, port, interval_ms, id);
+                Ok(id)
             }
             Err(e) => Err(format!("启动组播发送器失败: {}", e))
         }
@@ -134,7 +513,7 @@ pub fn create_multicast_sender_with_bind(
     bind_addr: String,
     data: Vec<u8>,
     interval_ms: u64,
-) -> Result<usize, String> {
+) -> Result<String, String> {
     RT.block_on(async move {
         let mut sender = match MulticastSender::new(multicast_addr.clone(), port, data, interval_ms) {
             Ok(s) => s.with_bind_addr(bind_addr.clone()),
@@ -143,66 +522,194 @@ pub fn create_multicast_sender_with_bind(
         
         match sender.start().await {
             Ok(_) => {
-                let mut senders = MULTICAST_SENDERS.lock().await;
-                senders.push(sender);
-                let index = senders.len() - 1;
-                println!("组播发送器已启动: {}:{}, 绑定: {}, 间隔: {}ms, 索引: {}",
-                    multicast_addr, port, bind_addr, interval_ms, index);
-                Ok(index)
+                let id = MULTICAST_SENDERS.insert(sender).await;
+                println!("组播发送器已启动: {}:{}, 绑定: {}, 间隔: {}ms, 句柄: {}",
+                    multicast_addr, port, bind_addr, interval_ms, id);
+                Ok(id)
             }
             Err(e) => Err(format!("启动组播发送器失败: {}", e))
         }
     })
 }
 
-/// 停止指定索引的组播发送器
-pub fn stop_multicast_sender(index: usize) -> Result<(), String> {
+/// 停止指定的组播发送器，记录保留以便查询
+pub fn stop_multicast_sender(id: String) -> Result<(), String> {
     RT.block_on(async move {
-        let mut senders = MULTICAST_SENDERS.lock().await;
-        
-        if index >= senders.len() {
-            return Err(format!("无效的发送器索引: {}", index));
-        }
-        
-        senders[index].stop().await;
-        println!("组播发送器已停止，索引: {}", index);
+        MULTICAST_SENDERS.stop(&id).await?;
+        println!("组播发送器已停止，句柄: {}", id);
         Ok(())
     })
 }
 
-/// 停止所有组播发送器
+/// 停止并移除指定的组播发送器
+pub fn remove_multicast_sender(id: String) -> Result<(), String> {
+    RT.block_on(async move {
+        MULTICAST_SENDERS.remove(&id).await?;
+        println!("组播发送器已移除，句柄: {}", id);
+        Ok(())
+    })
+}
+
+/// 停止并移除所有组播发送器
 pub fn stop_all_multicast_senders() -> Result<(), String> {
     RT.block_on(async move {
-        let mut senders = MULTICAST_SENDERS.lock().await;
-        
-        for (index, sender) in senders.iter_mut().enumerate() {
-            sender.stop().await;
-            println!("组播发送器已停止，索引: {}", index);
+        for id in MULTICAST_SENDERS.remove_all().await {
+            println!("组播发送器已停止，句柄: {}", id);
         }
-        
-        senders.clear();
         println!("所有组播发送器已停止");
         Ok(())
     })
 }
 
-/// 获取所有正在运行的组播发送器数量
-pub fn get_multicast_sender_count() -> usize {
+/// 列出所有组播发送器及其状态
+pub fn list_multicast_senders() -> Vec<MulticastSenderInfo> {
     RT.block_on(async move {
-        let senders = MULTICAST_SENDERS.lock().await;
-        senders.len()
+        MULTICAST_SENDERS
+            .lock()
+            .await
+            .iter()
+            .map(|entry| entry.value.info(&entry.id))
+            .collect()
     })
 }
 
+/// 获取已注册的组播发送器数量
+pub fn get_multicast_sender_count() -> usize {
+    RT.block_on(async move { MULTICAST_SENDERS.len().await })
+}
+
 /// 检查指定发送器是否正在运行
-pub fn is_multicast_sender_running(index: usize) -> bool {
+pub fn is_multicast_sender_running(id: String) -> bool {
     RT.block_on(async move {
-        let senders = MULTICAST_SENDERS.lock().await;
-        
-        if index >= senders.len() {
-            return false;
+        MULTICAST_SENDERS
+            .with(&id, |sender| sender.is_running())
+            .await
+            .unwrap_or(false)
+    })
+}
+
+/// 组播监听器的状态快照
+pub struct MulticastListenerInfo {
+    pub id: String,
+    pub group_addr: String,
+    pub interface_addr: String,
+    pub capacity: usize,
+    pub packets_received: u64,
+    pub is_running: bool,
+}
+
+/// 创建并启动一个组播/广播监听器
+/// group_addr 可以是组播地址，也可以是 255.255.255.255 或 0.0.0.0 以监听广播；
+/// interface_addr 为加入组播组所用的网卡地址，为空时由系统选择
+/// 返回监听器句柄，收到的数据包可通过 subscribe_multicast_listener 订阅
+pub fn create_multicast_listener(
+    group_addr: String,
+    port: u16,
+    interface_addr: Option<String>,
+    capacity: Option<usize>,
+) -> Result<String, String> {
+    let interface_addr = match interface_addr.filter(|addr| !addr.trim().is_empty()) {
+        Some(addr) => Some(
+            addr.trim()
+                .parse::<IpAddr>()
+                .map_err(|e| format!("无效的网卡地址: {}, 错误: {}", addr, e))?,
+        ),
+        None => None,
+    };
+
+    RT.block_on(async move {
+        let mut listener = match MulticastListener::new(group_addr.clone(), port) {
+            Ok(l) => l,
+            Err(e) => return Err(format!("创建组播监听器失败: {}", e)),
+        };
+        if let Some(interface_addr) = interface_addr {
+            listener = listener.with_interface_addr(interface_addr);
         }
-        
-        senders[index].is_running()
+        if let Some(capacity) = capacity {
+            listener = listener.with_capacity(capacity);
+        }
+
+        match listener.start().await {
+            Ok(_) => {
+                let id = MULTICAST_LISTENERS.insert(listener).await;
+                println!("组播监听器已启动: {}:{}, 句柄: {}", group_addr, port, id);
+                Ok(id)
+            }
+            Err(e) => Err(format!("启动组播监听器失败: {}", e)),
+        }
+    })
+}
+
+/// 订阅监听器收到的数据包，新的订阅会替换旧的订阅
+pub fn subscribe_multicast_listener(
+    id: String,
+    sink: StreamSink<MulticastPacket>,
+) -> Result<(), String> {
+    RT.block_on(async move {
+        MULTICAST_LISTENERS
+            .with(&id, |listener| listener.set_sink(sink))
+            .await
     })
 }
+
+/// 获取监听器缓冲区中最近的数据包
+pub fn get_multicast_listener_packets(
+    id: String,
+    limit: Option<usize>,
+) -> Result<Vec<MulticastPacket>, String> {
+    RT.block_on(async move {
+        MULTICAST_LISTENERS
+            .with(&id, |listener| listener.recent_packets(limit))
+            .await
+    })
+}
+
+/// 停止指定的组播监听器，缓冲区保留以便查询
+pub fn stop_multicast_listener(id: String) -> Result<(), String> {
+    RT.block_on(async move {
+        MULTICAST_LISTENERS.stop(&id).await?;
+        println!("组播监听器已停止，句柄: {}", id);
+        Ok(())
+    })
+}
+
+/// 停止并移除指定的组播监听器
+pub fn remove_multicast_listener(id: String) -> Result<(), String> {
+    RT.block_on(async move {
+        MULTICAST_LISTENERS.remove(&id).await?;
+        println!("组播监听器已移除，句柄: {}", id);
+        Ok(())
+    })
+}
+
+/// 列出所有组播监听器及其状态
+pub fn list_multicast_listeners() -> Vec<MulticastListenerInfo> {
+    RT.block_on(async move {
+        MULTICAST_LISTENERS
+            .lock()
+            .await
+            .iter()
+            .map(|entry| entry.value.info(&entry.id))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn interface_family_must_match_group() {
+        for (group, interface) in [
+            ("224.0.2.60", IpAddr::V6(Ipv6Addr::LOCALHOST)),
+            ("ff02::1", IpAddr::V4(Ipv4Addr::LOCALHOST)),
+        ] {
+            let mut listener = MulticastListener::new(group, 0)
+                .unwrap()
+                .with_interface_addr(interface);
+            let err = listener.start().await.unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+            assert!(!listener.is_running());
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use easytier::common::stun::{StunInfoCollector, UdpNatTypeDetector};
 use easytier::proto::common::NatType as EasyNatType;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::time::{Duration, Instant};
-use tokio::net::UdpSocket;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpSocket, UdpSocket};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum NatType {
@@ -36,6 +38,125 @@ pub struct NetworkTestResult {
     pub nat_type_v6: String,
     pub ipv4_latency: i64,
     pub ipv6_latency: i64,
+    /// EasyTier's NAT type, e.g. `SymmetricEasyInc`; `None` if detection failed.
+    pub nat_type_v4_raw: Option<String>,
+    pub error_v4: Option<String>,
+    pub error_v6: Option<String>,
+    /// Public `ip:port` seen by the first STUN server that answered.
+    pub public_endpoint_v4: Option<String>,
+    pub public_endpoint_v6: Option<String>,
+    pub stun_servers: Vec<StunServerResult>,
+    /// Expected outcome of UDP hole punching against each NAT type, based
+    /// on the local IPv4 NAT type.
+    pub hole_punch: Vec<HolePunchPrediction>,
+}
+
+/// Outcome of a single binding request to one STUN server.
+#[derive(Debug, Clone)]
+pub struct StunServerResult {
+    pub server: String,
+    /// `ipv4` or `ipv6`.
+    pub ip_version: String,
+    pub success: bool,
+    pub latency: i64,
+    pub mapped_addr: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HolePunchChance {
+    Likely,
+    /// Needs port prediction and may take several attempts.
+    Possible,
+    /// Traffic will most likely go through a relay.
+    Unlikely,
+}
+
+#[derive(Debug, Clone)]
+pub struct HolePunchPrediction {
+    /// EasyTier NAT type of the peer, e.g. `PortRestricted`.
+    pub peer_nat_type: String,
+    pub chance: HolePunchChance,
+}
+
+/// How the NAT picks the public endpoint of an outgoing flow (RFC 5780 §4.3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappingBehavior {
+    /// The mapped address is the local address.
+    NoNat,
+    EndpointIndependent,
+    AddressDependent,
+    AddressAndPortDependent,
+    Unknown,
+}
+
+/// Which inbound packets the NAT lets through to a mapping (RFC 5780 §4.4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilteringBehavior {
+    EndpointIndependent,
+    AddressDependent,
+    AddressAndPortDependent,
+    Unknown,
+}
+
+/// RFC 5780 behavior of one protocol and address family.
+///
+/// Mapping and filtering need a server that reports `OTHER-ADDRESS`; against
+/// other servers they stay `Unknown` but the mapped address is still known.
+/// Filtering is never tested over TCP, where `CHANGE-REQUEST` isn't defined.
+#[derive(Debug, Clone)]
+pub struct NatBehaviorResult {
+    /// `udp` or `tcp`.
+    pub protocol: String,
+    /// `ipv4` or `ipv6`.
+    pub ip_version: String,
+    pub server: Option<String>,
+    pub local_addr: Option<String>,
+    pub mapped_addr: Option<String>,
+    pub rtt_ms: Option<i64>,
+    pub mapping: MappingBehavior,
+    pub filtering: FilteringBehavior,
+    pub hairpinning: Option<bool>,
+    pub port_preserved: Option<bool>,
+    pub error: Option<String>,
+}
+
+impl NatBehaviorResult {
+    fn new(protocol: &str, server: Option<SocketAddr>, ipv6: bool) -> Self {
+        Self {
+            protocol: protocol.to_string(),
+            ip_version: if ipv6 { "ipv6" } else { "ipv4" }.to_string(),
+            server: server.map(|addr| addr.to_string()),
+            local_addr: None,
+            mapped_addr: None,
+            rtt_ms: None,
+            mapping: MappingBehavior::Unknown,
+            filtering: FilteringBehavior::Unknown,
+            hairpinning: None,
+            port_preserved: None,
+            error: None,
+        }
+    }
+
+    fn fail(mut self, error: impl ToString) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    /// Maps the behavior onto the classic RFC 3489 NAT types.
+    fn classic_nat_type(&self) -> NatType {
+        use FilteringBehavior as F;
+        use MappingBehavior as M;
+        match (self.mapping, self.filtering) {
+            (M::NoNat, F::EndpointIndependent | F::Unknown) => NatType::OpenInternet,
+            (M::NoNat, _) => NatType::SymmetricUdpFirewall,
+            (M::EndpointIndependent, F::EndpointIndependent) => NatType::FullCone,
+            (M::EndpointIndependent, F::AddressDependent) => NatType::RestrictedCone,
+            (M::EndpointIndependent, F::AddressAndPortDependent) => NatType::PortRestrictedCone,
+            (M::AddressDependent | M::AddressAndPortDependent, _) => NatType::Symmetric,
+            _ => NatType::Unknown,
+        }
+    }
 }
 
 fn normalize_stun_server(input: &str) -> String {
@@ -89,7 +210,7 @@ fn map_easy_nat_type(nat: EasyNatType) -> NatType {
     }
 }
 
-async fn detect_nat_type_ipv4(stun_server: &str) -> Result<NatType, String> {
+async fn detect_nat_type_ipv4(stun_server: &str) -> Result<EasyNatType, String> {
     let stun_servers = build_udp_stun_server_list(stun_server);
     if stun_servers.is_empty() {
         return Err("No STUN servers available".to_string());
@@ -101,83 +222,616 @@ async fn detect_nat_type_ipv4(stun_server: &str) -> Result<NatType, String> {
         .map_err(|_| "NAT detection timeout".to_string())
         .and_then(|ret| ret.map_err(|e| format!("NAT detection failed: {e}")))?;
 
-    Ok(map_easy_nat_type(detect_result.nat_type()))
+    Ok(detect_result.nat_type())
+}
+
+/// NAT types as far as hole punching is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PunchClass {
+    /// Accepts inbound traffic from anyone once mapped.
+    Open,
+    Restricted,
+    PortRestricted,
+    /// Symmetric with predictable port allocation.
+    EasySymmetric,
+    HardSymmetric,
+    Unknown,
+}
+
+fn punch_class(nat: EasyNatType) -> PunchClass {
+    match nat {
+        EasyNatType::OpenInternet | EasyNatType::NoPat | EasyNatType::FullCone => PunchClass::Open,
+        EasyNatType::Restricted => PunchClass::Restricted,
+        EasyNatType::PortRestricted | EasyNatType::SymUdpFirewall => PunchClass::PortRestricted,
+        EasyNatType::SymmetricEasyInc | EasyNatType::SymmetricEasyDec => PunchClass::EasySymmetric,
+        EasyNatType::Symmetric => PunchClass::HardSymmetric,
+        EasyNatType::Unknown => PunchClass::Unknown,
+    }
+}
+
+fn predict_hole_punch(local: EasyNatType, peer: EasyNatType) -> HolePunchChance {
+    use PunchClass::*;
+    match (punch_class(local), punch_class(peer)) {
+        (Open, _) | (_, Open) => HolePunchChance::Likely,
+        (Unknown, _) | (_, Unknown) => HolePunchChance::Possible,
+        // a hard symmetric peer reaches a restricted side only if that side has
+        // already sent to the peer's address, and its own filter usually drops
+        // the reply unless it hits the freshly allocated port
+        (Restricted, HardSymmetric) | (HardSymmetric, Restricted) => HolePunchChance::Possible,
+        (Restricted, _) | (_, Restricted) => HolePunchChance::Likely,
+        (PortRestricted, PortRestricted) => HolePunchChance::Likely,
+        (PortRestricted | EasySymmetric, EasySymmetric) | (EasySymmetric, PortRestricted) => {
+            HolePunchChance::Possible
+        }
+        _ => HolePunchChance::Unlikely,
+    }
+}
+
+/// Predictions against every NAT type; all unlikely when UDP is blocked.
+fn hole_punch_matrix(local: Option<EasyNatType>) -> Vec<HolePunchPrediction> {
+    [
+        EasyNatType::OpenInternet,
+        EasyNatType::NoPat,
+        EasyNatType::FullCone,
+        EasyNatType::Restricted,
+        EasyNatType::PortRestricted,
+        EasyNatType::SymUdpFirewall,
+        EasyNatType::SymmetricEasyInc,
+        EasyNatType::SymmetricEasyDec,
+        EasyNatType::Symmetric,
+    ]
+    .into_iter()
+    .map(|peer| HolePunchPrediction {
+        peer_nat_type: format!("{:?}", peer),
+        chance: local.map_or(HolePunchChance::Unlikely, |local| {
+            predict_hole_punch(local, peer)
+        }),
+    })
+    .collect()
+}
+
+const STUN_MAGIC_COOKIE: u32 = 0x2112_A442;
+pub(crate) const STUN_BINDING_REQUEST: u16 = 0x0001;
+pub(crate) const STUN_BINDING_SUCCESS: u16 = 0x0101;
+pub(crate) const ATTR_MAPPED_ADDRESS: u16 = 0x0001;
+pub(crate) const ATTR_CHANGE_REQUEST: u16 = 0x0003;
+pub(crate) const ATTR_CHANGED_ADDRESS: u16 = 0x0005;
+pub(crate) const ATTR_XOR_MAPPED_ADDRESS: u16 = 0x0020;
+pub(crate) const ATTR_RESPONSE_ORIGIN: u16 = 0x802B;
+pub(crate) const ATTR_OTHER_ADDRESS: u16 = 0x802C;
+const CHANGE_IP: u32 = 0x04;
+const CHANGE_PORT: u32 = 0x02;
+
+/// Timeout of one UDP transmission; requests are sent `UDP_ATTEMPTS` times.
+const UDP_ATTEMPT_TIMEOUT: Duration = Duration::from_millis(1000);
+const UDP_ATTEMPTS: usize = 2;
+const TCP_TIMEOUT: Duration = Duration::from_secs(3);
+const HAIRPIN_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// A STUN message with raw attributes, enough for binding requests and
+/// responses with the RFC 5780 attributes.
+#[derive(Debug, Clone)]
+pub(crate) struct StunMessage {
+    pub(crate) msg_type: u16,
+    pub(crate) tid: [u8; 12],
+    pub(crate) attrs: Vec<(u16, Vec<u8>)>,
 }
 
-fn create_binding_request() -> [u8; 20] {
-    let mut request = [0u8; 20];
-    request[0] = 0x00;
-    request[1] = 0x01; // Binding Request
-    request[2] = 0x00;
-    request[3] = 0x00; // Message Length
-    request[4] = 0x21;
-    request[5] = 0x12;
-    request[6] = 0xA4;
-    request[7] = 0x42; // Magic Cookie
+impl StunMessage {
+    pub(crate) fn new(msg_type: u16, tid: [u8; 12]) -> Self {
+        Self {
+            msg_type,
+            tid,
+            attrs: Vec::new(),
+        }
+    }
+
+    pub(crate) fn binding_request() -> Self {
+        Self::new(STUN_BINDING_REQUEST, rand::random())
+    }
+
+    /// Parses a message, or returns `None` if `data` isn't a STUN message.
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 20 || data[0] & 0xC0 != 0 {
+            return None;
+        }
+        if u32::from_be_bytes([data[4], data[5], data[6], data[7]]) != STUN_MAGIC_COOKIE {
+            return None;
+        }
+        let len = u16::from_be_bytes([data[2], data[3]]) as usize;
+        let body = data.get(20..20 + len)?;
+
+        let mut message = Self::new(
+            u16::from_be_bytes([data[0], data[1]]),
+            data[8..20].try_into().ok()?,
+        );
+        let mut rest = body;
+        while rest.len() >= 4 {
+            let attr_type = u16::from_be_bytes([rest[0], rest[1]]);
+            let attr_len = u16::from_be_bytes([rest[2], rest[3]]) as usize;
+            let value = rest.get(4..4 + attr_len)?;
+            message.attrs.push((attr_type, value.to_vec()));
+            let padded = (4 + attr_len + 3) & !3;
+            rest = rest.get(padded..).unwrap_or_default();
+        }
+        Some(message)
+    }
+
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (attr_type, value) in &self.attrs {
+            body.extend_from_slice(&attr_type.to_be_bytes());
+            body.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            body.extend_from_slice(value);
+            body.resize((body.len() + 3) & !3, 0);
+        }
+
+        let mut data = Vec::with_capacity(20 + body.len());
+        data.extend_from_slice(&self.msg_type.to_be_bytes());
+        data.extend_from_slice(&(body.len() as u16).to_be_bytes());
+        data.extend_from_slice(&STUN_MAGIC_COOKIE.to_be_bytes());
+        data.extend_from_slice(&self.tid);
+        data.extend_from_slice(&body);
+        data
+    }
+
+    pub(crate) fn attr(&self, attr_type: u16) -> Option<&[u8]> {
+        self.attrs
+            .iter()
+            .find(|(t, _)| *t == attr_type)
+            .map(|(_, value)| value.as_slice())
+    }
+
+    /// Adds an address attribute, XOR-encoded for `XOR-MAPPED-ADDRESS`.
+    pub(crate) fn add_address(&mut self, attr_type: u16, addr: SocketAddr) {
+        let addr = if attr_type == ATTR_XOR_MAPPED_ADDRESS {
+            xor_address(addr, &self.tid)
+        } else {
+            addr
+        };
+        let mut value = vec![0];
+        match addr.ip() {
+            IpAddr::V4(ip) => {
+                value.push(0x01);
+                value.extend_from_slice(&addr.port().to_be_bytes());
+                value.extend_from_slice(&ip.octets());
+            }
+            IpAddr::V6(ip) => {
+                value.push(0x02);
+                value.extend_from_slice(&addr.port().to_be_bytes());
+                value.extend_from_slice(&ip.octets());
+            }
+        }
+        self.attrs.push((attr_type, value));
+    }
+
+    pub(crate) fn address(&self, attr_type: u16) -> Option<SocketAddr> {
+        let value = self.attr(attr_type)?;
+        let port = u16::from_be_bytes([*value.get(2)?, *value.get(3)?]);
+        let ip: IpAddr = match value.get(1)? {
+            0x01 => Ipv4Addr::from(<[u8; 4]>::try_from(value.get(4..8)?).ok()?).into(),
+            0x02 => Ipv6Addr::from(<[u8; 16]>::try_from(value.get(4..20)?).ok()?).into(),
+            _ => return None,
+        };
+        let addr = SocketAddr::new(ip, port);
+        if attr_type == ATTR_XOR_MAPPED_ADDRESS {
+            Some(xor_address(addr, &self.tid))
+        } else {
+            Some(addr)
+        }
+    }
+
+    /// The reflexive address, preferring `XOR-MAPPED-ADDRESS`.
+    pub(crate) fn mapped_address(&self) -> Option<SocketAddr> {
+        self.address(ATTR_XOR_MAPPED_ADDRESS)
+            .or_else(|| self.address(ATTR_MAPPED_ADDRESS))
+    }
+
+    /// The server's alternate address, `OTHER-ADDRESS` or the RFC 3489
+    /// `CHANGED-ADDRESS`.
+    pub(crate) fn other_address(&self) -> Option<SocketAddr> {
+        self.address(ATTR_OTHER_ADDRESS)
+            .or_else(|| self.address(ATTR_CHANGED_ADDRESS))
+    }
+
+    pub(crate) fn add_change_request(&mut self, change_ip: bool, change_port: bool) {
+        let mut flags = 0;
+        if change_ip {
+            flags |= CHANGE_IP;
+        }
+        if change_port {
+            flags |= CHANGE_PORT;
+        }
+        self.attrs
+            .push((ATTR_CHANGE_REQUEST, flags.to_be_bytes().to_vec()));
+    }
+
+    /// The `(change_ip, change_port)` flags of a request.
+    pub(crate) fn change_request(&self) -> (bool, bool) {
+        let flags = self
+            .attr(ATTR_CHANGE_REQUEST)
+            .and_then(|value| value.get(..4))
+            .map(|value| u32::from_be_bytes([value[0], value[1], value[2], value[3]]))
+            .unwrap_or(0);
+        (flags & CHANGE_IP != 0, flags & CHANGE_PORT != 0)
+    }
+}
 
-    let now_nanos = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_nanos())
-        .unwrap_or(0);
-    let tid_seed = now_nanos.to_be_bytes();
-    request[8..20].copy_from_slice(&tid_seed[4..16]);
-    request
+/// XORs an address with the magic cookie (and transaction id for IPv6).
+/// Applying it twice gives back the original address.
+fn xor_address(addr: SocketAddr, tid: &[u8; 12]) -> SocketAddr {
+    let port = addr.port() ^ (STUN_MAGIC_COOKIE >> 16) as u16;
+    match addr.ip() {
+        IpAddr::V4(ip) => SocketAddr::new(
+            Ipv4Addr::from(u32::from(ip) ^ STUN_MAGIC_COOKIE).into(),
+            port,
+        ),
+        IpAddr::V6(ip) => {
+            let mut key = [0u8; 16];
+            key[..4].copy_from_slice(&STUN_MAGIC_COOKIE.to_be_bytes());
+            key[4..].copy_from_slice(tid);
+            let mut octets = ip.octets();
+            for (byte, k) in octets.iter_mut().zip(key) {
+                *byte ^= k;
+            }
+            SocketAddr::new(Ipv6Addr::from(octets).into(), port)
+        }
+    }
 }
 
-fn is_success_stun_response(data: &[u8]) -> bool {
-    if data.len() < 20 {
-        return false;
+fn unspecified_addr(ipv6: bool, port: u16) -> SocketAddr {
+    if ipv6 {
+        SocketAddr::new(Ipv6Addr::UNSPECIFIED.into(), port)
+    } else {
+        SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), port)
     }
+}
+
+async fn resolve_stun_server(stun_server: &str, ipv4: bool) -> Option<SocketAddr> {
+    let server = normalize_stun_server(stun_server);
+    let mut addrs = tokio::net::lookup_host(&server).await.ok()?;
+    addrs.find(|addr| addr.is_ipv4() == ipv4)
+}
 
-    let is_binding_success = data[0] == 0x01 && data[1] == 0x01;
-    let has_magic_cookie = data[4] == 0x21 && data[5] == 0x12 && data[6] == 0xA4 && data[7] == 0x42;
+/// The local address the OS would use to reach `server`.
+async fn local_ip_towards(server: SocketAddr) -> Option<IpAddr> {
+    let socket = UdpSocket::bind(unspecified_addr(server.is_ipv6(), 0))
+        .await
+        .ok()?;
+    socket.connect(server).await.ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
 
-    is_binding_success && has_magic_cookie
+struct StunExchange {
+    response: StunMessage,
+    rtt: Duration,
 }
 
-async fn test_udp_ipv4(stun_server: &str) -> (bool, i64, NatType) {
+async fn udp_exchange(
+    socket: &UdpSocket,
+    server: SocketAddr,
+    change_ip: bool,
+    change_port: bool,
+) -> Result<StunExchange, String> {
+    let mut request = StunMessage::binding_request();
+    if change_ip || change_port {
+        request.add_change_request(change_ip, change_port);
+    }
+    let data = request.encode();
     let start = Instant::now();
+    let mut buf = [0u8; 1500];
+
+    for _ in 0..UDP_ATTEMPTS {
+        socket
+            .send_to(&data, server)
+            .await
+            .map_err(|e| format!("send failed: {e}"))?;
+        let deadline = tokio::time::Instant::now() + UDP_ATTEMPT_TIMEOUT;
+        while let Ok(recv) = tokio::time::timeout_at(deadline, socket.recv_from(&mut buf)).await {
+            let (len, _) = recv.map_err(|e| format!("receive failed: {e}"))?;
+            if let Some(response) = StunMessage::parse(&buf[..len]) {
+                if response.tid == request.tid && response.msg_type == STUN_BINDING_SUCCESS {
+                    return Ok(StunExchange {
+                        response,
+                        rtt: start.elapsed(),
+                    });
+                }
+            }
+        }
+    }
+    Err("no response".to_string())
+}
+
+/// Sends a binding request from a second socket to `mapped` and checks
+/// whether it comes back through the NAT to `socket`.
+async fn udp_hairpinning(socket: &UdpSocket, mapped: SocketAddr) -> Option<bool> {
+    let sender = UdpSocket::bind(unspecified_addr(mapped.is_ipv6(), 0))
+        .await
+        .ok()?;
+    let request = StunMessage::binding_request();
+    sender.send_to(&request.encode(), mapped).await.ok()?;
+
+    let deadline = tokio::time::Instant::now() + HAIRPIN_TIMEOUT;
+    let mut buf = [0u8; 1500];
+    loop {
+        match tokio::time::timeout_at(deadline, socket.recv_from(&mut buf)).await {
+            Ok(Ok((len, _))) => {
+                if StunMessage::parse(&buf[..len]).is_some_and(|m| m.tid == request.tid) {
+                    return Some(true);
+                }
+            }
+            Ok(Err(_)) => return None,
+            Err(_) => return Some(false),
+        }
+    }
+}
+
+/// Filtering tests II and III. They run on a fresh socket, as the mapping
+/// tests have already sent to the alternate address, which would let its
+/// responses through.
+async fn udp_filtering(server: SocketAddr) -> FilteringBehavior {
+    let Ok(socket) = UdpSocket::bind(unspecified_addr(server.is_ipv6(), 0)).await else {
+        return FilteringBehavior::Unknown;
+    };
+    if udp_exchange(&socket, server, false, false).await.is_err() {
+        return FilteringBehavior::Unknown;
+    }
+    if udp_exchange(&socket, server, true, true).await.is_ok() {
+        FilteringBehavior::EndpointIndependent
+    } else if udp_exchange(&socket, server, false, true).await.is_ok() {
+        FilteringBehavior::AddressDependent
+    } else {
+        FilteringBehavior::AddressAndPortDependent
+    }
+}
+
+async fn udp_nat_behavior(server: SocketAddr) -> NatBehaviorResult {
+    let mut result = NatBehaviorResult::new("udp", Some(server), server.is_ipv6());
+    let socket = match UdpSocket::bind(unspecified_addr(server.is_ipv6(), 0)).await {
+        Ok(socket) => socket,
+        Err(e) => return result.fail(format!("bind failed: {e}")),
+    };
+    let local_port = socket.local_addr().map(|addr| addr.port()).unwrap_or(0);
+    let local_ip = local_ip_towards(server).await;
+    result.local_addr = local_ip.map(|ip| SocketAddr::new(ip, local_port).to_string());
+
+    // Test I: plain binding request to the primary address
+    let first = match udp_exchange(&socket, server, false, false).await {
+        Ok(exchange) => exchange,
+        Err(e) => return result.fail(e),
+    };
+    result.rtt_ms = Some(first.rtt.as_millis() as i64);
+    let Some(mapped) = first.response.mapped_address() else {
+        return result.fail("response carries no mapped address");
+    };
+    result.mapped_addr = Some(mapped.to_string());
+    result.port_preserved = Some(mapped.port() == local_port);
+    let other = first.response.other_address();
+
+    // Tests II and III: same flow to the alternate IP, then alternate IP and port
+    result.mapping = if local_ip == Some(mapped.ip()) {
+        MappingBehavior::NoNat
+    } else if let Some(other) = other {
+        let alt_ip = SocketAddr::new(other.ip(), server.port());
+        match udp_exchange(&socket, alt_ip, false, false).await {
+            Ok(second) => match second.response.mapped_address() {
+                Some(mapped2) if mapped2 == mapped => MappingBehavior::EndpointIndependent,
+                Some(mapped2) => match udp_exchange(&socket, other, false, false).await {
+                    Ok(third) if third.response.mapped_address() == Some(mapped2) => {
+                        MappingBehavior::AddressDependent
+                    }
+                    Ok(_) => MappingBehavior::AddressAndPortDependent,
+                    Err(_) => MappingBehavior::Unknown,
+                },
+                None => MappingBehavior::Unknown,
+            },
+            Err(_) => MappingBehavior::Unknown,
+        }
+    } else {
+        MappingBehavior::Unknown
+    };
 
-    match detect_nat_type_ipv4(stun_server).await {
-        Ok(nat_type) => (true, start.elapsed().as_millis() as i64, nat_type),
-        Err(_) => (false, -1, NatType::Blocked),
+    if other.is_some() {
+        result.filtering = udp_filtering(server).await;
     }
+
+    result.hairpinning = udp_hairpinning(&socket, mapped).await;
+    result
+}
+
+fn tcp_socket(local: SocketAddr) -> std::io::Result<TcpSocket> {
+    let socket = if local.is_ipv4() {
+        TcpSocket::new_v4()?
+    } else {
+        TcpSocket::new_v6()?
+    };
+    // the mapping tests connect to several servers from the same local port
+    socket.set_reuseaddr(true)?;
+    #[cfg(unix)]
+    socket.set_reuseport(true)?;
+    socket.bind(local)?;
+    Ok(socket)
 }
 
-async fn test_udp_ipv6(stun_server: &str) -> (bool, i64, NatType) {
+/// Runs a binding request over a new TCP connection from `local`.
+async fn tcp_exchange(
+    local: SocketAddr,
+    server: SocketAddr,
+) -> Result<(StunExchange, SocketAddr), String> {
     let start = Instant::now();
-    let server = normalize_stun_server(stun_server);
+    let socket = tcp_socket(local).map_err(|e| format!("bind failed: {e}"))?;
+    let mut stream = tokio::time::timeout(TCP_TIMEOUT, socket.connect(server))
+        .await
+        .map_err(|_| "connect timeout".to_string())?
+        .map_err(|e| format!("connect failed: {e}"))?;
+    let local_addr = stream.local_addr().map_err(|e| e.to_string())?;
+
+    let request = StunMessage::binding_request();
+    let response = tokio::time::timeout(TCP_TIMEOUT, async {
+        stream.write_all(&request.encode()).await?;
+        let mut data = vec![0u8; 20];
+        stream.read_exact(&mut data).await?;
+        let len = u16::from_be_bytes([data[2], data[3]]) as usize;
+        data.resize(20 + len, 0);
+        stream.read_exact(&mut data[20..]).await?;
+        Ok::<_, std::io::Error>(data)
+    })
+    .await
+    .map_err(|_| "no response".to_string())?
+    .map_err(|e| format!("exchange failed: {e}"))?;
+
+    let response = StunMessage::parse(&response)
+        .filter(|m| m.tid == request.tid && m.msg_type == STUN_BINDING_SUCCESS)
+        .ok_or_else(|| "invalid response".to_string())?;
+    Ok((
+        StunExchange {
+            response,
+            rtt: start.elapsed(),
+        },
+        local_addr,
+    ))
+}
+
+/// Listens on `local` and checks whether a connection to its mapped address
+/// from a second socket loops back through the NAT.
+async fn tcp_hairpinning(local: SocketAddr, mapped: SocketAddr) -> Option<bool> {
+    let listener = tcp_socket(local).ok()?.listen(1).ok()?;
+    let connector = tcp_socket(unspecified_addr(mapped.is_ipv6(), 0)).ok()?;
+    let (accepted, _) = tokio::join!(
+        tokio::time::timeout(HAIRPIN_TIMEOUT, listener.accept()),
+        tokio::time::timeout(HAIRPIN_TIMEOUT, connector.connect(mapped)),
+    );
+    Some(matches!(accepted, Ok(Ok(_))))
+}
+
+async fn tcp_nat_behavior(server: SocketAddr) -> NatBehaviorResult {
+    let mut result = NatBehaviorResult::new("tcp", Some(server), server.is_ipv6());
 
-    let mut addrs = match tokio::net::lookup_host(&server).await {
-        Ok(addrs) => addrs,
-        Err(_) => return (false, -1, NatType::Unknown),
+    let (first, local) = match tcp_exchange(unspecified_addr(server.is_ipv6(), 0), server).await {
+        Ok(first) => first,
+        Err(e) => return result.fail(e),
+    };
+    result.local_addr = Some(local.to_string());
+    result.rtt_ms = Some(first.rtt.as_millis() as i64);
+    let Some(mapped) = first.response.mapped_address() else {
+        return result.fail("response carries no mapped address");
+    };
+    result.mapped_addr = Some(mapped.to_string());
+    result.port_preserved = Some(mapped.port() == local.port());
+
+    let bind = unspecified_addr(server.is_ipv6(), local.port());
+    result.mapping = if local.ip() == mapped.ip() {
+        MappingBehavior::NoNat
+    } else if let Some(other) = first.response.other_address() {
+        let alt_ip = SocketAddr::new(other.ip(), server.port());
+        match tcp_exchange(bind, alt_ip).await {
+            Ok((second, _)) => match second.response.mapped_address() {
+                Some(mapped2) if mapped2 == mapped => MappingBehavior::EndpointIndependent,
+                Some(mapped2) => match tcp_exchange(bind, other).await {
+                    Ok((third, _)) if third.response.mapped_address() == Some(mapped2) => {
+                        MappingBehavior::AddressDependent
+                    }
+                    Ok(_) => MappingBehavior::AddressAndPortDependent,
+                    Err(_) => MappingBehavior::Unknown,
+                },
+                None => MappingBehavior::Unknown,
+            },
+            Err(_) => MappingBehavior::Unknown,
+        }
+    } else {
+        MappingBehavior::Unknown
     };
 
-    let Some(server_v6) = addrs.find(|addr| addr.is_ipv6()) else {
-        return (false, -1, NatType::Unknown);
+    result.hairpinning = tcp_hairpinning(bind, mapped).await;
+    result
+}
+
+async fn nat_behavior(stun_server: &str, tcp: bool, ipv6: bool) -> NatBehaviorResult {
+    let protocol = if tcp { "tcp" } else { "udp" };
+    let Some(server) = resolve_stun_server(stun_server, !ipv6).await else {
+        return NatBehaviorResult::new(protocol, None, ipv6)
+            .fail("server has no address of this family");
     };
+    if tcp {
+        tcp_nat_behavior(server).await
+    } else {
+        udp_nat_behavior(server).await
+    }
+}
+
+async fn test_udp_ipv4(stun_server: &str) -> Result<(i64, EasyNatType), String> {
+    let start = Instant::now();
+    let nat_type = detect_nat_type_ipv4(stun_server).await?;
+    Ok((start.elapsed().as_millis() as i64, nat_type))
+}
+
+async fn test_udp_ipv6(stun_server: &str) -> Result<(i64, NatType), String> {
+    let server_v6 = resolve_stun_server(stun_server, false)
+        .await
+        .ok_or_else(|| "STUN server has no IPv6 address".to_string())?;
 
-    let socket = match UdpSocket::bind("[::]:0").await {
-        Ok(s) => s,
-        Err(_) => return (false, -1, NatType::Unknown),
+    let behavior = udp_nat_behavior(server_v6).await;
+    match (behavior.rtt_ms, behavior.error.clone()) {
+        (_, Some(e)) => Err(e),
+        (Some(rtt), None) => Ok((rtt, behavior.classic_nat_type())),
+        (None, None) => Err("no response".to_string()),
+    }
+}
+
+async fn test_stun_server(server: String, ipv6: bool) -> StunServerResult {
+    let mut result = StunServerResult {
+        ip_version: if ipv6 { "ipv6" } else { "ipv4" }.to_string(),
+        server,
+        success: false,
+        latency: -1,
+        mapped_addr: None,
+        error: None,
     };
 
-    let request = create_binding_request();
-    if socket.send_to(&request, server_v6).await.is_err() {
-        return (false, -1, NatType::Unknown);
+    let exchange = match resolve_stun_server(&result.server, !ipv6).await {
+        None => Err("no address of this family".to_string()),
+        Some(addr) => match UdpSocket::bind(unspecified_addr(ipv6, 0)).await {
+            Ok(socket) => udp_exchange(&socket, addr, false, false).await,
+            Err(e) => Err(format!("bind failed: {e}")),
+        },
+    };
+    match exchange {
+        Ok(exchange) => {
+            result.success = true;
+            result.latency = exchange.rtt.as_millis() as i64;
+            result.mapped_addr = exchange.response.mapped_address().map(|a| a.to_string());
+        }
+        Err(e) => result.error = Some(e),
     }
+    result
+}
 
-    let mut buf = [0u8; 1500];
-    let recv = tokio::time::timeout(Duration::from_secs(3), socket.recv_from(&mut buf)).await;
+/// Queries every STUN server over both address families.
+async fn test_stun_servers(stun_server: &str) -> Vec<StunServerResult> {
+    let mut tests = tokio::task::JoinSet::new();
+    let servers = build_udp_stun_server_list(stun_server);
+    for (i, server) in servers.iter().enumerate() {
+        for ipv6 in [false, true] {
+            let test = test_stun_server(server.clone(), ipv6);
+            tests.spawn(async move { (i * 2 + ipv6 as usize, test.await) });
+        }
+    }
 
-    match recv {
-        Ok(Ok((len, _))) if is_success_stun_response(&buf[..len]) => (
-            true,
-            start.elapsed().as_millis() as i64,
-            NatType::OpenInternet,
-        ),
-        _ => (false, -1, NatType::Unknown),
+    let mut results = Vec::with_capacity(servers.len() * 2);
+    while let Some(joined) = tests.join_next().await {
+        if let Ok(result) = joined {
+            results.push(result);
+        }
     }
+    results.sort_by_key(|(order, _)| *order);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn first_endpoint(results: &[StunServerResult], ip_version: &str) -> Option<String> {
+    results
+        .iter()
+        .filter(|r| r.ip_version == ip_version)
+        .find_map(|r| r.mapped_addr.clone())
 }
 
 /// NAT test entry for Flutter.
@@ -186,13 +840,30 @@ pub fn test_network_connectivity(stun_server: String) -> Result<NetworkTestResul
         tokio::runtime::Runtime::new().map_err(|e| format!("Failed to create runtime: {e}"))?;
 
     let result = rt.block_on(async {
-        let (v4, v6) = tokio::join!(test_udp_ipv4(&stun_server), test_udp_ipv6(&stun_server));
+        let (v4, v6, stun_servers) = tokio::join!(
+            test_udp_ipv4(&stun_server),
+            test_udp_ipv6(&stun_server),
+            test_stun_servers(&stun_server)
+        );
+        let nat_v4 = v4.as_ref().ok().map(|(_, nat)| *nat);
 
         NetworkTestResult {
-            nat_type_v4: v4.2.get_description(),
-            nat_type_v6: v6.2.get_description(),
-            ipv4_latency: v4.1,
-            ipv6_latency: v6.1,
+            nat_type_v4: nat_v4
+                .map_or(NatType::Blocked, map_easy_nat_type)
+                .get_description(),
+            nat_type_v6: v6
+                .as_ref()
+                .map_or(NatType::Unknown, |(_, nat)| nat.clone())
+                .get_description(),
+            ipv4_latency: v4.as_ref().map_or(-1, |(latency, _)| *latency),
+            ipv6_latency: v6.as_ref().map_or(-1, |(latency, _)| *latency),
+            nat_type_v4_raw: nat_v4.map(|nat| format!("{:?}", nat)),
+            error_v4: v4.err(),
+            error_v6: v6.err(),
+            public_endpoint_v4: first_endpoint(&stun_servers, "ipv4"),
+            public_endpoint_v6: first_endpoint(&stun_servers, "ipv6"),
+            hole_punch: hole_punch_matrix(nat_v4),
+            stun_servers,
         }
     });
 
@@ -205,5 +876,474 @@ pub fn detect_nat_type(stun_server: String) -> Result<String, String> {
         tokio::runtime::Runtime::new().map_err(|e| format!("Failed to create runtime: {e}"))?;
 
     let nat_type = rt.block_on(async { detect_nat_type_ipv4(&stun_server).await })?;
-    Ok(nat_type.get_description())
+    Ok(map_easy_nat_type(nat_type).get_description())
+}
+
+/// RFC 5780 NAT behavior discovery entry for Flutter. Returns results for
+/// UDP/IPv4, UDP/IPv6, TCP/IPv4 and TCP/IPv6, in that order.
+pub fn test_nat_behavior(stun_server: String) -> Result<Vec<NatBehaviorResult>, String> {
+    let rt =
+        tokio::runtime::Runtime::new().map_err(|e| format!("Failed to create runtime: {e}"))?;
+
+    let results = rt.block_on(async {
+        let (udp4, udp6, tcp4, tcp6) = tokio::join!(
+            nat_behavior(&stun_server, false, false),
+            nat_behavior(&stun_server, false, true),
+            nat_behavior(&stun_server, true, false),
+            nat_behavior(&stun_server, true, true),
+        );
+        vec![udp4, udp6, tcp4, tcp6]
+    });
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(target_os = "linux")]
+    use std::sync::Arc;
+
+    const ATTR_USERNAME: u16 = 0x0006;
+    const ATTR_SOFTWARE: u16 = 0x8022;
+
+    // RFC 5769 test vectors; they share one transaction id
+    const TID: [u8; 12] = [
+        0xb7, 0xe7, 0xa7, 0x01, 0xbc, 0x34, 0xd6, 0x86, 0xfa, 0x87, 0xdf, 0xae,
+    ];
+
+    // §2.1 sample request
+    const REQUEST: &str = "
+        00 01 00 58 21 12 a4 42 b7 e7 a7 01 bc 34 d6 86 fa 87 df ae
+        80 22 00 10 53 54 55 4e 20 74 65 73 74 20 63 6c 69 65 6e 74
+        00 24 00 04 6e 00 01 ff
+        80 29 00 08 93 2f f9 b1 51 26 3b 36
+        00 06 00 09 65 76 74 6a 3a 68 36 76 59 20 20 20
+        00 08 00 14 9a ea a7 0c bf d8 cb 56 78 1e f2 b5 b2 d3 f2 49 c1 b5 71 a2
+        80 28 00 04 e5 7a 3b cf";
+
+    // §2.2 sample IPv4 response
+    const RESPONSE_V4: &str = "
+        01 01 00 3c 21 12 a4 42 b7 e7 a7 01 bc 34 d6 86 fa 87 df ae
+        80 22 00 0b 74 65 73 74 20 76 65 63 74 6f 72 20
+        00 20 00 08 00 01 a1 47 e1 12 a6 43
+        00 08 00 14 2b 91 f5 99 fd 9e 90 c3 8c 74 89 f9 2a f9 ba 53 f0 6b e7 d7
+        80 28 00 04 c0 7d 4c 96";
+
+    // §2.3 sample IPv6 response
+    const RESPONSE_V6: &str = "
+        01 01 00 48 21 12 a4 42 b7 e7 a7 01 bc 34 d6 86 fa 87 df ae
+        80 22 00 0b 74 65 73 74 20 76 65 63 74 6f 72 20
+        00 20 00 14 00 02 a1 47 01 13 a9 fa a5 d3 f1 79 bc 25 f4 b5 be d2 b9 d9
+        00 08 00 14 a3 82 95 4e 4b e6 7b f1 17 84 c9 7c 82 92 c2 75 bf e3 ed 41
+        80 28 00 04 c8 fb 0b 4c";
+
+    fn hex(data: &str) -> Vec<u8> {
+        data.split_whitespace()
+            .map(|byte| u8::from_str_radix(byte, 16).unwrap())
+            .collect()
+    }
+
+    fn addr(s: &str) -> SocketAddr {
+        s.parse().unwrap()
+    }
+
+    /// Re-encoding gives the same bytes except for padding, which the
+    /// vectors fill with spaces and `encode` with zeros.
+    fn assert_reencodes(data: &[u8], message: &StunMessage) {
+        let encoded = message.encode();
+        assert_eq!(encoded.len(), data.len());
+        assert_eq!(encoded[..20], data[..20]);
+        assert_eq!(StunMessage::parse(&encoded).unwrap().attrs, message.attrs);
+    }
+
+    #[test]
+    fn parses_rfc5769_request() {
+        let data = hex(REQUEST);
+        let message = StunMessage::parse(&data).unwrap();
+        assert_eq!(message.msg_type, STUN_BINDING_REQUEST);
+        assert_eq!(message.tid, TID);
+        let types: Vec<u16> = message.attrs.iter().map(|(t, _)| *t).collect();
+        assert_eq!(types, [0x8022, 0x0024, 0x8029, 0x0006, 0x0008, 0x8028]);
+        assert_eq!(message.attr(ATTR_SOFTWARE), Some(&b"STUN test client"[..]));
+        // the value excludes the padding after it
+        assert_eq!(message.attr(ATTR_USERNAME), Some(&b"evtj:h6vY"[..]));
+        assert_eq!(message.mapped_address(), None);
+        assert_eq!(message.change_request(), (false, false));
+        assert_reencodes(&data, &message);
+    }
+
+    #[test]
+    fn parses_rfc5769_responses() {
+        for (vector, expected) in [
+            (RESPONSE_V4, addr("192.0.2.1:32853")),
+            (
+                RESPONSE_V6,
+                addr("[2001:db8:1234:5678:11:2233:4455:6677]:32853"),
+            ),
+        ] {
+            let data = hex(vector);
+            let message = StunMessage::parse(&data).unwrap();
+            assert_eq!(message.msg_type, STUN_BINDING_SUCCESS);
+            assert_eq!(message.tid, TID);
+            assert_eq!(message.attrs.len(), 4);
+            assert_eq!(message.attr(ATTR_SOFTWARE), Some(&b"test vector"[..]));
+            assert_eq!(message.address(ATTR_XOR_MAPPED_ADDRESS), Some(expected));
+            assert_eq!(message.mapped_address(), Some(expected));
+            assert_eq!(message.other_address(), None);
+            assert_reencodes(&data, &message);
+        }
+    }
+
+    #[test]
+    fn encodes_rfc5769_mapped_addresses() {
+        for (vector, mapped) in [
+            (RESPONSE_V4, addr("192.0.2.1:32853")),
+            (
+                RESPONSE_V6,
+                addr("[2001:db8:1234:5678:11:2233:4455:6677]:32853"),
+            ),
+        ] {
+            let mut message = StunMessage::new(STUN_BINDING_SUCCESS, TID);
+            message.add_address(ATTR_XOR_MAPPED_ADDRESS, mapped);
+            let expected = StunMessage::parse(&hex(vector)).unwrap();
+            assert_eq!(message.attrs[0], expected.attrs[1]);
+        }
+    }
+
+    #[test]
+    fn xor_address_is_its_own_inverse() {
+        assert_eq!(
+            xor_address(addr("192.0.2.1:32853"), &TID),
+            addr("225.18.166.67:41287")
+        );
+        for original in [
+            addr("192.0.2.1:32853"),
+            addr("[2001:db8:1234:5678:11:2233:4455:6677]:32853"),
+            addr("0.0.0.0:0"),
+        ] {
+            assert_eq!(xor_address(xor_address(original, &TID), &TID), original);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_messages() {
+        let data = hex(RESPONSE_V4);
+        let mut bad_cookie = data.clone();
+        bad_cookie[4] ^= 0xff;
+        let mut top_bits = data.clone();
+        top_bits[0] |= 0x80;
+        let mut long_attr = data.clone();
+        long_attr[22..24].copy_from_slice(&0x00ffu16.to_be_bytes());
+
+        assert!(StunMessage::parse(&data[..19]).is_none());
+        assert!(StunMessage::parse(&data[..data.len() - 4]).is_none());
+        assert!(StunMessage::parse(&bad_cookie).is_none());
+        assert!(StunMessage::parse(&top_bits).is_none());
+        assert!(StunMessage::parse(&long_attr).is_none());
+    }
+
+    #[test]
+    fn rfc5780_attributes_round_trip() {
+        let mut request = StunMessage::new(STUN_BINDING_REQUEST, TID);
+        request.add_change_request(true, false);
+        let request = StunMessage::parse(&request.encode()).unwrap();
+        assert_eq!(request.change_request(), (true, false));
+
+        let mut response = StunMessage::new(STUN_BINDING_SUCCESS, TID);
+        response.add_address(ATTR_MAPPED_ADDRESS, addr("198.51.100.7:1000"));
+        response.add_address(ATTR_CHANGED_ADDRESS, addr("198.51.100.8:2000"));
+        let response = StunMessage::parse(&response.encode()).unwrap();
+        assert_eq!(response.mapped_address(), Some(addr("198.51.100.7:1000")));
+        assert_eq!(response.other_address(), Some(addr("198.51.100.8:2000")));
+
+        // XOR-MAPPED-ADDRESS and OTHER-ADDRESS win over the RFC 3489 ones
+        let mut response = response;
+        response.add_address(ATTR_XOR_MAPPED_ADDRESS, addr("[2001:db8::1]:3000"));
+        response.add_address(ATTR_OTHER_ADDRESS, addr("[2001:db8::2]:4000"));
+        let encoded = response.encode();
+        assert_eq!(encoded.len() % 4, 0);
+        let response = StunMessage::parse(&encoded).unwrap();
+        assert_eq!(response.mapped_address(), Some(addr("[2001:db8::1]:3000")));
+        assert_eq!(response.other_address(), Some(addr("[2001:db8::2]:4000")));
+    }
+
+    #[test]
+    fn classic_nat_types() {
+        use FilteringBehavior as F;
+        use MappingBehavior as M;
+        for (mapping, filtering, expected) in [
+            (M::NoNat, F::EndpointIndependent, NatType::OpenInternet),
+            (M::NoNat, F::Unknown, NatType::OpenInternet),
+            (M::NoNat, F::AddressDependent, NatType::SymmetricUdpFirewall),
+            (
+                M::EndpointIndependent,
+                F::EndpointIndependent,
+                NatType::FullCone,
+            ),
+            (
+                M::EndpointIndependent,
+                F::AddressDependent,
+                NatType::RestrictedCone,
+            ),
+            (
+                M::EndpointIndependent,
+                F::AddressAndPortDependent,
+                NatType::PortRestrictedCone,
+            ),
+            (M::EndpointIndependent, F::Unknown, NatType::Unknown),
+            (
+                M::AddressDependent,
+                F::EndpointIndependent,
+                NatType::Symmetric,
+            ),
+            (M::AddressAndPortDependent, F::Unknown, NatType::Symmetric),
+            (M::Unknown, F::EndpointIndependent, NatType::Unknown),
+        ] {
+            let mut result = NatBehaviorResult::new("udp", None, false);
+            result.mapping = mapping;
+            result.filtering = filtering;
+            assert_eq!(
+                result.classic_nat_type(),
+                expected,
+                "{mapping:?} {filtering:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn hole_punch_chance_for_every_pair() {
+        use EasyNatType::*;
+        let types = [
+            Unknown,
+            OpenInternet,
+            NoPat,
+            FullCone,
+            Restricted,
+            PortRestricted,
+            SymUdpFirewall,
+            SymmetricEasyInc,
+            SymmetricEasyDec,
+            Symmetric,
+        ];
+        // rows are the local type, columns the peer's, both in the order
+        // above: L likely, P possible, U unlikely
+        let expected = [
+            "PLLLPPPPPP",
+            "LLLLLLLLLL",
+            "LLLLLLLLLL",
+            "LLLLLLLLLL",
+            "PLLLLLLLLP",
+            "PLLLLLLPPU",
+            "PLLLLLLPPU",
+            "PLLLLPPPPU",
+            "PLLLLPPPPU",
+            "PLLLPUUUUU",
+        ];
+        for (local, row) in types.into_iter().zip(expected) {
+            for (peer, chance) in types.into_iter().zip(row.chars()) {
+                let expected = match chance {
+                    'L' => HolePunchChance::Likely,
+                    'P' => HolePunchChance::Possible,
+                    _ => HolePunchChance::Unlikely,
+                };
+                assert_eq!(
+                    predict_hole_punch(local, peer),
+                    expected,
+                    "{local:?} -> {peer:?}"
+                );
+                assert_eq!(
+                    predict_hole_punch(peer, local),
+                    expected,
+                    "{peer:?} -> {local:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hole_punch_matrix_covers_known_peers() {
+        let matrix = hole_punch_matrix(None);
+        assert_eq!(matrix.len(), 9);
+        assert!(matrix.iter().all(|p| p.chance == HolePunchChance::Unlikely));
+
+        let matrix = hole_punch_matrix(Some(EasyNatType::PortRestricted));
+        let chance = |peer: &str| {
+            matrix
+                .iter()
+                .find(|p| p.peer_nat_type == peer)
+                .map(|p| p.chance)
+        };
+        assert_eq!(chance("FullCone"), Some(HolePunchChance::Likely));
+        assert_eq!(chance("Symmetric"), Some(HolePunchChance::Unlikely));
+        assert_eq!(chance("Unknown"), None);
+    }
+
+    /// Canned answers of a fake server with its primary address on 127.0.0.1
+    /// and its alternate one on 127.0.0.2.
+    #[cfg(target_os = "linux")]
+    struct Script {
+        /// Mapped addresses reported to requests sent to the primary address,
+        /// the alternate IP and the alternate IP and port.
+        mapped: [SocketAddr; 3],
+        other_address: bool,
+        /// Whether requests to change both IP and port, or only the port,
+        /// are answered.
+        change_both: bool,
+        change_port: bool,
+    }
+
+    /// Starts the fake server and returns its primary address. It runs until
+    /// the test's runtime shuts down.
+    #[cfg(target_os = "linux")]
+    async fn fake_server(script: Script) -> SocketAddr {
+        let primary = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let port = primary.local_addr().unwrap().port();
+        let alt_ip = UdpSocket::bind(("127.0.0.2", port)).await.unwrap();
+        let alt_both = UdpSocket::bind("127.0.0.2:0").await.unwrap();
+        let alt_port = alt_both.local_addr().unwrap().port();
+        let alt_port_only = UdpSocket::bind(("127.0.0.1", alt_port)).await.unwrap();
+        let server = primary.local_addr().unwrap();
+        let other = alt_both.local_addr().unwrap();
+
+        let sockets = Arc::new([primary, alt_ip, alt_both, alt_port_only]);
+        let script = Arc::new(script);
+        for index in 0..3 {
+            let (sockets, script) = (sockets.clone(), script.clone());
+            tokio::spawn(async move {
+                let mut buf = [0u8; 1500];
+                loop {
+                    let (len, from) = sockets[index].recv_from(&mut buf).await.unwrap();
+                    let Some(request) = StunMessage::parse(&buf[..len]) else {
+                        continue;
+                    };
+                    let reply_from = match request.change_request() {
+                        (false, false) => index,
+                        (true, true) if script.change_both => 2,
+                        (false, true) if script.change_port => 3,
+                        _ => continue,
+                    };
+                    let mut response = StunMessage::new(STUN_BINDING_SUCCESS, request.tid);
+                    response.add_address(ATTR_XOR_MAPPED_ADDRESS, script.mapped[index]);
+                    if script.other_address {
+                        response.add_address(ATTR_OTHER_ADDRESS, other);
+                    }
+                    let _ = sockets[reply_from].send_to(&response.encode(), from).await;
+                }
+            });
+        }
+        server
+    }
+
+    // binding 127.0.0.2 needs the whole 127/8 on loopback, as on Linux;
+    // the canned mapped addresses are on loopback too, so hairpinning tests
+    // stay on the host
+    #[cfg(target_os = "linux")]
+    async fn behavior(script: Script) -> NatBehaviorResult {
+        udp_nat_behavior(fake_server(script).await).await
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn classifies_full_cone() {
+        let mapped = addr("127.0.0.9:40000");
+        let result = behavior(Script {
+            mapped: [mapped; 3],
+            other_address: true,
+            change_both: true,
+            change_port: true,
+        })
+        .await;
+        assert_eq!(result.error, None);
+        assert_eq!(result.mapped_addr, Some(mapped.to_string()));
+        assert_eq!(result.port_preserved, Some(false));
+        assert_eq!(result.mapping, MappingBehavior::EndpointIndependent);
+        assert_eq!(result.filtering, FilteringBehavior::EndpointIndependent);
+        assert_eq!(result.hairpinning, Some(false));
+        assert_eq!(result.classic_nat_type(), NatType::FullCone);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn classifies_restricted_cone() {
+        let result = behavior(Script {
+            mapped: [addr("127.0.0.9:40000"); 3],
+            other_address: true,
+            change_both: false,
+            change_port: true,
+        })
+        .await;
+        assert_eq!(result.mapping, MappingBehavior::EndpointIndependent);
+        assert_eq!(result.filtering, FilteringBehavior::AddressDependent);
+        assert_eq!(result.classic_nat_type(), NatType::RestrictedCone);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn classifies_address_dependent_mapping() {
+        let result = behavior(Script {
+            mapped: [
+                addr("127.0.0.9:40000"),
+                addr("127.0.0.9:40001"),
+                addr("127.0.0.9:40001"),
+            ],
+            other_address: true,
+            change_both: false,
+            change_port: false,
+        })
+        .await;
+        assert_eq!(result.mapping, MappingBehavior::AddressDependent);
+        assert_eq!(result.filtering, FilteringBehavior::AddressAndPortDependent);
+        assert_eq!(result.classic_nat_type(), NatType::Symmetric);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn classifies_address_and_port_dependent_mapping() {
+        let result = behavior(Script {
+            mapped: [
+                addr("127.0.0.9:40000"),
+                addr("127.0.0.9:40001"),
+                addr("127.0.0.9:40002"),
+            ],
+            other_address: true,
+            change_both: true,
+            change_port: true,
+        })
+        .await;
+        assert_eq!(result.mapping, MappingBehavior::AddressAndPortDependent);
+        assert_eq!(result.filtering, FilteringBehavior::EndpointIndependent);
+        assert_eq!(result.classic_nat_type(), NatType::Symmetric);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn mapped_local_address_is_no_nat() {
+        let result = behavior(Script {
+            mapped: [addr("127.0.0.1:40000"); 3],
+            other_address: true,
+            change_both: true,
+            change_port: true,
+        })
+        .await;
+        assert_eq!(result.mapping, MappingBehavior::NoNat);
+        assert_eq!(result.classic_nat_type(), NatType::OpenInternet);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn server_without_other_address_leaves_behavior_unknown() {
+        let mapped = addr("127.0.0.9:40000");
+        let result = behavior(Script {
+            mapped: [mapped; 3],
+            other_address: false,
+            change_both: true,
+            change_port: true,
+        })
+        .await;
+        assert_eq!(result.error, None);
+        assert_eq!(result.mapped_addr, Some(mapped.to_string()));
+        assert_eq!(result.mapping, MappingBehavior::Unknown);
+        assert_eq!(result.filtering, FilteringBehavior::Unknown);
+    }
 }
@@ -0,0 +1,791 @@
+//! Validated construction of EasyTier configs from the flags-style API.
+//!
+//! Every field is checked before anything is started, and all problems are
+//! reported together so the UI can highlight each offending input at once.
+
+use easytier::common::config::{
+    ConfigLoader, NetworkIdentity, PeerConfig, PortForwardConfig, TomlConfigLoader,
+};
+use std::net::{Ipv4Addr, SocketAddr};
+
+pub use super::relay_select::RelayPool;
+use super::relay_select::MIN_REEVALUATE_INTERVAL_SECS;
+pub use super::simple::{FlagsC, Forward};
+
+/// Prefix length used for `specified_ip` when neither the address nor the
+/// options carry one.
+pub const DEFAULT_IPV4_PREFIX_LEN: u8 = 24;
+
+/// TUN device name used unless `ConfigBuildOptions::custom_dev_name` is set.
+const DEFAULT_DEV_NAME: &str = "astral";
+
+/// Top-level TOML keys that identify one particular instance and must not
+/// travel with an exported config.
+const INSTANCE_KEYS: [&str; 2] = ["instance_id", "instance_name"];
+
+/// How proxy CIDRs that fail to parse are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidCidrPolicy {
+    /// Report the CIDR as a config issue and refuse to start.
+    Reject,
+    /// Drop the CIDR with a log line and start with the remaining ones.
+    Skip,
+}
+
+/// Knobs for behaviour that used to be hard-coded in the builder.
+#[derive(Debug, Clone)]
+pub struct ConfigBuildOptions {
+    /// Prefix length applied to `specified_ip` when it has no `/len` suffix.
+    pub ipv4_prefix_len: u8,
+    pub invalid_cidr_policy: InvalidCidrPolicy,
+    /// Name the TUN device after `flag.dev_name` instead of `astral`. Off by
+    /// default: the device used to be `astral` whatever the flag said, and saved
+    /// settings may still carry names that were never applied.
+    pub custom_dev_name: bool,
+}
+
+impl Default for ConfigBuildOptions {
+    fn default() -> Self {
+        Self {
+            ipv4_prefix_len: DEFAULT_IPV4_PREFIX_LEN,
+            invalid_cidr_policy: InvalidCidrPolicy::Reject,
+            custom_dev_name: false,
+        }
+    }
+}
+
+/// Everything `create_server_with_flags` takes plus the options it leaves at
+/// their defaults, bundled so it can be validated without starting an instance.
+pub struct NetworkConfigC {
+    pub username: String,
+    pub enable_dhcp: bool,
+    /// Static virtual IPv4, either `a.b.c.d` or `a.b.c.d/len`.
+    pub specified_ip: String,
+    pub room_name: String,
+    pub room_password: String,
+    pub severurl: Vec<String>,
+    pub onurl: Vec<String>,
+    pub cidrs: Vec<String>,
+    pub forwards: Vec<Forward>,
+    pub flag: FlagsC,
+    pub options: ConfigBuildOptions,
+    /// Relays picked by latency in addition to `severurl`, see `relay_select`.
+    pub relay_pool: Option<RelayPool>,
+}
+
+/// A single invalid input.
+#[derive(Debug, Clone)]
+pub struct ConfigIssue {
+    /// Path of the offending field, e.g. `forwards[1].dst_addr`.
+    pub field: String,
+    pub value: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {:?}: {}", self.field, self.value, self.message)
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Issues(Vec<ConfigIssue>);
+
+impl Issues {
+    pub(crate) fn push(
+        &mut self,
+        field: impl Into<String>,
+        value: impl Into<String>,
+        message: impl ToString,
+    ) {
+        self.0.push(ConfigIssue {
+            field: field.into(),
+            value: value.into(),
+            message: message.to_string(),
+        });
+    }
+
+    pub(crate) fn into_result<T>(self, value: T) -> Result<T, Vec<ConfigIssue>> {
+        if self.0.is_empty() {
+            Ok(value)
+        } else {
+            Err(self.0)
+        }
+    }
+}
+
+/// Joins issues into the single error string used by the `Result<_, String>` APIs.
+pub(crate) fn issues_to_string(issues: &[ConfigIssue]) -> String {
+    let details: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+    format!("invalid network config: {}", details.join("; "))
+}
+
+fn parse_specified_ip(
+    specified_ip: &str,
+    options: &ConfigBuildOptions,
+    issues: &mut Issues,
+) -> Option<String> {
+    let (addr, prefix) = match specified_ip.split_once('/') {
+        Some((addr, prefix)) => match prefix.parse::<u8>() {
+            Ok(prefix) => (addr, prefix),
+            Err(e) => {
+                issues.push(
+                    "specified_ip",
+                    specified_ip,
+                    format!("invalid prefix length: {}", e),
+                );
+                return None;
+            }
+        },
+        None => (specified_ip, options.ipv4_prefix_len),
+    };
+
+    let mut valid = true;
+    if let Err(e) = addr.parse::<Ipv4Addr>() {
+        issues.push("specified_ip", specified_ip, e);
+        valid = false;
+    }
+    if !(1..=32).contains(&prefix) {
+        let message = "prefix length must be between 1 and 32";
+        if specified_ip.contains('/') {
+            issues.push("specified_ip", specified_ip, message);
+        } else {
+            issues.push("options.ipv4_prefix_len", prefix.to_string(), message);
+        }
+        valid = false;
+    }
+    valid.then(|| format!("{}/{}", addr, prefix))
+}
+
+/// Parses a comma separated port whitelist such as `80,443,8000-9000`.
+pub(crate) fn parse_port_list(field: &str, list: &str, issues: &mut Issues) -> Vec<String> {
+    let mut ports = Vec::new();
+    for item in list.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let valid = match item.split_once('-') {
+            Some((start, end)) => match (start.trim().parse::<u16>(), end.trim().parse::<u16>()) {
+                (Ok(start), Ok(end)) => start <= end,
+                _ => false,
+            },
+            None => item.parse::<u16>().is_ok(),
+        };
+        if valid {
+            ports.push(item.to_string());
+        } else {
+            issues.push(
+                field,
+                item,
+                "expected a port or a port range like 8000-9000",
+            );
+        }
+    }
+    ports
+}
+
+/// Parses a port forward, reporting bad fields under `field`.
+pub(crate) fn parse_forward(
+    field: &str,
+    forward: &Forward,
+    issues: &mut Issues,
+) -> Option<PortForwardConfig> {
+    let bind_addr = forward.bind_addr.trim().parse::<SocketAddr>();
+    if let Err(e) = &bind_addr {
+        issues.push(format!("{}.bind_addr", field), &forward.bind_addr, e);
+    }
+    let dst_addr = forward.dst_addr.trim().parse::<SocketAddr>();
+    if let Err(e) = &dst_addr {
+        issues.push(format!("{}.dst_addr", field), &forward.dst_addr, e);
+    }
+    let proto = forward.proto.trim().to_lowercase();
+    if proto != "tcp" && proto != "udp" {
+        issues.push(
+            format!("{}.proto", field),
+            &forward.proto,
+            "expected tcp or udp",
+        );
+    }
+    Some(PortForwardConfig {
+        bind_addr: bind_addr.ok()?,
+        dst_addr: dst_addr.ok()?,
+        proto,
+    })
+}
+
+/// Builds the EasyTier config, or returns every issue found on the way.
+pub(crate) fn build_config(config: &NetworkConfigC) -> Result<TomlConfigLoader, Vec<ConfigIssue>> {
+    let mut issues = Issues::default();
+    let cfg = TomlConfigLoader::default();
+
+    let mut listeners = Vec::new();
+    for (i, url) in config.onurl.iter().enumerate() {
+        match url.parse() {
+            Ok(parsed) => listeners.push(parsed),
+            Err(e) => issues.push(format!("onurl[{}]", i), url, e),
+        }
+    }
+    cfg.set_listeners(listeners);
+
+    cfg.set_hostname(Some(config.username.clone()));
+    cfg.set_dhcp(config.enable_dhcp);
+
+    for (i, c) in config.cidrs.iter().enumerate() {
+        let field = format!("cidrs[{}]", i);
+        let error = match c.trim().parse() {
+            Ok(cidr) => cfg.add_proxy_cidr(cidr, None).err().map(|e| e.to_string()),
+            Err(e) => Some(e.to_string()),
+        };
+        if let Some(error) = error {
+            match config.options.invalid_cidr_policy {
+                InvalidCidrPolicy::Reject => issues.push(field, c, error),
+                InvalidCidrPolicy::Skip => {
+                    println!("skip invalid proxy cidr {} = {:?}: {}", field, c, error)
+                }
+            }
+        }
+    }
+
+    let mut port_forwards = cfg.get_port_forwards();
+    for (i, c) in config.forwards.iter().enumerate() {
+        if let Some(forward) = parse_forward(&format!("forwards[{}]", i), c, &mut issues) {
+            port_forwards.push(forward);
+        }
+    }
+    cfg.set_port_forwards(port_forwards);
+
+    let flag = &config.flag;
+    let mut flags = cfg.get_flags();
+    flags.default_protocol = flag.default_protocol.clone();
+    flags.dev_name = if config.options.custom_dev_name && !flag.dev_name.trim().is_empty() {
+        flag.dev_name.trim().to_string()
+    } else {
+        DEFAULT_DEV_NAME.to_string()
+    };
+    flags.enable_encryption = flag.enable_encryption;
+    flags.enable_ipv6 = flag.enable_ipv6;
+    flags.mtu = flag.mtu;
+    flags.latency_first = flag.latency_first;
+    flags.enable_exit_node = flag.enable_exit_node;
+    flags.no_tun = flag.no_tun;
+    flags.use_smoltcp = flag.use_smoltcp;
+    flags.relay_network_whitelist = flag.relay_network_whitelist.clone();
+    flags.disable_p2p = flag.disable_p2p;
+    flags.enable_udp_broadcast_relay = flag.enable_udp_broadcast_relay;
+    flags.relay_all_peer_rpc = flag.relay_all_peer_rpc;
+    flags.disable_udp_hole_punching = flag.disable_udp_hole_punching;
+    flags.disable_tcp_hole_punching = flag.disable_tcp_hole_punching;
+    flags.multi_thread = flag.multi_thread;
+    flags.data_compress_algo = flag.data_compress_algo;
+    flags.bind_device = flag.bind_device;
+    flags.enable_kcp_proxy = flag.enable_kcp_proxy;
+    flags.disable_kcp_input = flag.disable_kcp_input;
+    flags.disable_relay_kcp = flag.disable_relay_kcp;
+    flags.proxy_forward_by_system = flag.proxy_forward_by_system;
+    flags.accept_dns = flag.accept_dns;
+    flags.private_mode = flag.private_mode;
+    flags.enable_quic_proxy = flag.enable_quic_proxy;
+    flags.disable_quic_input = flag.disable_quic_input;
+    flags.disable_sym_hole_punching = flag.disable_sym_hole_punching;
+    cfg.set_flags(flags);
+
+    if flag.socks5_port > 0 {
+        let portal = format!("socks5://127.0.0.1:{}", flag.socks5_port);
+        match portal.parse() {
+            Ok(url) => cfg.set_socks5_portal(Some(url)),
+            Err(e) => issues.push("flag.socks5_port", flag.socks5_port.to_string(), e),
+        }
+    }
+
+    let tcp_ports = parse_port_list("flag.tcp_whitelist", &flag.tcp_whitelist, &mut issues);
+    if !tcp_ports.is_empty() {
+        cfg.set_tcp_whitelist(tcp_ports);
+    }
+    let udp_ports = parse_port_list("flag.udp_whitelist", &flag.udp_whitelist, &mut issues);
+    if !udp_ports.is_empty() {
+        cfg.set_udp_whitelist(udp_ports);
+    }
+
+    let mut peer_configs = Vec::new();
+    for (i, url) in config.severurl.iter().enumerate() {
+        match url.parse() {
+            Ok(uri) => peer_configs.push(PeerConfig {
+                uri,
+                peer_public_key: None,
+            }),
+            Err(e) => issues.push(format!("severurl[{}]", i), url, e),
+        }
+    }
+    cfg.set_peers(peer_configs);
+
+    if let Some(pool) = &config.relay_pool {
+        for (i, url) in pool.candidates.iter().enumerate() {
+            if let Err(e) = url.parse::<url::Url>() {
+                issues.push(format!("relay_pool.candidates[{}]", i), url, e);
+            }
+        }
+        if pool.best_n == 0 {
+            issues.push("relay_pool.best_n", "0", "must be at least 1");
+        }
+        if pool.reevaluate_interval_secs != 0
+            && pool.reevaluate_interval_secs < MIN_REEVALUATE_INTERVAL_SECS
+        {
+            issues.push(
+                "relay_pool.reevaluate_interval_secs",
+                pool.reevaluate_interval_secs.to_string(),
+                format!(
+                    "must be 0 or at least {} seconds",
+                    MIN_REEVALUATE_INTERVAL_SECS
+                ),
+            );
+        }
+        if pool.min_improvement_ms <= 0 {
+            issues.push(
+                "relay_pool.min_improvement_ms",
+                pool.min_improvement_ms.to_string(),
+                "must be greater than 0",
+            );
+        }
+    }
+
+    if !config.enable_dhcp && !config.specified_ip.trim().is_empty() {
+        if let Some(ip_str) =
+            parse_specified_ip(config.specified_ip.trim(), &config.options, &mut issues)
+        {
+            match ip_str.parse() {
+                Ok(ip) => cfg.set_ipv4(Some(ip)),
+                Err(e) => issues.push("specified_ip", &config.specified_ip, e),
+            }
+        }
+    }
+
+    cfg.set_network_identity(NetworkIdentity::new(
+        config.room_name.clone(),
+        config.room_password.clone(),
+    ));
+
+    issues.into_result(cfg)
+}
+
+/// Checks a network config without starting anything. An empty list means the
+/// config can be passed to `create_server_with_config` as is.
+pub fn validate_network_config(config: NetworkConfigC) -> Vec<ConfigIssue> {
+    build_config(&config).err().unwrap_or_default()
+}
+
+/// Renders a flags-style config as EasyTier TOML, e.g. to share a room file.
+///
+/// `instance_id` and `instance_name` are left out, so every import of the file
+/// starts a new instance instead of clashing with the exporter's one.
+pub fn flags_to_toml(config: NetworkConfigC) -> Result<String, String> {
+    let cfg = build_config(&config).map_err(|issues| issues_to_string(&issues))?;
+    Ok(strip_instance_keys(&cfg.dump()))
+}
+
+/// Drops the top-level instance identity keys from a dumped config.
+pub(crate) fn strip_instance_keys(toml: &str) -> String {
+    let mut out = String::with_capacity(toml.len());
+    let mut top_level = true;
+    for line in toml.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('[') {
+            top_level = false;
+        }
+        let key = trimmed.split('=').next().unwrap_or_default().trim();
+        if top_level && INSTANCE_KEYS.contains(&key) {
+            continue;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Reads an EasyTier TOML config back into the flags form.
+///
+/// Options that `NetworkConfigC` has no field for (mapped proxy CIDRs, extra
+/// flags, ...) are dropped; start from the TOML with `create_server` to keep them.
+/// The instance identity is dropped as well, a config built from the result
+/// gets a fresh `instance_id`.
+pub fn toml_to_flags(toml: String) -> Result<NetworkConfigC, String> {
+    let cfg =
+        TomlConfigLoader::new_from_str(&toml).map_err(|e| format!("invalid config toml: {}", e))?;
+
+    let identity = cfg.get_network_identity();
+    let ipv4 = cfg.get_ipv4();
+    let flags = cfg.get_flags();
+    // a file that names its own device keeps that name when started again
+    let custom_dev_name = flags.dev_name != DEFAULT_DEV_NAME;
+
+    let flag = FlagsC {
+        default_protocol: flags.default_protocol,
+        dev_name: flags.dev_name,
+        enable_encryption: flags.enable_encryption,
+        enable_ipv6: flags.enable_ipv6,
+        mtu: flags.mtu,
+        latency_first: flags.latency_first,
+        enable_exit_node: flags.enable_exit_node,
+        no_tun: flags.no_tun,
+        use_smoltcp: flags.use_smoltcp,
+        relay_network_whitelist: flags.relay_network_whitelist,
+        disable_p2p: flags.disable_p2p,
+        enable_udp_broadcast_relay: flags.enable_udp_broadcast_relay,
+        relay_all_peer_rpc: flags.relay_all_peer_rpc,
+        disable_udp_hole_punching: flags.disable_udp_hole_punching,
+        disable_tcp_hole_punching: flags.disable_tcp_hole_punching,
+        multi_thread: flags.multi_thread,
+        data_compress_algo: flags.data_compress_algo,
+        bind_device: flags.bind_device,
+        enable_kcp_proxy: flags.enable_kcp_proxy,
+        disable_kcp_input: flags.disable_kcp_input,
+        disable_relay_kcp: flags.disable_relay_kcp,
+        proxy_forward_by_system: flags.proxy_forward_by_system,
+        accept_dns: flags.accept_dns,
+        private_mode: flags.private_mode,
+        enable_quic_proxy: flags.enable_quic_proxy,
+        disable_quic_input: flags.disable_quic_input,
+        disable_sym_hole_punching: flags.disable_sym_hole_punching,
+        tcp_whitelist: cfg.get_tcp_whitelist().join(","),
+        udp_whitelist: cfg.get_udp_whitelist().join(","),
+        socks5_port: cfg
+            .get_socks5_portal()
+            .and_then(|url| url.port())
+            .unwrap_or(0),
+    };
+
+    Ok(NetworkConfigC {
+        username: cfg.get_hostname(),
+        enable_dhcp: cfg.get_dhcp(),
+        specified_ip: ipv4.map(|inet| inet.to_string()).unwrap_or_default(),
+        room_name: identity.network_name,
+        room_password: identity.network_secret.unwrap_or_default(),
+        severurl: cfg
+            .get_peers()
+            .into_iter()
+            .map(|peer| peer.uri.to_string())
+            .collect(),
+        onurl: cfg
+            .get_listeners()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|url| url.to_string())
+            .collect(),
+        cidrs: cfg
+            .get_proxy_cidrs()
+            .into_iter()
+            .map(|proxy| proxy.cidr.to_string())
+            .collect(),
+        forwards: cfg
+            .get_port_forwards()
+            .into_iter()
+            .map(|forward| Forward {
+                bind_addr: forward.bind_addr.to_string(),
+                dst_addr: forward.dst_addr.to_string(),
+                proto: forward.proto,
+            })
+            .collect(),
+        flag,
+        options: ConfigBuildOptions {
+            ipv4_prefix_len: ipv4
+                .map(|inet| inet.network_length())
+                .unwrap_or(DEFAULT_IPV4_PREFIX_LEN),
+            custom_dev_name,
+            ..Default::default()
+        },
+        relay_pool: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_config() -> NetworkConfigC {
+        NetworkConfigC {
+            username: "alice".to_string(),
+            enable_dhcp: false,
+            specified_ip: "10.126.126.5/24".to_string(),
+            room_name: "room".to_string(),
+            room_password: "secret".to_string(),
+            severurl: vec!["tcp://public.easytier.top:11010".to_string()],
+            onurl: vec![
+                "tcp://0.0.0.0:11010".to_string(),
+                "udp://0.0.0.0:11010".to_string(),
+            ],
+            cidrs: vec!["192.168.1.0/24".to_string()],
+            forwards: vec![Forward {
+                bind_addr: "0.0.0.0:25565".to_string(),
+                dst_addr: "10.126.126.1:25565".to_string(),
+                proto: "tcp".to_string(),
+            }],
+            flag: FlagsC {
+                default_protocol: "tcp".to_string(),
+                dev_name: "astral-test".to_string(),
+                enable_encryption: true,
+                enable_ipv6: false,
+                mtu: 1360,
+                latency_first: true,
+                enable_exit_node: false,
+                no_tun: false,
+                use_smoltcp: false,
+                relay_network_whitelist: "*".to_string(),
+                disable_p2p: false,
+                enable_udp_broadcast_relay: true,
+                relay_all_peer_rpc: false,
+                disable_udp_hole_punching: false,
+                disable_tcp_hole_punching: true,
+                multi_thread: true,
+                data_compress_algo: 0,
+                bind_device: true,
+                enable_kcp_proxy: true,
+                disable_kcp_input: false,
+                disable_relay_kcp: true,
+                proxy_forward_by_system: false,
+                accept_dns: false,
+                private_mode: true,
+                enable_quic_proxy: false,
+                disable_quic_input: false,
+                disable_sym_hole_punching: false,
+                tcp_whitelist: "80,8000-9000".to_string(),
+                udp_whitelist: "25565".to_string(),
+                socks5_port: 1080,
+            },
+            options: ConfigBuildOptions {
+                custom_dev_name: true,
+                ..Default::default()
+            },
+            relay_pool: None,
+        }
+    }
+
+    #[test]
+    fn flags_survive_toml_round_trip() {
+        let original = sample_config();
+        let toml = flags_to_toml(sample_config()).unwrap();
+        let back = toml_to_flags(toml.clone()).unwrap();
+
+        assert_eq!(back.username, original.username);
+        assert_eq!(back.enable_dhcp, original.enable_dhcp);
+        assert_eq!(back.specified_ip, original.specified_ip);
+        assert_eq!(back.room_name, original.room_name);
+        assert_eq!(back.room_password, original.room_password);
+        assert_eq!(back.severurl, original.severurl);
+        assert_eq!(back.onurl, original.onurl);
+        assert_eq!(back.cidrs, original.cidrs);
+        assert_eq!(back.forwards.len(), 1);
+        assert_eq!(back.forwards[0].bind_addr, original.forwards[0].bind_addr);
+        assert_eq!(back.forwards[0].dst_addr, original.forwards[0].dst_addr);
+        assert_eq!(back.forwards[0].proto, original.forwards[0].proto);
+        assert_eq!(back.options.ipv4_prefix_len, 24);
+
+        let (flag, expected) = (&back.flag, &original.flag);
+        assert_eq!(flag.default_protocol, expected.default_protocol);
+        assert_eq!(flag.dev_name, expected.dev_name);
+        assert_eq!(flag.enable_encryption, expected.enable_encryption);
+        assert_eq!(flag.mtu, expected.mtu);
+        assert_eq!(flag.latency_first, expected.latency_first);
+        assert_eq!(
+            flag.relay_network_whitelist,
+            expected.relay_network_whitelist
+        );
+        assert_eq!(
+            flag.enable_udp_broadcast_relay,
+            expected.enable_udp_broadcast_relay
+        );
+        assert_eq!(
+            flag.disable_tcp_hole_punching,
+            expected.disable_tcp_hole_punching
+        );
+        assert_eq!(flag.multi_thread, expected.multi_thread);
+        assert_eq!(flag.bind_device, expected.bind_device);
+        assert_eq!(flag.enable_kcp_proxy, expected.enable_kcp_proxy);
+        assert_eq!(flag.disable_relay_kcp, expected.disable_relay_kcp);
+        assert_eq!(flag.private_mode, expected.private_mode);
+        assert_eq!(flag.tcp_whitelist, expected.tcp_whitelist);
+        assert_eq!(flag.udp_whitelist, expected.udp_whitelist);
+        assert_eq!(flag.socks5_port, expected.socks5_port);
+
+        // a second export of the imported config is identical
+        assert_eq!(flags_to_toml(back).unwrap(), toml);
+    }
+
+    #[test]
+    fn exported_toml_has_no_instance_identity() {
+        let toml = flags_to_toml(sample_config()).unwrap();
+        assert!(!toml.contains("instance_id"));
+        assert!(!toml.contains("instance_name"));
+
+        // importing twice must not end up with the same instance
+        let a = build_config(&toml_to_flags(toml.clone()).unwrap()).unwrap();
+        let b = build_config(&toml_to_flags(toml).unwrap()).unwrap();
+        assert_ne!(a.get_id(), b.get_id());
+    }
+
+    #[test]
+    fn dev_name_needs_opt_in() {
+        let mut config = sample_config();
+        config.options.custom_dev_name = false;
+        let back = toml_to_flags(flags_to_toml(config).unwrap()).unwrap();
+        assert_eq!(back.flag.dev_name, DEFAULT_DEV_NAME);
+        assert!(!back.options.custom_dev_name);
+
+        let back = toml_to_flags(flags_to_toml(sample_config()).unwrap()).unwrap();
+        assert_eq!(back.flag.dev_name, "astral-test");
+        assert!(back.options.custom_dev_name);
+    }
+
+    #[test]
+    fn empty_dev_name_falls_back_to_default() {
+        let mut config = sample_config();
+        config.flag.dev_name = String::new();
+        let back = toml_to_flags(flags_to_toml(config).unwrap()).unwrap();
+        assert_eq!(back.flag.dev_name, DEFAULT_DEV_NAME);
+    }
+
+    fn issue_fields(issues: &[ConfigIssue]) -> Vec<&str> {
+        issues.iter().map(|issue| issue.field.as_str()).collect()
+    }
+
+    #[test]
+    fn valid_config_has_no_issues() {
+        assert!(validate_network_config(sample_config()).is_empty());
+    }
+
+    #[test]
+    fn invalid_cidrs_follow_policy() {
+        let mut config = sample_config();
+        config.cidrs = vec![
+            "192.168.1.0/24".to_string(),
+            "not-a-cidr".to_string(),
+            "10.0.0.0/33".to_string(),
+        ];
+
+        let issues = build_config(&config).unwrap_err();
+        assert_eq!(issue_fields(&issues), ["cidrs[1]", "cidrs[2]"]);
+        assert_eq!(issues[0].value, "not-a-cidr");
+
+        config.options.invalid_cidr_policy = InvalidCidrPolicy::Skip;
+        let cfg = build_config(&config).unwrap();
+        let cidrs: Vec<String> = cfg
+            .get_proxy_cidrs()
+            .into_iter()
+            .map(|proxy| proxy.cidr.to_string())
+            .collect();
+        assert_eq!(cidrs, ["192.168.1.0/24"]);
+    }
+
+    #[test]
+    fn port_list_keeps_valid_entries() {
+        let mut issues = Issues::default();
+        let ports = parse_port_list(
+            "flag.tcp_whitelist",
+            " 80, 8000-9000,,9000-8000, 70000, x-1, 443 ",
+            &mut issues,
+        );
+        assert_eq!(ports, ["80", "8000-9000", "443"]);
+
+        let issues = issues.into_result(()).unwrap_err();
+        assert_eq!(
+            issues
+                .iter()
+                .map(|issue| issue.value.as_str())
+                .collect::<Vec<_>>(),
+            ["9000-8000", "70000", "x-1"]
+        );
+        assert!(issues
+            .iter()
+            .all(|issue| issue.field == "flag.tcp_whitelist"));
+
+        let mut issues = Issues::default();
+        assert!(parse_port_list("flag.udp_whitelist", " ", &mut issues).is_empty());
+        assert!(issues.into_result(()).is_ok());
+    }
+
+    #[test]
+    fn specified_ip_prefix() {
+        let mut config = sample_config();
+        config.specified_ip = "10.0.0.1".to_string();
+        config.options.ipv4_prefix_len = 16;
+        let cfg = build_config(&config).unwrap();
+        assert_eq!(cfg.get_ipv4().unwrap().to_string(), "10.0.0.1/16");
+
+        config.options.ipv4_prefix_len = 0;
+        let issues = build_config(&config).unwrap_err();
+        assert_eq!(issue_fields(&issues), ["options.ipv4_prefix_len"]);
+
+        config.specified_ip = "10.0.0.1/x".to_string();
+        let issues = build_config(&config).unwrap_err();
+        assert_eq!(issue_fields(&issues), ["specified_ip"]);
+
+        // with DHCP the address is not used and not checked
+        config.enable_dhcp = true;
+        assert!(build_config(&config).unwrap().get_ipv4().is_none());
+    }
+
+    #[test]
+    fn every_issue_is_reported() {
+        let mut config = sample_config();
+        config.onurl = vec!["nope".to_string()];
+        config.forwards[0].dst_addr = "host".to_string();
+        config.forwards[0].proto = "icmp".to_string();
+        config.flag.tcp_whitelist = "80,abc".to_string();
+        config.severurl = vec!["::bad".to_string()];
+        config.specified_ip = "10.126.126.300/24".to_string();
+
+        let issues = build_config(&config).unwrap_err();
+        assert_eq!(
+            issue_fields(&issues),
+            [
+                "onurl[0]",
+                "forwards[0].dst_addr",
+                "forwards[0].proto",
+                "flag.tcp_whitelist",
+                "severurl[0]",
+                "specified_ip",
+            ]
+        );
+
+        let message = issues_to_string(&issues);
+        assert!(message.starts_with("invalid network config: "));
+        assert_eq!(message.matches("; ").count(), issues.len() - 1);
+        for issue in &issues {
+            assert!(message.contains(&issue.to_string()));
+        }
+        assert!(message.contains("forwards[0].proto = \"icmp\": expected tcp or udp"));
+    }
+
+    #[test]
+    fn relay_pool_settings_are_checked() {
+        let mut config = sample_config();
+        config.relay_pool = Some(RelayPool {
+            candidates: vec!["tcp://relay.example.com:11010".to_string()],
+            best_n: 1,
+            reevaluate_interval_secs: 0,
+            min_improvement_ms: 20,
+        });
+        assert!(build_config(&config).is_ok());
+
+        config.relay_pool = Some(RelayPool {
+            candidates: vec!["relay".to_string()],
+            best_n: 0,
+            reevaluate_interval_secs: 1,
+            min_improvement_ms: 0,
+        });
+        let issues = build_config(&config).unwrap_err();
+        assert_eq!(
+            issue_fields(&issues),
+            [
+                "relay_pool.candidates[0]",
+                "relay_pool.best_n",
+                "relay_pool.reevaluate_interval_secs",
+                "relay_pool.min_improvement_ms",
+            ]
+        );
+    }
+
+    #[test]
+    fn strip_instance_keys_keeps_nested_tables() {
+        let toml = "instance_name = \"default\"\ninstance_id = \"abc\"\nhostname = \"a\"\n\n[x]\ninstance_id = \"kept\"\n";
+        assert_eq!(
+            strip_instance_keys(toml),
+            "hostname = \"a\"\n\n[x]\ninstance_id = \"kept\"\n"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use dashmap::DashMap;
 use easytier::common::config::{ConfigFileControl, PortForwardConfig};
 pub use easytier::common::config::{ConfigLoader, NetworkIdentity, PeerConfig, TomlConfigLoader};
 pub use easytier::common::global_ctx::{EventBusSubscriber, GlobalCtxEvent};
@@ -5,24 +6,52 @@ pub use easytier::instance_manager::NetworkInstanceManager;
 pub use easytier::proto;
 pub use easytier::proto::api::instance::{PeerRoutePair, Route};
 pub use easytier::proto::common::NatType;
+use flutter_rust_bridge::frb;
 use lazy_static::lazy_static;
-use serde_json::json;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use tokio::runtime::Runtime;
 pub use tokio::task::JoinHandle;
 use uuid::Uuid;
 
+use super::instance_patch::PatchAction;
+use crate::frb_generated::StreamSink;
+
 pub static DEFAULT_ET_DNS_ZONE: &str = "as.net.";
 
 lazy_static! {
     static ref RT: Runtime = Runtime::new().expect("failed to create tokio runtime");
     static ref MANAGER: NetworkInstanceManager = NetworkInstanceManager::new();
+    /// Configs of instances started through this module, kept for fields
+    /// that the running info doesn't report back.
+    static ref CONFIGS: DashMap<Uuid, TomlConfigLoader> = DashMap::new();
+    static ref EVENT_CHANNELS: DashMap<String, EventChannel> = DashMap::new();
+}
+
+/// How many recent events of an instance are replayed to a new subscriber.
+const EVENT_HISTORY_LEN: usize = 64;
+
+/// Subscribers of an instance plus its recent events, so that a subscriber
+/// attaching after start-up still sees events like `ListenerAdded` and
+/// `TunDeviceReady`.
+#[derive(Default)]
+struct EventChannel {
+    history: VecDeque<InstanceEventRecord>,
+    sinks: Vec<StreamSink<InstanceEventRecord>>,
+    /// Whether an event task forwards the instance's event bus; that task
+    /// drops the channel once the bus closes.
+    watched: bool,
 }
 
-fn parse_instance_id(instance_id: &str) -> Result<Uuid, String> {
+pub(crate) fn parse_instance_id(instance_id: &str) -> Result<Uuid, String> {
     Uuid::parse_str(instance_id).map_err(|e| format!("invalid instance_id: {}", e))
 }
 
-async fn get_instance_info(
+pub(crate) fn manager() -> &'static NetworkInstanceManager {
+    &MANAGER
+}
+
+pub(crate) async fn get_instance_info(
     instance_id: &str,
 ) -> Result<easytier::launcher::NetworkInstanceRunningInfo, String> {
     let id = parse_instance_id(instance_id)?;
@@ -32,172 +61,351 @@ async fn get_instance_info(
         .ok_or_else(|| "instance not found".to_string())
 }
 
-fn peer_conn_info_to_string(p: proto::api::instance::PeerConnInfo) -> String {
-    format!(
-        "my_peer_id: {}, dst_peer_id: {}, tunnel_info: {:?}",
-        p.my_peer_id, p.peer_id, p.tunnel
-    )
+/// Summary of a peer connection carried by connection events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerConnEvent {
+    pub conn_id: String,
+    pub my_peer_id: u32,
+    pub peer_id: u32,
+    pub tunnel_type: String,
+    pub local_addr: String,
+    pub remote_addr: String,
 }
 
-pub fn send_udp_to_localhost(message: &str) -> Result<(), String> {
-    use std::net::UdpSocket;
+impl From<proto::api::instance::PeerConnInfo> for PeerConnEvent {
+    fn from(p: proto::api::instance::PeerConnInfo) -> Self {
+        let tunnel = p.tunnel.unwrap_or_default();
+        Self {
+            conn_id: p.conn_id,
+            my_peer_id: p.my_peer_id,
+            peer_id: p.peer_id,
+            tunnel_type: tunnel.tunnel_type,
+            local_addr: tunnel.local_addr.map(|u| u.url).unwrap_or_default(),
+            remote_addr: tunnel.remote_addr.map(|u| u.url).unwrap_or_default(),
+        }
+    }
+}
 
-    let socket = match UdpSocket::bind("0.0.0.0:0") {
-        Ok(s) => s,
-        Err(e) => return Err(format!("udp bind failed: {}", e)),
-    };
+/// One entry of a config patch applied to a running instance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigChange {
+    /// Patched field: `peers`, `port_forwards`, `proxy_cidrs`, `tcp_whitelist`,
+    /// `udp_whitelist`, `hostname` or `ipv4`.
+    pub field: String,
+    /// `None` when the value replaces the field instead of being added to or
+    /// removed from a list.
+    pub action: Option<PatchAction>,
+    pub value: String,
+}
 
-    match socket.send_to(message.as_bytes(), "127.0.0.1:9999") {
-        Ok(_) => Ok(()),
-        Err(e) => Err(format!("udp send failed: {}", e)),
+/// Typed mirror of `GlobalCtxEvent`, streamed to Flutter per instance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum InstanceEvent {
+    PeerAdded {
+        peer_id: u32,
+    },
+    PeerRemoved {
+        peer_id: u32,
+    },
+    PeerConnAdded {
+        conn: PeerConnEvent,
+    },
+    PeerConnRemoved {
+        conn: PeerConnEvent,
+    },
+    ListenerAdded {
+        listener: String,
+    },
+    ListenerAddFailed {
+        listener: String,
+        message: String,
+    },
+    ListenerAcceptFailed {
+        listener: String,
+        message: String,
+    },
+    ConnectionAccepted {
+        local: String,
+        remote: String,
+    },
+    ConnectionError {
+        local: String,
+        remote: String,
+        error: String,
+    },
+    TunDeviceReady {
+        dev: String,
+    },
+    TunDeviceError {
+        error: String,
+    },
+    Connecting {
+        dst: String,
+    },
+    ConnectError {
+        dst: String,
+        ip_version: String,
+        error: String,
+    },
+    VpnPortalStarted {
+        portal: String,
+    },
+    VpnPortalClientConnected {
+        portal: String,
+        client_addr: String,
+    },
+    VpnPortalClientDisconnected {
+        portal: String,
+        client_addr: String,
+    },
+    DhcpIpv4Changed {
+        old_ip: Option<String>,
+        new_ip: Option<String>,
+    },
+    DhcpIpv4Conflicted {
+        ip: Option<String>,
+    },
+    PortForwardAdded {
+        bind_addr: String,
+        dst_addr: String,
+        proto: String,
+    },
+    /// A config patch was applied to the running instance.
+    ConfigPatched {
+        #[serde(default)]
+        changes: Vec<ConfigChange>,
+    },
+    ProxyCidrsUpdated {
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+    /// The supervisor will restart the instance after `delay_ms`.
+    InstanceRestarting {
+        attempt: u32,
+        delay_ms: u64,
+        reason: String,
+    },
+    InstanceRestarted {
+        attempt: u32,
+    },
+    /// The supervisor ran out of retries; the instance stays down.
+    InstanceRestartGaveUp {
+        attempts: u32,
+        reason: String,
+    },
+    /// The subscriber fell behind and `dropped` events were lost.
+    EventsLagged {
+        dropped: u64,
+    },
+    /// The instance's event bus closed; no further events will follow.
+    EventsClosed,
+}
+
+impl InstanceEvent {
+    /// Converts an EasyTier event, returning `None` for events we don't surface.
+    fn from_global_ctx_event(event: GlobalCtxEvent) -> Option<Self> {
+        let event = match event {
+            GlobalCtxEvent::PeerAdded(peer_id) => Self::PeerAdded { peer_id },
+            GlobalCtxEvent::PeerRemoved(peer_id) => Self::PeerRemoved { peer_id },
+            GlobalCtxEvent::PeerConnAdded(p) => Self::PeerConnAdded { conn: p.into() },
+            GlobalCtxEvent::PeerConnRemoved(p) => Self::PeerConnRemoved { conn: p.into() },
+            GlobalCtxEvent::ListenerAdded(p) => {
+                if p.scheme() == "ring" {
+                    return None;
+                }
+                Self::ListenerAdded {
+                    listener: p.to_string(),
+                }
+            }
+            GlobalCtxEvent::ListenerAddFailed(p, message) => Self::ListenerAddFailed {
+                listener: p.to_string(),
+                message,
+            },
+            GlobalCtxEvent::ListenerAcceptFailed(p, message) => Self::ListenerAcceptFailed {
+                listener: p.to_string(),
+                message,
+            },
+            GlobalCtxEvent::ConnectionAccepted(local, remote) => {
+                Self::ConnectionAccepted { local, remote }
+            }
+            GlobalCtxEvent::ConnectionError(local, remote, error) => Self::ConnectionError {
+                local,
+                remote,
+                error,
+            },
+            GlobalCtxEvent::TunDeviceReady(dev) => Self::TunDeviceReady { dev },
+            GlobalCtxEvent::TunDeviceError(error) => Self::TunDeviceError { error },
+            GlobalCtxEvent::Connecting(dst) => Self::Connecting {
+                dst: dst.to_string(),
+            },
+            GlobalCtxEvent::ConnectError(dst, ip_version, error) => Self::ConnectError {
+                dst,
+                ip_version,
+                error,
+            },
+            GlobalCtxEvent::VpnPortalStarted(portal) => Self::VpnPortalStarted { portal },
+            GlobalCtxEvent::VpnPortalClientConnected(portal, client_addr) => {
+                Self::VpnPortalClientConnected {
+                    portal,
+                    client_addr,
+                }
+            }
+            GlobalCtxEvent::VpnPortalClientDisconnected(portal, client_addr) => {
+                Self::VpnPortalClientDisconnected {
+                    portal,
+                    client_addr,
+                }
+            }
+            GlobalCtxEvent::DhcpIpv4Changed(old, new) => Self::DhcpIpv4Changed {
+                old_ip: old.map(|ip| ip.to_string()),
+                new_ip: new.map(|ip| ip.to_string()),
+            },
+            GlobalCtxEvent::DhcpIpv4Conflicted(ip) => Self::DhcpIpv4Conflicted {
+                ip: ip.map(|ip| ip.to_string()),
+            },
+            GlobalCtxEvent::PortForwardAdded(cfg) => {
+                let cfg: PortForwardConfig = cfg.into();
+                Self::PortForwardAdded {
+                    bind_addr: cfg.bind_addr.to_string(),
+                    dst_addr: cfg.dst_addr.to_string(),
+                    proto: cfg.proto,
+                }
+            }
+            GlobalCtxEvent::ConfigPatched(patch) => Self::ConfigPatched {
+                changes: super::instance_patch::config_changes(&patch),
+            },
+            GlobalCtxEvent::ProxyCidrsUpdated(added, removed) => Self::ProxyCidrsUpdated {
+                added: added.iter().map(|c| c.to_string()).collect(),
+                removed: removed.iter().map(|c| c.to_string()).collect(),
+            },
+            _ => return None,
+        };
+        Some(event)
+    }
+}
+
+/// An event together with the instance that produced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceEventRecord {
+    pub instance_id: String,
+    /// Unix timestamp in milliseconds.
+    pub timestamp: i64,
+    pub event: InstanceEvent,
+}
+
+pub(crate) fn publish_event(instance_id: &str, event: InstanceEvent) {
+    super::supervisor::on_event(instance_id, &event);
+
+    let record = InstanceEventRecord {
+        instance_id: instance_id.to_string(),
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        event,
+    };
+    if let Err(e) = super::event_log::append(&record) {
+        eprintln!("write event log failed: {}", e);
+    }
+    // events of an instance that is already gone only reach existing
+    // subscribers, they don't bring its channel back
+    let mut channel = match EVENT_CHANNELS.get_mut(instance_id) {
+        Some(channel) => channel,
+        None if parse_instance_id(instance_id).is_ok_and(|id| is_known_instance(&id)) => {
+            EVENT_CHANNELS.entry(instance_id.to_string()).or_default()
+        }
+        None => return,
+    };
+    if channel.history.len() >= EVENT_HISTORY_LEN {
+        channel.history.pop_front();
     }
+    channel.history.push_back(record.clone());
+    // drop sinks whose Dart stream has been cancelled
+    channel
+        .sinks
+        .retain(|sink| sink.add(record.clone()).is_ok());
 }
 
-pub fn handle_event(mut events: EventBusSubscriber) -> tokio::task::JoinHandle<()> {
-    tokio::spawn(async move {
+#[frb(ignore)]
+pub fn handle_event(instance_id: String, mut events: EventBusSubscriber) -> JoinHandle<()> {
+    RT.spawn(async move {
         loop {
             match events.recv().await {
-                Ok(e) => match e {
-                    GlobalCtxEvent::PeerAdded(p) => {
-                        let msg = format!("peer added. peer_id: {}", p);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PeerRemoved(p) => {
-                        let msg = format!("peer removed. peer_id: {}", p);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PeerConnAdded(p) => {
-                        let conn_info = peer_conn_info_to_string(p);
-                        let msg = format!("peer connection added. conn_info: {}", conn_info);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PeerConnRemoved(p) => {
-                        let msg = format!(
-                            "peer connection removed. conn_info: {}",
-                            peer_conn_info_to_string(p)
-                        );
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ListenerAddFailed(p, msg) => {
-                        let msg = format!("listener add failed. listener: {}, msg: {}", p, msg);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ListenerAcceptFailed(p, msg) => {
-                        let msg = format!("listener accept failed. listener: {}, msg: {}", p, msg);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ListenerAdded(p) => {
-                        if p.scheme() == "ring" {
-                            continue;
-                        }
-                        let msg = format!("listener added. listener: {}", p);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ConnectionAccepted(local, remote) => {
-                        let msg =
-                            format!("connection accepted. local: {}, remote: {}", local, remote);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ConnectionError(local, remote, err) => {
-                        let msg = format!(
-                            "connection error. local: {}, remote: {}, err: {}",
-                            local, remote, err
-                        );
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::TunDeviceReady(dev) => {
-                        let msg = format!("tun device ready. dev: {}", dev);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::TunDeviceError(err) => {
-                        let msg = format!("tun device error. err: {}", err);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
+                Ok(e) => {
+                    if let Some(event) = InstanceEvent::from_global_ctx_event(e) {
+                        publish_event(&instance_id, event);
                     }
-                    GlobalCtxEvent::Connecting(dst) => {
-                        let msg = format!("connecting to peer. dst: {}", dst);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ConnectError(dst, ip_version, err) => {
-                        let msg = format!(
-                            "connect error. dst: {}, ip_version: {}, err: {}",
-                            dst, ip_version, err
-                        );
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::VpnPortalStarted(portal) => {
-                        let msg = format!("vpn portal started. portal: {}", portal);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::VpnPortalClientConnected(portal, client_addr) => {
-                        let msg = format!(
-                            "vpn portal client connected. portal: {}, client_addr: {}",
-                            portal, client_addr
-                        );
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::VpnPortalClientDisconnected(portal, client_addr) => {
-                        let msg = format!(
-                            "vpn portal client disconnected. portal: {}, client_addr: {}",
-                            portal, client_addr
-                        );
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::DhcpIpv4Changed(old, new) => {
-                        let msg = format!("dhcp ip changed. old: {:?}, new: {:?}", old, new);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::DhcpIpv4Conflicted(ip) => {
-                        let msg = format!("dhcp ip conflict. ip: {:?}", ip);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PortForwardAdded(cfg) => {
-                        let msg = format!("port forward added. cfg: {:?}", cfg);
-                        println!("{}", msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ConfigPatched(_) => {}
-                    GlobalCtxEvent::ProxyCidrsUpdated(_, _) => {}
-                    _ => {}
-                },
-                Err(err) => {
-                    eprintln!("event receive error: {:?}", err);
-                    match err {
-                        tokio::sync::broadcast::error::RecvError::Closed => {
-                            let msg = "event channel closed; stop handling events";
-                            println!("{}", msg);
-                            let _ = send_udp_to_localhost(msg);
-                            break;
-                        }
-                        tokio::sync::broadcast::error::RecvError::Lagged(n) => {
-                            let msg = format!("event lagged, dropped {} events", n);
-                            eprintln!("{}", msg);
-                            let _ = send_udp_to_localhost(&msg);
-                        }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    publish_event(&instance_id, InstanceEvent::EventsClosed);
+                    // an instance that still has a config is being restarted
+                    // under the same id, by the supervisor or by a patch
+                    let closed = match parse_instance_id(&instance_id) {
+                        Ok(id) => !has_instance_config(&id),
+                        Err(_) => true,
+                    };
+                    if closed && !super::supervisor::is_supervised(&instance_id) {
+                        drop_event_sinks(&instance_id);
                     }
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    publish_event(&instance_id, InstanceEvent::EventsLagged { dropped: n });
                 }
             }
         }
     })
 }
 
+/// Ends the event streams of an instance and forgets its event history.
+pub(crate) fn drop_event_sinks(instance_id: &str) {
+    EVENT_CHANNELS.remove(instance_id);
+}
+
+/// Starts forwarding events of a freshly launched instance to its subscribers.
+fn watch_instance_events(instance_id: &Uuid) -> Result<(), String> {
+    let events = MANAGER
+        .iter()
+        .find(|entry| entry.key() == instance_id)
+        .and_then(|entry| entry.value().subscribe_event())
+        .ok_or_else(|| "instance event bus not available".to_string())?;
+    EVENT_CHANNELS
+        .entry(instance_id.to_string())
+        .or_default()
+        .watched = true;
+    handle_event(instance_id.to_string(), events);
+    Ok(())
+}
+
+/// Whether the instance runs or is kept for a restart.
+fn is_known_instance(id: &Uuid) -> bool {
+    has_instance_config(id) || MANAGER.list_network_instance_ids().contains(id)
+}
+
+/// Subscribes to the typed event stream of an instance.
+///
+/// The most recent events of the instance are replayed first, so start-up
+/// events are not lost to a subscriber that attaches late. Several subscribers
+/// may listen to the same instance; a subscriber is dropped once its Dart
+/// stream is cancelled. Fails for instances that are neither running nor
+/// waiting for a restart.
+pub fn subscribe_instance_events(
+    instance_id: String,
+    sink: StreamSink<InstanceEventRecord>,
+) -> Result<(), String> {
+    let id = parse_instance_id(&instance_id)?;
+    if !is_known_instance(&id) {
+        return Err("instance not found".to_string());
+    }
+    // replay while holding the entry so no event slips in between
+    let mut channel = EVENT_CHANNELS.entry(id.to_string()).or_default();
+    for record in channel.history.iter() {
+        if sink.add(record.clone()).is_err() {
+            return Ok(());
+        }
+    }
+    channel.sinks.push(sink);
+    Ok(())
+}
+
 pub fn easytier_version() -> Result<String, String> {
     Ok(easytier::VERSION.to_string())
 }
@@ -212,29 +420,31 @@ pub async fn is_easytier_running(instance_id: String) -> bool {
 pub use super::simple::{KVNetworkStatus, KVNodeConnectionStats, KVNodeInfo, NodeHopStats};
 
 pub async fn get_ips(instance_id: String) -> Vec<String> {
+    let Ok(id) = parse_instance_id(&instance_id) else {
+        return Vec::new();
+    };
     let info = match get_instance_info(&instance_id).await {
         Ok(info) => info,
         Err(_) => return Vec::new(),
     };
 
     let mut result = Vec::new();
-
-    for route in &info.routes {
-        if let Some(ipv4_addr) = &route.ipv4_addr {
-            if let Some(addr) = &ipv4_addr.address {
-                let ip = format!(
-                    "{}.{}.{}.{}/{}",
-                    (addr.addr >> 24) & 0xFF,
-                    (addr.addr >> 16) & 0xFF,
-                    (addr.addr >> 8) & 0xFF,
-                    addr.addr & 0xFF,
-                    ipv4_addr.network_length
-                );
-                if !result.contains(&ip) {
-                    result.push(ip);
-                }
+    let mut push = |ip: Option<String>| {
+        if let Some(ip) = ip {
+            if !result.contains(&ip) {
+                result.push(ip);
             }
         }
+    };
+
+    if let Some(node) = &info.my_node_info {
+        push(node.virtual_ipv4.as_ref().and_then(format_ipv4_inet));
+    }
+    push(local_ipv6(&id).as_ref().and_then(format_ipv6_inet));
+
+    for route in &info.routes {
+        push(route.ipv4_addr.as_ref().and_then(format_ipv4_inet));
+        push(route.ipv6_addr.as_ref().and_then(format_ipv6_inet));
     }
 
     result
@@ -247,40 +457,296 @@ pub async fn set_tun_fd(instance_id: String, fd: i32) -> Result<(), String> {
         .map_err(|e| format!("set_tun_fd failed: {}", e))
 }
 
-pub async fn get_running_info(instance_id: String) -> String {
-    let info = match get_instance_info(&instance_id).await {
-        Ok(info) => info,
-        Err(_) => return "null".to_string(),
-    };
+pub(crate) fn format_ipv4(addr: &proto::common::Ipv4Addr) -> String {
+    std::net::Ipv4Addr::from(addr.addr).to_string()
+}
+
+pub(crate) fn format_ipv6(addr: &proto::common::Ipv6Addr) -> String {
+    let bits = ((addr.part1 as u128) << 96)
+        | ((addr.part2 as u128) << 64)
+        | ((addr.part3 as u128) << 32)
+        | addr.part4 as u128;
+    std::net::Ipv6Addr::from(bits).to_string()
+}
+
+fn ipv6_to_proto(addr: std::net::Ipv6Addr) -> proto::common::Ipv6Addr {
+    let bits = u128::from(addr);
+    proto::common::Ipv6Addr {
+        part1: (bits >> 96) as u32,
+        part2: (bits >> 64) as u32,
+        part3: (bits >> 32) as u32,
+        part4: bits as u32,
+    }
+}
+
+/// Virtual IPv6 of the local node; EasyTier only reports it through the config.
+fn local_ipv6(id: &Uuid) -> Option<proto::common::Ipv6Inet> {
+    let inet = CONFIGS.get(id)?.get_ipv6()?;
+    Some(proto::common::Ipv6Inet {
+        address: Some(ipv6_to_proto(inet.address())),
+        network_length: inet.network_length() as u32,
+    })
+}
+
+pub(crate) fn format_ipv4_inet(inet: &proto::common::Ipv4Inet) -> Option<String> {
+    inet.address
+        .as_ref()
+        .map(|addr| format!("{}/{}", format_ipv4(addr), inet.network_length))
+}
+
+pub(crate) fn format_ipv6_inet(inet: &proto::common::Ipv6Inet) -> Option<String> {
+    inet.address
+        .as_ref()
+        .map(|addr| format!("{}/{}", format_ipv6(addr), inet.network_length))
+}
+
+fn nat_type_name(nat_type: i32) -> String {
+    format!(
+        "{:?}",
+        NatType::try_from(nat_type).unwrap_or(NatType::Unknown)
+    )
+}
 
-    serde_json::to_string(&json!({
-        "dev_name": info.dev_name,
-        "my_node_info": info.my_node_info.as_ref().map(|node| json!({
-            "virtual_ipv4": node.virtual_ipv4.as_ref().map(|addr| json!({
-                "address": addr.address.as_ref().map(|a| json!({ "addr": a.addr })),
-            })),
-        })),
-        "routes": info.routes,
-        "peer_route_pairs": info.peer_route_pairs,
-    }))
-    .unwrap_or_else(|_| "null".to_string())
+/// STUN probing result of the local node.
+pub struct RunningStunInfo {
+    pub udp_nat_type: String,
+    pub tcp_nat_type: String,
+    pub public_ips: Vec<String>,
+    pub min_port: u32,
+    pub max_port: u32,
+    /// Unix timestamp of the last STUN probe, in seconds.
+    pub last_update_time: i64,
+}
+
+impl From<&proto::common::StunInfo> for RunningStunInfo {
+    fn from(stun: &proto::common::StunInfo) -> Self {
+        Self {
+            udp_nat_type: nat_type_name(stun.udp_nat_type),
+            tcp_nat_type: nat_type_name(stun.tcp_nat_type),
+            public_ips: stun.public_ip.clone(),
+            min_port: stun.min_port,
+            max_port: stun.max_port,
+            last_update_time: stun.last_update_time,
+        }
+    }
+}
+
+/// The local node as seen by the running instance.
+pub struct RunningNodeInfo {
+    pub peer_id: u32,
+    pub hostname: String,
+    pub version: String,
+    /// Virtual IPv4 in CIDR notation, `None` until assigned (e.g. waiting for DHCP).
+    pub virtual_ipv4: Option<String>,
+    /// Virtual IPv6 in CIDR notation, if one is configured.
+    pub virtual_ipv6: Option<String>,
+    pub listeners: Vec<String>,
+    pub public_ipv4: Option<String>,
+    pub public_ipv6: Option<String>,
+    pub interface_ipv4s: Vec<String>,
+    pub interface_ipv6s: Vec<String>,
+    pub stun: Option<RunningStunInfo>,
+}
+
+/// A single tunnel to a directly connected peer.
+pub struct RunningConnInfo {
+    pub conn_id: String,
+    pub tunnel_type: String,
+    pub local_addr: String,
+    pub remote_addr: String,
+    pub is_client: bool,
+    pub network_name: String,
+    pub latency_ms: f64,
+    pub loss_rate: f32,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+}
+
+impl From<&proto::api::instance::PeerConnInfo> for RunningConnInfo {
+    fn from(conn: &proto::api::instance::PeerConnInfo) -> Self {
+        let tunnel = conn.tunnel.clone().unwrap_or_default();
+        let stats = conn.stats.clone().unwrap_or_default();
+        Self {
+            conn_id: conn.conn_id.clone(),
+            tunnel_type: tunnel.tunnel_type,
+            local_addr: tunnel.local_addr.map(|u| u.url).unwrap_or_default(),
+            remote_addr: tunnel.remote_addr.map(|u| u.url).unwrap_or_default(),
+            is_client: conn.is_client,
+            network_name: conn.network_name.clone(),
+            latency_ms: stats.latency_us as f64 / 1000.0,
+            loss_rate: conn.loss_rate,
+            rx_bytes: stats.rx_bytes,
+            tx_bytes: stats.tx_bytes,
+            rx_packets: stats.rx_packets,
+            tx_packets: stats.tx_packets,
+        }
+    }
 }
 
+/// A directly connected peer and its tunnels.
+pub struct RunningPeerInfo {
+    pub peer_id: u32,
+    pub connections: Vec<RunningConnInfo>,
+}
+
+/// A route to a remote node, direct or relayed.
+pub struct RunningRouteInfo {
+    pub peer_id: u32,
+    pub inst_id: String,
+    pub hostname: String,
+    pub version: String,
+    pub ipv4: Option<String>,
+    pub ipv6: Option<String>,
+    pub next_hop_peer_id: u32,
+    pub cost: i32,
+    pub path_latency: i32,
+    pub proxy_cidrs: Vec<String>,
+    pub udp_nat_type: String,
+}
+
+impl From<&Route> for RunningRouteInfo {
+    fn from(route: &Route) -> Self {
+        Self {
+            peer_id: route.peer_id,
+            inst_id: route.inst_id.clone(),
+            hostname: route.hostname.clone(),
+            version: route.version.clone(),
+            ipv4: route.ipv4_addr.as_ref().and_then(format_ipv4_inet),
+            ipv6: route.ipv6_addr.as_ref().and_then(format_ipv6_inet),
+            next_hop_peer_id: route.next_hop_peer_id,
+            cost: route.cost,
+            path_latency: route.path_latency,
+            proxy_cidrs: route.proxy_cidrs.clone(),
+            udp_nat_type: route
+                .stun_info
+                .as_ref()
+                .map_or_else(|| nat_type_name(0), |stun| nat_type_name(stun.udp_nat_type)),
+        }
+    }
+}
+
+/// Snapshot of a running instance.
+pub struct RunningInfo {
+    pub dev_name: String,
+    pub running: bool,
+    pub error_msg: Option<String>,
+    pub my_node: Option<RunningNodeInfo>,
+    pub peers: Vec<RunningPeerInfo>,
+    pub routes: Vec<RunningRouteInfo>,
+}
+
+pub async fn get_running_info(instance_id: String) -> Result<RunningInfo, String> {
+    let id = parse_instance_id(&instance_id)?;
+    let info = get_instance_info(&instance_id).await?;
+    let virtual_ipv6 = local_ipv6(&id).as_ref().and_then(format_ipv6_inet);
+
+    let my_node = info.my_node_info.as_ref().map(|node| {
+        let ips = node.ips.clone().unwrap_or_default();
+        RunningNodeInfo {
+            peer_id: node.peer_id,
+            hostname: node.hostname.clone(),
+            version: node.version.clone(),
+            virtual_ipv4: node.virtual_ipv4.as_ref().and_then(format_ipv4_inet),
+            virtual_ipv6,
+            listeners: node.listeners.iter().map(|u| u.url.clone()).collect(),
+            public_ipv4: ips.public_ipv4.as_ref().map(format_ipv4),
+            public_ipv6: ips.public_ipv6.as_ref().map(format_ipv6),
+            interface_ipv4s: ips.interface_ipv4s.iter().map(format_ipv4).collect(),
+            interface_ipv6s: ips.interface_ipv6s.iter().map(format_ipv6).collect(),
+            stun: node.stun_info.as_ref().map(RunningStunInfo::from),
+        }
+    });
+
+    Ok(RunningInfo {
+        dev_name: info.dev_name,
+        running: info.running,
+        error_msg: info.error_msg,
+        my_node,
+        peers: info
+            .peers
+            .iter()
+            .map(|peer| RunningPeerInfo {
+                peer_id: peer.peer_id,
+                connections: peer.conns.iter().map(RunningConnInfo::from).collect(),
+            })
+            .collect(),
+        routes: info.routes.iter().map(RunningRouteInfo::from).collect(),
+    })
+}
+
+use super::network_config::{build_config, issues_to_string};
+pub use super::network_config::{ConfigBuildOptions, ConfigIssue, NetworkConfigC};
+pub use super::relay_select::RelayPool;
+use super::relay_select::{self, RelaySelector};
 pub use super::simple::{FlagsC, Forward};
 pub fn create_server(config_toml: String, watch_event: bool) -> JoinHandle<Result<String, String>> {
     RT.spawn(async move {
         let cfg = TomlConfigLoader::new_from_str(&config_toml)
             .map_err(|e| format!("invalid config toml: {}", e))?;
-        let instance_id = cfg.get_id();
+        // a shared file may carry the id of an instance that already runs here
+        if has_instance_config(&cfg.get_id()) {
+            cfg.set_id(Uuid::new_v4());
+        }
+        start_instance(cfg, watch_event)
+    })
+}
 
-        MANAGER
-            .run_network_instance(cfg, watch_event, ConfigFileControl::STATIC_CONFIG)
-            .map_err(|e| format!("start instance failed: {}", e))?;
+fn start_instance(cfg: TomlConfigLoader, watch_event: bool) -> Result<String, String> {
+    let instance_id = cfg.get_id();
+    run_instance(cfg, watch_event)?;
+    if super::supervisor::autostart_requested() {
+        super::supervisor::supervise(instance_id, Default::default());
+    }
+    Ok(instance_id.to_string())
+}
 
-        Ok(instance_id.to_string())
-    })
+fn run_instance(cfg: TomlConfigLoader, watch_event: bool) -> Result<(), String> {
+    let instance_id = cfg.get_id();
+
+    MANAGER
+        .run_network_instance(cfg.clone(), watch_event, ConfigFileControl::STATIC_CONFIG)
+        .map_err(|e| format!("start instance failed: {}", e))?;
+    CONFIGS.insert(instance_id, cfg);
+    if let Err(e) = watch_instance_events(&instance_id) {
+        eprintln!("watch events of instance {} failed: {}", instance_id, e);
+    }
+    Ok(())
+}
+
+pub(crate) fn has_instance_config(id: &Uuid) -> bool {
+    CONFIGS.contains_key(id)
+}
+
+/// Runs `f` on the cached config of an instance, the one restarts start from.
+pub(crate) fn with_instance_config<R>(
+    id: &Uuid,
+    f: impl FnOnce(&TomlConfigLoader) -> R,
+) -> Result<R, String> {
+    CONFIGS
+        .get(id)
+        .map(|cfg| f(&cfg))
+        .ok_or_else(|| "instance config not found".to_string())
+}
+
+/// Restarts an instance from its cached config, keeping its id. Used by the
+/// supervisor after the instance failed.
+pub(crate) fn restart_instance(id: &Uuid) -> Result<(), String> {
+    let cfg = CONFIGS
+        .get(id)
+        .map(|cfg| cfg.clone())
+        .ok_or_else(|| "instance config not found".to_string())?;
+    if MANAGER.list_network_instance_ids().contains(id) {
+        MANAGER
+            .delete_network_instance(vec![*id])
+            .map_err(|e| format!("delete instance failed: {}", e))?;
+    }
+    run_instance(cfg, true)
 }
 
+/// Starts an instance from the flags-style arguments. To pick relays from a
+/// `RelayPool` or to change the build options, use `create_server_with_config`.
 pub fn create_server_with_flags(
     username: String,
     enable_dhcp: bool,
@@ -293,136 +759,82 @@ pub fn create_server_with_flags(
     forwards: Vec<Forward>,
     flag: FlagsC,
 ) -> JoinHandle<Result<String, String>> {
-    RT.spawn(async move {
-        let cfg = TomlConfigLoader::default();
-
-        let mut listeners = Vec::new();
-        for url in onurl {
-            match url.parse() {
-                Ok(parsed) => listeners.push(parsed),
-                Err(e) => return Err(format!("invalid listener url: {}, error: {}", url, e)),
-            }
-        }
-        cfg.set_listeners(listeners);
-
-        cfg.set_hostname(Some(username));
-        cfg.set_dhcp(enable_dhcp);
-        for c in cidrs {
-            let _ = cfg.add_proxy_cidr(c.parse().unwrap(), None);
-        }
-        let mut old = cfg.get_port_forwards();
-
-        for c in forwards {
-            let port_forward_item = PortForwardConfig {
-                bind_addr: c.bind_addr.parse().unwrap(),
-                dst_addr: c.dst_addr.parse().unwrap(),
-                proto: c.proto,
-            };
-            old.push(port_forward_item);
-        }
+    create_server_with_config(NetworkConfigC {
+        username,
+        enable_dhcp,
+        specified_ip,
+        room_name,
+        room_password,
+        severurl,
+        onurl,
+        cidrs,
+        forwards,
+        flag,
+        options: ConfigBuildOptions::default(),
+        relay_pool: None,
+    })
+}
 
-        cfg.set_port_forwards(old);
-        let mut flags = cfg.get_flags();
-        flags.default_protocol = flag.default_protocol;
-        flags.dev_name = "astral".to_string();
-        flags.enable_encryption = flag.enable_encryption;
-        flags.enable_ipv6 = flag.enable_ipv6;
-        flags.mtu = flag.mtu;
-        flags.latency_first = flag.latency_first;
-        flags.enable_exit_node = flag.enable_exit_node;
-        flags.no_tun = flag.no_tun;
-        flags.use_smoltcp = flag.use_smoltcp;
-        flags.relay_network_whitelist = flag.relay_network_whitelist;
-        flags.disable_p2p = flag.disable_p2p;
-        flags.enable_udp_broadcast_relay = flag.enable_udp_broadcast_relay;
-        flags.relay_all_peer_rpc = flag.relay_all_peer_rpc;
-        flags.disable_udp_hole_punching = flag.disable_udp_hole_punching;
-        flags.disable_tcp_hole_punching = flag.disable_tcp_hole_punching;
-        flags.multi_thread = flag.multi_thread;
-        flags.data_compress_algo = flag.data_compress_algo;
-        flags.bind_device = flag.bind_device;
-        flags.enable_kcp_proxy = flag.enable_kcp_proxy;
-        flags.disable_kcp_input = flag.disable_kcp_input;
-        flags.disable_relay_kcp = flag.disable_relay_kcp;
-        flags.proxy_forward_by_system = flag.proxy_forward_by_system;
-        flags.accept_dns = flag.accept_dns;
-        flags.private_mode = flag.private_mode;
-        flags.enable_quic_proxy = flag.enable_quic_proxy;
-        flags.disable_quic_input = flag.disable_quic_input;
-        flags.disable_sym_hole_punching = flag.disable_sym_hole_punching;
-        cfg.set_flags(flags);
-
-        if flag.socks5_port > 0 {
-            let portal = format!("socks5://127.0.0.1:{}", flag.socks5_port);
-            match portal.parse() {
-                Ok(url) => cfg.set_socks5_portal(Some(url)),
-                Err(e) => return Err(format!("invalid socks5 portal: {}, error: {}", portal, e)),
+/// Validates `config` and starts an instance from it. All validation issues
+/// are reported in the error, see `validate_network_config`.
+///
+/// With a `relay_pool` the candidates are probed first and the best ones are
+/// connected in addition to `severurl`.
+pub fn create_server_with_config(mut config: NetworkConfigC) -> JoinHandle<Result<String, String>> {
+    RT.spawn(async move {
+        let mut cfg = build_config(&config).map_err(|issues| issues_to_string(&issues))?;
+
+        let mut selector = None;
+        if let Some(pool) = config.relay_pool.clone() {
+            let mut relays = RelaySelector::new(pool, &config.severurl);
+            let selected = relays.select().await;
+            if selected.is_empty() {
+                println!("no relay of the pool is reachable, starting without them");
             }
+            config.severurl.extend(selected);
+            cfg = build_config(&config).map_err(|issues| issues_to_string(&issues))?;
+            selector = Some(relays);
         }
 
-        if !flag.tcp_whitelist.is_empty() {
-            let tcp_ports: Vec<String> = flag
-                .tcp_whitelist
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect();
-            cfg.set_tcp_whitelist(tcp_ports);
-        }
-        if !flag.udp_whitelist.is_empty() {
-            let udp_ports: Vec<String> = flag
-                .udp_whitelist
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect();
-            cfg.set_udp_whitelist(udp_ports);
+        let instance_id = start_instance(cfg, true)?;
+        if let Some(selector) = selector {
+            relay_select::watch(parse_instance_id(&instance_id)?, selector);
         }
+        Ok(instance_id)
+    })
+}
 
-        let mut peer_configs = Vec::new();
-        for url in severurl {
-            match url.parse() {
-                Ok(uri) => peer_configs.push(PeerConfig {
-                    uri,
-                    peer_public_key: None,
-                }),
-                Err(e) => return Err(format!("invalid server url: {}, error: {}", url, e)),
-            }
-        }
-        cfg.set_peers(peer_configs);
-
-        if !enable_dhcp && !specified_ip.is_empty() {
-            let ip_str = format!("{}/24", specified_ip);
-            match ip_str.parse() {
-                Ok(ip) => cfg.set_ipv4(Some(ip)),
-                Err(e) => {
-                    return Err(format!(
-                        "invalid ip address: {}, error: {}",
-                        specified_ip, e
-                    ))
-                }
-            }
+/// Forgets an instance the supervisor gave up on: its config, relay watcher
+/// and event channel go, and whatever is left of it in the manager is deleted.
+pub(crate) fn discard_instance(id: &Uuid) {
+    relay_select::stop(id);
+    CONFIGS.remove(id);
+    if MANAGER.list_network_instance_ids().contains(id) {
+        if let Err(e) = MANAGER.delete_network_instance(vec![*id]) {
+            eprintln!("delete instance {} failed: {}", id, e);
         }
-
-        cfg.set_network_identity(NetworkIdentity::new(room_name, room_password));
-        let instance_id = cfg.get_id();
-
-        MANAGER
-            .run_network_instance(cfg, true, ConfigFileControl::STATIC_CONFIG)
-            .map_err(|e| format!("start instance failed: {}", e))?;
-
-        Ok(instance_id.to_string())
-    })
+    }
+    drop_event_sinks(&id.to_string());
 }
 
 pub fn close_server(instance_id: String) -> Result<(), String> {
     let id = parse_instance_id(&instance_id)?;
-    MANAGER
-        .delete_network_instance(vec![id])
-        .map_err(|e| format!("delete instance failed: {}", e))?;
+    super::supervisor::stop(&id);
+    relay_select::stop(&id);
+    // forget the config first, so the event task sees the instance as closed
+    let cfg = CONFIGS.remove(&id);
+    if let Err(e) = MANAGER.delete_network_instance(vec![id]) {
+        if let Some((id, cfg)) = cfg {
+            CONFIGS.insert(id, cfg);
+        }
+        return Err(format!("delete instance failed: {}", e));
+    }
+    // without an event task nothing else would drop the channel
+    EVENT_CHANNELS.remove_if(&id.to_string(), |_, channel| !channel.watched);
     Ok(())
 }
 pub async fn get_peer_route_pairs(instance_id: String) -> Result<Vec<PeerRoutePair>, String> {
+    let id = parse_instance_id(&instance_id)?;
     let info = get_instance_info(&instance_id).await?;
 
     let mut pairs = info.peer_route_pairs;
@@ -438,7 +850,7 @@ pub async fn get_peer_route_pairs(instance_id: String) -> Result<Vec<PeerRoutePa
         let my_route = Route {
             peer_id: my_peer_id,
             ipv4_addr: my_node_info.virtual_ipv4.clone(),
-            ipv6_addr: None,
+            ipv6_addr: local_ipv6(&id),
             next_hop_peer_id: my_peer_id,
             cost: 0,
             path_latency: 0,
@@ -451,7 +863,14 @@ pub async fn get_peer_route_pairs(instance_id: String) -> Result<Vec<PeerRoutePa
             next_hop_peer_id_latency_first: None,
             cost_latency_first: None,
             path_latency_latency_first: None,
-            public_ipv6_addr: None,
+            public_ipv6_addr: my_node_info
+                .ips
+                .as_ref()
+                .and_then(|ips| ips.public_ipv6.clone())
+                .map(|addr| proto::common::Ipv6Inet {
+                    address: Some(addr),
+                    network_length: 128,
+                }),
             ipv6_public_addr_prefix: None,
         };
 
@@ -492,15 +911,7 @@ pub async fn get_network_status(instance_id: String) -> KVNetworkStatus {
                 .ipv4_addr
                 .as_ref()
                 .and_then(|addr| addr.address.as_ref())
-                .map(|a| {
-                    format!(
-                        "{}.{}.{}.{}",
-                        (a.addr >> 24) & 0xFF,
-                        (a.addr >> 16) & 0xFF,
-                        (a.addr >> 8) & 0xFF,
-                        a.addr & 0xFF
-                    )
-                })
+                .map(format_ipv4)
                 .unwrap_or_else(|| "0.0.0.0".to_string());
 
             let mut node_info = KVNodeInfo {
@@ -528,15 +939,7 @@ pub async fn get_network_status(instance_id: String) -> KVNetworkStatus {
                                     .ipv4_addr
                                     .as_ref()
                                     .and_then(|addr| addr.address.as_ref())
-                                    .map(|a| {
-                                        format!(
-                                            "{}.{}.{}.{}",
-                                            (a.addr >> 24) & 0xFF,
-                                            (a.addr >> 16) & 0xFF,
-                                            (a.addr >> 8) & 0xFF,
-                                            a.addr & 0xFF
-                                        )
-                                    })
+                                    .map(format_ipv4)
                                     .unwrap_or_default();
 
                                 let (latency, loss) = pair.peer.as_ref().map_or((0.0, 0.0), |p| {
@@ -585,15 +988,7 @@ pub async fn get_network_status(instance_id: String) -> KVNetworkStatus {
                             .ipv4_addr
                             .as_ref()
                             .and_then(|addr| addr.address.as_ref())
-                            .map(|a| {
-                                format!(
-                                    "{}.{}.{}.{}",
-                                    (a.addr >> 24) & 0xFF,
-                                    (a.addr >> 16) & 0xFF,
-                                    (a.addr >> 8) & 0xFF,
-                                    a.addr & 0xFF
-                                )
-                            })
+                            .map(format_ipv4)
                             .unwrap_or_default();
 
                         if let Some(info) = &running_info {
@@ -604,15 +999,7 @@ pub async fn get_network_status(instance_id: String) -> KVNetworkStatus {
                                         .virtual_ipv4
                                         .as_ref()
                                         .and_then(|addr| addr.address.as_ref())
-                                        .map(|a| {
-                                            format!(
-                                                "{}.{}.{}.{}",
-                                                (a.addr >> 24) & 0xFF,
-                                                (a.addr >> 16) & 0xFF,
-                                                (a.addr >> 8) & 0xFF,
-                                                a.addr & 0xFF
-                                            )
-                                        })
+                                        .map(format_ipv4)
                                         .unwrap_or_else(|| local_node.hostname.clone()),
                                     latency_ms: 0.0,
                                     packet_loss: 0.0,
@@ -706,6 +1093,16 @@ pub async fn get_network_status(instance_id: String) -> KVNetworkStatus {
                     route.path_latency_latency_first() as f64
                 },
                 ipv4,
+                ipv6: route.ipv6_addr.as_ref().and_then(format_ipv6_inet),
+                public_ipv6: route
+                    .public_ipv6_addr
+                    .as_ref()
+                    .and_then(|inet| inet.address.as_ref())
+                    .map(format_ipv6),
+                ipv6_prefix: route
+                    .ipv6_public_addr_prefix
+                    .as_ref()
+                    .and_then(format_ipv6_inet),
                 loss_rate: if let Some(peer) = &pair.peer {
                     let mut total_loss_rate = 0.0;
                     for conn in &peer.conns {
@@ -0,0 +1,93 @@
+//! 以 UUID 句柄管理后台服务的共享注册表
+//!
+//! 端口转发、组播发送等子系统都通过它保存实例，句柄在实例被移除前始终有效，
+//! 不会像 Vec 索引那样在清理后错位。
+
+use tokio::sync::{Mutex, MutexGuard};
+use uuid::Uuid;
+
+/// 可以被注册表停止的后台服务
+pub(crate) trait RegistryEntry {
+    async fn stop(&mut self);
+}
+
+/// 注册表中的一条记录，按创建顺序保存
+pub(crate) struct Registered<T> {
+    pub id: String,
+    pub value: T,
+}
+
+pub(crate) struct HandleRegistry<T> {
+    entries: Mutex<Vec<Registered<T>>>,
+}
+
+impl<T: RegistryEntry> HandleRegistry<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 保存实例并返回新分配的句柄
+    pub(crate) async fn insert(&self, value: T) -> String {
+        let id = Uuid::new_v4().to_string();
+        self.entries.lock().await.push(Registered {
+            id: id.clone(),
+            value,
+        });
+        id
+    }
+
+    /// 锁定注册表，按创建顺序访问全部记录
+    pub(crate) async fn lock(&self) -> MutexGuard<'_, Vec<Registered<T>>> {
+        self.entries.lock().await
+    }
+
+    /// 对指定句柄的实例执行只读操作
+    pub(crate) async fn with<R>(&self, id: &str, f: impl FnOnce(&T) -> R) -> Result<R, String> {
+        let entries = self.entries.lock().await;
+        entries
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| f(&entry.value))
+            .ok_or_else(|| format!("无效的句柄: {}", id))
+    }
+
+    /// 停止实例但保留记录，便于界面继续展示其状态
+    pub(crate) async fn stop(&self, id: &str) -> Result<(), String> {
+        let mut entries = self.entries.lock().await;
+        let entry = entries
+            .iter_mut()
+            .find(|entry| entry.id == id)
+            .ok_or_else(|| format!("无效的句柄: {}", id))?;
+        entry.value.stop().await;
+        Ok(())
+    }
+
+    /// 停止实例并从注册表中移除
+    pub(crate) async fn remove(&self, id: &str) -> Result<(), String> {
+        let mut entries = self.entries.lock().await;
+        let index = entries
+            .iter()
+            .position(|entry| entry.id == id)
+            .ok_or_else(|| format!("无效的句柄: {}", id))?;
+        let mut entry = entries.remove(index);
+        entry.value.stop().await;
+        Ok(())
+    }
+
+    /// 停止并移除全部实例，返回被移除的句柄
+    pub(crate) async fn remove_all(&self) -> Vec<String> {
+        let mut entries = self.entries.lock().await;
+        let mut ids = Vec::with_capacity(entries.len());
+        for mut entry in entries.drain(..) {
+            entry.value.stop().await;
+            ids.push(entry.id);
+        }
+        ids
+    }
+
+    pub(crate) async fn len(&self) -> usize {
+        self.entries.lock().await.len()
+    }
+}
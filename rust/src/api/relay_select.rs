@@ -0,0 +1,391 @@
+//! Automatic choice of public relay servers by measured latency.
+//!
+//! The candidates of a `RelayPool` are probed in parallel with `server_probe`
+//! and the instance connects to the `best_n` with the lowest score, the handshake
+//! RTT divided by the success rate, both as exponentially weighted moving
+//! averages so recent probes count most. While the instance runs, the pool
+//! is probed again every `reevaluate_interval_secs` and a connected relay is
+//! swapped out through `patch_instance` once a candidate beats it by
+//! `min_improvement_ms`.
+
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use super::instance_patch::{patch_instance, InstancePatch, PatchAction, ValuePatch};
+use super::server_probe::{probe_all, ServerProbeResult};
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+/// Weight of the newest probe in the moving averages. A relay that degrades
+/// loses its place within a few rounds instead of living off its history.
+const EWMA_ALPHA: f64 = 0.3;
+/// Shortest accepted re-evaluation interval; a round of probes takes up to
+/// `PROBE_TIMEOUT` per stage.
+pub(crate) const MIN_REEVALUATE_INTERVAL_SECS: u64 = 30;
+
+lazy_static! {
+    static ref RT: Runtime = Runtime::new().expect("failed to create tokio runtime");
+    static ref SELECTORS: DashMap<Uuid, CancellationToken> = DashMap::new();
+}
+
+/// A pool of relay servers to choose from when starting an instance.
+#[derive(Debug, Clone)]
+pub struct RelayPool {
+    /// Candidate server URLs. Entries already in `severurl` are ignored.
+    pub candidates: Vec<String>,
+    /// Number of relays to connect to.
+    pub best_n: usize,
+    /// Seconds between re-evaluations, at least `MIN_REEVALUATE_INTERVAL_SECS`;
+    /// 0 keeps the initial choice.
+    pub reevaluate_interval_secs: u64,
+    /// How much lower a candidate's score must be before it replaces a
+    /// connected relay, so close scores don't cause flapping. Must be positive.
+    pub min_improvement_ms: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct CandidateStats {
+    /// Moving average of the RTT of successful probes.
+    rtt_ms: Option<f64>,
+    /// Moving average of the probe outcomes, 1 for a success and 0 for a failure.
+    success_rate: Option<f64>,
+}
+
+fn ewma(average: Option<f64>, sample: f64) -> f64 {
+    average.map_or(sample, |average| average + EWMA_ALPHA * (sample - average))
+}
+
+impl CandidateStats {
+    fn record(&mut self, result: &ServerProbeResult) {
+        match (result.reachable, result.rtt_ms) {
+            (true, Some(rtt_ms)) => {
+                self.success_rate = Some(ewma(self.success_rate, 1.0));
+                self.rtt_ms = Some(ewma(self.rtt_ms, rtt_ms as f64));
+            }
+            _ => self.success_rate = Some(ewma(self.success_rate, 0.0)),
+        }
+    }
+
+    /// Lower is better; never-reachable candidates score infinity.
+    fn score(&self) -> f64 {
+        match (self.rtt_ms, self.success_rate) {
+            (Some(rtt_ms), Some(success_rate)) if success_rate > 0.0 => rtt_ms / success_rate,
+            _ => f64::INFINITY,
+        }
+    }
+}
+
+pub(crate) struct RelaySelector {
+    pool: RelayPool,
+    stats: Vec<CandidateStats>,
+    /// Indices into `pool.candidates` the instance is connected to.
+    selected: Vec<usize>,
+}
+
+impl RelaySelector {
+    pub(crate) fn new(mut pool: RelayPool, severurl: &[String]) -> Self {
+        let mut candidates: Vec<String> = Vec::new();
+        for url in pool.candidates.drain(..) {
+            if !severurl.contains(&url) && !candidates.contains(&url) {
+                candidates.push(url);
+            }
+        }
+        pool.candidates = candidates;
+        Self {
+            stats: vec![CandidateStats::default(); pool.candidates.len()],
+            selected: Vec::new(),
+            pool,
+        }
+    }
+
+    async fn probe_round(&mut self) {
+        let results = probe_all(&self.pool.candidates, PROBE_TIMEOUT).await;
+        for (stats, result) in self.stats.iter_mut().zip(&results) {
+            stats.record(result);
+        }
+    }
+
+    /// Reachable candidates that aren't selected, best first.
+    fn challengers(&self) -> Vec<usize> {
+        let mut challengers: Vec<usize> = (0..self.stats.len())
+            .filter(|i| !self.selected.contains(i) && self.stats[*i].score().is_finite())
+            .collect();
+        challengers.sort_by(|a, b| self.stats[*a].score().total_cmp(&self.stats[*b].score()));
+        challengers
+    }
+
+    fn url(&self, i: usize) -> String {
+        self.pool.candidates[i].clone()
+    }
+
+    /// Probes the pool and picks the initial relays.
+    pub(crate) async fn select(&mut self) -> Vec<String> {
+        self.probe_round().await;
+        self.pick()
+    }
+
+    fn pick(&mut self) -> Vec<String> {
+        self.selected = self
+            .challengers()
+            .into_iter()
+            .take(self.pool.best_n)
+            .collect();
+        self.selected.iter().map(|i| self.url(*i)).collect()
+    }
+
+    /// Probes the pool again and returns the relays to remove and to add.
+    async fn reevaluate(&mut self) -> (Vec<String>, Vec<String>) {
+        self.probe_round().await;
+        self.rebalance()
+    }
+
+    /// Fills free slots and swaps out relays that a challenger beats by at
+    /// least `min_improvement_ms`.
+    fn rebalance(&mut self) -> (Vec<String>, Vec<String>) {
+        let (mut removed, mut added) = (Vec::new(), Vec::new());
+
+        for challenger in self.challengers() {
+            if self.selected.len() < self.pool.best_n {
+                self.selected.push(challenger);
+                added.push(self.url(challenger));
+                continue;
+            }
+            let Some((pos, worst)) = self
+                .selected
+                .iter()
+                .copied()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| self.stats[*a].score().total_cmp(&self.stats[*b].score()))
+            else {
+                break;
+            };
+            let improvement = self.stats[worst].score() - self.stats[challenger].score();
+            if improvement < self.pool.min_improvement_ms as f64 {
+                break;
+            }
+            self.selected[pos] = challenger;
+            removed.push(self.url(worst));
+            added.push(self.url(challenger));
+        }
+        (removed, added)
+    }
+}
+
+/// Keeps re-evaluating the relays of a running instance until `stop`.
+pub(crate) fn watch(id: Uuid, mut selector: RelaySelector) {
+    if selector.pool.reevaluate_interval_secs == 0 {
+        return;
+    }
+    let interval = Duration::from_secs(selector.pool.reevaluate_interval_secs);
+    let cancel = CancellationToken::new();
+    if let Some(old) = SELECTORS.insert(id, cancel.clone()) {
+        old.cancel();
+    }
+
+    RT.spawn(async move {
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = tokio::time::sleep(interval) => {}
+            }
+
+            let previous = selector.selected.clone();
+            let (removed, added) = selector.reevaluate().await;
+            if removed.is_empty() && added.is_empty() {
+                continue;
+            }
+            println!(
+                "instance {} switching relays, removed: {:?}, added: {:?}",
+                id, removed, added
+            );
+            let peers = removed
+                .into_iter()
+                .map(|value| ValuePatch {
+                    action: PatchAction::Remove,
+                    value,
+                })
+                .chain(added.into_iter().map(|value| ValuePatch {
+                    action: PatchAction::Add,
+                    value,
+                }))
+                .collect();
+            let patch = InstancePatch {
+                peers,
+                ..Default::default()
+            };
+            if let Err(e) = patch_instance(id.to_string(), patch).await {
+                eprintln!("switch relays of instance {} failed: {}", id, e);
+                selector.selected = previous;
+            }
+        }
+    });
+}
+
+/// Stops re-evaluating the relays of an instance.
+pub(crate) fn stop(id: &Uuid) {
+    if let Some((_, cancel)) = SELECTORS.remove(id) {
+        cancel.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe_result(url: &str, rtt_ms: Option<i64>) -> ServerProbeResult {
+        ServerProbeResult {
+            url: url.to_string(),
+            reachable: rtt_ms.is_some(),
+            resolved_addrs: Vec::new(),
+            dns_ms: None,
+            connect_ms: None,
+            rtt_ms,
+            remote_peer_id: None,
+            error_kind: None,
+            error: None,
+        }
+    }
+
+    fn selector(candidates: &[&str], best_n: usize, min_improvement_ms: i64) -> RelaySelector {
+        RelaySelector::new(
+            RelayPool {
+                candidates: candidates.iter().map(|c| c.to_string()).collect(),
+                best_n,
+                reevaluate_interval_secs: 60,
+                min_improvement_ms,
+            },
+            &[],
+        )
+    }
+
+    /// Records one probe round; `None` is a failed probe.
+    fn round(selector: &mut RelaySelector, rtts: &[Option<i64>]) {
+        for (i, rtt_ms) in rtts.iter().enumerate() {
+            let result = probe_result(&selector.pool.candidates[i], *rtt_ms);
+            selector.stats[i].record(&result);
+        }
+    }
+
+    #[test]
+    fn scores_follow_moving_averages() {
+        let mut stats = CandidateStats::default();
+        assert_eq!(stats.score(), f64::INFINITY);
+
+        stats.record(&probe_result("a", Some(100)));
+        assert_eq!(stats.score(), 100.0);
+
+        stats.record(&probe_result("a", Some(200)));
+        // 100 + 0.3 * (200 - 100)
+        assert!((stats.rtt_ms.unwrap() - 130.0).abs() < 1e-9);
+        assert_eq!(stats.success_rate, Some(1.0));
+
+        stats.record(&probe_result("a", None));
+        // a failure keeps the RTT and lowers the success rate to 0.7
+        assert!((stats.rtt_ms.unwrap() - 130.0).abs() < 1e-9);
+        assert!((stats.success_rate.unwrap() - 0.7).abs() < 1e-9);
+        assert!((stats.score() - 130.0 / 0.7).abs() < 1e-9);
+
+        let mut never = CandidateStats::default();
+        never.record(&probe_result("b", None));
+        never.record(&probe_result("b", None));
+        assert_eq!(never.score(), f64::INFINITY);
+    }
+
+    #[test]
+    fn recent_probes_outweigh_history() {
+        let mut stats = CandidateStats::default();
+        for _ in 0..20 {
+            stats.record(&probe_result("a", Some(20)));
+        }
+        for _ in 0..5 {
+            stats.record(&probe_result("a", Some(300)));
+        }
+        // over 80% of the way to the new RTT after five rounds
+        assert!(stats.rtt_ms.unwrap() > 250.0);
+    }
+
+    #[test]
+    fn duplicates_and_configured_servers_are_ignored() {
+        let selector = RelaySelector::new(
+            RelayPool {
+                candidates: vec!["tcp://a:1".into(), "tcp://b:1".into(), "tcp://a:1".into()],
+                best_n: 1,
+                reevaluate_interval_secs: 0,
+                min_improvement_ms: 10,
+            },
+            &["tcp://b:1".to_string()],
+        );
+        assert_eq!(selector.pool.candidates, ["tcp://a:1"]);
+        assert_eq!(selector.stats.len(), 1);
+    }
+
+    #[test]
+    fn pick_takes_the_best_reachable() {
+        let mut selector = selector(&["a", "b", "c", "d"], 2, 10);
+        round(&mut selector, &[Some(80), None, Some(20), Some(50)]);
+        assert_eq!(selector.pick(), ["c", "d"]);
+
+        let mut selector = selector_with_one_reachable();
+        assert_eq!(selector.pick(), ["b"]);
+    }
+
+    fn selector_with_one_reachable() -> RelaySelector {
+        let mut selector = selector(&["a", "b"], 2, 10);
+        round(&mut selector, &[None, Some(40)]);
+        selector
+    }
+
+    #[test]
+    fn rebalance_needs_min_improvement() {
+        let mut selector = selector(&["a", "b", "c"], 1, 50);
+        round(&mut selector, &[Some(100), Some(120), Some(200)]);
+        assert_eq!(selector.pick(), ["a"]);
+
+        // b gets better, but not by 50ms
+        round(&mut selector, &[Some(100), Some(0), Some(200)]);
+        assert!(selector.stats[1].score() < selector.stats[0].score());
+        assert_eq!(selector.rebalance(), (vec![], vec![]));
+
+        // a degrades until b beats it by more than 50ms
+        round(&mut selector, &[Some(400), Some(0), Some(200)]);
+        assert_eq!(
+            selector.rebalance(),
+            (vec!["a".to_string()], vec!["b".to_string()])
+        );
+        assert_eq!(selector.selected, [1]);
+
+        // the replaced relay has to win by the margin as well to come back
+        round(&mut selector, &[Some(100), Some(60), Some(200)]);
+        assert_eq!(selector.rebalance(), (vec![], vec![]));
+    }
+
+    #[test]
+    fn rebalance_fills_free_slots() {
+        let mut selector = selector_with_one_reachable();
+        assert_eq!(selector.pick(), ["b"]);
+
+        round(&mut selector, &[Some(300), Some(40)]);
+        assert_eq!(selector.rebalance(), (vec![], vec!["a".to_string()]));
+        assert_eq!(selector.selected, [1, 0]);
+        assert_eq!(selector.rebalance(), (vec![], vec![]));
+    }
+
+    #[test]
+    fn rebalance_replaces_the_worst_relay() {
+        let mut selector = selector(&["a", "b", "c"], 2, 10);
+        round(&mut selector, &[Some(50), Some(90), None]);
+        assert_eq!(selector.pick(), ["a", "b"]);
+
+        for _ in 0..5 {
+            round(&mut selector, &[Some(50), Some(90), Some(10)]);
+        }
+        assert_eq!(
+            selector.rebalance(),
+            (vec!["b".to_string()], vec!["c".to_string()])
+        );
+        assert_eq!(selector.selected, [0, 2]);
+    }
+}
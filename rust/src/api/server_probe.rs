@@ -0,0 +1,325 @@
+//! Reachability checks for server/relay URLs.
+//!
+//! Each URL is probed in three stages, each timed separately: DNS resolution,
+//! the transport handshake (TCP/UDP/WS/QUIC/... through EasyTier's own
+//! connectors), and an EasyTier peer handshake, which proves there is an
+//! EasyTier node behind the address and not just an open port.
+
+use easytier::common::config::{ConfigLoader, NetworkIdentity, TomlConfigLoader};
+use easytier::common::global_ctx::{ArcGlobalCtx, GlobalCtx};
+use easytier::connector::create_connector_by_url;
+use easytier::peers::peer_conn::PeerConn;
+use easytier::tunnel::{IpVersion, TunnelConnector};
+use lazy_static::lazy_static;
+use std::error::Error;
+use std::io;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::runtime::Runtime;
+use tokio::task::JoinSet;
+
+lazy_static! {
+    static ref RT: Runtime = Runtime::new().expect("failed to create tokio runtime");
+}
+
+const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Network name presented in the handshake. Public servers accept any
+/// network; the probe disconnects right after the handshake.
+const PROBE_NETWORK_NAME: &str = "astral-probe";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbeErrorKind {
+    InvalidUrl,
+    Dns,
+    Refused,
+    Timeout,
+    Tls,
+    /// The transport connected but the EasyTier handshake failed.
+    Handshake,
+    /// The server accepted the connection and closed it during the EasyTier
+    /// handshake. Servers that don't relay foreign networks do this for the
+    /// probe's network name, so the address itself works, for the networks
+    /// the server accepts.
+    NetworkRejected,
+    Other,
+}
+
+#[derive(Debug, Clone)]
+pub struct ServerProbeResult {
+    pub url: String,
+    pub reachable: bool,
+    pub resolved_addrs: Vec<String>,
+    pub dns_ms: Option<i64>,
+    /// Time to establish the transport connection.
+    pub connect_ms: Option<i64>,
+    /// Round trip of the EasyTier handshake over the established connection.
+    pub rtt_ms: Option<i64>,
+    pub remote_peer_id: Option<u32>,
+    pub error_kind: Option<ProbeErrorKind>,
+    pub error: Option<String>,
+}
+
+impl ServerProbeResult {
+    fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            reachable: false,
+            resolved_addrs: Vec::new(),
+            dns_ms: None,
+            connect_ms: None,
+            rtt_ms: None,
+            remote_peer_id: None,
+            error_kind: None,
+            error: None,
+        }
+    }
+
+    fn fail(mut self, kind: ProbeErrorKind, error: impl ToString) -> Self {
+        self.error_kind = Some(kind);
+        self.error = Some(error.to_string());
+        self
+    }
+}
+
+/// Finds the I/O error behind a connector or handshake error, if any.
+fn io_error_kind(error: &(dyn Error + 'static)) -> Option<io::ErrorKind> {
+    let mut current = Some(error);
+    while let Some(e) = current {
+        if let Some(e) = e.downcast_ref::<io::Error>() {
+            return Some(e.kind());
+        }
+        current = e.source();
+    }
+    None
+}
+
+fn classify_connect_error(error: &(dyn Error + 'static)) -> ProbeErrorKind {
+    match io_error_kind(error) {
+        Some(io::ErrorKind::ConnectionRefused | io::ErrorKind::ConnectionReset) => {
+            ProbeErrorKind::Refused
+        }
+        Some(io::ErrorKind::TimedOut) => ProbeErrorKind::Timeout,
+        // TLS and QUIC failures don't carry an I/O error
+        _ => classify_message(&error.to_string()),
+    }
+}
+
+fn classify_handshake_error(error: &(dyn Error + 'static)) -> ProbeErrorKind {
+    match io_error_kind(error) {
+        Some(
+            io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::UnexpectedEof
+            | io::ErrorKind::BrokenPipe,
+        ) => ProbeErrorKind::NetworkRejected,
+        Some(io::ErrorKind::TimedOut) => ProbeErrorKind::Timeout,
+        Some(_) => ProbeErrorKind::Handshake,
+        // EasyTier reports a tunnel that ended before the response as a
+        // plain message rather than an I/O error
+        None if error.to_string().to_lowercase().contains("closed") => {
+            ProbeErrorKind::NetworkRejected
+        }
+        None => ProbeErrorKind::Handshake,
+    }
+}
+
+fn classify_message(error: &str) -> ProbeErrorKind {
+    let error = error.to_lowercase();
+    if error.contains("refused") || error.contains("reset") {
+        ProbeErrorKind::Refused
+    } else if error.contains("timeout") || error.contains("timed out") {
+        ProbeErrorKind::Timeout
+    } else if error.contains("tls") || error.contains("cert") {
+        ProbeErrorKind::Tls
+    } else {
+        ProbeErrorKind::Other
+    }
+}
+
+fn default_port(scheme: &str) -> Option<u16> {
+    match scheme {
+        "tcp" | "udp" | "faketcp" => Some(11010),
+        "wg" => Some(11011),
+        "quic" => Some(11012),
+        "ws" => Some(80),
+        "wss" => Some(443),
+        _ => None,
+    }
+}
+
+fn probe_global_ctx() -> ArcGlobalCtx {
+    let cfg = TomlConfigLoader::default();
+    cfg.set_network_identity(NetworkIdentity::new(
+        PROBE_NETWORK_NAME.to_string(),
+        String::new(),
+    ));
+    Arc::new(GlobalCtx::new(cfg))
+}
+
+async fn probe(url: String, global_ctx: ArcGlobalCtx, timeout: Duration) -> ServerProbeResult {
+    let mut result = ServerProbeResult::new(&url);
+
+    let parsed = match url.trim().parse::<url::Url>() {
+        Ok(parsed) => parsed,
+        Err(e) => return result.fail(ProbeErrorKind::InvalidUrl, e),
+    };
+    let Some(host) = parsed.host_str() else {
+        return result.fail(ProbeErrorKind::InvalidUrl, "missing host");
+    };
+    let Some(port) = parsed.port().or_else(|| default_port(parsed.scheme())) else {
+        return result.fail(ProbeErrorKind::InvalidUrl, "missing port");
+    };
+
+    let start = Instant::now();
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    match tokio::time::timeout(timeout, tokio::net::lookup_host((host, port))).await {
+        Ok(Ok(addrs)) => {
+            result.dns_ms = Some(start.elapsed().as_millis() as i64);
+            result.resolved_addrs = addrs.map(|addr| addr.to_string()).collect();
+        }
+        Ok(Err(e)) => return result.fail(ProbeErrorKind::Dns, e),
+        Err(_) => return result.fail(ProbeErrorKind::Dns, "DNS resolution timeout"),
+    }
+    if result.resolved_addrs.is_empty() {
+        return result.fail(ProbeErrorKind::Dns, "no address found");
+    }
+
+    let start = Instant::now();
+    let mut connector =
+        match create_connector_by_url(url.trim(), &global_ctx, IpVersion::Both).await {
+            Ok(connector) => connector,
+            Err(e) => return result.fail(ProbeErrorKind::InvalidUrl, e),
+        };
+    let tunnel = match tokio::time::timeout(timeout, connector.connect()).await {
+        Ok(Ok(tunnel)) => tunnel,
+        Ok(Err(e)) => return result.fail(classify_connect_error(&e), e),
+        Err(_) => return result.fail(ProbeErrorKind::Timeout, "connect timeout"),
+    };
+    result.connect_ms = Some(start.elapsed().as_millis() as i64);
+
+    let start = Instant::now();
+    let mut peer_conn = PeerConn::new(rand::random(), global_ctx, tunnel);
+    match tokio::time::timeout(timeout, peer_conn.do_handshake_as_client()).await {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => return result.fail(classify_handshake_error(&e), e),
+        Err(_) => return result.fail(ProbeErrorKind::Timeout, "handshake timeout"),
+    }
+    result.rtt_ms = Some(start.elapsed().as_millis() as i64);
+    result.remote_peer_id = Some(peer_conn.get_peer_id());
+    result.reachable = true;
+    result
+}
+
+/// Probes all URLs in parallel; results are in the order of `urls`.
+pub(crate) async fn probe_all(urls: &[String], timeout: Duration) -> Vec<ServerProbeResult> {
+    let global_ctx = probe_global_ctx();
+    let mut probes = JoinSet::new();
+    for (i, url) in urls.iter().enumerate() {
+        let probe = probe(url.clone(), global_ctx.clone(), timeout);
+        probes.spawn(async move { (i, probe.await) });
+    }
+
+    let mut results: Vec<ServerProbeResult> = urls
+        .iter()
+        .map(|url| ServerProbeResult::new(url).fail(ProbeErrorKind::Other, "probe aborted"))
+        .collect();
+    while let Some(joined) = probes.join_next().await {
+        if let Ok((i, result)) = joined {
+            results[i] = result;
+        }
+    }
+    results
+}
+
+/// Server probe entry for Flutter. `timeout_ms` applies to each stage and
+/// defaults to 5 seconds.
+pub fn probe_servers(
+    urls: Vec<String>,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<ServerProbeResult>, String> {
+    let timeout = timeout_ms
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_PROBE_TIMEOUT);
+
+    Ok(RT.block_on(probe_all(&urls, timeout)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An error that wraps another, like EasyTier's tunnel and handshake errors.
+    #[derive(Debug)]
+    struct Wrapped(io::Error);
+
+    impl std::fmt::Display for Wrapped {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "tunnel error")
+        }
+    }
+
+    impl Error for Wrapped {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    fn wrapped(kind: io::ErrorKind) -> Wrapped {
+        Wrapped(io::Error::new(kind, "inner"))
+    }
+
+    #[test]
+    fn connect_errors_are_classified_by_io_kind() {
+        for (kind, expected) in [
+            (io::ErrorKind::ConnectionRefused, ProbeErrorKind::Refused),
+            (io::ErrorKind::ConnectionReset, ProbeErrorKind::Refused),
+            (io::ErrorKind::TimedOut, ProbeErrorKind::Timeout),
+            (io::ErrorKind::PermissionDenied, ProbeErrorKind::Other),
+        ] {
+            assert_eq!(
+                classify_connect_error(&wrapped(kind)),
+                expected,
+                "{:?}",
+                kind
+            );
+        }
+        let tls = io::Error::other("invalid peer certificate");
+        assert_eq!(classify_connect_error(&tls), ProbeErrorKind::Tls);
+    }
+
+    #[test]
+    fn closed_handshake_means_rejected_network() {
+        for kind in [
+            io::ErrorKind::ConnectionReset,
+            io::ErrorKind::ConnectionAborted,
+            io::ErrorKind::UnexpectedEof,
+            io::ErrorKind::BrokenPipe,
+        ] {
+            assert_eq!(
+                classify_handshake_error(&wrapped(kind)),
+                ProbeErrorKind::NetworkRejected,
+                "{:?}",
+                kind
+            );
+        }
+        assert_eq!(
+            classify_handshake_error(&wrapped(io::ErrorKind::TimedOut)),
+            ProbeErrorKind::Timeout
+        );
+        assert_eq!(
+            classify_handshake_error(&wrapped(io::ErrorKind::InvalidData)),
+            ProbeErrorKind::Handshake
+        );
+
+        let closed: Box<dyn Error> = "conn closed during wait handshake response".into();
+        assert_eq!(
+            classify_handshake_error(closed.as_ref()),
+            ProbeErrorKind::NetworkRejected
+        );
+        let invalid: Box<dyn Error> = "invalid handshake packet".into();
+        assert_eq!(
+            classify_handshake_error(invalid.as_ref()),
+            ProbeErrorKind::Handshake
+        );
+    }
+}
@@ -0,0 +1,365 @@
+//! RFC 5389/5780 STUN server for self-hosted NAT detection.
+//!
+//! The server listens on two IPs and two ports. UDP is served on all four
+//! combinations so `CHANGE-REQUEST` can be honored by answering from another
+//! socket, and TCP on all four so the TCP mapping tests work as well. Every
+//! response carries `OTHER-ADDRESS`, which `test_nat_behavior` needs to
+//! classify mapping and filtering.
+
+use lazy_static::lazy_static;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream, UdpSocket};
+use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use super::backoff::{is_transient, Backoff};
+use super::nat_test::{
+    StunMessage, ATTR_MAPPED_ADDRESS, ATTR_OTHER_ADDRESS, ATTR_RESPONSE_ORIGIN,
+    ATTR_XOR_MAPPED_ADDRESS, STUN_BINDING_REQUEST, STUN_BINDING_SUCCESS,
+};
+use super::registry::{HandleRegistry, RegistryEntry};
+
+lazy_static! {
+    static ref RT: Runtime = Runtime::new().expect("failed to create tokio runtime");
+    static ref STUN_SERVERS: HandleRegistry<StunServer> = HandleRegistry::new();
+}
+
+/// Largest STUN message accepted over TCP.
+const MAX_TCP_MESSAGE: usize = 1500;
+
+#[derive(Debug, Clone)]
+pub struct StunServerInfo {
+    pub id: String,
+    pub primary_addr: String,
+    pub alternate_addr: String,
+    pub running: bool,
+    /// Binding requests answered so far, over UDP and TCP.
+    pub requests: u64,
+}
+
+struct StunServer {
+    /// `addrs[ip][port]`, index 0 is the primary IP or port.
+    addrs: [[SocketAddr; 2]; 2],
+    requests: Arc<AtomicU64>,
+    cancel: CancellationToken,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl StunServer {
+    async fn start(
+        primary_ip: IpAddr,
+        alternate_ip: IpAddr,
+        primary_port: u16,
+        alternate_port: u16,
+    ) -> io::Result<Self> {
+        let addrs = [primary_ip, alternate_ip]
+            .map(|ip| [primary_port, alternate_port].map(|port| SocketAddr::new(ip, port)));
+
+        let mut udp = Vec::with_capacity(4);
+        let mut tcp = Vec::with_capacity(4);
+        for addr in addrs.iter().flatten() {
+            udp.push(Arc::new(UdpSocket::bind(addr).await?));
+            tcp.push(TcpListener::bind(addr).await?);
+        }
+        // same layout as `addrs`, flattened
+        let udp: Arc<[Arc<UdpSocket>]> = udp.into();
+
+        let mut server = Self {
+            addrs,
+            requests: Arc::new(AtomicU64::new(0)),
+            cancel: CancellationToken::new(),
+            tasks: Vec::new(),
+        };
+        for (i, listener) in tcp.into_iter().enumerate() {
+            let (ip, port) = (i / 2, i % 2);
+            server.tasks.push(tokio::spawn(serve_udp(
+                udp.clone(),
+                ip,
+                port,
+                addrs,
+                server.requests.clone(),
+                server.cancel.clone(),
+            )));
+            server.tasks.push(tokio::spawn(serve_tcp(
+                listener,
+                addrs[ip][port],
+                addrs[1 - ip][1 - port],
+                server.requests.clone(),
+                server.cancel.clone(),
+            )));
+        }
+        Ok(server)
+    }
+
+    fn is_running(&self) -> bool {
+        !self.cancel.is_cancelled()
+    }
+
+    fn info(&self, id: &str) -> StunServerInfo {
+        StunServerInfo {
+            id: id.to_string(),
+            primary_addr: self.addrs[0][0].to_string(),
+            alternate_addr: self.addrs[1][1].to_string(),
+            running: self.is_running(),
+            requests: self.requests.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl RegistryEntry for StunServer {
+    async fn stop(&mut self) {
+        self.cancel.cancel();
+        for task in self.tasks.drain(..) {
+            let _ = task.await;
+        }
+    }
+}
+
+fn binding_response(
+    request: &StunMessage,
+    source: SocketAddr,
+    origin: SocketAddr,
+    other: SocketAddr,
+) -> Vec<u8> {
+    let mut response = StunMessage::new(STUN_BINDING_SUCCESS, request.tid);
+    response.add_address(ATTR_XOR_MAPPED_ADDRESS, source);
+    response.add_address(ATTR_MAPPED_ADDRESS, source);
+    response.add_address(ATTR_RESPONSE_ORIGIN, origin);
+    response.add_address(ATTR_OTHER_ADDRESS, other);
+    response.encode()
+}
+
+/// Answers binding requests arriving on `sockets[ip * 2 + port]`, from the
+/// socket selected by the request's `CHANGE-REQUEST`.
+async fn serve_udp(
+    sockets: Arc<[Arc<UdpSocket>]>,
+    ip: usize,
+    port: usize,
+    addrs: [[SocketAddr; 2]; 2],
+    requests: Arc<AtomicU64>,
+    cancel: CancellationToken,
+) {
+    let socket = &sockets[ip * 2 + port];
+    let mut buf = [0u8; 1500];
+    let mut backoff = Backoff::new();
+    loop {
+        let recv = tokio::select! {
+            _ = cancel.cancelled() => break,
+            recv = socket.recv_from(&mut buf) => recv,
+        };
+        let (len, source) = match recv {
+            Ok(recv) => {
+                backoff.reset();
+                recv
+            }
+            Err(e) if is_transient(&e) => {
+                if !backoff.wait(&cancel).await {
+                    break;
+                }
+                continue;
+            }
+            Err(e) => {
+                eprintln!("stun udp socket {} failed: {}", addrs[ip][port], e);
+                break;
+            }
+        };
+        let Some(request) = StunMessage::parse(&buf[..len]) else {
+            continue;
+        };
+        if request.msg_type != STUN_BINDING_REQUEST {
+            continue;
+        }
+
+        let (change_ip, change_port) = request.change_request();
+        let (reply_ip, reply_port) = (ip ^ change_ip as usize, port ^ change_port as usize);
+        let response = binding_response(
+            &request,
+            source,
+            addrs[reply_ip][reply_port],
+            addrs[1 - ip][1 - port],
+        );
+        if let Err(e) = sockets[reply_ip * 2 + reply_port]
+            .send_to(&response, source)
+            .await
+        {
+            eprintln!("stun response to {} failed: {}", source, e);
+            continue;
+        }
+        requests.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+async fn serve_tcp(
+    listener: TcpListener,
+    local: SocketAddr,
+    other: SocketAddr,
+    requests: Arc<AtomicU64>,
+    cancel: CancellationToken,
+) {
+    let mut backoff = Backoff::new();
+    loop {
+        let accept = tokio::select! {
+            _ = cancel.cancelled() => break,
+            accept = listener.accept() => accept,
+        };
+        // accept errors, e.g. running out of file descriptors, usually clear
+        // up once connections close, so keep listening
+        let (stream, source) = match accept {
+            Ok(accepted) => {
+                backoff.reset();
+                accepted
+            }
+            Err(_) => {
+                if !backoff.wait(&cancel).await {
+                    break;
+                }
+                continue;
+            }
+        };
+        let requests = requests.clone();
+        let cancel = cancel.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = cancel.cancelled() => {}
+                _ = serve_tcp_connection(stream, source, local, other, &requests) => {}
+            }
+        });
+    }
+}
+
+/// Answers binding requests on one connection until the client closes it.
+/// `CHANGE-REQUEST` isn't defined for TCP and is ignored.
+async fn serve_tcp_connection(
+    mut stream: TcpStream,
+    source: SocketAddr,
+    local: SocketAddr,
+    other: SocketAddr,
+    requests: &AtomicU64,
+) -> io::Result<()> {
+    loop {
+        let mut data = vec![0u8; 20];
+        stream.read_exact(&mut data).await?;
+        let len = u16::from_be_bytes([data[2], data[3]]) as usize;
+        if 20 + len > MAX_TCP_MESSAGE {
+            return Ok(());
+        }
+        data.resize(20 + len, 0);
+        stream.read_exact(&mut data[20..]).await?;
+
+        let Some(request) = StunMessage::parse(&data) else {
+            return Ok(());
+        };
+        if request.msg_type != STUN_BINDING_REQUEST {
+            continue;
+        }
+        stream
+            .write_all(&binding_response(&request, source, local, other))
+            .await?;
+        requests.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Starts a STUN server on two IPs and two ports, returns its handle.
+///
+/// Both IPs must be local addresses of the same family, e.g. two addresses
+/// of one interface or `127.0.0.1` and `127.0.0.2` for local testing.
+pub fn start_stun_server(
+    primary_ip: String,
+    alternate_ip: String,
+    primary_port: u16,
+    alternate_port: u16,
+) -> Result<String, String> {
+    let primary_ip: IpAddr = primary_ip
+        .trim()
+        .parse()
+        .map_err(|e| format!("invalid primary ip {:?}: {}", primary_ip, e))?;
+    let alternate_ip: IpAddr = alternate_ip
+        .trim()
+        .parse()
+        .map_err(|e| format!("invalid alternate ip {:?}: {}", alternate_ip, e))?;
+    if primary_ip == alternate_ip || primary_ip.is_ipv4() != alternate_ip.is_ipv4() {
+        return Err("primary and alternate ip must differ and be of the same family".to_string());
+    }
+    if primary_port == 0 || alternate_port == 0 || primary_port == alternate_port {
+        return Err("primary and alternate port must differ and be non-zero".to_string());
+    }
+
+    RT.block_on(async move {
+        let server = StunServer::start(primary_ip, alternate_ip, primary_port, alternate_port)
+            .await
+            .map_err(|e| format!("start stun server failed: {}", e))?;
+        let info = server.info("");
+        let id = STUN_SERVERS.insert(server).await;
+        println!(
+            "stun server started on {} / {}, handle: {}",
+            info.primary_addr, info.alternate_addr, id
+        );
+        Ok(id)
+    })
+}
+
+/// Stops a STUN server; it stays listed until removed.
+pub fn stop_stun_server(id: String) -> Result<(), String> {
+    RT.block_on(async move { STUN_SERVERS.stop(&id).await })
+}
+
+pub fn remove_stun_server(id: String) -> Result<(), String> {
+    RT.block_on(async move { STUN_SERVERS.remove(&id).await })
+}
+
+pub fn list_stun_servers() -> Vec<StunServerInfo> {
+    RT.block_on(async move {
+        STUN_SERVERS
+            .lock()
+            .await
+            .iter()
+            .map(|entry| entry.value.info(&entry.id))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::nat_test::{test_nat_behavior, FilteringBehavior, MappingBehavior};
+
+    fn free_port(ip: &str) -> u16 {
+        std::net::UdpSocket::bind((ip, 0))
+            .and_then(|socket| socket.local_addr())
+            .map(|addr| addr.port())
+            .expect("no free port")
+    }
+
+    // binding 127.0.0.2 needs the whole 127/8 on loopback, as on Linux
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn loopback_nat_behavior() {
+        let (primary_port, alternate_port) = (free_port("127.0.0.1"), free_port("127.0.0.2"));
+        let id = start_stun_server(
+            "127.0.0.1".to_string(),
+            "127.0.0.2".to_string(),
+            primary_port,
+            alternate_port,
+        )
+        .unwrap();
+
+        let results = test_nat_behavior(format!("127.0.0.1:{primary_port}")).unwrap();
+        let (udp4, tcp4) = (&results[0], &results[2]);
+        assert_eq!(udp4.error, None);
+        assert_eq!(udp4.mapping, MappingBehavior::NoNat);
+        assert_eq!(udp4.filtering, FilteringBehavior::EndpointIndependent);
+        assert_eq!(tcp4.error, None);
+        assert!(tcp4.mapped_addr.is_some());
+
+        let info = list_stun_servers()
+            .into_iter()
+            .find(|info| info.id == id)
+            .unwrap();
+        assert!(info.requests > 0);
+        remove_stun_server(id).unwrap();
+    }
+}
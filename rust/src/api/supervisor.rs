@@ -0,0 +1,402 @@
+//! Automatic restart of network instances that stop unexpectedly.
+//!
+//! A supervised instance is checked every `CHECK_INTERVAL`. When it has left
+//! the manager, reports that it is no longer running, or (if the policy asks
+//! for it) hits a TUN device error, it is restarted from its config under the
+//! same instance id, with exponential backoff between attempts. Event
+//! subscribers stay attached across restarts and receive `InstanceRestarting`,
+//! `InstanceRestarted` and `InstanceRestartGaveUp` events.
+//!
+//! Instances started while the app was launched with `AUTOSTART_ARG` are
+//! supervised with the default policy.
+//!
+//! On Android a restarted instance has no TUN device until the VPN service
+//! calls `set_tun_fd` again, which it should do on `InstanceRestarted`.
+
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::runtime::Runtime;
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use super::p2p::{self, InstanceEvent};
+use super::utils::AUTOSTART_ARG;
+
+/// How often a supervised instance is checked.
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+/// An instance that stayed up this long counts as healthy again, and its
+/// consecutive failures start over.
+const STABLE_RUN: Duration = Duration::from_secs(60);
+
+lazy_static! {
+    static ref RT: Runtime = Runtime::new().expect("failed to create tokio runtime");
+    static ref SUPERVISORS: DashMap<Uuid, Supervisor> = DashMap::new();
+}
+
+/// When and how often a failed instance is restarted.
+#[derive(Debug, Clone)]
+pub struct RestartPolicy {
+    /// Consecutive failed restarts before giving up; 0 retries forever.
+    pub max_retries: u32,
+    /// Delay before the first restart, doubled on each further attempt.
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    /// Also restart when the TUN device reports an error, even if the
+    /// instance itself keeps running.
+    pub restart_on_tun_error: bool,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff_ms: 1_000,
+            max_backoff_ms: 60_000,
+            restart_on_tun_error: true,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Delay before restart `attempt`, counting from 1.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u64 << attempt.saturating_sub(1).min(20);
+        Duration::from_millis(
+            self.initial_backoff_ms
+                .saturating_mul(factor)
+                .min(self.max_backoff_ms),
+        )
+    }
+
+    /// Delay before restart `attempt`, or `None` once the policy gives up.
+    fn next_restart(&self, attempt: u32) -> Option<Duration> {
+        if self.max_retries > 0 && attempt > self.max_retries {
+            None
+        } else {
+            Some(self.backoff(attempt))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupervisorState {
+    Running,
+    /// Waiting for the backoff delay before the next restart.
+    Restarting,
+    /// `max_retries` was exceeded; the instance was removed.
+    GaveUp,
+}
+
+#[derive(Debug, Clone)]
+pub struct SupervisorStatus {
+    pub instance_id: String,
+    pub policy: RestartPolicy,
+    pub state: SupervisorState,
+    /// Successful restarts since supervision started.
+    pub restart_count: u32,
+    pub consecutive_failures: u32,
+    pub last_failure: Option<String>,
+    /// Unix timestamp in milliseconds.
+    pub last_failure_at: Option<i64>,
+    /// Unix timestamp in milliseconds of the next restart while `Restarting`.
+    pub next_restart_at: Option<i64>,
+}
+
+struct Supervisor {
+    status: SupervisorStatus,
+    cancel: CancellationToken,
+    wake: Arc<Notify>,
+    /// TUN error reported since the last check, if the policy restarts on it.
+    tun_error: Option<String>,
+}
+
+/// Whether the app was launched with `AUTOSTART_ARG`.
+pub(crate) fn autostart_requested() -> bool {
+    std::env::args().any(|arg| arg == AUTOSTART_ARG)
+}
+
+pub(crate) fn is_supervised(instance_id: &str) -> bool {
+    Uuid::parse_str(instance_id).is_ok_and(|id| SUPERVISORS.contains_key(&id))
+}
+
+/// Starts supervising an instance, replacing any previous supervisor of it.
+pub(crate) fn supervise(id: Uuid, policy: RestartPolicy) {
+    let cancel = CancellationToken::new();
+    let wake = Arc::new(Notify::new());
+    let supervisor = Supervisor {
+        status: SupervisorStatus {
+            instance_id: id.to_string(),
+            policy,
+            state: SupervisorState::Running,
+            restart_count: 0,
+            consecutive_failures: 0,
+            last_failure: None,
+            last_failure_at: None,
+            next_restart_at: None,
+        },
+        cancel: cancel.clone(),
+        wake: wake.clone(),
+        tun_error: None,
+    };
+    if let Some(old) = SUPERVISORS.insert(id, supervisor) {
+        old.cancel.cancel();
+    }
+    RT.spawn(run(id, cancel, wake));
+}
+
+/// Stops supervising an instance. Called before an instance is closed on
+/// purpose, so the supervisor doesn't bring it back.
+pub(crate) fn stop(id: &Uuid) {
+    if let Some((_, supervisor)) = SUPERVISORS.remove(id) {
+        supervisor.cancel.cancel();
+    }
+}
+
+/// Called for every published event; wakes the supervisor on TUN errors.
+pub(crate) fn on_event(instance_id: &str, event: &InstanceEvent) {
+    let InstanceEvent::TunDeviceError { error } = event else {
+        return;
+    };
+    let Ok(id) = Uuid::parse_str(instance_id) else {
+        return;
+    };
+    if let Some(mut supervisor) = SUPERVISORS.get_mut(&id) {
+        if supervisor.status.policy.restart_on_tun_error {
+            supervisor.tun_error = Some(format!("TUN device error: {}", error));
+            supervisor.wake.notify_one();
+        }
+    }
+}
+
+/// Updates the status unless this supervisor has been stopped or replaced.
+fn update<R>(
+    id: &Uuid,
+    cancel: &CancellationToken,
+    f: impl FnOnce(&mut Supervisor) -> R,
+) -> Option<R> {
+    if cancel.is_cancelled() {
+        return None;
+    }
+    SUPERVISORS
+        .get_mut(id)
+        .map(|mut supervisor| f(&mut supervisor))
+}
+
+/// Returns why the instance is down, or `None` while it is running.
+async fn check_instance(instance_id: &str) -> Option<String> {
+    match p2p::get_instance_info(instance_id).await {
+        Ok(info) if info.running => None,
+        Ok(info) => Some(
+            info.error_msg
+                .unwrap_or_else(|| "instance stopped".to_string()),
+        ),
+        Err(_) => Some("instance stopped".to_string()),
+    }
+}
+
+async fn run(id: Uuid, cancel: CancellationToken, wake: Arc<Notify>) {
+    let instance_id = id.to_string();
+    let mut running_since = Instant::now();
+
+    loop {
+        let mut reason = tokio::select! {
+            _ = cancel.cancelled() => return,
+            _ = wake.notified() => {
+                match update(&id, &cancel, |s| s.tun_error.take()).flatten() {
+                    Some(reason) => reason,
+                    None => continue,
+                }
+            }
+            _ = tokio::time::sleep(CHECK_INTERVAL) => {
+                match check_instance(&instance_id).await {
+                    Some(reason) => reason,
+                    None => continue,
+                }
+            }
+        };
+        if running_since.elapsed() >= STABLE_RUN {
+            update(&id, &cancel, |s| s.status.consecutive_failures = 0);
+        }
+
+        loop {
+            println!("instance {} failed: {}", instance_id, reason);
+            let Some((attempt, policy)) = update(&id, &cancel, |s| {
+                s.status.consecutive_failures += 1;
+                s.status.last_failure = Some(reason.clone());
+                s.status.last_failure_at = Some(chrono::Utc::now().timestamp_millis());
+                (s.status.consecutive_failures, s.status.policy.clone())
+            }) else {
+                return;
+            };
+
+            let Some(delay) = policy.next_restart(attempt) else {
+                let gave_up = update(&id, &cancel, |s| {
+                    s.status.state = SupervisorState::GaveUp;
+                    s.status.next_restart_at = None;
+                });
+                if gave_up.is_none() {
+                    return;
+                }
+                p2p::publish_event(
+                    &instance_id,
+                    InstanceEvent::InstanceRestartGaveUp {
+                        attempts: attempt - 1,
+                        reason,
+                    },
+                );
+                // the status stays queryable, the instance itself is gone
+                p2p::discard_instance(&id);
+                return;
+            };
+
+            update(&id, &cancel, |s| {
+                s.status.state = SupervisorState::Restarting;
+                s.status.next_restart_at =
+                    Some(chrono::Utc::now().timestamp_millis() + delay.as_millis() as i64);
+            });
+            p2p::publish_event(
+                &instance_id,
+                InstanceEvent::InstanceRestarting {
+                    attempt,
+                    delay_ms: delay.as_millis() as u64,
+                    reason: reason.clone(),
+                },
+            );
+            tokio::select! {
+                _ = cancel.cancelled() => return,
+                _ = tokio::time::sleep(delay) => {}
+            }
+
+            match p2p::restart_instance(&id) {
+                Ok(()) => {
+                    update(&id, &cancel, |s| {
+                        s.status.state = SupervisorState::Running;
+                        s.status.restart_count += 1;
+                        s.status.next_restart_at = None;
+                        s.tun_error = None;
+                    });
+                    p2p::publish_event(&instance_id, InstanceEvent::InstanceRestarted { attempt });
+                    running_since = Instant::now();
+                    break;
+                }
+                Err(e) => reason = e,
+            }
+        }
+    }
+}
+
+/// Supervises a running instance, restarting it according to `policy`
+/// (the default policy when `None`) if it stops unexpectedly.
+pub fn supervise_instance(
+    instance_id: String,
+    policy: Option<RestartPolicy>,
+) -> Result<(), String> {
+    let id = p2p::parse_instance_id(&instance_id)?;
+    if !p2p::has_instance_config(&id) {
+        return Err("instance not found".to_string());
+    }
+    supervise(id, policy.unwrap_or_default());
+    Ok(())
+}
+
+/// Stops supervising an instance; the instance itself keeps running.
+pub fn unsupervise_instance(instance_id: String) -> Result<(), String> {
+    let id = p2p::parse_instance_id(&instance_id)?;
+    stop(&id);
+    Ok(())
+}
+
+/// Returns the supervisor status of an instance, `None` if it isn't supervised.
+pub fn get_supervisor_status(instance_id: String) -> Result<Option<SupervisorStatus>, String> {
+    let id = p2p::parse_instance_id(&instance_id)?;
+    Ok(SUPERVISORS.get(&id).map(|s| s.status.clone()))
+}
+
+pub fn list_supervisor_status() -> Vec<SupervisorStatus> {
+    SUPERVISORS.iter().map(|s| s.status.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(max_retries: u32) -> RestartPolicy {
+        RestartPolicy {
+            max_retries,
+            initial_backoff_ms: 1_000,
+            max_backoff_ms: 10_000,
+            restart_on_tun_error: false,
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let policy = policy(0);
+        let delays: Vec<u64> = (0..=6)
+            .map(|attempt| policy.backoff(attempt).as_millis() as u64)
+            .collect();
+        assert_eq!(delays, [1_000, 1_000, 2_000, 4_000, 8_000, 10_000, 10_000]);
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(10));
+
+        let huge = RestartPolicy {
+            initial_backoff_ms: u64::MAX / 2,
+            max_backoff_ms: u64::MAX,
+            ..policy
+        };
+        assert_eq!(huge.backoff(30), Duration::from_millis(u64::MAX));
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let limited = policy(3);
+        for attempt in 1..=3 {
+            assert_eq!(
+                limited.next_restart(attempt),
+                Some(limited.backoff(attempt))
+            );
+        }
+        assert_eq!(limited.next_restart(4), None);
+
+        // 0 retries forever
+        assert!(policy(0).next_restart(1_000).is_some());
+    }
+
+    #[test]
+    fn failed_instance_is_given_up_and_forgotten() {
+        // an instance without config fails every check and every restart
+        let id = Uuid::new_v4();
+        supervise(
+            id,
+            RestartPolicy {
+                max_retries: 1,
+                initial_backoff_ms: 1,
+                max_backoff_ms: 1,
+                restart_on_tun_error: false,
+            },
+        );
+
+        let deadline = Instant::now() + CHECK_INTERVAL * 3;
+        let status = loop {
+            let status = get_supervisor_status(id.to_string()).unwrap().unwrap();
+            if status.state == SupervisorState::GaveUp {
+                break status;
+            }
+            assert!(Instant::now() < deadline, "supervisor did not give up");
+            std::thread::sleep(Duration::from_millis(50));
+        };
+        assert_eq!(status.restart_count, 0);
+        assert_eq!(status.consecutive_failures, 2);
+        assert_eq!(
+            status.last_failure.as_deref(),
+            Some("instance config not found")
+        );
+        assert_eq!(status.next_restart_at, None);
+        assert!(!p2p::has_instance_config(&id));
+
+        unsupervise_instance(id.to_string()).unwrap();
+        assert!(get_supervisor_status(id.to_string()).unwrap().is_none());
+    }
+}
@@ -27,6 +27,7 @@
 // Section: imports
 
 use crate::api::forward::*;
+use crate::api::minecraft_lan::*;
 use crate::api::multicast::*;
 use crate::api::p2p::*;
 use crate::api::simple::*;
@@ -42,7 +43,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.12.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -2101453571;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -458141824;
 
 // Section: executor
 
@@ -311,7 +312,7 @@ fn wire__crate__api__forward__ForwardServer_stop_impl(
         },
     )
 }
-fn wire__crate__api__multicast__MulticastSender_is_running_impl(
+fn wire__crate__api__minecraft_lan__MinecraftLanBridge_is_running_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -319,7 +320,7 @@ fn wire__crate__api__multicast__MulticastSender_is_running_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MulticastSender_is_running",
+            debug_name: "MinecraftLanBridge_is_running",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -334,7 +335,7 @@ fn wire__crate__api__multicast__MulticastSender_is_running_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
@@ -354,7 +355,7 @@ fn wire__crate__api__multicast__MulticastSender_is_running_impl(
                     }
                     let api_that_guard = api_that_guard.unwrap();
                     let output_ok = Result::<_, ()>::Ok(
-                        crate::api::multicast::MulticastSender::is_running(&*api_that_guard),
+                        crate::api::minecraft_lan::MinecraftLanBridge::is_running(&*api_that_guard),
                     )?;
                     Ok(output_ok)
                 })())
@@ -362,7 +363,7 @@ fn wire__crate__api__multicast__MulticastSender_is_running_impl(
         },
     )
 }
-fn wire__crate__api__multicast__MulticastSender_start_impl(
+fn wire__crate__api__minecraft_lan__MinecraftLanBridge_start_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -370,7 +371,7 @@ fn wire__crate__api__multicast__MulticastSender_start_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MulticastSender_start",
+            debug_name: "MinecraftLanBridge_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -385,7 +386,7 @@ fn wire__crate__api__multicast__MulticastSender_start_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
@@ -408,9 +409,10 @@ fn wire__crate__api__multicast__MulticastSender_start_impl(
                             }
                         }
                         let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::api::multicast::MulticastSender::start(&mut *api_that_guard)
-                                .await?;
+                        let output_ok = crate::api::minecraft_lan::MinecraftLanBridge::start(
+                            &mut *api_that_guard,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -419,7 +421,7 @@ fn wire__crate__api__multicast__MulticastSender_start_impl(
         },
     )
 }
-fn wire__crate__api__multicast__MulticastSender_stop_impl(
+fn wire__crate__api__minecraft_lan__MinecraftLanBridge_stop_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -427,7 +429,7 @@ fn wire__crate__api__multicast__MulticastSender_stop_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MulticastSender_stop",
+            debug_name: "MinecraftLanBridge_stop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -442,7 +444,7 @@ fn wire__crate__api__multicast__MulticastSender_stop_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
@@ -466,8 +468,10 @@ fn wire__crate__api__multicast__MulticastSender_stop_impl(
                         }
                         let mut api_that_guard = api_that_guard.unwrap();
                         let output_ok = Result::<_, ()>::Ok({
-                            crate::api::multicast::MulticastSender::stop(&mut *api_that_guard)
-                                .await;
+                            crate::api::minecraft_lan::MinecraftLanBridge::stop(
+                                &mut *api_that_guard,
+                            )
+                            .await;
                         })?;
                         Ok(output_ok)
                     })()
@@ -477,7 +481,7 @@ fn wire__crate__api__multicast__MulticastSender_stop_impl(
         },
     )
 }
-fn wire__crate__api__forward__ServerStats_get_bytes_received_impl(
+fn wire__crate__api__multicast__MulticastListener_is_running_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -485,7 +489,7 @@ fn wire__crate__api__forward__ServerStats_get_bytes_received_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ServerStats_get_bytes_received",
+            debug_name: "MulticastListener_is_running",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -500,7 +504,7 @@ fn wire__crate__api__forward__ServerStats_get_bytes_received_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
@@ -520,7 +524,7 @@ fn wire__crate__api__forward__ServerStats_get_bytes_received_impl(
                     }
                     let api_that_guard = api_that_guard.unwrap();
                     let output_ok = Result::<_, ()>::Ok(
-                        crate::api::forward::ServerStats::get_bytes_received(&*api_that_guard),
+                        crate::api::multicast::MulticastListener::is_running(&*api_that_guard),
                     )?;
                     Ok(output_ok)
                 })())
@@ -528,15 +532,15 @@ fn wire__crate__api__forward__ServerStats_get_bytes_received_impl(
         },
     )
 }
-fn wire__crate__api__forward__ServerStats_get_bytes_sent_impl(
+fn wire__crate__api__multicast__MulticastListener_start_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ServerStats_get_bytes_sent",
+            debug_name: "MulticastListener_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -551,35 +555,99 @@ fn wire__crate__api__forward__ServerStats_get_bytes_sent_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                }
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_that_guard = api_that_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::forward::ServerStats::get_bytes_sent(&*api_that_guard),
-                    )?;
-                    Ok(output_ok)
-                })())
+                        let mut api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::multicast::MulticastListener::start(&mut *api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__forward__ServerStats_get_connections_impl(
+fn wire__crate__api__multicast__MulticastListener_stop_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MulticastListener_stop",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::multicast::MulticastListener::stop(&mut *api_that_guard)
+                                .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__multicast__MulticastSender_is_running_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -587,7 +655,7 @@ fn wire__crate__api__forward__ServerStats_get_connections_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ServerStats_get_connections",
+            debug_name: "MulticastSender_is_running",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -602,7 +670,7 @@ fn wire__crate__api__forward__ServerStats_get_connections_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
@@ -622,7 +690,7 @@ fn wire__crate__api__forward__ServerStats_get_connections_impl(
                     }
                     let api_that_guard = api_that_guard.unwrap();
                     let output_ok = Result::<_, ()>::Ok(
-                        crate::api::forward::ServerStats::get_connections(&*api_that_guard),
+                        crate::api::multicast::MulticastSender::is_running(&*api_that_guard),
                     )?;
                     Ok(output_ok)
                 })())
@@ -630,15 +698,15 @@ fn wire__crate__api__forward__ServerStats_get_connections_impl(
         },
     )
 }
-fn wire__crate__api__forward__ServerStats_new_impl(
+fn wire__crate__api__multicast__MulticastSender_start_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ServerStats_new",
+            debug_name: "MulticastSender_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -652,25 +720,50 @@ fn wire__crate__api__forward__ServerStats_new_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::forward::ServerStats::new())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::multicast::MulticastSender::start(&mut *api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__magic_wall__add_magic_wall_rule_impl(
+fn wire__crate__api__multicast__MulticastSender_stop_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "add_magic_wall_rule",
+            debug_name: "MulticastSender_stop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -684,18 +777,43 @@ fn wire__crate__api__magic_wall__add_magic_wall_rule_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_rule = <crate::api::magic_wall::MagicWallRule>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::magic_wall::add_magic_wall_rule(api_rule)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::multicast::MulticastSender::stop(&mut *api_that_guard)
+                                .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__check_sudo_impl(
+fn wire__crate__api__forward__ServerStats_get_bytes_received_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -703,7 +821,7 @@ fn wire__crate__api__utils__check_sudo_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_sudo",
+            debug_name: "ServerStats_get_bytes_received",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -717,17 +835,36 @@ fn wire__crate__api__utils__check_sudo_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::check_sudo())?;
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::forward::ServerStats::get_bytes_received(&*api_that_guard),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__close_server_impl(
+fn wire__crate__api__forward__ServerStats_get_bytes_sent_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -735,7 +872,7 @@ fn wire__crate__api__p2p__close_server_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "close_server",
+            debug_name: "ServerStats_get_bytes_sent",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -749,18 +886,36 @@ fn wire__crate__api__p2p__close_server_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::p2p::close_server(api_instance_id)?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__simple__close_server_impl(
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::forward::ServerStats::get_bytes_sent(&*api_that_guard),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__forward__ServerStats_get_connections_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -768,7 +923,7 @@ fn wire__crate__api__simple__close_server_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "close_server",
+            debug_name: "ServerStats_get_connections",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -782,19 +937,36 @@ fn wire__crate__api__simple__close_server_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::close_server();
-                    })?;
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::forward::ServerStats::get_connections(&*api_that_guard),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__magic_wall__create_default_magic_wall_rules_impl(
+fn wire__crate__api__forward__ServerStats_new_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -802,7 +974,7 @@ fn wire__crate__api__magic_wall__create_default_magic_wall_rules_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_default_magic_wall_rules",
+            debug_name: "ServerStats_new",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -819,16 +991,14 @@ fn wire__crate__api__magic_wall__create_default_magic_wall_rules_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::magic_wall::create_default_magic_wall_rules(),
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::forward::ServerStats::new())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__forward__create_forward_server_impl(
+fn wire__crate__api__magic_wall__add_magic_wall_rule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -836,7 +1006,7 @@ fn wire__crate__api__forward__create_forward_server_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_forward_server",
+            debug_name: "add_magic_wall_rule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -850,22 +1020,18 @@ fn wire__crate__api__forward__create_forward_server_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_listen_addr = <String>::sse_decode(&mut deserializer);
-            let api_forward_addr = <String>::sse_decode(&mut deserializer);
+            let api_rule = <crate::api::magic_wall::MagicWallRule>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::forward::create_forward_server(
-                        api_listen_addr,
-                        api_forward_addr,
-                    )?;
+                    let output_ok = crate::api::magic_wall::add_magic_wall_rule(api_rule)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__multicast__create_multicast_sender_impl(
+fn wire__crate__api__magic_wall__analyze_magic_wall_rules_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -873,7 +1039,7 @@ fn wire__crate__api__multicast__create_multicast_sender_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_multicast_sender",
+            debug_name: "analyze_magic_wall_rules",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -887,26 +1053,19 @@ fn wire__crate__api__multicast__create_multicast_sender_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_multicast_addr = <String>::sse_decode(&mut deserializer);
-            let api_port = <u16>::sse_decode(&mut deserializer);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_interval_ms = <u64>::sse_decode(&mut deserializer);
+            let api_rules =
+                <Vec<crate::api::magic_wall::MagicWallRule>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::multicast::create_multicast_sender(
-                        api_multicast_addr,
-                        api_port,
-                        api_data,
-                        api_interval_ms,
-                    )?;
+                    let output_ok = crate::api::magic_wall::analyze_magic_wall_rules(api_rules)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__multicast__create_multicast_sender_with_bind_impl(
+fn wire__crate__api__invite__apply_invite_flags_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -914,7 +1073,7 @@ fn wire__crate__api__multicast__create_multicast_sender_with_bind_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_multicast_sender_with_bind",
+            debug_name: "apply_invite_flags",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -928,28 +1087,22 @@ fn wire__crate__api__multicast__create_multicast_sender_with_bind_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_multicast_addr = <String>::sse_decode(&mut deserializer);
-            let api_port = <u16>::sse_decode(&mut deserializer);
-            let api_bind_addr = <String>::sse_decode(&mut deserializer);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_interval_ms = <u64>::sse_decode(&mut deserializer);
+            let api_invite_flags = <crate::api::invite::InviteFlags>::sse_decode(&mut deserializer);
+            let api_flag = <crate::api::simple::FlagsC>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::multicast::create_multicast_sender_with_bind(
-                        api_multicast_addr,
-                        api_port,
-                        api_bind_addr,
-                        api_data,
-                        api_interval_ms,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::invite::apply_invite_flags(
+                        api_invite_flags,
+                        api_flag,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__create_server_impl(
+fn wire__crate__api__utils__check_sudo_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -957,7 +1110,7 @@ fn wire__crate__api__p2p__create_server_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_server",
+            debug_name: "check_sudo",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -971,22 +1124,17 @@ fn wire__crate__api__p2p__create_server_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_config_toml = <String>::sse_decode(&mut deserializer);
-            let api_watch_event = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::p2p::create_server(
-                        api_config_toml,
-                        api_watch_event,
-                    ))?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::check_sudo())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__create_server_impl(
+fn wire__crate__api__p2p__close_server_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -994,7 +1142,7 @@ fn wire__crate__api__simple__create_server_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_server",
+            debug_name: "close_server",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1008,38 +1156,18 @@ fn wire__crate__api__simple__create_server_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_username = <String>::sse_decode(&mut deserializer);
-            let api_enable_dhcp = <bool>::sse_decode(&mut deserializer);
-            let api_specified_ip = <String>::sse_decode(&mut deserializer);
-            let api_room_name = <String>::sse_decode(&mut deserializer);
-            let api_room_password = <String>::sse_decode(&mut deserializer);
-            let api_severurl = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_onurl = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_cidrs = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_forwards = <Vec<crate::api::simple::Forward>>::sse_decode(&mut deserializer);
-            let api_flag = <crate::api::simple::FlagsC>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::create_server(
-                        api_username,
-                        api_enable_dhcp,
-                        api_specified_ip,
-                        api_room_name,
-                        api_room_password,
-                        api_severurl,
-                        api_onurl,
-                        api_cidrs,
-                        api_forwards,
-                        api_flag,
-                    ))?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::p2p::close_server(api_instance_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__create_server_with_flags_impl(
+fn wire__crate__api__simple__close_server_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1047,7 +1175,7 @@ fn wire__crate__api__p2p__create_server_with_flags_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_server_with_flags",
+            debug_name: "close_server",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1061,39 +1189,19 @@ fn wire__crate__api__p2p__create_server_with_flags_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_username = <String>::sse_decode(&mut deserializer);
-            let api_enable_dhcp = <bool>::sse_decode(&mut deserializer);
-            let api_specified_ip = <String>::sse_decode(&mut deserializer);
-            let api_room_name = <String>::sse_decode(&mut deserializer);
-            let api_room_password = <String>::sse_decode(&mut deserializer);
-            let api_severurl = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_onurl = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_cidrs = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_forwards = <Vec<crate::api::simple::Forward>>::sse_decode(&mut deserializer);
-            let api_flag = <crate::api::simple::FlagsC>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::p2p::create_server_with_flags(
-                            api_username,
-                            api_enable_dhcp,
-                            api_specified_ip,
-                            api_room_name,
-                            api_room_password,
-                            api_severurl,
-                            api_onurl,
-                            api_cidrs,
-                            api_forwards,
-                            api_flag,
-                        ))?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::close_server();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__nat_test__detect_nat_type_impl(
+fn wire__crate__api__magic_wall__create_default_magic_wall_rules_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1101,7 +1209,7 @@ fn wire__crate__api__nat_test__detect_nat_type_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "detect_nat_type",
+            debug_name: "create_default_magic_wall_rules",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1115,18 +1223,19 @@ fn wire__crate__api__nat_test__detect_nat_type_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_stun_server = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::nat_test::detect_nat_type(api_stun_server)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::magic_wall::create_default_magic_wall_rules(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__easytier_version_impl(
+fn wire__crate__api__forward__create_forward_server_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1134,7 +1243,7 @@ fn wire__crate__api__p2p__easytier_version_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "easytier_version",
+            debug_name: "create_forward_server",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1148,17 +1257,22 @@ fn wire__crate__api__p2p__easytier_version_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_listen_addr = <String>::sse_decode(&mut deserializer);
+            let api_forward_addr = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::p2p::easytier_version()?;
+                    let output_ok = crate::api::forward::create_forward_server(
+                        api_listen_addr,
+                        api_forward_addr,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__easytier_version_impl(
+fn wire__crate__api__forward__create_forward_server_with_protocol_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1166,7 +1280,7 @@ fn wire__crate__api__simple__easytier_version_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "easytier_version",
+            debug_name: "create_forward_server_with_protocol",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1180,17 +1294,24 @@ fn wire__crate__api__simple__easytier_version_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_listen_addr = <String>::sse_decode(&mut deserializer);
+            let api_forward_addr = <String>::sse_decode(&mut deserializer);
+            let api_protocol = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::simple::easytier_version()?;
+                    let output_ok = crate::api::forward::create_forward_server_with_protocol(
+                        api_listen_addr,
+                        api_forward_addr,
+                        api_protocol,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__hops__get_all_interfaces_metrics_impl(
+fn wire__crate__api__multicast__create_multicast_listener_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1198,7 +1319,7 @@ fn wire__crate__api__hops__get_all_interfaces_metrics_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_interfaces_metrics",
+            debug_name: "create_multicast_listener",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1212,19 +1333,26 @@ fn wire__crate__api__hops__get_all_interfaces_metrics_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_group_addr = <String>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
+            let api_interface_addr = <Option<String>>::sse_decode(&mut deserializer);
+            let api_capacity = <Option<usize>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::hops::get_all_interfaces_metrics()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::multicast::create_multicast_listener(
+                        api_group_addr,
+                        api_port,
+                        api_interface_addr,
+                        api_capacity,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__firewall__get_firewall_status_impl(
+fn wire__crate__api__multicast__create_multicast_sender_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1232,7 +1360,7 @@ fn wire__crate__api__firewall__get_firewall_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_firewall_status",
+            debug_name: "create_multicast_sender",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1246,21 +1374,26 @@ fn wire__crate__api__firewall__get_firewall_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_profile_index = <u32>::sse_decode(&mut deserializer);
+            let api_multicast_addr = <String>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_interval_ms = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::firewall::get_firewall_status(api_profile_index)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::multicast::create_multicast_sender(
+                        api_multicast_addr,
+                        api_port,
+                        api_data,
+                        api_interval_ms,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__forward__get_forward_server_count_impl(
+fn wire__crate__api__multicast__create_multicast_sender_with_bind_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1268,7 +1401,7 @@ fn wire__crate__api__forward__get_forward_server_count_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_forward_server_count",
+            debug_name: "create_multicast_sender_with_bind",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1282,18 +1415,28 @@ fn wire__crate__api__forward__get_forward_server_count_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_multicast_addr = <String>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
+            let api_bind_addr = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_interval_ms = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::forward::get_forward_server_count())?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::multicast::create_multicast_sender_with_bind(
+                        api_multicast_addr,
+                        api_port,
+                        api_bind_addr,
+                        api_data,
+                        api_interval_ms,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__forward__get_forward_server_stats_impl(
+fn wire__crate__api__p2p__create_server_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1301,7 +1444,7 @@ fn wire__crate__api__forward__get_forward_server_stats_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_forward_server_stats",
+            debug_name: "create_server",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1315,26 +1458,30 @@ fn wire__crate__api__forward__get_forward_server_stats_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_index = <usize>::sse_decode(&mut deserializer);
+            let api_config_toml = <String>::sse_decode(&mut deserializer);
+            let api_watch_event = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::forward::get_forward_server_stats(api_index)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::p2p::create_server(
+                        api_config_toml,
+                        api_watch_event,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__get_ips_impl(
+fn wire__crate__api__simple__create_server_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_ips",
+            debug_name: "create_server",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1348,30 +1495,46 @@ fn wire__crate__api__p2p__get_ips_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_enable_dhcp = <bool>::sse_decode(&mut deserializer);
+            let api_specified_ip = <String>::sse_decode(&mut deserializer);
+            let api_room_name = <String>::sse_decode(&mut deserializer);
+            let api_room_password = <String>::sse_decode(&mut deserializer);
+            let api_severurl = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_onurl = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_cidrs = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_forwards = <Vec<crate::api::simple::Forward>>::sse_decode(&mut deserializer);
+            let api_flag = <crate::api::simple::FlagsC>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::p2p::get_ips(api_instance_id).await)?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::create_server(
+                        api_username,
+                        api_enable_dhcp,
+                        api_specified_ip,
+                        api_room_name,
+                        api_room_password,
+                        api_severurl,
+                        api_onurl,
+                        api_cidrs,
+                        api_forwards,
+                        api_flag,
+                    ))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__get_ips_impl(
+fn wire__crate__api__p2p__create_server_with_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_ips",
+            debug_name: "create_server_with_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1385,20 +1548,21 @@ fn wire__crate__api__simple__get_ips_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::network_config::NetworkConfigC>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_ips().await)?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::p2p::create_server_with_config(api_config),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__magic_wall__get_magic_wall_status_impl(
+fn wire__crate__api__p2p__create_server_with_flags_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1406,7 +1570,7 @@ fn wire__crate__api__magic_wall__get_magic_wall_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_magic_wall_status",
+            debug_name: "create_server_with_flags",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1420,17 +1584,39 @@ fn wire__crate__api__magic_wall__get_magic_wall_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_enable_dhcp = <bool>::sse_decode(&mut deserializer);
+            let api_specified_ip = <String>::sse_decode(&mut deserializer);
+            let api_room_name = <String>::sse_decode(&mut deserializer);
+            let api_room_password = <String>::sse_decode(&mut deserializer);
+            let api_severurl = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_onurl = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_cidrs = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_forwards = <Vec<crate::api::simple::Forward>>::sse_decode(&mut deserializer);
+            let api_flag = <crate::api::simple::FlagsC>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::magic_wall::get_magic_wall_status()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::p2p::create_server_with_flags(
+                            api_username,
+                            api_enable_dhcp,
+                            api_specified_ip,
+                            api_room_name,
+                            api_room_password,
+                            api_severurl,
+                            api_onurl,
+                            api_cidrs,
+                            api_forwards,
+                            api_flag,
+                        ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__multicast__get_multicast_sender_count_impl(
+fn wire__crate__api__forward__create_udp_forward_server_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1438,7 +1624,7 @@ fn wire__crate__api__multicast__get_multicast_sender_count_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_multicast_sender_count",
+            debug_name: "create_udp_forward_server",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1452,26 +1638,32 @@ fn wire__crate__api__multicast__get_multicast_sender_count_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_listen_addr = <String>::sse_decode(&mut deserializer);
+            let api_forward_addr = <String>::sse_decode(&mut deserializer);
+            let api_idle_timeout_secs = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::multicast::get_multicast_sender_count())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::forward::create_udp_forward_server(
+                        api_listen_addr,
+                        api_forward_addr,
+                        api_idle_timeout_secs,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__get_network_status_impl(
+fn wire__crate__api__invite__decode_room_invite_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_network_status",
+            debug_name: "decode_room_invite",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1485,31 +1677,27 @@ fn wire__crate__api__p2p__get_network_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_link = <String>::sse_decode(&mut deserializer);
+            let api_secret = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::p2p::get_network_status(api_instance_id).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::invite::decode_room_invite(api_link, api_secret)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__get_network_status_impl(
+fn wire__crate__api__simple__default_instance_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_network_status",
+            debug_name: "default_instance_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1524,20 +1712,16 @@ fn wire__crate__api__simple__get_network_status_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::simple::get_network_status().await)?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::default_instance_id())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__nt__get_nt_path_impl(
+fn wire__crate__api__nat_test__detect_nat_type_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1545,7 +1729,7 @@ fn wire__crate__api__nt__get_nt_path_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_nt_path",
+            debug_name: "detect_nat_type",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1559,27 +1743,26 @@ fn wire__crate__api__nt__get_nt_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_dos_path = <String>::sse_decode(&mut deserializer);
+            let api_stun_server = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::nt::get_nt_path(&api_dos_path))?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::nat_test::detect_nat_type(api_stun_server)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__get_peer_route_pairs_impl(
+fn wire__crate__api__p2p__easytier_version_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_peer_route_pairs",
+            debug_name: "easytier_version",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1593,30 +1776,25 @@ fn wire__crate__api__p2p__get_peer_route_pairs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_instance_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::p2p::get_peer_route_pairs(api_instance_id).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::p2p::easytier_version()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__get_peer_route_pairs_impl(
+fn wire__crate__api__simple__easytier_version_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_peer_route_pairs",
+            debug_name: "easytier_version",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1631,27 +1809,24 @@ fn wire__crate__api__simple__get_peer_route_pairs_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::simple::get_peer_route_pairs().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::simple::easytier_version()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__get_running_info_impl(
+fn wire__crate__api__minecraft_lan__encode_minecraft_lan_announcement_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_running_info",
+            debug_name: "encode_minecraft_lan_announcement",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1665,31 +1840,31 @@ fn wire__crate__api__p2p__get_running_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_motd = <String>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::p2p::get_running_info(api_instance_id).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::minecraft_lan::encode_minecraft_lan_announcement(
+                            api_motd, api_port,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__get_running_info_impl(
+fn wire__crate__api__invite__encode_room_invite_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_running_info",
+            debug_name: "encode_room_invite",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1703,21 +1878,19 @@ fn wire__crate__api__simple__get_running_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_invite = <crate::api::invite::RoomInvite>::sse_decode(&mut deserializer);
+            let api_secret = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::simple::get_running_info().await)?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::invite::encode_room_invite(api_invite, api_secret)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__handle_event_impl(
+fn wire__crate__api__magic_wall__export_magic_wall_rules_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1725,7 +1898,7 @@ fn wire__crate__api__p2p__handle_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "handle_event",
+            debug_name: "export_magic_wall_rules",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1739,18 +1912,17 @@ fn wire__crate__api__p2p__handle_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_events = <EventBusSubscriber>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::p2p::handle_event(api_events))?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::magic_wall::export_magic_wall_rules()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__handle_event_impl(
+fn wire__crate__api__network_config__flags_to_toml_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1758,7 +1930,7 @@ fn wire__crate__api__simple__handle_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "handle_event",
+            debug_name: "flags_to_toml",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1772,19 +1944,19 @@ fn wire__crate__api__simple__handle_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_events = <EventBusSubscriber>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::network_config::NetworkConfigC>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::simple::handle_event(api_events))?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::network_config::flags_to_toml(api_config)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__init_app_impl(
+fn wire__crate__api__hops__get_all_interfaces_metrics_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1792,7 +1964,77 @@ fn wire__crate__api__p2p__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "get_all_interfaces_metrics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::hops::get_all_interfaces_metrics()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__firewall__get_firewall_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_firewall_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_index = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::firewall::get_firewall_status(api_profile_index)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__forward__get_forward_server_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_forward_server_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1809,16 +2051,15 @@ fn wire__crate__api__p2p__init_app_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::p2p::init_app();
-                    })?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::forward::get_forward_server_count())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__forward__get_forward_server_stats_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1826,7 +2067,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "get_forward_server_stats",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1840,19 +2081,18 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::forward::get_forward_server_stats(api_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__p2p__is_easytier_running_impl(
+fn wire__crate__api__p2p__get_ips_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1860,7 +2100,7 @@ fn wire__crate__api__p2p__is_easytier_running_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_easytier_running",
+            debug_name: "get_ips",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1879,9 +2119,8 @@ fn wire__crate__api__p2p__is_easytier_running_impl(
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::p2p::is_easytier_running(api_instance_id).await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::p2p::get_ips(api_instance_id).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1890,7 +2129,7 @@ fn wire__crate__api__p2p__is_easytier_running_impl(
         },
     )
 }
-fn wire__crate__api__simple__is_easytier_running_impl(
+fn wire__crate__api__simple__get_ips_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1898,7 +2137,7 @@ fn wire__crate__api__simple__is_easytier_running_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_easytier_running",
+            debug_name: "get_ips",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1916,8 +2155,7 @@ fn wire__crate__api__simple__is_easytier_running_impl(
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::simple::is_easytier_running().await)?;
+                        let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_ips().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1926,7 +2164,7 @@ fn wire__crate__api__simple__is_easytier_running_impl(
         },
     )
 }
-fn wire__crate__api__forward__is_forward_server_running_impl(
+fn wire__crate__api__magic_wall__get_magic_wall_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1934,7 +2172,7 @@ fn wire__crate__api__forward__is_forward_server_running_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_forward_server_running",
+            debug_name: "get_magic_wall_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1948,20 +2186,17 @@ fn wire__crate__api__forward__is_forward_server_running_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_index = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::forward::is_forward_server_running(api_index),
-                    )?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::magic_wall::get_magic_wall_status()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__multicast__is_multicast_sender_running_impl(
+fn wire__crate__api__multicast__get_multicast_listener_packets_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1969,7 +2204,7 @@ fn wire__crate__api__multicast__is_multicast_sender_running_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_multicast_sender_running",
+            debug_name: "get_multicast_listener_packets",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1983,20 +2218,20 @@ fn wire__crate__api__multicast__is_multicast_sender_running_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_index = <usize>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_limit = <Option<usize>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::multicast::is_multicast_sender_running(api_index),
-                    )?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::multicast::get_multicast_listener_packets(api_id, api_limit)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__nat_test__nat_type_get_description_impl(
+fn wire__crate__api__multicast__get_multicast_sender_count_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2004,7 +2239,7 @@ fn wire__crate__api__nat_test__nat_type_get_description_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "nat_type_get_description",
+            debug_name: "get_multicast_sender_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2018,28 +2253,26 @@ fn wire__crate__api__nat_test__nat_type_get_description_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::nat_test::NatType>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::nat_test::NatType::get_description(&api_that),
-                    )?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::multicast::get_multicast_sender_count())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__magic_wall__remove_magic_wall_rule_impl(
+fn wire__crate__api__p2p__get_network_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_magic_wall_rule",
+            debug_name: "get_network_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2053,26 +2286,31 @@ fn wire__crate__api__magic_wall__remove_magic_wall_rule_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_rule_id = <String>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::magic_wall::remove_magic_wall_rule(api_rule_id)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::p2p::get_network_status(api_instance_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__p2p__send_udp_to_localhost_impl(
+fn wire__crate__api__simple__get_network_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_udp_to_localhost",
+            debug_name: "get_network_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2086,18 +2324,21 @@ fn wire__crate__api__p2p__send_udp_to_localhost_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::p2p::send_udp_to_localhost(&api_message)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::simple::get_network_status().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__simple__send_udp_to_localhost_impl(
+fn wire__crate__api__nt__get_nt_path_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2105,7 +2346,7 @@ fn wire__crate__api__simple__send_udp_to_localhost_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_udp_to_localhost",
+            debug_name: "get_nt_path",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2119,26 +2360,27 @@ fn wire__crate__api__simple__send_udp_to_localhost_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_dos_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::simple::send_udp_to_localhost(&api_message)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::nt::get_nt_path(&api_dos_path))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__firewall__set_firewall_status_impl(
+fn wire__crate__api__p2p__get_peer_route_pairs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_firewall_status",
+            debug_name: "get_peer_route_pairs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2152,32 +2394,30 @@ fn wire__crate__api__firewall__set_firewall_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_profile_index = <u32>::sse_decode(&mut deserializer);
-            let api_enable = <bool>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::firewall::set_firewall_status(
-                            api_profile_index,
-                            api_enable,
-                        )?;
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::p2p::get_peer_route_pairs(api_instance_id).await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__hops__set_interface_metric_impl(
+fn wire__crate__api__simple__get_peer_route_pairs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_interface_metric",
+            debug_name: "get_peer_route_pairs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2191,24 +2431,20 @@ fn wire__crate__api__hops__set_interface_metric_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_interface_name = <String>::sse_decode(&mut deserializer);
-            let api_metric = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::hops::set_interface_metric(
-                            &api_interface_name,
-                            api_metric,
-                        )?;
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::get_peer_route_pairs().await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__p2p__set_tun_fd_impl(
+fn wire__crate__api__p2p__get_running_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2216,7 +2452,7 @@ fn wire__crate__api__p2p__set_tun_fd_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tun_fd",
+            debug_name: "get_running_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2231,13 +2467,11 @@ fn wire__crate__api__p2p__set_tun_fd_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
-            let api_fd = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::p2p::set_tun_fd(api_instance_id, api_fd).await?;
+                        let output_ok = crate::api::p2p::get_running_info(api_instance_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2246,7 +2480,7 @@ fn wire__crate__api__p2p__set_tun_fd_impl(
         },
     )
 }
-fn wire__crate__api__simple__set_tun_fd_impl(
+fn wire__crate__api__simple__get_running_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2254,7 +2488,7 @@ fn wire__crate__api__simple__set_tun_fd_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tun_fd",
+            debug_name: "get_running_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2268,12 +2502,12 @@ fn wire__crate__api__simple__set_tun_fd_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_fd = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = crate::api::simple::set_tun_fd(api_fd).await?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::simple::get_running_info().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2282,7 +2516,7 @@ fn wire__crate__api__simple__set_tun_fd_impl(
         },
     )
 }
-fn wire__crate__api__magic_wall__start_magic_wall_impl(
+fn wire__crate__api__supervisor__get_supervisor_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2290,7 +2524,7 @@ fn wire__crate__api__magic_wall__start_magic_wall_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_magic_wall",
+            debug_name: "get_supervisor_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2304,17 +2538,18 @@ fn wire__crate__api__magic_wall__start_magic_wall_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::magic_wall::start_magic_wall()?;
+                    let output_ok = crate::api::supervisor::get_supervisor_status(api_instance_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__forward__stop_all_forward_servers_impl(
+fn wire__crate__api__magic_wall__import_magic_wall_rules_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2322,7 +2557,7 @@ fn wire__crate__api__forward__stop_all_forward_servers_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_all_forward_servers",
+            debug_name: "import_magic_wall_rules",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2336,17 +2571,20 @@ fn wire__crate__api__forward__stop_all_forward_servers_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_json = <String>::sse_decode(&mut deserializer);
+            let api_replace = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::forward::stop_all_forward_servers()?;
+                    let output_ok =
+                        crate::api::magic_wall::import_magic_wall_rules(api_json, api_replace)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__multicast__stop_all_multicast_senders_impl(
+fn wire__crate__api__p2p__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2354,7 +2592,7 @@ fn wire__crate__api__multicast__stop_all_multicast_senders_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_all_multicast_senders",
+            debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2370,15 +2608,17 @@ fn wire__crate__api__multicast__stop_all_multicast_senders_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::multicast::stop_all_multicast_senders()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::p2p::init_app();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__forward__stop_forward_server_impl(
+fn wire__crate__api__simple__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2386,7 +2626,7 @@ fn wire__crate__api__forward__stop_forward_server_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_forward_server",
+            debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2400,18 +2640,19 @@ fn wire__crate__api__forward__stop_forward_server_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_index = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::forward::stop_forward_server(api_index)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__magic_wall__stop_magic_wall_impl(
+fn wire__crate__api__event_log__init_event_log_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2419,7 +2660,7 @@ fn wire__crate__api__magic_wall__stop_magic_wall_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_magic_wall",
+            debug_name: "init_event_log",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2433,17 +2674,18 @@ fn wire__crate__api__magic_wall__stop_magic_wall_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::magic_wall::stop_magic_wall()?;
+                    let output_ok = crate::api::event_log::init_event_log(api_dir)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__multicast__stop_multicast_sender_impl(
+fn wire__crate__api__magic_wall__init_magic_wall_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2451,7 +2693,7 @@ fn wire__crate__api__multicast__stop_multicast_sender_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_multicast_sender",
+            debug_name: "init_magic_wall_store",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2465,18 +2707,18 @@ fn wire__crate__api__multicast__stop_multicast_sender_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_index = <usize>::sse_decode(&mut deserializer);
+            let api_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::multicast::stop_multicast_sender(api_index)?;
+                    let output_ok = crate::api::magic_wall::init_magic_wall_store(api_dir)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__nat_test__test_network_connectivity_impl(
+fn wire__crate__api__invite__invite_flags_from_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2484,7 +2726,7 @@ fn wire__crate__api__nat_test__test_network_connectivity_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_network_connectivity",
+            debug_name: "invite_flags_from",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2498,19 +2740,93 @@ fn wire__crate__api__nat_test__test_network_connectivity_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_stun_server = <String>::sse_decode(&mut deserializer);
+            let api_flag = <crate::api::simple::FlagsC>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        crate::api::nat_test::test_network_connectivity(api_stun_server)?;
+                        Result::<_, ()>::Ok(crate::api::invite::invite_flags_from(api_flag))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__magic_wall__update_magic_wall_rule_impl(
+fn wire__crate__api__p2p__is_easytier_running_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_easytier_running",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::p2p::is_easytier_running(api_instance_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__is_easytier_running_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_easytier_running",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::simple::is_easytier_running().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__forward__is_forward_server_running_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2518,7 +2834,7 @@ fn wire__crate__api__magic_wall__update_magic_wall_rule_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_magic_wall_rule",
+            debug_name: "is_forward_server_running",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2532,1839 +2848,7506 @@ fn wire__crate__api__magic_wall__update_magic_wall_rule_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_rule = <crate::api::magic_wall::MagicWallRule>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::magic_wall::update_magic_wall_rule(api_rule)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::forward::is_forward_server_running(api_id),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<(), String>>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>
-);
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for EventBusSubscriber {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
+fn wire__crate__api__multicast__is_multicast_sender_running_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_multicast_sender_running",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::multicast::is_multicast_sender_running(api_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for ForwardServer {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
+fn wire__crate__api__forward__kill_forward_connection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "kill_forward_connection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_connection_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::forward::kill_forward_connection(api_id, api_connection_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for JoinHandle<()> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
+fn wire__crate__api__forward__list_forward_connections_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_forward_connections",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::forward::list_forward_connections(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for JoinHandle<Result<(), String>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+fn wire__crate__api__forward__list_forward_servers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_forward_servers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::forward::list_forward_servers())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__event_log__list_logged_instances_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_logged_instances",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::event_log::list_logged_instances()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__minecraft_lan__list_minecraft_lan_worlds_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_minecraft_lan_worlds",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::minecraft_lan::list_minecraft_lan_worlds(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__multicast__list_multicast_listeners_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_multicast_listeners",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::multicast::list_multicast_listeners())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__multicast__list_multicast_senders_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_multicast_senders",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::multicast::list_multicast_senders())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__stun_server__list_stun_servers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_stun_servers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::stun_server::list_stun_servers())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__supervisor__list_supervisor_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_supervisor_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::supervisor::list_supervisor_status())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__nat_test__nat_type_get_description_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "nat_type_get_description",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::nat_test::NatType>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::nat_test::NatType::get_description(&api_that),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__minecraft_lan__parse_minecraft_lan_announcement_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_minecraft_lan_announcement",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::minecraft_lan::parse_minecraft_lan_announcement(api_data),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__instance_patch__patch_instance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "patch_instance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_patch =
+                <crate::api::instance_patch::InstancePatch>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::instance_patch::patch_instance(api_instance_id, api_patch)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__server_probe__probe_servers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "probe_servers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::server_probe::probe_servers(api_urls, api_timeout_ms)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__magic_wall__purge_magic_wall_filters_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "purge_magic_wall_filters",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::magic_wall::purge_magic_wall_filters()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__event_log__query_instance_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_instance_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_since = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_kinds = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<usize>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::event_log::query_instance_events(
+                        api_instance_id,
+                        api_since,
+                        api_kinds,
+                        api_limit,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__forward__remove_forward_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_forward_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::forward::remove_forward_server(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__magic_wall__remove_magic_wall_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_magic_wall_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rule_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::magic_wall::remove_magic_wall_rule(api_rule_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__multicast__remove_multicast_listener_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_multicast_listener",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::multicast::remove_multicast_listener(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__multicast__remove_multicast_sender_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_multicast_sender",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::multicast::remove_multicast_sender(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__stun_server__remove_stun_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_stun_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::stun_server::remove_stun_server(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__firewall__set_firewall_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_firewall_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_index = <u32>::sse_decode(&mut deserializer);
+            let api_enable = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::firewall::set_firewall_status(
+                            api_profile_index,
+                            api_enable,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__hops__set_interface_metric_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_interface_metric",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_interface_name = <String>::sse_decode(&mut deserializer);
+            let api_metric = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::hops::set_interface_metric(
+                            &api_interface_name,
+                            api_metric,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__set_tun_fd_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tun_fd",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_fd = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::p2p::set_tun_fd(api_instance_id, api_fd).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_tun_fd_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tun_fd",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_fd = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::set_tun_fd(api_fd).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__magic_wall__simulate_magic_wall_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "simulate_magic_wall",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rules =
+                <Vec<crate::api::magic_wall::MagicWallRule>>::sse_decode(&mut deserializer);
+            let api_packet =
+                <crate::api::magic_wall::MagicWallPacket>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::magic_wall::simulate_magic_wall(api_rules, api_packet)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__magic_wall__start_magic_wall_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_magic_wall",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::magic_wall::start_magic_wall()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__minecraft_lan__start_minecraft_lan_bridge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_minecraft_lan_bridge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_virtual_ip = <String>::sse_decode(&mut deserializer);
+            let api_lan_bind_addr = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::minecraft_lan::start_minecraft_lan_bridge(
+                        api_virtual_ip,
+                        api_lan_bind_addr,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__stun_server__start_stun_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_stun_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_primary_ip = <String>::sse_decode(&mut deserializer);
+            let api_alternate_ip = <String>::sse_decode(&mut deserializer);
+            let api_primary_port = <u16>::sse_decode(&mut deserializer);
+            let api_alternate_port = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::stun_server::start_stun_server(
+                        api_primary_ip,
+                        api_alternate_ip,
+                        api_primary_port,
+                        api_alternate_port,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__forward__stop_all_forward_servers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_all_forward_servers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::forward::stop_all_forward_servers()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__multicast__stop_all_multicast_senders_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_all_multicast_senders",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::multicast::stop_all_multicast_senders()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__forward__stop_forward_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_forward_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::forward::stop_forward_server(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__magic_wall__stop_magic_wall_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_magic_wall",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::magic_wall::stop_magic_wall()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__minecraft_lan__stop_minecraft_lan_bridge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_minecraft_lan_bridge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::minecraft_lan::stop_minecraft_lan_bridge(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__multicast__stop_multicast_listener_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_multicast_listener",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::multicast::stop_multicast_listener(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__multicast__stop_multicast_sender_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_multicast_sender",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::multicast::stop_multicast_sender(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__stun_server__stop_stun_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_stun_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::stun_server::stop_stun_server(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__subscribe_instance_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_instance_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::p2p::InstanceEventRecord,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::p2p::subscribe_instance_events(api_instance_id, api_sink)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__multicast__subscribe_multicast_listener_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_multicast_listener",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::multicast::MulticastPacket,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::multicast::subscribe_multicast_listener(api_id, api_sink)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__supervisor__supervise_instance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "supervise_instance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_policy =
+                <Option<crate::api::supervisor::RestartPolicy>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::supervisor::supervise_instance(api_instance_id, api_policy)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__nat_test__test_nat_behavior_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_nat_behavior",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stun_server = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::nat_test::test_nat_behavior(api_stun_server)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__nat_test__test_network_connectivity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_network_connectivity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stun_server = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::nat_test::test_network_connectivity(api_stun_server)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__network_config__toml_to_flags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "toml_to_flags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_toml = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::network_config::toml_to_flags(api_toml)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__supervisor__unsupervise_instance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsupervise_instance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::supervisor::unsupervise_instance(api_instance_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__magic_wall__update_magic_wall_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_magic_wall_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rule = <crate::api::magic_wall::MagicWallRule>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::magic_wall::update_magic_wall_rule(api_rule)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__network_config__validate_network_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_network_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::network_config::NetworkConfigC>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::network_config::validate_network_config(api_config),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<(), String>>>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for ForwardServer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for JoinHandle<Result<(), String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut inner = <RustOpaqueMoi<
             flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<(), String>>>,
         >>::sse_decode(deserializer);
         return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
     }
 }
-
-impl SseDecode for JoinHandle<Result<String, String>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                JoinHandle<Result<String, String>>,
-            >,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+
+impl SseDecode for JoinHandle<Result<String, String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                JoinHandle<Result<String, String>>,
+            >,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for MinecraftLanBridge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for MulticastListener {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for MulticastSender {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for PeerRoutePair {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for ServerStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<(), String>>>,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::p2p::InstanceEventRecord,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::multicast::MulticastPacket,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::network_config::ConfigBuildOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ipv4PrefixLen = <u8>::sse_decode(deserializer);
+        let mut var_invalidCidrPolicy =
+            <crate::api::network_config::InvalidCidrPolicy>::sse_decode(deserializer);
+        let mut var_customDevName = <bool>::sse_decode(deserializer);
+        return crate::api::network_config::ConfigBuildOptions {
+            ipv4_prefix_len: var_ipv4PrefixLen,
+            invalid_cidr_policy: var_invalidCidrPolicy,
+            custom_dev_name: var_customDevName,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::ConfigChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_action =
+            <Option<crate::api::instance_patch::PatchAction>>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::p2p::ConfigChange {
+            field: var_field,
+            action: var_action,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::network_config::ConfigIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::network_config::ConfigIssue {
+            field: var_field,
+            value: var_value,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::nat_test::FilteringBehavior {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::nat_test::FilteringBehavior::EndpointIndependent,
+            1 => crate::api::nat_test::FilteringBehavior::AddressDependent,
+            2 => crate::api::nat_test::FilteringBehavior::AddressAndPortDependent,
+            3 => crate::api::nat_test::FilteringBehavior::Unknown,
+            _ => unreachable!("Invalid variant for FilteringBehavior: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::FlagsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_defaultProtocol = <String>::sse_decode(deserializer);
+        let mut var_devName = <String>::sse_decode(deserializer);
+        let mut var_enableEncryption = <bool>::sse_decode(deserializer);
+        let mut var_enableIpv6 = <bool>::sse_decode(deserializer);
+        let mut var_mtu = <u32>::sse_decode(deserializer);
+        let mut var_latencyFirst = <bool>::sse_decode(deserializer);
+        let mut var_enableExitNode = <bool>::sse_decode(deserializer);
+        let mut var_noTun = <bool>::sse_decode(deserializer);
+        let mut var_useSmoltcp = <bool>::sse_decode(deserializer);
+        let mut var_relayNetworkWhitelist = <String>::sse_decode(deserializer);
+        let mut var_disableP2P = <bool>::sse_decode(deserializer);
+        let mut var_enableUdpBroadcastRelay = <bool>::sse_decode(deserializer);
+        let mut var_relayAllPeerRpc = <bool>::sse_decode(deserializer);
+        let mut var_disableUdpHolePunching = <bool>::sse_decode(deserializer);
+        let mut var_disableTcpHolePunching = <bool>::sse_decode(deserializer);
+        let mut var_multiThread = <bool>::sse_decode(deserializer);
+        let mut var_dataCompressAlgo = <i32>::sse_decode(deserializer);
+        let mut var_bindDevice = <bool>::sse_decode(deserializer);
+        let mut var_enableKcpProxy = <bool>::sse_decode(deserializer);
+        let mut var_disableKcpInput = <bool>::sse_decode(deserializer);
+        let mut var_disableRelayKcp = <bool>::sse_decode(deserializer);
+        let mut var_proxyForwardBySystem = <bool>::sse_decode(deserializer);
+        let mut var_acceptDns = <bool>::sse_decode(deserializer);
+        let mut var_privateMode = <bool>::sse_decode(deserializer);
+        let mut var_enableQuicProxy = <bool>::sse_decode(deserializer);
+        let mut var_disableQuicInput = <bool>::sse_decode(deserializer);
+        let mut var_disableSymHolePunching = <bool>::sse_decode(deserializer);
+        let mut var_tcpWhitelist = <String>::sse_decode(deserializer);
+        let mut var_udpWhitelist = <String>::sse_decode(deserializer);
+        let mut var_socks5Port = <u16>::sse_decode(deserializer);
+        return crate::api::simple::FlagsC {
+            default_protocol: var_defaultProtocol,
+            dev_name: var_devName,
+            enable_encryption: var_enableEncryption,
+            enable_ipv6: var_enableIpv6,
+            mtu: var_mtu,
+            latency_first: var_latencyFirst,
+            enable_exit_node: var_enableExitNode,
+            no_tun: var_noTun,
+            use_smoltcp: var_useSmoltcp,
+            relay_network_whitelist: var_relayNetworkWhitelist,
+            disable_p2p: var_disableP2P,
+            enable_udp_broadcast_relay: var_enableUdpBroadcastRelay,
+            relay_all_peer_rpc: var_relayAllPeerRpc,
+            disable_udp_hole_punching: var_disableUdpHolePunching,
+            disable_tcp_hole_punching: var_disableTcpHolePunching,
+            multi_thread: var_multiThread,
+            data_compress_algo: var_dataCompressAlgo,
+            bind_device: var_bindDevice,
+            enable_kcp_proxy: var_enableKcpProxy,
+            disable_kcp_input: var_disableKcpInput,
+            disable_relay_kcp: var_disableRelayKcp,
+            proxy_forward_by_system: var_proxyForwardBySystem,
+            accept_dns: var_acceptDns,
+            private_mode: var_privateMode,
+            enable_quic_proxy: var_enableQuicProxy,
+            disable_quic_input: var_disableQuicInput,
+            disable_sym_hole_punching: var_disableSymHolePunching,
+            tcp_whitelist: var_tcpWhitelist,
+            udp_whitelist: var_udpWhitelist,
+            socks5_port: var_socks5Port,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::Forward {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bindAddr = <String>::sse_decode(deserializer);
+        let mut var_dstAddr = <String>::sse_decode(deserializer);
+        let mut var_proto = <String>::sse_decode(deserializer);
+        return crate::api::simple::Forward {
+            bind_addr: var_bindAddr,
+            dst_addr: var_dstAddr,
+            proto: var_proto,
+        };
+    }
+}
+
+impl SseDecode for crate::api::forward::ForwardConnectionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u64>::sse_decode(deserializer);
+        let mut var_peerAddr = <String>::sse_decode(deserializer);
+        let mut var_startedAt = <i64>::sse_decode(deserializer);
+        let mut var_closedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        let mut var_closeReason = <Option<String>>::sse_decode(deserializer);
+        return crate::api::forward::ForwardConnectionInfo {
+            id: var_id,
+            peer_addr: var_peerAddr,
+            started_at: var_startedAt,
+            closed_at: var_closedAt,
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
+            close_reason: var_closeReason,
+        };
+    }
+}
+
+impl SseDecode for crate::api::instance_patch::ForwardPatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_action = <crate::api::instance_patch::PatchAction>::sse_decode(deserializer);
+        let mut var_forward = <crate::api::simple::Forward>::sse_decode(deserializer);
+        return crate::api::instance_patch::ForwardPatch {
+            action: var_action,
+            forward: var_forward,
+        };
+    }
+}
+
+impl SseDecode for crate::api::forward::ForwardServerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_listenAddr = <String>::sse_decode(deserializer);
+        let mut var_forwardAddr = <String>::sse_decode(deserializer);
+        let mut var_protocol = <String>::sse_decode(deserializer);
+        let mut var_isRunning = <bool>::sse_decode(deserializer);
+        let mut var_connections = <usize>::sse_decode(deserializer);
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        return crate::api::forward::ForwardServerInfo {
+            id: var_id,
+            listen_addr: var_listenAddr,
+            forward_addr: var_forwardAddr,
+            protocol: var_protocol,
+            is_running: var_isRunning,
+            connections: var_connections,
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat_test::HolePunchChance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::nat_test::HolePunchChance::Likely,
+            1 => crate::api::nat_test::HolePunchChance::Possible,
+            2 => crate::api::nat_test::HolePunchChance::Unlikely,
+            _ => unreachable!("Invalid variant for HolePunchChance: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat_test::HolePunchPrediction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerNatType = <String>::sse_decode(deserializer);
+        let mut var_chance = <crate::api::nat_test::HolePunchChance>::sse_decode(deserializer);
+        return crate::api::nat_test::HolePunchPrediction {
+            peer_nat_type: var_peerNatType,
+            chance: var_chance,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::p2p::InstanceEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_peerId = <u32>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::PeerAdded {
+                    peer_id: var_peerId,
+                };
+            }
+            1 => {
+                let mut var_peerId = <u32>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::PeerRemoved {
+                    peer_id: var_peerId,
+                };
+            }
+            2 => {
+                let mut var_conn = <crate::api::p2p::PeerConnEvent>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::PeerConnAdded { conn: var_conn };
+            }
+            3 => {
+                let mut var_conn = <crate::api::p2p::PeerConnEvent>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::PeerConnRemoved { conn: var_conn };
+            }
+            4 => {
+                let mut var_listener = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::ListenerAdded {
+                    listener: var_listener,
+                };
+            }
+            5 => {
+                let mut var_listener = <String>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::ListenerAddFailed {
+                    listener: var_listener,
+                    message: var_message,
+                };
+            }
+            6 => {
+                let mut var_listener = <String>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::ListenerAcceptFailed {
+                    listener: var_listener,
+                    message: var_message,
+                };
+            }
+            7 => {
+                let mut var_local = <String>::sse_decode(deserializer);
+                let mut var_remote = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::ConnectionAccepted {
+                    local: var_local,
+                    remote: var_remote,
+                };
+            }
+            8 => {
+                let mut var_local = <String>::sse_decode(deserializer);
+                let mut var_remote = <String>::sse_decode(deserializer);
+                let mut var_error = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::ConnectionError {
+                    local: var_local,
+                    remote: var_remote,
+                    error: var_error,
+                };
+            }
+            9 => {
+                let mut var_dev = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::TunDeviceReady { dev: var_dev };
+            }
+            10 => {
+                let mut var_error = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::TunDeviceError { error: var_error };
+            }
+            11 => {
+                let mut var_dst = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::Connecting { dst: var_dst };
+            }
+            12 => {
+                let mut var_dst = <String>::sse_decode(deserializer);
+                let mut var_ipVersion = <String>::sse_decode(deserializer);
+                let mut var_error = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::ConnectError {
+                    dst: var_dst,
+                    ip_version: var_ipVersion,
+                    error: var_error,
+                };
+            }
+            13 => {
+                let mut var_portal = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::VpnPortalStarted { portal: var_portal };
+            }
+            14 => {
+                let mut var_portal = <String>::sse_decode(deserializer);
+                let mut var_clientAddr = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::VpnPortalClientConnected {
+                    portal: var_portal,
+                    client_addr: var_clientAddr,
+                };
+            }
+            15 => {
+                let mut var_portal = <String>::sse_decode(deserializer);
+                let mut var_clientAddr = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::VpnPortalClientDisconnected {
+                    portal: var_portal,
+                    client_addr: var_clientAddr,
+                };
+            }
+            16 => {
+                let mut var_oldIp = <Option<String>>::sse_decode(deserializer);
+                let mut var_newIp = <Option<String>>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::DhcpIpv4Changed {
+                    old_ip: var_oldIp,
+                    new_ip: var_newIp,
+                };
+            }
+            17 => {
+                let mut var_ip = <Option<String>>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::DhcpIpv4Conflicted { ip: var_ip };
+            }
+            18 => {
+                let mut var_bindAddr = <String>::sse_decode(deserializer);
+                let mut var_dstAddr = <String>::sse_decode(deserializer);
+                let mut var_proto = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::PortForwardAdded {
+                    bind_addr: var_bindAddr,
+                    dst_addr: var_dstAddr,
+                    proto: var_proto,
+                };
+            }
+            19 => {
+                let mut var_changes =
+                    <Vec<crate::api::p2p::ConfigChange>>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::ConfigPatched {
+                    changes: var_changes,
+                };
+            }
+            20 => {
+                let mut var_added = <Vec<String>>::sse_decode(deserializer);
+                let mut var_removed = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::ProxyCidrsUpdated {
+                    added: var_added,
+                    removed: var_removed,
+                };
+            }
+            21 => {
+                let mut var_attempt = <u32>::sse_decode(deserializer);
+                let mut var_delayMs = <u64>::sse_decode(deserializer);
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::InstanceRestarting {
+                    attempt: var_attempt,
+                    delay_ms: var_delayMs,
+                    reason: var_reason,
+                };
+            }
+            22 => {
+                let mut var_attempt = <u32>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::InstanceRestarted {
+                    attempt: var_attempt,
+                };
+            }
+            23 => {
+                let mut var_attempts = <u32>::sse_decode(deserializer);
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::InstanceRestartGaveUp {
+                    attempts: var_attempts,
+                    reason: var_reason,
+                };
+            }
+            24 => {
+                let mut var_dropped = <u64>::sse_decode(deserializer);
+                return crate::api::p2p::InstanceEvent::EventsLagged {
+                    dropped: var_dropped,
+                };
+            }
+            25 => {
+                return crate::api::p2p::InstanceEvent::EventsClosed;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::p2p::InstanceEventRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_instanceId = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_event = <crate::api::p2p::InstanceEvent>::sse_decode(deserializer);
+        return crate::api::p2p::InstanceEventRecord {
+            instance_id: var_instanceId,
+            timestamp: var_timestamp,
+            event: var_event,
+        };
+    }
+}
+
+impl SseDecode for crate::api::instance_patch::InstancePatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peers = <Vec<crate::api::instance_patch::ValuePatch>>::sse_decode(deserializer);
+        let mut var_portForwards =
+            <Vec<crate::api::instance_patch::ForwardPatch>>::sse_decode(deserializer);
+        let mut var_proxyCidrs =
+            <Vec<crate::api::instance_patch::ValuePatch>>::sse_decode(deserializer);
+        let mut var_tcpWhitelist =
+            <Vec<crate::api::instance_patch::ValuePatch>>::sse_decode(deserializer);
+        let mut var_udpWhitelist =
+            <Vec<crate::api::instance_patch::ValuePatch>>::sse_decode(deserializer);
+        return crate::api::instance_patch::InstancePatch {
+            peers: var_peers,
+            port_forwards: var_portForwards,
+            proxy_cidrs: var_proxyCidrs,
+            tcp_whitelist: var_tcpWhitelist,
+            udp_whitelist: var_udpWhitelist,
+        };
+    }
+}
+
+impl SseDecode for crate::api::network_config::InvalidCidrPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::network_config::InvalidCidrPolicy::Reject,
+            1 => crate::api::network_config::InvalidCidrPolicy::Skip,
+            _ => unreachable!("Invalid variant for InvalidCidrPolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::invite::InviteFlags {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_defaultProtocol = <Option<String>>::sse_decode(deserializer);
+        let mut var_enableEncryption = <Option<bool>>::sse_decode(deserializer);
+        let mut var_enableIpv6 = <Option<bool>>::sse_decode(deserializer);
+        let mut var_mtu = <Option<u32>>::sse_decode(deserializer);
+        let mut var_latencyFirst = <Option<bool>>::sse_decode(deserializer);
+        let mut var_disableP2P = <Option<bool>>::sse_decode(deserializer);
+        let mut var_enableUdpBroadcastRelay = <Option<bool>>::sse_decode(deserializer);
+        let mut var_dataCompressAlgo = <Option<i32>>::sse_decode(deserializer);
+        let mut var_enableKcpProxy = <Option<bool>>::sse_decode(deserializer);
+        let mut var_enableQuicProxy = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::invite::InviteFlags {
+            default_protocol: var_defaultProtocol,
+            enable_encryption: var_enableEncryption,
+            enable_ipv6: var_enableIpv6,
+            mtu: var_mtu,
+            latency_first: var_latencyFirst,
+            disable_p2p: var_disableP2P,
+            enable_udp_broadcast_relay: var_enableUdpBroadcastRelay,
+            data_compress_algo: var_dataCompressAlgo,
+            enable_kcp_proxy: var_enableKcpProxy,
+            enable_quic_proxy: var_enableQuicProxy,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::KVNetworkStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_totalNodes = <usize>::sse_decode(deserializer);
+        let mut var_nodes = <Vec<crate::api::simple::KVNodeInfo>>::sse_decode(deserializer);
+        return crate::api::simple::KVNetworkStatus {
+            total_nodes: var_totalNodes,
+            nodes: var_nodes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::KVNodeConnectionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_connType = <String>::sse_decode(deserializer);
+        let mut var_rxBytes = <u64>::sse_decode(deserializer);
+        let mut var_txBytes = <u64>::sse_decode(deserializer);
+        let mut var_rxPackets = <u64>::sse_decode(deserializer);
+        let mut var_txPackets = <u64>::sse_decode(deserializer);
+        return crate::api::simple::KVNodeConnectionStats {
+            conn_type: var_connType,
+            rx_bytes: var_rxBytes,
+            tx_bytes: var_txBytes,
+            rx_packets: var_rxPackets,
+            tx_packets: var_txPackets,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::KVNodeInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_hostname = <String>::sse_decode(deserializer);
+        let mut var_ipv4 = <String>::sse_decode(deserializer);
+        let mut var_ipv6 = <Option<String>>::sse_decode(deserializer);
+        let mut var_publicIpv6 = <Option<String>>::sse_decode(deserializer);
+        let mut var_ipv6Prefix = <Option<String>>::sse_decode(deserializer);
+        let mut var_latencyMs = <f64>::sse_decode(deserializer);
+        let mut var_nat = <String>::sse_decode(deserializer);
+        let mut var_hops = <Vec<crate::api::simple::NodeHopStats>>::sse_decode(deserializer);
+        let mut var_lossRate = <f32>::sse_decode(deserializer);
+        let mut var_connections =
+            <Vec<crate::api::simple::KVNodeConnectionStats>>::sse_decode(deserializer);
+        let mut var_tunnelProto = <String>::sse_decode(deserializer);
+        let mut var_connType = <String>::sse_decode(deserializer);
+        let mut var_rxBytes = <u64>::sse_decode(deserializer);
+        let mut var_txBytes = <u64>::sse_decode(deserializer);
+        let mut var_version = <String>::sse_decode(deserializer);
+        let mut var_cost = <i32>::sse_decode(deserializer);
+        let mut var_proxyCidrs = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::simple::KVNodeInfo {
+            peer_id: var_peerId,
+            hostname: var_hostname,
+            ipv4: var_ipv4,
+            ipv6: var_ipv6,
+            public_ipv6: var_publicIpv6,
+            ipv6_prefix: var_ipv6Prefix,
+            latency_ms: var_latencyMs,
+            nat: var_nat,
+            hops: var_hops,
+            loss_rate: var_lossRate,
+            connections: var_connections,
+            tunnel_proto: var_tunnelProto,
+            conn_type: var_connType,
+            rx_bytes: var_rxBytes,
+            tx_bytes: var_txBytes,
+            version: var_version,
+            cost: var_cost,
+            proxy_cidrs: var_proxyCidrs,
+        };
+    }
+}
+
+impl SseDecode for Vec<PeerRoutePair> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<PeerRoutePair>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::p2p::ConfigChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::p2p::ConfigChange>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::network_config::ConfigIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::network_config::ConfigIssue>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::Forward> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::Forward>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::forward::ForwardConnectionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::forward::ForwardConnectionInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::instance_patch::ForwardPatch> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::instance_patch::ForwardPatch>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::forward::ForwardServerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::forward::ForwardServerInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::nat_test::HolePunchPrediction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::nat_test::HolePunchPrediction>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::p2p::InstanceEventRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::p2p::InstanceEventRecord>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::KVNodeConnectionStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::KVNodeConnectionStats>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::KVNodeInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::KVNodeInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::magic_wall::MagicWallRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::magic_wall::MagicWallRule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::magic_wall::MagicWallRuleIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::magic_wall::MagicWallRuleIssue>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::minecraft_lan::MinecraftLanWorld> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::minecraft_lan::MinecraftLanWorld>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::multicast::MulticastListenerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::multicast::MulticastListenerInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::multicast::MulticastPacket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::multicast::MulticastPacket>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::multicast::MulticastSenderInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::multicast::MulticastSenderInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::nat_test::NatBehaviorResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::nat_test::NatBehaviorResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::NodeHopStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::NodeHopStats>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, u32)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<(String, u32)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::p2p::RunningConnInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::p2p::RunningConnInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::p2p::RunningPeerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::p2p::RunningPeerInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::p2p::RunningRouteInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::p2p::RunningRouteInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::server_probe::ServerProbeResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::server_probe::ServerProbeResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::stun_server::StunServerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::stun_server::StunServerInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::nat_test::StunServerResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::nat_test::StunServerResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::supervisor::SupervisorStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::supervisor::SupervisorStatus>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::instance_patch::ValuePatch> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::instance_patch::ValuePatch>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::magic_wall::MagicWallPacket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_protocol = <String>::sse_decode(deserializer);
+        let mut var_direction = <String>::sse_decode(deserializer);
+        let mut var_localIp = <String>::sse_decode(deserializer);
+        let mut var_remoteIp = <String>::sse_decode(deserializer);
+        let mut var_localPort = <Option<u16>>::sse_decode(deserializer);
+        let mut var_remotePort = <Option<u16>>::sse_decode(deserializer);
+        let mut var_appPath = <Option<String>>::sse_decode(deserializer);
+        return crate::api::magic_wall::MagicWallPacket {
+            protocol: var_protocol,
+            direction: var_direction,
+            local_ip: var_localIp,
+            remote_ip: var_remoteIp,
+            local_port: var_localPort,
+            remote_port: var_remotePort,
+            app_path: var_appPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::magic_wall::MagicWallRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_action = <String>::sse_decode(deserializer);
+        let mut var_protocol = <String>::sse_decode(deserializer);
+        let mut var_direction = <String>::sse_decode(deserializer);
+        let mut var_appPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_remoteIp = <Option<String>>::sse_decode(deserializer);
+        let mut var_localIp = <Option<String>>::sse_decode(deserializer);
+        let mut var_remotePort = <Option<String>>::sse_decode(deserializer);
+        let mut var_localPort = <Option<String>>::sse_decode(deserializer);
+        let mut var_priority = <u32>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::magic_wall::MagicWallRule {
+            id: var_id,
+            name: var_name,
+            enabled: var_enabled,
+            action: var_action,
+            protocol: var_protocol,
+            direction: var_direction,
+            app_path: var_appPath,
+            remote_ip: var_remoteIp,
+            local_ip: var_localIp,
+            remote_port: var_remotePort,
+            local_port: var_localPort,
+            priority: var_priority,
+            description: var_description,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::magic_wall::MagicWallRuleIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::magic_wall::MagicWallRuleIssueKind>::sse_decode(deserializer);
+        let mut var_ruleId = <String>::sse_decode(deserializer);
+        let mut var_otherRuleId = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::magic_wall::MagicWallRuleIssue {
+            kind: var_kind,
+            rule_id: var_ruleId,
+            other_rule_id: var_otherRuleId,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::magic_wall::MagicWallRuleIssueKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::magic_wall::MagicWallRuleIssueKind::Shadowed,
+            1 => crate::api::magic_wall::MagicWallRuleIssueKind::Redundant,
+            2 => crate::api::magic_wall::MagicWallRuleIssueKind::Conflicting,
+            _ => unreachable!("Invalid variant for MagicWallRuleIssueKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::magic_wall::MagicWallStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_isRunning = <bool>::sse_decode(deserializer);
+        let mut var_activeRules = <usize>::sse_decode(deserializer);
+        let mut var_totalRules = <usize>::sse_decode(deserializer);
+        return crate::api::magic_wall::MagicWallStatus {
+            is_running: var_isRunning,
+            active_rules: var_activeRules,
+            total_rules: var_totalRules,
+        };
+    }
+}
+
+impl SseDecode for crate::api::magic_wall::MagicWallVerdict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_action = <String>::sse_decode(deserializer);
+        let mut var_matchedRuleId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::magic_wall::MagicWallVerdict {
+            action: var_action,
+            matched_rule_id: var_matchedRuleId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat_test::MappingBehavior {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::nat_test::MappingBehavior::NoNat,
+            1 => crate::api::nat_test::MappingBehavior::EndpointIndependent,
+            2 => crate::api::nat_test::MappingBehavior::AddressDependent,
+            3 => crate::api::nat_test::MappingBehavior::AddressAndPortDependent,
+            4 => crate::api::nat_test::MappingBehavior::Unknown,
+            _ => unreachable!("Invalid variant for MappingBehavior: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::minecraft_lan::MinecraftLanAnnouncement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_motd = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        return crate::api::minecraft_lan::MinecraftLanAnnouncement {
+            motd: var_motd,
+            port: var_port,
+        };
+    }
+}
+
+impl SseDecode for crate::api::minecraft_lan::MinecraftLanWorld {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_remotePort = <u16>::sse_decode(deserializer);
+        let mut var_localPort = <u16>::sse_decode(deserializer);
+        let mut var_motd = <String>::sse_decode(deserializer);
+        let mut var_lastSeen = <i64>::sse_decode(deserializer);
+        return crate::api::minecraft_lan::MinecraftLanWorld {
+            host: var_host,
+            remote_port: var_remotePort,
+            local_port: var_localPort,
+            motd: var_motd,
+            last_seen: var_lastSeen,
+        };
+    }
+}
+
+impl SseDecode for crate::api::multicast::MulticastListenerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_groupAddr = <String>::sse_decode(deserializer);
+        let mut var_interfaceAddr = <String>::sse_decode(deserializer);
+        let mut var_capacity = <usize>::sse_decode(deserializer);
+        let mut var_packetsReceived = <u64>::sse_decode(deserializer);
+        let mut var_isRunning = <bool>::sse_decode(deserializer);
+        return crate::api::multicast::MulticastListenerInfo {
+            id: var_id,
+            group_addr: var_groupAddr,
+            interface_addr: var_interfaceAddr,
+            capacity: var_capacity,
+            packets_received: var_packetsReceived,
+            is_running: var_isRunning,
+        };
+    }
+}
+
+impl SseDecode for crate::api::multicast::MulticastPacket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_size = <usize>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::multicast::MulticastPacket {
+            source: var_source,
+            size: var_size,
+            timestamp: var_timestamp,
+            data: var_data,
+        };
+    }
+}
+
+impl SseDecode for crate::api::multicast::MulticastSenderInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_multicastAddr = <String>::sse_decode(deserializer);
+        let mut var_bindAddr = <String>::sse_decode(deserializer);
+        let mut var_intervalMs = <u64>::sse_decode(deserializer);
+        let mut var_dataLen = <usize>::sse_decode(deserializer);
+        let mut var_isRunning = <bool>::sse_decode(deserializer);
+        return crate::api::multicast::MulticastSenderInfo {
+            id: var_id,
+            multicast_addr: var_multicastAddr,
+            bind_addr: var_bindAddr,
+            interval_ms: var_intervalMs,
+            data_len: var_dataLen,
+            is_running: var_isRunning,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat_test::NatBehaviorResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_protocol = <String>::sse_decode(deserializer);
+        let mut var_ipVersion = <String>::sse_decode(deserializer);
+        let mut var_server = <Option<String>>::sse_decode(deserializer);
+        let mut var_localAddr = <Option<String>>::sse_decode(deserializer);
+        let mut var_mappedAddr = <Option<String>>::sse_decode(deserializer);
+        let mut var_rttMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_mapping = <crate::api::nat_test::MappingBehavior>::sse_decode(deserializer);
+        let mut var_filtering = <crate::api::nat_test::FilteringBehavior>::sse_decode(deserializer);
+        let mut var_hairpinning = <Option<bool>>::sse_decode(deserializer);
+        let mut var_portPreserved = <Option<bool>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::nat_test::NatBehaviorResult {
+            protocol: var_protocol,
+            ip_version: var_ipVersion,
+            server: var_server,
+            local_addr: var_localAddr,
+            mapped_addr: var_mappedAddr,
+            rtt_ms: var_rttMs,
+            mapping: var_mapping,
+            filtering: var_filtering,
+            hairpinning: var_hairpinning,
+            port_preserved: var_portPreserved,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat_test::NatType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::nat_test::NatType::OpenInternet,
+            1 => crate::api::nat_test::NatType::FullCone,
+            2 => crate::api::nat_test::NatType::RestrictedCone,
+            3 => crate::api::nat_test::NatType::PortRestrictedCone,
+            4 => crate::api::nat_test::NatType::Symmetric,
+            5 => crate::api::nat_test::NatType::SymmetricUdpFirewall,
+            6 => crate::api::nat_test::NatType::Blocked,
+            7 => crate::api::nat_test::NatType::Unknown,
+            _ => unreachable!("Invalid variant for NatType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::network_config::NetworkConfigC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_username = <String>::sse_decode(deserializer);
+        let mut var_enableDhcp = <bool>::sse_decode(deserializer);
+        let mut var_specifiedIp = <String>::sse_decode(deserializer);
+        let mut var_roomName = <String>::sse_decode(deserializer);
+        let mut var_roomPassword = <String>::sse_decode(deserializer);
+        let mut var_severurl = <Vec<String>>::sse_decode(deserializer);
+        let mut var_onurl = <Vec<String>>::sse_decode(deserializer);
+        let mut var_cidrs = <Vec<String>>::sse_decode(deserializer);
+        let mut var_forwards = <Vec<crate::api::simple::Forward>>::sse_decode(deserializer);
+        let mut var_flag = <crate::api::simple::FlagsC>::sse_decode(deserializer);
+        let mut var_options =
+            <crate::api::network_config::ConfigBuildOptions>::sse_decode(deserializer);
+        let mut var_relayPool =
+            <Option<crate::api::relay_select::RelayPool>>::sse_decode(deserializer);
+        return crate::api::network_config::NetworkConfigC {
+            username: var_username,
+            enable_dhcp: var_enableDhcp,
+            specified_ip: var_specifiedIp,
+            room_name: var_roomName,
+            room_password: var_roomPassword,
+            severurl: var_severurl,
+            onurl: var_onurl,
+            cidrs: var_cidrs,
+            forwards: var_forwards,
+            flag: var_flag,
+            options: var_options,
+            relay_pool: var_relayPool,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat_test::NetworkTestResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_natTypeV4 = <String>::sse_decode(deserializer);
+        let mut var_natTypeV6 = <String>::sse_decode(deserializer);
+        let mut var_ipv4Latency = <i64>::sse_decode(deserializer);
+        let mut var_ipv6Latency = <i64>::sse_decode(deserializer);
+        let mut var_natTypeV4Raw = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorV4 = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorV6 = <Option<String>>::sse_decode(deserializer);
+        let mut var_publicEndpointV4 = <Option<String>>::sse_decode(deserializer);
+        let mut var_publicEndpointV6 = <Option<String>>::sse_decode(deserializer);
+        let mut var_stunServers =
+            <Vec<crate::api::nat_test::StunServerResult>>::sse_decode(deserializer);
+        let mut var_holePunch =
+            <Vec<crate::api::nat_test::HolePunchPrediction>>::sse_decode(deserializer);
+        return crate::api::nat_test::NetworkTestResult {
+            nat_type_v4: var_natTypeV4,
+            nat_type_v6: var_natTypeV6,
+            ipv4_latency: var_ipv4Latency,
+            ipv6_latency: var_ipv6Latency,
+            nat_type_v4_raw: var_natTypeV4Raw,
+            error_v4: var_errorV4,
+            error_v6: var_errorV6,
+            public_endpoint_v4: var_publicEndpointV4,
+            public_endpoint_v6: var_publicEndpointV6,
+            stun_servers: var_stunServers,
+            hole_punch: var_holePunch,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NodeHopStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_targetIp = <String>::sse_decode(deserializer);
+        let mut var_latencyMs = <f64>::sse_decode(deserializer);
+        let mut var_packetLoss = <f32>::sse_decode(deserializer);
+        let mut var_nodeName = <String>::sse_decode(deserializer);
+        return crate::api::simple::NodeHopStats {
+            peer_id: var_peerId,
+            target_ip: var_targetIp,
+            latency_ms: var_latencyMs,
+            packet_loss: var_packetLoss,
+            node_name: var_nodeName,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::minecraft_lan::MinecraftLanAnnouncement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::minecraft_lan::MinecraftLanAnnouncement>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::instance_patch::PatchAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::instance_patch::PatchAction>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::server_probe::ProbeErrorKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::server_probe::ProbeErrorKind>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::relay_select::RelayPool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::relay_select::RelayPool>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::supervisor::RestartPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::supervisor::RestartPolicy>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::p2p::RunningNodeInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::p2p::RunningNodeInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::p2p::RunningStunInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::p2p::RunningStunInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::supervisor::SupervisorStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::supervisor::SupervisorStatus>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<usize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::instance_patch::PatchAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::instance_patch::PatchAction::Add,
+            1 => crate::api::instance_patch::PatchAction::Remove,
+            _ => unreachable!("Invalid variant for PatchAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::PeerConnEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_connId = <String>::sse_decode(deserializer);
+        let mut var_myPeerId = <u32>::sse_decode(deserializer);
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_tunnelType = <String>::sse_decode(deserializer);
+        let mut var_localAddr = <String>::sse_decode(deserializer);
+        let mut var_remoteAddr = <String>::sse_decode(deserializer);
+        return crate::api::p2p::PeerConnEvent {
+            conn_id: var_connId,
+            my_peer_id: var_myPeerId,
+            peer_id: var_peerId,
+            tunnel_type: var_tunnelType,
+            local_addr: var_localAddr,
+            remote_addr: var_remoteAddr,
+        };
+    }
+}
+
+impl SseDecode for crate::api::server_probe::ProbeErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::server_probe::ProbeErrorKind::InvalidUrl,
+            1 => crate::api::server_probe::ProbeErrorKind::Dns,
+            2 => crate::api::server_probe::ProbeErrorKind::Refused,
+            3 => crate::api::server_probe::ProbeErrorKind::Timeout,
+            4 => crate::api::server_probe::ProbeErrorKind::Tls,
+            5 => crate::api::server_probe::ProbeErrorKind::Handshake,
+            6 => crate::api::server_probe::ProbeErrorKind::NetworkRejected,
+            7 => crate::api::server_probe::ProbeErrorKind::Other,
+            _ => unreachable!("Invalid variant for ProbeErrorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for (String, u32) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <u32>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (usize, u64, u64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <usize>::sse_decode(deserializer);
+        let mut var_field1 = <u64>::sse_decode(deserializer);
+        let mut var_field2 = <u64>::sse_decode(deserializer);
+        return (var_field0, var_field1, var_field2);
+    }
+}
+
+impl SseDecode for crate::api::relay_select::RelayPool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_candidates = <Vec<String>>::sse_decode(deserializer);
+        let mut var_bestN = <usize>::sse_decode(deserializer);
+        let mut var_reevaluateIntervalSecs = <u64>::sse_decode(deserializer);
+        let mut var_minImprovementMs = <i64>::sse_decode(deserializer);
+        return crate::api::relay_select::RelayPool {
+            candidates: var_candidates,
+            best_n: var_bestN,
+            reevaluate_interval_secs: var_reevaluateIntervalSecs,
+            min_improvement_ms: var_minImprovementMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::supervisor::RestartPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxRetries = <u32>::sse_decode(deserializer);
+        let mut var_initialBackoffMs = <u64>::sse_decode(deserializer);
+        let mut var_maxBackoffMs = <u64>::sse_decode(deserializer);
+        let mut var_restartOnTunError = <bool>::sse_decode(deserializer);
+        return crate::api::supervisor::RestartPolicy {
+            max_retries: var_maxRetries,
+            initial_backoff_ms: var_initialBackoffMs,
+            max_backoff_ms: var_maxBackoffMs,
+            restart_on_tun_error: var_restartOnTunError,
+        };
+    }
+}
+
+impl SseDecode for crate::api::invite::RoomInvite {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_roomName = <String>::sse_decode(deserializer);
+        let mut var_roomPassword = <String>::sse_decode(deserializer);
+        let mut var_servers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_ipRange = <Option<String>>::sse_decode(deserializer);
+        let mut var_flags = <crate::api::invite::InviteFlags>::sse_decode(deserializer);
+        let mut var_expiresAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_verified = <bool>::sse_decode(deserializer);
+        return crate::api::invite::RoomInvite {
+            room_name: var_roomName,
+            room_password: var_roomPassword,
+            servers: var_servers,
+            ip_range: var_ipRange,
+            flags: var_flags,
+            expires_at: var_expiresAt,
+            verified: var_verified,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::RunningConnInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_connId = <String>::sse_decode(deserializer);
+        let mut var_tunnelType = <String>::sse_decode(deserializer);
+        let mut var_localAddr = <String>::sse_decode(deserializer);
+        let mut var_remoteAddr = <String>::sse_decode(deserializer);
+        let mut var_isClient = <bool>::sse_decode(deserializer);
+        let mut var_networkName = <String>::sse_decode(deserializer);
+        let mut var_latencyMs = <f64>::sse_decode(deserializer);
+        let mut var_lossRate = <f32>::sse_decode(deserializer);
+        let mut var_rxBytes = <u64>::sse_decode(deserializer);
+        let mut var_txBytes = <u64>::sse_decode(deserializer);
+        let mut var_rxPackets = <u64>::sse_decode(deserializer);
+        let mut var_txPackets = <u64>::sse_decode(deserializer);
+        return crate::api::p2p::RunningConnInfo {
+            conn_id: var_connId,
+            tunnel_type: var_tunnelType,
+            local_addr: var_localAddr,
+            remote_addr: var_remoteAddr,
+            is_client: var_isClient,
+            network_name: var_networkName,
+            latency_ms: var_latencyMs,
+            loss_rate: var_lossRate,
+            rx_bytes: var_rxBytes,
+            tx_bytes: var_txBytes,
+            rx_packets: var_rxPackets,
+            tx_packets: var_txPackets,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::RunningInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_devName = <String>::sse_decode(deserializer);
+        let mut var_running = <bool>::sse_decode(deserializer);
+        let mut var_errorMsg = <Option<String>>::sse_decode(deserializer);
+        let mut var_myNode = <Option<crate::api::p2p::RunningNodeInfo>>::sse_decode(deserializer);
+        let mut var_peers = <Vec<crate::api::p2p::RunningPeerInfo>>::sse_decode(deserializer);
+        let mut var_routes = <Vec<crate::api::p2p::RunningRouteInfo>>::sse_decode(deserializer);
+        return crate::api::p2p::RunningInfo {
+            dev_name: var_devName,
+            running: var_running,
+            error_msg: var_errorMsg,
+            my_node: var_myNode,
+            peers: var_peers,
+            routes: var_routes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::RunningNodeInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_hostname = <String>::sse_decode(deserializer);
+        let mut var_version = <String>::sse_decode(deserializer);
+        let mut var_virtualIpv4 = <Option<String>>::sse_decode(deserializer);
+        let mut var_virtualIpv6 = <Option<String>>::sse_decode(deserializer);
+        let mut var_listeners = <Vec<String>>::sse_decode(deserializer);
+        let mut var_publicIpv4 = <Option<String>>::sse_decode(deserializer);
+        let mut var_publicIpv6 = <Option<String>>::sse_decode(deserializer);
+        let mut var_interfaceIpv4S = <Vec<String>>::sse_decode(deserializer);
+        let mut var_interfaceIpv6S = <Vec<String>>::sse_decode(deserializer);
+        let mut var_stun = <Option<crate::api::p2p::RunningStunInfo>>::sse_decode(deserializer);
+        return crate::api::p2p::RunningNodeInfo {
+            peer_id: var_peerId,
+            hostname: var_hostname,
+            version: var_version,
+            virtual_ipv4: var_virtualIpv4,
+            virtual_ipv6: var_virtualIpv6,
+            listeners: var_listeners,
+            public_ipv4: var_publicIpv4,
+            public_ipv6: var_publicIpv6,
+            interface_ipv4s: var_interfaceIpv4S,
+            interface_ipv6s: var_interfaceIpv6S,
+            stun: var_stun,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::RunningPeerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_connections = <Vec<crate::api::p2p::RunningConnInfo>>::sse_decode(deserializer);
+        return crate::api::p2p::RunningPeerInfo {
+            peer_id: var_peerId,
+            connections: var_connections,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::RunningRouteInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_instId = <String>::sse_decode(deserializer);
+        let mut var_hostname = <String>::sse_decode(deserializer);
+        let mut var_version = <String>::sse_decode(deserializer);
+        let mut var_ipv4 = <Option<String>>::sse_decode(deserializer);
+        let mut var_ipv6 = <Option<String>>::sse_decode(deserializer);
+        let mut var_nextHopPeerId = <u32>::sse_decode(deserializer);
+        let mut var_cost = <i32>::sse_decode(deserializer);
+        let mut var_pathLatency = <i32>::sse_decode(deserializer);
+        let mut var_proxyCidrs = <Vec<String>>::sse_decode(deserializer);
+        let mut var_udpNatType = <String>::sse_decode(deserializer);
+        return crate::api::p2p::RunningRouteInfo {
+            peer_id: var_peerId,
+            inst_id: var_instId,
+            hostname: var_hostname,
+            version: var_version,
+            ipv4: var_ipv4,
+            ipv6: var_ipv6,
+            next_hop_peer_id: var_nextHopPeerId,
+            cost: var_cost,
+            path_latency: var_pathLatency,
+            proxy_cidrs: var_proxyCidrs,
+            udp_nat_type: var_udpNatType,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::RunningStunInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_udpNatType = <String>::sse_decode(deserializer);
+        let mut var_tcpNatType = <String>::sse_decode(deserializer);
+        let mut var_publicIps = <Vec<String>>::sse_decode(deserializer);
+        let mut var_minPort = <u32>::sse_decode(deserializer);
+        let mut var_maxPort = <u32>::sse_decode(deserializer);
+        let mut var_lastUpdateTime = <i64>::sse_decode(deserializer);
+        return crate::api::p2p::RunningStunInfo {
+            udp_nat_type: var_udpNatType,
+            tcp_nat_type: var_tcpNatType,
+            public_ips: var_publicIps,
+            min_port: var_minPort,
+            max_port: var_maxPort,
+            last_update_time: var_lastUpdateTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::server_probe::ServerProbeResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_reachable = <bool>::sse_decode(deserializer);
+        let mut var_resolvedAddrs = <Vec<String>>::sse_decode(deserializer);
+        let mut var_dnsMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_connectMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_rttMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_remotePeerId = <Option<u32>>::sse_decode(deserializer);
+        let mut var_errorKind =
+            <Option<crate::api::server_probe::ProbeErrorKind>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::server_probe::ServerProbeResult {
+            url: var_url,
+            reachable: var_reachable,
+            resolved_addrs: var_resolvedAddrs,
+            dns_ms: var_dnsMs,
+            connect_ms: var_connectMs,
+            rtt_ms: var_rttMs,
+            remote_peer_id: var_remotePeerId,
+            error_kind: var_errorKind,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::stun_server::StunServerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_primaryAddr = <String>::sse_decode(deserializer);
+        let mut var_alternateAddr = <String>::sse_decode(deserializer);
+        let mut var_running = <bool>::sse_decode(deserializer);
+        let mut var_requests = <u64>::sse_decode(deserializer);
+        return crate::api::stun_server::StunServerInfo {
+            id: var_id,
+            primary_addr: var_primaryAddr,
+            alternate_addr: var_alternateAddr,
+            running: var_running,
+            requests: var_requests,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat_test::StunServerResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_server = <String>::sse_decode(deserializer);
+        let mut var_ipVersion = <String>::sse_decode(deserializer);
+        let mut var_success = <bool>::sse_decode(deserializer);
+        let mut var_latency = <i64>::sse_decode(deserializer);
+        let mut var_mappedAddr = <Option<String>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::nat_test::StunServerResult {
+            server: var_server,
+            ip_version: var_ipVersion,
+            success: var_success,
+            latency: var_latency,
+            mapped_addr: var_mappedAddr,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::supervisor::SupervisorState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::supervisor::SupervisorState::Running,
+            1 => crate::api::supervisor::SupervisorState::Restarting,
+            2 => crate::api::supervisor::SupervisorState::GaveUp,
+            _ => unreachable!("Invalid variant for SupervisorState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::supervisor::SupervisorStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_instanceId = <String>::sse_decode(deserializer);
+        let mut var_policy = <crate::api::supervisor::RestartPolicy>::sse_decode(deserializer);
+        let mut var_state = <crate::api::supervisor::SupervisorState>::sse_decode(deserializer);
+        let mut var_restartCount = <u32>::sse_decode(deserializer);
+        let mut var_consecutiveFailures = <u32>::sse_decode(deserializer);
+        let mut var_lastFailure = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastFailureAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_nextRestartAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::supervisor::SupervisorStatus {
+            instance_id: var_instanceId,
+            policy: var_policy,
+            state: var_state,
+            restart_count: var_restartCount,
+            consecutive_failures: var_consecutiveFailures,
+            last_failure: var_lastFailure,
+            last_failure_at: var_lastFailureAt,
+            next_restart_at: var_nextRestartAt,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::instance_patch::ValuePatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_action = <crate::api::instance_patch::PatchAction>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::instance_patch::ValuePatch {
+            action: var_action,
+            value: var_value,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        3 => wire__crate__api__forward__ForwardServer_is_running_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__forward__ForwardServer_start_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__forward__ForwardServer_stop_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__minecraft_lan__MinecraftLanBridge_is_running_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__minecraft_lan__MinecraftLanBridge_start_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__minecraft_lan__MinecraftLanBridge_stop_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__multicast__MulticastListener_is_running_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__multicast__MulticastListener_start_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__multicast__MulticastListener_stop_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__multicast__MulticastSender_is_running_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__multicast__MulticastSender_start_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__multicast__MulticastSender_stop_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__forward__ServerStats_get_bytes_received_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__forward__ServerStats_get_bytes_sent_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__forward__ServerStats_get_connections_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__forward__ServerStats_new_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__magic_wall__add_magic_wall_rule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__magic_wall__analyze_magic_wall_rules_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__invite__apply_invite_flags_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__utils__check_sudo_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__p2p__close_server_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__simple__close_server_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__magic_wall__create_default_magic_wall_rules_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => {
+            wire__crate__api__forward__create_forward_server_impl(port, ptr, rust_vec_len, data_len)
+        }
+        27 => wire__crate__api__forward__create_forward_server_with_protocol_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__multicast__create_multicast_listener_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__multicast__create_multicast_sender_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__multicast__create_multicast_sender_with_bind_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__p2p__create_server_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__simple__create_server_impl(port, ptr, rust_vec_len, data_len),
+        33 => {
+            wire__crate__api__p2p__create_server_with_config_impl(port, ptr, rust_vec_len, data_len)
+        }
+        34 => {
+            wire__crate__api__p2p__create_server_with_flags_impl(port, ptr, rust_vec_len, data_len)
+        }
+        35 => wire__crate__api__forward__create_udp_forward_server_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__invite__decode_room_invite_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__simple__default_instance_id_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__nat_test__detect_nat_type_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__p2p__easytier_version_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__simple__easytier_version_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__minecraft_lan__encode_minecraft_lan_announcement_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        42 => wire__crate__api__invite__encode_room_invite_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__magic_wall__export_magic_wall_rules_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => {
+            wire__crate__api__network_config__flags_to_toml_impl(port, ptr, rust_vec_len, data_len)
+        }
+        45 => wire__crate__api__hops__get_all_interfaces_metrics_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => {
+            wire__crate__api__firewall__get_firewall_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        47 => wire__crate__api__forward__get_forward_server_count_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__crate__api__forward__get_forward_server_stats_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        49 => wire__crate__api__p2p__get_ips_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__simple__get_ips_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__magic_wall__get_magic_wall_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        52 => wire__crate__api__multicast__get_multicast_listener_packets_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__multicast__get_multicast_sender_count_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__p2p__get_network_status_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__simple__get_network_status_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__nt__get_nt_path_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__p2p__get_peer_route_pairs_impl(port, ptr, rust_vec_len, data_len),
+        58 => {
+            wire__crate__api__simple__get_peer_route_pairs_impl(port, ptr, rust_vec_len, data_len)
+        }
+        59 => wire__crate__api__p2p__get_running_info_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__simple__get_running_info_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__supervisor__get_supervisor_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        62 => wire__crate__api__magic_wall__import_magic_wall_rules_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        63 => wire__crate__api__p2p__init_app_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__event_log__init_event_log_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__magic_wall__init_magic_wall_store_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        67 => wire__crate__api__invite__invite_flags_from_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__p2p__is_easytier_running_impl(port, ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__simple__is_easytier_running_impl(port, ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__forward__is_forward_server_running_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        71 => wire__crate__api__multicast__is_multicast_sender_running_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => wire__crate__api__forward__kill_forward_connection_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => wire__crate__api__forward__list_forward_connections_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        74 => {
+            wire__crate__api__forward__list_forward_servers_impl(port, ptr, rust_vec_len, data_len)
+        }
+        75 => wire__crate__api__event_log__list_logged_instances_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        76 => wire__crate__api__minecraft_lan__list_minecraft_lan_worlds_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        77 => wire__crate__api__multicast__list_multicast_listeners_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        78 => wire__crate__api__multicast__list_multicast_senders_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        79 => {
+            wire__crate__api__stun_server__list_stun_servers_impl(port, ptr, rust_vec_len, data_len)
+        }
+        80 => wire__crate__api__supervisor__list_supervisor_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        81 => wire__crate__api__nat_test__nat_type_get_description_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        82 => wire__crate__api__minecraft_lan__parse_minecraft_lan_announcement_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        83 => {
+            wire__crate__api__instance_patch__patch_instance_impl(port, ptr, rust_vec_len, data_len)
+        }
+        84 => wire__crate__api__server_probe__probe_servers_impl(port, ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__magic_wall__purge_magic_wall_filters_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        86 => wire__crate__api__event_log__query_instance_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        87 => {
+            wire__crate__api__forward__remove_forward_server_impl(port, ptr, rust_vec_len, data_len)
+        }
+        88 => wire__crate__api__magic_wall__remove_magic_wall_rule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        89 => wire__crate__api__multicast__remove_multicast_listener_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        90 => wire__crate__api__multicast__remove_multicast_sender_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        91 => wire__crate__api__stun_server__remove_stun_server_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        92 => {
+            wire__crate__api__firewall__set_firewall_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        93 => wire__crate__api__hops__set_interface_metric_impl(port, ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__p2p__set_tun_fd_impl(port, ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__simple__set_tun_fd_impl(port, ptr, rust_vec_len, data_len),
+        96 => wire__crate__api__magic_wall__simulate_magic_wall_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        97 => {
+            wire__crate__api__magic_wall__start_magic_wall_impl(port, ptr, rust_vec_len, data_len)
+        }
+        98 => wire__crate__api__minecraft_lan__start_minecraft_lan_bridge_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => {
+            wire__crate__api__stun_server__start_stun_server_impl(port, ptr, rust_vec_len, data_len)
+        }
+        100 => wire__crate__api__forward__stop_all_forward_servers_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        101 => wire__crate__api__multicast__stop_all_multicast_senders_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        102 => {
+            wire__crate__api__forward__stop_forward_server_impl(port, ptr, rust_vec_len, data_len)
+        }
+        103 => {
+            wire__crate__api__magic_wall__stop_magic_wall_impl(port, ptr, rust_vec_len, data_len)
+        }
+        104 => wire__crate__api__minecraft_lan__stop_minecraft_lan_bridge_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        105 => wire__crate__api__multicast__stop_multicast_listener_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        106 => wire__crate__api__multicast__stop_multicast_sender_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        107 => {
+            wire__crate__api__stun_server__stop_stun_server_impl(port, ptr, rust_vec_len, data_len)
+        }
+        108 => {
+            wire__crate__api__p2p__subscribe_instance_events_impl(port, ptr, rust_vec_len, data_len)
+        }
+        109 => wire__crate__api__multicast__subscribe_multicast_listener_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => {
+            wire__crate__api__supervisor__supervise_instance_impl(port, ptr, rust_vec_len, data_len)
+        }
+        111 => {
+            wire__crate__api__nat_test__test_nat_behavior_impl(port, ptr, rust_vec_len, data_len)
+        }
+        112 => wire__crate__api__nat_test__test_network_connectivity_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        113 => {
+            wire__crate__api__network_config__toml_to_flags_impl(port, ptr, rust_vec_len, data_len)
+        }
+        114 => wire__crate__api__supervisor__unsupervise_instance_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        115 => wire__crate__api__magic_wall__update_magic_wall_rule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        116 => wire__crate__api__network_config__validate_network_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__forward__ForwardServer_auto_accessor_get_stats_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__forward__ForwardServer_auto_accessor_set_stats_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ForwardServer> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ForwardServer> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ForwardServer>> for ForwardServer {
+    fn into_into_dart(self) -> FrbWrapper<ForwardServer> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<JoinHandle<Result<(), String>>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<JoinHandle<Result<(), String>>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<JoinHandle<Result<(), String>>>>
+    for JoinHandle<Result<(), String>>
+{
+    fn into_into_dart(self) -> FrbWrapper<JoinHandle<Result<(), String>>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<JoinHandle<Result<String, String>>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<JoinHandle<Result<String, String>>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<JoinHandle<Result<String, String>>>>
+    for JoinHandle<Result<String, String>>
+{
+    fn into_into_dart(self) -> FrbWrapper<JoinHandle<Result<String, String>>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<MinecraftLanBridge> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<MinecraftLanBridge>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MinecraftLanBridge>> for MinecraftLanBridge {
+    fn into_into_dart(self) -> FrbWrapper<MinecraftLanBridge> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<MulticastListener> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<MulticastListener> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MulticastListener>> for MulticastListener {
+    fn into_into_dart(self) -> FrbWrapper<MulticastListener> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<MulticastSender> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<MulticastSender> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MulticastSender>> for MulticastSender {
+    fn into_into_dart(self) -> FrbWrapper<MulticastSender> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<PeerRoutePair> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PeerRoutePair> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PeerRoutePair>> for PeerRoutePair {
+    fn into_into_dart(self) -> FrbWrapper<PeerRoutePair> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ServerStats> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ServerStats> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ServerStats>> for ServerStats {
+    fn into_into_dart(self) -> FrbWrapper<ServerStats> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::network_config::ConfigBuildOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ipv4_prefix_len.into_into_dart().into_dart(),
+            self.invalid_cidr_policy.into_into_dart().into_dart(),
+            self.custom_dev_name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::network_config::ConfigBuildOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::network_config::ConfigBuildOptions>
+    for crate::api::network_config::ConfigBuildOptions
+{
+    fn into_into_dart(self) -> crate::api::network_config::ConfigBuildOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::ConfigChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::ConfigChange {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::ConfigChange>
+    for crate::api::p2p::ConfigChange
+{
+    fn into_into_dart(self) -> crate::api::p2p::ConfigChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::network_config::ConfigIssue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::network_config::ConfigIssue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::network_config::ConfigIssue>
+    for crate::api::network_config::ConfigIssue
+{
+    fn into_into_dart(self) -> crate::api::network_config::ConfigIssue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat_test::FilteringBehavior {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::EndpointIndependent => 0.into_dart(),
+            Self::AddressDependent => 1.into_dart(),
+            Self::AddressAndPortDependent => 2.into_dart(),
+            Self::Unknown => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat_test::FilteringBehavior
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::FilteringBehavior>
+    for crate::api::nat_test::FilteringBehavior
+{
+    fn into_into_dart(self) -> crate::api::nat_test::FilteringBehavior {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::FlagsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.default_protocol.into_into_dart().into_dart(),
+            self.dev_name.into_into_dart().into_dart(),
+            self.enable_encryption.into_into_dart().into_dart(),
+            self.enable_ipv6.into_into_dart().into_dart(),
+            self.mtu.into_into_dart().into_dart(),
+            self.latency_first.into_into_dart().into_dart(),
+            self.enable_exit_node.into_into_dart().into_dart(),
+            self.no_tun.into_into_dart().into_dart(),
+            self.use_smoltcp.into_into_dart().into_dart(),
+            self.relay_network_whitelist.into_into_dart().into_dart(),
+            self.disable_p2p.into_into_dart().into_dart(),
+            self.enable_udp_broadcast_relay.into_into_dart().into_dart(),
+            self.relay_all_peer_rpc.into_into_dart().into_dart(),
+            self.disable_udp_hole_punching.into_into_dart().into_dart(),
+            self.disable_tcp_hole_punching.into_into_dart().into_dart(),
+            self.multi_thread.into_into_dart().into_dart(),
+            self.data_compress_algo.into_into_dart().into_dart(),
+            self.bind_device.into_into_dart().into_dart(),
+            self.enable_kcp_proxy.into_into_dart().into_dart(),
+            self.disable_kcp_input.into_into_dart().into_dart(),
+            self.disable_relay_kcp.into_into_dart().into_dart(),
+            self.proxy_forward_by_system.into_into_dart().into_dart(),
+            self.accept_dns.into_into_dart().into_dart(),
+            self.private_mode.into_into_dart().into_dart(),
+            self.enable_quic_proxy.into_into_dart().into_dart(),
+            self.disable_quic_input.into_into_dart().into_dart(),
+            self.disable_sym_hole_punching.into_into_dart().into_dart(),
+            self.tcp_whitelist.into_into_dart().into_dart(),
+            self.udp_whitelist.into_into_dart().into_dart(),
+            self.socks5_port.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::FlagsC {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FlagsC> for crate::api::simple::FlagsC {
+    fn into_into_dart(self) -> crate::api::simple::FlagsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::Forward {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bind_addr.into_into_dart().into_dart(),
+            self.dst_addr.into_into_dart().into_dart(),
+            self.proto.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::Forward {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::Forward>
+    for crate::api::simple::Forward
+{
+    fn into_into_dart(self) -> crate::api::simple::Forward {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::forward::ForwardConnectionInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.peer_addr.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.closed_at.into_into_dart().into_dart(),
+            self.bytes_sent.into_into_dart().into_dart(),
+            self.bytes_received.into_into_dart().into_dart(),
+            self.close_reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::forward::ForwardConnectionInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::forward::ForwardConnectionInfo>
+    for crate::api::forward::ForwardConnectionInfo
+{
+    fn into_into_dart(self) -> crate::api::forward::ForwardConnectionInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::instance_patch::ForwardPatch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.action.into_into_dart().into_dart(),
+            self.forward.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::instance_patch::ForwardPatch
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::instance_patch::ForwardPatch>
+    for crate::api::instance_patch::ForwardPatch
+{
+    fn into_into_dart(self) -> crate::api::instance_patch::ForwardPatch {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::forward::ForwardServerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.listen_addr.into_into_dart().into_dart(),
+            self.forward_addr.into_into_dart().into_dart(),
+            self.protocol.into_into_dart().into_dart(),
+            self.is_running.into_into_dart().into_dart(),
+            self.connections.into_into_dart().into_dart(),
+            self.bytes_sent.into_into_dart().into_dart(),
+            self.bytes_received.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::forward::ForwardServerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::forward::ForwardServerInfo>
+    for crate::api::forward::ForwardServerInfo
+{
+    fn into_into_dart(self) -> crate::api::forward::ForwardServerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat_test::HolePunchChance {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Likely => 0.into_dart(),
+            Self::Possible => 1.into_dart(),
+            Self::Unlikely => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat_test::HolePunchChance
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::HolePunchChance>
+    for crate::api::nat_test::HolePunchChance
+{
+    fn into_into_dart(self) -> crate::api::nat_test::HolePunchChance {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat_test::HolePunchPrediction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_nat_type.into_into_dart().into_dart(),
+            self.chance.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat_test::HolePunchPrediction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::HolePunchPrediction>
+    for crate::api::nat_test::HolePunchPrediction
+{
+    fn into_into_dart(self) -> crate::api::nat_test::HolePunchPrediction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::InstanceEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::p2p::InstanceEvent::PeerAdded { peer_id } => {
+                [0.into_dart(), peer_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::PeerRemoved { peer_id } => {
+                [1.into_dart(), peer_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::PeerConnAdded { conn } => {
+                [2.into_dart(), conn.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::PeerConnRemoved { conn } => {
+                [3.into_dart(), conn.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::ListenerAdded { listener } => {
+                [4.into_dart(), listener.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::ListenerAddFailed { listener, message } => [
+                5.into_dart(),
+                listener.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::ListenerAcceptFailed { listener, message } => [
+                6.into_dart(),
+                listener.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::ConnectionAccepted { local, remote } => [
+                7.into_dart(),
+                local.into_into_dart().into_dart(),
+                remote.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::ConnectionError {
+                local,
+                remote,
+                error,
+            } => [
+                8.into_dart(),
+                local.into_into_dart().into_dart(),
+                remote.into_into_dart().into_dart(),
+                error.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::TunDeviceReady { dev } => {
+                [9.into_dart(), dev.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::TunDeviceError { error } => {
+                [10.into_dart(), error.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::Connecting { dst } => {
+                [11.into_dart(), dst.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::ConnectError {
+                dst,
+                ip_version,
+                error,
+            } => [
+                12.into_dart(),
+                dst.into_into_dart().into_dart(),
+                ip_version.into_into_dart().into_dart(),
+                error.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::VpnPortalStarted { portal } => {
+                [13.into_dart(), portal.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::VpnPortalClientConnected {
+                portal,
+                client_addr,
+            } => [
+                14.into_dart(),
+                portal.into_into_dart().into_dart(),
+                client_addr.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::VpnPortalClientDisconnected {
+                portal,
+                client_addr,
+            } => [
+                15.into_dart(),
+                portal.into_into_dart().into_dart(),
+                client_addr.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::DhcpIpv4Changed { old_ip, new_ip } => [
+                16.into_dart(),
+                old_ip.into_into_dart().into_dart(),
+                new_ip.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::DhcpIpv4Conflicted { ip } => {
+                [17.into_dart(), ip.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::PortForwardAdded {
+                bind_addr,
+                dst_addr,
+                proto,
+            } => [
+                18.into_dart(),
+                bind_addr.into_into_dart().into_dart(),
+                dst_addr.into_into_dart().into_dart(),
+                proto.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::ConfigPatched { changes } => {
+                [19.into_dart(), changes.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::ProxyCidrsUpdated { added, removed } => [
+                20.into_dart(),
+                added.into_into_dart().into_dart(),
+                removed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::InstanceRestarting {
+                attempt,
+                delay_ms,
+                reason,
+            } => [
+                21.into_dart(),
+                attempt.into_into_dart().into_dart(),
+                delay_ms.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::InstanceRestarted { attempt } => {
+                [22.into_dart(), attempt.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::InstanceRestartGaveUp { attempts, reason } => [
+                23.into_dart(),
+                attempts.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::p2p::InstanceEvent::EventsLagged { dropped } => {
+                [24.into_dart(), dropped.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::p2p::InstanceEvent::EventsClosed => [25.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::InstanceEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::InstanceEvent>
+    for crate::api::p2p::InstanceEvent
+{
+    fn into_into_dart(self) -> crate::api::p2p::InstanceEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::InstanceEventRecord {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.instance_id.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.event.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::InstanceEventRecord
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::InstanceEventRecord>
+    for crate::api::p2p::InstanceEventRecord
+{
+    fn into_into_dart(self) -> crate::api::p2p::InstanceEventRecord {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::instance_patch::InstancePatch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peers.into_into_dart().into_dart(),
+            self.port_forwards.into_into_dart().into_dart(),
+            self.proxy_cidrs.into_into_dart().into_dart(),
+            self.tcp_whitelist.into_into_dart().into_dart(),
+            self.udp_whitelist.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::instance_patch::InstancePatch
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::instance_patch::InstancePatch>
+    for crate::api::instance_patch::InstancePatch
+{
+    fn into_into_dart(self) -> crate::api::instance_patch::InstancePatch {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::network_config::InvalidCidrPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Reject => 0.into_dart(),
+            Self::Skip => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::network_config::InvalidCidrPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::network_config::InvalidCidrPolicy>
+    for crate::api::network_config::InvalidCidrPolicy
+{
+    fn into_into_dart(self) -> crate::api::network_config::InvalidCidrPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::invite::InviteFlags {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.default_protocol.into_into_dart().into_dart(),
+            self.enable_encryption.into_into_dart().into_dart(),
+            self.enable_ipv6.into_into_dart().into_dart(),
+            self.mtu.into_into_dart().into_dart(),
+            self.latency_first.into_into_dart().into_dart(),
+            self.disable_p2p.into_into_dart().into_dart(),
+            self.enable_udp_broadcast_relay.into_into_dart().into_dart(),
+            self.data_compress_algo.into_into_dart().into_dart(),
+            self.enable_kcp_proxy.into_into_dart().into_dart(),
+            self.enable_quic_proxy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::invite::InviteFlags
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::invite::InviteFlags>
+    for crate::api::invite::InviteFlags
+{
+    fn into_into_dart(self) -> crate::api::invite::InviteFlags {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::KVNetworkStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total_nodes.into_into_dart().into_dart(),
+            self.nodes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::KVNetworkStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::KVNetworkStatus>
+    for crate::api::simple::KVNetworkStatus
+{
+    fn into_into_dart(self) -> crate::api::simple::KVNetworkStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::KVNodeConnectionStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conn_type.into_into_dart().into_dart(),
+            self.rx_bytes.into_into_dart().into_dart(),
+            self.tx_bytes.into_into_dart().into_dart(),
+            self.rx_packets.into_into_dart().into_dart(),
+            self.tx_packets.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::KVNodeConnectionStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::KVNodeConnectionStats>
+    for crate::api::simple::KVNodeConnectionStats
+{
+    fn into_into_dart(self) -> crate::api::simple::KVNodeConnectionStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::KVNodeInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.hostname.into_into_dart().into_dart(),
+            self.ipv4.into_into_dart().into_dart(),
+            self.ipv6.into_into_dart().into_dart(),
+            self.public_ipv6.into_into_dart().into_dart(),
+            self.ipv6_prefix.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.nat.into_into_dart().into_dart(),
+            self.hops.into_into_dart().into_dart(),
+            self.loss_rate.into_into_dart().into_dart(),
+            self.connections.into_into_dart().into_dart(),
+            self.tunnel_proto.into_into_dart().into_dart(),
+            self.conn_type.into_into_dart().into_dart(),
+            self.rx_bytes.into_into_dart().into_dart(),
+            self.tx_bytes.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.cost.into_into_dart().into_dart(),
+            self.proxy_cidrs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::KVNodeInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::KVNodeInfo>
+    for crate::api::simple::KVNodeInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::KVNodeInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::magic_wall::MagicWallPacket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.protocol.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.local_ip.into_into_dart().into_dart(),
+            self.remote_ip.into_into_dart().into_dart(),
+            self.local_port.into_into_dart().into_dart(),
+            self.remote_port.into_into_dart().into_dart(),
+            self.app_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::magic_wall::MagicWallPacket
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::magic_wall::MagicWallPacket>
+    for crate::api::magic_wall::MagicWallPacket
+{
+    fn into_into_dart(self) -> crate::api::magic_wall::MagicWallPacket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::magic_wall::MagicWallRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+            self.protocol.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.app_path.into_into_dart().into_dart(),
+            self.remote_ip.into_into_dart().into_dart(),
+            self.local_ip.into_into_dart().into_dart(),
+            self.remote_port.into_into_dart().into_dart(),
+            self.local_port.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::magic_wall::MagicWallRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::magic_wall::MagicWallRule>
+    for crate::api::magic_wall::MagicWallRule
+{
+    fn into_into_dart(self) -> crate::api::magic_wall::MagicWallRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::magic_wall::MagicWallRuleIssue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.rule_id.into_into_dart().into_dart(),
+            self.other_rule_id.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::magic_wall::MagicWallRuleIssue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::magic_wall::MagicWallRuleIssue>
+    for crate::api::magic_wall::MagicWallRuleIssue
+{
+    fn into_into_dart(self) -> crate::api::magic_wall::MagicWallRuleIssue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::magic_wall::MagicWallRuleIssueKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Shadowed => 0.into_dart(),
+            Self::Redundant => 1.into_dart(),
+            Self::Conflicting => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::magic_wall::MagicWallRuleIssueKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::magic_wall::MagicWallRuleIssueKind>
+    for crate::api::magic_wall::MagicWallRuleIssueKind
+{
+    fn into_into_dart(self) -> crate::api::magic_wall::MagicWallRuleIssueKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::magic_wall::MagicWallStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.is_running.into_into_dart().into_dart(),
+            self.active_rules.into_into_dart().into_dart(),
+            self.total_rules.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::magic_wall::MagicWallStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::magic_wall::MagicWallStatus>
+    for crate::api::magic_wall::MagicWallStatus
+{
+    fn into_into_dart(self) -> crate::api::magic_wall::MagicWallStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::magic_wall::MagicWallVerdict {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.action.into_into_dart().into_dart(),
+            self.matched_rule_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::magic_wall::MagicWallVerdict
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::magic_wall::MagicWallVerdict>
+    for crate::api::magic_wall::MagicWallVerdict
+{
+    fn into_into_dart(self) -> crate::api::magic_wall::MagicWallVerdict {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat_test::MappingBehavior {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NoNat => 0.into_dart(),
+            Self::EndpointIndependent => 1.into_dart(),
+            Self::AddressDependent => 2.into_dart(),
+            Self::AddressAndPortDependent => 3.into_dart(),
+            Self::Unknown => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat_test::MappingBehavior
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::MappingBehavior>
+    for crate::api::nat_test::MappingBehavior
+{
+    fn into_into_dart(self) -> crate::api::nat_test::MappingBehavior {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::minecraft_lan::MinecraftLanAnnouncement {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.motd.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::minecraft_lan::MinecraftLanAnnouncement
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::minecraft_lan::MinecraftLanAnnouncement>
+    for crate::api::minecraft_lan::MinecraftLanAnnouncement
+{
+    fn into_into_dart(self) -> crate::api::minecraft_lan::MinecraftLanAnnouncement {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::minecraft_lan::MinecraftLanWorld {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.host.into_into_dart().into_dart(),
+            self.remote_port.into_into_dart().into_dart(),
+            self.local_port.into_into_dart().into_dart(),
+            self.motd.into_into_dart().into_dart(),
+            self.last_seen.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::minecraft_lan::MinecraftLanWorld
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::minecraft_lan::MinecraftLanWorld>
+    for crate::api::minecraft_lan::MinecraftLanWorld
+{
+    fn into_into_dart(self) -> crate::api::minecraft_lan::MinecraftLanWorld {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::multicast::MulticastListenerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.group_addr.into_into_dart().into_dart(),
+            self.interface_addr.into_into_dart().into_dart(),
+            self.capacity.into_into_dart().into_dart(),
+            self.packets_received.into_into_dart().into_dart(),
+            self.is_running.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::multicast::MulticastListenerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::multicast::MulticastListenerInfo>
+    for crate::api::multicast::MulticastListenerInfo
+{
+    fn into_into_dart(self) -> crate::api::multicast::MulticastListenerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::multicast::MulticastPacket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::multicast::MulticastPacket
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::multicast::MulticastPacket>
+    for crate::api::multicast::MulticastPacket
+{
+    fn into_into_dart(self) -> crate::api::multicast::MulticastPacket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::multicast::MulticastSenderInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.multicast_addr.into_into_dart().into_dart(),
+            self.bind_addr.into_into_dart().into_dart(),
+            self.interval_ms.into_into_dart().into_dart(),
+            self.data_len.into_into_dart().into_dart(),
+            self.is_running.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::multicast::MulticastSenderInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::multicast::MulticastSenderInfo>
+    for crate::api::multicast::MulticastSenderInfo
+{
+    fn into_into_dart(self) -> crate::api::multicast::MulticastSenderInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat_test::NatBehaviorResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.protocol.into_into_dart().into_dart(),
+            self.ip_version.into_into_dart().into_dart(),
+            self.server.into_into_dart().into_dart(),
+            self.local_addr.into_into_dart().into_dart(),
+            self.mapped_addr.into_into_dart().into_dart(),
+            self.rtt_ms.into_into_dart().into_dart(),
+            self.mapping.into_into_dart().into_dart(),
+            self.filtering.into_into_dart().into_dart(),
+            self.hairpinning.into_into_dart().into_dart(),
+            self.port_preserved.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat_test::NatBehaviorResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::NatBehaviorResult>
+    for crate::api::nat_test::NatBehaviorResult
+{
+    fn into_into_dart(self) -> crate::api::nat_test::NatBehaviorResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat_test::NatType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::OpenInternet => 0.into_dart(),
+            Self::FullCone => 1.into_dart(),
+            Self::RestrictedCone => 2.into_dart(),
+            Self::PortRestrictedCone => 3.into_dart(),
+            Self::Symmetric => 4.into_dart(),
+            Self::SymmetricUdpFirewall => 5.into_dart(),
+            Self::Blocked => 6.into_dart(),
+            Self::Unknown => 7.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nat_test::NatType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::NatType>
+    for crate::api::nat_test::NatType
+{
+    fn into_into_dart(self) -> crate::api::nat_test::NatType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::network_config::NetworkConfigC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.username.into_into_dart().into_dart(),
+            self.enable_dhcp.into_into_dart().into_dart(),
+            self.specified_ip.into_into_dart().into_dart(),
+            self.room_name.into_into_dart().into_dart(),
+            self.room_password.into_into_dart().into_dart(),
+            self.severurl.into_into_dart().into_dart(),
+            self.onurl.into_into_dart().into_dart(),
+            self.cidrs.into_into_dart().into_dart(),
+            self.forwards.into_into_dart().into_dart(),
+            self.flag.into_into_dart().into_dart(),
+            self.options.into_into_dart().into_dart(),
+            self.relay_pool.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::network_config::NetworkConfigC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::network_config::NetworkConfigC>
+    for crate::api::network_config::NetworkConfigC
+{
+    fn into_into_dart(self) -> crate::api::network_config::NetworkConfigC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat_test::NetworkTestResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.nat_type_v4.into_into_dart().into_dart(),
+            self.nat_type_v6.into_into_dart().into_dart(),
+            self.ipv4_latency.into_into_dart().into_dart(),
+            self.ipv6_latency.into_into_dart().into_dart(),
+            self.nat_type_v4_raw.into_into_dart().into_dart(),
+            self.error_v4.into_into_dart().into_dart(),
+            self.error_v6.into_into_dart().into_dart(),
+            self.public_endpoint_v4.into_into_dart().into_dart(),
+            self.public_endpoint_v6.into_into_dart().into_dart(),
+            self.stun_servers.into_into_dart().into_dart(),
+            self.hole_punch.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat_test::NetworkTestResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::NetworkTestResult>
+    for crate::api::nat_test::NetworkTestResult
+{
+    fn into_into_dart(self) -> crate::api::nat_test::NetworkTestResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NodeHopStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.target_ip.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.packet_loss.into_into_dart().into_dart(),
+            self.node_name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::NodeHopStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NodeHopStats>
+    for crate::api::simple::NodeHopStats
+{
+    fn into_into_dart(self) -> crate::api::simple::NodeHopStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::instance_patch::PatchAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Add => 0.into_dart(),
+            Self::Remove => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::instance_patch::PatchAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::instance_patch::PatchAction>
+    for crate::api::instance_patch::PatchAction
+{
+    fn into_into_dart(self) -> crate::api::instance_patch::PatchAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::PeerConnEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conn_id.into_into_dart().into_dart(),
+            self.my_peer_id.into_into_dart().into_dart(),
+            self.peer_id.into_into_dart().into_dart(),
+            self.tunnel_type.into_into_dart().into_dart(),
+            self.local_addr.into_into_dart().into_dart(),
+            self.remote_addr.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::PeerConnEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::PeerConnEvent>
+    for crate::api::p2p::PeerConnEvent
+{
+    fn into_into_dart(self) -> crate::api::p2p::PeerConnEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::server_probe::ProbeErrorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::InvalidUrl => 0.into_dart(),
+            Self::Dns => 1.into_dart(),
+            Self::Refused => 2.into_dart(),
+            Self::Timeout => 3.into_dart(),
+            Self::Tls => 4.into_dart(),
+            Self::Handshake => 5.into_dart(),
+            Self::NetworkRejected => 6.into_dart(),
+            Self::Other => 7.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::server_probe::ProbeErrorKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::server_probe::ProbeErrorKind>
+    for crate::api::server_probe::ProbeErrorKind
+{
+    fn into_into_dart(self) -> crate::api::server_probe::ProbeErrorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::relay_select::RelayPool {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.candidates.into_into_dart().into_dart(),
+            self.best_n.into_into_dart().into_dart(),
+            self.reevaluate_interval_secs.into_into_dart().into_dart(),
+            self.min_improvement_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::relay_select::RelayPool
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relay_select::RelayPool>
+    for crate::api::relay_select::RelayPool
+{
+    fn into_into_dart(self) -> crate::api::relay_select::RelayPool {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::supervisor::RestartPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_retries.into_into_dart().into_dart(),
+            self.initial_backoff_ms.into_into_dart().into_dart(),
+            self.max_backoff_ms.into_into_dart().into_dart(),
+            self.restart_on_tun_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::supervisor::RestartPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::supervisor::RestartPolicy>
+    for crate::api::supervisor::RestartPolicy
+{
+    fn into_into_dart(self) -> crate::api::supervisor::RestartPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::invite::RoomInvite {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.room_name.into_into_dart().into_dart(),
+            self.room_password.into_into_dart().into_dart(),
+            self.servers.into_into_dart().into_dart(),
+            self.ip_range.into_into_dart().into_dart(),
+            self.flags.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+            self.verified.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::invite::RoomInvite
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::invite::RoomInvite>
+    for crate::api::invite::RoomInvite
+{
+    fn into_into_dart(self) -> crate::api::invite::RoomInvite {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::RunningConnInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conn_id.into_into_dart().into_dart(),
+            self.tunnel_type.into_into_dart().into_dart(),
+            self.local_addr.into_into_dart().into_dart(),
+            self.remote_addr.into_into_dart().into_dart(),
+            self.is_client.into_into_dart().into_dart(),
+            self.network_name.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.loss_rate.into_into_dart().into_dart(),
+            self.rx_bytes.into_into_dart().into_dart(),
+            self.tx_bytes.into_into_dart().into_dart(),
+            self.rx_packets.into_into_dart().into_dart(),
+            self.tx_packets.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::RunningConnInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::RunningConnInfo>
+    for crate::api::p2p::RunningConnInfo
+{
+    fn into_into_dart(self) -> crate::api::p2p::RunningConnInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::RunningInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.dev_name.into_into_dart().into_dart(),
+            self.running.into_into_dart().into_dart(),
+            self.error_msg.into_into_dart().into_dart(),
+            self.my_node.into_into_dart().into_dart(),
+            self.peers.into_into_dart().into_dart(),
+            self.routes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::RunningInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::RunningInfo>
+    for crate::api::p2p::RunningInfo
+{
+    fn into_into_dart(self) -> crate::api::p2p::RunningInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::RunningNodeInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.hostname.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.virtual_ipv4.into_into_dart().into_dart(),
+            self.virtual_ipv6.into_into_dart().into_dart(),
+            self.listeners.into_into_dart().into_dart(),
+            self.public_ipv4.into_into_dart().into_dart(),
+            self.public_ipv6.into_into_dart().into_dart(),
+            self.interface_ipv4s.into_into_dart().into_dart(),
+            self.interface_ipv6s.into_into_dart().into_dart(),
+            self.stun.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::RunningNodeInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::RunningNodeInfo>
+    for crate::api::p2p::RunningNodeInfo
+{
+    fn into_into_dart(self) -> crate::api::p2p::RunningNodeInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::RunningPeerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.connections.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for MulticastSender {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::RunningPeerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::RunningPeerInfo>
+    for crate::api::p2p::RunningPeerInfo
+{
+    fn into_into_dart(self) -> crate::api::p2p::RunningPeerInfo {
+        self
     }
 }
-
-impl SseDecode for PeerRoutePair {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::RunningRouteInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.inst_id.into_into_dart().into_dart(),
+            self.hostname.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.ipv4.into_into_dart().into_dart(),
+            self.ipv6.into_into_dart().into_dart(),
+            self.next_hop_peer_id.into_into_dart().into_dart(),
+            self.cost.into_into_dart().into_dart(),
+            self.path_latency.into_into_dart().into_dart(),
+            self.proxy_cidrs.into_into_dart().into_dart(),
+            self.udp_nat_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for ServerStats {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::RunningRouteInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::RunningRouteInfo>
+    for crate::api::p2p::RunningRouteInfo
+{
+    fn into_into_dart(self) -> crate::api::p2p::RunningRouteInfo {
+        self
     }
 }
-
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::RunningStunInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.udp_nat_type.into_into_dart().into_dart(),
+            self.tcp_nat_type.into_into_dart().into_dart(),
+            self.public_ips.into_into_dart().into_dart(),
+            self.min_port.into_into_dart().into_dart(),
+            self.max_port.into_into_dart().into_dart(),
+            self.last_update_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::RunningStunInfo
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::RunningStunInfo>
+    for crate::api::p2p::RunningStunInfo
+{
+    fn into_into_dart(self) -> crate::api::p2p::RunningStunInfo {
+        self
     }
 }
-
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>>
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::server_probe::ServerProbeResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.reachable.into_into_dart().into_dart(),
+            self.resolved_addrs.into_into_dart().into_dart(),
+            self.dns_ms.into_into_dart().into_dart(),
+            self.connect_ms.into_into_dart().into_dart(),
+            self.rtt_ms.into_into_dart().into_dart(),
+            self.remote_peer_id.into_into_dart().into_dart(),
+            self.error_kind.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::server_probe::ServerProbeResult
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::server_probe::ServerProbeResult>
+    for crate::api::server_probe::ServerProbeResult
+{
+    fn into_into_dart(self) -> crate::api::server_probe::ServerProbeResult {
+        self
     }
 }
-
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>>
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::stun_server::StunServerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.primary_addr.into_into_dart().into_dart(),
+            self.alternate_addr.into_into_dart().into_dart(),
+            self.running.into_into_dart().into_dart(),
+            self.requests.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::stun_server::StunServerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::stun_server::StunServerInfo>
+    for crate::api::stun_server::StunServerInfo
+{
+    fn into_into_dart(self) -> crate::api::stun_server::StunServerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat_test::StunServerResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.server.into_into_dart().into_dart(),
+            self.ip_version.into_into_dart().into_dart(),
+            self.success.into_into_dart().into_dart(),
+            self.latency.into_into_dart().into_dart(),
+            self.mapped_addr.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat_test::StunServerResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::StunServerResult>
+    for crate::api::nat_test::StunServerResult
+{
+    fn into_into_dart(self) -> crate::api::nat_test::StunServerResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::supervisor::SupervisorState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Running => 0.into_dart(),
+            Self::Restarting => 1.into_dart(),
+            Self::GaveUp => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::supervisor::SupervisorState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::supervisor::SupervisorState>
+    for crate::api::supervisor::SupervisorState
+{
+    fn into_into_dart(self) -> crate::api::supervisor::SupervisorState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::supervisor::SupervisorStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.instance_id.into_into_dart().into_dart(),
+            self.policy.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.restart_count.into_into_dart().into_dart(),
+            self.consecutive_failures.into_into_dart().into_dart(),
+            self.last_failure.into_into_dart().into_dart(),
+            self.last_failure_at.into_into_dart().into_dart(),
+            self.next_restart_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::supervisor::SupervisorStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::supervisor::SupervisorStatus>
+    for crate::api::supervisor::SupervisorStatus
+{
+    fn into_into_dart(self) -> crate::api::supervisor::SupervisorStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::instance_patch::ValuePatch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.action.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::instance_patch::ValuePatch
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::instance_patch::ValuePatch>
+    for crate::api::instance_patch::ValuePatch
 {
+    fn into_into_dart(self) -> crate::api::instance_patch::ValuePatch {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<(), String>>>,
-    >
-{
+impl SseEncode for ForwardServer {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>,
-    >
-{
+impl SseEncode for JoinHandle<Result<(), String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<(), String>>>,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>>
-{
+impl SseEncode for JoinHandle<Result<String, String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                JoinHandle<Result<String, String>>,
+            >,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>
-{
+impl SseEncode for MinecraftLanBridge {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>>
-{
+impl SseEncode for MulticastListener {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for String {
+impl SseEncode for MulticastSender {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for PeerRoutePair {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for ServerStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for f32 {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for f64 {
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<(), String>>>,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for crate::api::simple::FlagsC {
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_defaultProtocol = <String>::sse_decode(deserializer);
-        let mut var_devName = <String>::sse_decode(deserializer);
-        let mut var_enableEncryption = <bool>::sse_decode(deserializer);
-        let mut var_enableIpv6 = <bool>::sse_decode(deserializer);
-        let mut var_mtu = <u32>::sse_decode(deserializer);
-        let mut var_latencyFirst = <bool>::sse_decode(deserializer);
-        let mut var_enableExitNode = <bool>::sse_decode(deserializer);
-        let mut var_noTun = <bool>::sse_decode(deserializer);
-        let mut var_useSmoltcp = <bool>::sse_decode(deserializer);
-        let mut var_relayNetworkWhitelist = <String>::sse_decode(deserializer);
-        let mut var_disableP2P = <bool>::sse_decode(deserializer);
-        let mut var_enableUdpBroadcastRelay = <bool>::sse_decode(deserializer);
-        let mut var_relayAllPeerRpc = <bool>::sse_decode(deserializer);
-        let mut var_disableUdpHolePunching = <bool>::sse_decode(deserializer);
-        let mut var_disableTcpHolePunching = <bool>::sse_decode(deserializer);
-        let mut var_multiThread = <bool>::sse_decode(deserializer);
-        let mut var_dataCompressAlgo = <i32>::sse_decode(deserializer);
-        let mut var_bindDevice = <bool>::sse_decode(deserializer);
-        let mut var_enableKcpProxy = <bool>::sse_decode(deserializer);
-        let mut var_disableKcpInput = <bool>::sse_decode(deserializer);
-        let mut var_disableRelayKcp = <bool>::sse_decode(deserializer);
-        let mut var_proxyForwardBySystem = <bool>::sse_decode(deserializer);
-        let mut var_acceptDns = <bool>::sse_decode(deserializer);
-        let mut var_privateMode = <bool>::sse_decode(deserializer);
-        let mut var_enableQuicProxy = <bool>::sse_decode(deserializer);
-        let mut var_disableQuicInput = <bool>::sse_decode(deserializer);
-        let mut var_disableSymHolePunching = <bool>::sse_decode(deserializer);
-        let mut var_tcpWhitelist = <String>::sse_decode(deserializer);
-        let mut var_udpWhitelist = <String>::sse_decode(deserializer);
-        let mut var_socks5Port = <u16>::sse_decode(deserializer);
-        return crate::api::simple::FlagsC {
-            default_protocol: var_defaultProtocol,
-            dev_name: var_devName,
-            enable_encryption: var_enableEncryption,
-            enable_ipv6: var_enableIpv6,
-            mtu: var_mtu,
-            latency_first: var_latencyFirst,
-            enable_exit_node: var_enableExitNode,
-            no_tun: var_noTun,
-            use_smoltcp: var_useSmoltcp,
-            relay_network_whitelist: var_relayNetworkWhitelist,
-            disable_p2p: var_disableP2P,
-            enable_udp_broadcast_relay: var_enableUdpBroadcastRelay,
-            relay_all_peer_rpc: var_relayAllPeerRpc,
-            disable_udp_hole_punching: var_disableUdpHolePunching,
-            disable_tcp_hole_punching: var_disableTcpHolePunching,
-            multi_thread: var_multiThread,
-            data_compress_algo: var_dataCompressAlgo,
-            bind_device: var_bindDevice,
-            enable_kcp_proxy: var_enableKcpProxy,
-            disable_kcp_input: var_disableKcpInput,
-            disable_relay_kcp: var_disableRelayKcp,
-            proxy_forward_by_system: var_proxyForwardBySystem,
-            accept_dns: var_acceptDns,
-            private_mode: var_privateMode,
-            enable_quic_proxy: var_enableQuicProxy,
-            disable_quic_input: var_disableQuicInput,
-            disable_sym_hole_punching: var_disableSymHolePunching,
-            tcp_whitelist: var_tcpWhitelist,
-            udp_whitelist: var_udpWhitelist,
-            socks5_port: var_socks5Port,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for crate::api::simple::Forward {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_bindAddr = <String>::sse_decode(deserializer);
-        let mut var_dstAddr = <String>::sse_decode(deserializer);
-        let mut var_proto = <String>::sse_decode(deserializer);
-        return crate::api::simple::Forward {
-            bind_addr: var_bindAddr,
-            dst_addr: var_dstAddr,
-            proto: var_proto,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for crate::api::simple::KVNetworkStatus {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_totalNodes = <usize>::sse_decode(deserializer);
-        let mut var_nodes = <Vec<crate::api::simple::KVNodeInfo>>::sse_decode(deserializer);
-        return crate::api::simple::KVNetworkStatus {
-            total_nodes: var_totalNodes,
-            nodes: var_nodes,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for crate::api::simple::KVNodeConnectionStats {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_connType = <String>::sse_decode(deserializer);
-        let mut var_rxBytes = <u64>::sse_decode(deserializer);
-        let mut var_txBytes = <u64>::sse_decode(deserializer);
-        let mut var_rxPackets = <u64>::sse_decode(deserializer);
-        let mut var_txPackets = <u64>::sse_decode(deserializer);
-        return crate::api::simple::KVNodeConnectionStats {
-            conn_type: var_connType,
-            rx_bytes: var_rxBytes,
-            tx_bytes: var_txBytes,
-            rx_packets: var_rxPackets,
-            tx_packets: var_txPackets,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for crate::api::simple::KVNodeInfo {
+impl SseEncode
+    for StreamSink<
+        crate::api::p2p::InstanceEventRecord,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_peerId = <u32>::sse_decode(deserializer);
-        let mut var_hostname = <String>::sse_decode(deserializer);
-        let mut var_ipv4 = <String>::sse_decode(deserializer);
-        let mut var_latencyMs = <f64>::sse_decode(deserializer);
-        let mut var_nat = <String>::sse_decode(deserializer);
-        let mut var_hops = <Vec<crate::api::simple::NodeHopStats>>::sse_decode(deserializer);
-        let mut var_lossRate = <f32>::sse_decode(deserializer);
-        let mut var_connections =
-            <Vec<crate::api::simple::KVNodeConnectionStats>>::sse_decode(deserializer);
-        let mut var_tunnelProto = <String>::sse_decode(deserializer);
-        let mut var_connType = <String>::sse_decode(deserializer);
-        let mut var_rxBytes = <u64>::sse_decode(deserializer);
-        let mut var_txBytes = <u64>::sse_decode(deserializer);
-        let mut var_version = <String>::sse_decode(deserializer);
-        let mut var_cost = <i32>::sse_decode(deserializer);
-        let mut var_proxyCidrs = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::simple::KVNodeInfo {
-            peer_id: var_peerId,
-            hostname: var_hostname,
-            ipv4: var_ipv4,
-            latency_ms: var_latencyMs,
-            nat: var_nat,
-            hops: var_hops,
-            loss_rate: var_lossRate,
-            connections: var_connections,
-            tunnel_proto: var_tunnelProto,
-            conn_type: var_connType,
-            rx_bytes: var_rxBytes,
-            tx_bytes: var_txBytes,
-            version: var_version,
-            cost: var_cost,
-            proxy_cidrs: var_proxyCidrs,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for Vec<PeerRoutePair> {
+impl SseEncode
+    for StreamSink<
+        crate::api::multicast::MulticastPacket,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<PeerRoutePair>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::simple::Forward> {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::Forward>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseDecode for Vec<crate::api::simple::KVNodeConnectionStats> {
+impl SseEncode for crate::api::network_config::ConfigBuildOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::KVNodeConnectionStats>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.ipv4_prefix_len, serializer);
+        <crate::api::network_config::InvalidCidrPolicy>::sse_encode(
+            self.invalid_cidr_policy,
+            serializer,
+        );
+        <bool>::sse_encode(self.custom_dev_name, serializer);
+    }
+}
+
+impl SseEncode for crate::api::p2p::ConfigChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.field, serializer);
+        <Option<crate::api::instance_patch::PatchAction>>::sse_encode(self.action, serializer);
+        <String>::sse_encode(self.value, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::simple::KVNodeInfo> {
+impl SseEncode for crate::api::network_config::ConfigIssue {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::KVNodeInfo>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.field, serializer);
+        <String>::sse_encode(self.value, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::magic_wall::MagicWallRule> {
+impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::magic_wall::MagicWallRule>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for Vec<crate::api::simple::NodeHopStats> {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::NodeHopStats>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for crate::api::nat_test::FilteringBehavior {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::nat_test::FilteringBehavior::EndpointIndependent => 0,
+                crate::api::nat_test::FilteringBehavior::AddressDependent => 1,
+                crate::api::nat_test::FilteringBehavior::AddressAndPortDependent => 2,
+                crate::api::nat_test::FilteringBehavior::Unknown => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<(String, u32)> {
+impl SseEncode for crate::api::simple::FlagsC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<(String, u32)>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.default_protocol, serializer);
+        <String>::sse_encode(self.dev_name, serializer);
+        <bool>::sse_encode(self.enable_encryption, serializer);
+        <bool>::sse_encode(self.enable_ipv6, serializer);
+        <u32>::sse_encode(self.mtu, serializer);
+        <bool>::sse_encode(self.latency_first, serializer);
+        <bool>::sse_encode(self.enable_exit_node, serializer);
+        <bool>::sse_encode(self.no_tun, serializer);
+        <bool>::sse_encode(self.use_smoltcp, serializer);
+        <String>::sse_encode(self.relay_network_whitelist, serializer);
+        <bool>::sse_encode(self.disable_p2p, serializer);
+        <bool>::sse_encode(self.enable_udp_broadcast_relay, serializer);
+        <bool>::sse_encode(self.relay_all_peer_rpc, serializer);
+        <bool>::sse_encode(self.disable_udp_hole_punching, serializer);
+        <bool>::sse_encode(self.disable_tcp_hole_punching, serializer);
+        <bool>::sse_encode(self.multi_thread, serializer);
+        <i32>::sse_encode(self.data_compress_algo, serializer);
+        <bool>::sse_encode(self.bind_device, serializer);
+        <bool>::sse_encode(self.enable_kcp_proxy, serializer);
+        <bool>::sse_encode(self.disable_kcp_input, serializer);
+        <bool>::sse_encode(self.disable_relay_kcp, serializer);
+        <bool>::sse_encode(self.proxy_forward_by_system, serializer);
+        <bool>::sse_encode(self.accept_dns, serializer);
+        <bool>::sse_encode(self.private_mode, serializer);
+        <bool>::sse_encode(self.enable_quic_proxy, serializer);
+        <bool>::sse_encode(self.disable_quic_input, serializer);
+        <bool>::sse_encode(self.disable_sym_hole_punching, serializer);
+        <String>::sse_encode(self.tcp_whitelist, serializer);
+        <String>::sse_encode(self.udp_whitelist, serializer);
+        <u16>::sse_encode(self.socks5_port, serializer);
     }
 }
 
-impl SseDecode for crate::api::magic_wall::MagicWallRule {
+impl SseEncode for crate::api::simple::Forward {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_enabled = <bool>::sse_decode(deserializer);
-        let mut var_action = <String>::sse_decode(deserializer);
-        let mut var_protocol = <String>::sse_decode(deserializer);
-        let mut var_direction = <String>::sse_decode(deserializer);
-        let mut var_appPath = <Option<String>>::sse_decode(deserializer);
-        let mut var_remoteIp = <Option<String>>::sse_decode(deserializer);
-        let mut var_localIp = <Option<String>>::sse_decode(deserializer);
-        let mut var_remotePort = <Option<String>>::sse_decode(deserializer);
-        let mut var_localPort = <Option<String>>::sse_decode(deserializer);
-        let mut var_description = <Option<String>>::sse_decode(deserializer);
-        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
-        return crate::api::magic_wall::MagicWallRule {
-            id: var_id,
-            name: var_name,
-            enabled: var_enabled,
-            action: var_action,
-            protocol: var_protocol,
-            direction: var_direction,
-            app_path: var_appPath,
-            remote_ip: var_remoteIp,
-            local_ip: var_localIp,
-            remote_port: var_remotePort,
-            local_port: var_localPort,
-            description: var_description,
-            created_at: var_createdAt,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.bind_addr, serializer);
+        <String>::sse_encode(self.dst_addr, serializer);
+        <String>::sse_encode(self.proto, serializer);
     }
 }
 
-impl SseDecode for crate::api::magic_wall::MagicWallStatus {
+impl SseEncode for crate::api::forward::ForwardConnectionInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_isRunning = <bool>::sse_decode(deserializer);
-        let mut var_activeRules = <usize>::sse_decode(deserializer);
-        let mut var_totalRules = <usize>::sse_decode(deserializer);
-        return crate::api::magic_wall::MagicWallStatus {
-            is_running: var_isRunning,
-            active_rules: var_activeRules,
-            total_rules: var_totalRules,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.peer_addr, serializer);
+        <i64>::sse_encode(self.started_at, serializer);
+        <Option<i64>>::sse_encode(self.closed_at, serializer);
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+        <Option<String>>::sse_encode(self.close_reason, serializer);
     }
 }
 
-impl SseDecode for crate::api::nat_test::NatType {
+impl SseEncode for crate::api::instance_patch::ForwardPatch {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::nat_test::NatType::OpenInternet,
-            1 => crate::api::nat_test::NatType::FullCone,
-            2 => crate::api::nat_test::NatType::RestrictedCone,
-            3 => crate::api::nat_test::NatType::PortRestrictedCone,
-            4 => crate::api::nat_test::NatType::Symmetric,
-            5 => crate::api::nat_test::NatType::SymmetricUdpFirewall,
-            6 => crate::api::nat_test::NatType::Blocked,
-            7 => crate::api::nat_test::NatType::Unknown,
-            _ => unreachable!("Invalid variant for NatType: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::instance_patch::PatchAction>::sse_encode(self.action, serializer);
+        <crate::api::simple::Forward>::sse_encode(self.forward, serializer);
     }
 }
 
-impl SseDecode for crate::api::nat_test::NetworkTestResult {
+impl SseEncode for crate::api::forward::ForwardServerInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_natTypeV4 = <String>::sse_decode(deserializer);
-        let mut var_natTypeV6 = <String>::sse_decode(deserializer);
-        let mut var_ipv4Latency = <i64>::sse_decode(deserializer);
-        let mut var_ipv6Latency = <i64>::sse_decode(deserializer);
-        return crate::api::nat_test::NetworkTestResult {
-            nat_type_v4: var_natTypeV4,
-            nat_type_v6: var_natTypeV6,
-            ipv4_latency: var_ipv4Latency,
-            ipv6_latency: var_ipv6Latency,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.listen_addr, serializer);
+        <String>::sse_encode(self.forward_addr, serializer);
+        <String>::sse_encode(self.protocol, serializer);
+        <bool>::sse_encode(self.is_running, serializer);
+        <usize>::sse_encode(self.connections, serializer);
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
     }
 }
 
-impl SseDecode for crate::api::simple::NodeHopStats {
+impl SseEncode for crate::api::nat_test::HolePunchChance {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_peerId = <u32>::sse_decode(deserializer);
-        let mut var_targetIp = <String>::sse_decode(deserializer);
-        let mut var_latencyMs = <f64>::sse_decode(deserializer);
-        let mut var_packetLoss = <f32>::sse_decode(deserializer);
-        let mut var_nodeName = <String>::sse_decode(deserializer);
-        return crate::api::simple::NodeHopStats {
-            peer_id: var_peerId,
-            target_ip: var_targetIp,
-            latency_ms: var_latencyMs,
-            packet_loss: var_packetLoss,
-            node_name: var_nodeName,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::nat_test::HolePunchChance::Likely => 0,
+                crate::api::nat_test::HolePunchChance::Possible => 1,
+                crate::api::nat_test::HolePunchChance::Unlikely => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for crate::api::nat_test::HolePunchPrediction {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.peer_nat_type, serializer);
+        <crate::api::nat_test::HolePunchChance>::sse_encode(self.chance, serializer);
     }
 }
 
-impl SseDecode for Option<i64> {
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for (String, u32) {
+impl SseEncode for i64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <u32>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for (usize, u64, u64) {
+impl SseEncode for crate::api::p2p::InstanceEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <usize>::sse_decode(deserializer);
-        let mut var_field1 = <u64>::sse_decode(deserializer);
-        let mut var_field2 = <u64>::sse_decode(deserializer);
-        return (var_field0, var_field1, var_field2);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::p2p::InstanceEvent::PeerAdded { peer_id } => {
+                <i32>::sse_encode(0, serializer);
+                <u32>::sse_encode(peer_id, serializer);
+            }
+            crate::api::p2p::InstanceEvent::PeerRemoved { peer_id } => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(peer_id, serializer);
+            }
+            crate::api::p2p::InstanceEvent::PeerConnAdded { conn } => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::p2p::PeerConnEvent>::sse_encode(conn, serializer);
+            }
+            crate::api::p2p::InstanceEvent::PeerConnRemoved { conn } => {
+                <i32>::sse_encode(3, serializer);
+                <crate::api::p2p::PeerConnEvent>::sse_encode(conn, serializer);
+            }
+            crate::api::p2p::InstanceEvent::ListenerAdded { listener } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(listener, serializer);
+            }
+            crate::api::p2p::InstanceEvent::ListenerAddFailed { listener, message } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(listener, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::p2p::InstanceEvent::ListenerAcceptFailed { listener, message } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(listener, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::p2p::InstanceEvent::ConnectionAccepted { local, remote } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(local, serializer);
+                <String>::sse_encode(remote, serializer);
+            }
+            crate::api::p2p::InstanceEvent::ConnectionError {
+                local,
+                remote,
+                error,
+            } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(local, serializer);
+                <String>::sse_encode(remote, serializer);
+                <String>::sse_encode(error, serializer);
+            }
+            crate::api::p2p::InstanceEvent::TunDeviceReady { dev } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(dev, serializer);
+            }
+            crate::api::p2p::InstanceEvent::TunDeviceError { error } => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(error, serializer);
+            }
+            crate::api::p2p::InstanceEvent::Connecting { dst } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(dst, serializer);
+            }
+            crate::api::p2p::InstanceEvent::ConnectError {
+                dst,
+                ip_version,
+                error,
+            } => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(dst, serializer);
+                <String>::sse_encode(ip_version, serializer);
+                <String>::sse_encode(error, serializer);
+            }
+            crate::api::p2p::InstanceEvent::VpnPortalStarted { portal } => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(portal, serializer);
+            }
+            crate::api::p2p::InstanceEvent::VpnPortalClientConnected {
+                portal,
+                client_addr,
+            } => {
+                <i32>::sse_encode(14, serializer);
+                <String>::sse_encode(portal, serializer);
+                <String>::sse_encode(client_addr, serializer);
+            }
+            crate::api::p2p::InstanceEvent::VpnPortalClientDisconnected {
+                portal,
+                client_addr,
+            } => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(portal, serializer);
+                <String>::sse_encode(client_addr, serializer);
+            }
+            crate::api::p2p::InstanceEvent::DhcpIpv4Changed { old_ip, new_ip } => {
+                <i32>::sse_encode(16, serializer);
+                <Option<String>>::sse_encode(old_ip, serializer);
+                <Option<String>>::sse_encode(new_ip, serializer);
+            }
+            crate::api::p2p::InstanceEvent::DhcpIpv4Conflicted { ip } => {
+                <i32>::sse_encode(17, serializer);
+                <Option<String>>::sse_encode(ip, serializer);
+            }
+            crate::api::p2p::InstanceEvent::PortForwardAdded {
+                bind_addr,
+                dst_addr,
+                proto,
+            } => {
+                <i32>::sse_encode(18, serializer);
+                <String>::sse_encode(bind_addr, serializer);
+                <String>::sse_encode(dst_addr, serializer);
+                <String>::sse_encode(proto, serializer);
+            }
+            crate::api::p2p::InstanceEvent::ConfigPatched { changes } => {
+                <i32>::sse_encode(19, serializer);
+                <Vec<crate::api::p2p::ConfigChange>>::sse_encode(changes, serializer);
+            }
+            crate::api::p2p::InstanceEvent::ProxyCidrsUpdated { added, removed } => {
+                <i32>::sse_encode(20, serializer);
+                <Vec<String>>::sse_encode(added, serializer);
+                <Vec<String>>::sse_encode(removed, serializer);
+            }
+            crate::api::p2p::InstanceEvent::InstanceRestarting {
+                attempt,
+                delay_ms,
+                reason,
+            } => {
+                <i32>::sse_encode(21, serializer);
+                <u32>::sse_encode(attempt, serializer);
+                <u64>::sse_encode(delay_ms, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            crate::api::p2p::InstanceEvent::InstanceRestarted { attempt } => {
+                <i32>::sse_encode(22, serializer);
+                <u32>::sse_encode(attempt, serializer);
+            }
+            crate::api::p2p::InstanceEvent::InstanceRestartGaveUp { attempts, reason } => {
+                <i32>::sse_encode(23, serializer);
+                <u32>::sse_encode(attempts, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            crate::api::p2p::InstanceEvent::EventsLagged { dropped } => {
+                <i32>::sse_encode(24, serializer);
+                <u64>::sse_encode(dropped, serializer);
+            }
+            crate::api::p2p::InstanceEvent::EventsClosed => {
+                <i32>::sse_encode(25, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseDecode for u16 {
+impl SseEncode for crate::api::p2p::InstanceEventRecord {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.instance_id, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+        <crate::api::p2p::InstanceEvent>::sse_encode(self.event, serializer);
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for crate::api::instance_patch::InstancePatch {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::instance_patch::ValuePatch>>::sse_encode(self.peers, serializer);
+        <Vec<crate::api::instance_patch::ForwardPatch>>::sse_encode(self.port_forwards, serializer);
+        <Vec<crate::api::instance_patch::ValuePatch>>::sse_encode(self.proxy_cidrs, serializer);
+        <Vec<crate::api::instance_patch::ValuePatch>>::sse_encode(self.tcp_whitelist, serializer);
+        <Vec<crate::api::instance_patch::ValuePatch>>::sse_encode(self.udp_whitelist, serializer);
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for crate::api::network_config::InvalidCidrPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::network_config::InvalidCidrPolicy::Reject => 0,
+                crate::api::network_config::InvalidCidrPolicy::Skip => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::invite::InviteFlags {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.default_protocol, serializer);
+        <Option<bool>>::sse_encode(self.enable_encryption, serializer);
+        <Option<bool>>::sse_encode(self.enable_ipv6, serializer);
+        <Option<u32>>::sse_encode(self.mtu, serializer);
+        <Option<bool>>::sse_encode(self.latency_first, serializer);
+        <Option<bool>>::sse_encode(self.disable_p2p, serializer);
+        <Option<bool>>::sse_encode(self.enable_udp_broadcast_relay, serializer);
+        <Option<i32>>::sse_encode(self.data_compress_algo, serializer);
+        <Option<bool>>::sse_encode(self.enable_kcp_proxy, serializer);
+        <Option<bool>>::sse_encode(self.enable_quic_proxy, serializer);
     }
 }
 
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
-}
-
-impl SseDecode for usize {
+impl SseEncode for crate::api::simple::KVNetworkStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
-    }
-}
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        3 => wire__crate__api__forward__ForwardServer_is_running_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__forward__ForwardServer_start_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__forward__ForwardServer_stop_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__multicast__MulticastSender_is_running_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        7 => wire__crate__api__multicast__MulticastSender_start_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => wire__crate__api__multicast__MulticastSender_stop_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__forward__ServerStats_get_bytes_received_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__api__forward__ServerStats_get_bytes_sent_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => wire__crate__api__forward__ServerStats_get_connections_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        12 => wire__crate__api__forward__ServerStats_new_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__magic_wall__add_magic_wall_rule_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        14 => wire__crate__api__utils__check_sudo_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__p2p__close_server_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__simple__close_server_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__magic_wall__create_default_magic_wall_rules_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        18 => {
-            wire__crate__api__forward__create_forward_server_impl(port, ptr, rust_vec_len, data_len)
-        }
-        19 => wire__crate__api__multicast__create_multicast_sender_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        20 => wire__crate__api__multicast__create_multicast_sender_with_bind_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        21 => wire__crate__api__p2p__create_server_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__simple__create_server_impl(port, ptr, rust_vec_len, data_len),
-        23 => {
-            wire__crate__api__p2p__create_server_with_flags_impl(port, ptr, rust_vec_len, data_len)
-        }
-        24 => wire__crate__api__nat_test__detect_nat_type_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__p2p__easytier_version_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__simple__easytier_version_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__hops__get_all_interfaces_metrics_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        28 => {
-            wire__crate__api__firewall__get_firewall_status_impl(port, ptr, rust_vec_len, data_len)
-        }
-        29 => wire__crate__api__forward__get_forward_server_count_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        30 => wire__crate__api__forward__get_forward_server_stats_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        31 => wire__crate__api__p2p__get_ips_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__simple__get_ips_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__magic_wall__get_magic_wall_status_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        34 => wire__crate__api__multicast__get_multicast_sender_count_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        35 => wire__crate__api__p2p__get_network_status_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__simple__get_network_status_impl(port, ptr, rust_vec_len, data_len),
-        37 => wire__crate__api__nt__get_nt_path_impl(port, ptr, rust_vec_len, data_len),
-        38 => wire__crate__api__p2p__get_peer_route_pairs_impl(port, ptr, rust_vec_len, data_len),
-        39 => {
-            wire__crate__api__simple__get_peer_route_pairs_impl(port, ptr, rust_vec_len, data_len)
-        }
-        40 => wire__crate__api__p2p__get_running_info_impl(port, ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__simple__get_running_info_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__p2p__handle_event_impl(port, ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__simple__handle_event_impl(port, ptr, rust_vec_len, data_len),
-        44 => wire__crate__api__p2p__init_app_impl(port, ptr, rust_vec_len, data_len),
-        45 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__p2p__is_easytier_running_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__simple__is_easytier_running_impl(port, ptr, rust_vec_len, data_len),
-        48 => wire__crate__api__forward__is_forward_server_running_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        49 => wire__crate__api__multicast__is_multicast_sender_running_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        50 => wire__crate__api__nat_test__nat_type_get_description_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        51 => wire__crate__api__magic_wall__remove_magic_wall_rule_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        52 => wire__crate__api__p2p__send_udp_to_localhost_impl(port, ptr, rust_vec_len, data_len),
-        53 => {
-            wire__crate__api__simple__send_udp_to_localhost_impl(port, ptr, rust_vec_len, data_len)
-        }
-        54 => {
-            wire__crate__api__firewall__set_firewall_status_impl(port, ptr, rust_vec_len, data_len)
-        }
-        55 => wire__crate__api__hops__set_interface_metric_impl(port, ptr, rust_vec_len, data_len),
-        56 => wire__crate__api__p2p__set_tun_fd_impl(port, ptr, rust_vec_len, data_len),
-        57 => wire__crate__api__simple__set_tun_fd_impl(port, ptr, rust_vec_len, data_len),
-        58 => {
-            wire__crate__api__magic_wall__start_magic_wall_impl(port, ptr, rust_vec_len, data_len)
-        }
-        59 => wire__crate__api__forward__stop_all_forward_servers_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        60 => wire__crate__api__multicast__stop_all_multicast_senders_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        61 => {
-            wire__crate__api__forward__stop_forward_server_impl(port, ptr, rust_vec_len, data_len)
-        }
-        62 => wire__crate__api__magic_wall__stop_magic_wall_impl(port, ptr, rust_vec_len, data_len),
-        63 => wire__crate__api__multicast__stop_multicast_sender_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        64 => wire__crate__api__nat_test__test_network_connectivity_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        65 => wire__crate__api__magic_wall__update_magic_wall_rule_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
-    }
-}
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__forward__ForwardServer_auto_accessor_get_stats_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__forward__ForwardServer_auto_accessor_set_stats_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
-    }
-}
-
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<EventBusSubscriber> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.total_nodes, serializer);
+        <Vec<crate::api::simple::KVNodeInfo>>::sse_encode(self.nodes, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<EventBusSubscriber>
-{
-}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<EventBusSubscriber>> for EventBusSubscriber {
-    fn into_into_dart(self) -> FrbWrapper<EventBusSubscriber> {
-        self.into()
+impl SseEncode for crate::api::simple::KVNodeConnectionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.conn_type, serializer);
+        <u64>::sse_encode(self.rx_bytes, serializer);
+        <u64>::sse_encode(self.tx_bytes, serializer);
+        <u64>::sse_encode(self.rx_packets, serializer);
+        <u64>::sse_encode(self.tx_packets, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ForwardServer> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::simple::KVNodeInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.peer_id, serializer);
+        <String>::sse_encode(self.hostname, serializer);
+        <String>::sse_encode(self.ipv4, serializer);
+        <Option<String>>::sse_encode(self.ipv6, serializer);
+        <Option<String>>::sse_encode(self.public_ipv6, serializer);
+        <Option<String>>::sse_encode(self.ipv6_prefix, serializer);
+        <f64>::sse_encode(self.latency_ms, serializer);
+        <String>::sse_encode(self.nat, serializer);
+        <Vec<crate::api::simple::NodeHopStats>>::sse_encode(self.hops, serializer);
+        <f32>::sse_encode(self.loss_rate, serializer);
+        <Vec<crate::api::simple::KVNodeConnectionStats>>::sse_encode(self.connections, serializer);
+        <String>::sse_encode(self.tunnel_proto, serializer);
+        <String>::sse_encode(self.conn_type, serializer);
+        <u64>::sse_encode(self.rx_bytes, serializer);
+        <u64>::sse_encode(self.tx_bytes, serializer);
+        <String>::sse_encode(self.version, serializer);
+        <i32>::sse_encode(self.cost, serializer);
+        <Vec<String>>::sse_encode(self.proxy_cidrs, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ForwardServer> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ForwardServer>> for ForwardServer {
-    fn into_into_dart(self) -> FrbWrapper<ForwardServer> {
-        self.into()
+impl SseEncode for Vec<PeerRoutePair> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <PeerRoutePair>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<JoinHandle<()>> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<JoinHandle<()>> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<JoinHandle<()>>> for JoinHandle<()> {
-    fn into_into_dart(self) -> FrbWrapper<JoinHandle<()>> {
-        self.into()
+impl SseEncode for Vec<crate::api::p2p::ConfigChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::p2p::ConfigChange>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<JoinHandle<Result<(), String>>> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::network_config::ConfigIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::network_config::ConfigIssue>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<JoinHandle<Result<(), String>>>
-{
-}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<JoinHandle<Result<(), String>>>>
-    for JoinHandle<Result<(), String>>
-{
-    fn into_into_dart(self) -> FrbWrapper<JoinHandle<Result<(), String>>> {
-        self.into()
+impl SseEncode for Vec<crate::api::simple::Forward> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::Forward>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<JoinHandle<Result<String, String>>> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::forward::ForwardConnectionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::forward::ForwardConnectionInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<JoinHandle<Result<String, String>>>
-{
-}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<JoinHandle<Result<String, String>>>>
-    for JoinHandle<Result<String, String>>
-{
-    fn into_into_dart(self) -> FrbWrapper<JoinHandle<Result<String, String>>> {
-        self.into()
+impl SseEncode for Vec<crate::api::instance_patch::ForwardPatch> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::instance_patch::ForwardPatch>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<MulticastSender> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::forward::ForwardServerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::forward::ForwardServerInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<MulticastSender> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MulticastSender>> for MulticastSender {
-    fn into_into_dart(self) -> FrbWrapper<MulticastSender> {
-        self.into()
+impl SseEncode for Vec<crate::api::nat_test::HolePunchPrediction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::nat_test::HolePunchPrediction>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<PeerRoutePair> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::p2p::InstanceEventRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::p2p::InstanceEventRecord>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PeerRoutePair> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PeerRoutePair>> for PeerRoutePair {
-    fn into_into_dart(self) -> FrbWrapper<PeerRoutePair> {
-        self.into()
+impl SseEncode for Vec<crate::api::simple::KVNodeConnectionStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::KVNodeConnectionStats>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ServerStats> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::simple::KVNodeInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::KVNodeInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ServerStats> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ServerStats>> for ServerStats {
-    fn into_into_dart(self) -> FrbWrapper<ServerStats> {
-        self.into()
+impl SseEncode for Vec<crate::api::magic_wall::MagicWallRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::magic_wall::MagicWallRule>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::FlagsC {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.default_protocol.into_into_dart().into_dart(),
-            self.dev_name.into_into_dart().into_dart(),
-            self.enable_encryption.into_into_dart().into_dart(),
-            self.enable_ipv6.into_into_dart().into_dart(),
-            self.mtu.into_into_dart().into_dart(),
-            self.latency_first.into_into_dart().into_dart(),
-            self.enable_exit_node.into_into_dart().into_dart(),
-            self.no_tun.into_into_dart().into_dart(),
-            self.use_smoltcp.into_into_dart().into_dart(),
-            self.relay_network_whitelist.into_into_dart().into_dart(),
-            self.disable_p2p.into_into_dart().into_dart(),
-            self.enable_udp_broadcast_relay.into_into_dart().into_dart(),
-            self.relay_all_peer_rpc.into_into_dart().into_dart(),
-            self.disable_udp_hole_punching.into_into_dart().into_dart(),
-            self.disable_tcp_hole_punching.into_into_dart().into_dart(),
-            self.multi_thread.into_into_dart().into_dart(),
-            self.data_compress_algo.into_into_dart().into_dart(),
-            self.bind_device.into_into_dart().into_dart(),
-            self.enable_kcp_proxy.into_into_dart().into_dart(),
-            self.disable_kcp_input.into_into_dart().into_dart(),
-            self.disable_relay_kcp.into_into_dart().into_dart(),
-            self.proxy_forward_by_system.into_into_dart().into_dart(),
-            self.accept_dns.into_into_dart().into_dart(),
-            self.private_mode.into_into_dart().into_dart(),
-            self.enable_quic_proxy.into_into_dart().into_dart(),
-            self.disable_quic_input.into_into_dart().into_dart(),
-            self.disable_sym_hole_punching.into_into_dart().into_dart(),
-            self.tcp_whitelist.into_into_dart().into_dart(),
-            self.udp_whitelist.into_into_dart().into_dart(),
-            self.socks5_port.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::FlagsC {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FlagsC> for crate::api::simple::FlagsC {
-    fn into_into_dart(self) -> crate::api::simple::FlagsC {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::Forward {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.bind_addr.into_into_dart().into_dart(),
-            self.dst_addr.into_into_dart().into_dart(),
-            self.proto.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::Forward {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::Forward>
-    for crate::api::simple::Forward
-{
-    fn into_into_dart(self) -> crate::api::simple::Forward {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::KVNetworkStatus {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.total_nodes.into_into_dart().into_dart(),
-            self.nodes.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Vec<crate::api::magic_wall::MagicWallRuleIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::magic_wall::MagicWallRuleIssue>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::KVNetworkStatus
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::KVNetworkStatus>
-    for crate::api::simple::KVNetworkStatus
-{
-    fn into_into_dart(self) -> crate::api::simple::KVNetworkStatus {
-        self
+
+impl SseEncode for Vec<crate::api::minecraft_lan::MinecraftLanWorld> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::minecraft_lan::MinecraftLanWorld>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::KVNodeConnectionStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.conn_type.into_into_dart().into_dart(),
-            self.rx_bytes.into_into_dart().into_dart(),
-            self.tx_bytes.into_into_dart().into_dart(),
-            self.rx_packets.into_into_dart().into_dart(),
-            self.tx_packets.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::multicast::MulticastListenerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::multicast::MulticastListenerInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::KVNodeConnectionStats
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::KVNodeConnectionStats>
-    for crate::api::simple::KVNodeConnectionStats
-{
-    fn into_into_dart(self) -> crate::api::simple::KVNodeConnectionStats {
-        self
+
+impl SseEncode for Vec<crate::api::multicast::MulticastPacket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::multicast::MulticastPacket>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::KVNodeInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.peer_id.into_into_dart().into_dart(),
-            self.hostname.into_into_dart().into_dart(),
-            self.ipv4.into_into_dart().into_dart(),
-            self.latency_ms.into_into_dart().into_dart(),
-            self.nat.into_into_dart().into_dart(),
-            self.hops.into_into_dart().into_dart(),
-            self.loss_rate.into_into_dart().into_dart(),
-            self.connections.into_into_dart().into_dart(),
-            self.tunnel_proto.into_into_dart().into_dart(),
-            self.conn_type.into_into_dart().into_dart(),
-            self.rx_bytes.into_into_dart().into_dart(),
-            self.tx_bytes.into_into_dart().into_dart(),
-            self.version.into_into_dart().into_dart(),
-            self.cost.into_into_dart().into_dart(),
-            self.proxy_cidrs.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::multicast::MulticastSenderInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::multicast::MulticastSenderInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::KVNodeInfo
-{
+
+impl SseEncode for Vec<crate::api::nat_test::NatBehaviorResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::nat_test::NatBehaviorResult>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::KVNodeInfo>
-    for crate::api::simple::KVNodeInfo
-{
-    fn into_into_dart(self) -> crate::api::simple::KVNodeInfo {
-        self
+
+impl SseEncode for Vec<crate::api::simple::NodeHopStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::NodeHopStats>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::magic_wall::MagicWallRule {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.enabled.into_into_dart().into_dart(),
-            self.action.into_into_dart().into_dart(),
-            self.protocol.into_into_dart().into_dart(),
-            self.direction.into_into_dart().into_dart(),
-            self.app_path.into_into_dart().into_dart(),
-            self.remote_ip.into_into_dart().into_dart(),
-            self.local_ip.into_into_dart().into_dart(),
-            self.remote_port.into_into_dart().into_dart(),
-            self.local_port.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::magic_wall::MagicWallRule
-{
+
+impl SseEncode for Vec<(String, u32)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, u32)>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::magic_wall::MagicWallRule>
-    for crate::api::magic_wall::MagicWallRule
-{
-    fn into_into_dart(self) -> crate::api::magic_wall::MagicWallRule {
-        self
+
+impl SseEncode for Vec<crate::api::p2p::RunningConnInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::p2p::RunningConnInfo>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::magic_wall::MagicWallStatus {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.is_running.into_into_dart().into_dart(),
-            self.active_rules.into_into_dart().into_dart(),
-            self.total_rules.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::p2p::RunningPeerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::p2p::RunningPeerInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::magic_wall::MagicWallStatus
-{
+
+impl SseEncode for Vec<crate::api::p2p::RunningRouteInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::p2p::RunningRouteInfo>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::magic_wall::MagicWallStatus>
-    for crate::api::magic_wall::MagicWallStatus
-{
-    fn into_into_dart(self) -> crate::api::magic_wall::MagicWallStatus {
-        self
+
+impl SseEncode for Vec<crate::api::server_probe::ServerProbeResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::server_probe::ServerProbeResult>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::nat_test::NatType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::OpenInternet => 0.into_dart(),
-            Self::FullCone => 1.into_dart(),
-            Self::RestrictedCone => 2.into_dart(),
-            Self::PortRestrictedCone => 3.into_dart(),
-            Self::Symmetric => 4.into_dart(),
-            Self::SymmetricUdpFirewall => 5.into_dart(),
-            Self::Blocked => 6.into_dart(),
-            Self::Unknown => 7.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Vec<crate::api::stun_server::StunServerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::stun_server::StunServerInfo>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nat_test::NatType {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::NatType>
-    for crate::api::nat_test::NatType
-{
-    fn into_into_dart(self) -> crate::api::nat_test::NatType {
-        self
+
+impl SseEncode for Vec<crate::api::nat_test::StunServerResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::nat_test::StunServerResult>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::nat_test::NetworkTestResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.nat_type_v4.into_into_dart().into_dart(),
-            self.nat_type_v6.into_into_dart().into_dart(),
-            self.ipv4_latency.into_into_dart().into_dart(),
-            self.ipv6_latency.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::supervisor::SupervisorStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::supervisor::SupervisorStatus>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::nat_test::NetworkTestResult
-{
+
+impl SseEncode for Vec<crate::api::instance_patch::ValuePatch> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::instance_patch::ValuePatch>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::nat_test::NetworkTestResult>
-    for crate::api::nat_test::NetworkTestResult
-{
-    fn into_into_dart(self) -> crate::api::nat_test::NetworkTestResult {
-        self
+
+impl SseEncode for crate::api::magic_wall::MagicWallPacket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.protocol, serializer);
+        <String>::sse_encode(self.direction, serializer);
+        <String>::sse_encode(self.local_ip, serializer);
+        <String>::sse_encode(self.remote_ip, serializer);
+        <Option<u16>>::sse_encode(self.local_port, serializer);
+        <Option<u16>>::sse_encode(self.remote_port, serializer);
+        <Option<String>>::sse_encode(self.app_path, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::NodeHopStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.peer_id.into_into_dart().into_dart(),
-            self.target_ip.into_into_dart().into_dart(),
-            self.latency_ms.into_into_dart().into_dart(),
-            self.packet_loss.into_into_dart().into_dart(),
-            self.node_name.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::magic_wall::MagicWallRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+        <String>::sse_encode(self.action, serializer);
+        <String>::sse_encode(self.protocol, serializer);
+        <String>::sse_encode(self.direction, serializer);
+        <Option<String>>::sse_encode(self.app_path, serializer);
+        <Option<String>>::sse_encode(self.remote_ip, serializer);
+        <Option<String>>::sse_encode(self.local_ip, serializer);
+        <Option<String>>::sse_encode(self.remote_port, serializer);
+        <Option<String>>::sse_encode(self.local_port, serializer);
+        <u32>::sse_encode(self.priority, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<i64>>::sse_encode(self.created_at, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::NodeHopStats
-{
+
+impl SseEncode for crate::api::magic_wall::MagicWallRuleIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::magic_wall::MagicWallRuleIssueKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.rule_id, serializer);
+        <String>::sse_encode(self.other_rule_id, serializer);
+        <String>::sse_encode(self.message, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NodeHopStats>
-    for crate::api::simple::NodeHopStats
-{
-    fn into_into_dart(self) -> crate::api::simple::NodeHopStats {
-        self
+
+impl SseEncode for crate::api::magic_wall::MagicWallRuleIssueKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::magic_wall::MagicWallRuleIssueKind::Shadowed => 0,
+                crate::api::magic_wall::MagicWallRuleIssueKind::Redundant => 1,
+                crate::api::magic_wall::MagicWallRuleIssueKind::Conflicting => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::magic_wall::MagicWallStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <bool>::sse_encode(self.is_running, serializer);
+        <usize>::sse_encode(self.active_rules, serializer);
+        <usize>::sse_encode(self.total_rules, serializer);
     }
 }
 
-impl SseEncode for EventBusSubscriber {
+impl SseEncode for crate::api::magic_wall::MagicWallVerdict {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.action, serializer);
+        <Option<String>>::sse_encode(self.matched_rule_id, serializer);
     }
 }
 
-impl SseEncode for ForwardServer {
+impl SseEncode for crate::api::nat_test::MappingBehavior {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::nat_test::MappingBehavior::NoNat => 0,
+                crate::api::nat_test::MappingBehavior::EndpointIndependent => 1,
+                crate::api::nat_test::MappingBehavior::AddressDependent => 2,
+                crate::api::nat_test::MappingBehavior::AddressAndPortDependent => 3,
+                crate::api::nat_test::MappingBehavior::Unknown => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for JoinHandle<()> {
+impl SseEncode for crate::api::minecraft_lan::MinecraftLanAnnouncement {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner< JoinHandle < () >>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.motd, serializer);
+        <u16>::sse_encode(self.port, serializer);
     }
 }
 
-impl SseEncode for JoinHandle<Result<(), String>> {
+impl SseEncode for crate::api::minecraft_lan::MinecraftLanWorld {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<(), String>>>,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        <String>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.remote_port, serializer);
+        <u16>::sse_encode(self.local_port, serializer);
+        <String>::sse_encode(self.motd, serializer);
+        <i64>::sse_encode(self.last_seen, serializer);
     }
 }
 
-impl SseEncode for JoinHandle<Result<String, String>> {
+impl SseEncode for crate::api::multicast::MulticastListenerInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                JoinHandle<Result<String, String>>,
-            >,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.group_addr, serializer);
+        <String>::sse_encode(self.interface_addr, serializer);
+        <usize>::sse_encode(self.capacity, serializer);
+        <u64>::sse_encode(self.packets_received, serializer);
+        <bool>::sse_encode(self.is_running, serializer);
     }
 }
 
-impl SseEncode for MulticastSender {
+impl SseEncode for crate::api::multicast::MulticastPacket {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.source, serializer);
+        <usize>::sse_encode(self.size, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+        <Vec<u8>>::sse_encode(self.data, serializer);
     }
 }
 
-impl SseEncode for PeerRoutePair {
+impl SseEncode for crate::api::multicast::MulticastSenderInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.multicast_addr, serializer);
+        <String>::sse_encode(self.bind_addr, serializer);
+        <u64>::sse_encode(self.interval_ms, serializer);
+        <usize>::sse_encode(self.data_len, serializer);
+        <bool>::sse_encode(self.is_running, serializer);
     }
 }
 
-impl SseEncode for ServerStats {
+impl SseEncode for crate::api::nat_test::NatBehaviorResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.protocol, serializer);
+        <String>::sse_encode(self.ip_version, serializer);
+        <Option<String>>::sse_encode(self.server, serializer);
+        <Option<String>>::sse_encode(self.local_addr, serializer);
+        <Option<String>>::sse_encode(self.mapped_addr, serializer);
+        <Option<i64>>::sse_encode(self.rtt_ms, serializer);
+        <crate::api::nat_test::MappingBehavior>::sse_encode(self.mapping, serializer);
+        <crate::api::nat_test::FilteringBehavior>::sse_encode(self.filtering, serializer);
+        <Option<bool>>::sse_encode(self.hairpinning, serializer);
+        <Option<bool>>::sse_encode(self.port_preserved, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>
-{
+impl SseEncode for crate::api::nat_test::NatType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::nat_test::NatType::OpenInternet => 0,
+                crate::api::nat_test::NatType::FullCone => 1,
+                crate::api::nat_test::NatType::RestrictedCone => 2,
+                crate::api::nat_test::NatType::PortRestrictedCone => 3,
+                crate::api::nat_test::NatType::Symmetric => 4,
+                crate::api::nat_test::NatType::SymmetricUdpFirewall => 5,
+                crate::api::nat_test::NatType::Blocked => 6,
+                crate::api::nat_test::NatType::Unknown => 7,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>>
-{
+impl SseEncode for crate::api::network_config::NetworkConfigC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <String>::sse_encode(self.username, serializer);
+        <bool>::sse_encode(self.enable_dhcp, serializer);
+        <String>::sse_encode(self.specified_ip, serializer);
+        <String>::sse_encode(self.room_name, serializer);
+        <String>::sse_encode(self.room_password, serializer);
+        <Vec<String>>::sse_encode(self.severurl, serializer);
+        <Vec<String>>::sse_encode(self.onurl, serializer);
+        <Vec<String>>::sse_encode(self.cidrs, serializer);
+        <Vec<crate::api::simple::Forward>>::sse_encode(self.forwards, serializer);
+        <crate::api::simple::FlagsC>::sse_encode(self.flag, serializer);
+        <crate::api::network_config::ConfigBuildOptions>::sse_encode(self.options, serializer);
+        <Option<crate::api::relay_select::RelayPool>>::sse_encode(self.relay_pool, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>>
-{
+impl SseEncode for crate::api::nat_test::NetworkTestResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <String>::sse_encode(self.nat_type_v4, serializer);
+        <String>::sse_encode(self.nat_type_v6, serializer);
+        <i64>::sse_encode(self.ipv4_latency, serializer);
+        <i64>::sse_encode(self.ipv6_latency, serializer);
+        <Option<String>>::sse_encode(self.nat_type_v4_raw, serializer);
+        <Option<String>>::sse_encode(self.error_v4, serializer);
+        <Option<String>>::sse_encode(self.error_v6, serializer);
+        <Option<String>>::sse_encode(self.public_endpoint_v4, serializer);
+        <Option<String>>::sse_encode(self.public_endpoint_v6, serializer);
+        <Vec<crate::api::nat_test::StunServerResult>>::sse_encode(self.stun_servers, serializer);
+        <Vec<crate::api::nat_test::HolePunchPrediction>>::sse_encode(self.hole_punch, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<(), String>>>,
-    >
-{
+impl SseEncode for crate::api::simple::NodeHopStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <String>::sse_encode(self.target_ip, serializer);
+        <f64>::sse_encode(self.latency_ms, serializer);
+        <f32>::sse_encode(self.packet_loss, serializer);
+        <String>::sse_encode(self.node_name, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>,
-    >
-{
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastSender>>
-{
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>
-{
+impl SseEncode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerStats>>
-{
+impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<crate::api::minecraft_lan::MinecraftLanAnnouncement> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::minecraft_lan::MinecraftLanAnnouncement>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Option<crate::api::instance_patch::PatchAction> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::instance_patch::PatchAction>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for Option<crate::api::server_probe::ProbeErrorKind> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::server_probe::ProbeErrorKind>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for Option<crate::api::relay_select::RelayPool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::relay_select::RelayPool>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::FlagsC {
+impl SseEncode for Option<crate::api::supervisor::RestartPolicy> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.default_protocol, serializer);
-        <String>::sse_encode(self.dev_name, serializer);
-        <bool>::sse_encode(self.enable_encryption, serializer);
-        <bool>::sse_encode(self.enable_ipv6, serializer);
-        <u32>::sse_encode(self.mtu, serializer);
-        <bool>::sse_encode(self.latency_first, serializer);
-        <bool>::sse_encode(self.enable_exit_node, serializer);
-        <bool>::sse_encode(self.no_tun, serializer);
-        <bool>::sse_encode(self.use_smoltcp, serializer);
-        <String>::sse_encode(self.relay_network_whitelist, serializer);
-        <bool>::sse_encode(self.disable_p2p, serializer);
-        <bool>::sse_encode(self.enable_udp_broadcast_relay, serializer);
-        <bool>::sse_encode(self.relay_all_peer_rpc, serializer);
-        <bool>::sse_encode(self.disable_udp_hole_punching, serializer);
-        <bool>::sse_encode(self.disable_tcp_hole_punching, serializer);
-        <bool>::sse_encode(self.multi_thread, serializer);
-        <i32>::sse_encode(self.data_compress_algo, serializer);
-        <bool>::sse_encode(self.bind_device, serializer);
-        <bool>::sse_encode(self.enable_kcp_proxy, serializer);
-        <bool>::sse_encode(self.disable_kcp_input, serializer);
-        <bool>::sse_encode(self.disable_relay_kcp, serializer);
-        <bool>::sse_encode(self.proxy_forward_by_system, serializer);
-        <bool>::sse_encode(self.accept_dns, serializer);
-        <bool>::sse_encode(self.private_mode, serializer);
-        <bool>::sse_encode(self.enable_quic_proxy, serializer);
-        <bool>::sse_encode(self.disable_quic_input, serializer);
-        <bool>::sse_encode(self.disable_sym_hole_punching, serializer);
-        <String>::sse_encode(self.tcp_whitelist, serializer);
-        <String>::sse_encode(self.udp_whitelist, serializer);
-        <u16>::sse_encode(self.socks5_port, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::supervisor::RestartPolicy>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::Forward {
+impl SseEncode for Option<crate::api::p2p::RunningNodeInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.bind_addr, serializer);
-        <String>::sse_encode(self.dst_addr, serializer);
-        <String>::sse_encode(self.proto, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::p2p::RunningNodeInfo>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Option<crate::api::p2p::RunningStunInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::p2p::RunningStunInfo>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for Option<crate::api::supervisor::SupervisorStatus> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::supervisor::SupervisorStatus>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::KVNetworkStatus {
+impl SseEncode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <usize>::sse_encode(self.total_nodes, serializer);
-        <Vec<crate::api::simple::KVNodeInfo>>::sse_encode(self.nodes, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::KVNodeConnectionStats {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.conn_type, serializer);
-        <u64>::sse_encode(self.rx_bytes, serializer);
-        <u64>::sse_encode(self.tx_bytes, serializer);
-        <u64>::sse_encode(self.rx_packets, serializer);
-        <u64>::sse_encode(self.tx_packets, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::KVNodeInfo {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.peer_id, serializer);
-        <String>::sse_encode(self.hostname, serializer);
-        <String>::sse_encode(self.ipv4, serializer);
-        <f64>::sse_encode(self.latency_ms, serializer);
-        <String>::sse_encode(self.nat, serializer);
-        <Vec<crate::api::simple::NodeHopStats>>::sse_encode(self.hops, serializer);
-        <f32>::sse_encode(self.loss_rate, serializer);
-        <Vec<crate::api::simple::KVNodeConnectionStats>>::sse_encode(self.connections, serializer);
-        <String>::sse_encode(self.tunnel_proto, serializer);
-        <String>::sse_encode(self.conn_type, serializer);
-        <u64>::sse_encode(self.rx_bytes, serializer);
-        <u64>::sse_encode(self.tx_bytes, serializer);
-        <String>::sse_encode(self.version, serializer);
-        <i32>::sse_encode(self.cost, serializer);
-        <Vec<String>>::sse_encode(self.proxy_cidrs, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <usize>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<PeerRoutePair> {
+impl SseEncode for Option<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <PeerRoutePair>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<String>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::instance_patch::PatchAction {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::instance_patch::PatchAction::Add => 0,
+                crate::api::instance_patch::PatchAction::Remove => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::api::simple::Forward> {
+impl SseEncode for crate::api::p2p::PeerConnEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::simple::Forward>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.conn_id, serializer);
+        <u32>::sse_encode(self.my_peer_id, serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <String>::sse_encode(self.tunnel_type, serializer);
+        <String>::sse_encode(self.local_addr, serializer);
+        <String>::sse_encode(self.remote_addr, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::simple::KVNodeConnectionStats> {
+impl SseEncode for crate::api::server_probe::ProbeErrorKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::simple::KVNodeConnectionStats>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::server_probe::ProbeErrorKind::InvalidUrl => 0,
+                crate::api::server_probe::ProbeErrorKind::Dns => 1,
+                crate::api::server_probe::ProbeErrorKind::Refused => 2,
+                crate::api::server_probe::ProbeErrorKind::Timeout => 3,
+                crate::api::server_probe::ProbeErrorKind::Tls => 4,
+                crate::api::server_probe::ProbeErrorKind::Handshake => 5,
+                crate::api::server_probe::ProbeErrorKind::NetworkRejected => 6,
+                crate::api::server_probe::ProbeErrorKind::Other => 7,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::api::simple::KVNodeInfo> {
+impl SseEncode for (String, u32) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::simple::KVNodeInfo>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.0, serializer);
+        <u32>::sse_encode(self.1, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::magic_wall::MagicWallRule> {
+impl SseEncode for (usize, u64, u64) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::magic_wall::MagicWallRule>::sse_encode(item, serializer);
-        }
+        <usize>::sse_encode(self.0, serializer);
+        <u64>::sse_encode(self.1, serializer);
+        <u64>::sse_encode(self.2, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::simple::NodeHopStats> {
+impl SseEncode for crate::api::relay_select::RelayPool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::simple::NodeHopStats>::sse_encode(item, serializer);
-        }
+        <Vec<String>>::sse_encode(self.candidates, serializer);
+        <usize>::sse_encode(self.best_n, serializer);
+        <u64>::sse_encode(self.reevaluate_interval_secs, serializer);
+        <i64>::sse_encode(self.min_improvement_ms, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::supervisor::RestartPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <u32>::sse_encode(self.max_retries, serializer);
+        <u64>::sse_encode(self.initial_backoff_ms, serializer);
+        <u64>::sse_encode(self.max_backoff_ms, serializer);
+        <bool>::sse_encode(self.restart_on_tun_error, serializer);
     }
 }
 
-impl SseEncode for Vec<(String, u32)> {
+impl SseEncode for crate::api::invite::RoomInvite {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, u32)>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.room_name, serializer);
+        <String>::sse_encode(self.room_password, serializer);
+        <Vec<String>>::sse_encode(self.servers, serializer);
+        <Option<String>>::sse_encode(self.ip_range, serializer);
+        <crate::api::invite::InviteFlags>::sse_encode(self.flags, serializer);
+        <Option<i64>>::sse_encode(self.expires_at, serializer);
+        <bool>::sse_encode(self.verified, serializer);
     }
 }
 
-impl SseEncode for crate::api::magic_wall::MagicWallRule {
+impl SseEncode for crate::api::p2p::RunningConnInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <bool>::sse_encode(self.enabled, serializer);
-        <String>::sse_encode(self.action, serializer);
-        <String>::sse_encode(self.protocol, serializer);
-        <String>::sse_encode(self.direction, serializer);
-        <Option<String>>::sse_encode(self.app_path, serializer);
-        <Option<String>>::sse_encode(self.remote_ip, serializer);
-        <Option<String>>::sse_encode(self.local_ip, serializer);
-        <Option<String>>::sse_encode(self.remote_port, serializer);
-        <Option<String>>::sse_encode(self.local_port, serializer);
-        <Option<String>>::sse_encode(self.description, serializer);
-        <Option<i64>>::sse_encode(self.created_at, serializer);
+        <String>::sse_encode(self.conn_id, serializer);
+        <String>::sse_encode(self.tunnel_type, serializer);
+        <String>::sse_encode(self.local_addr, serializer);
+        <String>::sse_encode(self.remote_addr, serializer);
+        <bool>::sse_encode(self.is_client, serializer);
+        <String>::sse_encode(self.network_name, serializer);
+        <f64>::sse_encode(self.latency_ms, serializer);
+        <f32>::sse_encode(self.loss_rate, serializer);
+        <u64>::sse_encode(self.rx_bytes, serializer);
+        <u64>::sse_encode(self.tx_bytes, serializer);
+        <u64>::sse_encode(self.rx_packets, serializer);
+        <u64>::sse_encode(self.tx_packets, serializer);
     }
 }
 
-impl SseEncode for crate::api::magic_wall::MagicWallStatus {
+impl SseEncode for crate::api::p2p::RunningInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_running, serializer);
-        <usize>::sse_encode(self.active_rules, serializer);
-        <usize>::sse_encode(self.total_rules, serializer);
+        <String>::sse_encode(self.dev_name, serializer);
+        <bool>::sse_encode(self.running, serializer);
+        <Option<String>>::sse_encode(self.error_msg, serializer);
+        <Option<crate::api::p2p::RunningNodeInfo>>::sse_encode(self.my_node, serializer);
+        <Vec<crate::api::p2p::RunningPeerInfo>>::sse_encode(self.peers, serializer);
+        <Vec<crate::api::p2p::RunningRouteInfo>>::sse_encode(self.routes, serializer);
     }
 }
 
-impl SseEncode for crate::api::nat_test::NatType {
+impl SseEncode for crate::api::p2p::RunningNodeInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::nat_test::NatType::OpenInternet => 0,
-                crate::api::nat_test::NatType::FullCone => 1,
-                crate::api::nat_test::NatType::RestrictedCone => 2,
-                crate::api::nat_test::NatType::PortRestrictedCone => 3,
-                crate::api::nat_test::NatType::Symmetric => 4,
-                crate::api::nat_test::NatType::SymmetricUdpFirewall => 5,
-                crate::api::nat_test::NatType::Blocked => 6,
-                crate::api::nat_test::NatType::Unknown => 7,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        <u32>::sse_encode(self.peer_id, serializer);
+        <String>::sse_encode(self.hostname, serializer);
+        <String>::sse_encode(self.version, serializer);
+        <Option<String>>::sse_encode(self.virtual_ipv4, serializer);
+        <Option<String>>::sse_encode(self.virtual_ipv6, serializer);
+        <Vec<String>>::sse_encode(self.listeners, serializer);
+        <Option<String>>::sse_encode(self.public_ipv4, serializer);
+        <Option<String>>::sse_encode(self.public_ipv6, serializer);
+        <Vec<String>>::sse_encode(self.interface_ipv4s, serializer);
+        <Vec<String>>::sse_encode(self.interface_ipv6s, serializer);
+        <Option<crate::api::p2p::RunningStunInfo>>::sse_encode(self.stun, serializer);
     }
 }
 
-impl SseEncode for crate::api::nat_test::NetworkTestResult {
+impl SseEncode for crate::api::p2p::RunningPeerInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.nat_type_v4, serializer);
-        <String>::sse_encode(self.nat_type_v6, serializer);
-        <i64>::sse_encode(self.ipv4_latency, serializer);
-        <i64>::sse_encode(self.ipv6_latency, serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <Vec<crate::api::p2p::RunningConnInfo>>::sse_encode(self.connections, serializer);
     }
 }
 
-impl SseEncode for crate::api::simple::NodeHopStats {
+impl SseEncode for crate::api::p2p::RunningRouteInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <u32>::sse_encode(self.peer_id, serializer);
-        <String>::sse_encode(self.target_ip, serializer);
-        <f64>::sse_encode(self.latency_ms, serializer);
-        <f32>::sse_encode(self.packet_loss, serializer);
-        <String>::sse_encode(self.node_name, serializer);
+        <String>::sse_encode(self.inst_id, serializer);
+        <String>::sse_encode(self.hostname, serializer);
+        <String>::sse_encode(self.version, serializer);
+        <Option<String>>::sse_encode(self.ipv4, serializer);
+        <Option<String>>::sse_encode(self.ipv6, serializer);
+        <u32>::sse_encode(self.next_hop_peer_id, serializer);
+        <i32>::sse_encode(self.cost, serializer);
+        <i32>::sse_encode(self.path_latency, serializer);
+        <Vec<String>>::sse_encode(self.proxy_cidrs, serializer);
+        <String>::sse_encode(self.udp_nat_type, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::p2p::RunningStunInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.udp_nat_type, serializer);
+        <String>::sse_encode(self.tcp_nat_type, serializer);
+        <Vec<String>>::sse_encode(self.public_ips, serializer);
+        <u32>::sse_encode(self.min_port, serializer);
+        <u32>::sse_encode(self.max_port, serializer);
+        <i64>::sse_encode(self.last_update_time, serializer);
     }
 }
 
-impl SseEncode for Option<i64> {
+impl SseEncode for crate::api::server_probe::ServerProbeResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i64>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.url, serializer);
+        <bool>::sse_encode(self.reachable, serializer);
+        <Vec<String>>::sse_encode(self.resolved_addrs, serializer);
+        <Option<i64>>::sse_encode(self.dns_ms, serializer);
+        <Option<i64>>::sse_encode(self.connect_ms, serializer);
+        <Option<i64>>::sse_encode(self.rtt_ms, serializer);
+        <Option<u32>>::sse_encode(self.remote_peer_id, serializer);
+        <Option<crate::api::server_probe::ProbeErrorKind>>::sse_encode(self.error_kind, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for (String, u32) {
+impl SseEncode for crate::api::stun_server::StunServerInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.0, serializer);
-        <u32>::sse_encode(self.1, serializer);
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.primary_addr, serializer);
+        <String>::sse_encode(self.alternate_addr, serializer);
+        <bool>::sse_encode(self.running, serializer);
+        <u64>::sse_encode(self.requests, serializer);
     }
 }
 
-impl SseEncode for (usize, u64, u64) {
+impl SseEncode for crate::api::nat_test::StunServerResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <usize>::sse_encode(self.0, serializer);
-        <u64>::sse_encode(self.1, serializer);
-        <u64>::sse_encode(self.2, serializer);
+        <String>::sse_encode(self.server, serializer);
+        <String>::sse_encode(self.ip_version, serializer);
+        <bool>::sse_encode(self.success, serializer);
+        <i64>::sse_encode(self.latency, serializer);
+        <Option<String>>::sse_encode(self.mapped_addr, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::supervisor::SupervisorState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::supervisor::SupervisorState::Running => 0,
+                crate::api::supervisor::SupervisorState::Restarting => 1,
+                crate::api::supervisor::SupervisorState::GaveUp => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::supervisor::SupervisorStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.instance_id, serializer);
+        <crate::api::supervisor::RestartPolicy>::sse_encode(self.policy, serializer);
+        <crate::api::supervisor::SupervisorState>::sse_encode(self.state, serializer);
+        <u32>::sse_encode(self.restart_count, serializer);
+        <u32>::sse_encode(self.consecutive_failures, serializer);
+        <Option<String>>::sse_encode(self.last_failure, serializer);
+        <Option<i64>>::sse_encode(self.last_failure_at, serializer);
+        <Option<i64>>::sse_encode(self.next_restart_at, serializer);
     }
 }
 
@@ -4411,6 +10394,14 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::api::instance_patch::ValuePatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::instance_patch::PatchAction>::sse_encode(self.action, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -4420,6 +10411,7 @@ mod io {
 
     use super::*;
     use crate::api::forward::*;
+    use crate::api::minecraft_lan::*;
     use crate::api::multicast::*;
     use crate::api::p2p::*;
     use crate::api::simple::*;
@@ -4433,20 +10425,6 @@ mod io {
 
     flutter_rust_bridge::frb_generated_boilerplate_io!();
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_astral_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_astral_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>::decrement_strong_count(ptr as _);
-    }
-
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_astral_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
         ptr: *const std::ffi::c_void,
@@ -4461,20 +10439,6 @@ mod io {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>>::decrement_strong_count(ptr as _);
     }
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_astral_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner< JoinHandle < () >>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_astral_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner< JoinHandle < () >>>::decrement_strong_count(ptr as _);
-    }
-
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_astral_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
         ptr: *const std::ffi::c_void,
@@ -4515,6 +10479,34 @@ mod io {
         >::decrement_strong_count(ptr as _);
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_astral_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_astral_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>>::decrement_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_astral_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_astral_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_astral_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
         ptr: *const std::ffi::c_void,
@@ -4570,6 +10562,7 @@ mod web {
 
     use super::*;
     use crate::api::forward::*;
+    use crate::api::minecraft_lan::*;
     use crate::api::multicast::*;
     use crate::api::p2p::*;
     use crate::api::simple::*;
@@ -4585,20 +10578,6 @@ mod web {
 
     flutter_rust_bridge::frb_generated_boilerplate_web!();
 
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>::decrement_strong_count(ptr as _);
-    }
-
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerForwardServer(
         ptr: *const std::ffi::c_void,
@@ -4613,20 +10592,6 @@ mod web {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ForwardServer>>::decrement_strong_count(ptr as _);
     }
 
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner< JoinHandle < () >>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner< JoinHandle < () >>>::decrement_strong_count(ptr as _);
-    }
-
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultString(
         ptr: *const std::ffi::c_void,
@@ -4667,6 +10632,34 @@ mod web {
         >::decrement_strong_count(ptr as _);
     }
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMinecraftLanBridge(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MinecraftLanBridge>>::decrement_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastListener(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MulticastListener>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMulticastSender(
         ptr: *const std::ffi::c_void,